@@ -2,8 +2,9 @@ use actix_web::web::{Data, Json, Query};
 use lemmy_api_common::{
   comment::{ListCommentLikes, ListCommentLikesResponse},
   context::LemmyContext,
-  utils::is_mod_or_admin,
+  utils::check_community_permission,
 };
+use lemmy_db_schema::source::community_role::CommunityPermission;
 use lemmy_db_views::structs::{CommentView, LocalUserView, VoteView};
 use lemmy_utils::error::LemmyResult;
 
@@ -21,10 +22,11 @@ pub async fn list_comment_likes(
   )
   .await?;
 
-  is_mod_or_admin(
-    &mut context.pool(),
+  check_community_permission(
     &local_user_view.person,
-    comment_view.community.id,
+    &comment_view.community,
+    CommunityPermission::ViewVotes,
+    &mut context.pool(),
   )
   .await?;
 
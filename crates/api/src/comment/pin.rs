@@ -0,0 +1,79 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  comment::{CommentResponse, PinComment},
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_permission, check_community_user_action},
+};
+use lemmy_db_schema::{
+  source::{
+    comment::{Comment, CommentUpdateForm},
+    community_role::CommunityPermission,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::{CommentView, LocalUserView};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn pin_comment(
+  data: Json<PinComment>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommentResponse>> {
+  let orig_comment = CommentView::read(
+    &mut context.pool(),
+    data.comment_id,
+    Some(&local_user_view.local_user),
+  )
+  .await?;
+
+  if orig_comment.comment.parent_comment_id().is_some() {
+    Err(LemmyErrorType::CantPinReply)?
+  }
+
+  // The post creator can pin comments in their own post, others need the same permission as for
+  // featuring posts
+  if local_user_view.person.id == orig_comment.post.creator_id {
+    check_community_user_action(
+      &local_user_view.person,
+      &orig_comment.community,
+      &mut context.pool(),
+    )
+    .await?;
+  } else {
+    check_community_permission(
+      &local_user_view.person,
+      &orig_comment.community,
+      CommunityPermission::FeaturePost,
+      &mut context.pool(),
+    )
+    .await?;
+  }
+
+  let form = CommentUpdateForm {
+    distinguished_pinned: Some(data.pinned),
+    ..Default::default()
+  };
+  let comment = Comment::update(&mut context.pool(), data.comment_id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateComment)?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::PinComment(comment, local_user_view.person.clone(), data.pinned),
+    &context,
+  )?;
+
+  let comment_view = CommentView::read(
+    &mut context.pool(),
+    data.comment_id,
+    Some(&local_user_view.local_user),
+  )
+  .await?;
+
+  Ok(Json(CommentResponse {
+    comment_view,
+    recipient_ids: Vec::new(),
+  }))
+}
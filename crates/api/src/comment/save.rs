@@ -8,7 +8,10 @@ use lemmy_db_schema::{
   traits::Saveable,
 };
 use lemmy_db_views::structs::{CommentView, LocalUserView};
-use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
 
 #[tracing::instrument(skip(context))]
 pub async fn save_comment(
@@ -16,9 +19,14 @@ pub async fn save_comment(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<CommentResponse>> {
+  if let Some(saved_note) = &data.saved_note {
+    is_valid_body_field(saved_note, false)?;
+  }
+
   let comment_saved_form = CommentSavedForm {
     comment_id: data.comment_id,
     person_id: local_user_view.person.id,
+    saved_note: data.saved_note.clone(),
   };
 
   if data.save {
@@ -57,6 +57,8 @@ pub async fn create_comment_report(
     comment_id,
     original_comment_text: comment_view.comment.content,
     reason,
+    severity: data.severity,
+    category_id: data.category_id,
   };
 
   let report = CommentReport::report(&mut context.pool(), &report_form)
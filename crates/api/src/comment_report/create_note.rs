@@ -0,0 +1,42 @@
+use super::validate_note_content;
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  comment::{CommentReportNoteResponse, CreateCommentReportNote},
+  context::LemmyContext,
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::{
+  source::report_note::{ReportNote, ReportNoteInsertForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::{CommentReportView, LocalUserView, ReportNoteView};
+use lemmy_utils::error::LemmyResult;
+
+/// Leaves an internal note on a comment report, which only the moderators can see
+#[tracing::instrument(skip(context))]
+pub async fn create_comment_report_note(
+  data: Json<CreateCommentReportNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommentReportNoteResponse>> {
+  let person_id = local_user_view.person.id;
+  let report = CommentReportView::read(&mut context.pool(), data.report_id, person_id).await?;
+
+  check_community_mod_action(
+    &local_user_view.person,
+    &report.community,
+    true,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let form = ReportNoteInsertForm {
+    comment_report_id: data.report_id,
+    creator_id: person_id,
+    content: validate_note_content(&data.content)?,
+  };
+  let note = ReportNote::create(&mut context.pool(), &form).await?;
+  let report_note_view = ReportNoteView::read(&mut context.pool(), note.id).await?;
+
+  Ok(Json(CommentReportNoteResponse { report_note_view }))
+}
@@ -0,0 +1,38 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  comment::DeleteCommentReportNote,
+  context::LemmyContext,
+  utils::check_community_mod_action,
+  SuccessResponse,
+};
+use lemmy_db_schema::{source::report_note::ReportNote, traits::Crud};
+use lemmy_db_views::structs::{CommentReportView, LocalUserView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Deletes a note on a comment report. Admins can delete the notes of other moderators.
+#[tracing::instrument(skip(context))]
+pub async fn delete_comment_report_note(
+  data: Json<DeleteCommentReportNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let person_id = local_user_view.person.id;
+  let note = ReportNote::read(&mut context.pool(), data.note_id).await?;
+  if note.creator_id != person_id && !local_user_view.local_user.admin {
+    Err(LemmyErrorType::NoReportNoteEditAllowed)?
+  }
+
+  let report =
+    CommentReportView::read(&mut context.pool(), note.comment_report_id, person_id).await?;
+  check_community_mod_action(
+    &local_user_view.person,
+    &report.community,
+    true,
+    &mut context.pool(),
+  )
+  .await?;
+
+  ReportNote::delete(&mut context.pool(), data.note_id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
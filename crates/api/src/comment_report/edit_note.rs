@@ -0,0 +1,47 @@
+use super::validate_note_content;
+use actix_web::web::{Data, Json};
+use chrono::Utc;
+use lemmy_api_common::{
+  comment::{CommentReportNoteResponse, EditCommentReportNote},
+  context::LemmyContext,
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::{
+  source::report_note::{ReportNote, ReportNoteUpdateForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::{CommentReportView, LocalUserView, ReportNoteView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Edits a note on a comment report. Only the moderator who wrote the note can edit it.
+#[tracing::instrument(skip(context))]
+pub async fn edit_comment_report_note(
+  data: Json<EditCommentReportNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommentReportNoteResponse>> {
+  let person_id = local_user_view.person.id;
+  let orig_note = ReportNote::read(&mut context.pool(), data.note_id).await?;
+  if orig_note.creator_id != person_id {
+    Err(LemmyErrorType::NoReportNoteEditAllowed)?
+  }
+
+  let report =
+    CommentReportView::read(&mut context.pool(), orig_note.comment_report_id, person_id).await?;
+  check_community_mod_action(
+    &local_user_view.person,
+    &report.community,
+    true,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let form = ReportNoteUpdateForm {
+    content: validate_note_content(&data.content)?,
+    updated: Utc::now(),
+  };
+  ReportNote::update(&mut context.pool(), data.note_id, &form).await?;
+  let report_note_view = ReportNoteView::read(&mut context.pool(), data.note_id).await?;
+
+  Ok(Json(CommentReportNoteResponse { report_note_view }))
+}
@@ -4,7 +4,10 @@ use lemmy_api_common::{
   context::LemmyContext,
   utils::check_community_mod_of_any_or_admin_action,
 };
-use lemmy_db_views::{comment_report_view::CommentReportQuery, structs::LocalUserView};
+use lemmy_db_views::{
+  comment_report_view::{CommentReportPaginationCursorData, CommentReportQuery},
+  structs::{CommentReportView, LocalUserView, PersonModNoteView},
+};
 use lemmy_utils::error::LemmyResult;
 
 /// Lists comment reports for a community if an id is supplied
@@ -21,17 +24,41 @@ pub async fn list_comment_reports(
 
   check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
 
-  let page = data.page;
+  let page_after = if let Some(page_cursor) = &data.page_cursor {
+    Some(CommentReportPaginationCursorData::read(page_cursor, &mut context.pool()).await?)
+  } else {
+    None
+  };
+
   let limit = data.limit;
-  let comment_reports = CommentReportQuery {
+  let mut comment_reports = CommentReportQuery {
     community_id,
     comment_id,
     unresolved_only,
-    page,
+    sort_by_severity: data.sort_by_severity.unwrap_or_default(),
+    category: data.category_id,
+    outcome: data.outcome,
+    assigned_to_me: data.assigned_to_me.unwrap_or_default(),
+    page_after,
     limit,
+    ..Default::default()
   }
   .list(&mut context.pool(), &local_user_view)
   .await?;
+  CommentReportView::fill_notes(&mut context.pool(), &mut comment_reports).await?;
+
+  let next_page = comment_reports.last().map(CommentReportView::to_cursor);
+  let creator_ids: Vec<_> = comment_reports
+    .iter()
+    .map(|r| r.comment_creator.id)
+    .collect();
+  let mod_notes =
+    PersonModNoteView::list_for_persons(&mut context.pool(), &creator_ids, &local_user_view)
+      .await?;
 
-  Ok(Json(ListCommentReportsResponse { comment_reports }))
+  Ok(Json(ListCommentReportsResponse {
+    comment_reports,
+    next_page,
+    mod_notes,
+  }))
 }
@@ -1,3 +1,22 @@
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
 pub mod create;
+pub mod create_note;
+pub mod delete_note;
+pub mod edit_note;
 pub mod list;
 pub mod resolve;
+pub mod resolve_many;
+
+/// Trims the note content, and checks that it is neither empty nor too long.
+pub(crate) fn validate_note_content(content: &str) -> LemmyResult<String> {
+  let content = content.trim();
+  if content.is_empty() {
+    Err(LemmyErrorType::InvalidBodyField)?
+  }
+  is_valid_body_field(content, false)?;
+  Ok(content.to_string())
+}
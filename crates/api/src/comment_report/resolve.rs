@@ -4,7 +4,7 @@ use lemmy_api_common::{
   context::LemmyContext,
   utils::check_community_mod_action,
 };
-use lemmy_db_schema::{source::comment_report::CommentReport, traits::Reportable};
+use lemmy_db_schema::{source::comment_report::CommentReport, traits::Reportable, ReportOutcome};
 use lemmy_db_views::structs::{CommentReportView, LocalUserView};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
@@ -29,7 +29,8 @@ pub async fn resolve_comment_report(
   .await?;
 
   if data.resolved {
-    CommentReport::resolve(&mut context.pool(), report_id, person_id)
+    let outcome = data.outcome.unwrap_or(ReportOutcome::ResolvedNoAction);
+    CommentReport::resolve(&mut context.pool(), report_id, person_id, outcome)
       .await
       .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
   } else {
@@ -39,8 +40,13 @@ pub async fn resolve_comment_report(
   }
 
   let report_id = data.report_id;
-  let comment_report_view =
+  let mut comment_report_view =
     CommentReportView::read(&mut context.pool(), report_id, person_id).await?;
+  CommentReportView::fill_notes(
+    &mut context.pool(),
+    std::slice::from_mut(&mut comment_report_view),
+  )
+  .await?;
 
   Ok(Json(CommentReportResponse {
     comment_report_view,
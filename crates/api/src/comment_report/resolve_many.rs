@@ -0,0 +1,56 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  comment::{ResolveCommentReports, ResolveCommentReportsResponse},
+  context::LemmyContext,
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::{
+  source::{comment::Comment, comment_report::CommentReport, community::Community, post::Post},
+  traits::Crud,
+  ReportOutcome,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+/// Resolves all open reports against a comment, or against all comments of a post, so that
+/// moderators don't need to resolve the reports of mass-reported content one by one
+#[tracing::instrument(skip(context))]
+pub async fn resolve_comment_reports(
+  data: Json<ResolveCommentReports>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ResolveCommentReportsResponse>> {
+  let person_id = local_user_view.person.id;
+  let post_id = match (data.comment_id, data.post_id) {
+    (Some(comment_id), _) => {
+      Comment::read(&mut context.pool(), comment_id)
+        .await?
+        .post_id
+    }
+    (None, Some(post_id)) => post_id,
+    (None, None) => Err(LemmyErrorType::NoIdGiven)?,
+  };
+  let post = Post::read(&mut context.pool(), post_id).await?;
+  let community = Community::read(&mut context.pool(), post.community_id).await?;
+
+  check_community_mod_action(
+    &local_user_view.person,
+    &community,
+    true,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let outcome = data.outcome.unwrap_or(ReportOutcome::ResolvedNoAction);
+  let resolved = if let Some(comment_id) = data.comment_id {
+    CommentReport::resolve_all_for_comment(&mut context.pool(), comment_id, person_id, outcome)
+      .await
+  } else {
+    CommentReport::resolve_all_for_post(&mut context.pool(), post_id, person_id, outcome).await
+  }
+  .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
+
+  Ok(Json(ResolveCommentReportsResponse {
+    resolved: i64::try_from(resolved)?,
+  }))
+}
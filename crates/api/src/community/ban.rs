@@ -61,11 +61,16 @@ pub async fn ban_from_community(
   if let Some(reason) = &data.reason {
     is_valid_body_field(reason, false)?;
   }
+  if let Some(appeal) = &data.appeal {
+    is_valid_body_field(appeal, false)?;
+  }
 
   let community_user_ban_form = CommunityPersonBanForm {
     community_id: data.community_id,
     person_id: data.person_id,
     expires: Some(expires),
+    reason: Some(data.reason.clone()),
+    appeal: Some(data.appeal.clone()),
   };
 
   if data.ban {
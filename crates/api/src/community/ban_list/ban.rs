@@ -0,0 +1,59 @@
+use super::sync_ban_list_ban;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::BanFromBanList,
+  context::LemmyContext,
+  utils::{check_ban_list_manager, check_expire_time},
+  SuccessResponse,
+};
+use lemmy_db_schema::source::ban_list::{BanList, BanListEntry, BanListEntryForm};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_body_field};
+
+/// Adds a person to a ban list or removes them from it, and applies the change to every subscribed
+/// community which hasn't exempted them. Bans from a list are only applied locally.
+#[tracing::instrument(skip(context))]
+pub async fn ban_from_ban_list(
+  data: Json<BanFromBanList>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  check_ban_list_manager(&local_user_view, data.ban_list_id, &mut context.pool()).await?;
+
+  if let Some(reason) = &data.reason {
+    is_valid_body_field(reason, false)?;
+  }
+
+  let entry = if data.ban {
+    let form = BanListEntryForm {
+      ban_list_id: data.ban_list_id,
+      person_id: data.person_id,
+      creator_id: local_user_view.person.id,
+      reason: data.reason.clone(),
+      expires: check_expire_time(data.expires)?,
+    };
+    Some(BanListEntry::upsert(&mut context.pool(), &form).await?)
+  } else {
+    let entry = BanListEntry::read(&mut context.pool(), data.ban_list_id, data.person_id).await?;
+    BanListEntry::delete(&mut context.pool(), data.ban_list_id, data.person_id).await?;
+    entry
+  };
+
+  if let Some(entry) = entry {
+    let community_ids =
+      BanList::communities_to_ban(&mut context.pool(), data.ban_list_id, data.person_id).await?;
+    for community_id in community_ids {
+      sync_ban_list_ban(
+        &entry,
+        community_id,
+        data.ban,
+        local_user_view.person.id,
+        &context,
+      )
+      .await?;
+    }
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,41 @@
+use super::validate_ban_list_name;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{BanListResponse, CreateBanList},
+  context::LemmyContext,
+  utils::check_community_mod_of_any_or_admin_action,
+};
+use lemmy_db_schema::{
+  source::ban_list::{BanList, BanListInsertForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_ban_list(
+  data: Json<CreateBanList>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BanListResponse>> {
+  check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+
+  let name = validate_ban_list_name(&data.name, &context).await?;
+  if let Some(description) = &data.description {
+    is_valid_body_field(description, false)?;
+  }
+
+  let form = BanListInsertForm {
+    description: data.description.clone(),
+    ..BanListInsertForm::new(name, local_user_view.person.id)
+  };
+  let ban_list = BanList::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateBanList)?;
+
+  Ok(Json(BanListResponse { ban_list }))
+}
@@ -0,0 +1,46 @@
+use super::validate_ban_list_name;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{BanListResponse, EditBanList},
+  context::LemmyContext,
+  utils::check_ban_list_manager,
+};
+use lemmy_db_schema::{
+  source::ban_list::{BanList, BanListUpdateForm},
+  traits::Crud,
+  utils::{diesel_string_update, naive_now},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn edit_ban_list(
+  data: Json<EditBanList>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BanListResponse>> {
+  check_ban_list_manager(&local_user_view, data.ban_list_id, &mut context.pool()).await?;
+
+  let name = match &data.name {
+    Some(name) => Some(validate_ban_list_name(name, &context).await?),
+    None => None,
+  };
+  if let Some(description) = &data.description {
+    is_valid_body_field(description, false)?;
+  }
+
+  let form = BanListUpdateForm {
+    name,
+    description: diesel_string_update(data.description.as_deref()),
+    updated: Some(Some(naive_now())),
+  };
+  let ban_list = BanList::update(&mut context.pool(), data.ban_list_id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateBanList)?;
+
+  Ok(Json(BanListResponse { ban_list }))
+}
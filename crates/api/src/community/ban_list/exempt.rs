@@ -0,0 +1,60 @@
+use super::sync_ban_list_ban;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::ExemptFromBanList,
+  context::LemmyContext,
+  utils::is_mod_or_admin,
+  SuccessResponse,
+};
+use lemmy_db_schema::source::ban_list::{
+  BanListCommunity,
+  BanListEntry,
+  BanListExemption,
+  BanListExemptionForm,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+/// Lets a community opt out of a single ban on a list, or opt back in. This only affects the ban
+/// which the list applied, not direct bans by the community's moderators.
+#[tracing::instrument(skip(context))]
+pub async fn exempt_from_ban_list(
+  data: Json<ExemptFromBanList>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    data.community_id,
+  )
+  .await?;
+
+  let form = BanListExemptionForm {
+    ban_list_id: data.ban_list_id,
+    community_id: data.community_id,
+    person_id: data.person_id,
+  };
+  if data.exempt {
+    BanListExemption::create(&mut context.pool(), &form).await?;
+  } else {
+    BanListExemption::delete(&mut context.pool(), &form).await?;
+  }
+
+  let entry = BanListEntry::read(&mut context.pool(), data.ban_list_id, data.person_id).await?;
+  let subscription =
+    BanListCommunity::read(&mut context.pool(), data.ban_list_id, data.community_id).await?;
+  if let (Some(entry), Some(_)) = (entry, subscription) {
+    sync_ban_list_ban(
+      &entry,
+      data.community_id,
+      !data.exempt,
+      local_user_view.person.id,
+      &context,
+    )
+    .await?;
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,33 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  community::{GetBanList, GetBanListResponse},
+  context::LemmyContext,
+  utils::check_community_mod_of_any_or_admin_action,
+};
+use lemmy_db_schema::{
+  source::ban_list::{BanList, BanListCommunity, BanListEntry, BanListExemption},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn get_ban_list(
+  data: Query<GetBanList>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<GetBanListResponse>> {
+  check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+
+  let ban_list = BanList::read(&mut context.pool(), data.ban_list_id).await?;
+  let communities = BanListCommunity::list_for_ban_list(&mut context.pool(), ban_list.id).await?;
+  let entries = BanListEntry::list_for_ban_list(&mut context.pool(), ban_list.id).await?;
+  let exemptions = BanListExemption::list_for_ban_list(&mut context.pool(), ban_list.id).await?;
+
+  Ok(Json(GetBanListResponse {
+    ban_list,
+    communities,
+    entries,
+    exemptions,
+  }))
+}
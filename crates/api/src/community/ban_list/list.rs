@@ -0,0 +1,22 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  community::{ListBanLists, ListBanListsResponse},
+  context::LemmyContext,
+  utils::check_community_mod_of_any_or_admin_action,
+};
+use lemmy_db_schema::source::ban_list::BanList;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_ban_lists(
+  data: Query<ListBanLists>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListBanListsResponse>> {
+  check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+
+  let ban_lists = BanList::list(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListBanListsResponse { ban_lists }))
+}
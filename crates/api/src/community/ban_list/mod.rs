@@ -0,0 +1,90 @@
+use lemmy_api_common::{context::LemmyContext, utils::local_site_to_slur_regex};
+use lemmy_db_schema::{
+  newtypes::{CommunityId, PersonId},
+  source::{
+    ban_list::{BanList, BanListEntry},
+    community::{CommunityFollower, CommunityFollowerForm},
+    local_site::LocalSite,
+    moderator::{ModBanFromCommunity, ModBanFromCommunityForm},
+  },
+  traits::{Crud, Followable},
+};
+use lemmy_db_views_actor::structs::CommunityView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::slurs::check_slurs,
+};
+
+pub mod ban;
+pub mod create;
+pub mod edit;
+pub mod exempt;
+pub mod get;
+pub mod list;
+pub mod subscribe;
+
+const MAX_BAN_LIST_NAME_LENGTH: usize = 255;
+
+/// Trims the ban list name, and checks that it is neither empty nor too long.
+async fn validate_ban_list_name(name: &str, context: &LemmyContext) -> LemmyResult<String> {
+  let name = name.trim();
+  if name.is_empty() || name.chars().count() > MAX_BAN_LIST_NAME_LENGTH {
+    Err(LemmyErrorType::InvalidBanListName)?
+  }
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_slurs(name, &local_site_to_slur_regex(&local_site))?;
+  Ok(name.to_string())
+}
+
+/// Applies or lifts a ban of the list in one community, and writes the change to the modlog.
+/// Moderators and admins are never banned by a list.
+async fn sync_ban_list_ban(
+  entry: &BanListEntry,
+  community_id: CommunityId,
+  ban: bool,
+  mod_person_id: PersonId,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let pool = &mut context.pool();
+  let person_id = entry.person_id;
+  let changed = if ban {
+    if CommunityView::check_is_mod_or_admin(pool, person_id, community_id)
+      .await
+      .is_ok()
+    {
+      return Ok(());
+    }
+    let applied = BanList::apply_ban(
+      pool,
+      entry.ban_list_id,
+      community_id,
+      person_id,
+      entry.expires,
+    )
+    .await?;
+    if applied {
+      // Also unsubscribe them from the community, if they are subscribed
+      let community_follower_form = CommunityFollowerForm::new(community_id, person_id);
+      CommunityFollower::unfollow(pool, &community_follower_form)
+        .await
+        .ok();
+    }
+    applied
+  } else {
+    let count = BanList::lift_ban(pool, entry.ban_list_id, community_id, person_id).await?;
+    count.updated + count.deleted > 0
+  };
+
+  if changed {
+    let form = ModBanFromCommunityForm {
+      mod_person_id,
+      other_person_id: person_id,
+      community_id,
+      reason: entry.reason.clone(),
+      banned: Some(ban),
+      expires: entry.expires,
+    };
+    ModBanFromCommunity::create(pool, &form).await?;
+  }
+  Ok(())
+}
@@ -0,0 +1,65 @@
+use super::sync_ban_list_ban;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::SubscribeBanList,
+  context::LemmyContext,
+  utils::{check_ban_list_manager, is_mod_or_admin},
+  SuccessResponse,
+};
+use lemmy_db_schema::{
+  source::{
+    ban_list::{BanList, BanListCommunity, BanListCommunityForm},
+    community::Community,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Subscribes a community to a ban list and applies all of its bans, or unsubscribes it and lifts
+/// them again.
+#[tracing::instrument(skip(context))]
+pub async fn subscribe_ban_list(
+  data: Json<SubscribeBanList>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  if !community.local {
+    Err(LemmyErrorType::ObjectNotLocal)?
+  }
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    data.community_id,
+  )
+  .await?;
+
+  // Only the existing team of a list can add more communities to it
+  if data.subscribe {
+    check_ban_list_manager(&local_user_view, data.ban_list_id, &mut context.pool()).await?;
+    let form = BanListCommunityForm {
+      ban_list_id: data.ban_list_id,
+      community_id: data.community_id,
+    };
+    BanListCommunity::subscribe(&mut context.pool(), &form).await?;
+  } else {
+    BanListCommunity::unsubscribe(&mut context.pool(), data.ban_list_id, data.community_id).await?;
+  }
+
+  let entries =
+    BanList::entries_to_apply(&mut context.pool(), data.ban_list_id, data.community_id).await?;
+  for entry in entries {
+    sync_ban_list_ban(
+      &entry,
+      data.community_id,
+      data.subscribe,
+      local_user_view.person.id,
+      &context,
+    )
+    .await?;
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
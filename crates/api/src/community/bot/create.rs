@@ -0,0 +1,52 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{CommunityBotDelegationResponse, CreateCommunityBotDelegation},
+  community_bot::generate_community_bot_token,
+  context::LemmyContext,
+  utils::is_mod_or_admin,
+};
+use lemmy_db_schema::{
+  source::{
+    community_bot::{CommunityBotDelegation, CommunityBotDelegationInsertForm},
+    person::Person,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_community_bot_delegation(
+  data: Json<CreateCommunityBotDelegation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityBotDelegationResponse>> {
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    data.community_id,
+  )
+  .await?;
+
+  // Only local bot accounts can act for a community
+  let bot = Person::read(&mut context.pool(), data.bot_person_id).await?;
+  if !bot.bot_account || !bot.local {
+    Err(LemmyErrorType::PersonIsNotABot)?
+  }
+
+  let form = CommunityBotDelegationInsertForm::new(
+    data.community_id,
+    data.bot_person_id,
+    local_user_view.person.id,
+    data.scopes.clone(),
+    generate_community_bot_token(),
+  );
+  let delegation = CommunityBotDelegation::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateCommunityBotDelegation)?;
+
+  // The token is only shown once, so it can't leak through the delegation list
+  let token = Some(delegation.token.clone().into_inner());
+  Ok(Json(CommunityBotDelegationResponse { delegation, token }))
+}
@@ -0,0 +1,46 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{CommunityBotDelegationResponse, EditCommunityBotDelegation},
+  context::LemmyContext,
+  utils::is_mod_or_admin,
+};
+use lemmy_db_schema::{
+  source::community_bot::{CommunityBotDelegation, CommunityBotDelegationUpdateForm},
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn edit_community_bot_delegation(
+  data: Json<EditCommunityBotDelegation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityBotDelegationResponse>> {
+  let orig = CommunityBotDelegation::read(&mut context.pool(), data.id).await?;
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    orig.community_id,
+  )
+  .await?;
+
+  // Revoked delegations can't be reactivated, a new one needs to be created instead
+  if orig.revoked {
+    Err(LemmyErrorType::NotFound)?
+  }
+
+  let form = CommunityBotDelegationUpdateForm {
+    scopes: Some(data.scopes.clone()),
+    updated: Some(Some(naive_now())),
+    ..Default::default()
+  };
+  let delegation = CommunityBotDelegation::update(&mut context.pool(), data.id, &form).await?;
+
+  Ok(Json(CommunityBotDelegationResponse {
+    delegation,
+    token: None,
+  }))
+}
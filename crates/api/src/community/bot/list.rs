@@ -0,0 +1,29 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  community::{ListCommunityBotDelegations, ListCommunityBotDelegationsResponse},
+  context::LemmyContext,
+  utils::is_mod_or_admin,
+};
+use lemmy_db_schema::source::community_bot::CommunityBotDelegation;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_community_bot_delegations(
+  data: Query<ListCommunityBotDelegations>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListCommunityBotDelegationsResponse>> {
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    data.community_id,
+  )
+  .await?;
+
+  let delegations =
+    CommunityBotDelegation::list_for_community(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListCommunityBotDelegationsResponse { delegations }))
+}
@@ -0,0 +1,5 @@
+pub mod create;
+pub mod edit;
+pub mod list;
+pub mod list_actions;
+pub mod revoke;
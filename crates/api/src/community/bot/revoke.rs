@@ -0,0 +1,41 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{CommunityBotDelegationResponse, RevokeCommunityBotDelegation},
+  context::LemmyContext,
+  utils::is_mod_or_admin,
+};
+use lemmy_db_schema::{
+  source::community_bot::{CommunityBotDelegation, CommunityBotDelegationUpdateForm},
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn revoke_community_bot_delegation(
+  data: Json<RevokeCommunityBotDelegation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityBotDelegationResponse>> {
+  let orig = CommunityBotDelegation::read(&mut context.pool(), data.id).await?;
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    orig.community_id,
+  )
+  .await?;
+
+  let form = CommunityBotDelegationUpdateForm {
+    revoked: Some(true),
+    updated: Some(Some(naive_now())),
+    ..Default::default()
+  };
+  let delegation = CommunityBotDelegation::update(&mut context.pool(), data.id, &form).await?;
+
+  Ok(Json(CommunityBotDelegationResponse {
+    delegation,
+    token: None,
+  }))
+}
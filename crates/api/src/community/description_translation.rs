@@ -0,0 +1,55 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  community::{CommunityDescriptionTranslationsResponse, EditCommunityDescriptionTranslation},
+  context::LemmyContext,
+  description_translation::process_description_translation,
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    description_translation::{
+      CommunityDescriptionTranslation,
+      CommunityDescriptionTranslationForm,
+    },
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn edit_community_description_translation(
+  data: Json<EditCommunityDescriptionTranslation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityDescriptionTranslationsResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_mod_action(
+    &local_user_view.person,
+    &community,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
+
+  if data.sidebar.is_none() && data.description.is_none() {
+    CommunityDescriptionTranslation::delete(&mut context.pool(), community.id, data.language_id)
+      .await?;
+  } else {
+    let sidebar =
+      process_description_translation(&data.sidebar, &data.description, &context).await?;
+    let form = CommunityDescriptionTranslationForm {
+      sidebar,
+      description: data.description.clone(),
+      ..CommunityDescriptionTranslationForm::new(community.id, data.language_id)
+    };
+    CommunityDescriptionTranslation::upsert(&mut context.pool(), &form).await?;
+  }
+
+  let description_translations =
+    CommunityDescriptionTranslation::list(&mut context.pool(), community.id).await?;
+  Ok(Json(CommunityDescriptionTranslationsResponse {
+    description_translations,
+  }))
+}
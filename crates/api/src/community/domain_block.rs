@@ -0,0 +1,62 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  community::{BlockCommunityDomain, ListCommunityDomainBlocks, ListCommunityDomainBlocksResponse},
+  context::LemmyContext,
+  utils::check_community_mod_action,
+  SuccessResponse,
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    community_domain_block::{CommunityDomainBlock, CommunityDomainBlockForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::clean_domain,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn block_community_domain(
+  data: Json<BlockCommunityDomain>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_mod_action(
+    &local_user_view.person,
+    &community,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let domain = clean_domain(&data.domain)?;
+  if data.block {
+    let form = CommunityDomainBlockForm {
+      reason: data.reason.clone(),
+      ..CommunityDomainBlockForm::new(community.id, domain, local_user_view.person.id)
+    };
+    CommunityDomainBlock::block(&mut context.pool(), &form).await?;
+  } else {
+    let deleted = CommunityDomainBlock::unblock(&mut context.pool(), community.id, &domain).await?;
+    if deleted == 0 {
+      Err(LemmyErrorType::NotFound)?
+    }
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+/// The blocked domains are public, so that people know in advance which links aren't allowed.
+#[tracing::instrument(skip(context))]
+pub async fn list_community_domain_blocks(
+  data: Query<ListCommunityDomainBlocks>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<ListCommunityDomainBlocksResponse>> {
+  let domain_blocks = CommunityDomainBlock::list(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListCommunityDomainBlocksResponse { domain_blocks }))
+}
@@ -10,12 +10,18 @@ use lemmy_db_schema::{
   source::{
     actor_language::CommunityLanguage,
     community::{Community, CommunityFollower, CommunityFollowerForm, CommunityFollowerState},
+    private_message::{PrivateMessage, PrivateMessageInsertForm},
   },
   traits::{Crud, Followable},
   CommunityVisibility,
+  SubscribedType,
 };
 use lemmy_db_views::structs::LocalUserView;
-use lemmy_db_views_actor::structs::{CommunityPersonBanView, CommunityView};
+use lemmy_db_views_actor::structs::{
+  CommunityModeratorView,
+  CommunityPersonBanView,
+  CommunityView,
+};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
 #[tracing::instrument(skip(context))]
@@ -53,11 +59,27 @@ pub async fn follow_community(
       state,
       ..CommunityFollowerForm::new(community.id, local_user_view.person.id)
     };
+    let already_following = CommunityView::read(
+      &mut context.pool(),
+      community.id,
+      Some(&local_user_view.local_user),
+      false,
+    )
+    .await?
+    .subscribed
+      != SubscribedType::NotSubscribed;
 
     // Write to db
     CommunityFollower::follow(&mut context.pool(), &form)
       .await
       .with_lemmy_type(LemmyErrorType::CommunityFollowerAlreadyExists)?;
+
+    if !already_following {
+      // The follow itself succeeded, so only log if the welcome message couldn't be sent
+      if let Err(e) = send_welcome_message(&community, &local_user_view, &context).await {
+        tracing::warn!("Failed to send community welcome message: {e}");
+      }
+    }
   } else {
     CommunityFollower::unfollow(&mut context.pool(), &form)
       .await
@@ -88,3 +110,35 @@ pub async fn follow_community(
     discussion_languages,
   }))
 }
+
+/// Sends the welcome message of a local community to a new follower, in the name of the top
+/// moderator. Nothing is sent if the follower disabled private messages.
+async fn send_welcome_message(
+  community: &Community,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let Some(welcome_message) = &community.welcome_message else {
+    return Ok(());
+  };
+  if !community.local || !local_user_view.local_user.enable_private_messages {
+    return Ok(());
+  }
+  let moderators = CommunityModeratorView::for_community(&mut context.pool(), community.id).await?;
+  let Some(top_mod) = moderators.first().map(|m| &m.moderator) else {
+    return Ok(());
+  };
+  if !top_mod.local || top_mod.id == local_user_view.person.id {
+    return Ok(());
+  }
+
+  let form = PrivateMessageInsertForm::new(
+    top_mod.id,
+    Some(local_user_view.person.id),
+    welcome_message.clone(),
+  );
+  PrivateMessage::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreatePrivateMessage)?;
+  Ok(())
+}
@@ -0,0 +1,32 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  community::LaunchCommunity,
+  context::LemmyContext,
+  utils::is_admin,
+  SuccessResponse,
+};
+use lemmy_db_schema::{
+  source::community::{Community, CommunityUpdateForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn launch_community(
+  data: Json<LaunchCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  is_admin(&local_user_view)?;
+
+  let community_form = CommunityUpdateForm {
+    soft_launched: Some(false),
+    ..Default::default()
+  };
+  Community::update(&mut context.pool(), data.community_id, &community_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateCommunity)?;
+
+  Ok(Json(SuccessResponse::default()))
+}
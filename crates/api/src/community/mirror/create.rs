@@ -0,0 +1,51 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{CommunityMirrorResponse, CreateCommunityMirror},
+  context::LemmyContext,
+  utils::is_mod_or_admin,
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    community_mirror::{CommunityMirror, CommunityMirrorInsertForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_community_mirror(
+  data: Json<CreateCommunityMirror>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityMirrorResponse>> {
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  if !community.local {
+    Err(LemmyErrorType::ObjectNotLocal)?
+  }
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    data.community_id,
+  )
+  .await?;
+
+  // Actions of the partner community arrive over federation, so it has to be on another instance
+  let partner = Community::read(&mut context.pool(), data.partner_community_id).await?;
+  if partner.local {
+    Err(LemmyErrorType::MirrorPartnerMustBeRemote)?
+  }
+
+  let form = CommunityMirrorInsertForm {
+    mirror_bans: data.mirror_bans,
+    mirror_removals: data.mirror_removals,
+    ..CommunityMirrorInsertForm::new(community.id, partner.id, local_user_view.person.id)
+  };
+  let mirror = CommunityMirror::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateCommunityMirror)?;
+
+  Ok(Json(CommunityMirrorResponse { mirror }))
+}
@@ -0,0 +1,39 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{CommunityMirrorResponse, EditCommunityMirror},
+  context::LemmyContext,
+  utils::is_mod_or_admin,
+};
+use lemmy_db_schema::{
+  source::community_mirror::{CommunityMirror, CommunityMirrorUpdateForm},
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn edit_community_mirror(
+  data: Json<EditCommunityMirror>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityMirrorResponse>> {
+  let orig = CommunityMirror::read(&mut context.pool(), data.id).await?;
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    orig.community_id,
+  )
+  .await?;
+
+  let form = CommunityMirrorUpdateForm {
+    mirror_bans: data.mirror_bans,
+    mirror_removals: data.mirror_removals,
+    enabled: data.enabled,
+    updated: Some(Some(naive_now())),
+  };
+  let mirror = CommunityMirror::update(&mut context.pool(), data.id, &form).await?;
+
+  Ok(Json(CommunityMirrorResponse { mirror }))
+}
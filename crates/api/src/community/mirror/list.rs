@@ -0,0 +1,28 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  community::{ListCommunityMirrors, ListCommunityMirrorsResponse},
+  context::LemmyContext,
+  utils::is_mod_or_admin,
+};
+use lemmy_db_schema::source::community_mirror::CommunityMirror;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_community_mirrors(
+  data: Query<ListCommunityMirrors>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListCommunityMirrorsResponse>> {
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    data.community_id,
+  )
+  .await?;
+
+  let mirrors = CommunityMirror::list_for_community(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListCommunityMirrorsResponse { mirrors }))
+}
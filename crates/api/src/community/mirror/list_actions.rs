@@ -0,0 +1,34 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  community::{ListCommunityMirrorActions, ListCommunityMirrorActionsResponse},
+  context::LemmyContext,
+  utils::is_mod_or_admin,
+};
+use lemmy_db_schema::source::community_mirror::CommunityMirrorAction;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_community_mirror_actions(
+  data: Query<ListCommunityMirrorActions>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListCommunityMirrorActionsResponse>> {
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    data.community_id,
+  )
+  .await?;
+
+  let actions = CommunityMirrorAction::list_for_community(
+    &mut context.pool(),
+    data.community_id,
+    data.page,
+    data.limit,
+  )
+  .await?;
+
+  Ok(Json(ListCommunityMirrorActionsResponse { actions }))
+}
@@ -0,0 +1,4 @@
+pub mod create;
+pub mod edit;
+pub mod list;
+pub mod list_actions;
@@ -1,8 +1,21 @@
 pub mod add_mod;
 pub mod ban;
+pub mod ban_list;
 pub mod block;
+pub mod bot;
+pub mod description_translation;
+pub mod domain_block;
 pub mod follow;
 pub mod hide;
+pub mod launch;
+pub mod mirror;
+pub mod onboarding;
 pub mod pending_follows;
+pub mod post_tag;
 pub mod random;
+pub mod rename;
+pub mod reserve_name;
+pub mod role;
+pub mod settings;
+pub mod suppress_recommendation;
 pub mod transfer;
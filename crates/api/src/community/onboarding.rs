@@ -0,0 +1,101 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  interest_category::{
+    GetOnboardingCommunities,
+    GetOnboardingCommunitiesResponse,
+    OnboardingCategory,
+  },
+  utils::check_private_instance,
+};
+use lemmy_db_schema::{source::interest_category::InterestCategory, ListingType};
+use lemmy_db_views::structs::{LocalUserView, SiteView};
+use lemmy_db_views_actor::{
+  community_view::CommunityQuery,
+  structs::{CommunitySortType, CommunityView},
+};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Number of communities which are suggested per category, if the client doesn't specify a limit.
+const DEFAULT_COMMUNITIES_PER_CATEGORY: i64 = 5;
+const MAX_COMMUNITIES_PER_CATEGORY: i64 = 20;
+const MAX_CATEGORIES: usize = 50;
+
+/// Suggests communities for each of the given interest categories. The communities which admins
+/// picked for a category come first, the rest is filled up with the most active communities
+/// matching the category name.
+#[tracing::instrument(skip(context))]
+pub async fn get_onboarding_communities(
+  data: Json<GetOnboardingCommunities>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<GetOnboardingCommunitiesResponse>> {
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &site_view.local_site)?;
+
+  if data.category_ids.len() > MAX_CATEGORIES {
+    Err(LemmyErrorType::TooManyItems)?
+  }
+  let limit = data
+    .limit
+    .unwrap_or(DEFAULT_COMMUNITIES_PER_CATEGORY)
+    .clamp(1, MAX_COMMUNITIES_PER_CATEGORY);
+  let local_user = local_user_view.as_ref().map(|l| &l.local_user);
+
+  let selected_categories = InterestCategory::list(&mut context.pool())
+    .await?
+    .into_iter()
+    .filter(|c| data.category_ids.contains(&c.id));
+
+  let mut categories = vec![];
+  for category in selected_categories {
+    let mut communities: Vec<CommunityView> = vec![];
+
+    for community_id in InterestCategory::communities(&mut context.pool(), category.id).await? {
+      if communities.len() as i64 >= limit {
+        break;
+      }
+      // Skip picks which were removed or deleted in the meantime
+      if let Ok(community_view) =
+        CommunityView::read(&mut context.pool(), community_id, local_user, false).await
+      {
+        if !community_view.blocked {
+          communities.push(community_view);
+        }
+      }
+    }
+
+    if (communities.len() as i64) < limit {
+      let active = CommunityQuery {
+        listing_type: Some(ListingType::All),
+        sort: Some(CommunitySortType::Active),
+        search_term: Some(category.name.clone()),
+        local_user,
+        show_nsfw: local_user.is_some_and(|l| l.show_nsfw),
+        limit: Some(limit),
+        ..Default::default()
+      }
+      .list(&site_view.site, &mut context.pool())
+      .await?;
+      for community_view in active {
+        if communities.len() as i64 >= limit {
+          break;
+        }
+        if !communities
+          .iter()
+          .any(|c| c.community.id == community_view.community.id)
+        {
+          communities.push(community_view);
+        }
+      }
+    }
+
+    categories.push(OnboardingCategory {
+      category,
+      communities,
+    });
+  }
+
+  Ok(Json(GetOnboardingCommunitiesResponse { categories }))
+}
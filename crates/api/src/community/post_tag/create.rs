@@ -0,0 +1,44 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{CommunityPostTagResponse, CreateCommunityPostTag},
+  context::LemmyContext,
+  utils::{is_mod_or_admin, local_site_to_slur_regex},
+};
+use lemmy_db_schema::{
+  source::{
+    community_post_tag::{CommunityPostTag, CommunityPostTagInsertForm},
+    local_site::LocalSite,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::{slurs::check_slurs, validation::is_valid_post_tag_name},
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_community_post_tag(
+  data: Json<CreateCommunityPostTag>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityPostTagResponse>> {
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    data.community_id,
+  )
+  .await?;
+
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  is_valid_post_tag_name(&data.name)?;
+  check_slurs(&data.name, &local_site_to_slur_regex(&local_site))?;
+
+  let form = CommunityPostTagInsertForm::new(data.community_id, data.name.clone());
+  let tag = CommunityPostTag::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::PostTagAlreadyExists)?;
+
+  Ok(Json(CommunityPostTagResponse { tag }))
+}
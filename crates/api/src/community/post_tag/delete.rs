@@ -0,0 +1,31 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::DeleteCommunityPostTag,
+  context::LemmyContext,
+  utils::is_mod_or_admin,
+  SuccessResponse,
+};
+use lemmy_db_schema::{source::community_post_tag::CommunityPostTag, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_community_post_tag(
+  data: Json<DeleteCommunityPostTag>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let tag = CommunityPostTag::read(&mut context.pool(), data.tag_id).await?;
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    tag.community_id,
+  )
+  .await?;
+
+  // This also removes the tag from all posts
+  CommunityPostTag::delete(&mut context.pool(), data.tag_id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,49 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{CommunityPostTagResponse, EditCommunityPostTag},
+  context::LemmyContext,
+  utils::{is_mod_or_admin, local_site_to_slur_regex},
+};
+use lemmy_db_schema::{
+  source::{
+    community_post_tag::{CommunityPostTag, CommunityPostTagUpdateForm},
+    local_site::LocalSite,
+  },
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::{slurs::check_slurs, validation::is_valid_post_tag_name},
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn edit_community_post_tag(
+  data: Json<EditCommunityPostTag>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityPostTagResponse>> {
+  let orig = CommunityPostTag::read(&mut context.pool(), data.tag_id).await?;
+  is_mod_or_admin(
+    &mut context.pool(),
+    &local_user_view.person,
+    orig.community_id,
+  )
+  .await?;
+
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  is_valid_post_tag_name(&data.name)?;
+  check_slurs(&data.name, &local_site_to_slur_regex(&local_site))?;
+
+  let form = CommunityPostTagUpdateForm {
+    name: Some(data.name.clone()),
+    updated: Some(Some(naive_now())),
+  };
+  let tag = CommunityPostTag::update(&mut context.pool(), data.tag_id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::PostTagAlreadyExists)?;
+
+  Ok(Json(CommunityPostTagResponse { tag }))
+}
@@ -0,0 +1,24 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  community::{ListCommunityPostTags, ListCommunityPostTagsResponse},
+  context::LemmyContext,
+  utils::check_private_instance,
+};
+use lemmy_db_schema::source::{community_post_tag::CommunityPostTag, local_site::LocalSite};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_community_post_tags(
+  data: Query<ListCommunityPostTags>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<ListCommunityPostTagsResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let tags = CommunityPostTag::list_for_community(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListCommunityPostTagsResponse { tags }))
+}
@@ -0,0 +1,77 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  build_response::build_community_response,
+  community::{CommunityResponse, RenameCommunity},
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_name_available, is_admin, local_site_to_slur_regex},
+};
+use lemmy_db_schema::{
+  source::{
+    community::{Community, CommunityUpdateForm},
+    community_name_alias::{CommunityNameAlias, CommunityNameAliasForm},
+    local_site::LocalSite,
+  },
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::{slurs::check_slurs, validation::is_valid_actor_name},
+};
+
+/// Only the name changes, the actor id of the community stays the same so that federation keeps
+/// working. Other instances learn about the new name through `Update/Group`.
+#[tracing::instrument(skip(context))]
+pub async fn rename_community(
+  data: Json<RenameCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityResponse>> {
+  is_admin(&local_user_view)?;
+
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  if !community.local {
+    Err(LemmyErrorType::CouldntUpdateCommunity)?
+  }
+
+  is_valid_actor_name(&data.name, local_site.actor_name_max_length as usize)?;
+  check_slurs(&data.name, &local_site_to_slur_regex(&local_site))?;
+
+  // Renaming a community back to one of its old names removes that alias
+  let alias = CommunityNameAlias::read(&mut context.pool(), &data.name).await?;
+  if alias.and_then(|a| a.community_id) == Some(community.id) {
+    CommunityNameAlias::delete(&mut context.pool(), &data.name).await?;
+  } else {
+    check_community_name_available(&mut context.pool(), &data.name).await?;
+  }
+
+  let community_form = CommunityUpdateForm {
+    name: Some(data.name.clone()),
+    updated: Some(Some(naive_now())),
+    ..Default::default()
+  };
+  let updated_community = Community::update(&mut context.pool(), community.id, &community_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateCommunity)?;
+
+  let alias_form = CommunityNameAliasForm {
+    reason: data.reason.clone(),
+    ..CommunityNameAliasForm::new(
+      community.name,
+      Some(community.id),
+      local_user_view.person.id,
+    )
+  };
+  CommunityNameAlias::create(&mut context.pool(), &alias_form).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::UpdateCommunity(local_user_view.person.clone(), updated_community),
+    &context,
+  )?;
+
+  build_community_response(&context, local_user_view, community.id).await
+}
@@ -0,0 +1,49 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  community::{ListReservedCommunityNamesResponse, ReserveCommunityName},
+  context::LemmyContext,
+  utils::{check_community_name_available, is_admin},
+  SuccessResponse,
+};
+use lemmy_db_schema::source::community_name_alias::{CommunityNameAlias, CommunityNameAliasForm};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn reserve_community_name(
+  data: Json<ReserveCommunityName>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  is_admin(&local_user_view)?;
+
+  if data.reserved {
+    check_community_name_available(&mut context.pool(), &data.name).await?;
+    let form = CommunityNameAliasForm {
+      reason: data.reason.clone(),
+      ..CommunityNameAliasForm::new(data.name.clone(), None, local_user_view.person.id)
+    };
+    CommunityNameAlias::create(&mut context.pool(), &form).await?;
+  } else {
+    // Old names of renamed communities can't be released, they still resolve to the community
+    let alias = CommunityNameAlias::read(&mut context.pool(), &data.name)
+      .await?
+      .filter(|a| a.community_id.is_none())
+      .ok_or(LemmyErrorType::NotFound)?;
+    CommunityNameAlias::delete(&mut context.pool(), &alias.name).await?;
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+#[tracing::instrument(skip(context))]
+pub async fn list_reserved_community_names(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListReservedCommunityNamesResponse>> {
+  is_admin(&local_user_view)?;
+
+  let reserved_names = CommunityNameAlias::list_reserved(&mut context.pool()).await?;
+
+  Ok(Json(ListReservedCommunityNamesResponse { reserved_names }))
+}
@@ -0,0 +1,44 @@
+use super::check_community_owner;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{EditCommunityRolePermission, ListCommunityRolePermissionsResponse},
+  context::LemmyContext,
+  utils::check_user_valid,
+};
+use lemmy_db_schema::{
+  source::community_role::{CommunityRolePermission, CommunityRolePermissionForm},
+  utils::naive_now,
+  CommunityRole,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn edit_community_role_permission(
+  data: Json<EditCommunityRolePermission>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListCommunityRolePermissionsResponse>> {
+  check_user_valid(&local_user_view.person)?;
+  check_community_owner(&local_user_view, data.community_id, &mut context.pool()).await?;
+
+  // The owner can always do everything
+  if data.role == CommunityRole::Owner {
+    Err(LemmyErrorType::CantAssignCommunityRole)?
+  }
+
+  let form = CommunityRolePermissionForm {
+    community_id: data.community_id,
+    role: data.role,
+    can_feature_posts: data.can_feature_posts,
+    can_remove_comments: data.can_remove_comments,
+    can_edit_sidebar: data.can_edit_sidebar,
+    updated: naive_now(),
+    can_view_votes: data.can_view_votes,
+  };
+  CommunityRolePermission::upsert(&mut context.pool(), &form).await?;
+
+  let permissions = CommunityRolePermission::list(&mut context.pool(), data.community_id).await?;
+  Ok(Json(ListCommunityRolePermissionsResponse { permissions }))
+}
@@ -0,0 +1,18 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  community::{ListCommunityRolePermissions, ListCommunityRolePermissionsResponse},
+  context::LemmyContext,
+};
+use lemmy_db_schema::source::community_role::CommunityRolePermission;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_community_role_permissions(
+  data: Query<ListCommunityRolePermissions>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<ListCommunityRolePermissionsResponse>> {
+  let permissions = CommunityRolePermission::list(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListCommunityRolePermissionsResponse { permissions }))
+}
@@ -0,0 +1,20 @@
+use lemmy_db_schema::{newtypes::CommunityId, utils::DbPool, CommunityRole};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub mod edit_permission;
+pub mod list_permissions;
+pub mod set;
+
+/// Roles are managed by the owner of the community, or by admins.
+async fn check_community_owner(
+  local_user_view: &LocalUserView,
+  community_id: CommunityId,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  let role = CommunityRole::read(pool, community_id, local_user_view.person.id).await?;
+  if role != CommunityRole::Owner && !local_user_view.local_user.admin {
+    Err(LemmyErrorType::NotTopMod)?
+  }
+  Ok(())
+}
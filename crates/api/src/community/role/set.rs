@@ -0,0 +1,40 @@
+use super::check_community_owner;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::SetCommunityRole,
+  context::LemmyContext,
+  utils::check_user_valid,
+  SuccessResponse,
+};
+use lemmy_db_schema::CommunityRole;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn set_community_role(
+  data: Json<SetCommunityRole>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  check_user_valid(&local_user_view.person)?;
+  check_community_owner(&local_user_view, data.community_id, &mut context.pool()).await?;
+
+  // Owner and moderators come from the moderator list, and can't be changed here
+  let current_role =
+    CommunityRole::read(&mut context.pool(), data.community_id, data.person_id).await?;
+  let is_mod_role = |role| matches!(role, CommunityRole::Owner | CommunityRole::Moderator);
+  if is_mod_role(data.role) || is_mod_role(current_role) {
+    Err(LemmyErrorType::CantAssignCommunityRole)?
+  }
+
+  CommunityRole::set(
+    &mut context.pool(),
+    data.community_id,
+    data.person_id,
+    data.role,
+  )
+  .await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,35 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{CommunitySettingsResponse, SaveCommunitySettings},
+  context::LemmyContext,
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    local_user_community_settings::{LocalUserCommunitySettings, LocalUserCommunitySettingsForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn save_community_settings(
+  data: Json<SaveCommunitySettings>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunitySettingsResponse>> {
+  // Make sure the community exists
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+
+  let form = LocalUserCommunitySettingsForm {
+    post_sort_type: data.post_sort_type,
+    post_listing_mode: data.post_listing_mode,
+    show_bot_accounts: data.show_bot_accounts,
+    ..LocalUserCommunitySettingsForm::new(local_user_view.local_user.id, community.id)
+  };
+  let settings = LocalUserCommunitySettings::save(&mut context.pool(), &form).await?;
+
+  Ok(Json(CommunitySettingsResponse { settings }))
+}
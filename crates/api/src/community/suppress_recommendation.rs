@@ -0,0 +1,47 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{CommunityResponse, SuppressCommunityRecommendation},
+  context::LemmyContext,
+};
+use lemmy_db_schema::source::{
+  actor_language::CommunityLanguage,
+  community::CommunitySuppressedRecommendation,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::structs::CommunityView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn suppress_community_recommendation(
+  data: Json<SuppressCommunityRecommendation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityResponse>> {
+  let community_id = data.community_id;
+  let person_id = local_user_view.person.id;
+
+  if data.suppress {
+    CommunitySuppressedRecommendation::suppress(&mut context.pool(), community_id, person_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntSuppressCommunityRecommendation)?;
+  } else {
+    CommunitySuppressedRecommendation::unsuppress(&mut context.pool(), community_id, person_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntSuppressCommunityRecommendation)?;
+  }
+
+  let community_view = CommunityView::read(
+    &mut context.pool(),
+    community_id,
+    Some(&local_user_view.local_user),
+    false,
+  )
+  .await?;
+  let discussion_languages = CommunityLanguage::read(&mut context.pool(), community_id).await?;
+
+  Ok(Json(CommunityResponse {
+    community_view,
+    discussion_languages,
+  }))
+}
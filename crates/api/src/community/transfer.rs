@@ -93,5 +93,7 @@ pub async fn transfer_community(
     site: None,
     moderators,
     discussion_languages: vec![],
+    description_translations: vec![],
+    my_settings: None,
   }))
 }
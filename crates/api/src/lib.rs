@@ -8,7 +8,7 @@ use lemmy_api_common::{
   community::BanFromCommunity,
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_expire_time, check_user_valid, local_site_to_slur_regex, AUTH_COOKIE_NAME},
+  utils::{check_expire_time, check_local_user_valid, local_site_to_slur_regex, AUTH_COOKIE_NAME},
 };
 use lemmy_db_schema::{
   source::{
@@ -188,6 +188,8 @@ pub(crate) async fn ban_nonlocal_user_from_local_communities(
         community_id,
         person_id: target.id,
         expires: Some(expires_dt),
+        reason: Some(reason.clone()),
+        appeal: Some(None),
       };
 
       if ban {
@@ -228,6 +230,7 @@ pub(crate) async fn ban_nonlocal_user_from_local_communities(
         reason: reason.clone(),
         remove_or_restore_data: *remove_or_restore_data,
         expires: *expires,
+        appeal: None,
       };
 
       ActivityChannel::submit_activity(
@@ -254,7 +257,7 @@ pub async fn local_user_view_from_jwt(
     .await
     .with_lemmy_type(LemmyErrorType::NotLoggedIn)?;
   let local_user_view = LocalUserView::read(&mut context.pool(), local_user_id).await?;
-  check_user_valid(&local_user_view.person)?;
+  check_local_user_valid(&local_user_view)?;
 
   Ok(local_user_view)
 }
@@ -0,0 +1,101 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{AssignReport, AssignReportResponse},
+  utils::{check_community_mod_action, is_admin},
+};
+use lemmy_db_schema::{
+  newtypes::PersonId,
+  source::{
+    comment_report::CommentReport,
+    post_report::PostReport,
+    private_message_report::PrivateMessageReport,
+  },
+  traits::Reportable,
+};
+use lemmy_db_views::structs::{
+  CommentReportView,
+  LocalUserView,
+  PostReportView,
+  PrivateMessageReportView,
+  ReportCombinedView,
+};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Claims a report for the user, or gives up the claim, so that moderators don't work on the
+/// same report twice
+#[tracing::instrument(skip(context))]
+pub async fn assign_report(
+  data: Json<AssignReport>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<AssignReportResponse>> {
+  let person_id = local_user_view.person.id;
+  let assignee_id = data.assign.then_some(person_id);
+
+  let report = match (
+    data.comment_report_id,
+    data.post_report_id,
+    data.private_message_report_id,
+  ) {
+    (Some(report_id), None, None) => {
+      let report = CommentReportView::read(&mut context.pool(), report_id, person_id).await?;
+      check_community_mod_action(
+        &local_user_view.person,
+        &report.community,
+        true,
+        &mut context.pool(),
+      )
+      .await?;
+      check_assignment(report.comment_report.assigned_to, &local_user_view)?;
+      CommentReport::assign(&mut context.pool(), report_id, assignee_id).await?;
+      ReportCombinedView::Comment(
+        CommentReportView::read(&mut context.pool(), report_id, person_id).await?,
+      )
+    }
+    (None, Some(report_id), None) => {
+      let report = PostReportView::read(&mut context.pool(), report_id, person_id).await?;
+      check_community_mod_action(
+        &local_user_view.person,
+        &report.community,
+        true,
+        &mut context.pool(),
+      )
+      .await?;
+      check_assignment(report.post_report.assigned_to, &local_user_view)?;
+      PostReport::assign(&mut context.pool(), report_id, assignee_id).await?;
+      ReportCombinedView::Post(
+        PostReportView::read(&mut context.pool(), report_id, person_id).await?,
+      )
+    }
+    (None, None, Some(report_id)) => {
+      // Private message reports are only handled by admins
+      is_admin(&local_user_view)?;
+      let report = PrivateMessageReportView::read(&mut context.pool(), report_id).await?;
+      check_assignment(report.private_message_report.assigned_to, &local_user_view)?;
+      PrivateMessageReport::assign(&mut context.pool(), report_id, assignee_id).await?;
+      ReportCombinedView::PrivateMessage(
+        PrivateMessageReportView::read(&mut context.pool(), report_id).await?,
+      )
+    }
+    _ => Err(LemmyErrorType::NoIdGiven)?,
+  };
+
+  Ok(Json(AssignReportResponse { report }))
+}
+
+/// Only reports which are unclaimed or claimed by the user can be changed. Admins can take over
+/// the reports of other moderators.
+fn check_assignment(
+  assigned_to: Option<PersonId>,
+  local_user_view: &LocalUserView,
+) -> LemmyResult<()> {
+  match assigned_to {
+    Some(assignee_id)
+      if assignee_id != local_user_view.person.id && !local_user_view.local_user.admin =>
+    {
+      Err(LemmyErrorType::ReportAlreadyAssigned)?
+    }
+    _ => Ok(()),
+  }
+}
@@ -0,0 +1,50 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{BlockKeyword, BlockKeywordResponse},
+};
+use lemmy_db_schema::source::local_user_keyword_block::{
+  LocalUserKeywordBlock,
+  LocalUserKeywordBlockForm,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+const MAX_KEYWORD_BLOCKS: usize = 50;
+const MAX_KEYWORD_LENGTH: usize = 100;
+
+#[tracing::instrument(skip(context))]
+pub async fn block_keyword(
+  data: Json<BlockKeyword>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BlockKeywordResponse>> {
+  let local_user_id = local_user_view.local_user.id;
+  let keyword = data.keyword.trim();
+
+  if data.block {
+    if keyword.is_empty() || keyword.chars().count() > MAX_KEYWORD_LENGTH {
+      Err(LemmyErrorType::InvalidKeywordBlock)?
+    }
+    // The regex is evaluated by the database, so it has to be checked there
+    let is_regex = data.is_regex.unwrap_or_default();
+    if is_regex && !LocalUserKeywordBlock::is_valid_regex(&mut context.pool(), keyword).await? {
+      Err(LemmyErrorType::InvalidRegex)?
+    }
+
+    let existing =
+      LocalUserKeywordBlock::for_local_user(&mut context.pool(), local_user_id).await?;
+    if existing.len() >= MAX_KEYWORD_BLOCKS && !existing.iter().any(|b| b.keyword == keyword) {
+      Err(LemmyErrorType::TooManyKeywordBlocks)?
+    }
+
+    let form = LocalUserKeywordBlockForm::new(local_user_id, keyword.to_string(), is_regex);
+    LocalUserKeywordBlock::block(&mut context.pool(), &form).await?;
+  } else {
+    LocalUserKeywordBlock::unblock(&mut context.pool(), local_user_id, keyword).await?;
+  }
+
+  let keyword_blocks =
+    LocalUserKeywordBlock::for_local_user(&mut context.pool(), local_user_id).await?;
+  Ok(Json(BlockKeywordResponse { keyword_blocks }))
+}
@@ -0,0 +1,58 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{FollowPerson, FollowPersonResponse},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_user_valid,
+};
+use lemmy_db_schema::{
+  source::person::{Person, PersonFollower, PersonFollowerForm},
+  traits::{Crud, Followable},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::structs::PersonView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn follow_person(
+  data: Json<FollowPerson>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<FollowPersonResponse>> {
+  check_user_valid(&local_user_view.person)?;
+  let target_id = data.person_id;
+  let person_id = local_user_view.person.id;
+
+  if target_id == person_id {
+    Err(LemmyErrorType::CantFollowYourself)?
+  }
+
+  let target = Person::read(&mut context.pool(), target_id).await?;
+  let form = PersonFollowerForm {
+    person_id: target_id,
+    follower_id: person_id,
+    // Remote follows are pending until the other instance accepts them
+    pending: !target.local,
+  };
+
+  if data.follow {
+    PersonFollower::follow(&mut context.pool(), &form).await?;
+  } else {
+    PersonFollower::unfollow(&mut context.pool(), &form).await?;
+  }
+
+  // Send the federated follow
+  if !target.local {
+    ActivityChannel::submit_activity(
+      SendActivityData::FollowPerson(target, local_user_view.person.clone(), data.follow),
+      &context,
+    )?;
+  }
+
+  let person_view = PersonView::read(&mut context.pool(), target_id).await?;
+  Ok(Json(FollowPersonResponse {
+    person_view,
+    followed: data.follow,
+  }))
+}
@@ -0,0 +1,197 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{FreezeAccount, FreezeAccountResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  source::{
+    local_user::{LocalUser, LocalUserUpdateForm},
+    login_token::LoginToken,
+    moderator::{AdminFreezeAccount, AdminFreezeAccountForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn freeze_account(
+  data: Json<FreezeAccount>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<FreezeAccountResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  // Also make sure you're a higher admin than the target
+  LocalUser::is_higher_admin_check(
+    &mut context.pool(),
+    local_user_view.person.id,
+    vec![data.person_id],
+  )
+  .await?;
+
+  if let Some(reason) = &data.reason {
+    is_valid_body_field(reason, false)?;
+  }
+
+  // Only local accounts can be frozen
+  let target = LocalUserView::read_person(&mut context.pool(), data.person_id).await?;
+
+  LocalUser::update(
+    &mut context.pool(),
+    target.local_user.id,
+    &LocalUserUpdateForm {
+      frozen: Some(data.freeze),
+      ..Default::default()
+    },
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntUpdateUser)?;
+
+  // Kick out every existing session, including the attacker's
+  if data.freeze {
+    LoginToken::invalidate_all(&mut context.pool(), target.local_user.id).await?;
+  }
+
+  // Mod tables
+  let form = AdminFreezeAccountForm {
+    admin_person_id: local_user_view.person.id,
+    other_person_id: target.person.id,
+    reason: data.reason.clone(),
+    frozen: data.freeze,
+  };
+  AdminFreezeAccount::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(FreezeAccountResponse {
+    person_id: target.person.id,
+    frozen: data.freeze,
+  }))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_api_common::utils::check_local_user_valid;
+  use lemmy_db_schema::source::{
+    instance::Instance,
+    local_user::LocalUserInsertForm,
+    login_token::LoginTokenCreateForm,
+    person::{Person, PersonInsertForm},
+  };
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_freeze_account() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let admin_person = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "freeze_admin"),
+    )
+    .await?;
+    LocalUser::create(
+      pool,
+      &LocalUserInsertForm::test_form_admin(admin_person.id),
+      vec![],
+    )
+    .await?;
+    let admin_view = LocalUserView::read_person(pool, admin_person.id).await?;
+
+    let person = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "compromised"),
+    )
+    .await?;
+    let local_user =
+      LocalUser::create(pool, &LocalUserInsertForm::test_form(person.id), vec![]).await?;
+    let person_view = LocalUserView::read_person(pool, person.id).await?;
+    let token = "freeze_test_token".to_string();
+    LoginToken::create(
+      pool,
+      LoginTokenCreateForm {
+        token: token.clone().into(),
+        user_id: local_user.id,
+        ip: None,
+        user_agent: None,
+        linked_from_user_id: None,
+      },
+    )
+    .await?;
+
+    // Only admins can freeze accounts
+    let freeze = FreezeAccount {
+      person_id: admin_person.id,
+      freeze: true,
+      reason: None,
+    };
+    let not_admin = freeze_account(
+      Json(freeze),
+      context.reset_request_count(),
+      person_view.clone(),
+    )
+    .await;
+    assert!(not_admin.is_err());
+
+    // Freezing logs the user out and blocks further actions
+    let freeze = FreezeAccount {
+      person_id: person.id,
+      freeze: true,
+      reason: Some("stolen password".to_string()),
+    };
+    let res = freeze_account(
+      Json(freeze),
+      context.reset_request_count(),
+      admin_view.clone(),
+    )
+    .await?;
+    assert!(res.frozen);
+    assert!(LoginToken::validate(pool, local_user.id, &token)
+      .await
+      .is_err());
+    let frozen_view = LocalUserView::read_person(pool, person.id).await?;
+    assert!(frozen_view.local_user.frozen);
+    assert_eq!(
+      Some(LemmyErrorType::AccountFrozen),
+      check_local_user_valid(&frozen_view)
+        .err()
+        .map(|e| e.error_type)
+    );
+
+    // The account isn't banned, so the profile stays normal
+    assert!(!frozen_view.person.banned);
+
+    // Unfreezing allows the user to act again
+    let unfreeze = FreezeAccount {
+      person_id: person.id,
+      freeze: false,
+      reason: None,
+    };
+    let res = freeze_account(Json(unfreeze), context.reset_request_count(), admin_view).await?;
+    assert!(!res.frozen);
+    let unfrozen_view = LocalUserView::read_person(pool, person.id).await?;
+    assert!(check_local_user_valid(&unfrozen_view).is_ok());
+
+    // Both actions are in the audit log
+    let log = AdminFreezeAccount::list_for_person(pool, person.id).await?;
+    assert_eq!(
+      vec![false, true],
+      log.iter().map(|l| l.frozen).collect::<Vec<_>>()
+    );
+    assert_eq!(Some("stolen password".to_string()), log[1].reason.clone());
+    assert!(log.iter().all(|l| l.admin_person_id == admin_person.id));
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,166 @@
+use activitypub_federation::http_signatures::generate_actor_keypair;
+use ldap3::{dn_escape, drive, LdapConnAsync, LdapError, Scope, SearchEntry};
+use lemmy_api_common::{
+  context::LemmyContext,
+  utils::{generate_inbox_url, generate_local_apub_endpoint, EndpointType},
+};
+use lemmy_db_schema::{
+  source::{
+    local_user::{LocalUser, LocalUserInsertForm},
+    person::{Person, PersonInsertForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::{LocalUserView, SiteView};
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_actor_name,
+};
+
+/// Checks the credentials against the LDAP directory configured in the site settings. If the bind
+/// succeeds for a name which doesn't have a local user yet, one is created. Existing accounts can
+/// only log in like this if they were created through LDAP login.
+pub(crate) async fn check_ldap_credentials(
+  username_or_email: &str,
+  password: &str,
+  local_user_view: Option<LocalUserView>,
+  site_view: &SiteView,
+  context: &LemmyContext,
+) -> LemmyResult<LocalUserView> {
+  let local_site = &site_view.local_site;
+  let (Some(url), Some(user_dn_template)) =
+    (&local_site.ldap_url, &local_site.ldap_user_dn_template)
+  else {
+    Err(LemmyErrorType::IncorrectLogin)?
+  };
+  // A bind without password is an anonymous bind, which usually succeeds
+  if password.is_empty() {
+    Err(LemmyErrorType::IncorrectLogin)?
+  }
+  if let Some(local_user_view) = &local_user_view {
+    check_ldap_login_allowed(&local_user_view.local_user)?;
+  }
+
+  let username = local_user_view
+    .as_ref()
+    .map_or(username_or_email, |view| view.person.name.as_str());
+  let user_dn = user_dn_template.replace("{username}", &dn_escape(username));
+  let email = ldap_bind(url, &user_dn, password).await.map_err(|e| {
+    tracing::debug!("LDAP login for {user_dn} failed: {e}");
+    LemmyErrorType::IncorrectLogin
+  })?;
+
+  match local_user_view {
+    Some(local_user_view) => Ok(local_user_view),
+    None => create_ldap_user(username, email, site_view, context).await,
+  }
+}
+
+/// Accounts with a local password must not be taken over by a directory entry of the same name,
+/// and admins only log in through LDAP if they opted in.
+fn check_ldap_login_allowed(local_user: &LocalUser) -> LemmyResult<()> {
+  if !local_user.ldap_login || (local_user.admin && !local_user.ldap_admin_login) {
+    Err(LemmyErrorType::IncorrectLogin)?
+  }
+  Ok(())
+}
+
+/// Binds as the given user, and returns their email address if the directory has one.
+async fn ldap_bind(url: &str, user_dn: &str, password: &str) -> Result<Option<String>, LdapError> {
+  let (conn, mut ldap) = LdapConnAsync::new(url).await?;
+  drive!(conn);
+  ldap.simple_bind(user_dn, password).await?.success()?;
+
+  let (entries, _) = ldap
+    .search(user_dn, Scope::Base, "(objectClass=*)", vec!["mail"])
+    .await?
+    .success()?;
+  let email = entries
+    .into_iter()
+    .next()
+    .and_then(|entry| SearchEntry::construct(entry).attrs.remove("mail"))
+    .and_then(|mails| mails.into_iter().next());
+
+  ldap.unbind().await?;
+  Ok(email)
+}
+
+/// Creates the local user for someone logging in through LDAP for the first time. The directory
+/// is trusted, so neither email verification nor a registration application is needed.
+async fn create_ldap_user(
+  username: &str,
+  email: Option<String>,
+  site_view: &SiteView,
+  context: &LemmyContext,
+) -> LemmyResult<LocalUserView> {
+  let local_site = &site_view.local_site;
+  is_valid_actor_name(username, local_site.actor_name_max_length as usize)?;
+  Person::check_username_taken(&mut context.pool(), username).await?;
+  let email = email.map(|e| e.to_lowercase());
+  if let Some(email) = &email {
+    LocalUser::check_is_email_taken(&mut context.pool(), email).await?;
+  }
+
+  let actor_keypair = generate_actor_keypair()?;
+  let actor_id = generate_local_apub_endpoint(
+    EndpointType::Person,
+    username,
+    &context.settings().get_protocol_and_hostname(),
+  )?;
+  let person_form = PersonInsertForm {
+    actor_id: Some(actor_id),
+    inbox_url: Some(generate_inbox_url()?),
+    private_key: Some(actor_keypair.private_key),
+    ..PersonInsertForm::new(
+      username.to_string(),
+      actor_keypair.public_key,
+      site_view.site.instance_id,
+    )
+  };
+  let person = Person::create(&mut context.pool(), &person_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::UserAlreadyExists)?;
+
+  let local_user_form = LocalUserInsertForm {
+    email,
+    email_verified: Some(true),
+    accepted_application: Some(true),
+    ldap_login: Some(true),
+    ..LocalUserInsertForm::new(person.id, None)
+  };
+  let local_user = LocalUser::create(&mut context.pool(), &local_user_form, vec![]).await?;
+
+  Ok(LocalUserView::read(&mut context.pool(), local_user.id).await?)
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::check_ldap_login_allowed;
+  use lemmy_db_schema::source::local_user::LocalUser;
+
+  #[test]
+  fn test_ldap_login_allowed() {
+    // A matching directory entry doesn't give access to an account with a local password
+    let password_user = LocalUser::default();
+    assert!(check_ldap_login_allowed(&password_user).is_err());
+
+    let ldap_user = LocalUser {
+      ldap_login: true,
+      ..Default::default()
+    };
+    assert!(check_ldap_login_allowed(&ldap_user).is_ok());
+
+    // Admins need to opt in
+    let ldap_admin = LocalUser {
+      admin: true,
+      ..ldap_user
+    };
+    assert!(check_ldap_login_allowed(&ldap_admin).is_err());
+    let opted_in_admin = LocalUser {
+      ldap_admin_login: true,
+      ..ldap_admin
+    };
+    assert!(check_ldap_login_allowed(&opted_in_admin).is_ok());
+  }
+}
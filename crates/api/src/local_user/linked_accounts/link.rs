@@ -0,0 +1,50 @@
+use crate::local_user::login::check_login_credentials;
+use actix_web::{
+  web::{Data, Json},
+  HttpRequest,
+};
+use lemmy_api_common::{
+  claims::Claims,
+  context::LemmyContext,
+  person::{LinkAccount, LinkAccountResponse},
+};
+use lemmy_db_schema::source::local_user_link::LocalUserLink;
+use lemmy_db_views::structs::{LocalUserView, SiteView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Links the account with the given credentials to the logged in account, and returns a login
+/// token for it.
+#[tracing::instrument(skip(context))]
+pub async fn link_account(
+  data: Json<LinkAccount>,
+  req: HttpRequest,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<LinkAccountResponse>> {
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+  let linked_user_view = check_login_credentials(
+    &data.username_or_email,
+    &data.password,
+    &data.totp_2fa_token,
+    &site_view,
+    &context,
+  )
+  .await?;
+
+  let local_user_id = local_user_view.local_user.id;
+  let linked_local_user_id = linked_user_view.local_user.id;
+  if local_user_id == linked_local_user_id {
+    Err(LemmyErrorType::CantLinkAccountToItself)?
+  }
+
+  LocalUserLink::link(&mut context.pool(), local_user_id, linked_local_user_id).await?;
+
+  let jwt =
+    Claims::generate_for_linked_account(linked_local_user_id, local_user_id, req, &context).await?;
+  let linked_accounts = LocalUserLink::list_persons(&mut context.pool(), local_user_id).await?;
+
+  Ok(Json(LinkAccountResponse {
+    jwt,
+    linked_accounts,
+  }))
+}
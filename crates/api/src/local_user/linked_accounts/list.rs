@@ -0,0 +1,16 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::ListLinkedAccountsResponse};
+use lemmy_db_schema::source::local_user_link::LocalUserLink;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_linked_accounts(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListLinkedAccountsResponse>> {
+  let linked_accounts =
+    LocalUserLink::list_persons(&mut context.pool(), local_user_view.local_user.id).await?;
+
+  Ok(Json(ListLinkedAccountsResponse { linked_accounts }))
+}
@@ -0,0 +1,4 @@
+pub mod link;
+pub mod list;
+pub mod switch;
+pub mod unlink;
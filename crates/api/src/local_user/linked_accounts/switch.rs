@@ -0,0 +1,38 @@
+use actix_web::{
+  web::{Data, Json},
+  HttpRequest,
+};
+use lemmy_api_common::{
+  claims::Claims,
+  context::LemmyContext,
+  person::{LoginResponse, SwitchAccount},
+  utils::check_local_user_valid,
+};
+use lemmy_db_schema::source::local_user_link::LocalUserLink;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+/// Exchanges the token of the logged in account for a token of a linked account.
+#[tracing::instrument(skip(context))]
+pub async fn switch_account(
+  data: Json<SwitchAccount>,
+  req: HttpRequest,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<LoginResponse>> {
+  let target = LocalUserView::read_person(&mut context.pool(), data.person_id).await?;
+  let local_user_id = local_user_view.local_user.id;
+  LocalUserLink::check_linked(&mut context.pool(), local_user_id, target.local_user.id).await?;
+
+  // The linked account may have been banned, frozen or deleted since it was linked
+  check_local_user_valid(&target)?;
+
+  let jwt =
+    Claims::generate_for_linked_account(target.local_user.id, local_user_id, req, &context).await?;
+
+  Ok(Json(LoginResponse {
+    jwt: Some(jwt),
+    verify_email_sent: false,
+    registration_created: false,
+  }))
+}
@@ -0,0 +1,22 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::UnlinkAccount, SuccessResponse};
+use lemmy_db_schema::source::local_user_link::LocalUserLink;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn unlink_account(
+  data: Json<UnlinkAccount>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let target = LocalUserView::read_person(&mut context.pool(), data.person_id).await?;
+  LocalUserLink::unlink(
+    &mut context.pool(),
+    local_user_view.local_user.id,
+    target.local_user.id,
+  )
+  .await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
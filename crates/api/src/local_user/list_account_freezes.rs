@@ -0,0 +1,22 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ListAccountFreezes, ListAccountFreezesResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::source::moderator::AdminFreezeAccount;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_account_freezes(
+  data: Query<ListAccountFreezes>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListAccountFreezesResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let freezes = AdminFreezeAccount::list_for_person(&mut context.pool(), data.person_id).await?;
+
+  Ok(Json(ListAccountFreezesResponse { freezes }))
+}
@@ -0,0 +1,38 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ListReadPosts, ListReadPostsResponse},
+};
+use lemmy_db_schema::{ListingType, PostSortType};
+use lemmy_db_views::{
+  post_view::PostQuery,
+  structs::{LocalUserView, SiteView},
+};
+use lemmy_utils::error::LemmyResult;
+
+/// Lists the read history of the user. Posts can be taken out of it by marking them as unread.
+#[tracing::instrument(skip(context))]
+pub async fn list_read_posts(
+  data: Query<ListReadPosts>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListReadPostsResponse>> {
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+
+  let posts = PostQuery {
+    listing_type: Some(ListingType::All),
+    sort: Some(PostSortType::New),
+    local_user: Some(&local_user_view.local_user),
+    read_only: Some(true),
+    show_hidden: Some(true),
+    show_read: Some(true),
+    page: data.page,
+    limit: data.limit,
+    ..Default::default()
+  }
+  .list(&site_view.site, &mut context.pool())
+  .await?;
+
+  Ok(Json(ListReadPostsResponse { posts }))
+}
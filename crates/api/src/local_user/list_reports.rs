@@ -0,0 +1,68 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ListReports, ListReportsResponse},
+  utils::check_community_mod_of_any_or_admin_action,
+};
+use lemmy_db_views::{
+  report_combined_view::{ReportCombinedPaginationCursorData, ReportCombinedQuery},
+  structs::{LocalUserView, PersonModNoteView, ReportCombinedView},
+};
+use lemmy_utils::error::LemmyResult;
+
+/// Lists post, comment and private message reports together, for a community if an id is
+/// supplied or for all communities a user moderates
+#[tracing::instrument(skip(context))]
+pub async fn list_reports(
+  data: Query<ListReports>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListReportsResponse>> {
+  let community_id = data.community_id;
+
+  check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+
+  let page_after = if let Some(page_cursor) = &data.page_cursor {
+    Some(ReportCombinedPaginationCursorData::read(page_cursor, &mut context.pool()).await?)
+  } else {
+    None
+  };
+
+  let reports = ReportCombinedQuery {
+    community_id,
+    unresolved_only: data.unresolved_only.unwrap_or_default(),
+    page_after,
+    limit: data.limit,
+  }
+  .list(&mut context.pool(), &local_user_view)
+  .await?;
+
+  let unresolved_count = ReportCombinedView::get_report_count(
+    &mut context.pool(),
+    local_user_view.person.id,
+    local_user_view.local_user.admin,
+    community_id,
+  )
+  .await?;
+
+  let next_page = reports.last().map(ReportCombinedView::to_cursor);
+
+  let creator_ids: Vec<_> = reports
+    .iter()
+    .map(|r| match r {
+      ReportCombinedView::Post(v) => v.post_creator.id,
+      ReportCombinedView::Comment(v) => v.comment_creator.id,
+      ReportCombinedView::PrivateMessage(v) => v.private_message_creator.id,
+    })
+    .collect();
+  let mod_notes =
+    PersonModNoteView::list_for_persons(&mut context.pool(), &creator_ids, &local_user_view)
+      .await?;
+
+  Ok(Json(ListReportsResponse {
+    reports,
+    unresolved_count,
+    mod_notes,
+    next_page,
+  }))
+}
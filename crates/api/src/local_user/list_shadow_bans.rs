@@ -0,0 +1,23 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ListShadowBans, ListShadowBansResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::source::moderator::AdminShadowBanPerson;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+pub async fn list_shadow_bans(
+  data: Query<ListShadowBans>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListShadowBansResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let shadow_bans =
+    AdminShadowBanPerson::list_for_person(&mut context.pool(), data.person_id).await?;
+
+  Ok(Json(ListShadowBansResponse { shadow_bans }))
+}
@@ -1,4 +1,4 @@
-use crate::check_totp_2fa_valid;
+use crate::{check_totp_2fa_valid, local_user::ldap::check_ldap_credentials};
 use actix_web::{
   web::{Data, Json},
   HttpRequest,
@@ -8,10 +8,10 @@ use lemmy_api_common::{
   claims::Claims,
   context::LemmyContext,
   person::{Login, LoginResponse},
-  utils::{check_email_verified, check_registration_application, check_user_valid},
+  utils::{check_email_verified, check_local_user_valid, check_registration_application},
 };
 use lemmy_db_views::structs::{LocalUserView, SiteView};
-use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use lemmy_utils::error::LemmyResult;
 
 #[tracing::instrument(skip(context))]
 pub async fn login(
@@ -20,42 +20,78 @@ pub async fn login(
   context: Data<LemmyContext>,
 ) -> LemmyResult<Json<LoginResponse>> {
   let site_view = SiteView::read_local(&mut context.pool()).await?;
+  let local_user_view = check_login_credentials(
+    &data.username_or_email,
+    &data.password,
+    &data.totp_2fa_token,
+    &site_view,
+    &context,
+  )
+  .await?;
 
+  let jwt = Claims::generate(local_user_view.local_user.id, req, &context).await?;
+
+  Ok(Json(LoginResponse {
+    jwt: Some(jwt.clone()),
+    verify_email_sent: false,
+    registration_created: false,
+  }))
+}
+
+/// Checks the credentials of a local user, as well as whether they are allowed to log in.
+pub(crate) async fn check_login_credentials(
+  username_or_email: &str,
+  password: &str,
+  totp_2fa_token: &Option<String>,
+  site_view: &SiteView,
+  context: &LemmyContext,
+) -> LemmyResult<LocalUserView> {
   // Fetch that username / email
-  let username_or_email = data.username_or_email.clone();
   let local_user_view =
-    LocalUserView::find_by_email_or_name(&mut context.pool(), &username_or_email).await?;
+    LocalUserView::find_by_email_or_name(&mut context.pool(), username_or_email).await;
 
   // Verify the password
   let valid: bool = local_user_view
-    .local_user
-    .password_encrypted
     .as_ref()
-    .and_then(|password_encrypted| verify(&data.password, password_encrypted).ok())
+    .ok()
+    .and_then(|view| view.local_user.password_encrypted.as_ref())
+    .and_then(|password_encrypted| verify(password, password_encrypted).ok())
     .unwrap_or(false);
-  if !valid {
-    Err(LemmyErrorType::IncorrectLogin)?
-  }
-  check_user_valid(&local_user_view.person)?;
-  check_email_verified(&local_user_view, &site_view)?;
 
-  check_registration_application(&local_user_view, &site_view.local_site, &mut context.pool())
-    .await?;
+  // Otherwise fall back to LDAP, if the site has it configured
+  let local_user_view = match local_user_view {
+    Ok(local_user_view) if valid => local_user_view,
+    local_user_view => {
+      check_ldap_credentials(
+        username_or_email,
+        password,
+        local_user_view.ok(),
+        site_view,
+        context,
+      )
+      .await?
+    }
+  };
+  check_login_allowed(&local_user_view, site_view, context).await?;
 
   // Check the totp if enabled
   if local_user_view.local_user.totp_2fa_enabled {
     check_totp_2fa_valid(
       &local_user_view,
-      &data.totp_2fa_token,
+      totp_2fa_token,
       &context.settings().hostname,
     )?;
   }
+  Ok(local_user_view)
+}
 
-  let jwt = Claims::generate(local_user_view.local_user.id, req, &context).await?;
-
-  Ok(Json(LoginResponse {
-    jwt: Some(jwt.clone()),
-    verify_email_sent: false,
-    registration_created: false,
-  }))
+/// Checks whether a user whose credentials are valid may log in.
+pub(crate) async fn check_login_allowed(
+  local_user_view: &LocalUserView,
+  site_view: &SiteView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  check_local_user_valid(local_user_view)?;
+  check_email_verified(local_user_view, site_view)?;
+  check_registration_application(local_user_view, &site_view.local_site, &mut context.pool()).await
 }
@@ -1,19 +1,36 @@
 pub mod add_admin;
+pub mod assign_report;
 pub mod ban_person;
 pub mod block;
+pub mod block_keyword;
 pub mod change_password;
 pub mod change_password_after_reset;
+pub mod follow;
+pub mod freeze_account;
 pub mod generate_totp_secret;
 pub mod get_captcha;
+pub mod ldap;
+pub mod linked_accounts;
+pub mod list_account_freezes;
 pub mod list_banned;
 pub mod list_logins;
 pub mod list_media;
+pub mod list_read_posts;
+pub mod list_reports;
+pub mod list_shadow_bans;
 pub mod login;
 pub mod logout;
+pub mod mod_note;
 pub mod notifications;
+pub mod passkey;
+pub mod pin_to_profile;
 pub mod report_count;
+pub mod report_history;
 pub mod reset_password;
+pub mod revoke_login;
 pub mod save_settings;
+pub mod shadow_ban;
 pub mod update_totp;
 pub mod validate_auth;
 pub mod verify_email;
+pub mod warn_person;
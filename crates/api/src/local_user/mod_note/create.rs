@@ -0,0 +1,42 @@
+use super::check_mod_note_action;
+use crate::comment_report::validate_note_content;
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{CreatePersonModNote, PersonModNoteResponse},
+};
+use lemmy_db_schema::{
+  source::{
+    person::Person,
+    person_mod_note::{PersonModNote, PersonModNoteInsertForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::{LocalUserView, PersonModNoteView};
+use lemmy_utils::error::LemmyResult;
+
+/// Leaves a private note on a person, which only moderators of the community (or admins for
+/// instance-wide notes) can see
+#[tracing::instrument(skip(context))]
+pub async fn create_person_mod_note(
+  data: Json<CreatePersonModNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PersonModNoteResponse>> {
+  check_mod_note_action(&local_user_view, data.community_id, &context).await?;
+  // Make sure the person exists
+  Person::read(&mut context.pool(), data.person_id).await?;
+
+  let form = PersonModNoteInsertForm {
+    person_id: data.person_id,
+    community_id: data.community_id,
+    creator_id: local_user_view.person.id,
+    content: validate_note_content(&data.content)?,
+  };
+  let note = PersonModNote::create(&mut context.pool(), &form).await?;
+  let person_mod_note_view = PersonModNoteView::read(&mut context.pool(), note.id).await?;
+
+  Ok(Json(PersonModNoteResponse {
+    person_mod_note_view,
+  }))
+}
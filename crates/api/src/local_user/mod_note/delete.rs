@@ -0,0 +1,24 @@
+use super::check_mod_note_action;
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::DeletePersonModNote, SuccessResponse};
+use lemmy_db_schema::{source::person_mod_note::PersonModNote, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Deletes a note on a person. Admins can delete the notes of others.
+#[tracing::instrument(skip(context))]
+pub async fn delete_person_mod_note(
+  data: Json<DeletePersonModNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let note = PersonModNote::read(&mut context.pool(), data.note_id).await?;
+  if note.creator_id != local_user_view.person.id && !local_user_view.local_user.admin {
+    Err(LemmyErrorType::NoModNoteEditAllowed)?
+  }
+  check_mod_note_action(&local_user_view, note.community_id, &context).await?;
+
+  PersonModNote::delete(&mut context.pool(), data.note_id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
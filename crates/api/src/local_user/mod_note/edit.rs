@@ -0,0 +1,39 @@
+use super::check_mod_note_action;
+use crate::comment_report::validate_note_content;
+use actix_web::web::{Data, Json};
+use chrono::Utc;
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{EditPersonModNote, PersonModNoteResponse},
+};
+use lemmy_db_schema::{
+  source::person_mod_note::{PersonModNote, PersonModNoteUpdateForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::{LocalUserView, PersonModNoteView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+/// Edits a note on a person. Only the one who wrote the note can edit it.
+#[tracing::instrument(skip(context))]
+pub async fn edit_person_mod_note(
+  data: Json<EditPersonModNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PersonModNoteResponse>> {
+  let orig_note = PersonModNote::read(&mut context.pool(), data.note_id).await?;
+  if orig_note.creator_id != local_user_view.person.id {
+    Err(LemmyErrorType::NoModNoteEditAllowed)?
+  }
+  check_mod_note_action(&local_user_view, orig_note.community_id, &context).await?;
+
+  let form = PersonModNoteUpdateForm {
+    content: validate_note_content(&data.content)?,
+    updated: Utc::now(),
+  };
+  PersonModNote::update(&mut context.pool(), data.note_id, &form).await?;
+  let person_mod_note_view = PersonModNoteView::read(&mut context.pool(), data.note_id).await?;
+
+  Ok(Json(PersonModNoteResponse {
+    person_mod_note_view,
+  }))
+}
@@ -0,0 +1,24 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ListPersonModNotes, ListPersonModNotesResponse},
+  utils::check_community_mod_of_any_or_admin_action,
+};
+use lemmy_db_views::structs::{LocalUserView, PersonModNoteView};
+use lemmy_utils::error::LemmyResult;
+
+/// Lists the notes on a person. Moderators only see the notes of communities they moderate.
+#[tracing::instrument(skip(context))]
+pub async fn list_person_mod_notes(
+  data: Query<ListPersonModNotes>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListPersonModNotesResponse>> {
+  check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+
+  let mod_notes =
+    PersonModNoteView::list_for_persons(&mut context.pool(), &[data.person_id], &local_user_view)
+      .await?;
+
+  Ok(Json(ListPersonModNotesResponse { mod_notes }))
+}
@@ -0,0 +1,32 @@
+use lemmy_api_common::{
+  context::LemmyContext,
+  utils::{check_community_mod_action, is_admin},
+};
+use lemmy_db_schema::{newtypes::CommunityId, source::community::Community, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+pub mod create;
+pub mod delete;
+pub mod edit;
+pub mod list;
+
+/// Notes of a community can be managed by its moderators, instance-wide notes only by admins.
+async fn check_mod_note_action(
+  local_user_view: &LocalUserView,
+  community_id: Option<CommunityId>,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  if let Some(community_id) = community_id {
+    let community = Community::read(&mut context.pool(), community_id).await?;
+    check_community_mod_action(
+      &local_user_view.person,
+      &community,
+      true,
+      &mut context.pool(),
+    )
+    .await
+  } else {
+    is_admin(local_user_view)
+  }
+}
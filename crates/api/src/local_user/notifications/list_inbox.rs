@@ -0,0 +1,38 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{GetInbox, GetInboxResponse},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::{
+  inbox_combined_view::{InboxCombinedPaginationCursorData, InboxCombinedQuery},
+  structs::InboxCombinedView,
+};
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_inbox(
+  data: Query<GetInbox>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<GetInboxResponse>> {
+  let page_after = if let Some(page_cursor) = &data.page_cursor {
+    Some(InboxCombinedPaginationCursorData::read(page_cursor, &mut context.pool()).await?)
+  } else {
+    None
+  };
+
+  let inbox = InboxCombinedQuery {
+    my_person_id: local_user_view.person.id,
+    unread_only: data.unread_only.unwrap_or_default(),
+    show_bot_accounts: local_user_view.local_user.show_bot_accounts,
+    page_after,
+    limit: data.limit,
+  }
+  .list(&mut context.pool())
+  .await?;
+
+  let next_page = inbox.last().map(InboxCombinedView::to_cursor);
+
+  Ok(Json(GetInboxResponse { inbox, next_page }))
+}
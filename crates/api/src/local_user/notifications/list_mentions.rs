@@ -26,6 +26,7 @@ pub async fn list_mentions(
     sort,
     unread_only,
     show_bot_accounts,
+    before: None,
     page,
     limit,
   }
@@ -20,17 +20,29 @@ pub async fn list_replies(
   let person_id = Some(local_user_view.person.id);
   let show_bot_accounts = local_user_view.local_user.show_bot_accounts;
 
-  let replies = CommentReplyQuery {
+  let query = CommentReplyQuery {
     recipient_id: person_id,
     my_person_id: person_id,
     sort,
     unread_only,
     show_bot_accounts,
+    before: None,
     page,
     limit,
+  };
+
+  if data.grouped.unwrap_or_default() {
+    let groups = query.list_grouped(&mut context.pool()).await?;
+    return Ok(Json(GetRepliesResponse {
+      replies: vec![],
+      groups: Some(groups),
+    }));
   }
-  .list(&mut context.pool())
-  .await?;
 
-  Ok(Json(GetRepliesResponse { replies }))
+  let replies = query.list(&mut context.pool()).await?;
+
+  Ok(Json(GetRepliesResponse {
+    replies,
+    groups: None,
+  }))
 }
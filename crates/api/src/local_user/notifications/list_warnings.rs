@@ -0,0 +1,33 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ListWarnings, ListWarningsResponse},
+};
+use lemmy_db_schema::source::local_site::LocalSite;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_moderator::structs::{ModWarnPersonView, ModlogListParams};
+use lemmy_utils::error::LemmyResult;
+
+/// Lists the warnings which the user received. The moderator names are hidden like in the modlog.
+#[tracing::instrument(skip(context))]
+pub async fn list_warnings(
+  data: Query<ListWarnings>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListWarningsResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+
+  let params = ModlogListParams {
+    community_id: None,
+    mod_person_id: None,
+    other_person_id: Some(local_user_view.person.id),
+    post_id: None,
+    comment_id: None,
+    page: data.page,
+    limit: data.limit,
+    hide_modlog_names: local_site.hide_modlog_mod_names,
+  };
+  let warnings = ModWarnPersonView::list(&mut context.pool(), params).await?;
+
+  Ok(Json(ListWarningsResponse { warnings }))
+}
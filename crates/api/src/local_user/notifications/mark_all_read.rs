@@ -1,9 +1,13 @@
 use actix_web::web::{Data, Json};
-use lemmy_api_common::{context::LemmyContext, person::GetRepliesResponse};
-use lemmy_db_schema::source::{
-  comment_reply::CommentReply,
-  person_mention::PersonMention,
-  private_message::PrivateMessage,
+use lemmy_api_common::{context::LemmyContext, person::GetRepliesResponse, SuccessResponse};
+use lemmy_db_schema::{
+  newtypes::PersonId,
+  source::{
+    comment_reply::CommentReply,
+    moderator::ModWarnPerson,
+    person_mention::PersonMention,
+    private_message::PrivateMessage,
+  },
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
@@ -13,8 +17,26 @@ pub async fn mark_all_notifications_read(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<GetRepliesResponse>> {
-  let person_id = local_user_view.person.id;
+  mark_all_as_read(&context, local_user_view.person.id).await?;
 
+  Ok(Json(GetRepliesResponse {
+    replies: vec![],
+    groups: None,
+  }))
+}
+
+/// Marks every item of the combined inbox as read.
+#[tracing::instrument(skip(context))]
+pub async fn mark_inbox_as_read(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  mark_all_as_read(&context, local_user_view.person.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+async fn mark_all_as_read(context: &LemmyContext, person_id: PersonId) -> LemmyResult<()> {
   // Mark all comment_replies as read
   CommentReply::mark_all_as_read(&mut context.pool(), person_id)
     .await
@@ -30,5 +52,7 @@ pub async fn mark_all_notifications_read(
     .await
     .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
 
-  Ok(Json(GetRepliesResponse { replies: vec![] }))
+  ModWarnPerson::mark_all_as_read(&mut context.pool(), person_id).await?;
+
+  Ok(())
 }
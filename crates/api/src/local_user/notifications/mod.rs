@@ -1,5 +1,7 @@
+pub mod list_inbox;
 pub mod list_mentions;
 pub mod list_replies;
+pub mod list_warnings;
 pub mod mark_all_read;
 pub mod mark_mention_read;
 pub mod mark_reply_read;
@@ -0,0 +1,21 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, person::DeletePasskey, SuccessResponse};
+use lemmy_db_schema::source::webauthn_credential::WebauthnCredential;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_passkey(
+  data: Json<DeletePasskey>,
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let deleted =
+    WebauthnCredential::delete(&mut context.pool(), local_user_view.local_user.id, data.id).await?;
+  if deleted == 0 {
+    Err(LemmyErrorType::NotFound)?
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,18 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, person::ListPasskeysResponse};
+use lemmy_db_schema::source::webauthn_credential::WebauthnCredential;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_passkeys(
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<ListPasskeysResponse>> {
+  let passkeys =
+    WebauthnCredential::list_for_local_user(&mut context.pool(), local_user_view.local_user.id)
+      .await?;
+
+  Ok(Json(ListPasskeysResponse { passkeys }))
+}
@@ -0,0 +1,100 @@
+use super::{build_webauthn, parse_passkey, take_challenge};
+use crate::local_user::login::check_login_allowed;
+use actix_web::{
+  web::{Data, Json},
+  HttpRequest,
+};
+use lemmy_api_common::{
+  claims::Claims,
+  context::LemmyContext,
+  person::{FinishPasskeyLogin, LoginResponse, PasskeyChallengeResponse, StartPasskeyLogin},
+};
+use lemmy_db_schema::source::webauthn_credential::{
+  WebauthnChallenge,
+  WebauthnChallengeForm,
+  WebauthnCredential,
+};
+use lemmy_db_views::structs::{LocalUserView, SiteView};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use webauthn_rs::prelude::{PasskeyAuthentication, PublicKeyCredential};
+
+/// Starts logging in with one of the passkeys of the user. As with a password login, the
+/// response doesn't tell whether the user exists.
+#[tracing::instrument(skip(context))]
+pub async fn start_passkey_login(
+  data: Json<StartPasskeyLogin>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<PasskeyChallengeResponse>> {
+  let local_user_view =
+    LocalUserView::find_by_email_or_name(&mut context.pool(), &data.username_or_email)
+      .await
+      .with_lemmy_type(LemmyErrorType::IncorrectLogin)?;
+  let local_user_id = local_user_view.local_user.id;
+
+  let passkeys = WebauthnCredential::list_for_local_user(&mut context.pool(), local_user_id)
+    .await?
+    .iter()
+    .map(parse_passkey)
+    .collect::<LemmyResult<Vec<_>>>()?;
+  if passkeys.is_empty() {
+    Err(LemmyErrorType::IncorrectLogin)?
+  }
+  let (options, state) = build_webauthn(&context)?.start_passkey_authentication(&passkeys)?;
+
+  let form = WebauthnChallengeForm::new(local_user_id, serde_json::to_string(&state)?);
+  let challenge = WebauthnChallenge::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(PasskeyChallengeResponse {
+    challenge_id: challenge.uuid.to_string(),
+    options: serde_json::to_string(&options)?,
+  }))
+}
+
+/// Logs in without password or totp token, as the passkey already proves both.
+#[tracing::instrument(skip(context))]
+pub async fn finish_passkey_login(
+  data: Json<FinishPasskeyLogin>,
+  req: HttpRequest,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<LoginResponse>> {
+  let challenge = take_challenge(&data.challenge_id, &context).await?;
+  let state: PasskeyAuthentication =
+    serde_json::from_str(&challenge.state).with_lemmy_type(LemmyErrorType::InvalidPasskey)?;
+  let credential: PublicKeyCredential =
+    serde_json::from_str(&data.credential).with_lemmy_type(LemmyErrorType::InvalidPasskey)?;
+  let result = build_webauthn(&context)?
+    .finish_passkey_authentication(&credential, &state)
+    .with_lemmy_type(LemmyErrorType::InvalidPasskey)?;
+
+  // The key may have been removed since the login was started
+  let mut used = None;
+  for stored in
+    WebauthnCredential::list_for_local_user(&mut context.pool(), challenge.local_user_id).await?
+  {
+    let passkey = parse_passkey(&stored)?;
+    if passkey.cred_id() == result.cred_id() {
+      used = Some((stored, passkey));
+    }
+  }
+  let (stored, mut passkey) = used.ok_or(LemmyErrorType::InvalidPasskey)?;
+  // Keep the signature counter up to date, so that cloned keys are detected
+  passkey.update_credential(&result);
+  WebauthnCredential::mark_as_used(
+    &mut context.pool(),
+    stored.id,
+    &serde_json::to_string(&passkey)?,
+  )
+  .await?;
+
+  let local_user_view = LocalUserView::read(&mut context.pool(), challenge.local_user_id).await?;
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+  check_login_allowed(&local_user_view, &site_view, &context).await?;
+
+  let jwt = Claims::generate(local_user_view.local_user.id, req, &context).await?;
+
+  Ok(Json(LoginResponse {
+    jwt: Some(jwt),
+    verify_email_sent: false,
+    registration_created: false,
+  }))
+}
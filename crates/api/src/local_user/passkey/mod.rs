@@ -0,0 +1,49 @@
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  newtypes::LocalUserId,
+  source::webauthn_credential::{WebauthnChallenge, WebauthnCredential},
+};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use url::Url;
+use webauthn_rs::{
+  prelude::{Passkey, Uuid},
+  Webauthn,
+  WebauthnBuilder,
+};
+
+pub mod delete;
+pub mod list;
+pub mod login;
+pub mod register;
+
+/// Passkeys are bound to the domain of this instance.
+fn build_webauthn(context: &LemmyContext) -> LemmyResult<Webauthn> {
+  let origin = Url::parse(&context.settings().get_protocol_and_hostname())?;
+  let rp_id = origin.host_str().unwrap_or_default().to_string();
+  let webauthn = WebauthnBuilder::new(&rp_id, &origin)?
+    .rp_name(&context.settings().hostname)
+    .build()?;
+  Ok(webauthn)
+}
+
+/// Authenticators identify the user by a uuid, which is derived from the local user id so that it
+/// is the same for all passkeys of the user.
+fn user_handle(local_user_id: LocalUserId) -> Uuid {
+  Uuid::from_u64_pair(0, local_user_id.0.unsigned_abs().into())
+}
+
+fn parse_passkey(credential: &WebauthnCredential) -> LemmyResult<Passkey> {
+  Ok(serde_json::from_str(&credential.passkey)?)
+}
+
+/// Each challenge can only be answered once, and only within a few minutes.
+async fn take_challenge(
+  challenge_id: &str,
+  context: &LemmyContext,
+) -> LemmyResult<WebauthnChallenge> {
+  let uuid = Uuid::parse_str(challenge_id).with_lemmy_type(LemmyErrorType::InvalidPasskey)?;
+  let challenge = WebauthnChallenge::take(&mut context.pool(), uuid)
+    .await?
+    .ok_or(LemmyErrorType::InvalidPasskey)?;
+  Ok(challenge)
+}
@@ -0,0 +1,89 @@
+use super::{build_webauthn, parse_passkey, take_challenge, user_handle};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{FinishPasskeyRegistration, PasskeyChallengeResponse, PasskeyResponse},
+};
+use lemmy_db_schema::source::webauthn_credential::{
+  WebauthnChallenge,
+  WebauthnChallengeForm,
+  WebauthnCredential,
+  WebauthnCredentialInsertForm,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use webauthn_rs::prelude::{PasskeyRegistration, RegisterPublicKeyCredential};
+
+const MAX_PASSKEY_NAME_LENGTH: usize = 50;
+
+/// Starts adding a passkey or hardware key to your account. The returned options have to be
+/// passed to the browser, and its answer to [finish_passkey_registration].
+#[tracing::instrument(skip(context))]
+pub async fn start_passkey_registration(
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<PasskeyChallengeResponse>> {
+  let local_user_id = local_user_view.local_user.id;
+  // Keys which are already registered can't be added again
+  let exclude_credentials =
+    WebauthnCredential::list_for_local_user(&mut context.pool(), local_user_id)
+      .await?
+      .iter()
+      .map(|c| parse_passkey(c).map(|p| p.cred_id().clone()))
+      .collect::<LemmyResult<Vec<_>>>()?;
+
+  let name = &local_user_view.person.name;
+  let display_name = local_user_view.person.display_name.as_ref().unwrap_or(name);
+  let (options, state) = build_webauthn(&context)?.start_passkey_registration(
+    user_handle(local_user_id),
+    name,
+    display_name,
+    Some(exclude_credentials),
+  )?;
+
+  let form = WebauthnChallengeForm::new(local_user_id, serde_json::to_string(&state)?);
+  let challenge = WebauthnChallenge::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(PasskeyChallengeResponse {
+    challenge_id: challenge.uuid.to_string(),
+    options: serde_json::to_string(&options)?,
+  }))
+}
+
+#[tracing::instrument(skip(context))]
+pub async fn finish_passkey_registration(
+  data: Json<FinishPasskeyRegistration>,
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<PasskeyResponse>> {
+  let name = data.name.trim();
+  if name.is_empty() || name.chars().count() > MAX_PASSKEY_NAME_LENGTH {
+    Err(LemmyErrorType::InvalidPasskeyName)?
+  }
+
+  let challenge = take_challenge(&data.challenge_id, &context).await?;
+  if challenge.local_user_id != local_user_view.local_user.id {
+    Err(LemmyErrorType::InvalidPasskey)?
+  }
+  let state: PasskeyRegistration =
+    serde_json::from_str(&challenge.state).with_lemmy_type(LemmyErrorType::InvalidPasskey)?;
+  let credential: RegisterPublicKeyCredential =
+    serde_json::from_str(&data.credential).with_lemmy_type(LemmyErrorType::InvalidPasskey)?;
+  let passkey = build_webauthn(&context)?
+    .finish_passkey_registration(&credential, &state)
+    .with_lemmy_type(LemmyErrorType::InvalidPasskey)?;
+
+  let form = WebauthnCredentialInsertForm::new(
+    challenge.local_user_id,
+    URL_SAFE_NO_PAD.encode(passkey.cred_id()),
+    serde_json::to_string(&passkey)?,
+    name.to_string(),
+  );
+  let passkey = WebauthnCredential::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::PasskeyAlreadyExists)?;
+
+  Ok(Json(PasskeyResponse { passkey }))
+}
@@ -0,0 +1,56 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::PinToProfile, SuccessResponse};
+use lemmy_db_schema::{
+  source::{
+    comment::Comment,
+    person_pin::{PersonPin, PersonPinForm},
+    post::Post,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn pin_to_profile(
+  data: Json<PinToProfile>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let person_id = local_user_view.person.id;
+
+  // Only your own content which is still visible can be pinned
+  match (data.post_id, data.comment_id) {
+    (Some(post_id), None) => {
+      let post = Post::read(&mut context.pool(), post_id).await?;
+      if post.creator_id != person_id || post.deleted || post.removed {
+        Err(LemmyErrorType::NoPostEditAllowed)?
+      }
+    }
+    (None, Some(comment_id)) => {
+      let comment = Comment::read(&mut context.pool(), comment_id).await?;
+      if comment.creator_id != person_id || comment.deleted || comment.removed {
+        Err(LemmyErrorType::NoCommentEditAllowed)?
+      }
+    }
+    _ => Err(LemmyErrorType::InvalidBodyField)?,
+  }
+
+  let form = PersonPinForm::new(person_id, data.post_id, data.comment_id);
+
+  if data.pinned {
+    let count = PersonPin::count_for_person(&mut context.pool(), person_id).await?;
+    if count >= PersonPin::LIMIT {
+      Err(LemmyErrorType::TooManyProfilePins)?
+    }
+    PersonPin::pin(&mut context.pool(), &form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdateUser)?;
+  } else {
+    PersonPin::unpin(&mut context.pool(), &form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdateUser)?;
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,95 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{GetPersonReportHistory, GetPersonReportHistoryResponse},
+  utils::check_community_mod_of_any_or_admin_action,
+};
+use lemmy_db_views::{
+  comment_report_view::CommentReportQuery,
+  post_report_view::PostReportQuery,
+  private_message_report_view::PrivateMessageReportQuery,
+  structs::{LocalUserView, PersonModNoteView},
+};
+use lemmy_utils::error::LemmyResult;
+
+/// Lists the reports filed by and against a person, to help judge patterns of behaviour. For
+/// moderators this is limited to the communities they moderate.
+#[tracing::instrument(skip(context))]
+pub async fn get_person_report_history(
+  data: Query<GetPersonReportHistory>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<GetPersonReportHistoryResponse>> {
+  let person_id = data.person_id;
+  let limit = data.limit;
+
+  check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+
+  let filed_post_reports = PostReportQuery {
+    creator_id: Some(person_id),
+    limit,
+    ..Default::default()
+  }
+  .list(&mut context.pool(), &local_user_view)
+  .await?;
+
+  let received_post_reports = PostReportQuery {
+    post_creator_id: Some(person_id),
+    limit,
+    ..Default::default()
+  }
+  .list(&mut context.pool(), &local_user_view)
+  .await?;
+
+  let filed_comment_reports = CommentReportQuery {
+    creator_id: Some(person_id),
+    limit,
+    ..Default::default()
+  }
+  .list(&mut context.pool(), &local_user_view)
+  .await?;
+
+  let received_comment_reports = CommentReportQuery {
+    comment_creator_id: Some(person_id),
+    limit,
+    ..Default::default()
+  }
+  .list(&mut context.pool(), &local_user_view)
+  .await?;
+
+  // Private message reports are only visible to admins
+  let (filed_private_message_reports, received_private_message_reports) =
+    if local_user_view.local_user.admin {
+      let filed = PrivateMessageReportQuery {
+        creator_id: Some(person_id),
+        limit,
+        ..Default::default()
+      }
+      .list(&mut context.pool())
+      .await?;
+      let received = PrivateMessageReportQuery {
+        private_message_creator_id: Some(person_id),
+        limit,
+        ..Default::default()
+      }
+      .list(&mut context.pool())
+      .await?;
+      (Some(filed), Some(received))
+    } else {
+      (None, None)
+    };
+
+  let mod_notes =
+    PersonModNoteView::list_for_persons(&mut context.pool(), &[person_id], &local_user_view)
+      .await?;
+
+  Ok(Json(GetPersonReportHistoryResponse {
+    filed_post_reports,
+    filed_comment_reports,
+    received_post_reports,
+    received_comment_reports,
+    filed_private_message_reports,
+    received_private_message_reports,
+    mod_notes,
+  }))
+}
@@ -0,0 +1,22 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, person::RevokeLogin, SuccessResponse};
+use lemmy_db_schema::source::login_token::LoginToken;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn revoke_login(
+  data: Json<RevokeLogin>,
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let revoked =
+    LoginToken::invalidate_by_id(&mut context.pool(), local_user_view.local_user.id, data.id)
+      .await?;
+  if revoked == 0 {
+    Err(LemmyErrorType::NotFound)?
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
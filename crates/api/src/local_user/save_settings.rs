@@ -5,6 +5,7 @@ use lemmy_api_common::{
   person::SaveUserSettings,
   request::replace_image,
   utils::{
+    check_timezone_valid,
     get_url_blocklist,
     local_site_to_slur_regex,
     process_markdown_opt,
@@ -97,6 +98,16 @@ pub async fn save_user_settings(
     is_valid_matrix_id(matrix_user_id)?;
   }
 
+  if let Some(home_discovery_percent) = data.home_discovery_percent {
+    if !(0..=100).contains(&home_discovery_percent) {
+      Err(LemmyErrorType::InvalidHomeDiscoveryPercent)?
+    }
+  }
+
+  if let Some(quiet_hours_timezone) = &data.quiet_hours_timezone {
+    check_timezone_valid(quiet_hours_timezone)?;
+  }
+
   let local_user_id = local_user_view.local_user.id;
   let person_id = local_user_view.person.id;
   let default_listing_type = data.default_listing_type;
@@ -144,8 +155,25 @@ pub async fn save_user_settings(
     enable_private_messages: data.enable_private_messages,
     collapse_bot_comments: data.collapse_bot_comments,
     auto_mark_fetched_posts_as_read: data.auto_mark_fetched_posts_as_read,
+    home_discovery_percent: data.home_discovery_percent,
+    image_text_recognition: data.image_text_recognition,
+    quiet_hours_enabled: data.quiet_hours_enabled,
+    quiet_hours_start: data.quiet_hours_start,
+    quiet_hours_end: data.quiet_hours_end,
+    quiet_hours_timezone: data.quiet_hours_timezone.clone(),
+    mark_read_on_save: data.mark_read_on_save,
+    private_message_requests: data.private_message_requests,
+    allow_private_messages: data.allow_private_messages,
+    downrank_bot_accounts: data.downrank_bot_accounts,
+    ldap_admin_login: data.ldap_admin_login,
     ..Default::default()
   };
+  let local_user_form = LocalUserUpdateForm {
+    customized_settings: local_user_view
+      .local_user
+      .customized_settings_after(&local_user_form),
+    ..local_user_form
+  };
 
   LocalUser::update(&mut context.pool(), local_user_id, &local_user_form).await?;
 
@@ -0,0 +1,68 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ShadowBanPerson, ShadowBanPersonResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  source::{
+    local_user::LocalUser,
+    moderator::{AdminShadowBanPerson, AdminShadowBanPersonForm},
+    person::{Person, PersonUpdateForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn shadow_ban_person(
+  data: Json<ShadowBanPerson>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ShadowBanPersonResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  // Also make sure you're a higher admin than the target
+  LocalUser::is_higher_admin_check(
+    &mut context.pool(),
+    local_user_view.person.id,
+    vec![data.person_id],
+  )
+  .await?;
+
+  if let Some(reason) = &data.reason {
+    is_valid_body_field(reason, false)?;
+  }
+
+  // Remote persons can also be shadow banned, which hides their content on this instance
+  let person = Person::update(
+    &mut context.pool(),
+    data.person_id,
+    &PersonUpdateForm {
+      shadow_banned: Some(data.shadow_ban),
+      ..Default::default()
+    },
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntUpdateUser)?;
+
+  // Mod tables
+  let form = AdminShadowBanPersonForm {
+    admin_person_id: local_user_view.person.id,
+    other_person_id: person.id,
+    reason: data.reason.clone(),
+    shadow_banned: data.shadow_ban,
+  };
+  AdminShadowBanPerson::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(ShadowBanPersonResponse {
+    person_id: person.id,
+    shadow_banned: person.shadow_banned,
+  }))
+}
@@ -0,0 +1,167 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::TimeDelta;
+use lemmy_api_common::{
+  community::BanFromCommunity,
+  context::LemmyContext,
+  person::{WarnPerson, WarnPersonResponse},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, check_expire_time, is_admin},
+};
+use lemmy_db_schema::{
+  source::{
+    community::{
+      Community,
+      CommunityFollower,
+      CommunityFollowerForm,
+      CommunityPersonBan,
+      CommunityPersonBanForm,
+    },
+    local_user::LocalUser,
+    moderator::{ModBanFromCommunity, ModBanFromCommunityForm, ModWarnPerson, ModWarnPersonForm},
+    person::Person,
+  },
+  traits::{Bannable, Crud, Followable},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::structs::CommunityPersonBanView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn warn_person(
+  data: Json<WarnPerson>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<WarnPersonResponse>> {
+  // Moderators warn in their community, admins can also warn for the whole site
+  let community = if let Some(community_id) = data.community_id {
+    let community = Community::read(&mut context.pool(), community_id).await?;
+    check_community_mod_action(
+      &local_user_view.person,
+      &community,
+      false,
+      &mut context.pool(),
+    )
+    .await?;
+    LocalUser::is_higher_mod_or_admin_check(
+      &mut context.pool(),
+      community_id,
+      local_user_view.person.id,
+      vec![data.person_id],
+    )
+    .await?;
+    Some(community)
+  } else {
+    is_admin(&local_user_view)?;
+    LocalUser::is_higher_admin_check(
+      &mut context.pool(),
+      local_user_view.person.id,
+      vec![data.person_id],
+    )
+    .await?;
+    None
+  };
+
+  let reason = data.reason.trim();
+  if reason.is_empty() {
+    Err(LemmyErrorType::InvalidBodyField)?
+  }
+  is_valid_body_field(reason, false)?;
+  let expires = check_expire_time(data.expires)?;
+  let target = Person::read(&mut context.pool(), data.person_id).await?;
+
+  let form = ModWarnPersonForm {
+    mod_person_id: local_user_view.person.id,
+    other_person_id: target.id,
+    community_id: data.community_id,
+    reason: reason.to_string(),
+    strikes: data.strikes.filter(|s| *s > 0),
+    expires,
+  };
+  ModWarnPerson::create(&mut context.pool(), &form).await?;
+
+  let strikes = ModWarnPerson::active_strikes(
+    &mut context.pool(),
+    target.id,
+    data.community_id,
+    context.clock().now(),
+  )
+  .await?;
+
+  let mut banned = false;
+  if let Some(community) = community {
+    if let Some(limit) = community.warning_ban_strikes.filter(|l| *l > 0) {
+      if strikes >= i64::from(limit) {
+        banned = ban_for_strikes(&local_user_view, &community, &target, strikes, &context).await?;
+      }
+    }
+  }
+
+  Ok(Json(WarnPersonResponse { strikes, banned }))
+}
+
+/// Bans the person from the community for the days configured by the community, unless they are
+/// already banned. Returns whether they were banned.
+async fn ban_for_strikes(
+  local_user_view: &LocalUserView,
+  community: &Community,
+  target: &Person,
+  strikes: i64,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<bool> {
+  if CommunityPersonBanView::check(&mut context.pool(), target.id, community.id)
+    .await
+    .is_err()
+  {
+    return Ok(false);
+  }
+
+  let expires = context.clock().now() + TimeDelta::days(i64::from(community.warning_ban_days));
+  let reason = Some(format!("{strikes} warning strikes"));
+  let ban_form = CommunityPersonBanForm {
+    community_id: community.id,
+    person_id: target.id,
+    expires: Some(Some(expires)),
+    reason: Some(reason.clone()),
+    appeal: Some(None),
+  };
+  CommunityPersonBan::ban(&mut context.pool(), &ban_form).await?;
+
+  let follower_form = CommunityFollowerForm::new(community.id, target.id);
+  CommunityFollower::unfollow(&mut context.pool(), &follower_form)
+    .await
+    .ok();
+
+  let form = ModBanFromCommunityForm {
+    mod_person_id: local_user_view.person.id,
+    other_person_id: target.id,
+    community_id: community.id,
+    reason: reason.clone(),
+    banned: Some(true),
+    expires: Some(expires),
+  };
+  ModBanFromCommunity::create(&mut context.pool(), &form).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::BanFromCommunity {
+      moderator: local_user_view.person.clone(),
+      community_id: community.id,
+      target: target.clone(),
+      data: BanFromCommunity {
+        community_id: community.id,
+        person_id: target.id,
+        ban: true,
+        reason,
+        remove_or_restore_data: None,
+        expires: Some(expires.timestamp()),
+        appeal: None,
+      },
+    },
+    context,
+  )?;
+
+  Ok(true)
+}
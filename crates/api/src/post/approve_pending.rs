@@ -0,0 +1,72 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  build_response::build_post_response,
+  context::LemmyContext,
+  post::{ApprovePendingPost, PostResponse},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    moderator::{ModRemovePost, ModRemovePostForm},
+    post::{Post, PostUpdateForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn approve_pending_post(
+  data: Json<ApprovePendingPost>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PostResponse>> {
+  let orig_post = Post::read(&mut context.pool(), data.post_id).await?;
+  let community = Community::read(&mut context.pool(), orig_post.community_id).await?;
+  check_community_mod_action(
+    &local_user_view.person,
+    &community,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
+  // Rejected posts stay pending, so that they are back in the queue if they are restored
+  if !orig_post.pending || orig_post.removed {
+    Err(LemmyErrorType::NotFound)?
+  }
+
+  let form = if data.approve {
+    PostUpdateForm {
+      pending: Some(false),
+      ..Default::default()
+    }
+  } else {
+    PostUpdateForm {
+      removed: Some(true),
+      ..Default::default()
+    }
+  };
+  let post = Post::update(&mut context.pool(), data.post_id, &form).await?;
+
+  if data.approve {
+    // Pending posts weren't federated on creation. Scheduled posts are federated once they are
+    // published.
+    if post.scheduled_publish_time.is_none() {
+      ActivityChannel::submit_activity(SendActivityData::CreatePost(post), &context)?;
+    }
+  } else {
+    let form = ModRemovePostForm {
+      mod_person_id: local_user_view.person.id,
+      post_id: data.post_id,
+      removed: Some(true),
+      reason: data.reason.clone(),
+      automod: false,
+    };
+    ModRemovePost::create(&mut context.pool(), &form).await?;
+  }
+
+  build_post_response(&context, community.id, local_user_view, data.post_id).await
+}
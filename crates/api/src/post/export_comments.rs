@@ -0,0 +1,225 @@
+use actix_web::{
+  web::{Bytes, Data, Query},
+  HttpResponse,
+};
+use futures::stream;
+use lemmy_api_common::{
+  context::LemmyContext,
+  post::{ExportPostComments, ExportedComment},
+  utils::{check_private_instance, is_mod_or_admin_opt},
+};
+use lemmy_db_schema::{
+  newtypes::PostId,
+  source::{comment::Comment, post::Post},
+  utils::{ActualDbPool, DbPool},
+};
+use lemmy_db_views::structs::{LocalUserView, PostView, SiteView};
+use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
+
+/// How many comments are read from the database at once.
+const EXPORT_BATCH_SIZE: i64 = 500;
+
+/// Streams the whole comment tree of a post as JSON Lines. Comments are read in batches ordered by
+/// their path, so the connection is only held while reading a batch, and parents are always
+/// written before their children.
+#[tracing::instrument(skip(context))]
+pub async fn export_post_comments(
+  data: Query<ExportPostComments>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<HttpResponse> {
+  let local_site = SiteView::read_local(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site.local_site)?;
+
+  let community_id = Post::read_xx(&mut context.pool(), data.post_id)
+    .await?
+    .community_id;
+  let is_mod_or_admin = is_mod_or_admin_opt(
+    &mut context.pool(),
+    local_user_view.as_ref(),
+    Some(community_id),
+  )
+  .await
+  .is_ok();
+
+  // Also checks that the post is visible to the user
+  let post_view = PostView::read(
+    &mut context.pool(),
+    data.post_id,
+    local_user_view.as_ref().map(|l| &l.local_user),
+    is_mod_or_admin,
+  )
+  .await?;
+  if !is_mod_or_admin && !post_view.community.comment_export_public {
+    Err(LemmyErrorType::NotAModOrAdmin)?
+  }
+
+  let body = comment_export_stream(
+    context.inner_pool().clone(),
+    post_view.post.id,
+    is_mod_or_admin,
+  );
+
+  Ok(
+    HttpResponse::Ok()
+      .content_type("application/x-ndjson")
+      .streaming(body),
+  )
+}
+
+fn comment_export_stream(
+  pool: ActualDbPool,
+  post_id: PostId,
+  include_hidden_content: bool,
+) -> impl futures::Stream<Item = Result<Bytes, LemmyError>> {
+  // The state is the path of the last exported comment, or None once the export is finished
+  stream::try_unfold(Some(None), move |after| {
+    let pool = pool.clone();
+    async move {
+      let Some(after) = after else {
+        return Ok::<_, LemmyError>(None);
+      };
+      let mut comments = Comment::list_for_export(
+        &mut DbPool::Pool(&pool),
+        post_id,
+        after.as_ref(),
+        EXPORT_BATCH_SIZE,
+      )
+      .await?;
+      Comment::restore_archived(&mut DbPool::Pool(&pool), &mut comments).await?;
+      let next = if comments.len() < EXPORT_BATCH_SIZE as usize {
+        None
+      } else {
+        comments.last().map(|c| Some(c.path.clone()))
+      };
+
+      let mut lines = Vec::new();
+      for comment in comments {
+        serde_json::to_writer(
+          &mut lines,
+          &exported_comment(comment, include_hidden_content),
+        )?;
+        lines.push(b'\n');
+      }
+      Ok(Some((Bytes::from(lines), next)))
+    }
+  })
+}
+
+fn exported_comment(comment: Comment, include_hidden_content: bool) -> ExportedComment {
+  let hidden = comment.removed || comment.deleted;
+  ExportedComment {
+    id: comment.id,
+    parent_id: comment.parent_comment_id(),
+    ap_id: comment.ap_id,
+    creator_id: comment.creator_id,
+    content: (include_hidden_content || !hidden).then_some(comment.content),
+    removed: comment.removed,
+    deleted: comment.deleted,
+    distinguished: comment.distinguished,
+    language_id: comment.language_id,
+    published: comment.published,
+    updated: comment.updated,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use futures::TryStreamExt;
+  use lemmy_db_schema::{
+    source::{
+      comment::CommentInsertForm,
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::PostInsertForm,
+    },
+    traits::Crud,
+  };
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  async fn export(
+    context: &LemmyContext,
+    post_id: PostId,
+    include_hidden_content: bool,
+  ) -> LemmyResult<Vec<ExportedComment>> {
+    let bytes: Vec<Bytes> = comment_export_stream(
+      context.inner_pool().clone(),
+      post_id,
+      include_hidden_content,
+    )
+    .try_collect()
+    .await?;
+    let lines = bytes.concat();
+    let mut exported = Vec::new();
+    for line in lines.split(|b| *b == b'\n').filter(|l| !l.is_empty()) {
+      exported.push(serde_json::from_slice(line)?);
+    }
+    Ok(exported)
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_comment_export_stream() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "exporter"),
+    )
+    .await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "export_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let post = Post::create(
+      pool,
+      &PostInsertForm::new("Export me".into(), person.id, community.id),
+    )
+    .await?;
+
+    let root_form = CommentInsertForm::new(person.id, post.id, "root".into());
+    let root = Comment::create(pool, &root_form, None).await?;
+    let reply_form = CommentInsertForm::new(person.id, post.id, "reply".into());
+    let reply = Comment::create(pool, &reply_form, Some(&root.path)).await?;
+    let removed_form = CommentInsertForm {
+      removed: Some(true),
+      ..CommentInsertForm::new(person.id, post.id, "removed".into())
+    };
+    let removed = Comment::create(pool, &removed_form, None).await?;
+
+    let exported = export(&context, post.id, false).await?;
+    assert_eq!(3, exported.len());
+
+    // Parents come before their children, and children reference their parent
+    let position = |id| exported.iter().position(|c| c.id == id);
+    assert!(position(root.id) < position(reply.id));
+    let exported_reply = &exported[position(reply.id).unwrap_or_default()];
+    assert_eq!(Some(root.id), exported_reply.parent_id);
+    assert_eq!(Some("reply".to_string()), exported_reply.content);
+    let exported_root = &exported[position(root.id).unwrap_or_default()];
+    assert_eq!(None, exported_root.parent_id);
+
+    // The content of removed comments is only exported for mods and admins
+    let exported_removed = &exported[position(removed.id).unwrap_or_default()];
+    assert!(exported_removed.removed);
+    assert_eq!(None, exported_removed.content);
+    let exported = export(&context, post.id, true).await?;
+    let exported_removed = exported
+      .iter()
+      .find(|c| c.id == removed.id)
+      .and_then(|c| c.content.clone());
+    assert_eq!(Some("removed".to_string()), exported_removed);
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
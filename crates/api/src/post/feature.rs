@@ -2,37 +2,50 @@ use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
   build_response::build_post_response,
+  community_bot::ModerationActor,
   context::LemmyContext,
   post::{FeaturePost, PostResponse},
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_mod_action, is_admin},
+  utils::{
+    acting_mod_person_id,
+    check_community_permission_or_bot,
+    is_admin,
+    log_community_bot_action,
+  },
 };
 use lemmy_db_schema::{
   source::{
     community::Community,
+    community_role::CommunityPermission,
     moderator::{ModFeaturePost, ModFeaturePostForm},
     post::{Post, PostUpdateForm},
   },
   traits::Crud,
+  CommunityBotScope,
   PostFeatureType,
 };
-use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::error::LemmyResult;
 
 #[tracing::instrument(skip(context))]
 pub async fn feature_post(
   data: Json<FeaturePost>,
   context: Data<LemmyContext>,
-  local_user_view: LocalUserView,
+  actor: ModerationActor,
 ) -> LemmyResult<Json<PostResponse>> {
+  let ModerationActor {
+    local_user_view,
+    bot_delegation,
+  } = actor;
   let post_id = data.post_id;
   let orig_post = Post::read(&mut context.pool(), post_id).await?;
 
   let community = Community::read(&mut context.pool(), orig_post.community_id).await?;
-  check_community_mod_action(
+  let delegation = check_community_permission_or_bot(
     &local_user_view.person,
+    &bot_delegation,
     &community,
-    false,
+    CommunityPermission::FeaturePost,
+    CommunityBotScope::FeaturePost,
     &mut context.pool(),
   )
   .await?;
@@ -58,16 +71,32 @@ pub async fn feature_post(
 
   // Mod tables
   let form = ModFeaturePostForm {
-    mod_person_id: local_user_view.person.id,
+    mod_person_id: acting_mod_person_id(&local_user_view.person, &delegation),
     post_id: data.post_id,
     featured: data.featured,
     is_featured_community: data.feature_type == PostFeatureType::Community,
+    automod: delegation.is_some(),
   };
 
   ModFeaturePost::create(&mut context.pool(), &form).await?;
+  log_community_bot_action(
+    &delegation,
+    CommunityBotScope::FeaturePost,
+    Some(post_id),
+    None,
+    data.featured,
+    None,
+    &mut context.pool(),
+  )
+  .await?;
 
   ActivityChannel::submit_activity(
-    SendActivityData::FeaturePost(post, local_user_view.person.clone(), data.featured),
+    SendActivityData::FeaturePost(
+      post,
+      local_user_view.person.clone(),
+      data.featured,
+      delegation.is_some(),
+    ),
     &context,
   )?;
 
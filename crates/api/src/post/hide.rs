@@ -1,11 +1,12 @@
 use actix_web::web::{Data, Json};
 use lemmy_api_common::{
   context::LemmyContext,
-  post::{HidePost, PostResponse},
+  post::{HidePost, HidePosts, PostResponse},
+  SuccessResponse,
 };
 use lemmy_db_schema::source::post::PostHide;
 use lemmy_db_views::structs::{LocalUserView, PostView};
-use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult, MAX_API_PARAM_ELEMENTS};
 
 #[tracing::instrument(skip(context))]
 pub async fn hide_post(
@@ -37,3 +38,27 @@ pub async fn hide_post(
 
   Ok(Json(PostResponse { post_view }))
 }
+
+#[tracing::instrument(skip(context))]
+pub async fn hide_posts(
+  data: Json<HidePosts>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let person_id = local_user_view.person.id;
+  if data.post_ids.len() > MAX_API_PARAM_ELEMENTS {
+    Err(LemmyErrorType::TooManyItems)?
+  }
+
+  if data.hide {
+    PostHide::hide_many(&mut context.pool(), &data.post_ids, person_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntHidePost)?;
+  } else {
+    PostHide::unhide_many(&mut context.pool(), &data.post_ids, person_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntHidePost)?;
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,50 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  post::{ListPendingPosts, ListPendingPostsResponse},
+  utils::is_mod_or_admin,
+};
+use lemmy_db_schema::{ListingType, PostSortType};
+use lemmy_db_views::{
+  post_view::PostQuery,
+  structs::{LocalUserView, SiteView},
+};
+use lemmy_utils::error::LemmyResult;
+
+/// Lists posts which wait for approval by a moderator.
+#[tracing::instrument(skip(context))]
+pub async fn list_pending_posts(
+  data: Query<ListPendingPosts>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListPendingPostsResponse>> {
+  if let Some(community_id) = data.community_id {
+    is_mod_or_admin(&mut context.pool(), &local_user_view.person, community_id).await?;
+  }
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+
+  // Admins see the queue of all communities, moderators only that of their own
+  let listing_type = if local_user_view.local_user.admin {
+    ListingType::All
+  } else {
+    ListingType::ModeratorView
+  };
+  let posts = PostQuery {
+    listing_type: Some(listing_type),
+    sort: Some(PostSortType::Old),
+    community_id: data.community_id,
+    local_user: Some(&local_user_view.local_user),
+    pending_only: Some(true),
+    show_hidden: Some(true),
+    show_read: Some(true),
+    show_nsfw: Some(true),
+    page: data.page,
+    limit: data.limit,
+    ..Default::default()
+  }
+  .list(&site_view.site, &mut context.pool())
+  .await?;
+
+  Ok(Json(ListPendingPostsResponse { posts }))
+}
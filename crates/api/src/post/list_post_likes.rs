@@ -2,9 +2,12 @@ use actix_web::web::{Data, Json, Query};
 use lemmy_api_common::{
   context::LemmyContext,
   post::{ListPostLikes, ListPostLikesResponse},
-  utils::is_mod_or_admin,
+  utils::check_community_permission,
+};
+use lemmy_db_schema::{
+  source::{community::Community, community_role::CommunityPermission, post::Post},
+  traits::Crud,
 };
-use lemmy_db_schema::{source::post::Post, traits::Crud};
 use lemmy_db_views::structs::{LocalUserView, VoteView};
 use lemmy_utils::error::LemmyResult;
 
@@ -16,10 +19,12 @@ pub async fn list_post_likes(
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<ListPostLikesResponse>> {
   let post = Post::read(&mut context.pool(), data.post_id).await?;
-  is_mod_or_admin(
-    &mut context.pool(),
+  let community = Community::read(&mut context.pool(), post.community_id).await?;
+  check_community_permission(
     &local_user_view.person,
-    post.community_id,
+    &community,
+    CommunityPermission::ViewVotes,
+    &mut context.pool(),
   )
   .await?;
 
@@ -2,10 +2,16 @@ use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
   build_response::build_post_response,
+  community_bot::ModerationActor,
   context::LemmyContext,
   post::{LockPost, PostResponse},
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_mod_action,
+  utils::{
+    acting_mod_person_id,
+    check_community_mod_action_or_bot,
+    check_expire_time,
+    log_community_bot_action,
+  },
 };
 use lemmy_db_schema::{
   source::{
@@ -13,35 +19,47 @@ use lemmy_db_schema::{
     post::{Post, PostUpdateForm},
   },
   traits::Crud,
+  CommunityBotScope,
 };
-use lemmy_db_views::structs::{LocalUserView, PostView};
+use lemmy_db_views::structs::PostView;
 use lemmy_utils::error::LemmyResult;
 
 #[tracing::instrument(skip(context))]
 pub async fn lock_post(
   data: Json<LockPost>,
   context: Data<LemmyContext>,
-  local_user_view: LocalUserView,
+  actor: ModerationActor,
 ) -> LemmyResult<Json<PostResponse>> {
+  let ModerationActor {
+    local_user_view,
+    bot_delegation,
+  } = actor;
   let post_id = data.post_id;
   let orig_post = PostView::read(&mut context.pool(), post_id, None, false).await?;
 
-  check_community_mod_action(
+  let delegation = check_community_mod_action_or_bot(
     &local_user_view.person,
+    &bot_delegation,
     &orig_post.community,
-    false,
+    CommunityBotScope::LockPost,
     &mut context.pool(),
   )
   .await?;
 
-  // Update the post
+  // Update the post. Unlocking also clears the expiry of a temporary lock.
   let post_id = data.post_id;
   let locked = data.locked;
+  let lock_expires = if locked {
+    check_expire_time(data.expires)?
+  } else {
+    None
+  };
   let post = Post::update(
     &mut context.pool(),
     post_id,
     &PostUpdateForm {
       locked: Some(locked),
+      lock_expires: Some(lock_expires),
       ..Default::default()
     },
   )
@@ -49,14 +67,30 @@ pub async fn lock_post(
 
   // Mod tables
   let form = ModLockPostForm {
-    mod_person_id: local_user_view.person.id,
+    mod_person_id: acting_mod_person_id(&local_user_view.person, &delegation),
     post_id: data.post_id,
     locked: Some(locked),
+    automod: delegation.is_some(),
   };
   ModLockPost::create(&mut context.pool(), &form).await?;
+  log_community_bot_action(
+    &delegation,
+    CommunityBotScope::LockPost,
+    Some(post_id),
+    None,
+    locked,
+    None,
+    &mut context.pool(),
+  )
+  .await?;
 
   ActivityChannel::submit_activity(
-    SendActivityData::LockPost(post, local_user_view.person.clone(), data.locked),
+    SendActivityData::LockPost(
+      post,
+      local_user_view.person.clone(),
+      data.locked,
+      delegation.is_some(),
+    ),
     &context,
   )?;
 
@@ -1,8 +1,12 @@
+pub mod approve_pending;
+pub mod export_comments;
 pub mod feature;
 pub mod get_link_metadata;
 pub mod hide;
 pub mod like;
+pub mod list_pending;
 pub mod list_post_likes;
 pub mod lock;
 pub mod mark_read;
 pub mod save;
+pub mod vote_poll;
@@ -8,7 +8,10 @@ use lemmy_db_schema::{
   traits::Saveable,
 };
 use lemmy_db_views::structs::{LocalUserView, PostView};
-use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
 
 #[tracing::instrument(skip(context))]
 pub async fn save_post(
@@ -16,9 +19,14 @@ pub async fn save_post(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<PostResponse>> {
+  if let Some(saved_note) = &data.saved_note {
+    is_valid_body_field(saved_note, false)?;
+  }
+
   let post_saved_form = PostSavedForm {
     post_id: data.post_id,
     person_id: local_user_view.person.id,
+    saved_note: data.saved_note.clone(),
   };
 
   if data.save {
@@ -41,7 +49,13 @@ pub async fn save_post(
   )
   .await?;
 
-  PostRead::mark_as_read(&mut context.pool(), post_id, person_id).await?;
+  if data.save
+    && data
+      .mark_as_read
+      .unwrap_or(local_user_view.local_user.mark_read_on_save)
+  {
+    PostRead::mark_as_read(&mut context.pool(), post_id, person_id).await?;
+  }
 
   Ok(Json(PostResponse { post_view }))
 }
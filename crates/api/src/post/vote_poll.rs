@@ -0,0 +1,61 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_common::{
+  context::LemmyContext,
+  post::{PollResponse, VotePoll},
+  utils::{check_bot_account, check_community_user_action},
+};
+use lemmy_db_schema::source::poll::{Poll, PollOption, PollVote};
+use lemmy_db_views::structs::{LocalUserView, PollView, PostView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use std::collections::HashSet;
+
+#[tracing::instrument(skip(context))]
+pub async fn vote_poll(
+  data: Json<VotePoll>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PollResponse>> {
+  let person_id = local_user_view.person.id;
+  check_bot_account(&local_user_view.person)?;
+
+  let poll = Poll::read(&mut context.pool(), data.poll_id).await?;
+  if poll.end_time.is_some_and(|end_time| end_time < Utc::now()) {
+    Err(LemmyErrorType::PollEnded)?
+  }
+
+  // Check for a community ban
+  let post = PostView::read(&mut context.pool(), poll.post_id, None, false).await?;
+  check_community_user_action(
+    &local_user_view.person,
+    &post.community,
+    &mut context.pool(),
+  )
+  .await?;
+
+  // Each option may only be picked once, and only from this poll
+  let option_ids = data.option_ids.iter().collect::<HashSet<_>>();
+  let poll_option_ids = PollOption::list_for_poll(&mut context.pool(), poll.id)
+    .await?
+    .into_iter()
+    .map(|o| o.id)
+    .collect::<Vec<_>>();
+  if option_ids.len() != data.option_ids.len()
+    || (!poll.multiple_choice && option_ids.len() > 1)
+    || !option_ids.iter().all(|id| poll_option_ids.contains(id))
+  {
+    Err(LemmyErrorType::InvalidPollVote)?
+  }
+
+  PollVote::vote(
+    &mut context.pool(),
+    poll.id,
+    person_id,
+    data.option_ids.clone(),
+  )
+  .await?;
+
+  let poll_view = PollView::read(&mut context.pool(), poll.id, Some(person_id)).await?;
+  Ok(Json(PollResponse { poll_view }))
+}
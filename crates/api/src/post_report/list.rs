@@ -4,7 +4,10 @@ use lemmy_api_common::{
   post::{ListPostReports, ListPostReportsResponse},
   utils::check_community_mod_of_any_or_admin_action,
 };
-use lemmy_db_views::{post_report_view::PostReportQuery, structs::LocalUserView};
+use lemmy_db_views::{
+  post_report_view::{PostReportPaginationCursorData, PostReportQuery},
+  structs::{LocalUserView, PersonModNoteView, PostReportView},
+};
 use lemmy_utils::error::LemmyResult;
 
 /// Lists post reports for a community if an id is supplied
@@ -21,17 +24,33 @@ pub async fn list_post_reports(
 
   check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
 
-  let page = data.page;
+  let page_after = if let Some(page_cursor) = &data.page_cursor {
+    Some(PostReportPaginationCursorData::read(page_cursor, &mut context.pool()).await?)
+  } else {
+    None
+  };
+
   let limit = data.limit;
   let post_reports = PostReportQuery {
     community_id,
     post_id,
     unresolved_only,
-    page,
+    page_after,
     limit,
+    ..Default::default()
   }
   .list(&mut context.pool(), &local_user_view)
   .await?;
 
-  Ok(Json(ListPostReportsResponse { post_reports }))
+  let next_page = post_reports.last().map(PostReportView::to_cursor);
+  let creator_ids: Vec<_> = post_reports.iter().map(|r| r.post_creator.id).collect();
+  let mod_notes =
+    PersonModNoteView::list_for_persons(&mut context.pool(), &creator_ids, &local_user_view)
+      .await?;
+
+  Ok(Json(ListPostReportsResponse {
+    post_reports,
+    next_page,
+    mod_notes,
+  }))
 }
@@ -4,7 +4,7 @@ use lemmy_api_common::{
   post::{PostReportResponse, ResolvePostReport},
   utils::check_community_mod_action,
 };
-use lemmy_db_schema::{source::post_report::PostReport, traits::Reportable};
+use lemmy_db_schema::{source::post_report::PostReport, traits::Reportable, ReportOutcome};
 use lemmy_db_views::structs::{LocalUserView, PostReportView};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
@@ -29,7 +29,8 @@ pub async fn resolve_post_report(
   .await?;
 
   if data.resolved {
-    PostReport::resolve(&mut context.pool(), report_id, person_id)
+    let outcome = data.outcome.unwrap_or(ReportOutcome::ResolvedNoAction);
+    PostReport::resolve(&mut context.pool(), report_id, person_id, outcome)
       .await
       .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
   } else {
@@ -0,0 +1,69 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  private_message::{AddPmGroupParticipant, PmConversationResponse},
+  utils::{check_private_messages_allowed, check_private_messages_enabled},
+};
+use lemmy_db_schema::{
+  newtypes::PersonId,
+  source::{
+    person_block::PersonBlock,
+    pm_conversation::{PmConversation, PmConversationParticipant},
+  },
+  utils::DbPool,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  MAX_PM_GROUP_PARTICIPANTS,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn add_pm_group_participant(
+  data: Json<AddPmGroupParticipant>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PmConversationResponse>> {
+  let conversation = PmConversation::read(&mut context.pool(), data.conversation_id).await?;
+  if !conversation.is_group {
+    Err(LemmyErrorType::PmConversationNotAGroup)?
+  }
+  if conversation.creator_id != Some(local_user_view.person.id) {
+    Err(LemmyErrorType::NotPmConversationParticipant)?
+  }
+  let participants = conversation.list_participants(&mut context.pool()).await?;
+  if participants.len() >= MAX_PM_GROUP_PARTICIPANTS {
+    Err(LemmyErrorType::TooManyPmConversationParticipants)?
+  }
+
+  check_pm_group_participant(
+    data.person_id,
+    local_user_view.person.id,
+    &mut context.pool(),
+  )
+  .await?;
+  PmConversationParticipant::add(&mut context.pool(), conversation.id, &[data.person_id]).await?;
+
+  let participants = conversation.list_participants(&mut context.pool()).await?;
+  Ok(Json(PmConversationResponse {
+    conversation,
+    participants,
+  }))
+}
+
+/// Checks that the person can be added to a group conversation by the given creator.
+pub(crate) async fn check_pm_group_participant(
+  person_id: PersonId,
+  creator_id: PersonId,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  PersonBlock::read(pool, person_id, creator_id).await?;
+
+  // Don't allow adding people who have private messages disabled, or who don't accept them from
+  // the creator
+  if let Ok(local_user_view) = LocalUserView::read_person(pool, person_id).await {
+    check_private_messages_enabled(&local_user_view)?;
+    check_private_messages_allowed(creator_id, &local_user_view, pool).await?;
+  }
+  Ok(())
+}
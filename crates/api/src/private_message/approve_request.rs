@@ -0,0 +1,30 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, private_message::ApprovePmRequest, SuccessResponse};
+use lemmy_db_schema::source::pm_conversation::PmConversation;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn approve_pm_request(
+  data: Json<ApprovePmRequest>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let person_id = local_user_view.person.id;
+  let conversation = PmConversation::read(&mut context.pool(), data.conversation_id).await?;
+  if conversation.request_recipient_id != Some(person_id) {
+    Err(LemmyErrorType::PmConversationNotARequest)?
+  }
+
+  if data.approve {
+    PmConversation::accept_request(&mut context.pool(), conversation.id, person_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
+  } else {
+    PmConversation::delete(&mut context.pool(), conversation.id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
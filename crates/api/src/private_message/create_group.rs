@@ -0,0 +1,73 @@
+use super::add_participant::check_pm_group_participant;
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  private_message::{CreatePmGroup, PmConversationResponse},
+  utils::{
+    check_new_account_restrictions,
+    check_private_messages_enabled,
+    local_site_to_slur_regex,
+  },
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    pm_conversation::{PmConversation, PmGroupConversationInsertForm},
+  },
+  NewAccountRestriction,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::slurs::check_slurs_opt,
+  MAX_PM_GROUP_PARTICIPANTS,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_pm_group(
+  data: Json<CreatePmGroup>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PmConversationResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let person_id = local_user_view.person.id;
+
+  check_private_messages_enabled(&local_user_view)?;
+  check_new_account_restrictions(
+    &local_user_view,
+    &local_site,
+    None,
+    NewAccountRestriction::PrivateMessages,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let slur_regex = local_site_to_slur_regex(&local_site);
+  check_slurs_opt(&data.name, &slur_regex)?;
+
+  let mut participant_ids = data.participant_ids.clone();
+  participant_ids.retain(|p| *p != person_id);
+  participant_ids.sort_by_key(|p| p.0);
+  participant_ids.dedup();
+  if participant_ids.len() >= MAX_PM_GROUP_PARTICIPANTS {
+    Err(LemmyErrorType::TooManyPmConversationParticipants)?
+  }
+  for participant_id in &participant_ids {
+    check_pm_group_participant(*participant_id, person_id, &mut context.pool()).await?;
+  }
+  participant_ids.insert(0, person_id);
+
+  let form = PmGroupConversationInsertForm {
+    creator_id: Some(person_id),
+    ..PmGroupConversationInsertForm::new(data.name.clone())
+  };
+  let conversation = PmConversation::create_group(&mut context.pool(), &form, &participant_ids)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreatePrivateMessage)?;
+
+  let participants = conversation.list_participants(&mut context.pool()).await?;
+  Ok(Json(PmConversationResponse {
+    conversation,
+    participants,
+  }))
+}
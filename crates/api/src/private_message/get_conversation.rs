@@ -0,0 +1,29 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  private_message::{GetPmConversation, PmConversationResponse},
+};
+use lemmy_db_schema::source::pm_conversation::PmConversation;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn get_pm_conversation(
+  data: Query<GetPmConversation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PmConversationResponse>> {
+  let conversation = PmConversation::read(&mut context.pool(), data.conversation_id).await?;
+  if !conversation
+    .is_participant(&mut context.pool(), local_user_view.person.id)
+    .await?
+  {
+    Err(LemmyErrorType::NotPmConversationParticipant)?
+  }
+
+  let participants = conversation.list_participants(&mut context.pool()).await?;
+  Ok(Json(PmConversationResponse {
+    conversation,
+    participants,
+  }))
+}
@@ -0,0 +1,25 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  private_message::{ListPmConversations, ListPmConversationsResponse},
+};
+use lemmy_db_views::{pm_conversation_view::PmConversationQuery, structs::LocalUserView};
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_pm_conversations(
+  data: Query<ListPmConversations>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListPmConversationsResponse>> {
+  let conversations = PmConversationQuery {
+    unread_only: data.unread_only.unwrap_or_default(),
+    requests_only: data.requests_only.unwrap_or_default(),
+    page: data.page,
+    limit: data.limit,
+  }
+  .list(&mut context.pool(), local_user_view.person.id)
+  .await?;
+
+  Ok(Json(ListPmConversationsResponse { conversations }))
+}
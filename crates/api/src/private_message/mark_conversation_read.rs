@@ -0,0 +1,40 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  private_message::MarkPmConversationAsRead,
+  SuccessResponse,
+};
+use lemmy_db_schema::source::{
+  pm_conversation::{PmConversation, PmConversationParticipant},
+  private_message::PrivateMessage,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn mark_pm_conversation_as_read(
+  data: Json<MarkPmConversationAsRead>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let person_id = local_user_view.person.id;
+  let conversation = PmConversation::read(&mut context.pool(), data.conversation_id).await?;
+  if !conversation
+    .is_participant(&mut context.pool(), person_id)
+    .await?
+  {
+    Err(LemmyErrorType::NotPmConversationParticipant)?
+  }
+
+  if conversation.is_group {
+    PmConversationParticipant::mark_as_read(&mut context.pool(), conversation.id, person_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
+  } else {
+    PrivateMessage::mark_conversation_as_read(&mut context.pool(), conversation.id, person_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
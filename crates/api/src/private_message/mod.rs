@@ -1 +1,8 @@
+pub mod add_participant;
+pub mod approve_request;
+pub mod create_group;
+pub mod get_conversation;
+pub mod list_conversations;
+pub mod mark_conversation_read;
 pub mod mark_read;
+pub mod remove_participant;
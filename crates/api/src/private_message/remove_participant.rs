@@ -0,0 +1,31 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  private_message::RemovePmGroupParticipant,
+  SuccessResponse,
+};
+use lemmy_db_schema::source::pm_conversation::{PmConversation, PmConversationParticipant};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn remove_pm_group_participant(
+  data: Json<RemovePmGroupParticipant>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let person_id = local_user_view.person.id;
+  let conversation = PmConversation::read(&mut context.pool(), data.conversation_id).await?;
+  if !conversation.is_group {
+    Err(LemmyErrorType::PmConversationNotAGroup)?
+  }
+
+  // Participants can leave the group, and the creator can remove anyone
+  if data.person_id != person_id && conversation.creator_id != Some(person_id) {
+    Err(LemmyErrorType::NotPmConversationParticipant)?
+  }
+
+  PmConversationParticipant::remove(&mut context.pool(), conversation.id, data.person_id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
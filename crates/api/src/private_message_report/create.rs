@@ -8,6 +8,7 @@ use lemmy_api_common::{
 use lemmy_db_schema::{
   source::{
     local_site::LocalSite,
+    pm_conversation::PmConversation,
     private_message::PrivateMessage,
     private_message_report::{PrivateMessageReport, PrivateMessageReportForm},
   },
@@ -31,8 +32,20 @@ pub async fn create_pm_report(
   let private_message_id = data.private_message_id;
   let private_message = PrivateMessage::read(&mut context.pool(), private_message_id).await?;
 
-  // Make sure that only the recipient of the private message can create a report
-  if person_id != private_message.recipient_id {
+  // Make sure that only the recipient of the private message can create a report. For group
+  // messages this is every participant except the creator.
+  let is_recipient = match private_message.recipient_id {
+    Some(recipient_id) => person_id == recipient_id,
+    None => {
+      let conversation =
+        PmConversation::read(&mut context.pool(), private_message.conversation_id).await?;
+      person_id != private_message.creator_id
+        && conversation
+          .is_participant(&mut context.pool(), person_id)
+          .await?
+    }
+  };
+  if !is_recipient {
     Err(LemmyErrorType::CouldntCreateReport)?
   }
 
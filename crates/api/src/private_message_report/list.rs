@@ -5,8 +5,11 @@ use lemmy_api_common::{
   utils::is_admin,
 };
 use lemmy_db_views::{
-  private_message_report_view::PrivateMessageReportQuery,
-  structs::LocalUserView,
+  private_message_report_view::{
+    PrivateMessageReportPaginationCursorData,
+    PrivateMessageReportQuery,
+  },
+  structs::{LocalUserView, PrivateMessageReportView},
 };
 use lemmy_utils::error::LemmyResult;
 
@@ -19,17 +22,28 @@ pub async fn list_pm_reports(
   is_admin(&local_user_view)?;
 
   let unresolved_only = data.unresolved_only.unwrap_or_default();
-  let page = data.page;
+  let page_after = if let Some(page_cursor) = &data.page_cursor {
+    Some(PrivateMessageReportPaginationCursorData::read(page_cursor, &mut context.pool()).await?)
+  } else {
+    None
+  };
+
   let limit = data.limit;
   let private_message_reports = PrivateMessageReportQuery {
     unresolved_only,
-    page,
+    page_after,
     limit,
+    ..Default::default()
   }
   .list(&mut context.pool())
   .await?;
 
+  let next_page = private_message_reports
+    .last()
+    .map(PrivateMessageReportView::to_cursor);
+
   Ok(Json(ListPrivateMessageReportsResponse {
     private_message_reports,
+    next_page,
   }))
 }
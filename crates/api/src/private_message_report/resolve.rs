@@ -4,7 +4,11 @@ use lemmy_api_common::{
   private_message::{PrivateMessageReportResponse, ResolvePrivateMessageReport},
   utils::is_admin,
 };
-use lemmy_db_schema::{source::private_message_report::PrivateMessageReport, traits::Reportable};
+use lemmy_db_schema::{
+  source::private_message_report::PrivateMessageReport,
+  traits::Reportable,
+  ReportOutcome,
+};
 use lemmy_db_views::structs::{LocalUserView, PrivateMessageReportView};
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
@@ -19,7 +23,8 @@ pub async fn resolve_pm_report(
   let report_id = data.report_id;
   let person_id = local_user_view.person.id;
   if data.resolved {
-    PrivateMessageReport::resolve(&mut context.pool(), report_id, person_id)
+    let outcome = data.outcome.unwrap_or(ReportOutcome::ResolvedNoAction);
+    PrivateMessageReport::resolve(&mut context.pool(), report_id, person_id, outcome)
       .await
       .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
   } else {
@@ -0,0 +1,69 @@
+use actix_web::web::{Data, Json, Query};
+use chrono::{TimeDelta, Utc};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{ListClientUsage, ListClientUsageResponse, RestrictClient},
+  utils::is_admin,
+  SuccessResponse,
+};
+use lemmy_db_schema::source::client_usage::{
+  ClientRestriction,
+  ClientRestrictionForm,
+  ClientUsage,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_client_name,
+};
+
+const CLIENT_USAGE_DEFAULT_DAYS: i64 = 7;
+const CLIENT_USAGE_MAX_DAYS: i64 = 90;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_client_usage(
+  data: Query<ListClientUsage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListClientUsageResponse>> {
+  is_admin(&local_user_view)?;
+
+  let days = data
+    .days
+    .unwrap_or(CLIENT_USAGE_DEFAULT_DAYS)
+    .clamp(1, CLIENT_USAGE_MAX_DAYS);
+  let since = Utc::now() - TimeDelta::days(days);
+  let usage = ClientUsage::list(&mut context.pool(), since).await?;
+  let restrictions = ClientRestriction::list(&mut context.pool()).await?;
+
+  Ok(Json(ListClientUsageResponse {
+    usage,
+    restrictions,
+  }))
+}
+
+#[tracing::instrument(skip(context))]
+pub async fn restrict_client(
+  data: Json<RestrictClient>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  is_admin(&local_user_view)?;
+  is_valid_client_name(&data.client)?;
+
+  if data.restricted {
+    if data.requests_per_minute.is_some_and(|r| r <= 0) {
+      Err(LemmyErrorType::InvalidClientRestriction)?
+    }
+    let form = ClientRestrictionForm {
+      client: data.client.clone(),
+      requests_per_minute: data.requests_per_minute,
+      reason: data.reason.clone(),
+    };
+    ClientRestriction::upsert(&mut context.pool(), &form).await?;
+  } else {
+    ClientRestriction::delete(&mut context.pool(), &data.client).await?;
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,48 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  description_translation::process_description_translation,
+  site::{EditSiteDescriptionTranslation, SiteDescriptionTranslationsResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  source::{
+    description_translation::{SiteDescriptionTranslation, SiteDescriptionTranslationForm},
+    site::Site,
+  },
+  utils::cache_invalidation::CacheInvalidation,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn edit_site_description_translation(
+  data: Json<EditSiteDescriptionTranslation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SiteDescriptionTranslationsResponse>> {
+  is_admin(&local_user_view)?;
+
+  let site = Site::read_local(&mut context.pool()).await?;
+  if data.sidebar.is_none() && data.description.is_none() {
+    SiteDescriptionTranslation::delete(&mut context.pool(), site.id, data.language_id).await?;
+  } else {
+    let sidebar =
+      process_description_translation(&data.sidebar, &data.description, &context).await?;
+    let form = SiteDescriptionTranslationForm {
+      sidebar,
+      description: data.description.clone(),
+      ..SiteDescriptionTranslationForm::new(site.id, data.language_id)
+    };
+    SiteDescriptionTranslation::upsert(&mut context.pool(), &form).await?;
+  }
+  CacheInvalidation::SiteView
+    .notify(&mut context.pool())
+    .await?;
+
+  let description_translations =
+    SiteDescriptionTranslation::list(&mut context.pool(), site.id).await?;
+  Ok(Json(SiteDescriptionTranslationsResponse {
+    description_translations,
+  }))
+}
@@ -0,0 +1,31 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{EditFeatureFlag, FeatureFlagResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::source::feature_flag::{FeatureFlag, FeatureFlagForm};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn edit_feature_flag(
+  data: Json<EditFeatureFlag>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<FeatureFlagResponse>> {
+  is_admin(&local_user_view)?;
+
+  if !(0..=100).contains(&data.rollout_percentage) {
+    Err(LemmyErrorType::InvalidRolloutPercentage)?
+  }
+
+  let form = FeatureFlagForm {
+    feature: data.feature,
+    enabled: data.enabled,
+    rollout_percentage: data.rollout_percentage,
+  };
+  let feature_flag = FeatureFlag::upsert(&mut context.pool(), &form).await?;
+
+  Ok(Json(FeatureFlagResponse { feature_flag }))
+}
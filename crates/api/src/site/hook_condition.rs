@@ -0,0 +1,50 @@
+use actix_web::web::Json;
+use lemmy_api_common::{
+  hooks::evaluate_condition,
+  site::{ValidateHookCondition, ValidateHookConditionResponse},
+  utils::is_admin,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{error::LemmyResult, utils::expression::Expression};
+use serde_json::Value;
+use std::time::Duration;
+
+/// Same as the default timeout of hooks.
+const EVALUATION_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[tracing::instrument]
+pub async fn validate_hook_condition(
+  data: Json<ValidateHookCondition>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ValidateHookConditionResponse>> {
+  is_admin(&local_user_view)?;
+
+  if let Err(e) = Expression::parse(&data.condition) {
+    return Ok(Json(ValidateHookConditionResponse {
+      error: Some(e.to_string()),
+      result: None,
+    }));
+  }
+  let Some(sample_data) = &data.sample_data else {
+    return Ok(Json(ValidateHookConditionResponse {
+      error: None,
+      result: None,
+    }));
+  };
+
+  let response = match serde_json::from_str::<Value>(sample_data)
+    .map_err(|e| format!("Invalid sample data: {e}"))
+    .and_then(|sample| {
+      evaluate_condition(&data.condition, &sample, EVALUATION_TIMEOUT).map_err(|e| e.to_string())
+    }) {
+    Ok(result) => ValidateHookConditionResponse {
+      error: None,
+      result: Some(result),
+    },
+    Err(error) => ValidateHookConditionResponse {
+      error: Some(error),
+      result: None,
+    },
+  };
+  Ok(Json(response))
+}
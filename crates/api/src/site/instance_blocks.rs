@@ -0,0 +1,68 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{AllowedInstance, BlockedInstance, EditInstanceBlock, GetInstanceBlocksResponse},
+  utils::{check_private_instance, is_admin},
+  SuccessResponse,
+};
+use lemmy_db_schema::{
+  source::{
+    federation_blocklist::{FederationBlockList, FederationBlockListUpdateForm},
+    instance::Instance,
+  },
+  utils::{diesel_string_update, naive_now},
+};
+use lemmy_db_views::structs::{LocalUserView, SiteView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn get_instance_blocks(
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<GetInstanceBlocksResponse>> {
+  let local_site = SiteView::read_local(&mut context.pool()).await?.local_site;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  // Admins can always see the lists, for everyone else it needs to be enabled
+  if !local_site.instance_blocks_public {
+    let local_user_view = local_user_view.ok_or(LemmyErrorType::NotAnAdmin)?;
+    is_admin(&local_user_view)?;
+  }
+
+  let blocked = Instance::blocklist_with_details(&mut context.pool())
+    .await?
+    .into_iter()
+    .map(|(instance, block)| BlockedInstance {
+      instance,
+      blocked: block.published,
+      reason: block.reason,
+    })
+    .collect();
+  let allowed = Instance::allowlist_with_details(&mut context.pool())
+    .await?
+    .into_iter()
+    .map(|(instance, allow)| AllowedInstance {
+      instance,
+      allowed: allow.published,
+    })
+    .collect();
+
+  Ok(Json(GetInstanceBlocksResponse { blocked, allowed }))
+}
+
+#[tracing::instrument(skip(context))]
+pub async fn edit_instance_block(
+  data: Json<EditInstanceBlock>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  is_admin(&local_user_view)?;
+
+  let form = FederationBlockListUpdateForm {
+    reason: Some(diesel_string_update(data.reason.as_deref()).flatten()),
+    updated: Some(Some(naive_now())),
+  };
+  FederationBlockList::update(&mut context.pool(), data.instance_id, &form).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
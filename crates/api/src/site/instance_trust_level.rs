@@ -0,0 +1,28 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{EditInstanceTrustLevel, InstanceResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{source::instance::Instance, utils::cache_invalidation::CacheInvalidation};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn edit_instance_trust_level(
+  data: Json<EditInstanceTrustLevel>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<InstanceResponse>> {
+  is_admin(&local_user_view)?;
+
+  let instance =
+    Instance::set_trust_level(&mut context.pool(), data.instance_id, data.trust_level).await?;
+
+  // Trusted instances are cached together with the federation allow- and blocklist
+  CacheInvalidation::SiteView
+    .notify(&mut context.pool())
+    .await?;
+
+  Ok(Json(InstanceResponse { instance }))
+}
@@ -1,13 +1,20 @@
 use actix_web::web::{Data, Json};
-use lemmy_api_common::{context::LemmyContext, site::GetSiteResponse, utils::is_admin};
+use lemmy_api_common::{
+  context::LemmyContext,
+  feature_flag::{enabled_features, get_feature_flags},
+  site::GetSiteResponse,
+  utils::is_admin,
+};
 use lemmy_db_schema::{
   source::{
     actor_language::SiteLanguage,
+    description_translation::SiteDescriptionTranslation,
     language::Language,
     local_site_url_blocklist::LocalSiteUrlBlocklist,
     local_user::{LocalUser, LocalUserUpdateForm},
     moderator::{ModAdd, ModAddForm},
     oauth_provider::OAuthProvider,
+    registration_question::RegistrationQuestion,
     tagline::Tagline,
   },
   traits::Crud,
@@ -64,6 +71,11 @@ pub async fn leave_admin(
   let oauth_providers = OAuthProvider::get_all_public(&mut context.pool()).await?;
   let blocked_urls = LocalSiteUrlBlocklist::get_all(&mut context.pool()).await?;
   let tagline = Tagline::get_random(&mut context.pool()).await.ok();
+  let feature_flags = get_feature_flags(&context).await?;
+  let enabled_features = enabled_features(&feature_flags, Some(&local_user_view.local_user));
+  let description_translations =
+    SiteDescriptionTranslation::list(&mut context.pool(), site_view.site.id).await?;
+  let registration_questions = RegistrationQuestion::list(&mut context.pool()).await?;
 
   Ok(Json(GetSiteResponse {
     site_view,
@@ -74,9 +86,14 @@ pub async fn leave_admin(
     discussion_languages,
     oauth_providers: Some(oauth_providers),
     admin_oauth_providers: None,
+    email_domains: None,
     blocked_urls,
     tagline,
     taglines: vec![],
     custom_emojis: vec![],
+    feature_flags,
+    enabled_features,
+    description_translations,
+    registration_questions,
   }))
 }
@@ -0,0 +1,27 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::SetMaintenanceMode,
+  utils::is_admin,
+  SuccessResponse,
+};
+use lemmy_db_schema::source::local_site::{LocalSite, LocalSiteUpdateForm};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn set_maintenance_mode(
+  data: Json<SetMaintenanceMode>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  is_admin(&local_user_view)?;
+
+  let form = LocalSiteUpdateForm {
+    maintenance_mode: Some(data.enabled),
+    ..Default::default()
+  };
+  LocalSite::update(&mut context.pool(), &form).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -1,7 +1,18 @@
 pub mod block;
+pub mod client_usage;
+pub mod description_translation;
+pub mod feature_flag;
 pub mod federated_instances;
+pub mod hook_condition;
+pub mod instance_blocks;
+pub mod instance_trust_level;
 pub mod leave_admin;
 pub mod list_all_media;
+pub mod maintenance_mode;
 pub mod mod_log;
 pub mod purge;
 pub mod registration_applications;
+pub mod spam;
+pub mod transparency_report;
+pub mod user_defaults;
+pub mod vote_anomaly;
@@ -21,7 +21,9 @@ use lemmy_db_views_moderator::structs::{
   ModRemoveCommentView,
   ModRemoveCommunityView,
   ModRemovePostView,
+  ModResolveBanAppealView,
   ModTransferCommunityView,
+  ModWarnPersonView,
   ModlogListParams,
 };
 use lemmy_utils::error::LemmyResult;
@@ -112,6 +114,16 @@ pub async fn get_mod_log(
     _ => Default::default(),
   };
 
+  let warned_persons = match type_ {
+    All | ModWarnPerson => ModWarnPersonView::list(&mut context.pool(), params).await?,
+    _ => Default::default(),
+  };
+
+  let resolved_ban_appeals = match type_ {
+    All | ModResolveBanAppeal => ModResolveBanAppealView::list(&mut context.pool(), params).await?,
+    _ => Default::default(),
+  };
+
   // These arrays are only for the full modlog, when a community isn't given
   let (
     banned,
@@ -183,5 +195,7 @@ pub async fn get_mod_log(
     admin_purged_posts,
     admin_purged_comments,
     hidden_communities,
+    warned_persons,
+    resolved_ban_appeals,
   }))
 }
@@ -65,6 +65,7 @@ pub async fn purge_comment(
       moderator: local_user_view.person.clone(),
       community: comment_view.community,
       reason: data.reason.clone(),
+      automod: false,
     },
     &context,
   )?;
@@ -64,6 +64,7 @@ pub async fn purge_post(
       moderator: local_user_view.person.clone(),
       reason: data.reason.clone(),
       removed: true,
+      automod: false,
     },
     &context,
   )?;
@@ -0,0 +1,27 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use chrono::{TimeDelta, Utc};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{ListRegistrationBursts, ListRegistrationBurstsResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{source::registration_source::RegistrationSource, utils::limit_and_offset};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+/// Helps admins to find spam waves which fill up the registration application queue.
+pub async fn list_registration_bursts(
+  data: Query<ListRegistrationBursts>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListRegistrationBurstsResponse>> {
+  // Only let admins do this
+  is_admin(&local_user_view)?;
+
+  let since = Utc::now() - TimeDelta::hours(data.hours.unwrap_or(24).clamp(1, 24 * 30));
+  let (limit, _) = limit_and_offset(None, data.limit)?;
+  let bursts = RegistrationSource::list_bursts(&mut context.pool(), since, limit).await?;
+
+  Ok(Json(ListRegistrationBurstsResponse { bursts }))
+}
@@ -1,6 +1,7 @@
 pub mod approve;
 pub mod get;
 pub mod list;
+pub mod list_bursts;
 #[cfg(test)]
 mod tests;
 pub mod unread_count;
@@ -24,6 +24,7 @@ use lemmy_db_schema::{
     local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
     person::{Person, PersonInsertForm},
     registration_application::{RegistrationApplication, RegistrationApplicationInsertForm},
+    registration_question::{RegistrationQuestion, RegistrationQuestionInsertForm},
     site::{Site, SiteInsertForm},
   },
   traits::Crud,
@@ -37,7 +38,9 @@ use lemmy_utils::{
 };
 use serial_test::serial;
 
-async fn create_test_site(context: &Data<LemmyContext>) -> LemmyResult<(Instance, LocalUserView)> {
+async fn create_test_site(
+  context: &Data<LemmyContext>,
+) -> LemmyResult<(Instance, LocalUserView, RegistrationQuestion)> {
   let pool = &mut context.pool();
 
   let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
@@ -63,7 +66,6 @@ async fn create_test_site(context: &Data<LemmyContext>) -> LemmyResult<(Instance
   // required
   let local_site_form = LocalSiteInsertForm {
     require_email_verification: Some(true),
-    application_question: Some(".".to_string()),
     registration_mode: Some(RegistrationMode::RequireApplication),
     site_setup: Some(true),
     ..LocalSiteInsertForm::new(site.id)
@@ -75,7 +77,12 @@ async fn create_test_site(context: &Data<LemmyContext>) -> LemmyResult<(Instance
   let rate_limit_form = LocalSiteRateLimitInsertForm::new(local_site.id);
   LocalSiteRateLimit::create(pool, &rate_limit_form).await?;
 
-  Ok((inserted_instance, admin_local_user_view))
+  // Requiring applications is only allowed if there is at least one question
+  let question =
+    RegistrationQuestion::create(pool, &RegistrationQuestionInsertForm::new(".".to_string()))
+      .await?;
+
+  Ok((inserted_instance, admin_local_user_view, question))
 }
 
 async fn signup(
@@ -100,7 +107,6 @@ async fn signup(
 
   let application_insert_form = RegistrationApplicationInsertForm {
     local_user_id: local_user.id,
-    answer: "x".to_string(),
   };
   let application = RegistrationApplication::create(pool, &application_insert_form).await?;
 
@@ -142,7 +148,7 @@ async fn test_application_approval() -> LemmyResult<()> {
   let context = LemmyContext::init_test_context().await;
   let pool = &mut context.pool();
 
-  let (instance, admin_local_user_view) = create_test_site(&context).await?;
+  let (instance, admin_local_user_view, question) = create_test_site(&context).await?;
 
   // Non-unread counts unfortunately are duplicated due to different types (i64 vs usize)
   let mut expected_total_applications = 0;
@@ -410,6 +416,7 @@ async fn test_application_approval() -> LemmyResult<()> {
   );
 
   LocalSite::delete(pool).await?;
+  RegistrationQuestion::delete(pool, question.id).await?;
   // Instance deletion cascades cleanup of all created persons
   Instance::delete(pool, instance.id).await?;
 
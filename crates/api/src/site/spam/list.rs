@@ -0,0 +1,29 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{ListSpamScores, ListSpamScoresResponse},
+  utils::is_admin,
+};
+use lemmy_db_views::{spam_score_view::SpamScoreQuery, structs::LocalUserView};
+use lemmy_utils::error::LemmyResult;
+
+/// Lists content which was held back or removed by the spam filter.
+pub async fn list_spam_scores(
+  data: Query<ListSpamScores>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListSpamScoresResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let spam_scores = SpamScoreQuery {
+    unreviewed_only: data.unreviewed_only.unwrap_or_default(),
+    page: data.page,
+    limit: data.limit,
+  }
+  .list(&mut context.pool())
+  .await?;
+
+  Ok(Json(ListSpamScoresResponse { spam_scores }))
+}
@@ -0,0 +1,172 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::{DateTime, Days, Utc};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{ContentFilterMatch, PreviewContentFilters, PreviewContentFiltersResponse},
+  spam::{SpamCandidate, SpamVerdict},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  source::{comment::Comment, local_site::LocalSite, post::Post},
+  utils::{diesel_opt_number_update, diesel_string_update},
+  SpamAction,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::{
+    slurs::{slur_check, SlurRegex},
+    validation::build_and_check_regex,
+  },
+};
+
+const PREVIEW_DEFAULT_LIMIT: i64 = 100;
+const PREVIEW_MAX_LIMIT: i64 = 1000;
+
+/// Shows which recent local content would have been affected by changed content filter settings,
+/// so that admins can catch a broken regex or threshold before saving it.
+pub async fn preview_content_filters(
+  data: Json<PreviewContentFilters>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<PreviewContentFiltersResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let local_site = preview_local_site(&data, LocalSite::read(&mut context.pool()).await?);
+  let slur_regex = build_and_check_regex(&local_site.slur_filter_regex.as_deref())?
+    .map(|regex| SlurRegex::new(regex, local_site.slur_filter_normalize));
+  let limit = data
+    .limit
+    .unwrap_or(PREVIEW_DEFAULT_LIMIT)
+    .clamp(1, PREVIEW_MAX_LIMIT);
+
+  let posts = Post::list_recent_local(&mut context.pool(), limit).await?;
+  let comments = Comment::list_recent_local(&mut context.pool(), limit).await?;
+
+  let mut matches = vec![];
+  for (post, creator_published, creator_is_admin, creator_reputation) in &posts {
+    let recent_duplicates = posts
+      .iter()
+      .filter(|(other, ..)| {
+        other.creator_id == post.creator_id
+          && other.community_id != post.community_id
+          && is_recent_before(other.published, post.published)
+          && (other.name == post.name || (post.url.is_some() && other.url == post.url))
+      })
+      .count();
+    let text = format!(
+      "{}\n{}",
+      post.name,
+      post.body.as_deref().unwrap_or_default()
+    );
+    let candidate = SpamCandidate {
+      text: &text,
+      published: post.published,
+      creator_published: *creator_published,
+      recent_duplicates: recent_duplicates as i64,
+      creator_reputation: *creator_reputation,
+    };
+    let verdict = SpamVerdict::evaluate(&candidate, *creator_is_admin, &local_site);
+    let slurs = slur_check(&text, &slur_regex).err().unwrap_or_default();
+    if !slurs.is_empty() || verdict.action != SpamAction::None {
+      matches.push(ContentFilterMatch {
+        post_id: post.id,
+        comment_id: None,
+        creator_id: post.creator_id,
+        slurs: slurs.into_iter().map(ToString::to_string).collect(),
+        spam_score: verdict.score,
+        spam_action: verdict.action,
+      });
+    }
+  }
+
+  for (comment, creator_published, creator_is_admin, creator_reputation) in &comments {
+    let recent_duplicates = comments
+      .iter()
+      .filter(|(other, ..)| {
+        other.creator_id == comment.creator_id
+          && other.post_id != comment.post_id
+          && is_recent_before(other.published, comment.published)
+          && other.content == comment.content
+      })
+      .count();
+    let candidate = SpamCandidate {
+      text: &comment.content,
+      published: comment.published,
+      creator_published: *creator_published,
+      recent_duplicates: recent_duplicates as i64,
+      creator_reputation: *creator_reputation,
+    };
+    let verdict = SpamVerdict::evaluate(&candidate, *creator_is_admin, &local_site);
+    let slurs = slur_check(&comment.content, &slur_regex)
+      .err()
+      .unwrap_or_default();
+    if !slurs.is_empty() || verdict.action != SpamAction::None {
+      matches.push(ContentFilterMatch {
+        post_id: comment.post_id,
+        comment_id: Some(comment.id),
+        creator_id: comment.creator_id,
+        slurs: slurs.into_iter().map(ToString::to_string).collect(),
+        spam_score: verdict.score,
+        spam_action: verdict.action,
+      });
+    }
+  }
+
+  Ok(Json(PreviewContentFiltersResponse {
+    posts_checked: posts.len() as i64,
+    comments_checked: comments.len() as i64,
+    matches,
+  }))
+}
+
+/// The current site settings, with the previewed changes applied.
+fn preview_local_site(data: &PreviewContentFilters, local_site: LocalSite) -> LocalSite {
+  let slur_filter_regex = diesel_string_update(data.slur_filter_regex.as_deref());
+  let spam_hold_threshold = diesel_opt_number_update(data.spam_hold_threshold);
+  let spam_remove_threshold = diesel_opt_number_update(data.spam_remove_threshold);
+  LocalSite {
+    slur_filter_regex: slur_filter_regex.unwrap_or(local_site.slur_filter_regex),
+    slur_filter_normalize: data
+      .slur_filter_normalize
+      .unwrap_or(local_site.slur_filter_normalize),
+    spam_link_density_weight: data
+      .spam_link_density_weight
+      .unwrap_or(local_site.spam_link_density_weight),
+    spam_repetition_weight: data
+      .spam_repetition_weight
+      .unwrap_or(local_site.spam_repetition_weight),
+    spam_account_age_weight: data
+      .spam_account_age_weight
+      .unwrap_or(local_site.spam_account_age_weight),
+    spam_low_reputation_weight: data
+      .spam_low_reputation_weight
+      .unwrap_or(local_site.spam_low_reputation_weight),
+    spam_hold_threshold: spam_hold_threshold.unwrap_or(local_site.spam_hold_threshold),
+    spam_remove_threshold: spam_remove_threshold.unwrap_or(local_site.spam_remove_threshold),
+    ..local_site
+  }
+}
+
+/// Whether `other` was published within the day before `published`, which is how far back
+/// duplicates are counted for new content.
+fn is_recent_before(other: DateTime<Utc>, published: DateTime<Utc>) -> bool {
+  other < published && other > published - Days::new(1)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::TimeDelta;
+
+  #[test]
+  fn test_is_recent_before() {
+    let now = Utc::now();
+    assert!(is_recent_before(now - TimeDelta::hours(2), now));
+    assert!(!is_recent_before(now, now));
+    assert!(!is_recent_before(now - TimeDelta::days(2), now));
+    assert!(!is_recent_before(now + TimeDelta::hours(2), now));
+  }
+}
@@ -0,0 +1,72 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  site::{ReviewSpamScore, SpamScoreResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  source::{
+    comment::{Comment, CommentUpdateForm},
+    post::{Post, PostUpdateForm},
+    spam_score::{SpamScore, SpamScoreUpdateForm},
+  },
+  traits::Crud,
+  utils::naive_now,
+  SpamAction,
+};
+use lemmy_db_views::structs::{LocalUserView, SpamScoreView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn review_spam_score(
+  data: Json<ReviewSpamScore>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SpamScoreResponse>> {
+  // Only let admins do this
+  is_admin(&local_user_view)?;
+
+  let orig = SpamScore::read(&mut context.pool(), data.id).await?;
+  if orig.action == SpamAction::None {
+    Err(LemmyErrorType::NotFound)?
+  }
+  // Approved content is public again, and has to be removed like any other content
+  if orig.approved == Some(true) {
+    Err(LemmyErrorType::SpamScoreAlreadyApproved)?
+  }
+
+  let form = SpamScoreUpdateForm {
+    approved: Some(Some(data.approve)),
+    reviewer_id: Some(Some(local_user_view.person.id)),
+    updated: Some(Some(naive_now())),
+  };
+  SpamScore::update(&mut context.pool(), data.id, &form).await?;
+
+  // Approved content is restored, and federated as it wasn't sent out on creation. Rejected
+  // content simply stays removed.
+  if data.approve {
+    if let Some(comment_id) = orig.comment_id {
+      let form = CommentUpdateForm {
+        removed: Some(false),
+        ..Default::default()
+      };
+      let comment = Comment::update(&mut context.pool(), comment_id, &form).await?;
+      ActivityChannel::submit_activity(SendActivityData::CreateComment(comment), &context)?;
+    } else {
+      let form = PostUpdateForm {
+        removed: Some(false),
+        ..Default::default()
+      };
+      let post = Post::update(&mut context.pool(), orig.post_id, &form).await?;
+      // Scheduled posts are federated once they are published, pending posts once they are
+      // approved by a moderator
+      if post.scheduled_publish_time.is_none() && !post.pending {
+        ActivityChannel::submit_activity(SendActivityData::CreatePost(post), &context)?;
+      }
+    }
+  }
+
+  let spam_score_view = SpamScoreView::read(&mut context.pool(), data.id).await?;
+  Ok(Json(SpamScoreResponse { spam_score_view }))
+}
@@ -0,0 +1,29 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{ListTransparencyReports, ListTransparencyReportsResponse},
+  utils::check_private_instance,
+};
+use lemmy_db_schema::source::{local_site::LocalSite, transparency_report::TransparencyReport};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_transparency_reports(
+  data: Query<ListTransparencyReports>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<ListTransparencyReportsResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let reports = TransparencyReport::list(
+    &mut context.pool(),
+    data.community_id,
+    data.page,
+    data.limit,
+  )
+  .await?;
+
+  Ok(Json(ListTransparencyReportsResponse { reports }))
+}
@@ -0,0 +1,28 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{ApplyUserDefaults, ApplyUserDefaultsResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::source::{local_site::LocalSite, local_user::LocalUser};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn apply_user_defaults(
+  data: Json<ApplyUserDefaults>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ApplyUserDefaultsResponse>> {
+  is_admin(&local_user_view)?;
+
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let mut updated = 0;
+  for setting in &data.settings {
+    updated += LocalUser::apply_site_default(&mut context.pool(), *setting, &local_site).await?;
+  }
+
+  Ok(Json(ApplyUserDefaultsResponse {
+    updated: i64::try_from(updated)?,
+  }))
+}
@@ -0,0 +1,29 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{ListVoteAnomalies, ListVoteAnomaliesResponse},
+  utils::is_admin,
+};
+use lemmy_db_views::{structs::LocalUserView, vote_anomaly_view::VoteAnomalyQuery};
+use lemmy_utils::error::LemmyResult;
+
+/// Lists suspicious voting patterns which were found by the scheduled vote analysis.
+pub async fn list_vote_anomalies(
+  data: Query<ListVoteAnomalies>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListVoteAnomaliesResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let vote_anomalies = VoteAnomalyQuery {
+    unreviewed_only: data.unreviewed_only.unwrap_or_default(),
+    page: data.page,
+    limit: data.limit,
+  }
+  .list(&mut context.pool())
+  .await?;
+
+  Ok(Json(ListVoteAnomaliesResponse { vote_anomalies }))
+}
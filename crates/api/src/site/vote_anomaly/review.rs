@@ -0,0 +1,47 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{ReviewVoteAnomaly, VoteAnomalyResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  source::vote_anomaly::{VoteAnomaly, VoteAnomalyUpdateForm},
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::{LocalUserView, VoteAnomalyView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub async fn review_vote_anomaly(
+  data: Json<ReviewVoteAnomaly>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<VoteAnomalyResponse>> {
+  // Only let admins do this
+  is_admin(&local_user_view)?;
+
+  let orig = VoteAnomaly::read(&mut context.pool(), data.id).await?;
+  if orig.votes_removed.is_some() {
+    Err(LemmyErrorType::VoteAnomalyAlreadyReviewed)?
+  }
+
+  let removed_votes = if data.remove_votes {
+    orig.remove_votes(&mut context.pool()).await?
+  } else {
+    0
+  };
+
+  let form = VoteAnomalyUpdateForm {
+    votes_removed: Some(Some(data.remove_votes)),
+    reviewer_id: Some(Some(local_user_view.person.id)),
+    updated: Some(Some(naive_now())),
+  };
+  VoteAnomaly::update(&mut context.pool(), data.id, &form).await?;
+
+  let vote_anomaly_view = VoteAnomalyView::read(&mut context.pool(), data.id).await?;
+  Ok(Json(VoteAnomalyResponse {
+    vote_anomaly_view,
+    removed_votes,
+  }))
+}
@@ -0,0 +1,63 @@
+use lemmy_db_schema::{
+  newtypes::{BanAppealId, CommunityId},
+  BanAppealStatus,
+};
+use lemmy_db_views::structs::BanAppealView;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Appeal your ban from a community. Each ban can only be appealed once.
+pub struct CreateBanAppeal {
+  pub community_id: CommunityId,
+  /// Why the ban should be lifted.
+  pub reason: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Fetches the ban appeals in the communities you moderate, or in all communities for admins.
+pub struct ListBanAppeals {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub status: Option<BanAppealStatus>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page: Option<i64>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Accept or deny a ban appeal. Accepting lifts the ban.
+pub struct ResolveBanAppeal {
+  pub id: BanAppealId,
+  pub accept: bool,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub deny_reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A ban appeal response.
+pub struct BanAppealResponse {
+  pub ban_appeal_view: BanAppealView,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A list of ban appeals.
+pub struct ListBanAppealsResponse {
+  pub ban_appeals: Vec<BanAppealView>,
+}
@@ -126,6 +126,9 @@ pub async fn send_local_notifs(
       // Potential duplication of notifications, one for reply and the other for mention, is handled
       // below by checking recipient ids
       recipient_ids.push(mention_user_view.local_user.id);
+      if !notifies(person, &mention_user_view) {
+        continue;
+      }
 
       let user_mention_form = PersonMentionInsertForm {
         recipient_id: mention_user_view.person.id,
@@ -179,6 +182,9 @@ pub async fn send_local_notifs(
         // Don't duplicate notif if already mentioned by checking recipient ids
         if !recipient_ids.contains(&parent_user_view.local_user.id) {
           recipient_ids.push(parent_user_view.local_user.id);
+          if !notifies(person, &parent_user_view) {
+            return Ok(recipient_ids);
+          }
 
           let comment_reply_form = CommentReplyInsertForm {
             recipient_id: parent_user_view.person.id,
@@ -225,6 +231,9 @@ pub async fn send_local_notifs(
       if let Ok(parent_user_view) = parent_user {
         if !recipient_ids.contains(&parent_user_view.local_user.id) {
           recipient_ids.push(parent_user_view.local_user.id);
+          if !notifies(person, &parent_user_view) {
+            return Ok(recipient_ids);
+          }
 
           let comment_reply_form = CommentReplyInsertForm {
             recipient_id: parent_user_view.person.id,
@@ -256,3 +265,9 @@ pub async fn send_local_notifs(
 
   Ok(recipient_ids)
 }
+
+/// Shadow banned persons only notify admins. The recipient is still returned, so that the shadow
+/// banned person doesn't notice.
+fn notifies(person: &Person, recipient: &LocalUserView) -> bool {
+  !person.shadow_banned || recipient.local_user.admin
+}
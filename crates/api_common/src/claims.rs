@@ -37,6 +37,25 @@ impl Claims {
     user_id: LocalUserId,
     req: HttpRequest,
     context: &LemmyContext,
+  ) -> LemmyResult<SensitiveString> {
+    Self::generate_inner(user_id, None, req, context).await
+  }
+
+  /// Generates a token when switching from another account, which is kept as audit record.
+  pub async fn generate_for_linked_account(
+    user_id: LocalUserId,
+    linked_from_user_id: LocalUserId,
+    req: HttpRequest,
+    context: &LemmyContext,
+  ) -> LemmyResult<SensitiveString> {
+    Self::generate_inner(user_id, Some(linked_from_user_id), req, context).await
+  }
+
+  async fn generate_inner(
+    user_id: LocalUserId,
+    linked_from_user_id: Option<LocalUserId>,
+    req: HttpRequest,
+    context: &LemmyContext,
   ) -> LemmyResult<SensitiveString> {
     let hostname = context.settings().hostname.clone();
     let my_claims = Claims {
@@ -62,6 +81,7 @@ impl Claims {
       user_id,
       ip,
       user_agent,
+      linked_from_user_id,
     };
     LoginToken::create(&mut context.pool(), form).await?;
     Ok(token)
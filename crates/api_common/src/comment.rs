@@ -1,9 +1,27 @@
 use lemmy_db_schema::{
-  newtypes::{CommentId, CommentReportId, CommunityId, LanguageId, LocalUserId, PostId},
+  newtypes::{
+    CommentId,
+    CommentReportId,
+    CommunityId,
+    LanguageId,
+    LocalUserId,
+    PostId,
+    ReportNoteId,
+    ReportReasonCategoryId,
+  },
   CommentSortType,
   ListingType,
+  ReportOutcome,
+  ReportSeverity,
+};
+use lemmy_db_views::structs::{
+  CommentReportView,
+  CommentView,
+  PaginationCursor,
+  PersonModNoteView,
+  ReportNoteView,
+  VoteView,
 };
-use lemmy_db_views::structs::{CommentReportView, CommentView, VoteView};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
@@ -55,6 +73,15 @@ pub struct DistinguishComment {
   pub distinguished: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Pin a top level comment to the top of its post. Only the post creator and mods can do this.
+pub struct PinComment {
+  pub comment_id: CommentId,
+  pub pinned: bool,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
@@ -77,13 +104,18 @@ pub struct RemoveComment {
   pub reason: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// Save / bookmark a comment.
 pub struct SaveComment {
   pub comment_id: CommentId,
   pub save: bool,
+  /// A personal note about the saved comment, which is only visible to you. Saving again without a
+  /// note keeps the existing one.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub saved_note: Option<String>,
 }
 
 #[skip_serializing_none]
@@ -132,10 +164,16 @@ pub struct GetComments {
   pub parent_id: Option<CommentId>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub saved_only: Option<bool>,
+  /// Only show saved items whose personal note contains this text.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub saved_notes: Option<String>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub liked_only: Option<bool>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub disliked_only: Option<bool>,
+  /// Only show comments by persons which you follow.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub followed_persons_only: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -146,6 +184,7 @@ pub struct GetCommentsResponse {
   pub comments: Vec<CommentView>,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -153,6 +192,12 @@ pub struct GetCommentsResponse {
 pub struct CreateCommentReport {
   pub comment_id: CommentId,
   pub reason: String,
+  /// How urgently the report needs to be handled, defaults to normal.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub severity: Option<ReportSeverity>,
+  /// One of the report reason categories defined by the admins.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub category_id: Option<ReportReasonCategoryId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -163,6 +208,7 @@ pub struct CommentReportResponse {
   pub comment_report_view: CommentReportView,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -170,18 +216,44 @@ pub struct CommentReportResponse {
 pub struct ResolveCommentReport {
   pub report_id: CommentReportId,
   pub resolved: bool,
+  /// How the report was handled, defaults to resolved without action. Ignored when unresolving.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub outcome: Option<ReportOutcome>,
 }
 
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
+/// Resolve all open reports against a comment, or against all comments of a post.
+pub struct ResolveCommentReports {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub comment_id: Option<CommentId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub post_id: Option<PostId>,
+  /// How the reports were handled, defaults to resolved without action.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub outcome: Option<ReportOutcome>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The number of resolved comment reports.
+pub struct ResolveCommentReportsResponse {
+  pub resolved: i64,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
 /// List comment reports.
 pub struct ListCommentReports {
   #[cfg_attr(feature = "full", ts(optional))]
   pub comment_id: Option<CommentId>,
   #[cfg_attr(feature = "full", ts(optional))]
-  pub page: Option<i64>,
+  pub page_cursor: Option<PaginationCursor>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub limit: Option<i64>,
   /// Only shows the unresolved reports
@@ -190,21 +262,74 @@ pub struct ListCommentReports {
   /// if no community is given, it returns reports for all communities moderated by the auth user
   #[cfg_attr(feature = "full", ts(optional))]
   pub community_id: Option<CommunityId>,
+  /// Shows the most urgent reports first
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub sort_by_severity: Option<bool>,
+  /// Only shows reports with this report reason category
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub category_id: Option<ReportReasonCategoryId>,
+  /// Only shows reports which were handled this way
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub outcome: Option<ReportOutcome>,
+  /// Only shows the reports which you claimed
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub assigned_to_me: Option<bool>,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// The comment report list response.
 pub struct ListCommentReportsResponse {
   pub comment_reports: Vec<CommentReportView>,
+  /// the pagination cursor to use to fetch the next page
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub next_page: Option<PaginationCursor>,
+  /// Private notes on the creators of the reported comments
+  pub mod_notes: Vec<PersonModNoteView>,
 }
 
 #[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Leave an internal note on a comment report (only doable by mods).
+pub struct CreateCommentReportNote {
+  pub report_id: CommentReportId,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit your note on a comment report.
+pub struct EditCommentReportNote {
+  pub note_id: ReportNoteId,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a note on a comment report.
+pub struct DeleteCommentReportNote {
+  pub note_id: ReportNoteId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The comment report note response.
+pub struct CommentReportNoteResponse {
+  pub report_note_view: ReportNoteView,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
-/// List comment likes. Admins-only.
+/// List who voted on a comment. Only for admins, and for community roles which are allowed to
+/// view votes.
 pub struct ListCommentLikes {
   pub comment_id: CommentId,
   #[cfg_attr(feature = "full", ts(optional))]
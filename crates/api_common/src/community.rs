@@ -1,8 +1,32 @@
 use lemmy_db_schema::{
-  newtypes::{CommunityId, LanguageId, PersonId},
-  source::site::Site,
+  newtypes::{
+    BanListId,
+    CommunityBotDelegationId,
+    CommunityId,
+    CommunityMirrorId,
+    CommunityPostTagId,
+    LanguageId,
+    PersonId,
+  },
+  source::{
+    ban_list::{BanList, BanListCommunity, BanListEntry, BanListExemption},
+    community_bot::{CommunityBotAction, CommunityBotDelegation},
+    community_domain_block::CommunityDomainBlock,
+    community_mirror::{CommunityMirror, CommunityMirrorAction},
+    community_name_alias::CommunityNameAlias,
+    community_post_tag::CommunityPostTag,
+    community_role::CommunityRolePermission,
+    description_translation::CommunityDescriptionTranslation,
+    local_user_community_settings::LocalUserCommunitySettings,
+    site::Site,
+  },
+  CommunityBotScope,
+  CommunityRole,
   CommunityVisibility,
   ListingType,
+  NewAccountRestriction,
+  PostListingMode,
+  PostSortType,
 };
 use lemmy_db_views_actor::structs::{
   CommunityModeratorView,
@@ -41,6 +65,12 @@ pub struct GetCommunityResponse {
   pub site: Option<Site>,
   pub moderators: Vec<CommunityModeratorView>,
   pub discussion_languages: Vec<LanguageId>,
+  /// All translations of the sidebar and description. The community in `community_view` is
+  /// already translated into the preferred language of the user.
+  pub description_translations: Vec<CommunityDescriptionTranslation>,
+  /// The sort and listing mode which you last saved for this community.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub my_settings: Option<LocalUserCommunitySettings>,
 }
 
 #[skip_serializing_none]
@@ -71,10 +101,47 @@ pub struct CreateCommunity {
   /// Whether to restrict posting only to moderators.
   #[cfg_attr(feature = "full", ts(optional))]
   pub posting_restricted_to_mods: Option<bool>,
+  /// Whether anyone can export the comments of posts, not only moderators.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub comment_export_public: Option<bool>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub discussion_languages: Option<Vec<LanguageId>>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub visibility: Option<CommunityVisibility>,
+  /// Whether text recognized in uploaded images is used to find posts in search.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub image_text_search: Option<bool>,
+  /// Minimum reputation of users to post and comment in the community. A negative value
+  /// removes the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub posting_min_reputation: Option<i64>,
+  /// Sent as a private message to people who start following the community.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub welcome_message: Option<String>,
+  /// Whether new posts of members wait for approval by a moderator.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub posts_require_approval: Option<bool>,
+  /// Ban people automatically once their active warning strikes reach this number. A negative
+  /// value removes the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub warning_ban_strikes: Option<i32>,
+  /// The length of automatic bans for too many warning strikes.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub warning_ban_days: Option<i32>,
+  /// New accounts must be at least this many days old for the restricted actions. A negative
+  /// value removes the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_min_age_days: Option<i32>,
+  /// New accounts must have written at least this many comments for the restricted actions. A
+  /// negative value removes the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_min_comments: Option<i32>,
+  /// New accounts must have a verified email for the restricted actions.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_require_verified_email: Option<bool>,
+  /// The actions which are restricted until an account meets the thresholds.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_restrictions: Option<Vec<NewAccountRestriction>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -132,6 +199,10 @@ pub struct BanFromCommunity {
   /// An i64 unix timestamp is used for a simpler API client implementation.
   #[cfg_attr(feature = "full", ts(optional))]
   pub expires: Option<i64>,
+  /// Where or how the banned person can appeal the ban, eg a url or a message. It is shown to
+  /// them together with the reason when they try to post in the community.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub appeal: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -189,10 +260,69 @@ pub struct EditCommunity {
   /// Whether to restrict posting only to moderators.
   #[cfg_attr(feature = "full", ts(optional))]
   pub posting_restricted_to_mods: Option<bool>,
+  /// Whether anyone can export the comments of posts, not only moderators.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub comment_export_public: Option<bool>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub discussion_languages: Option<Vec<LanguageId>>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub visibility: Option<CommunityVisibility>,
+  /// Whether text recognized in uploaded images is used to find posts in search.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub image_text_search: Option<bool>,
+  /// Minimum reputation of users to post and comment in the community. A negative value
+  /// removes the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub posting_min_reputation: Option<i64>,
+  /// Sent as a private message to people who start following the community.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub welcome_message: Option<String>,
+  /// Whether new posts of members wait for approval by a moderator.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub posts_require_approval: Option<bool>,
+  /// Ban people automatically once their active warning strikes reach this number. A negative
+  /// value removes the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub warning_ban_strikes: Option<i32>,
+  /// The length of automatic bans for too many warning strikes.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub warning_ban_days: Option<i32>,
+  /// New accounts must be at least this many days old for the restricted actions. A negative
+  /// value removes the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_min_age_days: Option<i32>,
+  /// New accounts must have written at least this many comments for the restricted actions. A
+  /// negative value removes the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_min_comments: Option<i32>,
+  /// New accounts must have a verified email for the restricted actions.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_require_verified_email: Option<bool>,
+  /// The actions which are restricted until an account meets the thresholds.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_restrictions: Option<Vec<NewAccountRestriction>>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Sets the sidebar and description of a community in another language. Without both of them, the
+/// translation is removed.
+pub struct EditCommunityDescriptionTranslation {
+  pub community_id: CommunityId,
+  pub language_id: LanguageId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub sidebar: Option<String>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct CommunityDescriptionTranslationsResponse {
+  pub description_translations: Vec<CommunityDescriptionTranslation>,
 }
 
 #[skip_serializing_none]
@@ -207,6 +337,15 @@ pub struct HideCommunity {
   pub reason: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Ends the soft launch of a new community before it reached the required number of posts, so
+/// that it shows up in Local/All listings and search.
+pub struct LaunchCommunity {
+  pub community_id: CommunityId,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
@@ -257,6 +396,41 @@ pub struct BlockCommunityResponse {
   pub blocked: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Show fewer posts from a community in discovery listings, without blocking it.
+pub struct SuppressCommunityRecommendation {
+  pub community_id: CommunityId,
+  pub suppress: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Remember the sort and listing mode for a community, so that clients can restore them when the
+/// community is opened again. Empty values fall back to the defaults of the user.
+pub struct SaveCommunitySettings {
+  pub community_id: CommunityId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub post_sort_type: Option<PostSortType>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub post_listing_mode: Option<PostListingMode>,
+  /// Show bot content in this community even if you hide or down-rank bots elsewhere, or hide it
+  /// only here.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub show_bot_accounts: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The saved community settings.
+pub struct CommunitySettingsResponse {
+  pub settings: LocalUserCommunitySettings,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -266,6 +440,68 @@ pub struct TransferCommunity {
   pub person_id: PersonId,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Rename a local community. The old name keeps resolving to the community, so that existing
+/// links and webfinger lookups still work.
+pub struct RenameCommunity {
+  pub community_id: CommunityId,
+  pub name: String,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Reserve a name so that no community can be created with it, or release the reservation.
+pub struct ReserveCommunityName {
+  pub name: String,
+  pub reserved: bool,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The community names which are reserved by admins.
+pub struct ListReservedCommunityNamesResponse {
+  pub reserved_names: Vec<CommunityNameAlias>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Block a domain, including its subdomains, as post link in a community, or unblock it. Only
+/// for moderators.
+pub struct BlockCommunityDomain {
+  pub community_id: CommunityId,
+  pub domain: String,
+  pub block: bool,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The domains which posts in a community can't link to.
+pub struct ListCommunityDomainBlocks {
+  pub community_id: CommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListCommunityDomainBlocksResponse {
+  pub domain_blocks: Vec<CommunityDomainBlock>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
@@ -322,3 +558,347 @@ pub struct ApproveCommunityPendingFollower {
   pub follower_id: PersonId,
   pub approve: bool,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Allow a bot account to perform moderation actions in a community. The bot acts with your
+/// authority, until the delegation is revoked or you stop being a moderator.
+///
+/// The response contains a token, which the bot needs to send instead of its login for the
+/// given scopes.
+pub struct CreateCommunityBotDelegation {
+  pub community_id: CommunityId,
+  pub bot_person_id: PersonId,
+  pub scopes: Vec<CommunityBotScope>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Change the scopes of a bot delegation. The token stays the same.
+pub struct EditCommunityBotDelegation {
+  pub id: CommunityBotDelegationId,
+  pub scopes: Vec<CommunityBotScope>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Revoke a bot delegation. This takes effect immediately.
+pub struct RevokeCommunityBotDelegation {
+  pub id: CommunityBotDelegationId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct CommunityBotDelegationResponse {
+  pub delegation: CommunityBotDelegation,
+  /// The token which the bot authenticates with. Only returned when creating the delegation.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the bot delegations of a community, including revoked ones.
+pub struct ListCommunityBotDelegations {
+  pub community_id: CommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListCommunityBotDelegationsResponse {
+  pub delegations: Vec<CommunityBotDelegation>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Make someone a trusted contributor of a community, or a regular member again. Only the owner
+/// can do this, moderators are added through the moderator list.
+pub struct SetCommunityRole {
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+  pub role: CommunityRole,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// What each role is allowed to do in a community.
+pub struct ListCommunityRolePermissions {
+  pub community_id: CommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Change what a role is allowed to do in a community. Only the owner can do this.
+pub struct EditCommunityRolePermission {
+  pub community_id: CommunityId,
+  pub role: CommunityRole,
+  pub can_feature_posts: bool,
+  pub can_remove_comments: bool,
+  pub can_edit_sidebar: bool,
+  /// Whether the role can see who voted on posts and comments, for example to detect brigading.
+  pub can_view_votes: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListCommunityRolePermissionsResponse {
+  pub permissions: Vec<CommunityRolePermission>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The audit log of actions which bots performed in a community.
+pub struct ListCommunityBotActions {
+  pub community_id: CommunityId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page: Option<i64>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListCommunityBotActionsResponse {
+  pub actions: Vec<CommunityBotAction>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a ban list which can be shared between the communities of a mod team.
+pub struct CreateBanList {
+  pub name: String,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub description: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a ban list.
+pub struct EditBanList {
+  pub ban_list_id: BanListId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub name: Option<String>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct BanListResponse {
+  pub ban_list: BanList,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Subscribe a community to a ban list, so that all bans on the list are applied to it.
+/// Unsubscribing lifts the bans which the list applied.
+pub struct SubscribeBanList {
+  pub ban_list_id: BanListId,
+  pub community_id: CommunityId,
+  pub subscribe: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Add a person to a ban list, or remove them from it. The ban is applied to all subscribed
+/// communities.
+pub struct BanFromBanList {
+  pub ban_list_id: BanListId,
+  pub person_id: PersonId,
+  pub ban: bool,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+  /// A time that the ban will expire, in unix epoch seconds.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub expires: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Opt a community out of a single ban on a ban list it is subscribed to.
+pub struct ExemptFromBanList {
+  pub ban_list_id: BanListId,
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+  pub exempt: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List all ban lists, or the ones a community is subscribed to.
+pub struct ListBanLists {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListBanListsResponse {
+  pub ban_lists: Vec<BanList>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get a ban list with its subscribed communities, bans and exemptions.
+pub struct GetBanList {
+  pub ban_list_id: BanListId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct GetBanListResponse {
+  pub ban_list: BanList,
+  pub communities: Vec<BanListCommunity>,
+  pub entries: Vec<BanListEntry>,
+  pub exemptions: Vec<BanListExemption>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Agree to apply the bans and removals of a remote partner community which covers the same
+/// topic. Bans are applied to the same person, and removals to cross-posts of the same link.
+pub struct CreateCommunityMirror {
+  pub community_id: CommunityId,
+  pub partner_community_id: CommunityId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub mirror_bans: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub mirror_removals: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Change or disable a mirror agreement.
+pub struct EditCommunityMirror {
+  pub id: CommunityMirrorId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub mirror_bans: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub mirror_removals: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct CommunityMirrorResponse {
+  pub mirror: CommunityMirror,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the mirror agreements of a community, including disabled ones.
+pub struct ListCommunityMirrors {
+  pub community_id: CommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListCommunityMirrorsResponse {
+  pub mirrors: Vec<CommunityMirror>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The audit log of bans and removals which were mirrored into a community.
+pub struct ListCommunityMirrorActions {
+  pub community_id: CommunityId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page: Option<i64>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListCommunityMirrorActionsResponse {
+  pub actions: Vec<CommunityMirrorAction>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a tag which can be attached to the posts of a community. Only for mods.
+pub struct CreateCommunityPostTag {
+  pub community_id: CommunityId,
+  pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Rename a post tag.
+pub struct EditCommunityPostTag {
+  pub tag_id: CommunityPostTagId,
+  pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a post tag. It is also removed from all posts.
+pub struct DeleteCommunityPostTag {
+  pub tag_id: CommunityPostTagId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct CommunityPostTagResponse {
+  pub tag: CommunityPostTag,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the post tags of a community.
+pub struct ListCommunityPostTags {
+  pub community_id: CommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListCommunityPostTagsResponse {
+  pub tags: Vec<CommunityPostTag>,
+}
@@ -0,0 +1,72 @@
+use crate::{context::LemmyContext, utils::check_user_valid};
+use actix_web::{dev::Payload, FromRequest, HttpMessage, HttpRequest};
+use lemmy_db_schema::{sensitive::SensitiveString, source::community_bot::CommunityBotDelegation};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
+use std::future::{ready, Ready};
+use uuid::Uuid;
+
+/// Tokens of community bots are told apart from login tokens by this prefix.
+pub const COMMUNITY_BOT_TOKEN_PREFIX: &str = "lemmy_bot_";
+
+pub fn generate_community_bot_token() -> SensitiveString {
+  format!("{COMMUNITY_BOT_TOKEN_PREFIX}{}", Uuid::new_v4()).into()
+}
+
+/// A request which a bot authenticated with the token of a community bot delegation. This doesn't
+/// log the bot in, so it can only be used for [ModerationActor].
+#[derive(Clone, Debug)]
+pub struct CommunityBotToken {
+  pub local_user_view: LocalUserView,
+  pub delegation: CommunityBotDelegation,
+}
+
+impl CommunityBotToken {
+  /// Returns nothing if the token doesn't exist or was revoked.
+  pub async fn read(token: &str, context: &LemmyContext) -> LemmyResult<Option<Self>> {
+    let Some(delegation) =
+      CommunityBotDelegation::read_from_token(&mut context.pool(), token).await?
+    else {
+      return Ok(None);
+    };
+    let local_user_view =
+      LocalUserView::read_person(&mut context.pool(), delegation.bot_person_id).await?;
+    check_user_valid(&local_user_view.person)?;
+    Ok(Some(CommunityBotToken {
+      local_user_view,
+      delegation,
+    }))
+  }
+}
+
+/// Who performs a moderation action which community bots are allowed to do. This is either a
+/// logged in user, or a bot with a delegation token. The scopes of the token need to be checked
+/// with [crate::utils::check_community_mod_action_or_bot].
+#[derive(Clone, Debug)]
+pub struct ModerationActor {
+  pub local_user_view: LocalUserView,
+  pub bot_delegation: Option<CommunityBotDelegation>,
+}
+
+impl FromRequest for ModerationActor {
+  type Error = LemmyError;
+  type Future = Ready<Result<Self, Self::Error>>;
+
+  fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+    let extensions = req.extensions();
+    let actor = if let Some(local_user_view) = extensions.get::<LocalUserView>() {
+      Ok(ModerationActor {
+        local_user_view: local_user_view.clone(),
+        bot_delegation: None,
+      })
+    } else if let Some(token) = extensions.get::<CommunityBotToken>() {
+      Ok(ModerationActor {
+        local_user_view: token.local_user_view.clone(),
+        bot_delegation: Some(token.delegation.clone()),
+      })
+    } else {
+      Err(LemmyErrorType::IncorrectLogin.into())
+    };
+    ready(actor)
+  }
+}
@@ -0,0 +1,103 @@
+use lemmy_db_schema::{newtypes::CommunityRequestId, CommunityRequestStatus};
+use lemmy_db_views::structs::{CommunityRequestCommentView, CommunityRequestView};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Request a new community, for instances where only admins can create communities.
+pub struct CreateCommunityRequest {
+  /// The unique name.
+  pub name: String,
+  /// A longer title.
+  pub title: String,
+  /// What the community would be about.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get a community request, with its comments.
+pub struct GetCommunityRequest {
+  pub id: CommunityRequestId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Fetches a list of community requests.
+pub struct ListCommunityRequests {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub status: Option<CommunityRequestStatus>,
+  /// Show the requests with the most votes first, instead of the newest.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub sort_by_score: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page: Option<i64>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Vote for or against a community request.
+pub struct VoteCommunityRequest {
+  pub community_request_id: CommunityRequestId,
+  /// Score must be -1, 0, or 1.
+  pub score: i16,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Comment on a community request.
+pub struct CreateCommunityRequestComment {
+  pub community_request_id: CommunityRequestId,
+  pub content: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Approve or deny a community request. Approving creates the community, with the requester as
+/// moderator.
+pub struct ApproveCommunityRequest {
+  pub id: CommunityRequestId,
+  pub approve: bool,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub deny_reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A community request response.
+pub struct CommunityRequestResponse {
+  pub community_request_view: CommunityRequestView,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A community request, with its comments.
+pub struct GetCommunityRequestResponse {
+  pub community_request_view: CommunityRequestView,
+  pub comments: Vec<CommunityRequestCommentView>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A list of community requests.
+pub struct ListCommunityRequestsResponse {
+  pub community_requests: Vec<CommunityRequestView>,
+}
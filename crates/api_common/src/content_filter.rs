@@ -0,0 +1,345 @@
+use chrono::Utc;
+use lemmy_db_schema::{
+  newtypes::{CommunityId, DbUrl},
+  source::{
+    comment::Comment,
+    comment_report::{CommentReport, CommentReportForm},
+    filter_rule::FilterRule,
+    moderator::{ModRemoveComment, ModRemoveCommentForm, ModRemovePost, ModRemovePostForm},
+    post::Post,
+    post_report::{PostReport, PostReportForm},
+  },
+  traits::{Crud, Reportable},
+  utils::DbPool,
+  FilterRuleAction,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::expression::Expression,
+};
+use regex::{Regex, RegexBuilder};
+use serde_json::{json, Map, Value};
+use std::{sync::LazyLock, time::Duration};
+use url::Url;
+
+static LINK_REGEX: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r#"https?://[^\s<>"'()\[\]]+"#).expect("compile regex"));
+
+/// Conditions are evaluated while the content is created, so they must not hold it up.
+const CONDITION_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// The filter rules which matched new or edited content. Rejecting rules never end up here, as
+/// the content is refused right away.
+#[derive(Debug, Default)]
+pub struct FilterRuleMatches {
+  rules: Vec<FilterRule>,
+}
+
+impl FilterRuleMatches {
+  /// Checks text and its author against the site wide rules and those of the community. Content
+  /// by admins is never filtered.
+  pub async fn check(
+    text: &str,
+    community_id: CommunityId,
+    local_user_view: &LocalUserView,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self> {
+    if local_user_view.local_user.admin {
+      return Ok(Self::default());
+    }
+    let author = author_data(local_user_view);
+    let rules = FilterRule::for_community(pool, community_id)
+      .await?
+      .into_iter()
+      .filter(|rule| {
+        rule_matches(rule, text)
+          && domain_matches(rule, text)
+          && author_matches(rule, local_user_view)
+          && condition_matches(rule, text, &author)
+      })
+      .collect::<Vec<_>>();
+    if rules.iter().any(|r| r.action == FilterRuleAction::Reject) {
+      Err(LemmyErrorType::ContentRejectedByFilter)?
+    }
+    Ok(Self { rules })
+  }
+
+  /// Checks the title, body and url of a post.
+  pub async fn check_post(
+    name: &str,
+    body: Option<&str>,
+    url: Option<&DbUrl>,
+    community_id: CommunityId,
+    local_user_view: &LocalUserView,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self> {
+    let url = url.map(ToString::to_string).unwrap_or_default();
+    let text = format!("{name}\n{}\n{url}", body.unwrap_or_default());
+    Self::check(&text, community_id, local_user_view, pool).await
+  }
+
+  fn any(&self, action: FilterRuleAction) -> bool {
+    self.rules.iter().any(|r| r.action == action)
+  }
+
+  /// Whether new content should be held back for review.
+  pub fn flags(&self) -> bool {
+    self.any(FilterRuleAction::Flag)
+  }
+
+  /// Whether new content should be removed right away.
+  pub fn removes(&self) -> bool {
+    self.any(FilterRuleAction::Remove)
+  }
+
+  /// Whether a new post should wait for approval by a moderator. Comments can't wait for
+  /// approval, so they are held back for review instead.
+  pub fn requires_approval(&self) -> bool {
+    self.any(FilterRuleAction::RequireApproval)
+  }
+
+  /// Whether a new post should be locked.
+  pub fn locks(&self) -> bool {
+    self.any(FilterRuleAction::Lock)
+  }
+
+  /// The rules which report the content. Rules whose action wasn't applied report the content
+  /// instead: flagging rules for content which wasn't held back, locking rules for comments, and
+  /// all other actions for edits.
+  fn reporting(&self, held: bool, new: bool, is_post: bool) -> impl Iterator<Item = &FilterRule> {
+    self.rules.iter().filter(move |r| match r.action {
+      FilterRuleAction::Reject => false,
+      FilterRuleAction::Report => true,
+      FilterRuleAction::Flag => !held,
+      FilterRuleAction::Remove | FilterRuleAction::RequireApproval => !new,
+      FilterRuleAction::Lock => !(new && is_post),
+    })
+  }
+
+  /// The removing rules, if the content is new.
+  fn removing(&self, new: bool) -> impl Iterator<Item = &FilterRule> {
+    self
+      .rules
+      .iter()
+      .filter(move |r| new && r.action == FilterRuleAction::Remove)
+  }
+
+  /// Reports the post in the name of each rule creator, and adds removals by rules to the
+  /// modlog. Reports which already exist are skipped.
+  pub async fn report_post(&self, post: &Post, held: bool, pool: &mut DbPool<'_>) {
+    let new = post.updated.is_none();
+    for rule in self.removing(new) {
+      let form = ModRemovePostForm {
+        mod_person_id: rule.creator_id,
+        post_id: post.id,
+        removed: Some(true),
+        reason: Some(report_reason(rule)),
+        automod: false,
+      };
+      ModRemovePost::create(pool, &form).await.ok();
+    }
+    for rule in self.reporting(held, new, true) {
+      let form = PostReportForm {
+        creator_id: rule.creator_id,
+        post_id: post.id,
+        original_post_name: post.name.clone(),
+        original_post_url: post.url.clone(),
+        original_post_body: post.body.clone(),
+        reason: report_reason(rule),
+      };
+      PostReport::report(pool, &form).await.ok();
+    }
+  }
+
+  /// Reports the comment in the name of each rule creator, and adds removals by rules to the
+  /// modlog. Reports which already exist are skipped.
+  pub async fn report_comment(&self, comment: &Comment, held: bool, pool: &mut DbPool<'_>) {
+    let new = comment.updated.is_none();
+    for rule in self.removing(new) {
+      let form = ModRemoveCommentForm {
+        mod_person_id: rule.creator_id,
+        comment_id: comment.id,
+        removed: Some(true),
+        reason: Some(report_reason(rule)),
+        automod: false,
+      };
+      ModRemoveComment::create(pool, &form).await.ok();
+    }
+    for rule in self.reporting(held, new, false) {
+      let form = CommentReportForm {
+        creator_id: rule.creator_id,
+        comment_id: comment.id,
+        original_comment_text: comment.content.clone(),
+        reason: report_reason(rule),
+        severity: None,
+        category_id: None,
+      };
+      CommentReport::report(pool, &form).await.ok();
+    }
+  }
+}
+
+/// Keywords match anywhere in the text, both keywords and regexes ignore case. An empty keyword
+/// matches any text.
+fn rule_matches(rule: &FilterRule, text: &str) -> bool {
+  let pattern = if rule.is_regex {
+    rule.pattern.clone()
+  } else {
+    regex::escape(&rule.pattern)
+  };
+  RegexBuilder::new(&pattern)
+    .case_insensitive(true)
+    .build()
+    .is_ok_and(|regex| regex.is_match(text))
+}
+
+/// All conditions of the rule about the author have to hold. Rules without conditions match
+/// everyone.
+fn author_matches(rule: &FilterRule, local_user_view: &LocalUserView) -> bool {
+  let account_age_days = (Utc::now() - local_user_view.person.published).num_days();
+  let too_old = rule
+    .max_account_age_days
+    .is_some_and(|max| account_age_days >= i64::from(max));
+  let reputation_too_high = rule
+    .max_reputation
+    .is_some_and(|max| local_user_view.counts.reputation > max);
+  !too_old && !reputation_too_high
+}
+
+/// Whether the text links to the domain of the rule, or one of its subdomains. Rules without a
+/// domain match any text.
+fn domain_matches(rule: &FilterRule, text: &str) -> bool {
+  let Some(domain) = &rule.link_domain else {
+    return true;
+  };
+  LINK_REGEX
+    .find_iter(text)
+    .filter_map(|link| Url::parse(link.as_str()).ok())
+    .filter_map(|url| url.host_str().map(str::to_lowercase))
+    .any(|host| host == *domain || host.ends_with(&format!(".{domain}")))
+}
+
+/// The author as seen by conditions.
+fn author_data(local_user_view: &LocalUserView) -> Value {
+  json!({
+    "name": local_user_view.person.name,
+    "account_age_days": (Utc::now() - local_user_view.person.published).num_days(),
+    "reputation": local_user_view.counts.reputation,
+    "post_count": local_user_view.counts.post_count,
+    "comment_count": local_user_view.counts.comment_count,
+  })
+}
+
+/// Whether the condition of the rule is true for the text and its author. Like broken regexes,
+/// conditions which fail or time out never match. Rules without a condition match any text.
+fn condition_matches(rule: &FilterRule, text: &str, author: &Value) -> bool {
+  let Some(condition) = &rule.condition else {
+    return true;
+  };
+  let mut variables = Map::new();
+  variables.insert("text".to_string(), Value::String(text.to_string()));
+  variables.insert("author".to_string(), author.clone());
+  Expression::parse(condition)
+    .and_then(|expression| expression.evaluate_bool(&variables, CONDITION_TIMEOUT))
+    .unwrap_or(false)
+}
+
+fn report_reason(rule: &FilterRule) -> String {
+  let mut conditions = vec![];
+  if !rule.pattern.is_empty() {
+    conditions.push(rule.pattern.clone());
+  }
+  if let Some(domain) = &rule.link_domain {
+    conditions.push(format!("links to {domain}"));
+  }
+  if let Some(days) = rule.max_account_age_days {
+    conditions.push(format!("account younger than {days} days"));
+  }
+  if let Some(reputation) = rule.max_reputation {
+    conditions.push(format!("reputation at most {reputation}"));
+  }
+  if let Some(condition) = &rule.condition {
+    conditions.push(condition.clone());
+  }
+  format!("Matched filter rule: {}", conditions.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use lemmy_db_schema::newtypes::{FilterRuleId, PersonId};
+
+  fn rule(pattern: &str, is_regex: bool) -> FilterRule {
+    FilterRule {
+      id: FilterRuleId::default(),
+      community_id: None,
+      pattern: pattern.to_string(),
+      is_regex,
+      action: FilterRuleAction::Flag,
+      creator_id: PersonId(1),
+      published: Utc::now(),
+      updated: None,
+      link_domain: None,
+      max_account_age_days: None,
+      max_reputation: None,
+      condition: None,
+    }
+  }
+
+  #[test]
+  fn test_rule_matches() {
+    assert!(rule_matches(&rule("c++", false), "I like C++ a lot"));
+    assert!(!rule_matches(&rule("c++", false), "I like c a lot"));
+    assert!(rule_matches(
+      &rule(r"\bfree money\b", true),
+      "Get FREE money now"
+    ));
+    assert!(!rule_matches(&rule(r"^free", true), "not free"));
+    // Broken regexes never match
+    assert!(!rule_matches(&rule("(unclosed", true), "(unclosed"));
+    // Rules without pattern only check their conditions
+    assert!(rule_matches(&rule("", false), "anything"));
+  }
+
+  #[test]
+  fn test_domain_matches() {
+    let domain_rule = FilterRule {
+      link_domain: Some("example.com".to_string()),
+      ..rule("", false)
+    };
+    assert!(domain_matches(
+      &domain_rule,
+      "see [this](https://www.Example.com/page)"
+    ));
+    assert!(domain_matches(&domain_rule, "http://example.com"));
+    assert!(!domain_matches(&domain_rule, "https://notexample.com/page"));
+    assert!(!domain_matches(&domain_rule, "example.com without link"));
+    assert!(domain_matches(&rule("", false), "no links at all"));
+  }
+
+  #[test]
+  fn test_condition_matches() {
+    let condition_rule = FilterRule {
+      condition: Some("author.account_age_days < 2 && text.contains('crypto')".to_string()),
+      ..rule("", false)
+    };
+    let author = json!({"name": "new_user", "account_age_days": 1, "reputation": 0});
+    assert!(condition_matches(&condition_rule, "Buy crypto", &author));
+    assert!(!condition_matches(&condition_rule, "Buy shoes", &author));
+    let old_author = json!({"name": "old_user", "account_age_days": 30, "reputation": 0});
+    assert!(!condition_matches(
+      &condition_rule,
+      "Buy crypto",
+      &old_author
+    ));
+
+    // Conditions which don't return a boolean never match
+    let broken_rule = FilterRule {
+      condition: Some("size(text)".to_string()),
+      ..rule("", false)
+    };
+    assert!(!condition_matches(&broken_rule, "anything", &author));
+    assert!(condition_matches(&rule("", false), "anything", &author));
+  }
+}
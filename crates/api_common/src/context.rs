@@ -5,6 +5,7 @@ use lemmy_db_schema::{
   utils::{build_db_pool_for_tests, ActualDbPool, DbPool},
 };
 use lemmy_utils::{
+  clock::{Clock, SharedClock, SystemClock},
   rate_limit::RateLimitCell,
   settings::{structs::Settings, SETTINGS},
 };
@@ -17,6 +18,7 @@ pub struct LemmyContext {
   client: Arc<ClientWithMiddleware>,
   secret: Arc<Secret>,
   rate_limit_cell: RateLimitCell,
+  clock: SharedClock,
 }
 
 impl LemmyContext {
@@ -30,6 +32,7 @@ impl LemmyContext {
       pool,
       client: Arc::new(client),
       secret: Arc::new(secret),
+      clock: rate_limit_cell.clock().clone(),
       rate_limit_cell,
     }
   }
@@ -51,11 +54,23 @@ impl LemmyContext {
   pub fn rate_limit_cell(&self) -> &RateLimitCell {
     &self.rate_limit_cell
   }
+  /// Use this instead of `Utc::now()` or `now()` in queries, so that tests can control the time.
+  pub fn clock(&self) -> &dyn Clock {
+    self.clock.as_ref()
+  }
 
   /// Initialize a context for use in tests which blocks federation network calls.
   ///
   /// Do not use this in production code.
   pub async fn init_test_federation_config() -> FederationConfig<LemmyContext> {
+    Self::init_test_federation_config_with_clock(Arc::new(SystemClock)).await
+  }
+
+  /// Same as [LemmyContext::init_test_federation_config], but using the given clock, for example
+  /// a `FakeClock`.
+  pub async fn init_test_federation_config_with_clock(
+    clock: SharedClock,
+  ) -> FederationConfig<LemmyContext> {
     // call this to run migrations
     let pool = build_db_pool_for_tests();
 
@@ -67,7 +82,7 @@ impl LemmyContext {
       jwt_secret: String::new().into(),
     };
 
-    let rate_limit_cell = RateLimitCell::with_test_config();
+    let rate_limit_cell = RateLimitCell::with_test_config_and_clock(clock);
 
     let context = LemmyContext::create(pool, client, secret, rate_limit_cell.clone());
 
@@ -85,4 +100,8 @@ impl LemmyContext {
     let config = Self::init_test_federation_config().await;
     config.to_request_data()
   }
+  pub async fn init_test_context_with_clock(clock: SharedClock) -> Data<LemmyContext> {
+    let config = Self::init_test_federation_config_with_clock(clock).await;
+    config.to_request_data()
+  }
 }
@@ -0,0 +1,85 @@
+use lemmy_db_schema::{
+  newtypes::{CommunityId, CustomFeedId},
+  source::{community::Community, custom_feed::CustomFeed},
+};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a custom feed, which combines the posts of several communities.
+pub struct CreateCustomFeed {
+  pub name: String,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub description: Option<String>,
+  /// Public feeds can be viewed by anyone.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub public: Option<bool>,
+  /// A unique name for the feed, to share it with a url.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub slug: Option<String>,
+  pub community_ids: Vec<CommunityId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a custom feed.
+pub struct EditCustomFeed {
+  pub id: CustomFeedId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub name: Option<String>,
+  /// An empty string removes the description.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub description: Option<String>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub public: Option<bool>,
+  /// An empty string removes the slug.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub slug: Option<String>,
+  /// Replaces the communities of the feed.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_ids: Option<Vec<CommunityId>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a custom feed.
+pub struct DeleteCustomFeed {
+  pub id: CustomFeedId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Fetches a custom feed by id or slug. Private feeds can only be fetched by their creator.
+pub struct GetCustomFeed {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub id: Option<CustomFeedId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub slug: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A custom feed with its communities. Use the feed id with `GetPosts` to list its posts.
+pub struct CustomFeedResponse {
+  pub custom_feed: CustomFeed,
+  pub communities: Vec<Community>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The custom feeds of the user.
+pub struct ListCustomFeedsResponse {
+  pub custom_feeds: Vec<CustomFeed>,
+}
@@ -0,0 +1,169 @@
+//! Sidebars and descriptions of communities and the site can be translated into multiple
+//! languages. Readers get the translation into their preferred language, or else the original.
+
+use crate::{
+  context::LemmyContext,
+  utils::{get_url_blocklist, local_site_to_slur_regex, process_markdown_opt},
+};
+use actix_web::HttpRequest;
+use lemmy_db_schema::{
+  newtypes::LanguageId,
+  source::{
+    community::Community,
+    description_translation::{CommunityDescriptionTranslation, SiteDescriptionTranslation},
+    language::Language,
+    local_site::LocalSite,
+    site::Site,
+  },
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::{
+    language::accept_language_tags,
+    slurs::check_slurs_opt,
+    validation::{is_valid_body_field, site_or_community_description_length_check},
+  },
+};
+
+/// Languages which the user prefers to read, starting with the interface language and followed by
+/// the ones from the `Accept-Language` header.
+pub async fn preferred_language_ids(
+  local_user_view: Option<&LocalUserView>,
+  req: &HttpRequest,
+  context: &LemmyContext,
+) -> LemmyResult<Vec<LanguageId>> {
+  let codes: Vec<String> = local_user_view
+    .map(|l| l.local_user.interface_language.clone())
+    .filter(|l| l != "browser")
+    .into_iter()
+    .chain(accept_language_tags(req))
+    .collect();
+  if codes.is_empty() {
+    return Ok(vec![]);
+  }
+
+  let all_languages = Language::read_all(&mut context.pool()).await?;
+  Ok(
+    codes
+      .iter()
+      // Interface languages may contain a region or script, eg `pt_BR` or `zh_Hant`
+      .filter_map(|code| code.split('_').next())
+      .filter_map(|code| all_languages.iter().find(|l| l.code == code))
+      .map(|l| l.id)
+      .collect(),
+  )
+}
+
+/// Replaces the sidebar and description of the community with the translation into the first
+/// preferred language which has one.
+pub fn localize_community(
+  community: &mut Community,
+  translations: &[CommunityDescriptionTranslation],
+  language_ids: &[LanguageId],
+) {
+  if let Some(t) = preferred_translation(translations, language_ids, |t| t.language_id) {
+    localize(&mut community.sidebar, &t.sidebar);
+    localize(&mut community.description, &t.description);
+  }
+}
+
+/// Replaces the sidebar and description of the site with the translation into the first
+/// preferred language which has one.
+pub fn localize_site(
+  site: &mut Site,
+  translations: &[SiteDescriptionTranslation],
+  language_ids: &[LanguageId],
+) {
+  if let Some(t) = preferred_translation(translations, language_ids, |t| t.language_id) {
+    localize(&mut site.sidebar, &t.sidebar);
+    localize(&mut site.description, &t.description);
+  }
+}
+
+/// Checks a translated sidebar and description in the same way as the original ones, and returns
+/// the processed sidebar.
+pub async fn process_description_translation(
+  sidebar: &Option<String>,
+  description: &Option<String>,
+  context: &LemmyContext,
+) -> LemmyResult<Option<String>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let slur_regex = local_site_to_slur_regex(&local_site);
+  let url_blocklist = get_url_blocklist(context).await?;
+
+  let sidebar = process_markdown_opt(sidebar, &slur_regex, &url_blocklist, context).await?;
+  if let Some(sidebar) = &sidebar {
+    is_valid_body_field(sidebar, false)?;
+  }
+  if let Some(description) = description {
+    site_or_community_description_length_check(description)?;
+  }
+  check_slurs_opt(description, &slur_regex)?;
+  Ok(sidebar)
+}
+
+fn preferred_translation<'a, T>(
+  translations: &'a [T],
+  language_ids: &[LanguageId],
+  language_id: fn(&T) -> LanguageId,
+) -> Option<&'a T> {
+  language_ids
+    .iter()
+    .find_map(|id| translations.iter().find(|t| language_id(t) == *id))
+}
+
+/// Fields which aren't translated keep the original text.
+fn localize(original: &mut Option<String>, translation: &Option<String>) {
+  if translation.is_some() {
+    original.clone_from(translation);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::Utc;
+  use lemmy_db_schema::newtypes::CommunityId;
+  use pretty_assertions::assert_eq;
+
+  fn translation(language_id: i32, description: &str) -> CommunityDescriptionTranslation {
+    CommunityDescriptionTranslation {
+      community_id: CommunityId(1),
+      language_id: LanguageId(language_id),
+      sidebar: None,
+      description: Some(description.to_string()),
+      published: Utc::now(),
+      updated: None,
+    }
+  }
+
+  #[test]
+  fn test_preferred_translation() {
+    let translations = [
+      translation(2, "Beschreibung"),
+      translation(3, "Description"),
+    ];
+    let language_id = |t: &CommunityDescriptionTranslation| t.language_id;
+
+    let preferred = preferred_translation(
+      &translations,
+      &[LanguageId(5), LanguageId(3), LanguageId(2)],
+      language_id,
+    );
+    assert_eq!(translations.get(1), preferred);
+    assert_eq!(
+      None,
+      preferred_translation(&translations, &[LanguageId(5)], language_id)
+    );
+  }
+
+  #[test]
+  fn test_localize() {
+    let mut sidebar = Some("Sidebar".to_string());
+    localize(&mut sidebar, &None);
+    assert_eq!(Some("Sidebar".to_string()), sidebar);
+    localize(&mut sidebar, &Some("Seitenleiste".to_string()));
+    assert_eq!(Some("Seitenleiste".to_string()), sidebar);
+  }
+}
@@ -0,0 +1,136 @@
+use crate::context::LemmyContext;
+use lemmy_db_schema::{
+  newtypes::LocalUserId,
+  source::{feature_flag::FeatureFlag, local_user::LocalUser},
+  FeatureFlagName,
+};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType, LemmyResult},
+  CACHE_DURATION_API,
+};
+use moka::future::Cache;
+use std::sync::LazyLock;
+
+/// Reads all feature flags, cached for a short time as they are checked on many requests.
+pub async fn get_feature_flags(context: &LemmyContext) -> LemmyResult<Vec<FeatureFlag>> {
+  static FEATURE_FLAGS: LazyLock<Cache<(), Vec<FeatureFlag>>> = LazyLock::new(|| {
+    Cache::builder()
+      .max_capacity(1)
+      .time_to_live(CACHE_DURATION_API)
+      .build()
+  });
+
+  Ok(
+    FEATURE_FLAGS
+      .try_get_with::<_, LemmyError>((), async {
+        Ok(FeatureFlag::list(&mut context.pool()).await?)
+      })
+      .await
+      .map_err(|e| anyhow::anyhow!("Failed to read feature flags due to `{}`", e))?,
+  )
+}
+
+/// Whether the feature is enabled for the given user. During a partial rollout each local user
+/// lands in a fixed bucket per feature, so that the feature doesn't flicker on and off between
+/// requests. Anonymous users only get features which are rolled out to everyone.
+pub fn is_feature_enabled(flag: &FeatureFlag, local_user: Option<&LocalUser>) -> bool {
+  if !flag.enabled {
+    return false;
+  }
+  match local_user {
+    Some(local_user) => rollout_bucket(flag.feature, local_user.id) < flag.rollout_percentage,
+    None => flag.rollout_percentage >= 100,
+  }
+}
+
+/// All features which are enabled for the given user, so that clients can adapt their UI.
+pub fn enabled_features(
+  flags: &[FeatureFlag],
+  local_user: Option<&LocalUser>,
+) -> Vec<FeatureFlagName> {
+  flags
+    .iter()
+    .filter(|f| is_feature_enabled(f, local_user))
+    .map(|f| f.feature)
+    .collect()
+}
+
+/// Returns an error if the feature is not enabled for the given user.
+pub async fn check_feature_enabled(
+  feature: FeatureFlagName,
+  local_user: Option<&LocalUser>,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let enabled = get_feature_flags(context)
+    .await?
+    .iter()
+    .any(|f| f.feature == feature && is_feature_enabled(f, local_user));
+  if enabled {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::FeatureDisabled)?
+  }
+}
+
+/// Stable bucket between 0 and 99 for the user. Uses FNV-1a because the std hasher is randomly
+/// seeded per process, which would move users between buckets on every restart.
+fn rollout_bucket(feature: FeatureFlagName, local_user_id: LocalUserId) -> i16 {
+  let key = format!("{feature}:{}", local_user_id.0);
+  let hash = key.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+    (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+  });
+  // The remainder is always below 100, so the conversion can't fail.
+  i16::try_from(hash % 100).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::Utc;
+  use pretty_assertions::assert_eq;
+
+  fn flag(enabled: bool, rollout_percentage: i16) -> FeatureFlag {
+    FeatureFlag {
+      feature: FeatureFlagName::Reactions,
+      enabled,
+      rollout_percentage,
+      published: Utc::now(),
+      updated: None,
+    }
+  }
+
+  fn enabled_user_count(flag: &FeatureFlag) -> usize {
+    (1..=1000)
+      .map(|id| LocalUser {
+        id: LocalUserId(id),
+        ..Default::default()
+      })
+      .filter(|u| is_feature_enabled(flag, Some(u)))
+      .count()
+  }
+
+  #[test]
+  fn test_rollout_percentage() {
+    assert_eq!(0, enabled_user_count(&flag(false, 100)));
+    assert_eq!(0, enabled_user_count(&flag(true, 0)));
+    assert_eq!(1000, enabled_user_count(&flag(true, 100)));
+    let partial = enabled_user_count(&flag(true, 30));
+    assert!((200..400).contains(&partial), "{partial}");
+  }
+
+  #[test]
+  fn test_rollout_is_stable() {
+    let bucket = rollout_bucket(FeatureFlagName::Polls, LocalUserId(42));
+    assert_eq!(
+      bucket,
+      rollout_bucket(FeatureFlagName::Polls, LocalUserId(42))
+    );
+    assert!((0..100).contains(&bucket));
+  }
+
+  #[test]
+  fn test_anonymous_only_full_rollout() {
+    assert!(!is_feature_enabled(&flag(true, 99), None));
+    assert!(is_feature_enabled(&flag(true, 100), None));
+  }
+}
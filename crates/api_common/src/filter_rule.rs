@@ -0,0 +1,97 @@
+use lemmy_db_schema::{
+  newtypes::{CommunityId, FilterRuleId},
+  source::filter_rule::FilterRule,
+  FilterRuleAction,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a filter rule. Without a community, only admins can do this. The pattern can be empty
+/// if the rule has at least one other condition.
+pub struct CreateFilterRule {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  pub pattern: String,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub is_regex: Option<bool>,
+  pub action: FilterRuleAction,
+  /// Only match content linking to this domain or its subdomains.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub link_domain: Option<String>,
+  /// Only match content by accounts younger than this.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub max_account_age_days: Option<i32>,
+  /// Only match content by people with at most this reputation.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub max_reputation: Option<i64>,
+  /// Only match content for which this expression is true. The content is available as `text`,
+  /// and its author as `author` with the fields `name`, `account_age_days`, `reputation`,
+  /// `post_count` and `comment_count`.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub condition: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a filter rule.
+pub struct EditFilterRule {
+  pub id: FilterRuleId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub pattern: Option<String>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub is_regex: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub action: Option<FilterRuleAction>,
+  /// An empty string removes the condition.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub link_domain: Option<String>,
+  /// A negative value removes the condition.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub max_account_age_days: Option<i32>,
+  /// Removing this condition isn't possible, as any reputation is valid. Delete the rule instead.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub max_reputation: Option<i64>,
+  /// An empty string removes the condition.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub condition: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a filter rule.
+pub struct DeleteFilterRule {
+  pub id: FilterRuleId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct FilterRuleResponse {
+  pub filter_rule: FilterRule,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Fetches the filter rules of a community, or the site wide rules if no community is given.
+pub struct ListFilterRules {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListFilterRulesResponse {
+  pub filter_rules: Vec<FilterRule>,
+}
@@ -0,0 +1,265 @@
+//! Extension points where admin-configured hooks can allow, deny or modify an action. Hooks are
+//! either HTTP endpoints or WebAssembly modules, which can be limited to data matching a
+//! condition. A hook with only a condition denies all matching actions. External hooks receive a
+//! JSON object like `{"hook": "pre_post_create", "data": {..}}` and return one of
+//! `{"action": "allow"}`, `{"action": "deny", "reason": ".."}` or
+//! `{"action": "modify", "data": {..}}`.
+
+use crate::context::LemmyContext;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  settings::structs::{HookConfig, HookPoint},
+  utils::expression::{Expression, ExpressionError},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{
+  collections::HashMap,
+  sync::{LazyLock, Mutex},
+  time::Duration,
+};
+use tracing::warn;
+
+#[derive(Serialize)]
+struct HookRequest<'a> {
+  hook: HookPoint,
+  data: &'a Value,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "action", rename_all = "snake_case")]
+enum HookResponse {
+  Allow,
+  Deny { reason: Option<String> },
+  Modify { data: Value },
+}
+
+/// Result of calling all hooks for an extension point.
+pub enum HookOutcome<T> {
+  /// The action may proceed, with data which may have been modified by the hooks.
+  Allow(T),
+  /// A hook rejected the action, optionally with a reason.
+  Deny(Option<String>),
+}
+
+/// Calls all hooks which are configured for the extension point, in order. Each hook receives the
+/// data as modified by the previous ones.
+pub async fn call_hooks<T>(
+  point: HookPoint,
+  data: T,
+  context: &LemmyContext,
+) -> LemmyResult<HookOutcome<T>>
+where
+  T: Serialize + DeserializeOwned,
+{
+  let mut hooks = context
+    .settings()
+    .hooks
+    .iter()
+    .filter(|h| h.point == point)
+    .peekable();
+  // Skip the serialization roundtrip if there is nothing to call
+  if hooks.peek().is_none() {
+    return Ok(HookOutcome::Allow(data));
+  }
+
+  let mut value = serde_json::to_value(data)?;
+  for hook in hooks {
+    let response = match call_hook(hook, &value, context).await {
+      Ok(response) => response,
+      Err(e) => {
+        warn!("Hook for {:?} failed: {e}", point);
+        if hook.fail_closed {
+          return Ok(HookOutcome::Deny(None));
+        }
+        continue;
+      }
+    };
+    match response {
+      HookResponse::Allow => {}
+      HookResponse::Deny { reason } => return Ok(HookOutcome::Deny(reason)),
+      HookResponse::Modify { data } => value = data,
+    }
+  }
+  Ok(HookOutcome::Allow(serde_json::from_value(value)?))
+}
+
+/// Same as [call_hooks], but returns an error if a hook denies the action.
+pub async fn run_hooks<T>(point: HookPoint, data: T, context: &LemmyContext) -> LemmyResult<T>
+where
+  T: Serialize + DeserializeOwned,
+{
+  match call_hooks(point, data, context).await? {
+    HookOutcome::Allow(data) => Ok(data),
+    HookOutcome::Deny(reason) => Err(LemmyErrorType::DeniedByHook { reason })?,
+  }
+}
+
+async fn call_hook(
+  hook: &HookConfig,
+  data: &Value,
+  context: &LemmyContext,
+) -> LemmyResult<HookResponse> {
+  let request = HookRequest {
+    hook: hook.point,
+    data,
+  };
+  let timeout = Duration::from_millis(hook.timeout_ms);
+  if let Some(condition) = &hook.condition {
+    if !evaluate_expression(&parse_condition(condition)?, data, timeout)? {
+      return Ok(HookResponse::Allow);
+    }
+  }
+
+  if let Some(url) = &hook.url {
+    Ok(
+      context
+        .client()
+        .post(url.as_str())
+        .json(&request)
+        .timeout(timeout)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?,
+    )
+  } else if let Some(module) = &hook.wasm_module {
+    call_wasm_hook(module, serde_json::to_string(&request)?, timeout).await
+  } else if hook.condition.is_some() {
+    Ok(HookResponse::Deny {
+      reason: hook.deny_reason.clone(),
+    })
+  } else {
+    Err(anyhow::anyhow!(
+      "Hook needs at least one of `url`, `wasm_module` or `condition`"
+    ))?
+  }
+}
+
+/// Evaluates a hook condition against the hook data, which is available as `data`.
+pub fn evaluate_condition(
+  condition: &str,
+  data: &Value,
+  timeout: Duration,
+) -> Result<bool, ExpressionError> {
+  evaluate_expression(&Expression::parse(condition)?, data, timeout)
+}
+
+fn evaluate_expression(
+  expression: &Expression,
+  data: &Value,
+  timeout: Duration,
+) -> Result<bool, ExpressionError> {
+  let mut variables = Map::new();
+  variables.insert("data".to_string(), data.clone());
+  expression.evaluate_bool(&variables, timeout)
+}
+
+/// Parses the condition of a configured hook. Conditions only come from the config file, so each
+/// one is parsed once, which also compiles its regexes only once.
+fn parse_condition(condition: &str) -> LemmyResult<Expression> {
+  static CONDITIONS: LazyLock<Mutex<HashMap<String, Expression>>> = LazyLock::new(Default::default);
+
+  let mut conditions = CONDITIONS
+    .lock()
+    .map_err(|e| anyhow::anyhow!("Hook conditions poisoned: {e}"))?;
+  if let Some(expression) = conditions.get(condition) {
+    return Ok(expression.clone());
+  }
+  let expression = Expression::parse(condition)?;
+  conditions.insert(condition.to_string(), expression.clone());
+  Ok(expression)
+}
+
+#[cfg(feature = "wasm-hooks")]
+async fn call_wasm_hook(
+  module: &str,
+  input: String,
+  timeout: Duration,
+) -> LemmyResult<HookResponse> {
+  use extism::{Manifest, Plugin, Wasm};
+
+  // Compiling a module is expensive, so idle instances are kept for reuse. A new instance is only
+  // compiled if all existing ones are busy, so that concurrent calls don't wait for each other.
+  // Instances are separate per timeout, as it is part of the manifest.
+  static PLUGINS: LazyLock<Mutex<HashMap<(String, Duration), Vec<Plugin>>>> =
+    LazyLock::new(Default::default);
+
+  let key = (module.to_string(), timeout);
+  tokio::task::spawn_blocking(move || -> LemmyResult<HookResponse> {
+    let idle = PLUGINS
+      .lock()
+      .map_err(|e| anyhow::anyhow!("Hook plugins poisoned: {e}"))?
+      .get_mut(&key)
+      .and_then(Vec::pop);
+    let mut plugin = match idle {
+      Some(plugin) => plugin,
+      None => {
+        let manifest = Manifest::new([Wasm::file(&key.0)]).with_timeout(timeout);
+        Plugin::new(&manifest, [], true)?
+      }
+    };
+    // An instance which failed or timed out is dropped instead of being reused
+    let output = plugin.call::<&str, String>("hook", &input)?;
+    PLUGINS
+      .lock()
+      .map_err(|e| anyhow::anyhow!("Hook plugins poisoned: {e}"))?
+      .entry(key)
+      .or_default()
+      .push(plugin);
+    Ok(serde_json::from_str(&output)?)
+  })
+  .await?
+}
+
+#[cfg(not(feature = "wasm-hooks"))]
+async fn call_wasm_hook(
+  _module: &str,
+  _input: String,
+  _timeout: Duration,
+) -> LemmyResult<HookResponse> {
+  Err(anyhow::anyhow!(
+    "WebAssembly hooks need Lemmy to be compiled with the `wasm-hooks` feature"
+  ))?
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  #[test]
+  fn test_parse_hook_response() -> LemmyResult<()> {
+    let allow: HookResponse = serde_json::from_value(json!({"action": "allow"}))?;
+    assert_eq!(HookResponse::Allow, allow);
+
+    let deny: HookResponse = serde_json::from_value(json!({"action": "deny"}))?;
+    assert_eq!(HookResponse::Deny { reason: None }, deny);
+
+    let modify: HookResponse =
+      serde_json::from_value(json!({"action": "modify", "data": {"name": "changed"}}))?;
+    assert_eq!(
+      HookResponse::Modify {
+        data: json!({"name": "changed"})
+      },
+      modify
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_evaluate_condition() -> Result<(), ExpressionError> {
+    let timeout = Duration::from_secs(1);
+    let data = json!({"name": "Buy crypto now", "body": null});
+    assert!(evaluate_condition(
+      "data.name.contains('crypto') && data.body == null",
+      &data,
+      timeout
+    )?);
+    assert!(!evaluate_condition("size(data.name) < 5", &data, timeout)?);
+    assert!(evaluate_condition("post.name == 'x'", &data, timeout).is_err());
+    Ok(())
+  }
+}
@@ -0,0 +1,88 @@
+use lemmy_db_schema::{
+  newtypes::{CommunityId, InterestCategoryId},
+  source::interest_category::InterestCategory,
+};
+use lemmy_db_views_actor::structs::CommunityView;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create an interest category for onboarding.
+pub struct CreateInterestCategory {
+  pub name: String,
+  /// Communities which are suggested first for this category.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_ids: Option<Vec<CommunityId>>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit an interest category.
+pub struct EditInterestCategory {
+  pub id: InterestCategoryId,
+  pub name: String,
+  /// Communities which are suggested first for this category.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_ids: Option<Vec<CommunityId>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete an interest category.
+pub struct DeleteInterestCategory {
+  pub id: InterestCategoryId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An interest category, with the communities picked by admins.
+pub struct InterestCategoryResponse {
+  pub category: InterestCategory,
+  pub community_ids: Vec<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A response for interest categories.
+pub struct ListInterestCategoriesResponse {
+  pub categories: Vec<InterestCategory>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get community suggestions for the interests which a new user picked.
+pub struct GetOnboardingCommunities {
+  pub category_ids: Vec<InterestCategoryId>,
+  /// The maximum number of communities per category.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Suggested communities for an interest category.
+pub struct OnboardingCategory {
+  pub category: InterestCategory,
+  pub communities: Vec<CommunityView>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for onboarding community suggestions.
+pub struct GetOnboardingCommunitiesResponse {
+  pub categories: Vec<OnboardingCategory>,
+}
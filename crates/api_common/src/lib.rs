@@ -1,3 +1,4 @@
+pub mod ban_appeal;
 #[cfg(feature = "full")]
 pub mod build_response;
 #[cfg(feature = "full")]
@@ -5,17 +6,37 @@ pub mod claims;
 pub mod comment;
 pub mod community;
 #[cfg(feature = "full")]
+pub mod community_bot;
+pub mod community_request;
+#[cfg(feature = "full")]
+pub mod content_filter;
+#[cfg(feature = "full")]
 pub mod context;
 pub mod custom_emoji;
+pub mod custom_feed;
+#[cfg(feature = "full")]
+pub mod description_translation;
+#[cfg(feature = "full")]
+pub mod feature_flag;
+pub mod filter_rule;
+#[cfg(feature = "full")]
+pub mod hooks;
+pub mod interest_category;
+#[cfg(feature = "full")]
+pub mod matrix_bridge;
 pub mod oauth_provider;
 pub mod person;
 pub mod post;
 pub mod private_message;
+pub mod registration_question;
+pub mod report_reason_category;
 #[cfg(feature = "full")]
 pub mod request;
 #[cfg(feature = "full")]
 pub mod send_activity;
 pub mod site;
+#[cfg(feature = "full")]
+pub mod spam;
 pub mod tagline;
 #[cfg(feature = "full")]
 pub mod utils;
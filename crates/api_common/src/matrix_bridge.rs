@@ -0,0 +1,166 @@
+//! Optional bridge which relays private messages to Matrix direct rooms, if both persons have a
+//! Matrix user id. Lemmy stores private messages unencrypted, so this doesn't make them private.
+//! Instead the Matrix room gives both persons a place where they can continue the conversation
+//! with end-to-end encryption. The rooms are created by the bot account from the `matrix`
+//! config, which invites both persons and relays the messages.
+//!
+//! Only messages written on this instance are bridged, so that a conversation between two
+//! instances with a bridge doesn't end up in two rooms.
+
+use crate::context::LemmyContext;
+use lemmy_db_schema::source::{
+  person::Person,
+  pm_conversation::PmConversation,
+  private_message::PrivateMessage,
+};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  settings::structs::MatrixConfig,
+  utils::markdown::markdown_to_html,
+  REQWEST_TIMEOUT,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Serialize)]
+struct CreateRoomRequest<'a> {
+  preset: &'static str,
+  is_direct: bool,
+  invite: [&'a str; 2],
+  name: String,
+  topic: &'static str,
+}
+
+#[derive(Deserialize)]
+struct CreateRoomResponse {
+  room_id: String,
+}
+
+#[derive(Serialize)]
+struct RoomMessage {
+  msgtype: &'static str,
+  body: String,
+  format: &'static str,
+  formatted_body: String,
+}
+
+/// Relays a direct private message to the Matrix room of its conversation, creating the room when
+/// the first message is bridged. Does nothing if the bridge isn't configured, or if one of the
+/// persons has no Matrix user id.
+pub async fn bridge_private_message(
+  private_message: &PrivateMessage,
+  creator: &Person,
+  recipient: &Person,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let Some(matrix) = &context.settings().matrix else {
+    return Ok(());
+  };
+  let (Some(creator_matrix_id), Some(recipient_matrix_id)) =
+    (&creator.matrix_user_id, &recipient.matrix_user_id)
+  else {
+    return Ok(());
+  };
+
+  let conversation =
+    PmConversation::read(&mut context.pool(), private_message.conversation_id).await?;
+  let room_id = match conversation.matrix_room_id {
+    Some(room_id) => room_id,
+    None => {
+      let request = CreateRoomRequest {
+        preset: "trusted_private_chat",
+        is_direct: true,
+        invite: [creator_matrix_id, recipient_matrix_id],
+        name: format!("{} & {}", creator.name, recipient.name),
+        topic: "Private messages from Lemmy. Enable encryption in the room settings to continue \
+                the conversation end-to-end encrypted.",
+      };
+      let response: CreateRoomResponse = context
+        .client()
+        .post(matrix_api_url(matrix, &["createRoom"])?.as_str())
+        .bearer_auth(&matrix.access_token)
+        .json(&request)
+        .timeout(REQWEST_TIMEOUT)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+      PmConversation::set_matrix_room_id(&mut context.pool(), conversation.id, &response.room_id)
+        .await?;
+      response.room_id
+    }
+  };
+
+  // The message id is used as transaction id, so that retries don't send it twice
+  let transaction_id = format!("lemmy-pm-{}", private_message.id);
+  let message = RoomMessage {
+    msgtype: "m.text",
+    body: format!("{}: {}", creator.name, private_message.content),
+    format: "org.matrix.custom.html",
+    formatted_body: format!(
+      "<strong>{}</strong>: {}",
+      creator.name,
+      markdown_to_html(&private_message.content)
+    ),
+  };
+  context
+    .client()
+    .put(
+      matrix_api_url(
+        matrix,
+        &["rooms", &room_id, "send", "m.room.message", &transaction_id],
+      )?
+      .as_str(),
+    )
+    .bearer_auth(&matrix.access_token)
+    .json(&message)
+    .timeout(REQWEST_TIMEOUT)
+    .send()
+    .await?
+    .error_for_status()?;
+  Ok(())
+}
+
+/// Builds the url of a Matrix client-server API endpoint. Each segment is escaped, so that room
+/// ids can't change the path.
+fn matrix_api_url(matrix: &MatrixConfig, segments: &[&str]) -> LemmyResult<Url> {
+  let mut url = matrix.homeserver_url.clone();
+  url
+    .path_segments_mut()
+    .map_err(|()| LemmyErrorType::InvalidUrl)?
+    .pop_if_empty()
+    .extend(["_matrix", "client", "v3"])
+    .extend(segments);
+  Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::matrix_api_url;
+  use lemmy_utils::{error::LemmyResult, settings::structs::MatrixConfig};
+  use pretty_assertions::assert_eq;
+  use url::Url;
+
+  #[test]
+  fn test_matrix_api_url() -> LemmyResult<()> {
+    let matrix = MatrixConfig {
+      homeserver_url: Url::parse("https://matrix.example.com/")?,
+      access_token: String::new(),
+    };
+    assert_eq!(
+      "https://matrix.example.com/_matrix/client/v3/createRoom",
+      matrix_api_url(&matrix, &["createRoom"])?.as_str()
+    );
+    assert_eq!(
+      "https://matrix.example.com/_matrix/client/v3/rooms/!abc:example.com/send/m.room.message/1",
+      matrix_api_url(
+        &matrix,
+        &["rooms", "!abc:example.com", "send", "m.room.message", "1"]
+      )?
+      .as_str()
+    );
+    Ok(())
+  }
+}
@@ -1,4 +1,5 @@
-use lemmy_db_schema::newtypes::OAuthProviderId;
+use crate::registration_question::RegistrationQuestionAnswer;
+use lemmy_db_schema::{newtypes::OAuthProviderId, source::oauth_account::OAuthAccount};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
@@ -79,7 +80,33 @@ pub struct AuthenticateWithOauth {
   /// Username is mandatory at registration time
   #[cfg_attr(feature = "full", ts(optional))]
   pub username: Option<String>,
-  /// An answer is mandatory if require application is enabled on the server
+  /// Answers to the registration questions of the site. Required questions have to be answered
+  /// if require application is enabled on the server.
   #[cfg_attr(feature = "full", ts(optional))]
-  pub answer: Option<String>,
+  pub answers: Option<Vec<RegistrationQuestionAnswer>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Link an account of an OAuth provider to your existing account, so that you can log in with it.
+pub struct LinkOAuthAccount {
+  pub code: String,
+  pub oauth_provider_id: OAuthProviderId,
+  pub redirect_uri: Url,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Remove the link to an account of an OAuth provider.
+pub struct UnlinkOAuthAccount {
+  pub oauth_provider_id: OAuthProviderId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListOAuthAccountsResponse {
+  pub oauth_accounts: Vec<OAuthAccount>,
 }
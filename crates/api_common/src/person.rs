@@ -1,19 +1,56 @@
+use crate::registration_question::RegistrationQuestionAnswer;
+use chrono::NaiveTime;
 use lemmy_db_schema::{
-  newtypes::{CommentReplyId, CommunityId, LanguageId, PersonId, PersonMentionId},
+  newtypes::{
+    CommentId,
+    CommentReplyId,
+    CommentReportId,
+    CommunityId,
+    LanguageId,
+    LoginTokenId,
+    PersonId,
+    PersonMentionId,
+    PersonModNoteId,
+    PostId,
+    PostReportId,
+    PrivateMessageReportId,
+    WebauthnCredentialId,
+  },
   sensitive::SensitiveString,
-  source::{login_token::LoginToken, site::Site},
+  source::{
+    local_user_keyword_block::LocalUserKeywordBlock,
+    login_token::LoginToken,
+    moderator::{AdminFreezeAccount, AdminShadowBanPerson},
+    person::Person,
+    site::Site,
+    webauthn_credential::WebauthnCredential,
+  },
+  AllowPrivateMessages,
   CommentSortType,
   ListingType,
   PostListingMode,
   PostSortType,
 };
-use lemmy_db_views::structs::{CommentView, LocalImageView, PostView};
+use lemmy_db_views::structs::{
+  CommentReportView,
+  CommentView,
+  LocalImageView,
+  PaginationCursor,
+  PersonModNoteView,
+  PostReportView,
+  PostView,
+  PrivateMessageReportView,
+  ReportCombinedView,
+};
 use lemmy_db_views_actor::structs::{
+  CommentReplyGroupView,
   CommentReplyView,
   CommunityModeratorView,
+  InboxCombinedView,
   PersonMentionView,
   PersonView,
 };
+use lemmy_db_views_moderator::structs::ModWarnPersonView;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
@@ -55,9 +92,10 @@ pub struct Register {
   /// A form field to trick signup bots. Should be None.
   #[cfg_attr(feature = "full", ts(optional))]
   pub honeypot: Option<String>,
-  /// An answer is mandatory if require application is enabled on the server
+  /// Answers to the registration questions of the site. Required questions have to be answered
+  /// if require application is enabled on the server.
   #[cfg_attr(feature = "full", ts(optional))]
-  pub answer: Option<String>,
+  pub answers: Option<Vec<RegistrationQuestionAnswer>>,
 }
 
 #[skip_serializing_none]
@@ -181,6 +219,42 @@ pub struct SaveUserSettings {
   /// Whether to automatically mark fetched posts as read.
   #[cfg_attr(feature = "full", ts(optional))]
   pub auto_mark_fetched_posts_as_read: Option<bool>,
+  /// Percentage of posts in the Home listing which come from communities you're not subscribed to.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub home_discovery_percent: Option<i16>,
+  /// Whether your uploaded images are sent to the OCR service of the instance, so that the
+  /// recognized text can be used for search.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub image_text_recognition: Option<bool>,
+  /// Whether to hold back notification emails during quiet hours.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub quiet_hours_enabled: Option<bool>,
+  /// The time at which quiet hours start, in the quiet hours timezone.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub quiet_hours_start: Option<NaiveTime>,
+  /// The time at which quiet hours end, in the quiet hours timezone.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub quiet_hours_end: Option<NaiveTime>,
+  /// The IANA timezone of the quiet hours, for example `Europe/Berlin`.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub quiet_hours_timezone: Option<String>,
+  /// Whether saving a post also marks it as read.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub mark_read_on_save: Option<bool>,
+  /// Hold back private messages from persons you haven't talked to before, until you accept them.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub private_message_requests: Option<bool>,
+  /// Who can send you private messages.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub allow_private_messages: Option<AllowPrivateMessages>,
+  /// Leave bot posts out of feeds with several communities, and list bot comments last. This
+  /// can be overridden per community with `SaveCommunitySettings`.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub downrank_bot_accounts: Option<bool>,
+  /// Admins only: keep logging in with the credentials of the LDAP directory. This only applies
+  /// to accounts which were created through LDAP login.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub ldap_admin_login: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
@@ -246,6 +320,17 @@ pub struct GetPersonDetailsResponse {
   pub comments: Vec<CommentView>,
   pub posts: Vec<PostView>,
   pub moderates: Vec<CommunityModeratorView>,
+  /// Posts the person pinned to their profile, shown before the others.
+  pub pinned_posts: Vec<PostView>,
+  /// Comments the person pinned to their profile, shown before the others.
+  pub pinned_comments: Vec<CommentView>,
+  /// Private notes on the person, only returned for moderators and admins.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub mod_notes: Option<Vec<PersonModNoteView>>,
+  /// The strikes of all warnings which haven't expired yet, only returned for moderators and
+  /// admins.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub strikes: Option<i64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
@@ -304,6 +389,149 @@ pub struct BanPersonResponse {
   pub banned: bool,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Give a person a formal warning. Warnings in a community are given by its moderators, warnings
+/// without a community by admins.
+pub struct WarnPerson {
+  pub person_id: PersonId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+  /// How many strikes the warning counts as, defaults to 1.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub strikes: Option<i32>,
+  /// A time after which the strikes don't count anymore, in unix epoch seconds.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub expires: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A response for a warned person.
+pub struct WarnPersonResponse {
+  /// The active strikes of the person in the community, or site-wide.
+  pub strikes: i64,
+  /// Whether the person was banned from the community for having too many strikes.
+  pub banned: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get the warnings you received.
+pub struct ListWarnings {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page: Option<i64>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Your warnings, newest first.
+pub struct ListWarningsResponse {
+  pub warnings: Vec<ModWarnPersonView>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Freeze or unfreeze a local account. A frozen account can't log in, and all of its existing
+/// sessions are invalidated. Only for admins.
+pub struct FreezeAccount {
+  pub person_id: PersonId,
+  pub freeze: bool,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for freezing an account.
+pub struct FreezeAccountResponse {
+  pub person_id: PersonId,
+  pub frozen: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the freeze history of a local account. Only for admins.
+pub struct ListAccountFreezes {
+  pub person_id: PersonId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The freeze history of an account, newest first.
+pub struct ListAccountFreezesResponse {
+  pub freezes: Vec<AdminFreezeAccount>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Shadow ban or unban a person. The posts and comments of a shadow banned person are only
+/// visible to themselves and admins, and aren't federated. Only for admins.
+pub struct ShadowBanPerson {
+  pub person_id: PersonId,
+  pub shadow_ban: bool,
+  pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for shadow banning a person.
+pub struct ShadowBanPersonResponse {
+  pub person_id: PersonId,
+  pub shadow_banned: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the shadow ban history of a person. Only for admins.
+pub struct ListShadowBans {
+  pub person_id: PersonId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The shadow ban history of a person, newest first.
+pub struct ListShadowBansResponse {
+  pub shadow_bans: Vec<AdminShadowBanPerson>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Follow a person, to see their posts and comments in your feed.
+pub struct FollowPerson {
+  pub person_id: PersonId,
+  pub follow: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for a person follow.
+pub struct FollowPersonResponse {
+  pub person_view: PersonView,
+  pub followed: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -322,6 +550,40 @@ pub struct BlockPersonResponse {
   pub blocked: bool,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Hide all posts and comments which contain a keyword.
+pub struct BlockKeyword {
+  pub keyword: String,
+  /// Treat the keyword as a case insensitive regular expression, instead of plain text.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub is_regex: Option<bool>,
+  pub block: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for a keyword block, with all keyword blocks of the user.
+pub struct BlockKeywordResponse {
+  pub keyword_blocks: Vec<LocalUserKeywordBlock>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Pins one of your own posts or comments to your profile. Exactly one id must be given.
+pub struct PinToProfile {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub post_id: Option<PostId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub comment_id: Option<CommentId>,
+  pub pinned: bool,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
@@ -336,8 +598,38 @@ pub struct GetReplies {
   pub limit: Option<i64>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub unread_only: Option<bool>,
+  /// Group replies to the same post or comment into a single notification.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub grouped: Option<bool>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get your inbox, combining replies, mentions and private messages.
+pub struct GetInbox {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub unread_only: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page_cursor: Option<PaginationCursor>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Your inbox, newest items first.
+pub struct GetInboxResponse {
+  pub inbox: Vec<InboxCombinedView>,
+  /// the pagination cursor to use to fetch the next page
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub next_page: Option<PaginationCursor>,
+}
+
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -345,6 +637,9 @@ pub struct GetReplies {
 // TODO, replies and mentions below should be redone as tagged enums.
 pub struct GetRepliesResponse {
   pub replies: Vec<CommentReplyView>,
+  /// The grouped replies, only returned if `grouped` was requested.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub groups: Option<Vec<CommentReplyGroupView>>,
 }
 
 #[skip_serializing_none]
@@ -412,6 +707,10 @@ pub struct CommentReplyResponse {
 pub struct DeleteAccount {
   pub password: SensitiveString,
   pub delete_content: bool,
+  /// Keep your posts and comments, but show them as written by a placeholder account. Ignored if
+  /// delete_content is set.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub anonymize_content: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
@@ -456,14 +755,174 @@ pub struct GetReportCountResponse {
   pub private_message_reports: Option<i64>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List post, comment and private message reports in a single queue.
+pub struct ListReports {
+  /// Only shows the unresolved reports
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub unresolved_only: Option<bool>,
+  /// if no community is given, it returns reports for all communities moderated by the auth user
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page_cursor: Option<PaginationCursor>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The combined report queue response.
+pub struct ListReportsResponse {
+  pub reports: Vec<ReportCombinedView>,
+  /// The number of unresolved reports of all types
+  pub unresolved_count: i64,
+  /// Private notes on the creators of the reported content
+  pub mod_notes: Vec<PersonModNoteView>,
+  /// the pagination cursor to use to fetch the next page
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub next_page: Option<PaginationCursor>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Claim a report, so that other moderators see that it is being handled. Exactly one report id
+/// must be given.
+pub struct AssignReport {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub comment_report_id: Option<CommentReportId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub post_report_id: Option<PostReportId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub private_message_report_id: Option<PrivateMessageReportId>,
+  /// Set to false to give up the claim again.
+  pub assign: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The claimed report.
+pub struct AssignReportResponse {
+  pub report: ReportCombinedView,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get the reports filed by and against a person. Moderators only see reports from communities
+/// they moderate.
+pub struct GetPersonReportHistory {
+  pub person_id: PersonId,
+  /// The number of most recent reports of each kind which are returned.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A person's report history. Each report includes its resolution and resolver.
+pub struct GetPersonReportHistoryResponse {
+  pub filed_post_reports: Vec<PostReportView>,
+  pub filed_comment_reports: Vec<CommentReportView>,
+  pub received_post_reports: Vec<PostReportView>,
+  pub received_comment_reports: Vec<CommentReportView>,
+  /// Only returned for admins.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub filed_private_message_reports: Option<Vec<PrivateMessageReportView>>,
+  /// Only returned for admins.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub received_private_message_reports: Option<Vec<PrivateMessageReportView>>,
+  /// Private notes on the person.
+  pub mod_notes: Vec<PersonModNoteView>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Leave a private note on a person. Notes without a community are instance-wide, and can only be
+/// created and seen by admins.
+pub struct CreatePersonModNote {
+  pub person_id: PersonId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit your note on a person.
+pub struct EditPersonModNote {
+  pub note_id: PersonModNoteId,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a note on a person.
+pub struct DeletePersonModNote {
+  pub note_id: PersonModNoteId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get the notes on a person which you can see.
+pub struct ListPersonModNotes {
+  pub person_id: PersonId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The person mod note response.
+pub struct PersonModNoteResponse {
+  pub person_mod_note_view: PersonModNoteView,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The notes on a person, newest first.
+pub struct ListPersonModNotesResponse {
+  pub mod_notes: Vec<PersonModNoteView>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get counts for your notifications.
+pub struct GetUnreadCount {
+  /// Count replies to the same post or comment as a single notification.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub grouped: Option<bool>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// A response containing counts for your notifications.
 pub struct GetUnreadCountResponse {
+  /// The number of unread replies, or of posts and comments with unread replies if grouped.
   pub replies: i64,
   pub mentions: i64,
   pub private_messages: i64,
+  /// The number of warnings you haven't seen yet.
+  pub warnings: i64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
@@ -515,9 +974,144 @@ pub struct ListMediaResponse {
   pub images: Vec<LocalImageView>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get the posts you read, most recently read first.
+pub struct ListReadPosts {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page: Option<i64>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListReadPostsResponse {
+  pub posts: Vec<PostView>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 pub struct ListLoginsResponse {
   pub logins: Vec<LoginToken>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Logs out one of your logins, for example on another device.
+pub struct RevokeLogin {
+  pub id: LoginTokenId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Link another account on this instance to your account, by providing its credentials.
+pub struct LinkAccount {
+  pub username_or_email: SensitiveString,
+  pub password: SensitiveString,
+  /// May be required, if totp is enabled for the other account.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub totp_2fa_token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response after linking an account, with a login token for the linked account.
+pub struct LinkAccountResponse {
+  pub jwt: SensitiveString,
+  pub linked_accounts: Vec<Person>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get a login token for one of your linked accounts.
+pub struct SwitchAccount {
+  pub person_id: PersonId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Remove the link to another account.
+pub struct UnlinkAccount {
+  pub person_id: PersonId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListLinkedAccountsResponse {
+  pub linked_accounts: Vec<Person>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A challenge which the browser has to sign with a passkey.
+pub struct PasskeyChallengeResponse {
+  /// Has to be sent back with the signed challenge.
+  pub challenge_id: String,
+  /// JSON encoded options for `navigator.credentials.create()` when registering a passkey, or
+  /// for `navigator.credentials.get()` when logging in.
+  pub options: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Adds a passkey or hardware key to your account.
+pub struct FinishPasskeyRegistration {
+  pub challenge_id: String,
+  pub name: String,
+  /// The JSON encoded credential which the browser created.
+  pub credential: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct PasskeyResponse {
+  pub passkey: WebauthnCredential,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListPasskeysResponse {
+  pub passkeys: Vec<WebauthnCredential>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Removes a passkey from your account.
+pub struct DeletePasskey {
+  pub id: WebauthnCredentialId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Starts logging in with a passkey instead of a password.
+pub struct StartPasskeyLogin {
+  pub username_or_email: SensitiveString,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Logs in with the challenge signed by a passkey.
+pub struct FinishPasskeyLogin {
+  pub challenge_id: String,
+  /// The JSON encoded assertion which the browser returned.
+  pub credential: String,
+}
@@ -1,10 +1,31 @@
+use chrono::{DateTime, Utc};
 use lemmy_db_schema::{
-  newtypes::{CommentId, CommunityId, DbUrl, LanguageId, PostId, PostReportId},
+  newtypes::{
+    CommentId,
+    CommunityId,
+    CommunityPostTagId,
+    CustomFeedId,
+    DbUrl,
+    LanguageId,
+    PersonId,
+    PollId,
+    PollOptionId,
+    PostId,
+    PostReportId,
+  },
   ListingType,
   PostFeatureType,
   PostSortType,
+  ReportOutcome,
+};
+use lemmy_db_views::structs::{
+  PaginationCursor,
+  PersonModNoteView,
+  PollView,
+  PostReportView,
+  PostView,
+  VoteView,
 };
-use lemmy_db_views::structs::{PaginationCursor, PostReportView, PostView, VoteView};
 use lemmy_db_views_actor::structs::{CommunityModeratorView, CommunityView};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -40,6 +61,33 @@ pub struct CreatePost {
   /// Time when this post should be scheduled. Null means publish immediately.
   #[cfg_attr(feature = "full", ts(optional))]
   pub scheduled_publish_time: Option<i64>,
+  /// Attaches a poll with these options to the post.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub poll_options: Option<Vec<String>>,
+  /// Whether voters can pick more than one poll option, defaults to false.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub poll_multiple_choice: Option<bool>,
+  /// Time when the poll closes, as a unix timestamp. Null means it stays open.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub poll_end_time: Option<i64>,
+  /// Additional images which are shown after the url, turning the post into a gallery. If no url
+  /// is given, the first image is used as url.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub gallery: Option<Vec<PostGalleryImage>>,
+  /// Tags of the community which are attached to the post.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub tag_ids: Option<Vec<CommunityPostTagId>>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An image of a gallery post.
+pub struct PostGalleryImage {
+  pub url: String,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub alt_text: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -73,6 +121,8 @@ pub struct GetPostResponse {
   pub moderators: Vec<CommunityModeratorView>,
   /// A list of cross-posts, or other times / communities this link has been posted to.
   pub cross_posts: Vec<PostView>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub poll: Option<PollView>,
 }
 
 #[skip_serializing_none]
@@ -94,12 +144,24 @@ pub struct GetPosts {
   pub community_id: Option<CommunityId>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub community_name: Option<String>,
+  /// Only show posts with this tag.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub tag_id: Option<CommunityPostTagId>,
+  /// Only show posts in the communities of this custom feed.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub custom_feed_id: Option<CustomFeedId>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub saved_only: Option<bool>,
+  /// Only show saved items whose personal note contains this text.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub saved_notes: Option<String>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub liked_only: Option<bool>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub disliked_only: Option<bool>,
+  /// Only show posts by persons which you follow.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub followed_persons_only: Option<bool>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub show_hidden: Option<bool>,
   /// If true, then show the read posts (even if your user setting is to hide them)
@@ -140,6 +202,23 @@ pub struct CreatePostLike {
   pub score: i16,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Vote in a poll. This replaces any previous vote, and an empty list takes the vote back.
+pub struct VotePoll {
+  pub poll_id: PollId,
+  pub option_ids: Vec<PollOptionId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The poll response.
+pub struct PollResponse {
+  pub poll_view: PollView,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
@@ -167,6 +246,12 @@ pub struct EditPost {
   /// Time when this post should be scheduled. Null means publish immediately.
   #[cfg_attr(feature = "full", ts(optional))]
   pub scheduled_publish_time: Option<i64>,
+  /// Replaces the gallery images of the post. An empty list removes the gallery.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub gallery: Option<Vec<PostGalleryImage>>,
+  /// Replaces the tags of the post. An empty list removes all tags.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub tag_ids: Option<Vec<CommunityPostTagId>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -210,6 +295,17 @@ pub struct HidePost {
   pub hide: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Hide several posts from list views at once, for example all posts of a page which you aren't
+/// interested in.
+pub struct HidePosts {
+  pub post_ids: Vec<PostId>,
+  pub hide: bool,
+}
+
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -217,6 +313,10 @@ pub struct HidePost {
 pub struct LockPost {
   pub post_id: PostId,
   pub locked: bool,
+  /// A time at which the post is unlocked again, in unix epoch seconds. Without it the lock is
+  /// permanent.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub expires: Option<i64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -229,13 +329,22 @@ pub struct FeaturePost {
   pub feature_type: PostFeatureType,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// Save / bookmark a post.
 pub struct SavePost {
   pub post_id: PostId,
   pub save: bool,
+  /// A personal note about the saved post, which is only visible to you. Saving again without a
+  /// note keeps the existing one.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub saved_note: Option<String>,
+  /// Whether to mark the post as read when saving it. Defaults to the `mark_read_on_save` user
+  /// setting.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub mark_as_read: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -255,6 +364,7 @@ pub struct PostReportResponse {
   pub post_report_view: PostReportView,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -262,16 +372,19 @@ pub struct PostReportResponse {
 pub struct ResolvePostReport {
   pub report_id: PostReportId,
   pub resolved: bool,
+  /// How the report was handled, defaults to resolved without action. Ignored when unresolving.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub outcome: Option<ReportOutcome>,
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// List post reports.
 pub struct ListPostReports {
   #[cfg_attr(feature = "full", ts(optional))]
-  pub page: Option<i64>,
+  pub page_cursor: Option<PaginationCursor>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub limit: Option<i64>,
   /// Only shows the unresolved reports
@@ -285,12 +398,18 @@ pub struct ListPostReports {
   pub post_id: Option<PostId>,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// The post reports response.
 pub struct ListPostReportsResponse {
   pub post_reports: Vec<PostReportView>,
+  /// the pagination cursor to use to fetch the next page
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub next_page: Option<PaginationCursor>,
+  /// Private notes on the creators of the reported posts
+  pub mod_notes: Vec<PersonModNoteView>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
@@ -341,7 +460,8 @@ pub struct OpenGraphData {
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
-/// List post likes. Admins-only.
+/// List who voted on a post. Only for admins, and for community roles which are allowed to view
+/// votes.
 pub struct ListPostLikes {
   pub post_id: PostId,
   #[cfg_attr(feature = "full", ts(optional))]
@@ -357,3 +477,71 @@ pub struct ListPostLikes {
 pub struct ListPostLikesResponse {
   pub post_likes: Vec<VoteView>,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Posts which wait for approval, oldest first. Only the communities you moderate are included,
+/// or all communities for admins.
+pub struct ListPendingPosts {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page: Option<i64>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListPendingPostsResponse {
+  pub posts: Vec<PostView>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Approve a post which waits for approval, so that it becomes visible and is federated. A
+/// rejected post is removed instead.
+pub struct ApprovePendingPost {
+  pub post_id: PostId,
+  pub approve: bool,
+  /// The reason for removing a rejected post.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Export the entire comment tree of a post as JSON Lines, with one `ExportedComment` per line.
+/// Only for moderators, unless the community allows public exports.
+pub struct ExportPostComments {
+  pub post_id: PostId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A single comment of a comment export. Parents are always exported before their children.
+pub struct ExportedComment {
+  pub id: CommentId,
+  pub ap_id: DbUrl,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub parent_id: Option<CommentId>,
+  pub creator_id: PersonId,
+  /// Left out for removed or deleted comments, unless exported by a moderator.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub content: Option<String>,
+  pub removed: bool,
+  pub deleted: bool,
+  pub distinguished: bool,
+  pub language_id: LanguageId,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
@@ -1,17 +1,30 @@
-use lemmy_db_schema::newtypes::{PersonId, PrivateMessageId, PrivateMessageReportId};
-use lemmy_db_views::structs::{PrivateMessageReportView, PrivateMessageView};
+use lemmy_db_schema::{
+  newtypes::{PersonId, PmConversationId, PrivateMessageId, PrivateMessageReportId},
+  source::{person::Person, pm_conversation::PmConversation},
+  ReportOutcome,
+};
+use lemmy_db_views::structs::{
+  PaginationCursor,
+  PmConversationView,
+  PrivateMessageReportView,
+  PrivateMessageView,
+};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
 use ts_rs::TS;
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
-/// Create a private message.
+/// Create a private message, either for a single recipient or for a group conversation.
 pub struct CreatePrivateMessage {
   pub content: String,
-  pub recipient_id: PersonId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub recipient_id: Option<PersonId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub conversation_id: Option<PmConversationId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
@@ -55,6 +68,9 @@ pub struct GetPrivateMessages {
   pub limit: Option<i64>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub creator_id: Option<PersonId>,
+  /// Only get the messages of this conversation.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub conversation_id: Option<PmConversationId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -65,6 +81,96 @@ pub struct PrivateMessagesResponse {
   pub private_messages: Vec<PrivateMessageView>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get your private message conversations, with the most recently active ones first.
+pub struct ListPmConversations {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub unread_only: Option<bool>,
+  /// Only list message requests which you haven't accepted yet.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub requests_only: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page: Option<i64>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The private message conversations response.
+pub struct ListPmConversationsResponse {
+  pub conversations: Vec<PmConversationView>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Mark all messages which you received in a conversation as read.
+pub struct MarkPmConversationAsRead {
+  pub conversation_id: PmConversationId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Accept a message request, so that its messages show up in your inbox. Declining it deletes the
+/// conversation.
+pub struct ApprovePmRequest {
+  pub conversation_id: PmConversationId,
+  pub approve: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a group conversation. You are added as participant automatically.
+pub struct CreatePmGroup {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub name: Option<String>,
+  pub participant_ids: Vec<PersonId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get a conversation and its participants.
+pub struct GetPmConversation {
+  pub conversation_id: PmConversationId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Add a participant to a group conversation. Only the creator of the group can do this.
+pub struct AddPmGroupParticipant {
+  pub conversation_id: PmConversationId,
+  pub person_id: PersonId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Remove a participant from a group conversation. The creator of the group can remove anyone,
+/// other participants can only remove themselves to leave the group.
+pub struct RemovePmGroupParticipant {
+  pub conversation_id: PmConversationId,
+  pub person_id: PersonId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A conversation response.
+pub struct PmConversationResponse {
+  pub conversation: PmConversation,
+  pub participants: Vec<Person>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -90,6 +196,7 @@ pub struct PrivateMessageReportResponse {
   pub private_message_report_view: PrivateMessageReportView,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -97,17 +204,20 @@ pub struct PrivateMessageReportResponse {
 pub struct ResolvePrivateMessageReport {
   pub report_id: PrivateMessageReportId,
   pub resolved: bool,
+  /// How the report was handled, defaults to resolved without action. Ignored when unresolving.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub outcome: Option<ReportOutcome>,
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// List private message reports.
 // TODO , perhaps GetReports should be a tagged enum list too.
 pub struct ListPrivateMessageReports {
   #[cfg_attr(feature = "full", ts(optional))]
-  pub page: Option<i64>,
+  pub page_cursor: Option<PaginationCursor>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub limit: Option<i64>,
   /// Only shows the unresolved reports
@@ -115,10 +225,14 @@ pub struct ListPrivateMessageReports {
   pub unresolved_only: Option<bool>,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// The response for list private message reports.
 pub struct ListPrivateMessageReportsResponse {
   pub private_message_reports: Vec<PrivateMessageReportView>,
+  /// the pagination cursor to use to fetch the next page
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub next_page: Option<PaginationCursor>,
 }
@@ -0,0 +1,61 @@
+use lemmy_db_schema::{
+  newtypes::RegistrationQuestionId,
+  source::registration_question::RegistrationQuestion,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a question which new users have to answer in their registration application.
+pub struct CreateRegistrationQuestion {
+  pub question: String,
+  /// Defaults to true.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub required: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub position: Option<i32>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a registration question.
+pub struct EditRegistrationQuestion {
+  pub id: RegistrationQuestionId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub question: Option<String>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub required: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub position: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a registration question. Existing answers keep a copy of the question text.
+pub struct DeleteRegistrationQuestion {
+  pub id: RegistrationQuestionId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct RegistrationQuestionResponse {
+  pub registration_question: RegistrationQuestion,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The answer to a registration question, given when registering.
+pub struct RegistrationQuestionAnswer {
+  pub question_id: RegistrationQuestionId,
+  pub answer: String,
+}
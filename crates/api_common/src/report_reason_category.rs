@@ -0,0 +1,48 @@
+use lemmy_db_schema::{
+  newtypes::ReportReasonCategoryId,
+  source::report_reason_category::ReportReasonCategory,
+};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a report reason category.
+pub struct CreateReportReasonCategory {
+  pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a report reason category.
+pub struct EditReportReasonCategory {
+  pub id: ReportReasonCategoryId,
+  pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a report reason category.
+pub struct DeleteReportReasonCategory {
+  pub id: ReportReasonCategoryId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A response for a report reason category.
+pub struct ReportReasonCategoryResponse {
+  pub category: ReportReasonCategory,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A response for report reason categories.
+pub struct ListReportReasonCategoriesResponse {
+  pub categories: Vec<ReportReasonCategory>,
+}
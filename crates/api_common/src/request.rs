@@ -12,7 +12,7 @@ use futures::StreamExt;
 use lemmy_db_schema::{
   newtypes::DbUrl,
   source::{
-    images::{ImageDetailsForm, LocalImage, LocalImageForm},
+    images::{ImageDetailsForm, ImageText, ImageTextForm, LocalImage, LocalImageForm},
     post::{Post, PostUpdateForm},
     site::Site,
   },
@@ -318,6 +318,58 @@ pub async fn purge_image_from_pictrs(image_url: &Url, context: &LemmyContext) ->
   }
 }
 
+#[derive(Deserialize, Debug)]
+struct OcrResponse {
+  text: String,
+}
+
+/// Sends an uploaded image to the OCR service, and stores the recognized text so that posts with
+/// this image can be found with search. Does nothing if no OCR service is configured.
+pub async fn recognize_image_text(
+  pictrs_alias: String,
+  link: Url,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let pictrs_config = context.settings().pictrs_config()?;
+  let Some(ocr_url) = pictrs_config.ocr_url else {
+    return Ok(());
+  };
+
+  let image = context
+    .client()
+    .get(format!(
+      "{}image/original/{}",
+      pictrs_config.url, pictrs_alias
+    ))
+    .timeout(REQWEST_TIMEOUT)
+    .send()
+    .await?
+    .error_for_status()?;
+  let content_type = image.headers().get(CONTENT_TYPE).cloned();
+  let bytes = image.bytes().await?;
+
+  let mut ocr_request = context
+    .client()
+    .post(ocr_url)
+    .timeout(REQWEST_TIMEOUT)
+    .body(bytes);
+  if let Some(content_type) = content_type {
+    ocr_request = ocr_request.header(CONTENT_TYPE, content_type);
+  }
+  let ocr_response: OcrResponse = ocr_request.send().await?.error_for_status()?.json().await?;
+
+  let text = ocr_response.text.trim();
+  if !text.is_empty() {
+    let form = ImageTextForm {
+      pictrs_alias,
+      link: link.into(),
+      text: text.to_string(),
+    };
+    ImageText::upsert(&mut context.pool(), &form).await?;
+  }
+  Ok(())
+}
+
 pub async fn delete_image_from_pictrs(
   alias: &str,
   delete_token: &str,
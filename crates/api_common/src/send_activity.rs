@@ -40,17 +40,23 @@ pub enum SendActivityData {
     moderator: Person,
     reason: Option<String>,
     removed: bool,
+    /// Performed by a community bot, and sent with the community as actor.
+    automod: bool,
   },
-  LockPost(Post, Person, bool),
-  FeaturePost(Post, Person, bool),
+  /// The last field is true if a community bot locked the post.
+  LockPost(Post, Person, bool, bool),
+  /// The last field is true if a community bot featured the post.
+  FeaturePost(Post, Person, bool, bool),
   CreateComment(Comment),
   UpdateComment(Comment),
+  PinComment(Comment, Person, bool),
   DeleteComment(Comment, Person, Community),
   RemoveComment {
     comment: Comment,
     moderator: Person,
     community: Community,
     reason: Option<String>,
+    automod: bool,
   },
   LikePostOrComment {
     object_id: DbUrl,
@@ -59,6 +65,7 @@ pub enum SendActivityData {
     score: i16,
   },
   FollowCommunity(Community, Person, bool),
+  FollowPerson(Person, Person, bool),
   AcceptFollower(CommunityId, PersonId),
   RejectFollower(CommunityId, PersonId),
   UpdateCommunity(Person, Community),
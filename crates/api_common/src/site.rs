@@ -9,25 +9,40 @@ use lemmy_db_schema::{
     PersonId,
     PostId,
     RegistrationApplicationId,
+    SpamScoreId,
+    VoteAnomalyId,
   },
   source::{
+    client_usage::{ClientRestriction, ClientUsage},
     community::Community,
+    description_translation::SiteDescriptionTranslation,
+    feature_flag::FeatureFlag,
     federation_queue_state::FederationQueueState,
     instance::Instance,
     language::Language,
+    local_site_email_domain::LocalSiteEmailDomain,
     local_site_url_blocklist::LocalSiteUrlBlocklist,
+    local_user_keyword_block::LocalUserKeywordBlock,
     oauth_provider::{OAuthProvider, PublicOAuthProvider},
     person::Person,
+    registration_question::RegistrationQuestion,
+    registration_source::RegistrationBurst,
     tagline::Tagline,
+    transparency_report::TransparencyReport,
   },
   CommentSortType,
+  DefaultUserSetting,
+  FeatureFlagName,
   FederationMode,
+  InstanceTrustLevel,
   ListingType,
   ModlogActionType,
+  NewAccountRestriction,
   PostListingMode,
   PostSortType,
   RegistrationMode,
   SearchType,
+  SpamAction,
 };
 use lemmy_db_views::structs::{
   CommentView,
@@ -35,6 +50,8 @@ use lemmy_db_views::structs::{
   PostView,
   RegistrationApplicationView,
   SiteView,
+  SpamScoreView,
+  VoteAnomalyView,
 };
 use lemmy_db_views_actor::structs::{
   CommunityFollowerView,
@@ -57,7 +74,9 @@ use lemmy_db_views_moderator::structs::{
   ModRemoveCommentView,
   ModRemoveCommunityView,
   ModRemovePostView,
+  ModResolveBanAppealView,
   ModTransferCommunityView,
+  ModWarnPersonView,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -183,6 +202,8 @@ pub struct GetModlogResponse {
   pub admin_purged_posts: Vec<AdminPurgePostView>,
   pub admin_purged_comments: Vec<AdminPurgeCommentView>,
   pub hidden_communities: Vec<ModHideCommunityView>,
+  pub warned_persons: Vec<ModWarnPersonView>,
+  pub resolved_ban_appeals: Vec<ModResolveBanAppealView>,
 }
 
 #[skip_serializing_none]
@@ -207,8 +228,6 @@ pub struct CreateSite {
   #[cfg_attr(feature = "full", ts(optional))]
   pub require_email_verification: Option<bool>,
   #[cfg_attr(feature = "full", ts(optional))]
-  pub application_question: Option<String>,
-  #[cfg_attr(feature = "full", ts(optional))]
   pub private_instance: Option<bool>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub default_theme: Option<String>,
@@ -257,6 +276,14 @@ pub struct CreateSite {
   #[cfg_attr(feature = "full", ts(optional))]
   pub rate_limit_search_per_second: Option<i32>,
   #[cfg_attr(feature = "full", ts(optional))]
+  pub rate_limit_register_subnet: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub rate_limit_register_subnet_per_second: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub rate_limit_register_asn: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub rate_limit_register_asn_per_second: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
   pub federation_enabled: Option<bool>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub federation_debug: Option<bool>,
@@ -282,6 +309,79 @@ pub struct CreateSite {
   pub comment_upvotes: Option<FederationMode>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub comment_downvotes: Option<FederationMode>,
+  /// Whether the list of blocked and allowed instances, including block reasons, is visible to
+  /// everyone.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub instance_blocks_public: Option<bool>,
+  /// Fold unicode lookalike characters and strip accents and invisible characters before
+  /// applying the slur filter, so that it can't be bypassed with homoglyphs.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub slur_filter_normalize: Option<bool>,
+  /// Weight of the share of links in new content for the spam score.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_link_density_weight: Option<i32>,
+  /// Weight of the same content being posted repeatedly in different places for the spam score.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_repetition_weight: Option<i32>,
+  /// Weight of the creator's account being new for the spam score.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_account_age_weight: Option<i32>,
+  /// New content with at least this spam score is hidden until an admin reviews it. A negative
+  /// value disables it.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_hold_threshold: Option<i32>,
+  /// New content with at least this spam score is removed. A negative value disables it.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_remove_threshold: Option<i32>,
+  /// If set, new local communities are left out of Local/All listings and search until they have
+  /// this many posts, or an admin launches them. A negative value disables it.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_soft_launch_posts: Option<i32>,
+  /// Show NSFW content for new accounts.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub default_show_nsfw: Option<bool>,
+  /// Show posts and comments of bot accounts for new accounts.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub default_show_bot_accounts: Option<bool>,
+  /// Send notifications to email for new accounts.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub default_send_notifications_to_email: Option<bool>,
+  /// Interface language for new accounts whose browser doesn't send a
+  /// preferred language.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub default_interface_language: Option<String>,
+  /// After this many days a vote only counts half for the reputation of its recipient. Without it,
+  /// reputation isn't computed. A negative value disables it.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reputation_half_life_days: Option<i32>,
+  /// Weight of the creator having a negative reputation for the spam score.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_low_reputation_weight: Option<i32>,
+  /// If set, users can also log in with the credentials of this LDAP directory. An empty string
+  /// disables LDAP login.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub ldap_url: Option<String>,
+  /// The DN to bind as when logging in with LDAP, where `{username}` is replaced by the name
+  /// which was entered.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub ldap_user_dn_template: Option<String>,
+  /// Reject signups with email addresses of well-known disposable email providers.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub block_disposable_email_domains: Option<bool>,
+  /// New accounts must be at least this many days old for the restricted actions. A negative
+  /// value removes the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_min_age_days: Option<i32>,
+  /// New accounts must have written at least this many comments for the restricted actions. A
+  /// negative value removes the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_min_comments: Option<i32>,
+  /// New accounts must have a verified email for the restricted actions.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_require_verified_email: Option<bool>,
+  /// The actions which are restricted until an account meets the thresholds.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_restrictions: Option<Vec<NewAccountRestriction>>,
 }
 
 #[skip_serializing_none]
@@ -313,9 +413,6 @@ pub struct EditSite {
   /// Whether to require email verification.
   #[cfg_attr(feature = "full", ts(optional))]
   pub require_email_verification: Option<bool>,
-  /// Your application question form. This is in markdown, and can be many questions.
-  #[cfg_attr(feature = "full", ts(optional))]
-  pub application_question: Option<String>,
   /// Whether your instance is public, or private.
   #[cfg_attr(feature = "full", ts(optional))]
   pub private_instance: Option<bool>,
@@ -382,6 +479,18 @@ pub struct EditSite {
   pub rate_limit_search: Option<i32>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub rate_limit_search_per_second: Option<i32>,
+  /// The number of registrations allowed from the same IP subnet in a given time frame. 0
+  /// disables the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub rate_limit_register_subnet: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub rate_limit_register_subnet_per_second: Option<i32>,
+  /// The number of registrations allowed from the same autonomous system in a given time frame.
+  /// Needs a MaxMind ASN database in the config. 0 disables the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub rate_limit_register_asn: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub rate_limit_register_asn_per_second: Option<i32>,
   /// Whether to enable federation.
   #[cfg_attr(feature = "full", ts(optional))]
   pub federation_enabled: Option<bool>,
@@ -403,6 +512,14 @@ pub struct EditSite {
   /// A list of blocked URLs
   #[cfg_attr(feature = "full", ts(optional))]
   pub blocked_urls: Option<Vec<String>>,
+  /// If set, only emails of these domains can be used to sign up. Entries starting with `*.`
+  /// match all subdomains.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub allowed_email_domains: Option<Vec<String>>,
+  /// Emails of these domains can't be used to sign up. Entries starting with `*.` match all
+  /// subdomains.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub blocked_email_domains: Option<Vec<String>>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub registration_mode: Option<RegistrationMode>,
   /// Whether to email admins for new reports.
@@ -427,6 +544,79 @@ pub struct EditSite {
   /// What kind of comment downvotes your site allows.
   #[cfg_attr(feature = "full", ts(optional))]
   pub comment_downvotes: Option<FederationMode>,
+  /// Whether the list of blocked and allowed instances, including block reasons, is visible to
+  /// everyone.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub instance_blocks_public: Option<bool>,
+  /// Fold unicode lookalike characters and strip accents and invisible characters before
+  /// applying the slur filter, so that it can't be bypassed with homoglyphs.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub slur_filter_normalize: Option<bool>,
+  /// Weight of the share of links in new content for the spam score.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_link_density_weight: Option<i32>,
+  /// Weight of the same content being posted repeatedly in different places for the spam score.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_repetition_weight: Option<i32>,
+  /// Weight of the creator's account being new for the spam score.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_account_age_weight: Option<i32>,
+  /// New content with at least this spam score is hidden until an admin reviews it. A negative
+  /// value disables it.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_hold_threshold: Option<i32>,
+  /// New content with at least this spam score is removed. A negative value disables it.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_remove_threshold: Option<i32>,
+  /// If set, new local communities are left out of Local/All listings and search until they have
+  /// this many posts, or an admin launches them. A negative value disables it.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_soft_launch_posts: Option<i32>,
+  /// Show NSFW content for new accounts.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub default_show_nsfw: Option<bool>,
+  /// Show posts and comments of bot accounts for new accounts.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub default_show_bot_accounts: Option<bool>,
+  /// Send notifications to email for new accounts.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub default_send_notifications_to_email: Option<bool>,
+  /// Interface language for new accounts whose browser doesn't send a
+  /// preferred language.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub default_interface_language: Option<String>,
+  /// After this many days a vote only counts half for the reputation of its recipient. Without it,
+  /// reputation isn't computed. A negative value disables it.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reputation_half_life_days: Option<i32>,
+  /// Weight of the creator having a negative reputation for the spam score.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_low_reputation_weight: Option<i32>,
+  /// If set, users can also log in with the credentials of this LDAP directory. An empty string
+  /// disables LDAP login.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub ldap_url: Option<String>,
+  /// The DN to bind as when logging in with LDAP, where `{username}` is replaced by the name
+  /// which was entered.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub ldap_user_dn_template: Option<String>,
+  /// Reject signups with email addresses of well-known disposable email providers.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub block_disposable_email_domains: Option<bool>,
+  /// New accounts must be at least this many days old for the restricted actions. A negative
+  /// value removes the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_min_age_days: Option<i32>,
+  /// New accounts must have written at least this many comments for the restricted actions. A
+  /// negative value removes the limit.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_min_comments: Option<i32>,
+  /// New accounts must have a verified email for the restricted actions.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_require_verified_email: Option<bool>,
+  /// The actions which are restricted until an account meets the thresholds.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_restrictions: Option<Vec<NewAccountRestriction>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -464,7 +654,20 @@ pub struct GetSiteResponse {
   pub oauth_providers: Option<Vec<PublicOAuthProvider>>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub admin_oauth_providers: Option<Vec<OAuthProvider>>,
+  /// The allowed and blocked email domains for signups. Only visible to admins.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub email_domains: Option<Vec<LocalSiteEmailDomain>>,
   pub blocked_urls: Vec<LocalSiteUrlBlocklist>,
+  /// Configuration of all experimental features. Use `enabled_features` to check if a feature is
+  /// available for the current user.
+  pub feature_flags: Vec<FeatureFlag>,
+  /// Experimental features which are enabled for the current user.
+  pub enabled_features: Vec<FeatureFlagName>,
+  /// All translations of the site sidebar and description. The site in `site_view` is already
+  /// translated into the preferred language of the user.
+  pub description_translations: Vec<SiteDescriptionTranslation>,
+  /// Questions which have to be answered in registration applications.
+  pub registration_questions: Vec<RegistrationQuestion>,
 }
 
 #[skip_serializing_none]
@@ -489,6 +692,9 @@ pub struct MyUserInfo {
   pub community_blocks: Vec<Community>,
   pub instance_blocks: Vec<Instance>,
   pub person_blocks: Vec<Person>,
+  /// Persons which the user follows, to show their posts and comments in the feed.
+  pub person_follows: Vec<Person>,
+  pub keyword_blocks: Vec<LocalUserKeywordBlock>,
   pub discussion_languages: Vec<LanguageId>,
 }
 
@@ -539,6 +745,215 @@ pub struct InstanceWithFederationState {
   pub federation_state: Option<ReadableFederationState>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An instance which is blocked from federating with this site.
+pub struct BlockedInstance {
+  pub instance: Instance,
+  pub blocked: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An instance which is on the federation allowlist of this site.
+pub struct AllowedInstance {
+  pub instance: Instance,
+  pub allowed: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The federation block- and allowlist, for instance transparency pages. Only available to
+/// non-admins if `instance_blocks_public` is enabled.
+pub struct GetInstanceBlocksResponse {
+  pub blocked: Vec<BlockedInstance>,
+  pub allowed: Vec<AllowedInstance>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Sets the public reason for blocking an instance. The instance needs to be on the blocklist
+/// already.
+pub struct EditInstanceBlock {
+  pub instance_id: InstanceId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Sets how much activities from a federated instance are trusted. Only for admins.
+pub struct EditInstanceTrustLevel {
+  pub instance_id: InstanceId,
+  pub trust_level: InstanceTrustLevel,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct InstanceResponse {
+  pub instance: Instance,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Lists the quarterly transparency reports of a community, or of the whole instance if no
+/// community is given.
+pub struct ListTransparencyReports {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page: Option<i64>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ListTransparencyReportsResponse {
+  pub reports: Vec<TransparencyReport>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Turns maintenance mode on or off. While it is on, all mutating API calls are rejected and
+/// incoming activities are queued.
+pub struct SetMaintenanceMode {
+  pub enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Enables, disables or partially rolls out an experimental feature.
+pub struct EditFeatureFlag {
+  pub feature: FeatureFlagName,
+  pub enabled: bool,
+  /// Share of local users, from 0 to 100, who get the feature. Anonymous users only get it at 100.
+  pub rollout_percentage: i16,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct FeatureFlagResponse {
+  pub feature_flag: FeatureFlag,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Sets the sidebar and description of the site in another language. Without both of them, the
+/// translation is removed.
+pub struct EditSiteDescriptionTranslation {
+  pub language_id: LanguageId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub sidebar: Option<String>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct SiteDescriptionTranslationsResponse {
+  pub description_translations: Vec<SiteDescriptionTranslation>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Applies the current site defaults for the given settings to all local users who never changed
+/// them.
+pub struct ApplyUserDefaults {
+  pub settings: Vec<DefaultUserSetting>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ApplyUserDefaultsResponse {
+  /// Number of updated users, counted once for each setting.
+  pub updated: i64,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Checks the syntax of a hook condition, and optionally evaluates it against sample data, so that
+/// admins can test it before adding it to the config file.
+pub struct ValidateHookCondition {
+  pub condition: String,
+  /// JSON which is available as `data` in the condition, like the data passed to a hook.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub sample_data: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ValidateHookConditionResponse {
+  /// Why the condition couldn't be parsed or evaluated. If none, the condition is valid.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub error: Option<String>,
+  /// Result of evaluating the condition against the sample data.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub result: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Lists the daily request counts of clients which identify themselves with the
+/// `X-Lemmy-Client` header.
+pub struct ListClientUsage {
+  /// How many days to go back. Defaults to 7, at most 90.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub days: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The client usage, and all currently active client restrictions.
+pub struct ListClientUsageResponse {
+  pub usage: Vec<ClientUsage>,
+  pub restrictions: Vec<ClientRestriction>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Throttles or blocks a client instance-wide, or lifts an existing restriction.
+pub struct RestrictClient {
+  pub client: String,
+  /// If false, an existing restriction is removed.
+  pub restricted: bool,
+  /// Maximum number of requests per minute. If not given, the client is blocked entirely.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub requests_per_minute: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
@@ -643,6 +1058,165 @@ pub struct GetUnreadRegistrationApplicationCountResponse {
   pub registration_applications: i64,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Lists IP subnets and autonomous systems from which several accounts were registered recently.
+/// Only for admins.
+pub struct ListRegistrationBursts {
+  /// How far back to look, in hours. Defaults to 24.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub hours: Option<i64>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Recent registration bursts, the largest first.
+pub struct ListRegistrationBurstsResponse {
+  pub bursts: Vec<RegistrationBurst>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Fetches content which was held back or removed by the spam filter.
+pub struct ListSpamScores {
+  /// Only shows held content which wasn't reviewed yet.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub unreviewed_only: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page: Option<i64>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The spam review queue.
+pub struct ListSpamScoresResponse {
+  pub spam_scores: Vec<SpamScoreView>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Approves content held back or removed by the spam filter, which restores it. Rejecting it
+/// keeps it removed.
+pub struct ReviewSpamScore {
+  pub id: SpamScoreId,
+  pub approve: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct SpamScoreResponse {
+  pub spam_score_view: SpamScoreView,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Fetches suspicious voting patterns which were found by the scheduled vote analysis.
+pub struct ListVoteAnomalies {
+  /// Only shows findings which weren't reviewed yet.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub unreviewed_only: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub page: Option<i64>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The vote anomaly review queue.
+pub struct ListVoteAnomaliesResponse {
+  pub vote_anomalies: Vec<VoteAnomalyView>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Reviews a suspicious voting pattern, either removing the votes which were found or dismissing
+/// the finding. The voters can be banned separately.
+pub struct ReviewVoteAnomaly {
+  pub id: VoteAnomalyId,
+  pub remove_votes: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct VoteAnomalyResponse {
+  pub vote_anomaly_view: VoteAnomalyView,
+  /// The number of votes which were removed.
+  pub removed_votes: i64,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Evaluates changed slur filter and spam settings against recent local posts and comments,
+/// without saving them. Fields which aren't given keep their current value, and are interpreted
+/// the same way as in [EditSite].
+pub struct PreviewContentFilters {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub slur_filter_regex: Option<String>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub slur_filter_normalize: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_link_density_weight: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_repetition_weight: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_account_age_weight: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_low_reputation_weight: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_hold_threshold: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_remove_threshold: Option<i32>,
+  /// How many of the newest posts, and as many comments, to check. Defaults to 100, at most
+  /// 1000.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub limit: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A post or comment which the previewed content filters would have affected.
+pub struct ContentFilterMatch {
+  pub post_id: PostId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub comment_id: Option<CommentId>,
+  pub creator_id: PersonId,
+  /// The parts of the text which match the slur filter.
+  pub slurs: Vec<String>,
+  pub spam_score: i32,
+  pub spam_action: SpamAction,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct PreviewContentFiltersResponse {
+  pub posts_checked: i64,
+  pub comments_checked: i64,
+  pub matches: Vec<ContentFilterMatch>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -0,0 +1,285 @@
+use chrono::{DateTime, Days, Utc};
+use lemmy_db_schema::{
+  newtypes::{CommentId, CommunityId, DbUrl, PostId},
+  source::{
+    comment::Comment,
+    local_site::LocalSite,
+    post::Post,
+    spam_score::{SpamScore, SpamScoreInsertForm},
+  },
+  traits::Crud,
+  utils::DbPool,
+  SpamAction,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+/// Content with this many links per word is considered to consist only of links.
+const MAX_LINKS_PER_WORD: f64 = 0.2;
+/// Posting the same content this often in other places is considered certain spam.
+const MAX_RECENT_DUPLICATES: f64 = 3.0;
+/// Accounts younger than this are considered suspicious, the younger the more so.
+const NEW_ACCOUNT_DAYS: f64 = 30.0;
+/// Creators with this reputation or lower are considered certain spammers.
+const MIN_REPUTATION: f64 = -20.0;
+
+/// The new content which gets scored.
+pub struct SpamCandidate<'a> {
+  /// Post title and body, or comment content.
+  pub text: &'a str,
+  /// When the content was created.
+  pub published: DateTime<Utc>,
+  /// When the creator's account was created.
+  pub creator_published: DateTime<Utc>,
+  /// How often the creator posted the same content elsewhere within the last day.
+  pub recent_duplicates: i64,
+  /// The time-decayed reputation of the creator.
+  pub creator_reputation: i64,
+}
+
+/// A single signal for the spam score. New heuristics only need to be added to [HEURISTICS].
+pub trait SpamHeuristic: Sync {
+  /// How much the heuristic contributes to the score, as configured by the admin.
+  fn weight(&self, local_site: &LocalSite) -> i32;
+  /// Between 0 for no sign of spam, and 1 for certain spam.
+  fn evaluate(&self, candidate: &SpamCandidate) -> f64;
+}
+
+struct LinkDensity;
+
+impl SpamHeuristic for LinkDensity {
+  fn weight(&self, local_site: &LocalSite) -> i32 {
+    local_site.spam_link_density_weight
+  }
+
+  fn evaluate(&self, candidate: &SpamCandidate) -> f64 {
+    let words = candidate.text.split_whitespace().count().max(1);
+    let links = candidate.text.matches("://").count();
+    (links as f64 / words as f64 / MAX_LINKS_PER_WORD).min(1.0)
+  }
+}
+
+struct Repetition;
+
+impl SpamHeuristic for Repetition {
+  fn weight(&self, local_site: &LocalSite) -> i32 {
+    local_site.spam_repetition_weight
+  }
+
+  fn evaluate(&self, candidate: &SpamCandidate) -> f64 {
+    (candidate.recent_duplicates as f64 / MAX_RECENT_DUPLICATES).min(1.0)
+  }
+}
+
+struct AccountAge;
+
+impl SpamHeuristic for AccountAge {
+  fn weight(&self, local_site: &LocalSite) -> i32 {
+    local_site.spam_account_age_weight
+  }
+
+  fn evaluate(&self, candidate: &SpamCandidate) -> f64 {
+    let age_days = (candidate.published - candidate.creator_published).num_hours() as f64 / 24.0;
+    (1.0 - age_days / NEW_ACCOUNT_DAYS).clamp(0.0, 1.0)
+  }
+}
+
+struct LowReputation;
+
+impl SpamHeuristic for LowReputation {
+  fn weight(&self, local_site: &LocalSite) -> i32 {
+    local_site.spam_low_reputation_weight
+  }
+
+  fn evaluate(&self, candidate: &SpamCandidate) -> f64 {
+    (candidate.creator_reputation as f64 / MIN_REPUTATION).clamp(0.0, 1.0)
+  }
+}
+
+static HEURISTICS: [&dyn SpamHeuristic; 4] =
+  [&LinkDensity, &Repetition, &AccountAge, &LowReputation];
+
+/// The spam score of new content, and what should happen to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpamVerdict {
+  pub score: i32,
+  pub action: SpamAction,
+}
+
+impl SpamVerdict {
+  /// Scores the content with the weights and thresholds of the given site. Content by admins is
+  /// never held or removed.
+  pub fn evaluate(
+    candidate: &SpamCandidate,
+    creator_is_admin: bool,
+    local_site: &LocalSite,
+  ) -> Self {
+    let score = HEURISTICS
+      .iter()
+      .map(|h| f64::from(h.weight(local_site)) * h.evaluate(candidate))
+      .sum::<f64>()
+      .round() as i32;
+
+    let reached = |threshold: Option<i32>| threshold.is_some_and(|t| score >= t);
+    let action = if creator_is_admin {
+      SpamAction::None
+    } else if reached(local_site.spam_remove_threshold) {
+      SpamAction::Remove
+    } else if reached(local_site.spam_hold_threshold) {
+      SpamAction::Hold
+    } else {
+      SpamAction::None
+    };
+    SpamVerdict { score, action }
+  }
+
+  /// Scores a new local post before it is created.
+  pub async fn for_post(
+    name: &str,
+    body: Option<&str>,
+    url: Option<&DbUrl>,
+    community_id: CommunityId,
+    local_user_view: &LocalUserView,
+    local_site: &LocalSite,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self> {
+    let person = &local_user_view.person;
+    let recent_duplicates = Post::count_recent_duplicates(
+      pool,
+      person.id,
+      community_id,
+      name,
+      url,
+      Utc::now() - Days::new(1),
+    )
+    .await?;
+    let text = format!("{name}\n{}", body.unwrap_or_default());
+    let candidate = SpamCandidate {
+      text: &text,
+      published: Utc::now(),
+      creator_published: person.published,
+      recent_duplicates,
+      creator_reputation: local_user_view.counts.reputation,
+    };
+    Ok(Self::evaluate(
+      &candidate,
+      local_user_view.local_user.admin,
+      local_site,
+    ))
+  }
+
+  /// Scores a new local comment before it is created.
+  pub async fn for_comment(
+    content: &str,
+    post_id: PostId,
+    local_user_view: &LocalUserView,
+    local_site: &LocalSite,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<Self> {
+    let person = &local_user_view.person;
+    let recent_duplicates = Comment::count_recent_duplicates(
+      pool,
+      person.id,
+      post_id,
+      content,
+      Utc::now() - Days::new(1),
+    )
+    .await?;
+    let candidate = SpamCandidate {
+      text: content,
+      published: Utc::now(),
+      creator_published: person.published,
+      recent_duplicates,
+      creator_reputation: local_user_view.counts.reputation,
+    };
+    Ok(Self::evaluate(
+      &candidate,
+      local_user_view.local_user.admin,
+      local_site,
+    ))
+  }
+
+  /// Held and removed content is created as removed, and not federated.
+  pub fn hides_content(&self) -> bool {
+    self.action != SpamAction::None
+  }
+
+  /// Holds the content back for review, unless it is removed anyway.
+  pub fn hold(self) -> Self {
+    let action = match self.action {
+      SpamAction::None => SpamAction::Hold,
+      action => action,
+    };
+    SpamVerdict { action, ..self }
+  }
+
+  pub async fn record(
+    &self,
+    local_user_view: &LocalUserView,
+    post_id: PostId,
+    comment_id: Option<CommentId>,
+    pool: &mut DbPool<'_>,
+  ) -> LemmyResult<()> {
+    let form = SpamScoreInsertForm::new(
+      local_user_view.person.id,
+      post_id,
+      comment_id,
+      self.score,
+      self.action,
+    );
+    SpamScore::create(pool, &form).await?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  fn candidate(
+    text: &str,
+    age_days: u64,
+    recent_duplicates: i64,
+    creator_reputation: i64,
+  ) -> SpamCandidate<'_> {
+    SpamCandidate {
+      text,
+      published: Utc::now(),
+      creator_published: Utc::now() - Days::new(age_days),
+      recent_duplicates,
+      creator_reputation,
+    }
+  }
+
+  #[test]
+  fn test_spam_heuristics() {
+    let plain = candidate("just a normal comment about the topic", 60, 0, 15);
+    assert_eq!(0.0, LinkDensity.evaluate(&plain));
+    assert_eq!(0.0, Repetition.evaluate(&plain));
+    assert_eq!(0.0, AccountAge.evaluate(&plain));
+    assert_eq!(0.0, LowReputation.evaluate(&plain));
+
+    let spam = candidate(
+      "buy now https://spam.example https://spam.example/2",
+      0,
+      5,
+      -50,
+    );
+    assert_eq!(1.0, LinkDensity.evaluate(&spam));
+    assert_eq!(1.0, Repetition.evaluate(&spam));
+    assert_eq!(1.0, AccountAge.evaluate(&spam));
+    assert_eq!(1.0, LowReputation.evaluate(&spam));
+
+    let some_links = candidate(
+      "an interesting article https://example.com which i found while reading about this topic",
+      15,
+      1,
+      -10,
+    );
+    assert!(LinkDensity.evaluate(&some_links) < 1.0);
+    assert!(Repetition.evaluate(&some_links) < 1.0);
+    assert_eq!(0.5, AccountAge.evaluate(&some_links));
+    assert_eq!(0.5, LowReputation.evaluate(&some_links));
+  }
+}
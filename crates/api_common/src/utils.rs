@@ -1,5 +1,6 @@
 use crate::{
   context::LemmyContext,
+  post::PostGalleryImage,
   request::{
     delete_image_from_pictrs,
     fetch_pictrs_proxied_image_details,
@@ -7,15 +8,24 @@ use crate::{
   },
   site::{FederatedInstances, InstanceWithFederationState},
 };
+use activitypub_federation::http_signatures::generate_actor_keypair;
 use chrono::{DateTime, Days, Local, TimeZone, Utc};
+use chrono_tz::Tz;
 use enum_map::{enum_map, EnumMap};
 use lemmy_db_schema::{
   aggregates::structs::{PersonPostAggregates, PersonPostAggregatesForm},
-  newtypes::{CommentId, CommunityId, DbUrl, InstanceId, PersonId, PostId},
+  newtypes::{BanListId, CommentId, CommunityId, DbUrl, InstanceId, PersonId, PostId},
   source::{
+    ban_list::BanList,
     comment::{Comment, CommentLike, CommentUpdateForm},
+    comment_report::CommentReport,
     community::{Community, CommunityModerator, CommunityUpdateForm},
     community_block::CommunityBlock,
+    community_bot::{CommunityBotAction, CommunityBotActionForm, CommunityBotDelegation},
+    community_domain_block::CommunityDomainBlock,
+    community_name_alias::CommunityNameAlias,
+    community_role::{CommunityPermission, CommunityRolePermission},
+    custom_feed::CustomFeed,
     email_verification::{EmailVerification, EmailVerificationForm},
     images::{ImageDetails, RemoteImage},
     instance::Instance,
@@ -23,18 +33,26 @@ use lemmy_db_schema::{
     local_site::LocalSite,
     local_site_rate_limit::LocalSiteRateLimit,
     local_site_url_blocklist::LocalSiteUrlBlocklist,
+    local_user::LocalUser,
     moderator::{ModRemoveComment, ModRemoveCommentForm, ModRemovePost, ModRemovePostForm},
     oauth_account::OAuthAccount,
     password_reset_request::PasswordResetRequest,
-    person::{Person, PersonUpdateForm},
+    person::{Person, PersonFollower, PersonInsertForm, PersonUpdateForm},
     person_block::PersonBlock,
+    pm_conversation::PmConversation,
     post::{Post, PostLike},
+    post_media::PostMediaForm,
+    post_report::PostReport,
     registration_application::RegistrationApplication,
     site::Site,
   },
-  traits::{Crud, Likeable},
+  traits::{ApubActor, Crud, Likeable},
   utils::DbPool,
+  AllowPrivateMessages,
+  CommunityBotScope,
+  CommunityRole,
   FederationMode,
+  NewAccountRestriction,
   RegistrationMode,
 };
 use lemmy_db_views::{
@@ -57,13 +75,19 @@ use lemmy_utils::{
   },
   utils::{
     markdown::{image_links::markdown_rewrite_image_links, markdown_check_for_blocked_urls},
-    slurs::{build_slur_regex, remove_slurs},
-    validation::clean_urls_in_text,
+    slurs::{build_slur_regex, remove_slurs, SlurRegex},
+    validation::{
+      clean_url,
+      clean_urls_in_text,
+      is_url_blocked,
+      is_valid_alt_text_field,
+      is_valid_url,
+    },
   },
   CACHE_DURATION_FEDERATION,
 };
 use moka::future::Cache;
-use regex::{escape, Regex, RegexSet};
+use regex::{escape, RegexSet};
 use rosetta_i18n::{Language, LanguageId};
 use std::sync::LazyLock;
 use tracing::warn;
@@ -113,6 +137,23 @@ pub async fn check_community_mod_of_any_or_admin_action(
   CommunityView::check_is_mod_of_any_or_admin(pool, person.id).await
 }
 
+/// Check that a person can manage a ban list. This is the case for its creator, the moderators of
+/// subscribed communities, and admins.
+pub async fn check_ban_list_manager(
+  local_user_view: &LocalUserView,
+  ban_list_id: BanListId,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  check_user_valid(&local_user_view.person)?;
+  if local_user_view.local_user.admin
+    || BanList::is_manager(pool, ban_list_id, local_user_view.person.id).await?
+  {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::NotAModOrAdmin)?
+  }
+}
+
 pub fn is_admin(local_user_view: &LocalUserView) -> LemmyResult<()> {
   check_user_valid(&local_user_view.person)?;
   if !local_user_view.local_user.admin {
@@ -173,6 +214,15 @@ pub fn check_user_valid(person: &Person) -> LemmyResult<()> {
   }
 }
 
+/// Like [check_user_valid], but also checks restrictions which only exist for local accounts.
+pub fn check_local_user_valid(local_user_view: &LocalUserView) -> LemmyResult<()> {
+  check_user_valid(&local_user_view.person)?;
+  if local_user_view.local_user.frozen {
+    Err(LemmyErrorType::AccountFrozen)?
+  }
+  Ok(())
+}
+
 /// Check if the user's email is verified if email verification is turned on
 /// However, skip checking verification if the user is an admin
 pub fn check_email_verified(
@@ -229,6 +279,111 @@ pub async fn check_community_user_action(
   Ok(())
 }
 
+/// Checks that the user's reputation is high enough to post and comment in the community.
+/// Moderators and admins are exempt.
+pub async fn check_community_min_reputation(
+  local_user_view: &LocalUserView,
+  community: &Community,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  if let Some(min_reputation) = community.posting_min_reputation {
+    if local_user_view.counts.reputation < min_reputation
+      && is_mod_or_admin(pool, &local_user_view.person, community.id)
+        .await
+        .is_err()
+    {
+      Err(LemmyErrorType::ReputationTooLow)?
+    }
+  }
+  Ok(())
+}
+
+/// Checks that the account is old and active enough for the action, if the site or community
+/// restricts it for new accounts. Admins, and moderators for the community thresholds, are exempt.
+pub async fn check_new_account_restrictions(
+  local_user_view: &LocalUserView,
+  local_site: &LocalSite,
+  community: Option<&Community>,
+  restriction: NewAccountRestriction,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  if local_user_view.local_user.admin {
+    return Ok(());
+  }
+  let email_verified =
+    local_user_view.local_user.email.is_some() && local_user_view.local_user.email_verified;
+  let check = |min_age_days, min_comments, require_verified_email| {
+    check_new_account_thresholds(
+      local_user_view.person.published,
+      local_user_view.counts.comment_count,
+      email_verified,
+      min_age_days,
+      min_comments,
+      require_verified_email,
+    )
+  };
+
+  if local_site.new_account_restrictions.contains(&restriction) {
+    check(
+      local_site.new_account_min_age_days,
+      local_site.new_account_min_comments,
+      local_site.new_account_require_verified_email,
+    )?;
+  }
+  if let Some(community) = community {
+    if community.new_account_restrictions.contains(&restriction)
+      && is_mod_or_admin(pool, &local_user_view.person, community.id)
+        .await
+        .is_err()
+    {
+      check(
+        community.new_account_min_age_days,
+        community.new_account_min_comments,
+        community.new_account_require_verified_email,
+      )?;
+    }
+  }
+  Ok(())
+}
+
+fn check_new_account_thresholds(
+  published: DateTime<Utc>,
+  comment_count: i64,
+  email_verified: bool,
+  min_age_days: Option<i32>,
+  min_comments: Option<i32>,
+  require_verified_email: bool,
+) -> LemmyResult<()> {
+  if let Some(min_age_days) = min_age_days {
+    if (Utc::now() - published).num_days() < i64::from(min_age_days) {
+      Err(LemmyErrorType::AccountTooNew { min_age_days })?
+    }
+  }
+  if let Some(min_comments) = min_comments {
+    if comment_count < i64::from(min_comments) {
+      Err(LemmyErrorType::TooFewComments { min_comments })?
+    }
+  }
+  if require_verified_email && !email_verified {
+    Err(LemmyErrorType::VerifiedEmailRequired)?
+  }
+  Ok(())
+}
+
+/// Checks that the post link doesn't go to a domain which the community blocked.
+pub async fn check_community_domain_blocked(
+  community_id: CommunityId,
+  url: Option<&DbUrl>,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  if let Some(host) = url.and_then(|url| url.host_str()) {
+    if CommunityDomainBlock::is_blocked(pool, community_id, host).await? {
+      Err(LemmyErrorType::DomainBlockedByCommunity)?
+    }
+  }
+  Ok(())
+}
+
 pub fn check_community_deleted_removed(community: &Community) -> LemmyResult<()> {
   if community.deleted || community.removed {
     Err(LemmyErrorType::Deleted)?
@@ -236,6 +391,20 @@ pub fn check_community_deleted_removed(community: &Community) -> LemmyResult<()>
   Ok(())
 }
 
+/// Check that a name can be used for a new or renamed local community. Old names of renamed
+/// communities and names reserved by admins can't be used.
+pub async fn check_community_name_available(pool: &mut DbPool<'_>, name: &str) -> LemmyResult<()> {
+  if let Some(alias) = CommunityNameAlias::read(pool, name).await? {
+    if alias.community_id.is_none() {
+      Err(LemmyErrorType::CommunityNameReserved)?
+    }
+  }
+  if CommunityNameAlias::is_name_taken(pool, name).await? {
+    Err(LemmyErrorType::CommunityAlreadyExists)?
+  }
+  Ok(())
+}
+
 /// Check that the given user can perform a mod action in the community.
 ///
 /// In particular it checks that he is an admin or mod, wasn't banned and the community isn't
@@ -256,6 +425,116 @@ pub async fn check_community_mod_action(
   Ok(())
 }
 
+/// Like [check_community_mod_action], but also allows bots which authenticated with the token of
+/// a delegation from one of the community's moderators, if the token has the scope.
+///
+/// Returns the delegation if the action is performed by a bot. In that case the moderator who
+/// created the delegation should be used for hierarchy checks.
+pub async fn check_community_mod_action_or_bot(
+  person: &Person,
+  bot_delegation: &Option<CommunityBotDelegation>,
+  community: &Community,
+  scope: CommunityBotScope,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<Option<CommunityBotDelegation>> {
+  let Some(delegation) = bot_delegation else {
+    return check_community_mod_action(person, community, false, pool)
+      .await
+      .map(|_| None);
+  };
+  if delegation.community_id != community.id || !delegation.allows(scope) {
+    Err(LemmyErrorType::NotAModOrAdmin)?
+  }
+
+  check_user_valid(person)?;
+  CommunityPersonBanView::check(pool, person.id, community.id).await?;
+  check_community_deleted_removed(community)?;
+  // The delegation is only valid as long as its creator is still a moderator
+  CommunityView::check_is_mod_or_admin(pool, delegation.creator_id, community.id).await?;
+  Ok(Some(delegation.clone()))
+}
+
+/// Check that the person may perform an action in the community, based on their role and the
+/// permissions which the community owner gave to that role. Admins may do everything.
+pub async fn check_community_permission(
+  person: &Person,
+  community: &Community,
+  permission: CommunityPermission,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  check_user_valid(person)?;
+  // Admins can act in any community, regardless of community bans or its removal
+  if LocalUserView::read_person(pool, person.id)
+    .await
+    .is_ok_and(|l| l.local_user.admin)
+  {
+    return Ok(());
+  }
+  CommunityPersonBanView::check(pool, person.id, community.id).await?;
+  check_community_deleted_removed(community)?;
+
+  let role = CommunityRole::read(pool, community.id, person.id).await?;
+  if CommunityRolePermission::read(pool, community.id, role)
+    .await?
+    .allows(permission)
+  {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::MissingCommunityPermission)?
+  }
+}
+
+/// Like [check_community_mod_action_or_bot], but logged in users are checked with
+/// [check_community_permission] instead of having to be moderators.
+pub async fn check_community_permission_or_bot(
+  person: &Person,
+  bot_delegation: &Option<CommunityBotDelegation>,
+  community: &Community,
+  permission: CommunityPermission,
+  scope: CommunityBotScope,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<Option<CommunityBotDelegation>> {
+  if bot_delegation.is_none() {
+    return check_community_permission(person, community, permission, pool)
+      .await
+      .map(|_| None);
+  }
+  check_community_mod_action_or_bot(person, bot_delegation, community, scope, pool).await
+}
+
+/// The moderator with whose authority an action is performed. For a bot this is the moderator
+/// who created the delegation, and the action is attributed to the community in the mod log.
+pub fn acting_mod_person_id(
+  person: &Person,
+  delegation: &Option<CommunityBotDelegation>,
+) -> PersonId {
+  delegation.as_ref().map_or(person.id, |d| d.creator_id)
+}
+
+/// Writes an entry to the bot audit log, if the action was performed through a delegation.
+pub async fn log_community_bot_action(
+  delegation: &Option<CommunityBotDelegation>,
+  scope: CommunityBotScope,
+  post_id: Option<PostId>,
+  comment_id: Option<CommentId>,
+  enabled: bool,
+  reason: Option<String>,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  if let Some(delegation) = delegation {
+    let form = CommunityBotActionForm {
+      delegation_id: delegation.id,
+      scope,
+      post_id,
+      comment_id,
+      enabled,
+      reason,
+    };
+    CommunityBotAction::create(pool, &form).await?;
+  }
+  Ok(())
+}
+
 /// Don't allow creating reports for removed / deleted posts
 pub fn check_post_deleted_or_removed(post: &Post) -> LemmyResult<()> {
   if post.deleted || post.removed {
@@ -341,6 +620,22 @@ pub fn check_private_instance(
   }
 }
 
+/// Private custom feeds can only be viewed by their creator. Others get a not found error, so that
+/// the feed isn't revealed.
+pub fn check_custom_feed_visible(
+  custom_feed: &CustomFeed,
+  local_user_view: &Option<LocalUserView>,
+) -> LemmyResult<()> {
+  let is_creator = local_user_view
+    .as_ref()
+    .is_some_and(|l| l.person.id == custom_feed.creator_id);
+  if custom_feed.public || is_creator {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::NotFound)?
+  }
+}
+
 /// If private messages are disabled, dont allow them to be sent / received
 #[tracing::instrument(skip_all)]
 pub fn check_private_messages_enabled(local_user_view: &LocalUserView) -> Result<(), LemmyError> {
@@ -351,6 +646,41 @@ pub fn check_private_messages_enabled(local_user_view: &LocalUserView) -> Result
   }
 }
 
+/// Checks that the recipient accepts private messages from the sender.
+pub async fn check_private_messages_allowed(
+  sender_id: PersonId,
+  recipient: &LocalUserView,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  let allowed = match recipient.local_user.allow_private_messages {
+    AllowPrivateMessages::Everyone => true,
+    AllowPrivateMessages::Followed => {
+      PersonFollower::is_following(pool, recipient.person.id, sender_id).await?
+    }
+    AllowPrivateMessages::Nobody => false,
+  };
+  if !allowed {
+    Err(LemmyErrorType::PrivateMessagesNotAllowed)?
+  } else {
+    Ok(())
+  }
+}
+
+/// Returns true if a direct message from the sender should be held back as a message request,
+/// because the recipient enabled message requests and never talked to the sender before.
+pub async fn is_private_message_request(
+  sender_id: PersonId,
+  recipient: &LocalUserView,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<bool> {
+  let conversation = PmConversation::read_direct(pool, sender_id, recipient.person.id).await?;
+  match conversation {
+    // Further messages are held back too, until the request is accepted
+    Some(c) => Ok(c.request_recipient_id == Some(recipient.person.id)),
+    None => Ok(recipient.local_user.private_message_requests),
+  }
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn build_federated_instances(
   local_site: &LocalSite,
@@ -414,7 +744,10 @@ pub async fn send_email_to_user(
   body: &str,
   settings: &Settings,
 ) {
-  if local_user_view.person.banned || !local_user_view.local_user.send_notifications_to_email {
+  if local_user_view.person.banned
+    || !local_user_view.local_user.send_notifications_to_email
+    || is_in_quiet_hours(&local_user_view.local_user, Utc::now())
+  {
     return;
   }
 
@@ -434,6 +767,34 @@ pub async fn send_email_to_user(
   }
 }
 
+/// Checks if the given time is within the quiet hours of the user, during which notification
+/// emails are held back. Quiet hours which end before they start wrap around midnight.
+pub fn is_in_quiet_hours(local_user: &LocalUser, now: DateTime<Utc>) -> bool {
+  if !local_user.quiet_hours_enabled {
+    return false;
+  }
+  let timezone = local_user
+    .quiet_hours_timezone
+    .parse::<Tz>()
+    .unwrap_or(Tz::UTC);
+  let time = now.with_timezone(&timezone).time();
+  let (start, end) = (local_user.quiet_hours_start, local_user.quiet_hours_end);
+  if start <= end {
+    start <= time && time < end
+  } else {
+    start <= time || time < end
+  }
+}
+
+/// Checks that the given name is a valid IANA timezone, for example `Europe/Berlin`.
+pub fn check_timezone_valid(timezone: &str) -> LemmyResult<()> {
+  if timezone.parse::<Tz>().is_err() {
+    Err(LemmyErrorType::InvalidTimezone)?
+  } else {
+    Ok(())
+  }
+}
+
 pub async fn send_password_reset_email(
   user: &LocalUserView,
   pool: &mut DbPool<'_>,
@@ -518,25 +879,28 @@ pub fn local_site_rate_limit_to_rate_limit_config(
   })
 }
 
-pub fn local_site_to_slur_regex(local_site: &LocalSite) -> Option<Regex> {
-  build_slur_regex(local_site.slur_filter_regex.as_deref())
+pub fn local_site_to_slur_regex(local_site: &LocalSite) -> Option<SlurRegex> {
+  build_slur_regex(
+    local_site.slur_filter_regex.as_deref(),
+    local_site.slur_filter_normalize,
+  )
 }
 
-pub fn local_site_opt_to_slur_regex(local_site: &Option<LocalSite>) -> Option<Regex> {
+pub fn local_site_opt_to_slur_regex(local_site: &Option<LocalSite>) -> Option<SlurRegex> {
   local_site
     .as_ref()
     .map(local_site_to_slur_regex)
     .unwrap_or(None)
 }
 
-pub async fn get_url_blocklist(context: &LemmyContext) -> LemmyResult<RegexSet> {
-  static URL_BLOCKLIST: LazyLock<Cache<(), RegexSet>> = LazyLock::new(|| {
-    Cache::builder()
-      .max_capacity(1)
-      .time_to_live(CACHE_DURATION_FEDERATION)
-      .build()
-  });
+static URL_BLOCKLIST: LazyLock<Cache<(), RegexSet>> = LazyLock::new(|| {
+  Cache::builder()
+    .max_capacity(1)
+    .time_to_live(CACHE_DURATION_FEDERATION)
+    .build()
+});
 
+pub async fn get_url_blocklist(context: &LemmyContext) -> LemmyResult<RegexSet> {
   Ok(
     URL_BLOCKLIST
       .try_get_with::<_, LemmyError>((), async {
@@ -553,6 +917,11 @@ pub async fn get_url_blocklist(context: &LemmyContext) -> LemmyResult<RegexSet>
   )
 }
 
+/// Drops the cached URL blocklist, so that the next read gets it from the database.
+pub fn invalidate_url_blocklist_cache() {
+  URL_BLOCKLIST.invalidate_all();
+}
+
 pub async fn send_application_approved_email(
   user: &LocalUserView,
   settings: &Settings,
@@ -775,10 +1144,11 @@ pub async fn remove_or_restore_user_data(
   // Posts
   let removed_or_restored_posts =
     Post::update_removed_for_creator(pool, banned_person_id, None, removed).await?;
+  let post_ids: Vec<PostId> = removed_or_restored_posts.iter().map(|r| r.id).collect();
   create_modlog_entries_for_removed_or_restored_posts(
     pool,
     mod_person_id,
-    removed_or_restored_posts.iter().map(|r| r.id).collect(),
+    post_ids.clone(),
     removed,
     reason,
   )
@@ -787,15 +1157,33 @@ pub async fn remove_or_restore_user_data(
   // Comments
   let removed_or_restored_comments =
     Comment::update_removed_for_creator(pool, banned_person_id, removed).await?;
+  let comment_ids: Vec<CommentId> = removed_or_restored_comments.iter().map(|r| r.id).collect();
   create_modlog_entries_for_removed_or_restored_comments(
     pool,
     mod_person_id,
-    removed_or_restored_comments.iter().map(|r| r.id).collect(),
+    comment_ids.clone(),
     removed,
     reason,
   )
   .await?;
 
+  if removed {
+    resolve_reports_for_removed_content(pool, mod_person_id, &post_ids, &comment_ids).await?;
+  }
+
+  Ok(())
+}
+
+/// Resolves the pending reports for removed posts and comments, so that they don't stay in the
+/// report queue.
+async fn resolve_reports_for_removed_content(
+  pool: &mut DbPool<'_>,
+  mod_person_id: PersonId,
+  post_ids: &[PostId],
+  comment_ids: &[CommentId],
+) -> LemmyResult<()> {
+  PostReport::resolve_all_for_posts(pool, post_ids, mod_person_id).await?;
+  CommentReport::resolve_all_for_comments(pool, comment_ids, mod_person_id).await?;
   Ok(())
 }
 
@@ -814,6 +1202,7 @@ async fn create_modlog_entries_for_removed_or_restored_posts(
       post_id,
       removed: Some(removed),
       reason: reason.clone(),
+      automod: false,
     })
     .collect();
 
@@ -837,6 +1226,7 @@ async fn create_modlog_entries_for_removed_or_restored_comments(
       comment_id,
       removed: Some(removed),
       reason: reason.clone(),
+      automod: false,
     })
     .collect();
 
@@ -856,10 +1246,11 @@ pub async fn remove_or_restore_user_data_in_community(
   // Posts
   let posts =
     Post::update_removed_for_creator(pool, banned_person_id, Some(community_id), remove).await?;
+  let post_ids: Vec<PostId> = posts.iter().map(|r| r.id).collect();
   create_modlog_entries_for_removed_or_restored_posts(
     pool,
     mod_person_id,
-    posts.iter().map(|r| r.id).collect(),
+    post_ids.clone(),
     remove,
     reason,
   )
@@ -889,15 +1280,20 @@ pub async fn remove_or_restore_user_data_in_community(
     .await?;
   }
 
+  let comment_ids: Vec<CommentId> = comments.iter().map(|r| r.comment.id).collect();
   create_modlog_entries_for_removed_or_restored_comments(
     pool,
     mod_person_id,
-    comments.iter().map(|r| r.comment.id).collect(),
+    comment_ids.clone(),
     remove,
     reason,
   )
   .await?;
 
+  if remove {
+    resolve_reports_for_removed_content(pool, mod_person_id, &post_ids, &comment_ids).await?;
+  }
+
   Ok(())
 }
 
@@ -943,6 +1339,53 @@ pub async fn purge_user_account(person_id: PersonId, context: &LemmyContext) ->
   Ok(())
 }
 
+/// Deletes the account but keeps its posts and comments, which are attributed to a placeholder
+/// account instead.
+pub async fn anonymize_user_account(
+  person_id: PersonId,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let pool = &mut context.pool();
+
+  let tombstone = match Person::read_from_name(pool, Person::TOMBSTONE_NAME, true).await? {
+    Some(tombstone) => tombstone,
+    None => create_tombstone_person(context).await?,
+  };
+  Person::reassign_content(pool, person_id, tombstone.id).await?;
+
+  CommunityModerator::leave_all_communities(pool, person_id).await?;
+
+  if let Ok(local_user) = LocalUserView::read_person(pool, person_id).await {
+    OAuthAccount::delete_user_accounts(pool, local_user.local_user.id).await?;
+  }
+
+  Person::delete_account(pool, person_id).await?;
+
+  Ok(())
+}
+
+async fn create_tombstone_person(context: &LemmyContext) -> LemmyResult<Person> {
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+  let actor_keypair = generate_actor_keypair()?;
+  let actor_id = generate_local_apub_endpoint(
+    EndpointType::Person,
+    Person::TOMBSTONE_NAME,
+    &context.settings().get_protocol_and_hostname(),
+  )?;
+  let form = PersonInsertForm {
+    actor_id: Some(actor_id),
+    inbox_url: Some(generate_inbox_url()?),
+    private_key: Some(actor_keypair.private_key),
+    deleted: Some(true),
+    ..PersonInsertForm::new(
+      Person::TOMBSTONE_NAME.to_string(),
+      actor_keypair.public_key,
+      site_view.site.instance_id,
+    )
+  };
+  Ok(Person::create(&mut context.pool(), &form).await?)
+}
+
 pub enum EndpointType {
   Community,
   Person,
@@ -1028,9 +1471,36 @@ pub fn check_conflicting_like_filters(
   }
 }
 
+const MAX_GALLERY_IMAGES: usize = 20;
+
+/// Checks the urls and alt texts of the images of a gallery post.
+pub fn process_post_gallery(
+  gallery: &[PostGalleryImage],
+  url_blocklist: &RegexSet,
+) -> LemmyResult<Vec<PostMediaForm>> {
+  if gallery.len() > MAX_GALLERY_IMAGES {
+    Err(LemmyErrorType::TooManyGalleryImages)?
+  }
+  gallery
+    .iter()
+    .map(|image| {
+      let url = clean_url(&Url::parse(&image.url).with_lemmy_type(LemmyErrorType::InvalidUrl)?);
+      is_url_blocked(&url, url_blocklist)?;
+      is_valid_url(&url)?;
+      if let Some(alt_text) = &image.alt_text {
+        is_valid_alt_text_field(alt_text)?;
+      }
+      Ok(PostMediaForm {
+        alt_text: image.alt_text.clone(),
+        ..PostMediaForm::new(url.into())
+      })
+    })
+    .collect()
+}
+
 pub async fn process_markdown(
   text: &str,
-  slur_regex: &Option<Regex>,
+  slur_regex: &Option<SlurRegex>,
   url_blocklist: &RegexSet,
   context: &LemmyContext,
 ) -> LemmyResult<String> {
@@ -1062,7 +1532,7 @@ pub async fn process_markdown(
 
 pub async fn process_markdown_opt(
   text: &Option<String>,
-  slur_regex: &Option<Regex>,
+  slur_regex: &Option<SlurRegex>,
   url_blocklist: &RegexSet,
   context: &LemmyContext,
 ) -> LemmyResult<Option<String>> {
@@ -1166,13 +1636,30 @@ fn build_proxied_image_url(
 mod tests {
 
   use super::*;
-  use lemmy_db_schema::source::{
-    comment::CommentInsertForm,
-    community::CommunityInsertForm,
-    person::PersonInsertForm,
-    post::PostInsertForm,
+  use crate::community_bot::generate_community_bot_token;
+  use lemmy_db_schema::{
+    source::{
+      comment::CommentInsertForm,
+      community::{
+        CommunityInsertForm,
+        CommunityModeratorForm,
+        CommunityPersonBan,
+        CommunityPersonBanForm,
+      },
+      community_bot::{CommunityBotDelegationInsertForm, CommunityBotDelegationUpdateForm},
+      community_role::CommunityRolePermissionForm,
+      local_site::LocalSiteInsertForm,
+      local_site_rate_limit::LocalSiteRateLimitInsertForm,
+      local_user::LocalUserInsertForm,
+      moderator::{ModLockPost, ModLockPostForm},
+      person::PersonInsertForm,
+      post::PostInsertForm,
+      site::SiteInsertForm,
+    },
+    traits::{Bannable, Joinable},
   };
   use lemmy_db_views_moderator::structs::{
+    ModLockPostView,
     ModRemoveCommentView,
     ModRemovePostView,
     ModlogListParams,
@@ -1189,6 +1676,91 @@ mod tests {
     assert!(password_length_check("looooooooooooooooooooooooooooooooooooooooooooooooooooooooooong").is_err());
   }
 
+  #[test]
+  fn test_check_new_account_thresholds() {
+    let week_old = Utc::now() - chrono::Duration::days(7);
+    assert!(check_new_account_thresholds(week_old, 0, false, None, None, false).is_ok());
+    assert!(check_new_account_thresholds(week_old, 0, false, Some(7), None, false).is_ok());
+    assert_eq!(
+      Some(LemmyErrorType::AccountTooNew { min_age_days: 8 }),
+      check_new_account_thresholds(week_old, 0, false, Some(8), None, false)
+        .err()
+        .map(|e| e.error_type)
+    );
+    assert_eq!(
+      Some(LemmyErrorType::TooFewComments { min_comments: 3 }),
+      check_new_account_thresholds(week_old, 2, true, None, Some(3), true)
+        .err()
+        .map(|e| e.error_type)
+    );
+    assert_eq!(
+      Some(LemmyErrorType::VerifiedEmailRequired),
+      check_new_account_thresholds(week_old, 3, false, None, Some(3), true)
+        .err()
+        .map(|e| e.error_type)
+    );
+  }
+
+  #[test]
+  fn test_is_in_quiet_hours() -> LemmyResult<()> {
+    let time =
+      |t: &str| -> LemmyResult<DateTime<Utc>> { Ok(DateTime::parse_from_rfc3339(t)?.to_utc()) };
+    let mut local_user = LocalUser {
+      quiet_hours_enabled: true,
+      quiet_hours_start: "22:00:00".parse()?,
+      quiet_hours_end: "07:00:00".parse()?,
+      quiet_hours_timezone: "Europe/Berlin".to_string(),
+      ..Default::default()
+    };
+
+    // Berlin is one hour ahead of UTC in winter, and two hours ahead in summer
+    assert!(is_in_quiet_hours(
+      &local_user,
+      time("2024-01-15T21:30:00Z")?
+    ));
+    assert!(is_in_quiet_hours(
+      &local_user,
+      time("2024-01-15T05:30:00Z")?
+    ));
+    assert!(!is_in_quiet_hours(
+      &local_user,
+      time("2024-01-15T06:30:00Z")?
+    ));
+    assert!(!is_in_quiet_hours(
+      &local_user,
+      time("2024-07-15T19:30:00Z")?
+    ));
+    assert!(is_in_quiet_hours(
+      &local_user,
+      time("2024-07-15T20:30:00Z")?
+    ));
+
+    local_user.quiet_hours_start = "09:00:00".parse()?;
+    local_user.quiet_hours_end = "17:00:00".parse()?;
+    assert!(is_in_quiet_hours(
+      &local_user,
+      time("2024-01-15T12:00:00Z")?
+    ));
+    assert!(!is_in_quiet_hours(
+      &local_user,
+      time("2024-01-15T20:00:00Z")?
+    ));
+
+    local_user.quiet_hours_enabled = false;
+    assert!(!is_in_quiet_hours(
+      &local_user,
+      time("2024-01-15T12:00:00Z")?
+    ));
+    Ok(())
+  }
+
+  #[test]
+  fn test_check_timezone_valid() {
+    assert!(check_timezone_valid("Europe/Berlin").is_ok());
+    assert!(check_timezone_valid("UTC").is_ok());
+    assert!(check_timezone_valid("Mars/Olympus_Mons").is_err());
+  }
+
   #[test]
   fn honeypot() {
     assert!(honeypot_check(&None).is_ok());
@@ -1401,4 +1973,351 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_community_bot_mod_log() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let inserted_mod = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "bot_owner"),
+    )
+    .await?;
+    let bot_form = PersonInsertForm {
+      bot_account: Some(true),
+      ..PersonInsertForm::test_form(inserted_instance.id, "automod")
+    };
+    let inserted_bot = Person::create(pool, &bot_form).await?;
+
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "bot_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+    let moderator_form = CommunityModeratorForm {
+      community_id: inserted_community.id,
+      person_id: inserted_mod.id,
+    };
+    CommunityModerator::join(pool, &moderator_form).await?;
+
+    let post_form = PostInsertForm::new(
+      "A post for the bot".into(),
+      inserted_mod.id,
+      inserted_community.id,
+    );
+    let inserted_post = Post::create(pool, &post_form).await?;
+
+    // Without a delegation the bot can't act as a moderator
+    let no_delegation = check_community_mod_action_or_bot(
+      &inserted_bot,
+      &None,
+      &inserted_community,
+      CommunityBotScope::LockPost,
+      pool,
+    )
+    .await;
+    assert!(no_delegation.is_err());
+
+    let delegation_form = CommunityBotDelegationInsertForm::new(
+      inserted_community.id,
+      inserted_bot.id,
+      inserted_mod.id,
+      vec![CommunityBotScope::LockPost],
+      generate_community_bot_token(),
+    );
+    let inserted_delegation = CommunityBotDelegation::create(pool, &delegation_form).await?;
+    let token_delegation =
+      CommunityBotDelegation::read_from_token(pool, &inserted_delegation.token).await?;
+    assert_eq!(Some(&inserted_delegation), token_delegation.as_ref());
+
+    // The token only works for its scopes
+    let wrong_scope = check_community_mod_action_or_bot(
+      &inserted_bot,
+      &token_delegation,
+      &inserted_community,
+      CommunityBotScope::RemovePost,
+      pool,
+    )
+    .await;
+    assert!(wrong_scope.is_err());
+
+    let delegation = check_community_mod_action_or_bot(
+      &inserted_bot,
+      &token_delegation,
+      &inserted_community,
+      CommunityBotScope::LockPost,
+      pool,
+    )
+    .await?;
+    assert!(delegation.is_some());
+    assert_eq!(
+      inserted_mod.id,
+      acting_mod_person_id(&inserted_bot, &delegation)
+    );
+
+    // The moderator locks the post, then the bot unlocks it
+    let mod_form = ModLockPostForm {
+      mod_person_id: inserted_mod.id,
+      post_id: inserted_post.id,
+      locked: Some(true),
+      automod: false,
+    };
+    ModLockPost::create(pool, &mod_form).await?;
+    let bot_form = ModLockPostForm {
+      mod_person_id: acting_mod_person_id(&inserted_bot, &delegation),
+      post_id: inserted_post.id,
+      locked: Some(false),
+      automod: delegation.is_some(),
+    };
+    ModLockPost::create(pool, &bot_form).await?;
+
+    let params = ModlogListParams {
+      community_id: Some(inserted_community.id),
+      mod_person_id: None,
+      other_person_id: None,
+      post_id: None,
+      comment_id: None,
+      page: None,
+      limit: None,
+      hide_modlog_names: false,
+    };
+    let modlog = ModLockPostView::list(pool, params).await?;
+    assert_eq!(2, modlog.len());
+
+    // The bot action is attributed to the community, without showing the bot or the moderator
+    assert!(modlog[0].mod_lock_post.automod);
+    assert!(modlog[0].moderator.is_none());
+    assert!(!modlog[1].mod_lock_post.automod);
+    assert_eq!(
+      Some(inserted_mod.id),
+      modlog[1].moderator.as_ref().map(|m| m.id)
+    );
+
+    // The bot action isn't listed under the moderator either
+    let mod_params = ModlogListParams {
+      mod_person_id: Some(inserted_mod.id),
+      ..params
+    };
+    let mod_modlog = ModLockPostView::list(pool, mod_params).await?;
+    assert_eq!(1, mod_modlog.len());
+    assert!(!mod_modlog[0].mod_lock_post.automod);
+
+    // A revoked token can't be used anymore
+    let revoke_form = CommunityBotDelegationUpdateForm {
+      revoked: Some(true),
+      ..Default::default()
+    };
+    CommunityBotDelegation::update(pool, inserted_delegation.id, &revoke_form).await?;
+    let revoked = CommunityBotDelegation::read_from_token(pool, &inserted_delegation.token).await?;
+    assert!(revoked.is_none());
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_community_min_reputation() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let inserted_person = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "low_reputation"),
+    )
+    .await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    LocalUser::create(pool, &local_user_form, vec![]).await?;
+    let mut local_user_view = LocalUserView::read_person(pool, inserted_person.id).await?;
+
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "reputation_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+
+    // Without a minimum anyone can post
+    check_community_min_reputation(&local_user_view, &inserted_community, pool).await?;
+
+    let update_form = CommunityUpdateForm {
+      posting_min_reputation: Some(Some(5)),
+      ..Default::default()
+    };
+    let inserted_community = Community::update(pool, inserted_community.id, &update_form).await?;
+    assert_eq!(
+      Some(LemmyErrorType::ReputationTooLow),
+      check_community_min_reputation(&local_user_view, &inserted_community, pool)
+        .await
+        .err()
+        .map(|e| e.error_type)
+    );
+
+    local_user_view.counts.reputation = 5;
+    check_community_min_reputation(&local_user_view, &inserted_community, pool).await?;
+
+    // Moderators are exempt
+    local_user_view.counts.reputation = -10;
+    let moderator_form = CommunityModeratorForm {
+      community_id: inserted_community.id,
+      person_id: inserted_person.id,
+    };
+    CommunityModerator::join(pool, &moderator_form).await?;
+    check_community_min_reputation(&local_user_view, &inserted_community, pool).await?;
+
+    Instance::delete(pool, inserted_instance.id).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_community_permission() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let inserted_member = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "role_member"),
+    )
+    .await?;
+    let inserted_admin = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "role_admin"),
+    )
+    .await?;
+    let admin_form = LocalUserInsertForm::test_form_admin(inserted_admin.id);
+    LocalUser::create(pool, &admin_form, vec![]).await?;
+
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "role_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+    let view_votes = CommunityPermission::ViewVotes;
+
+    // Members can't view votes, until the role is allowed to
+    assert_eq!(
+      Some(LemmyErrorType::MissingCommunityPermission),
+      check_community_permission(&inserted_member, &inserted_community, view_votes, pool)
+        .await
+        .err()
+        .map(|e| e.error_type)
+    );
+    CommunityRole::set(
+      pool,
+      inserted_community.id,
+      inserted_member.id,
+      CommunityRole::TrustedContributor,
+    )
+    .await?;
+    let permission_form = CommunityRolePermissionForm {
+      community_id: inserted_community.id,
+      role: CommunityRole::TrustedContributor,
+      can_feature_posts: false,
+      can_remove_comments: false,
+      can_edit_sidebar: false,
+      updated: Utc::now(),
+      can_view_votes: true,
+    };
+    CommunityRolePermission::upsert(pool, &permission_form).await?;
+    check_community_permission(&inserted_member, &inserted_community, view_votes, pool).await?;
+
+    // Admins are allowed without any role, even when banned from the removed community
+    check_community_permission(&inserted_admin, &inserted_community, view_votes, pool).await?;
+    for person_id in [inserted_member.id, inserted_admin.id] {
+      let ban_form = CommunityPersonBanForm {
+        community_id: inserted_community.id,
+        person_id,
+        expires: None,
+        reason: None,
+        appeal: None,
+      };
+      CommunityPersonBan::ban(pool, &ban_form).await?;
+    }
+    let update_form = CommunityUpdateForm {
+      removed: Some(true),
+      ..Default::default()
+    };
+    let inserted_community = Community::update(pool, inserted_community.id, &update_form).await?;
+    check_community_permission(&inserted_admin, &inserted_community, view_votes, pool).await?;
+    assert!(
+      check_community_permission(&inserted_member, &inserted_community, view_votes, pool)
+        .await
+        .is_err()
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_anonymize_user_account() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+
+    // The placeholder account is created on the local site
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let site_form = SiteInsertForm::new("test site".to_string(), inserted_instance.id);
+    let site = Site::create(pool, &site_form).await?;
+    let local_site = LocalSite::create(pool, &LocalSiteInsertForm::new(site.id)).await?;
+    LocalSiteRateLimit::create(pool, &LocalSiteRateLimitInsertForm::new(local_site.id)).await?;
+
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "anonymize_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+
+    let mut contents = vec![];
+    for name in ["anonymize_alice", "anonymize_bob"] {
+      let person = Person::create(
+        pool,
+        &PersonInsertForm::test_form(inserted_instance.id, name),
+      )
+      .await?;
+      LocalUser::create(pool, &LocalUserInsertForm::test_form(person.id), vec![]).await?;
+      let post_form =
+        PostInsertForm::new(format!("Post by {name}"), person.id, inserted_community.id);
+      let post = Post::create(pool, &post_form).await?;
+      let comment_form = CommentInsertForm::new(person.id, post.id, format!("Comment by {name}"));
+      let comment = Comment::create(pool, &comment_form, None).await?;
+      contents.push((person, post, comment));
+    }
+
+    // The content stays, but is attributed to the placeholder account, which is reused for
+    // every anonymized account
+    let mut tombstone_ids = vec![];
+    for (person, post, comment) in contents {
+      anonymize_user_account(person.id, &context).await?;
+      let tombstone = Person::read_from_name(pool, Person::TOMBSTONE_NAME, true)
+        .await?
+        .ok_or(LemmyErrorType::NotFound)?;
+      assert!(tombstone.deleted);
+      assert_eq!(tombstone.id, Post::read(pool, post.id).await?.creator_id);
+      assert_eq!(
+        tombstone.id,
+        Comment::read(pool, comment.id).await?.creator_id
+      );
+      assert!(Person::read(pool, person.id).await?.deleted);
+      tombstone_ids.push(tombstone.id);
+    }
+    assert_eq!(tombstone_ids.first(), tombstone_ids.last());
+
+    Instance::delete(pool, inserted_instance.id).await?;
+    Ok(())
+  }
 }
@@ -0,0 +1,58 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  ban_appeal::{BanAppealResponse, CreateBanAppeal},
+  context::LemmyContext,
+  utils::local_site_to_slur_regex,
+};
+use lemmy_db_schema::{
+  source::{
+    ban_appeal::{BanAppeal, BanAppealInsertForm},
+    community::CommunityPersonBan,
+    local_site::LocalSite,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::{BanAppealView, LocalUserView};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{slurs::check_slurs, validation::is_valid_body_field},
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_ban_appeal(
+  data: Json<CreateBanAppeal>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BanAppealResponse>> {
+  let person_id = local_user_view.person.id;
+  let ban = CommunityPersonBan::read(&mut context.pool(), person_id, data.community_id)
+    .await?
+    .ok_or(LemmyErrorType::NotBannedFromCommunity)?;
+
+  // Only one appeal per ban, a later ban can be appealed again
+  if BanAppeal::exists_for_ban(
+    &mut context.pool(),
+    person_id,
+    data.community_id,
+    ban.published,
+  )
+  .await?
+  {
+    Err(LemmyErrorType::BanAppealAlreadyExists)?
+  }
+
+  let reason = data.reason.trim();
+  if reason.is_empty() {
+    Err(LemmyErrorType::InvalidBodyField)?
+  }
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_slurs(reason, &local_site_to_slur_regex(&local_site))?;
+  is_valid_body_field(reason, false)?;
+
+  let form = BanAppealInsertForm::new(person_id, data.community_id, reason.to_string());
+  let ban_appeal = BanAppeal::create(&mut context.pool(), &form).await?;
+
+  let ban_appeal_view = BanAppealView::read(&mut context.pool(), ban_appeal.id).await?;
+  Ok(Json(BanAppealResponse { ban_appeal_view }))
+}
@@ -0,0 +1,33 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  ban_appeal::{ListBanAppeals, ListBanAppealsResponse},
+  context::LemmyContext,
+  utils::check_community_mod_of_any_or_admin_action,
+};
+use lemmy_db_views::{ban_appeal_view::BanAppealQuery, structs::LocalUserView};
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_ban_appeals(
+  data: Query<ListBanAppeals>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListBanAppealsResponse>> {
+  check_community_mod_of_any_or_admin_action(&local_user_view, &mut context.pool()).await?;
+
+  let ban_appeals = BanAppealQuery {
+    status: data.status,
+    community_id: data.community_id,
+    page: data.page,
+    limit: data.limit,
+  }
+  .list(
+    &mut context.pool(),
+    local_user_view.person.id,
+    local_user_view.local_user.admin,
+  )
+  .await?;
+
+  Ok(Json(ListBanAppealsResponse { ban_appeals }))
+}
@@ -0,0 +1,131 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  ban_appeal::{BanAppealResponse, ResolveBanAppeal},
+  community::BanFromCommunity,
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::{
+  source::{
+    ban_appeal::{BanAppeal, BanAppealUpdateForm},
+    community::{Community, CommunityPersonBan, CommunityPersonBanForm},
+    moderator::{
+      ModBanFromCommunity,
+      ModBanFromCommunityForm,
+      ModResolveBanAppeal,
+      ModResolveBanAppealForm,
+    },
+    person::Person,
+  },
+  traits::{Bannable, Crud},
+  utils::{diesel_string_update, naive_now},
+  BanAppealStatus,
+};
+use lemmy_db_views::structs::{BanAppealView, LocalUserView};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_body_field,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn resolve_ban_appeal(
+  data: Json<ResolveBanAppeal>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<BanAppealResponse>> {
+  let appeal = BanAppeal::read(&mut context.pool(), data.id).await?;
+  let community = Community::read(&mut context.pool(), appeal.community_id).await?;
+  check_community_mod_action(
+    &local_user_view.person,
+    &community,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
+
+  if appeal.status != BanAppealStatus::Pending {
+    Err(LemmyErrorType::BanAppealAlreadyHandled)?
+  }
+
+  let mut form = BanAppealUpdateForm {
+    resolver_id: Some(Some(local_user_view.person.id)),
+    updated: Some(Some(naive_now())),
+    ..Default::default()
+  };
+
+  let deny_reason = if data.accept {
+    form.status = Some(BanAppealStatus::Accepted);
+    lift_ban(&local_user_view, &appeal, &context).await?;
+    None
+  } else {
+    if let Some(deny_reason) = &data.deny_reason {
+      is_valid_body_field(deny_reason, false)?;
+    }
+    form.status = Some(BanAppealStatus::Denied);
+    form.deny_reason = diesel_string_update(data.deny_reason.as_deref());
+    form.deny_reason.clone().flatten()
+  };
+  BanAppeal::update(&mut context.pool(), appeal.id, &form).await?;
+
+  let mod_form = ModResolveBanAppealForm {
+    mod_person_id: local_user_view.person.id,
+    other_person_id: appeal.creator_id,
+    community_id: appeal.community_id,
+    accepted: data.accept,
+    reason: deny_reason,
+  };
+  ModResolveBanAppeal::create(&mut context.pool(), &mod_form).await?;
+
+  let ban_appeal_view = BanAppealView::read(&mut context.pool(), appeal.id).await?;
+  Ok(Json(BanAppealResponse { ban_appeal_view }))
+}
+
+/// Unbans the appellant from the community, the same way as a manual unban by the moderator.
+async fn lift_ban(
+  local_user_view: &LocalUserView,
+  appeal: &BanAppeal,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let ban_form = CommunityPersonBanForm {
+    community_id: appeal.community_id,
+    person_id: appeal.creator_id,
+    expires: None,
+    reason: None,
+    appeal: None,
+  };
+  CommunityPersonBan::unban(&mut context.pool(), &ban_form).await?;
+
+  let reason = Some("Ban appeal accepted".to_string());
+  let form = ModBanFromCommunityForm {
+    mod_person_id: local_user_view.person.id,
+    other_person_id: appeal.creator_id,
+    community_id: appeal.community_id,
+    reason: reason.clone(),
+    banned: Some(false),
+    expires: None,
+  };
+  ModBanFromCommunity::create(&mut context.pool(), &form).await?;
+
+  let target = Person::read(&mut context.pool(), appeal.creator_id).await?;
+  ActivityChannel::submit_activity(
+    SendActivityData::BanFromCommunity {
+      moderator: local_user_view.person.clone(),
+      community_id: appeal.community_id,
+      target,
+      data: BanFromCommunity {
+        community_id: appeal.community_id,
+        person_id: appeal.creator_id,
+        ban: false,
+        reason,
+        remove_or_restore_data: None,
+        expires: None,
+        appeal: None,
+      },
+    },
+    context,
+  )?;
+
+  Ok(())
+}
@@ -3,9 +3,13 @@ use actix_web::web::Json;
 use lemmy_api_common::{
   build_response::{build_comment_response, send_local_notifs},
   comment::{CommentResponse, CreateComment},
+  content_filter::FilterRuleMatches,
   context::LemmyContext,
+  hooks::run_hooks,
   send_activity::{ActivityChannel, SendActivityData},
+  spam::SpamVerdict,
   utils::{
+    check_community_min_reputation,
     check_community_user_action,
     check_post_deleted_or_removed,
     get_url_blocklist,
@@ -28,6 +32,7 @@ use lemmy_db_schema::{
 use lemmy_db_views::structs::{LocalUserView, PostView};
 use lemmy_utils::{
   error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  settings::structs::HookPoint,
   utils::{mention::scrape_text_for_mentions, validation::is_valid_body_field},
   MAX_COMMENT_DEPTH_LIMIT,
 };
@@ -38,6 +43,8 @@ pub async fn create_comment(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<CommentResponse>> {
+  let data = run_hooks(HookPoint::PreCommentCreate, data.into_inner(), &context).await?;
+
   let local_site = LocalSite::read(&mut context.pool()).await?;
 
   let slur_regex = local_site_to_slur_regex(&local_site);
@@ -66,7 +73,12 @@ pub async fn create_comment(
     &mut context.pool(),
   )
   .await?;
+  check_community_min_reputation(&local_user_view, &post_view.community, &mut context.pool())
+    .await?;
   check_post_deleted_or_removed(&post)?;
+  if post.pending {
+    Err(LemmyErrorType::PostIsPending)?
+  }
 
   // Check if post is locked, no new comments
   let is_mod_or_admin = is_mod_or_admin(&mut context.pool(), &local_user_view.person, community_id)
@@ -100,8 +112,31 @@ pub async fn create_comment(
   )
   .await?;
 
+  let filter_matches = FilterRuleMatches::check(
+    &content,
+    community_id,
+    &local_user_view,
+    &mut context.pool(),
+  )
+  .await?;
+  let mut spam = SpamVerdict::for_comment(
+    &content,
+    post_id,
+    &local_user_view,
+    &local_site,
+    &mut context.pool(),
+  )
+  .await?;
+  // Flagged comments go through the same review as suspected spam, as well as those which would
+  // need approval
+  if filter_matches.flags() || filter_matches.requires_approval() {
+    spam = spam.hold();
+  }
+  let removed = spam.hides_content() || filter_matches.removes();
+
   let comment_form = CommentInsertForm {
     language_id: Some(language_id),
+    removed: removed.then_some(true),
     ..CommentInsertForm::new(local_user_view.person.id, data.post_id, content.clone())
   };
 
@@ -112,18 +147,34 @@ pub async fn create_comment(
     .with_lemmy_type(LemmyErrorType::CouldntCreateComment)?;
 
   let inserted_comment_id = inserted_comment.id;
+  spam
+    .record(
+      &local_user_view,
+      post_id,
+      Some(inserted_comment_id),
+      &mut context.pool(),
+    )
+    .await?;
+  filter_matches
+    .report_comment(&inserted_comment, spam.hides_content(), &mut context.pool())
+    .await;
 
-  // Scan the comment for user mentions, add those rows
-  let mentions = scrape_text_for_mentions(&content);
-  let recipient_ids = send_local_notifs(
-    mentions,
-    inserted_comment_id,
-    &local_user_view.person,
-    true,
-    &context,
-    Some(&local_user_view),
-  )
-  .await?;
+  // Scan the comment for user mentions, add those rows. Comments held back by the spam filter
+  // or removed by a filter rule don't notify anyone.
+  let recipient_ids = if removed {
+    vec![]
+  } else {
+    let mentions = scrape_text_for_mentions(&content);
+    send_local_notifs(
+      mentions,
+      inserted_comment_id,
+      &local_user_view.person,
+      true,
+      &context,
+      Some(&local_user_view),
+    )
+    .await?
+  };
 
   // You like your own comment by default
   let like_form = CommentLikeForm {
@@ -136,10 +187,12 @@ pub async fn create_comment(
     .await
     .with_lemmy_type(LemmyErrorType::CouldntLikeComment)?;
 
-  ActivityChannel::submit_activity(
-    SendActivityData::CreateComment(inserted_comment.clone()),
-    &context,
-  )?;
+  if !removed {
+    ActivityChannel::submit_activity(
+      SendActivityData::CreateComment(inserted_comment.clone()),
+      &context,
+    )?;
+  }
 
   // Update the read comments, so your own new comment doesn't appear as a +1 unread
   update_read_comments(
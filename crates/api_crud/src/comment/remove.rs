@@ -3,28 +3,36 @@ use actix_web::web::Json;
 use lemmy_api_common::{
   build_response::{build_comment_response, send_local_notifs},
   comment::{CommentResponse, RemoveComment},
+  community_bot::ModerationActor,
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_mod_action,
+  utils::{acting_mod_person_id, check_community_permission_or_bot, log_community_bot_action},
 };
 use lemmy_db_schema::{
   source::{
     comment::{Comment, CommentUpdateForm},
     comment_report::CommentReport,
+    community_role::CommunityPermission,
     local_user::LocalUser,
     moderator::{ModRemoveComment, ModRemoveCommentForm},
   },
   traits::{Crud, Reportable},
+  CommunityBotScope,
 };
-use lemmy_db_views::structs::{CommentView, LocalUserView};
+use lemmy_db_views::structs::CommentView;
+use lemmy_db_views_actor::structs::CommunityView;
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
 #[tracing::instrument(skip(context))]
 pub async fn remove_comment(
   data: Json<RemoveComment>,
   context: Data<LemmyContext>,
-  local_user_view: LocalUserView,
+  actor: ModerationActor,
 ) -> LemmyResult<Json<CommentResponse>> {
+  let ModerationActor {
+    local_user_view,
+    bot_delegation,
+  } = actor;
   let comment_id = data.comment_id;
   let orig_comment = CommentView::read(
     &mut context.pool(),
@@ -33,21 +41,34 @@ pub async fn remove_comment(
   )
   .await?;
 
-  check_community_mod_action(
+  let delegation = check_community_permission_or_bot(
     &local_user_view.person,
+    &bot_delegation,
     &orig_comment.community,
-    false,
+    CommunityPermission::RemoveComment,
+    CommunityBotScope::RemoveComment,
     &mut context.pool(),
   )
   .await?;
 
-  LocalUser::is_higher_mod_or_admin_check(
+  // Comments of moderators and admins can only be removed by someone ranking higher. A bot acts
+  // with the authority of the moderator who delegated to it.
+  if CommunityView::check_is_mod_or_admin(
     &mut context.pool(),
+    orig_comment.creator.id,
     orig_comment.community.id,
-    local_user_view.person.id,
-    vec![orig_comment.creator.id],
   )
-  .await?;
+  .await
+  .is_ok()
+  {
+    LocalUser::is_higher_mod_or_admin_check(
+      &mut context.pool(),
+      orig_comment.community.id,
+      acting_mod_person_id(&local_user_view.person, &delegation),
+      vec![orig_comment.creator.id],
+    )
+    .await?;
+  }
 
   // Don't allow removing or restoring comment which was deleted by user, as it would reveal
   // the comment text in mod log.
@@ -73,12 +94,23 @@ pub async fn remove_comment(
 
   // Mod tables
   let form = ModRemoveCommentForm {
-    mod_person_id: local_user_view.person.id,
+    mod_person_id: acting_mod_person_id(&local_user_view.person, &delegation),
     comment_id: data.comment_id,
     removed: Some(removed),
     reason: data.reason.clone(),
+    automod: delegation.is_some(),
   };
   ModRemoveComment::create(&mut context.pool(), &form).await?;
+  log_community_bot_action(
+    &delegation,
+    CommunityBotScope::RemoveComment,
+    Some(orig_comment.post.id),
+    Some(comment_id),
+    removed,
+    data.reason.clone(),
+    &mut context.pool(),
+  )
+  .await?;
 
   let recipient_ids = send_local_notifs(
     vec![],
@@ -97,6 +129,7 @@ pub async fn remove_comment(
       moderator: local_user_view.person.clone(),
       community: orig_comment.community,
       reason: data.reason.clone(),
+      automod: delegation.is_some(),
     },
     &context,
   )?;
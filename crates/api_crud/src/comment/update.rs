@@ -3,6 +3,7 @@ use actix_web::web::Json;
 use lemmy_api_common::{
   build_response::{build_comment_response, send_local_notifs},
   comment::{CommentResponse, EditComment},
+  content_filter::FilterRuleMatches,
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
   utils::{
@@ -69,6 +70,13 @@ pub async fn update_comment(
   if let Some(content) = &content {
     is_valid_body_field(content, false)?;
   }
+  let filter_matches = FilterRuleMatches::check(
+    content.as_deref().unwrap_or(&orig_comment.comment.content),
+    orig_comment.community.id,
+    &local_user_view,
+    &mut context.pool(),
+  )
+  .await?;
 
   let comment_id = data.comment_id;
   let form = CommentUpdateForm {
@@ -80,6 +88,9 @@ pub async fn update_comment(
   let updated_comment = Comment::update(&mut context.pool(), comment_id, &form)
     .await
     .with_lemmy_type(LemmyErrorType::CouldntUpdateComment)?;
+  filter_matches
+    .report_comment(&updated_comment, false, &mut context.pool())
+    .await;
 
   // Do the mentions / recipients
   let updated_comment_content = updated_comment.content.clone();
@@ -1,41 +1,29 @@
-use super::check_community_visibility_allowed;
-use activitypub_federation::{config::Data, http_signatures::generate_actor_keypair};
+use super::{check_community_visibility_allowed, insert_local_community};
+use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
   build_response::build_community_response,
   community::{CommunityResponse, CreateCommunity},
   context::LemmyContext,
   utils::{
-    generate_followers_url,
-    generate_inbox_url,
-    generate_local_apub_endpoint,
+    check_community_name_available,
     get_url_blocklist,
     is_admin,
     local_site_to_slur_regex,
     process_markdown_opt,
     proxy_image_link_api,
-    EndpointType,
   },
 };
 use lemmy_db_schema::{
   source::{
     actor_language::{CommunityLanguage, SiteLanguage},
-    community::{
-      Community,
-      CommunityFollower,
-      CommunityFollowerForm,
-      CommunityFollowerState,
-      CommunityInsertForm,
-      CommunityModerator,
-      CommunityModeratorForm,
-    },
+    community::CommunityInsertForm,
   },
-  traits::{ApubActor, Crud, Followable, Joinable},
   utils::diesel_url_create,
 };
 use lemmy_db_views::structs::{LocalUserView, SiteView};
 use lemmy_utils::{
-  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  error::{LemmyErrorType, LemmyResult},
   utils::{
     slurs::check_slurs,
     validation::{
@@ -70,6 +58,12 @@ pub async fn create_community(
     is_valid_body_field(sidebar, false)?;
   }
 
+  let welcome_message =
+    process_markdown_opt(&data.welcome_message, &slur_regex, &url_blocklist, &context).await?;
+  if let Some(welcome_message) = &welcome_message {
+    is_valid_body_field(welcome_message, false)?;
+  }
+
   let description = data.description.clone();
   if let Some(desc) = &description {
     site_or_community_description_length_check(desc)?;
@@ -89,21 +83,7 @@ pub async fn create_community(
   }
 
   check_community_visibility_allowed(data.visibility, &local_user_view)?;
-
-  // Double check for duplicate community actor_ids
-  let community_actor_id = generate_local_apub_endpoint(
-    EndpointType::Community,
-    &data.name,
-    &context.settings().get_protocol_and_hostname(),
-  )?;
-  let community_dupe =
-    Community::read_from_apub_id(&mut context.pool(), &community_actor_id).await?;
-  if community_dupe.is_some() {
-    Err(LemmyErrorType::CommunityAlreadyExists)?
-  }
-
-  // When you create a community, make sure the user becomes a moderator and a follower
-  let keypair = generate_actor_keypair()?;
+  check_community_name_available(&mut context.pool(), &data.name).await?;
 
   let community_form = CommunityInsertForm {
     sidebar,
@@ -111,45 +91,31 @@ pub async fn create_community(
     icon,
     banner,
     nsfw: data.nsfw,
-    actor_id: Some(community_actor_id.clone()),
-    private_key: Some(keypair.private_key),
-    followers_url: Some(generate_followers_url(&community_actor_id)?),
-    inbox_url: Some(generate_inbox_url()?),
     posting_restricted_to_mods: data.posting_restricted_to_mods,
+    comment_export_public: data.comment_export_public,
+    image_text_search: data.image_text_search,
+    soft_launched: Some(local_site.community_soft_launch_posts.is_some()),
     visibility: data.visibility,
+    posting_min_reputation: data.posting_min_reputation.filter(|r| *r >= 0),
+    welcome_message,
+    posts_require_approval: data.posts_require_approval,
+    warning_ban_strikes: data.warning_ban_strikes.filter(|s| *s > 0),
+    warning_ban_days: data.warning_ban_days.filter(|d| *d > 0),
+    new_account_min_age_days: data.new_account_min_age_days.filter(|d| *d >= 0),
+    new_account_min_comments: data.new_account_min_comments.filter(|c| *c >= 0),
+    new_account_require_verified_email: data.new_account_require_verified_email,
+    new_account_restrictions: data.new_account_restrictions.clone(),
+    // The keys are generated in insert_local_community()
     ..CommunityInsertForm::new(
       site_view.site.instance_id,
       data.name.clone(),
       data.title.clone(),
-      keypair.public_key,
+      String::new(),
     )
   };
 
-  let inserted_community = Community::create(&mut context.pool(), &community_form)
-    .await
-    .with_lemmy_type(LemmyErrorType::CommunityAlreadyExists)?;
-
-  // The community creator becomes a moderator
-  let community_moderator_form = CommunityModeratorForm {
-    community_id: inserted_community.id,
-    person_id: local_user_view.person.id,
-  };
-
-  CommunityModerator::join(&mut context.pool(), &community_moderator_form)
-    .await
-    .with_lemmy_type(LemmyErrorType::CommunityModeratorAlreadyExists)?;
-
-  // Follow your own community
-  let community_follower_form = CommunityFollowerForm {
-    community_id: inserted_community.id,
-    person_id: local_user_view.person.id,
-    state: Some(CommunityFollowerState::Accepted),
-    approver_id: None,
-  };
-
-  CommunityFollower::follow(&mut context.pool(), &community_follower_form)
-    .await
-    .with_lemmy_type(LemmyErrorType::CommunityFollowerAlreadyExists)?;
+  let inserted_community =
+    insert_local_community(community_form, local_user_view.person.id, &context).await?;
 
   // Update the discussion_languages if that's provided
   let community_id = inserted_community.id;
@@ -1,7 +1,30 @@
-use lemmy_api_common::utils::is_admin;
-use lemmy_db_schema::CommunityVisibility;
+use activitypub_federation::http_signatures::generate_actor_keypair;
+use lemmy_api_common::{
+  context::LemmyContext,
+  utils::{
+    generate_followers_url,
+    generate_inbox_url,
+    generate_local_apub_endpoint,
+    is_admin,
+    EndpointType,
+  },
+};
+use lemmy_db_schema::{
+  newtypes::PersonId,
+  source::community::{
+    Community,
+    CommunityFollower,
+    CommunityFollowerForm,
+    CommunityFollowerState,
+    CommunityInsertForm,
+    CommunityModerator,
+    CommunityModeratorForm,
+  },
+  traits::{ApubActor, Crud, Followable, Joinable},
+  CommunityVisibility,
+};
 use lemmy_db_views::structs::LocalUserView;
-use lemmy_utils::error::LemmyResult;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 
 pub mod create;
 pub mod delete;
@@ -20,3 +43,61 @@ fn check_community_visibility_allowed(
   }
   Ok(())
 }
+
+/// Generates the actor urls and keys for a new local community and inserts it. The creator
+/// becomes a moderator and a follower of the community.
+pub(crate) async fn insert_local_community(
+  community_form: CommunityInsertForm,
+  creator_id: PersonId,
+  context: &LemmyContext,
+) -> LemmyResult<Community> {
+  // Double check for duplicate community actor_ids
+  let community_actor_id = generate_local_apub_endpoint(
+    EndpointType::Community,
+    &community_form.name,
+    &context.settings().get_protocol_and_hostname(),
+  )?;
+  let community_dupe =
+    Community::read_from_apub_id(&mut context.pool(), &community_actor_id).await?;
+  if community_dupe.is_some() {
+    Err(LemmyErrorType::CommunityAlreadyExists)?
+  }
+
+  let keypair = generate_actor_keypair()?;
+  let community_form = CommunityInsertForm {
+    actor_id: Some(community_actor_id.clone()),
+    private_key: Some(keypair.private_key),
+    public_key: keypair.public_key,
+    followers_url: Some(generate_followers_url(&community_actor_id)?),
+    inbox_url: Some(generate_inbox_url()?),
+    ..community_form
+  };
+
+  let inserted_community = Community::create(&mut context.pool(), &community_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CommunityAlreadyExists)?;
+
+  // The community creator becomes a moderator
+  let community_moderator_form = CommunityModeratorForm {
+    community_id: inserted_community.id,
+    person_id: creator_id,
+  };
+
+  CommunityModerator::join(&mut context.pool(), &community_moderator_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CommunityModeratorAlreadyExists)?;
+
+  // Follow your own community
+  let community_follower_form = CommunityFollowerForm {
+    community_id: inserted_community.id,
+    person_id: creator_id,
+    state: Some(CommunityFollowerState::Accepted),
+    approver_id: None,
+  };
+
+  CommunityFollower::follow(&mut context.pool(), &community_follower_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CommunityFollowerAlreadyExists)?;
+
+  Ok(inserted_community)
+}
@@ -9,6 +9,7 @@ use lemmy_api_common::{
   send_activity::{ActivityChannel, SendActivityData},
   utils::{
     check_community_mod_action,
+    check_community_permission,
     get_url_blocklist,
     local_site_to_slur_regex,
     process_markdown_opt,
@@ -19,10 +20,11 @@ use lemmy_db_schema::{
   source::{
     actor_language::{CommunityLanguage, SiteLanguage},
     community::{Community, CommunityUpdateForm},
+    community_role::CommunityPermission,
     local_site::LocalSite,
   },
   traits::Crud,
-  utils::{diesel_string_update, diesel_url_update, naive_now},
+  utils::{diesel_opt_number_update, diesel_string_update, diesel_url_update, naive_now},
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::{
@@ -52,6 +54,15 @@ pub async fn update_community(
     is_valid_body_field(sidebar, false)?;
   }
 
+  let welcome_message = diesel_string_update(
+    process_markdown_opt(&data.welcome_message, &slur_regex, &url_blocklist, &context)
+      .await?
+      .as_deref(),
+  );
+  if let Some(Some(welcome_message)) = &welcome_message {
+    is_valid_body_field(welcome_message, false)?;
+  }
+
   check_community_visibility_allowed(data.visibility, &local_user_view)?;
   let description = diesel_string_update(data.description.as_deref());
 
@@ -65,14 +76,33 @@ pub async fn update_community(
   replace_image(&banner, &old_community.banner, &context).await?;
   let banner = proxy_image_link_opt_api(banner, &context).await?;
 
-  // Verify its a mod (only mods can edit it)
-  check_community_mod_action(
-    &local_user_view.person,
-    &old_community,
-    false,
-    &mut context.pool(),
-  )
-  .await?;
+  // Verify its a mod (only mods can edit it). Other roles may be allowed to edit the sidebar, but
+  // nothing else.
+  let sidebar_only = data.sidebar.is_some()
+    && *data
+      == EditCommunity {
+        community_id: data.community_id,
+        sidebar: data.sidebar.clone(),
+        ..Default::default()
+      };
+  if !sidebar_only {
+    check_community_mod_action(
+      &local_user_view.person,
+      &old_community,
+      false,
+      &mut context.pool(),
+    )
+    .await?;
+  }
+  if data.sidebar.is_some() {
+    check_community_permission(
+      &local_user_view.person,
+      &old_community,
+      CommunityPermission::EditSidebar,
+      &mut context.pool(),
+    )
+    .await?;
+  }
 
   let community_id = data.community_id;
   if let Some(languages) = data.discussion_languages.clone() {
@@ -94,7 +124,18 @@ pub async fn update_community(
     banner,
     nsfw: data.nsfw,
     posting_restricted_to_mods: data.posting_restricted_to_mods,
+    comment_export_public: data.comment_export_public,
+    image_text_search: data.image_text_search,
     visibility: data.visibility,
+    posting_min_reputation: diesel_opt_number_update(data.posting_min_reputation),
+    welcome_message,
+    posts_require_approval: data.posts_require_approval,
+    warning_ban_strikes: diesel_opt_number_update(data.warning_ban_strikes),
+    warning_ban_days: data.warning_ban_days.filter(|d| *d > 0),
+    new_account_min_age_days: diesel_opt_number_update(data.new_account_min_age_days),
+    new_account_min_comments: diesel_opt_number_update(data.new_account_min_comments),
+    new_account_require_verified_email: data.new_account_require_verified_email,
+    new_account_restrictions: data.new_account_restrictions.clone(),
     updated: Some(Some(naive_now())),
     ..Default::default()
   };
@@ -0,0 +1,79 @@
+use crate::community::insert_local_community;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community_request::{ApproveCommunityRequest, CommunityRequestResponse},
+  context::LemmyContext,
+  utils::{check_community_name_available, is_admin},
+};
+use lemmy_db_schema::{
+  source::{
+    community::CommunityInsertForm,
+    community_request::{CommunityRequest, CommunityRequestUpdateForm},
+    local_site::LocalSite,
+    site::Site,
+  },
+  traits::Crud,
+  utils::{diesel_string_update, naive_now},
+  CommunityRequestStatus,
+};
+use lemmy_db_views::structs::{CommunityRequestView, LocalUserView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn approve_community_request(
+  data: Json<ApproveCommunityRequest>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityRequestResponse>> {
+  // Only let admins do this
+  is_admin(&local_user_view)?;
+
+  let request = CommunityRequest::read(&mut context.pool(), data.id).await?;
+  if request.status != CommunityRequestStatus::Pending {
+    Err(LemmyErrorType::CommunityRequestAlreadyHandled)?
+  }
+
+  let mut form = CommunityRequestUpdateForm {
+    admin_id: Some(Some(local_user_view.person.id)),
+    updated: Some(Some(naive_now())),
+    ..Default::default()
+  };
+
+  if data.approve {
+    // The name may have been taken since the request was made
+    check_community_name_available(&mut context.pool(), &request.name).await?;
+
+    let local_site = LocalSite::read(&mut context.pool()).await?;
+    let site = Site::read_local(&mut context.pool()).await?;
+    let community_form = CommunityInsertForm {
+      description: request.description.clone(),
+      soft_launched: Some(local_site.community_soft_launch_posts.is_some()),
+      // The keys are generated in insert_local_community()
+      ..CommunityInsertForm::new(
+        site.instance_id,
+        request.name.clone(),
+        request.title.clone(),
+        String::new(),
+      )
+    };
+    let community = insert_local_community(community_form, request.creator_id, &context).await?;
+
+    form.status = Some(CommunityRequestStatus::Approved);
+    form.community_id = Some(Some(community.id));
+  } else {
+    form.status = Some(CommunityRequestStatus::Denied);
+    form.deny_reason = diesel_string_update(data.deny_reason.as_deref());
+  }
+  CommunityRequest::update(&mut context.pool(), request.id, &form).await?;
+
+  let community_request_view = CommunityRequestView::read(
+    &mut context.pool(),
+    request.id,
+    Some(local_user_view.person.id),
+  )
+  .await?;
+  Ok(Json(CommunityRequestResponse {
+    community_request_view,
+  }))
+}
@@ -0,0 +1,43 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community_request::{CreateCommunityRequestComment, GetCommunityRequestResponse},
+  context::LemmyContext,
+  utils::local_site_to_slur_regex,
+};
+use lemmy_db_schema::source::{
+  community_request::{CommunityRequestComment, CommunityRequestCommentForm},
+  local_site::LocalSite,
+};
+use lemmy_db_views::structs::{CommunityRequestCommentView, CommunityRequestView, LocalUserView};
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::{slurs::check_slurs, validation::is_valid_body_field},
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_community_request_comment(
+  data: Json<CreateCommunityRequestComment>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<GetCommunityRequestResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  is_valid_body_field(&data.content, false)?;
+  check_slurs(&data.content, &local_site_to_slur_regex(&local_site))?;
+
+  let person_id = local_user_view.person.id;
+  let community_request_id = data.community_request_id;
+  let form =
+    CommunityRequestCommentForm::new(community_request_id, person_id, data.content.clone());
+  CommunityRequestComment::create(&mut context.pool(), &form).await?;
+
+  let community_request_view =
+    CommunityRequestView::read(&mut context.pool(), community_request_id, Some(person_id)).await?;
+  let comments =
+    CommunityRequestCommentView::list_for_request(&mut context.pool(), community_request_id)
+      .await?;
+  Ok(Json(GetCommunityRequestResponse {
+    community_request_view,
+    comments,
+  }))
+}
@@ -0,0 +1,70 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community_request::{CommunityRequestResponse, CreateCommunityRequest},
+  context::LemmyContext,
+  utils::{check_community_name_available, local_site_to_slur_regex},
+};
+use lemmy_db_schema::{
+  source::{
+    community_request::{
+      CommunityRequest,
+      CommunityRequestInsertForm,
+      CommunityRequestVote,
+      CommunityRequestVoteForm,
+    },
+    local_site::LocalSite,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::{CommunityRequestView, LocalUserView};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{
+    slurs::check_slurs,
+    validation::{is_valid_actor_name, site_or_community_description_length_check},
+  },
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_community_request(
+  data: Json<CreateCommunityRequest>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityRequestResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let slur_regex = local_site_to_slur_regex(&local_site);
+  check_slurs(&data.name, &slur_regex)?;
+  check_slurs(&data.title, &slur_regex)?;
+  if let Some(desc) = &data.description {
+    site_or_community_description_length_check(desc)?;
+    check_slurs(desc, &slur_regex)?;
+  }
+  is_valid_actor_name(&data.name, local_site.actor_name_max_length as usize)?;
+  check_community_name_available(&mut context.pool(), &data.name).await?;
+
+  if CommunityRequest::pending_name_exists(&mut context.pool(), &data.name).await? {
+    Err(LemmyErrorType::CommunityRequestAlreadyExists)?
+  }
+
+  let person_id = local_user_view.person.id;
+  let form = CommunityRequestInsertForm {
+    description: data.description.clone(),
+    ..CommunityRequestInsertForm::new(person_id, data.name.clone(), data.title.clone())
+  };
+  let community_request = CommunityRequest::create(&mut context.pool(), &form).await?;
+
+  // The requester automatically votes for their own request
+  let vote_form = CommunityRequestVoteForm {
+    person_id,
+    community_request_id: community_request.id,
+    score: 1,
+  };
+  CommunityRequestVote::vote(&mut context.pool(), &vote_form).await?;
+
+  let community_request_view =
+    CommunityRequestView::read(&mut context.pool(), community_request.id, Some(person_id)).await?;
+  Ok(Json(CommunityRequestResponse {
+    community_request_view,
+  }))
+}
@@ -0,0 +1,32 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  community_request::{ListCommunityRequests, ListCommunityRequestsResponse},
+  context::LemmyContext,
+  utils::check_private_instance,
+};
+use lemmy_db_schema::source::local_site::LocalSite;
+use lemmy_db_views::{community_request_view::CommunityRequestQuery, structs::LocalUserView};
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_community_requests(
+  data: Query<ListCommunityRequests>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<ListCommunityRequestsResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let community_requests = CommunityRequestQuery {
+    status: data.status,
+    sort_by_score: data.sort_by_score.unwrap_or_default(),
+    my_person_id: local_user_view.map(|l| l.person.id),
+    page: data.page,
+    limit: data.limit,
+  }
+  .list(&mut context.pool())
+  .await?;
+
+  Ok(Json(ListCommunityRequestsResponse { community_requests }))
+}
@@ -0,0 +1,6 @@
+pub mod approve;
+pub mod comment;
+pub mod create;
+pub mod list;
+pub mod read;
+pub mod vote;
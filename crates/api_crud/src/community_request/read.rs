@@ -0,0 +1,31 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  community_request::{GetCommunityRequest, GetCommunityRequestResponse},
+  context::LemmyContext,
+  utils::check_private_instance,
+};
+use lemmy_db_schema::source::local_site::LocalSite;
+use lemmy_db_views::structs::{CommunityRequestCommentView, CommunityRequestView, LocalUserView};
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn get_community_request(
+  data: Query<GetCommunityRequest>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<GetCommunityRequestResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let my_person_id = local_user_view.map(|l| l.person.id);
+  let community_request_view =
+    CommunityRequestView::read(&mut context.pool(), data.id, my_person_id).await?;
+  let comments =
+    CommunityRequestCommentView::list_for_request(&mut context.pool(), data.id).await?;
+
+  Ok(Json(GetCommunityRequestResponse {
+    community_request_view,
+    comments,
+  }))
+}
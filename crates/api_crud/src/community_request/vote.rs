@@ -0,0 +1,37 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community_request::{CommunityRequestResponse, VoteCommunityRequest},
+  context::LemmyContext,
+};
+use lemmy_db_schema::source::community_request::{CommunityRequestVote, CommunityRequestVoteForm};
+use lemmy_db_views::structs::{CommunityRequestView, LocalUserView};
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn vote_community_request(
+  data: Json<VoteCommunityRequest>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CommunityRequestResponse>> {
+  let person_id = local_user_view.person.id;
+  let community_request_id = data.community_request_id;
+
+  // Only add the vote if the score is 1 or -1, otherwise remove it
+  if data.score == 1 || data.score == -1 {
+    let form = CommunityRequestVoteForm {
+      person_id,
+      community_request_id,
+      score: data.score,
+    };
+    CommunityRequestVote::vote(&mut context.pool(), &form).await?;
+  } else {
+    CommunityRequestVote::remove(&mut context.pool(), person_id, community_request_id).await?;
+  }
+
+  let community_request_view =
+    CommunityRequestView::read(&mut context.pool(), community_request_id, Some(person_id)).await?;
+  Ok(Json(CommunityRequestResponse {
+    community_request_view,
+  }))
+}
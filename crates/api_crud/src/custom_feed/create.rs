@@ -0,0 +1,51 @@
+use super::validate_custom_feed;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  custom_feed::{CreateCustomFeed, CustomFeedResponse},
+};
+use lemmy_db_schema::{
+  source::{
+    custom_feed::{CustomFeed, CustomFeedInsertForm},
+    local_site::LocalSite,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_custom_feed(
+  data: Json<CreateCustomFeed>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CustomFeedResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let name = data.name.trim();
+  let description = data.description.as_deref().filter(|d| !d.is_empty());
+  validate_custom_feed(
+    Some(name),
+    description,
+    data.slug.as_deref(),
+    Some(&data.community_ids),
+    &local_site,
+  )?;
+
+  let form = CustomFeedInsertForm {
+    description: description.map(ToString::to_string),
+    public: data.public,
+    slug: data.slug.clone(),
+    ..CustomFeedInsertForm::new(local_user_view.person.id, name.to_string())
+  };
+  let custom_feed = CustomFeed::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CustomFeedAlreadyExists)?;
+  CustomFeed::set_communities(&mut context.pool(), custom_feed.id, &data.community_ids).await?;
+
+  let communities = CustomFeed::communities(&mut context.pool(), custom_feed.id).await?;
+  Ok(Json(CustomFeedResponse {
+    custom_feed,
+    communities,
+  }))
+}
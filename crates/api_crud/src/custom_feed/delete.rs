@@ -0,0 +1,21 @@
+use super::check_custom_feed_creator;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, custom_feed::DeleteCustomFeed, SuccessResponse};
+use lemmy_db_schema::{source::custom_feed::CustomFeed, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_custom_feed(
+  data: Json<DeleteCustomFeed>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let orig = CustomFeed::read(&mut context.pool(), data.id).await?;
+  check_custom_feed_creator(&orig, &local_user_view)?;
+
+  CustomFeed::delete(&mut context.pool(), data.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
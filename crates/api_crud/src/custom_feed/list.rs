@@ -0,0 +1,17 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, custom_feed::ListCustomFeedsResponse};
+use lemmy_db_schema::source::custom_feed::CustomFeed;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_custom_feeds(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListCustomFeedsResponse>> {
+  let custom_feeds =
+    CustomFeed::list_for_person(&mut context.pool(), local_user_view.person.id).await?;
+
+  Ok(Json(ListCustomFeedsResponse { custom_feeds }))
+}
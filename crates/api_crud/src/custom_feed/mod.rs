@@ -0,0 +1,52 @@
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{custom_feed::CustomFeed, local_site::LocalSite},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::validation::{is_valid_actor_name, is_valid_body_field, is_valid_display_name},
+};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod read;
+pub mod update;
+
+const MAX_CUSTOM_FEED_COMMUNITIES: usize = 100;
+
+/// Checks the fields of a new or edited feed. Slugs follow the rules of community names, so
+/// that they can be used in urls.
+fn validate_custom_feed(
+  name: Option<&str>,
+  description: Option<&str>,
+  slug: Option<&str>,
+  community_ids: Option<&[CommunityId]>,
+  local_site: &LocalSite,
+) -> LemmyResult<()> {
+  let max_length = local_site.actor_name_max_length as usize;
+  if let Some(name) = name {
+    is_valid_display_name(name, max_length)?;
+  }
+  if let Some(description) = description {
+    is_valid_body_field(description, false)?;
+  }
+  if let Some(slug) = slug {
+    is_valid_actor_name(slug, max_length)?;
+  }
+  if community_ids.is_some_and(|ids| ids.len() > MAX_CUSTOM_FEED_COMMUNITIES) {
+    Err(LemmyErrorType::TooManyCustomFeedCommunities)?
+  }
+  Ok(())
+}
+
+fn check_custom_feed_creator(
+  custom_feed: &CustomFeed,
+  local_user_view: &LocalUserView,
+) -> LemmyResult<()> {
+  if custom_feed.creator_id != local_user_view.person.id {
+    Err(LemmyErrorType::NotCustomFeedCreator)?
+  }
+  Ok(())
+}
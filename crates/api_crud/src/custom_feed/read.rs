@@ -0,0 +1,35 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  custom_feed::{CustomFeedResponse, GetCustomFeed},
+  utils::{check_custom_feed_visible, check_private_instance},
+};
+use lemmy_db_schema::{source::custom_feed::CustomFeed, traits::Crud};
+use lemmy_db_views::structs::{LocalUserView, SiteView};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn get_custom_feed(
+  data: Query<GetCustomFeed>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<CustomFeedResponse>> {
+  let local_site = SiteView::read_local(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site.local_site)?;
+
+  let custom_feed = match (data.id, &data.slug) {
+    (Some(id), _) => CustomFeed::read(&mut context.pool(), id).await?,
+    (None, Some(slug)) => CustomFeed::read_from_slug(&mut context.pool(), slug)
+      .await?
+      .ok_or(LemmyErrorType::NotFound)?,
+    (None, None) => Err(LemmyErrorType::NoIdGiven)?,
+  };
+  check_custom_feed_visible(&custom_feed, &local_user_view)?;
+
+  let communities = CustomFeed::communities(&mut context.pool(), custom_feed.id).await?;
+  Ok(Json(CustomFeedResponse {
+    custom_feed,
+    communities,
+  }))
+}
@@ -0,0 +1,59 @@
+use super::{check_custom_feed_creator, validate_custom_feed};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  custom_feed::{CustomFeedResponse, EditCustomFeed},
+};
+use lemmy_db_schema::{
+  source::{
+    custom_feed::{CustomFeed, CustomFeedUpdateForm},
+    local_site::LocalSite,
+  },
+  traits::Crud,
+  utils::{diesel_string_update, naive_now},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_custom_feed(
+  data: Json<EditCustomFeed>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<CustomFeedResponse>> {
+  let orig = CustomFeed::read(&mut context.pool(), data.id).await?;
+  check_custom_feed_creator(&orig, &local_user_view)?;
+
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let name = data.name.as_deref().map(str::trim);
+  let description = diesel_string_update(data.description.as_deref());
+  let slug = diesel_string_update(data.slug.as_deref());
+  validate_custom_feed(
+    name,
+    description.clone().flatten().as_deref(),
+    slug.clone().flatten().as_deref(),
+    data.community_ids.as_deref(),
+    &local_site,
+  )?;
+
+  let form = CustomFeedUpdateForm {
+    name: name.map(ToString::to_string),
+    description,
+    public: data.public,
+    slug,
+    updated: Some(Some(naive_now())),
+  };
+  let custom_feed = CustomFeed::update(&mut context.pool(), data.id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CustomFeedAlreadyExists)?;
+  if let Some(community_ids) = &data.community_ids {
+    CustomFeed::set_communities(&mut context.pool(), custom_feed.id, community_ids).await?;
+  }
+
+  let communities = CustomFeed::communities(&mut context.pool(), custom_feed.id).await?;
+  Ok(Json(CustomFeedResponse {
+    custom_feed,
+    communities,
+  }))
+}
@@ -0,0 +1,59 @@
+use super::{check_filter_rule_permission, validate_condition, validate_pattern};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  filter_rule::{CreateFilterRule, FilterRuleResponse},
+};
+use lemmy_db_schema::{
+  source::filter_rule::{FilterRule, FilterRuleInsertForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::clean_domain,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_filter_rule(
+  data: Json<CreateFilterRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<FilterRuleResponse>> {
+  check_filter_rule_permission(data.community_id, &local_user_view, &context).await?;
+
+  let link_domain = data.link_domain.as_deref().map(clean_domain).transpose()?;
+  let max_account_age_days = data.max_account_age_days.filter(|d| *d > 0);
+  let condition = data
+    .condition
+    .as_deref()
+    .map(validate_condition)
+    .transpose()?
+    .flatten();
+  let has_conditions = link_domain.is_some()
+    || max_account_age_days.is_some()
+    || data.max_reputation.is_some()
+    || condition.is_some();
+  let is_regex = data.is_regex.unwrap_or_default();
+  let pattern = validate_pattern(&data.pattern, is_regex, has_conditions)?;
+
+  let form = FilterRuleInsertForm {
+    link_domain,
+    max_account_age_days,
+    max_reputation: data.max_reputation,
+    condition,
+    ..FilterRuleInsertForm::new(
+      data.community_id,
+      pattern,
+      is_regex,
+      data.action,
+      local_user_view.person.id,
+    )
+  };
+  let filter_rule = FilterRule::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::FilterRuleAlreadyExists)?;
+
+  Ok(Json(FilterRuleResponse { filter_rule }))
+}
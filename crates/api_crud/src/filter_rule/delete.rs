@@ -0,0 +1,21 @@
+use super::check_filter_rule_permission;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, filter_rule::DeleteFilterRule, SuccessResponse};
+use lemmy_db_schema::{source::filter_rule::FilterRule, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_filter_rule(
+  data: Json<DeleteFilterRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let orig = FilterRule::read(&mut context.pool(), data.id).await?;
+  check_filter_rule_permission(orig.community_id, &local_user_view, &context).await?;
+
+  FilterRule::delete(&mut context.pool(), data.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
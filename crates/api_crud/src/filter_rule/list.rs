@@ -0,0 +1,23 @@
+use super::check_filter_rule_permission;
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  filter_rule::{ListFilterRules, ListFilterRulesResponse},
+};
+use lemmy_db_schema::source::filter_rule::FilterRule;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_filter_rules(
+  data: Query<ListFilterRules>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListFilterRulesResponse>> {
+  check_filter_rule_permission(data.community_id, &local_user_view, &context).await?;
+
+  let filter_rules = FilterRule::list(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListFilterRulesResponse { filter_rules }))
+}
@@ -0,0 +1,56 @@
+use lemmy_api_common::{
+  context::LemmyContext,
+  utils::{is_admin, is_mod_or_admin},
+};
+use lemmy_db_schema::newtypes::CommunityId;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::{expression::Expression, validation::build_and_check_regex},
+};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+const MAX_PATTERN_LENGTH: usize = 200;
+
+/// Site wide rules are managed by admins, community rules also by the moderators.
+async fn check_filter_rule_permission(
+  community_id: Option<CommunityId>,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  if let Some(community_id) = community_id {
+    is_mod_or_admin(&mut context.pool(), &local_user_view.person, community_id).await
+  } else {
+    is_admin(local_user_view)
+  }
+}
+
+/// Trims the pattern, and checks that it isn't too long. It can only be empty if the rule has
+/// other conditions. Regexes which don't compile or match almost anything are refused.
+fn validate_pattern(pattern: &str, is_regex: bool, has_conditions: bool) -> LemmyResult<String> {
+  let pattern = pattern.trim();
+  if (pattern.is_empty() && (is_regex || !has_conditions))
+    || pattern.chars().count() > MAX_PATTERN_LENGTH
+  {
+    Err(LemmyErrorType::InvalidFilterRule)?
+  }
+  if is_regex {
+    build_and_check_regex(&Some(pattern))?;
+  }
+  Ok(pattern.to_string())
+}
+
+/// Trims the condition, and checks that it can be parsed. An empty condition is removed.
+fn validate_condition(condition: &str) -> LemmyResult<Option<String>> {
+  let condition = condition.trim();
+  if condition.is_empty() {
+    return Ok(None);
+  }
+  Expression::parse(condition)
+    .map_err(|e| LemmyErrorType::InvalidFilterRuleCondition(e.to_string()))?;
+  Ok(Some(condition.to_string()))
+}
@@ -0,0 +1,71 @@
+use super::{check_filter_rule_permission, validate_condition, validate_pattern};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  filter_rule::{EditFilterRule, FilterRuleResponse},
+};
+use lemmy_db_schema::{
+  source::filter_rule::{FilterRule, FilterRuleUpdateForm},
+  traits::Crud,
+  utils::{diesel_opt_number_update, naive_now},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::validation::clean_domain,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_filter_rule(
+  data: Json<EditFilterRule>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<FilterRuleResponse>> {
+  let orig = FilterRule::read(&mut context.pool(), data.id).await?;
+  check_filter_rule_permission(orig.community_id, &local_user_view, &context).await?;
+
+  let link_domain = match data.link_domain.as_deref() {
+    Some("") => Some(None),
+    Some(domain) => Some(Some(clean_domain(domain)?)),
+    None => None,
+  };
+  let max_account_age_days =
+    diesel_opt_number_update(data.max_account_age_days.filter(|d| *d != 0));
+  let max_reputation = data.max_reputation.map(Some);
+  let condition = data
+    .condition
+    .as_deref()
+    .map(validate_condition)
+    .transpose()?;
+  let has_conditions = link_domain.clone().unwrap_or(orig.link_domain).is_some()
+    || max_account_age_days
+      .unwrap_or(orig.max_account_age_days)
+      .is_some()
+    || max_reputation.unwrap_or(orig.max_reputation).is_some()
+    || condition.clone().unwrap_or(orig.condition).is_some();
+
+  // The pattern has to be checked again if only the regex flag or the conditions change
+  let is_regex = data.is_regex.unwrap_or(orig.is_regex);
+  let pattern = validate_pattern(
+    data.pattern.as_ref().unwrap_or(&orig.pattern),
+    is_regex,
+    has_conditions,
+  )?;
+
+  let form = FilterRuleUpdateForm {
+    pattern: Some(pattern),
+    is_regex: Some(is_regex),
+    action: data.action,
+    updated: Some(Some(naive_now())),
+    link_domain,
+    max_account_age_days,
+    max_reputation,
+    condition,
+  };
+  let filter_rule = FilterRule::update(&mut context.pool(), data.id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::FilterRuleAlreadyExists)?;
+
+  Ok(Json(FilterRuleResponse { filter_rule }))
+}
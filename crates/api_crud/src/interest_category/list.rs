@@ -0,0 +1,22 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  interest_category::ListInterestCategoriesResponse,
+  utils::check_private_instance,
+};
+use lemmy_db_schema::source::{interest_category::InterestCategory, local_site::LocalSite};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_interest_categories(
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<ListInterestCategoriesResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let categories = InterestCategory::list(&mut context.pool()).await?;
+
+  Ok(Json(ListInterestCategoriesResponse { categories }))
+}
@@ -0,0 +1,41 @@
+use super::validate_category_name;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  interest_category::{EditInterestCategory, InterestCategoryResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  source::interest_category::{InterestCategory, InterestCategoryUpdateForm},
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn update_interest_category(
+  data: Json<EditInterestCategory>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<InterestCategoryResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let form = InterestCategoryUpdateForm {
+    name: validate_category_name(&data.name, &context).await?,
+    updated: naive_now(),
+  };
+  let category = InterestCategory::update(&mut context.pool(), data.id, &form).await?;
+
+  if let Some(community_ids) = data.community_ids.clone() {
+    InterestCategory::set_communities(&mut context.pool(), category.id, community_ids).await?;
+  }
+  let community_ids = InterestCategory::communities(&mut context.pool(), category.id).await?;
+
+  Ok(Json(InterestCategoryResponse {
+    category,
+    community_ids,
+  }))
+}
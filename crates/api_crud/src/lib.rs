@@ -1,9 +1,16 @@
+pub mod ban_appeal;
 pub mod comment;
 pub mod community;
+pub mod community_request;
 pub mod custom_emoji;
+pub mod custom_feed;
+pub mod filter_rule;
+pub mod interest_category;
 pub mod oauth_provider;
 pub mod post;
 pub mod private_message;
+pub mod registration_question;
+pub mod report_reason_category;
 pub mod site;
 pub mod tagline;
 pub mod user;
@@ -1,35 +1,54 @@
-use super::convert_published_time;
+use super::{
+  convert_poll_end_time,
+  convert_published_time,
+  validate_poll_options,
+  validate_post_tags,
+};
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
   build_response::build_post_response,
+  content_filter::FilterRuleMatches,
   context::LemmyContext,
+  hooks::run_hooks,
   post::{CreatePost, PostResponse},
   request::generate_post_link_metadata,
   send_activity::SendActivityData,
+  spam::SpamVerdict,
   utils::{
+    check_community_domain_blocked,
+    check_community_min_reputation,
     check_community_user_action,
+    check_new_account_restrictions,
     get_url_blocklist,
     honeypot_check,
     local_site_to_slur_regex,
     process_markdown_opt,
+    process_post_gallery,
   },
 };
 use lemmy_db_schema::{
   impls::actor_language::validate_post_language,
+  newtypes::DbUrl,
   source::{
     community::Community,
+    community_post_tag::PostTag,
     local_site::LocalSite,
+    poll::{Poll, PollInsertForm},
     post::{Post, PostInsertForm, PostLike, PostLikeForm, PostRead},
+    post_media::PostMedia,
   },
   traits::{Crud, Likeable},
   utils::diesel_url_create,
+  CommunityRole,
   CommunityVisibility,
+  NewAccountRestriction,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_db_views_actor::structs::CommunityModeratorView;
 use lemmy_utils::{
   error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  settings::structs::HookPoint,
   spawn_try_task,
   utils::{
     slurs::check_slurs,
@@ -52,6 +71,8 @@ pub async fn create_post(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> LemmyResult<Json<PostResponse>> {
+  let data = run_hooks(HookPoint::PrePostCreate, data.into_inner(), &context).await?;
+
   let local_site = LocalSite::read(&mut context.pool()).await?;
 
   honeypot_check(&data.honeypot)?;
@@ -83,8 +104,41 @@ pub async fn create_post(
     is_valid_body_field(body, true)?;
   }
 
+  let poll_options = data
+    .poll_options
+    .as_deref()
+    .map(|options| validate_poll_options(options, &slur_regex))
+    .transpose()?;
+  let poll_end_time = convert_poll_end_time(data.poll_end_time)?;
+  let mut gallery = data
+    .gallery
+    .as_deref()
+    .map(|gallery| process_post_gallery(gallery, &url_blocklist))
+    .transpose()?
+    .unwrap_or_default();
+
   let community = Community::read(&mut context.pool(), data.community_id).await?;
   check_community_user_action(&local_user_view.person, &community, &mut context.pool()).await?;
+  check_community_min_reputation(&local_user_view, &community, &mut context.pool()).await?;
+  check_community_domain_blocked(community.id, url.as_ref(), &mut context.pool()).await?;
+  check_new_account_restrictions(
+    &local_user_view,
+    &local_site,
+    Some(&community),
+    NewAccountRestriction::Posts,
+    &mut context.pool(),
+  )
+  .await?;
+  if url.is_some() {
+    check_new_account_restrictions(
+      &local_user_view,
+      &local_site,
+      Some(&community),
+      NewAccountRestriction::Links,
+      &mut context.pool(),
+    )
+    .await?;
+  }
 
   if community.posting_restricted_to_mods {
     let community_id = data.community_id;
@@ -96,6 +150,12 @@ pub async fn create_post(
     .await?;
   }
 
+  let tag_ids = if let Some(tag_ids) = &data.tag_ids {
+    validate_post_tags(tag_ids, community.id, &context).await?
+  } else {
+    vec![]
+  };
+
   let language_id = validate_post_language(
     &mut context.pool(),
     data.language_id,
@@ -106,13 +166,54 @@ pub async fn create_post(
 
   let scheduled_publish_time =
     convert_published_time(data.scheduled_publish_time, &local_user_view, &context).await?;
+  let url: Option<DbUrl> = url.map(Into::into);
+  // Without a url, the first gallery image takes its place
+  let (url, alt_text) = if url.is_none() && !gallery.is_empty() {
+    let first = gallery.remove(0);
+    (Some(first.url), data.alt_text.clone().or(first.alt_text))
+  } else {
+    (url, data.alt_text.clone())
+  };
+  let filter_matches = FilterRuleMatches::check_post(
+    &data.name,
+    body.as_deref(),
+    url.as_ref(),
+    community.id,
+    &local_user_view,
+    &mut context.pool(),
+  )
+  .await?;
+  let mut spam = SpamVerdict::for_post(
+    &data.name,
+    body.as_deref(),
+    url.as_ref(),
+    community.id,
+    &local_user_view,
+    &local_site,
+    &mut context.pool(),
+  )
+  .await?;
+  // Flagged posts go through the same review as suspected spam
+  if filter_matches.flags() {
+    spam = spam.hold();
+  }
+  let removed = spam.hides_content() || filter_matches.removes();
+  // Posts of members wait for approval if the community or a filter rule requires it
+  let pending = filter_matches.requires_approval()
+    || (community.posts_require_approval
+      && !local_user_view.local_user.admin
+      && CommunityRole::read(&mut context.pool(), community.id, local_user_view.person.id).await?
+        == CommunityRole::Member);
   let post_form = PostInsertForm {
-    url: url.map(Into::into),
+    url,
     body,
-    alt_text: data.alt_text.clone(),
+    removed: removed.then_some(true),
+    locked: filter_matches.locks().then_some(true),
+    alt_text,
     nsfw: data.nsfw,
     language_id: Some(language_id),
     scheduled_publish_time,
+    pending: Some(pending),
     ..PostInsertForm::new(
       data.name.trim().to_string(),
       local_user_view.person.id,
@@ -124,8 +225,47 @@ pub async fn create_post(
     .await
     .with_lemmy_type(LemmyErrorType::CouldntCreatePost)?;
 
+  if let Some(poll_options) = poll_options {
+    let poll_form = PollInsertForm {
+      end_time: poll_end_time,
+      ..PollInsertForm::new(
+        inserted_post.id,
+        data.poll_multiple_choice.unwrap_or_default(),
+      )
+    };
+    Poll::create_with_options(&mut context.pool(), &poll_form, poll_options).await?;
+  }
+
+  if !gallery.is_empty() {
+    PostMedia::replace_for_post(&mut context.pool(), inserted_post.id, gallery).await?;
+  }
+
+  if !tag_ids.is_empty() {
+    PostTag::set(&mut context.pool(), inserted_post.id, tag_ids).await?;
+  }
+
+  spam
+    .record(
+      &local_user_view,
+      inserted_post.id,
+      None,
+      &mut context.pool(),
+    )
+    .await?;
+  filter_matches
+    .report_post(&inserted_post, spam.hides_content(), &mut context.pool())
+    .await;
+
+  if community.soft_launched {
+    let min_posts = local_site.community_soft_launch_posts.unwrap_or_default();
+    Community::end_soft_launch_if_enough_posts(&mut context.pool(), community.id, min_posts.into())
+      .await?;
+  }
+
   let community_id = community.id;
-  let federate_post = if scheduled_publish_time.is_none() {
+  // Posts held back by the spam filter or waiting for approval are only federated once they are
+  // approved, posts removed by a filter rule never
+  let federate_post = if scheduled_publish_time.is_none() && !removed && !pending {
     send_webmention(inserted_post.clone(), community);
     |post| Some(SendActivityData::CreatePost(post))
   } else {
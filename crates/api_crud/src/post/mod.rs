@@ -1,8 +1,15 @@
 use chrono::{DateTime, TimeZone, Utc};
 use lemmy_api_common::context::LemmyContext;
-use lemmy_db_schema::source::post::Post;
+use lemmy_db_schema::{
+  newtypes::{CommunityId, CommunityPostTagId},
+  source::{community_post_tag::CommunityPostTag, post::Post},
+};
 use lemmy_db_views::structs::LocalUserView;
-use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::slurs::{check_slurs, SlurRegex},
+};
+use std::collections::HashSet;
 
 pub mod create;
 pub mod delete;
@@ -36,3 +43,63 @@ async fn convert_published_time(
     Ok(None)
   }
 }
+
+const MAX_POLL_OPTIONS: usize = 20;
+const MAX_POLL_OPTION_LENGTH: usize = 200;
+
+/// Trims the poll options, and checks that there are enough distinct and valid ones.
+fn validate_poll_options(
+  options: &[String],
+  slur_regex: &Option<SlurRegex>,
+) -> LemmyResult<Vec<String>> {
+  let options = options
+    .iter()
+    .map(|o| o.trim().to_string())
+    .collect::<Vec<_>>();
+  let invalid_option = options
+    .iter()
+    .any(|o| o.is_empty() || o.chars().count() > MAX_POLL_OPTION_LENGTH);
+  if invalid_option
+    || !(2..=MAX_POLL_OPTIONS).contains(&options.len())
+    || options.iter().collect::<HashSet<_>>().len() != options.len()
+  {
+    Err(LemmyErrorType::InvalidPollOptions)?
+  }
+  for option in &options {
+    check_slurs(option, slur_regex)?;
+  }
+  Ok(options)
+}
+
+fn convert_poll_end_time(poll_end_time: Option<i64>) -> LemmyResult<Option<DateTime<Utc>>> {
+  if let Some(poll_end_time) = poll_end_time {
+    let converted = Utc
+      .timestamp_opt(poll_end_time, 0)
+      .single()
+      .ok_or(LemmyErrorType::InvalidUnixTime)?;
+    if converted < Utc::now() {
+      Err(LemmyErrorType::InvalidUnixTime)?;
+    }
+    Ok(Some(converted))
+  } else {
+    Ok(None)
+  }
+}
+
+/// Checks that the tags belong to the community of the post, and removes duplicates.
+async fn validate_post_tags(
+  tag_ids: &[CommunityPostTagId],
+  community_id: CommunityId,
+  context: &LemmyContext,
+) -> LemmyResult<Vec<CommunityPostTagId>> {
+  let community_tags = CommunityPostTag::list_for_community(&mut context.pool(), community_id)
+    .await?
+    .into_iter()
+    .map(|t| t.id)
+    .collect::<HashSet<_>>();
+  let tag_ids = tag_ids.iter().copied().collect::<HashSet<_>>();
+  if !tag_ids.is_subset(&community_tags) {
+    Err(LemmyErrorType::InvalidPostTag)?
+  }
+  Ok(tag_ids.into_iter().collect())
+}
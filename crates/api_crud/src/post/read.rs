@@ -13,7 +13,7 @@ use lemmy_db_schema::{
 };
 use lemmy_db_views::{
   post_view::PostQuery,
-  structs::{LocalUserView, PostView, SiteView},
+  structs::{LocalUserView, PollView, PostView, SiteView},
 };
 use lemmy_db_views_actor::structs::{CommunityModeratorView, CommunityView};
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
@@ -105,11 +105,14 @@ pub async fn get_post(
     Vec::new()
   };
 
+  let poll = PollView::read_for_post(&mut context.pool(), post_id, person_id).await?;
+
   // Return the jwt
   Ok(Json(GetPostResponse {
     post_view,
     community_view,
     moderators,
     cross_posts,
+    poll,
   }))
 }
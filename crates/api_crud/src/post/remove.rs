@@ -2,10 +2,11 @@ use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
   build_response::build_post_response,
+  community_bot::ModerationActor,
   context::LemmyContext,
   post::{PostResponse, RemovePost},
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_mod_action,
+  utils::{acting_mod_person_id, check_community_mod_action_or_bot, log_community_bot_action},
 };
 use lemmy_db_schema::{
   source::{
@@ -16,16 +17,20 @@ use lemmy_db_schema::{
     post_report::PostReport,
   },
   traits::{Crud, Reportable},
+  CommunityBotScope,
 };
-use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::error::LemmyResult;
 
 #[tracing::instrument(skip(context))]
 pub async fn remove_post(
   data: Json<RemovePost>,
   context: Data<LemmyContext>,
-  local_user_view: LocalUserView,
+  actor: ModerationActor,
 ) -> LemmyResult<Json<PostResponse>> {
+  let ModerationActor {
+    local_user_view,
+    bot_delegation,
+  } = actor;
   let post_id = data.post_id;
 
   // We cannot use PostView to avoid a database read here, as it doesn't return removed items
@@ -34,18 +39,20 @@ pub async fn remove_post(
   let orig_post = Post::read(&mut context.pool(), post_id).await?;
   let community = Community::read(&mut context.pool(), orig_post.community_id).await?;
 
-  check_community_mod_action(
+  let delegation = check_community_mod_action_or_bot(
     &local_user_view.person,
+    &bot_delegation,
     &community,
-    false,
+    CommunityBotScope::RemovePost,
     &mut context.pool(),
   )
   .await?;
 
+  // A bot acts with the authority of the moderator who delegated to it
   LocalUser::is_higher_mod_or_admin_check(
     &mut context.pool(),
     orig_post.community_id,
-    local_user_view.person.id,
+    acting_mod_person_id(&local_user_view.person, &delegation),
     vec![orig_post.creator_id],
   )
   .await?;
@@ -68,12 +75,23 @@ pub async fn remove_post(
 
   // Mod tables
   let form = ModRemovePostForm {
-    mod_person_id: local_user_view.person.id,
+    mod_person_id: acting_mod_person_id(&local_user_view.person, &delegation),
     post_id: data.post_id,
     removed: Some(removed),
     reason: data.reason.clone(),
+    automod: delegation.is_some(),
   };
   ModRemovePost::create(&mut context.pool(), &form).await?;
+  log_community_bot_action(
+    &delegation,
+    CommunityBotScope::RemovePost,
+    Some(post_id),
+    None,
+    removed,
+    data.reason.clone(),
+    &mut context.pool(),
+  )
+  .await?;
 
   ActivityChannel::submit_activity(
     SendActivityData::RemovePost {
@@ -81,6 +99,7 @@ pub async fn remove_post(
       moderator: local_user_view.person.clone(),
       reason: data.reason.clone(),
       removed: data.removed,
+      automod: delegation.is_some(),
     },
     &context,
   )?;
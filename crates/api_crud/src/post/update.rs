@@ -1,25 +1,30 @@
-use super::{convert_published_time, create::send_webmention};
+use super::{convert_published_time, create::send_webmention, validate_post_tags};
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
   build_response::build_post_response,
+  content_filter::FilterRuleMatches,
   context::LemmyContext,
   post::{EditPost, PostResponse},
   request::generate_post_link_metadata,
   send_activity::SendActivityData,
   utils::{
+    check_community_domain_blocked,
     check_community_user_action,
     get_url_blocklist,
     local_site_to_slur_regex,
     process_markdown_opt,
+    process_post_gallery,
   },
 };
 use lemmy_db_schema::{
   impls::actor_language::validate_post_language,
   source::{
     community::Community,
+    community_post_tag::PostTag,
     local_site::LocalSite,
     post::{Post, PostUpdateForm},
+    post_media::PostMedia,
   },
   traits::Crud,
   utils::{diesel_string_update, diesel_url_update, naive_now},
@@ -86,6 +91,12 @@ pub async fn update_post(
     is_valid_url(custom_thumbnail)?;
   }
 
+  let gallery = data
+    .gallery
+    .as_deref()
+    .map(|gallery| process_post_gallery(gallery, &url_blocklist))
+    .transpose()?;
+
   let post_id = data.post_id;
   let orig_post = PostView::read(&mut context.pool(), post_id, None, false).await?;
 
@@ -100,6 +111,30 @@ pub async fn update_post(
   if !Post::is_post_creator(local_user_view.person.id, orig_post.post.creator_id) {
     Err(LemmyErrorType::NoPostEditAllowed)?
   }
+  if let Some(url) = &url {
+    check_community_domain_blocked(orig_post.community.id, url.as_ref(), &mut context.pool())
+      .await?;
+  }
+
+  let filter_matches = FilterRuleMatches::check_post(
+    data.name.as_deref().unwrap_or(&orig_post.post.name),
+    body
+      .as_ref()
+      .map_or(orig_post.post.body.as_deref(), Option::as_deref),
+    url
+      .as_ref()
+      .map_or(orig_post.post.url.as_ref(), Option::as_ref),
+    orig_post.community.id,
+    &local_user_view,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let tag_ids = if let Some(tag_ids) = &data.tag_ids {
+    Some(validate_post_tags(tag_ids, orig_post.community.id, &context).await?)
+  } else {
+    None
+  };
 
   let language_id = validate_post_language(
     &mut context.pool(),
@@ -140,6 +175,17 @@ pub async fn update_post(
   let updated_post = Post::update(&mut context.pool(), post_id, &post_form)
     .await
     .with_lemmy_type(LemmyErrorType::CouldntUpdatePost)?;
+  filter_matches
+    .report_post(&updated_post, false, &mut context.pool())
+    .await;
+
+  if let Some(gallery) = gallery {
+    PostMedia::replace_for_post(&mut context.pool(), post_id, gallery).await?;
+  }
+
+  if let Some(tag_ids) = tag_ids {
+    PostTag::set(&mut context.pool(), post_id, tag_ids).await?;
+  }
 
   // send out federation/webmention if necessary
   match (
@@ -153,17 +199,17 @@ pub async fn update_post(
       generate_post_link_metadata(
         updated_post.clone(),
         custom_thumbnail.flatten().map(Into::into),
-        |post| Some(SendActivityData::CreatePost(post)),
+        |post| (!post.pending).then_some(SendActivityData::CreatePost(post)),
         context.reset_request_count(),
       )
       .await?;
     }
-    // post was already public, send update
+    // post was already public, send update. Pending posts are federated once approved.
     (None, _) => {
       generate_post_link_metadata(
         updated_post.clone(),
         custom_thumbnail.flatten().map(Into::into),
-        |post| Some(SendActivityData::UpdatePost(post)),
+        |post| (!post.pending).then_some(SendActivityData::UpdatePost(post)),
         context.reset_request_count(),
       )
       .await?
@@ -2,12 +2,16 @@ use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
   context::LemmyContext,
+  matrix_bridge::bridge_private_message,
   private_message::{CreatePrivateMessage, PrivateMessageResponse},
   send_activity::{ActivityChannel, SendActivityData},
   utils::{
+    check_new_account_restrictions,
+    check_private_messages_allowed,
     check_private_messages_enabled,
     get_interface_language,
     get_url_blocklist,
+    is_private_message_request,
     local_site_to_slur_regex,
     process_markdown,
     send_email_to_user,
@@ -17,13 +21,16 @@ use lemmy_db_schema::{
   source::{
     local_site::LocalSite,
     person_block::PersonBlock,
+    pm_conversation::PmConversation,
     private_message::{PrivateMessage, PrivateMessageInsertForm},
   },
   traits::Crud,
+  NewAccountRestriction,
 };
 use lemmy_db_views::structs::{LocalUserView, PrivateMessageView};
 use lemmy_utils::{
   error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  spawn_try_task,
   utils::{markdown::markdown_to_html, validation::is_valid_body_field},
 };
 
@@ -40,39 +47,101 @@ pub async fn create_private_message(
   let content = process_markdown(&data.content, &slur_regex, &url_blocklist, &context).await?;
   is_valid_body_field(&content, false)?;
 
-  PersonBlock::read(
+  check_private_messages_enabled(&local_user_view)?;
+  check_new_account_restrictions(
+    &local_user_view,
+    &local_site,
+    None,
+    NewAccountRestriction::PrivateMessages,
     &mut context.pool(),
-    data.recipient_id,
-    local_user_view.person.id,
   )
   .await?;
 
-  check_private_messages_enabled(&local_user_view)?;
+  let (private_message_form, request_recipient_id) = match (data.recipient_id, data.conversation_id)
+  {
+    (Some(recipient_id), None) => {
+      PersonBlock::read(&mut context.pool(), recipient_id, local_user_view.person.id).await?;
 
-  // Don't allow local sends to people who have private messages disabled
-  let recipient_local_user_opt = LocalUserView::read_person(&mut context.pool(), data.recipient_id)
-    .await
-    .ok();
-  if let Some(recipient_local_user) = recipient_local_user_opt {
-    check_private_messages_enabled(&recipient_local_user)?;
-  }
+      // Don't allow local sends to people who have private messages disabled
+      let recipient_local_user_opt = LocalUserView::read_person(&mut context.pool(), recipient_id)
+        .await
+        .ok();
+      let mut is_request = false;
+      if let Some(recipient_local_user) = recipient_local_user_opt {
+        check_private_messages_enabled(&recipient_local_user)?;
+
+        // Admins can always reach local users
+        if !local_user_view.local_user.admin {
+          check_private_messages_allowed(
+            local_user_view.person.id,
+            &recipient_local_user,
+            &mut context.pool(),
+          )
+          .await?;
+          is_request = is_private_message_request(
+            local_user_view.person.id,
+            &recipient_local_user,
+            &mut context.pool(),
+          )
+          .await?;
+        }
+      }
+
+      let form = PrivateMessageInsertForm::new(
+        local_user_view.person.id,
+        Some(recipient_id),
+        content.clone(),
+      );
+      (form, is_request.then_some(recipient_id))
+    }
+    (None, Some(conversation_id)) => {
+      let conversation = PmConversation::read(&mut context.pool(), conversation_id).await?;
+      if !conversation.is_group {
+        Err(LemmyErrorType::PmConversationNotAGroup)?
+      }
+      if !conversation
+        .is_participant(&mut context.pool(), local_user_view.person.id)
+        .await?
+      {
+        Err(LemmyErrorType::NotPmConversationParticipant)?
+      }
 
-  let private_message_form = PrivateMessageInsertForm::new(
-    local_user_view.person.id,
-    data.recipient_id,
-    content.clone(),
-  );
+      let form = PrivateMessageInsertForm {
+        conversation_id: Some(conversation.id),
+        ..PrivateMessageInsertForm::new(local_user_view.person.id, None, content.clone())
+      };
+      (form, None)
+    }
+    _ => Err(LemmyErrorType::InvalidPrivateMessageTarget)?,
+  };
 
   let inserted_private_message = PrivateMessage::create(&mut context.pool(), &private_message_form)
     .await
     .with_lemmy_type(LemmyErrorType::CouldntCreatePrivateMessage)?;
 
+  let conversation_id = inserted_private_message.conversation_id;
+  if let Some(request_recipient_id) = request_recipient_id {
+    PmConversation::mark_as_request(&mut context.pool(), conversation_id, request_recipient_id)
+      .await?;
+  } else if data.recipient_id.is_some() {
+    // Replying to a message request accepts it
+    PmConversation::accept_request(
+      &mut context.pool(),
+      conversation_id,
+      local_user_view.person.id,
+    )
+    .await?;
+  }
+
   let view = PrivateMessageView::read(&mut context.pool(), inserted_private_message.id).await?;
 
-  // Send email to the local recipient, if one exists
-  if view.recipient.local {
-    let recipient_id = data.recipient_id;
-    let local_recipient = LocalUserView::read_person(&mut context.pool(), recipient_id).await?;
+  // Send email to the local recipient, if one exists. Message requests don't send notifications.
+  if let Some(recipient) = view
+    .recipient
+    .as_ref()
+    .filter(|r| r.local && request_recipient_id.is_none())
+  {
+    let local_recipient = LocalUserView::read_person(&mut context.pool(), recipient.id).await?;
     let lang = get_interface_language(&local_recipient);
     let inbox_link = format!("{}/inbox", context.settings().get_protocol_and_hostname());
     let sender_name = &local_user_view.person.name;
@@ -86,6 +155,16 @@ pub async fn create_private_message(
     .await;
   }
 
+  // Relay direct messages to Matrix in the background, unless they are held back as requests
+  if let (Some(recipient), None) = (view.recipient.clone(), request_recipient_id) {
+    let private_message = view.private_message.clone();
+    let creator = view.creator.clone();
+    let context = context.reset_request_count();
+    spawn_try_task(async move {
+      bridge_private_message(&private_message, &creator, &recipient, &context).await
+    });
+  }
+
   ActivityChannel::submit_activity(
     SendActivityData::CreatePrivateMessage(view.clone()),
     &context,
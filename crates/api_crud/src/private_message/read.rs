@@ -18,11 +18,14 @@ pub async fn get_private_message(
   let limit = data.limit;
   let unread_only = data.unread_only.unwrap_or_default();
   let creator_id = data.creator_id;
+  let conversation_id = data.conversation_id;
   let messages = PrivateMessageQuery {
     page,
     limit,
     unread_only,
     creator_id,
+    conversation_id,
+    before: None,
   }
   .list(&mut context.pool(), person_id)
   .await?;
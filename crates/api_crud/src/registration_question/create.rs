@@ -0,0 +1,45 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  registration_question::{CreateRegistrationQuestion, RegistrationQuestionResponse},
+  utils::{is_admin, local_site_to_slur_regex},
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    registration_question::{RegistrationQuestion, RegistrationQuestionInsertForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::{slurs::check_slurs, validation::is_valid_body_field},
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_registration_question(
+  data: Json<CreateRegistrationQuestion>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RegistrationQuestionResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let slur_regex = local_site_to_slur_regex(&local_site);
+  is_valid_body_field(&data.question, false)?;
+  check_slurs(&data.question, &slur_regex)?;
+
+  let form = RegistrationQuestionInsertForm {
+    required: data.required,
+    position: data.position,
+    ..RegistrationQuestionInsertForm::new(data.question.clone())
+  };
+  let registration_question = RegistrationQuestion::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(RegistrationQuestionResponse {
+    registration_question,
+  }))
+}
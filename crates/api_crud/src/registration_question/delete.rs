@@ -0,0 +1,35 @@
+use crate::site::application_question_check;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  registration_question::DeleteRegistrationQuestion,
+  utils::is_admin,
+  SuccessResponse,
+};
+use lemmy_db_schema::{
+  source::{local_site::LocalSite, registration_question::RegistrationQuestion},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_registration_question(
+  data: Json<DeleteRegistrationQuestion>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  // The last question can't be removed while applications are required
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let questions = RegistrationQuestion::list(&mut context.pool()).await?;
+  let has_other_questions = questions.iter().any(|q| q.id != data.id);
+  application_question_check(has_other_questions, local_site.registration_mode)?;
+
+  RegistrationQuestion::delete(&mut context.pool(), data.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,50 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  registration_question::{EditRegistrationQuestion, RegistrationQuestionResponse},
+  utils::{is_admin, local_site_to_slur_regex},
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    registration_question::{RegistrationQuestion, RegistrationQuestionUpdateForm},
+  },
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::LemmyResult,
+  utils::{slurs::check_slurs, validation::is_valid_body_field},
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_registration_question(
+  data: Json<EditRegistrationQuestion>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<RegistrationQuestionResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  if let Some(question) = &data.question {
+    let local_site = LocalSite::read(&mut context.pool()).await?;
+    let slur_regex = local_site_to_slur_regex(&local_site);
+    is_valid_body_field(question, false)?;
+    check_slurs(question, &slur_regex)?;
+  }
+
+  let form = RegistrationQuestionUpdateForm {
+    question: data.question.clone(),
+    required: data.required,
+    position: data.position,
+    updated: Some(Some(naive_now())),
+  };
+  let registration_question =
+    RegistrationQuestion::update(&mut context.pool(), data.id, &form).await?;
+
+  Ok(Json(RegistrationQuestionResponse {
+    registration_question,
+  }))
+}
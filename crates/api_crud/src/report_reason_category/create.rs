@@ -0,0 +1,33 @@
+use super::validate_category_name;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  report_reason_category::{CreateReportReasonCategory, ReportReasonCategoryResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  source::report_reason_category::{ReportReasonCategory, ReportReasonCategoryInsertForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn create_report_reason_category(
+  data: Json<CreateReportReasonCategory>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ReportReasonCategoryResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let name = validate_category_name(&data.name, &context).await?;
+  let category = ReportReasonCategory::create(
+    &mut context.pool(),
+    &ReportReasonCategoryInsertForm { name },
+  )
+  .await?;
+
+  Ok(Json(ReportReasonCategoryResponse { category }))
+}
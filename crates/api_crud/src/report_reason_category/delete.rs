@@ -0,0 +1,26 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  report_reason_category::DeleteReportReasonCategory,
+  utils::is_admin,
+  SuccessResponse,
+};
+use lemmy_db_schema::{source::report_reason_category::ReportReasonCategory, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+/// Deletes a report reason category. Existing reports with this category keep their reason text.
+#[tracing::instrument(skip(context))]
+pub async fn delete_report_reason_category(
+  data: Json<DeleteReportReasonCategory>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  ReportReasonCategory::delete(&mut context.pool(), data.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
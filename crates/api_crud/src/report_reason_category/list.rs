@@ -0,0 +1,25 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  report_reason_category::ListReportReasonCategoriesResponse,
+  utils::check_private_instance,
+};
+use lemmy_db_schema::source::{
+  local_site::LocalSite,
+  report_reason_category::ReportReasonCategory,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_report_reason_categories(
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> LemmyResult<Json<ListReportReasonCategoriesResponse>> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let categories = ReportReasonCategory::list(&mut context.pool()).await?;
+
+  Ok(Json(ListReportReasonCategoriesResponse { categories }))
+}
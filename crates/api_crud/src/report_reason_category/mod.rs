@@ -0,0 +1,24 @@
+use lemmy_api_common::{context::LemmyContext, utils::local_site_to_slur_regex};
+use lemmy_db_schema::source::local_site::LocalSite;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  utils::slurs::check_slurs,
+};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+const MAX_CATEGORY_NAME_LENGTH: usize = 100;
+
+/// Trims the category name, and checks that it is neither empty nor too long.
+async fn validate_category_name(name: &str, context: &LemmyContext) -> LemmyResult<String> {
+  let name = name.trim();
+  if name.is_empty() || name.chars().count() > MAX_CATEGORY_NAME_LENGTH {
+    Err(LemmyErrorType::InvalidReportReasonCategoryName)?
+  }
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_slurs(name, &local_site_to_slur_regex(&local_site))?;
+  Ok(name.to_string())
+}
@@ -0,0 +1,33 @@
+use super::validate_category_name;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  report_reason_category::{EditReportReasonCategory, ReportReasonCategoryResponse},
+  utils::is_admin,
+};
+use lemmy_db_schema::{
+  source::report_reason_category::{ReportReasonCategory, ReportReasonCategoryUpdateForm},
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+
+#[tracing::instrument(skip(context))]
+pub async fn update_report_reason_category(
+  data: Json<EditReportReasonCategory>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ReportReasonCategoryResponse>> {
+  // Make sure user is an admin
+  is_admin(&local_user_view)?;
+
+  let form = ReportReasonCategoryUpdateForm {
+    name: validate_category_name(&data.name, &context).await?,
+    updated: naive_now(),
+  };
+  let category = ReportReasonCategory::update(&mut context.pool(), data.id, &form).await?;
+
+  Ok(Json(ReportReasonCategoryResponse { category }))
+}
@@ -20,20 +20,28 @@ use lemmy_db_schema::{
   source::{
     local_site::{LocalSite, LocalSiteUpdateForm},
     local_site_rate_limit::{LocalSiteRateLimit, LocalSiteRateLimitUpdateForm},
+    registration_question::RegistrationQuestion,
     site::{Site, SiteUpdateForm},
   },
   traits::Crud,
-  utils::{diesel_string_update, diesel_url_create, naive_now},
+  utils::{
+    cache_invalidation::CacheInvalidation,
+    diesel_opt_number_update,
+    diesel_string_update,
+    diesel_url_create,
+    naive_now,
+  },
 };
 use lemmy_db_views::structs::{LocalUserView, SiteView};
 use lemmy_utils::{
   error::{LemmyErrorType, LemmyResult},
   utils::{
-    slurs::check_slurs,
+    slurs::{check_slurs, SlurRegex},
     validation::{
       build_and_check_regex,
       check_site_visibility_valid,
       is_valid_body_field,
+      is_valid_ldap_settings,
       site_name_length_check,
       site_or_community_description_length_check,
     },
@@ -52,7 +60,10 @@ pub async fn create_site(
   // Make sure user is an admin; other types of users should not create site data...
   is_admin(&local_user_view)?;
 
-  validate_create_payload(&local_site, &data)?;
+  let has_registration_questions = !RegistrationQuestion::list(&mut context.pool())
+    .await?
+    .is_empty();
+  validate_create_payload(&local_site, &data, has_registration_questions)?;
 
   let actor_id: DbUrl = Url::parse(&context.settings().get_protocol_and_hostname())?.into();
   let inbox_url = Some(generate_inbox_url()?);
@@ -93,7 +104,6 @@ pub async fn create_site(
     registration_mode: data.registration_mode,
     community_creation_admin_only: data.community_creation_admin_only,
     require_email_verification: data.require_email_verification,
-    application_question: diesel_string_update(data.application_question.as_deref()),
     private_instance: data.private_instance,
     default_theme: data.default_theme.clone(),
     default_post_listing_type: data.default_post_listing_type,
@@ -113,6 +123,27 @@ pub async fn create_site(
     post_downvotes: data.post_downvotes,
     comment_upvotes: data.comment_upvotes,
     comment_downvotes: data.comment_downvotes,
+    instance_blocks_public: data.instance_blocks_public,
+    slur_filter_normalize: data.slur_filter_normalize,
+    spam_link_density_weight: data.spam_link_density_weight,
+    spam_repetition_weight: data.spam_repetition_weight,
+    spam_account_age_weight: data.spam_account_age_weight,
+    spam_hold_threshold: diesel_opt_number_update(data.spam_hold_threshold),
+    spam_remove_threshold: diesel_opt_number_update(data.spam_remove_threshold),
+    community_soft_launch_posts: diesel_opt_number_update(data.community_soft_launch_posts),
+    default_show_nsfw: data.default_show_nsfw,
+    default_show_bot_accounts: data.default_show_bot_accounts,
+    default_send_notifications_to_email: data.default_send_notifications_to_email,
+    default_interface_language: data.default_interface_language.clone(),
+    reputation_half_life_days: diesel_opt_number_update(data.reputation_half_life_days),
+    spam_low_reputation_weight: data.spam_low_reputation_weight,
+    ldap_url: diesel_string_update(data.ldap_url.as_deref()),
+    ldap_user_dn_template: diesel_string_update(data.ldap_user_dn_template.as_deref()),
+    block_disposable_email_domains: data.block_disposable_email_domains,
+    new_account_min_age_days: diesel_opt_number_update(data.new_account_min_age_days),
+    new_account_min_comments: diesel_opt_number_update(data.new_account_min_comments),
+    new_account_require_verified_email: data.new_account_require_verified_email,
+    new_account_restrictions: data.new_account_restrictions.clone(),
     ..Default::default()
   };
 
@@ -131,6 +162,10 @@ pub async fn create_site(
     comment_per_second: not_zero(data.rate_limit_comment_per_second),
     search: data.rate_limit_search,
     search_per_second: not_zero(data.rate_limit_search_per_second),
+    register_subnet: data.rate_limit_register_subnet,
+    register_subnet_per_second: not_zero(data.rate_limit_register_subnet_per_second),
+    register_asn: data.rate_limit_register_asn,
+    register_asn_per_second: not_zero(data.rate_limit_register_asn_per_second),
     ..Default::default()
   };
 
@@ -142,13 +177,25 @@ pub async fn create_site(
     local_site_rate_limit_to_rate_limit_config(&site_view.local_site_rate_limit);
   context.rate_limit_cell().set_config(rate_limit_config);
 
+  // Let other Lemmy processes using the same database pick up the changes
+  CacheInvalidation::SiteView
+    .notify(&mut context.pool())
+    .await?;
+  CacheInvalidation::RateLimits
+    .notify(&mut context.pool())
+    .await?;
+
   Ok(Json(SiteResponse {
     site_view,
     taglines: vec![],
   }))
 }
 
-fn validate_create_payload(local_site: &LocalSite, create_site: &CreateSite) -> LemmyResult<()> {
+fn validate_create_payload(
+  local_site: &LocalSite,
+  create_site: &CreateSite,
+  has_registration_questions: bool,
+) -> LemmyResult<()> {
   // Make sure the site hasn't already been set up...
   if local_site.site_setup {
     Err(LemmyErrorType::SiteAlreadyExists)?
@@ -161,7 +208,13 @@ fn validate_create_payload(local_site: &LocalSite, create_site: &CreateSite) ->
       .slur_filter_regex
       .as_deref()
       .or(local_site.slur_filter_regex.as_deref()),
-  )?;
+  )?
+  .map(|regex| {
+    let normalize = create_site
+      .slur_filter_normalize
+      .unwrap_or(local_site.slur_filter_normalize);
+    SlurRegex::new(regex, normalize)
+  });
 
   site_name_length_check(&create_site.name)?;
   check_slurs(&create_site.name, &slur_regex)?;
@@ -185,9 +238,19 @@ fn validate_create_payload(local_site: &LocalSite, create_site: &CreateSite) ->
     is_valid_body_field(body, false)?;
   }
 
+  is_valid_ldap_settings(
+    create_site
+      .ldap_url
+      .as_deref()
+      .or(local_site.ldap_url.as_deref()),
+    create_site
+      .ldap_user_dn_template
+      .as_deref()
+      .or(local_site.ldap_user_dn_template.as_deref()),
+  )?;
+
   application_question_check(
-    &local_site.application_question,
-    &create_site.application_question,
+    has_registration_questions,
     create_site
       .registration_mode
       .unwrap_or(local_site.registration_mode),
@@ -307,7 +370,7 @@ mod tests {
         },
       ),
       (
-        "CreateSite requires application, but neither it nor LocalSite has an application question",
+        "CreateSite requires application, but there are no registration questions",
         LemmyErrorType::ApplicationQuestionRequired,
         &LocalSite {
           site_setup: false,
@@ -329,10 +392,7 @@ mod tests {
          idx,
          &(reason, ref expected_err, local_site, create_site),
        )| {
-        match validate_create_payload(
-          local_site,
-          create_site,
-        ) {
+        match validate_create_payload(local_site, create_site, false) {
           Ok(_) => {
             panic!(
               "Got Ok, but validation should have failed with error: {} for reason: {}. invalid_payloads.nth({})",
@@ -384,7 +444,6 @@ mod tests {
           name: String::from("site_name"),
           sidebar: Some(String::new()),
           description: Some(String::new()),
-          application_question: Some(String::new()),
           private_instance: Some(false),
           default_post_listing_type: Some(ListingType::All),
           default_post_sort_type: Some(PostSortType::Active),
@@ -411,10 +470,9 @@ mod tests {
         },
       ),
       (
-        "LocalSite has application question and CreateSite now requires applications,",
+        "Registration questions exist and CreateSite now requires applications,",
         &LocalSite {
           site_setup: false,
-          application_question: Some(String::from("question")),
           private_instance: true,
           federation_enabled: false,
           registration_mode: RegistrationMode::Open,
@@ -433,7 +491,7 @@ mod tests {
       .enumerate()
       .for_each(|(idx, &(reason, local_site, edit_site))| {
         assert!(
-          validate_create_payload(local_site, edit_site).is_ok(),
+          validate_create_payload(local_site, edit_site, true).is_ok(),
           "Got Err, but should have got Ok for reason: {}. valid_payloads.nth({})",
           reason,
           idx
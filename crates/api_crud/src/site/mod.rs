@@ -21,19 +21,12 @@ pub fn site_default_post_listing_type_check(
   }
 }
 
-/// Checks whether the application question and registration mode align.
+/// Checks that registration questions exist if the registration mode requires applications.
 pub fn application_question_check(
-  current_application_question: &Option<String>,
-  new_application_question: &Option<String>,
+  has_registration_questions: bool,
   registration_mode: RegistrationMode,
 ) -> LemmyResult<()> {
-  let has_no_question: bool =
-    current_application_question.is_none() && new_application_question.is_none();
-  let is_nullifying_question: bool = new_application_question == &Some(String::new());
-
-  if registration_mode == RegistrationMode::RequireApplication
-    && (has_no_question || is_nullifying_question)
-  {
+  if registration_mode == RegistrationMode::RequireApplication && !has_registration_questions {
     Err(LemmyErrorType::ApplicationQuestionRequired)?
   } else {
     Ok(())
@@ -64,38 +57,16 @@ mod tests {
   #[test]
   fn test_application_question_check() {
     assert!(
-      application_question_check(&Some(String::from("q")), &Some(String::new()), RegistrationMode::RequireApplication).is_err(),
-      "Expected application to be invalid because an application is required, current question: {:?}, new question: {:?}",
-      "q",
-      String::new(),
-    );
-    assert!(
-      application_question_check(&None, &None, RegistrationMode::RequireApplication).is_err(),
-      "Expected application to be invalid because an application is required, current question: {:?}, new question: {:?}",
-      None::<String>,
-      None::<String>
-    );
-
-    assert!(
-      application_question_check(&None, &None, RegistrationMode::Open).is_ok(),
-      "Expected application to be valid because no application required, current question: {:?}, new question: {:?}, mode: {:?}",
-      None::<String>,
-      None::<String>,
-      RegistrationMode::Open
+      application_question_check(false, RegistrationMode::RequireApplication).is_err(),
+      "Expected application to be invalid because an application is required without questions"
     );
     assert!(
-      application_question_check(&None, &Some(String::from("q")), RegistrationMode::RequireApplication).is_ok(),
-      "Expected application to be valid because new application provided, current question: {:?}, new question: {:?}, mode: {:?}",
-      None::<String>,
-      Some(String::from("q")),
-      RegistrationMode::RequireApplication
+      application_question_check(false, RegistrationMode::Open).is_ok(),
+      "Expected application to be valid because no application required"
     );
     assert!(
-      application_question_check(&Some(String::from("q")), &None, RegistrationMode::RequireApplication).is_ok(),
-      "Expected application to be valid because application existed, current question: {:?}, new question: {:?}, mode: {:?}",
-      Some(String::from("q")),
-      None::<String>,
-      RegistrationMode::RequireApplication
+      application_question_check(true, RegistrationMode::RequireApplication).is_ok(),
+      "Expected application to be valid because registration questions exist"
     );
   }
 
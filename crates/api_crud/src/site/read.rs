@@ -1,16 +1,26 @@
-use actix_web::web::{Data, Json};
+use actix_web::{
+  web::{Data, Json},
+  HttpRequest,
+};
 use lemmy_api_common::{
   context::LemmyContext,
+  description_translation::{localize_site, preferred_language_ids},
+  feature_flag::{enabled_features, get_feature_flags},
   site::{GetSiteResponse, MyUserInfo},
 };
 use lemmy_db_schema::source::{
   actor_language::{LocalUserLanguage, SiteLanguage},
   community_block::CommunityBlock,
+  description_translation::SiteDescriptionTranslation,
   instance_block::InstanceBlock,
   language::Language,
+  local_site_email_domain::LocalSiteEmailDomain,
   local_site_url_blocklist::LocalSiteUrlBlocklist,
+  local_user_keyword_block::LocalUserKeywordBlock,
   oauth_provider::OAuthProvider,
+  person::PersonFollower,
   person_block::PersonBlock,
+  registration_question::RegistrationQuestion,
   tagline::Tagline,
 };
 use lemmy_db_views::structs::{LocalUserView, SiteView};
@@ -23,18 +33,19 @@ use lemmy_utils::{
 use moka::future::Cache;
 use std::sync::LazyLock;
 
+static CACHE: LazyLock<Cache<(), GetSiteResponse>> = LazyLock::new(|| {
+  Cache::builder()
+    .max_capacity(1)
+    .time_to_live(CACHE_DURATION_API)
+    .build()
+});
+
 #[tracing::instrument(skip(context))]
 pub async fn get_site(
+  req: HttpRequest,
   local_user_view: Option<LocalUserView>,
   context: Data<LemmyContext>,
 ) -> LemmyResult<Json<GetSiteResponse>> {
-  static CACHE: LazyLock<Cache<(), GetSiteResponse>> = LazyLock::new(|| {
-    Cache::builder()
-      .max_capacity(1)
-      .time_to_live(CACHE_DURATION_API)
-      .build()
-  });
-
   // This data is independent from the user account so we can cache it across requests
   let mut site_response = CACHE
     .try_get_with::<_, LemmyError>((), async {
@@ -45,8 +56,12 @@ pub async fn get_site(
       let blocked_urls = LocalSiteUrlBlocklist::get_all(&mut context.pool()).await?;
       let tagline = Tagline::get_random(&mut context.pool()).await.ok();
       let admin_oauth_providers = OAuthProvider::get_all(&mut context.pool()).await?;
+      let email_domains = LocalSiteEmailDomain::get_all(&mut context.pool()).await?;
       let oauth_providers =
         OAuthProvider::convert_providers_to_public(admin_oauth_providers.clone());
+      let description_translations =
+        SiteDescriptionTranslation::list(&mut context.pool(), site_view.site.id).await?;
+      let registration_questions = RegistrationQuestion::list(&mut context.pool()).await?;
 
       Ok(GetSiteResponse {
         site_view,
@@ -59,8 +74,13 @@ pub async fn get_site(
         tagline,
         oauth_providers: Some(oauth_providers),
         admin_oauth_providers: Some(admin_oauth_providers),
+        email_domains: Some(email_domains),
         taglines: vec![],
         custom_emojis: vec![],
+        feature_flags: vec![],
+        enabled_features: vec![],
+        description_translations,
+        registration_questions,
       })
     })
     .await
@@ -77,6 +97,8 @@ pub async fn get_site(
       community_blocks,
       instance_blocks,
       person_blocks,
+      person_follows,
+      keyword_blocks,
       moderates,
       discussion_languages,
     ) = lemmy_db_schema::try_join_with_pool!(pool => (
@@ -84,6 +106,8 @@ pub async fn get_site(
       |pool| CommunityBlock::for_person(pool, person_id),
       |pool| InstanceBlock::for_person(pool, person_id),
       |pool| PersonBlock::for_person(pool, person_id),
+      |pool| PersonFollower::list_followed(pool, person_id),
+      |pool| LocalUserKeywordBlock::for_local_user(pool, local_user_id),
       |pool| CommunityModeratorView::for_person(pool, person_id, Some(&local_user_view.local_user)),
       |pool| LocalUserLanguage::read(pool, local_user_id)
     ))
@@ -96,19 +120,42 @@ pub async fn get_site(
       community_blocks,
       instance_blocks,
       person_blocks,
+      person_follows,
+      keyword_blocks,
       discussion_languages,
     })
   } else {
     None
   };
 
-  // filter oauth_providers for public access
+  // Partially rolled out features differ per user, so they can't be part of the cached response
+  site_response.feature_flags = get_feature_flags(&context).await?;
+  site_response.enabled_features = enabled_features(
+    &site_response.feature_flags,
+    local_user_view.as_ref().map(|l| &l.local_user),
+  );
+
+  // Translations depend on the preferred language of the user
+  let language_ids = preferred_language_ids(local_user_view.as_ref(), &req, &context).await?;
+  localize_site(
+    &mut site_response.site_view.site,
+    &site_response.description_translations,
+    &language_ids,
+  );
+
+  // filter oauth_providers and email domains for public access
   if !local_user_view
     .map(|l| l.local_user.admin)
     .unwrap_or_default()
   {
     site_response.admin_oauth_providers = None;
+    site_response.email_domains = None;
   }
 
   Ok(Json(site_response))
 }
+
+/// Drops the cached site response, so that the next request gets it from the database.
+pub fn invalidate_site_cache() {
+  CACHE.invalidate_all();
+}
@@ -21,25 +21,35 @@ use lemmy_db_schema::{
     federation_allowlist::FederationAllowList,
     federation_blocklist::FederationBlockList,
     local_site::{LocalSite, LocalSiteUpdateForm},
+    local_site_email_domain::LocalSiteEmailDomain,
     local_site_rate_limit::{LocalSiteRateLimit, LocalSiteRateLimitUpdateForm},
     local_site_url_blocklist::LocalSiteUrlBlocklist,
     local_user::LocalUser,
+    registration_question::RegistrationQuestion,
     site::{Site, SiteUpdateForm},
   },
   traits::Crud,
-  utils::{diesel_string_update, diesel_url_update, naive_now},
+  utils::{
+    cache_invalidation::CacheInvalidation,
+    diesel_opt_number_update,
+    diesel_string_update,
+    diesel_url_update,
+    naive_now,
+  },
   RegistrationMode,
 };
 use lemmy_db_views::structs::{LocalUserView, SiteView};
 use lemmy_utils::{
   error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
   utils::{
-    slurs::check_slurs_opt,
+    slurs::{check_slurs_opt, SlurRegex},
     validation::{
       build_and_check_regex,
       check_site_visibility_valid,
       check_urls_are_valid,
+      clean_email_domain,
       is_valid_body_field,
+      is_valid_ldap_settings,
       site_name_length_check,
       site_or_community_description_length_check,
     },
@@ -59,7 +69,10 @@ pub async fn update_site(
   // Make sure user is an admin; other types of users should not update site data...
   is_admin(&local_user_view)?;
 
-  validate_update_payload(&local_site, &data)?;
+  let has_registration_questions = !RegistrationQuestion::list(&mut context.pool())
+    .await?
+    .is_empty();
+  validate_update_payload(&local_site, &data, has_registration_questions)?;
 
   if let Some(discussion_languages) = data.discussion_languages.clone() {
     SiteLanguage::update(&mut context.pool(), discussion_languages.clone(), &site).await?;
@@ -102,7 +115,6 @@ pub async fn update_site(
     registration_mode: data.registration_mode,
     community_creation_admin_only: data.community_creation_admin_only,
     require_email_verification: data.require_email_verification,
-    application_question: diesel_string_update(data.application_question.as_deref()),
     private_instance: data.private_instance,
     default_theme: data.default_theme.clone(),
     default_post_listing_type: data.default_post_listing_type,
@@ -124,6 +136,27 @@ pub async fn update_site(
     post_downvotes: data.post_downvotes,
     comment_upvotes: data.comment_upvotes,
     comment_downvotes: data.comment_downvotes,
+    instance_blocks_public: data.instance_blocks_public,
+    slur_filter_normalize: data.slur_filter_normalize,
+    spam_link_density_weight: data.spam_link_density_weight,
+    spam_repetition_weight: data.spam_repetition_weight,
+    spam_account_age_weight: data.spam_account_age_weight,
+    spam_hold_threshold: diesel_opt_number_update(data.spam_hold_threshold),
+    spam_remove_threshold: diesel_opt_number_update(data.spam_remove_threshold),
+    community_soft_launch_posts: diesel_opt_number_update(data.community_soft_launch_posts),
+    default_show_nsfw: data.default_show_nsfw,
+    default_show_bot_accounts: data.default_show_bot_accounts,
+    default_send_notifications_to_email: data.default_send_notifications_to_email,
+    default_interface_language: data.default_interface_language.clone(),
+    reputation_half_life_days: diesel_opt_number_update(data.reputation_half_life_days),
+    spam_low_reputation_weight: data.spam_low_reputation_weight,
+    ldap_url: diesel_string_update(data.ldap_url.as_deref()),
+    ldap_user_dn_template: diesel_string_update(data.ldap_user_dn_template.as_deref()),
+    block_disposable_email_domains: data.block_disposable_email_domains,
+    new_account_min_age_days: diesel_opt_number_update(data.new_account_min_age_days),
+    new_account_min_comments: diesel_opt_number_update(data.new_account_min_comments),
+    new_account_require_verified_email: data.new_account_require_verified_email,
+    new_account_restrictions: data.new_account_restrictions.clone(),
     ..Default::default()
   };
 
@@ -144,6 +177,10 @@ pub async fn update_site(
     comment_per_second: not_zero(data.rate_limit_comment_per_second),
     search: data.rate_limit_search,
     search_per_second: not_zero(data.rate_limit_search_per_second),
+    register_subnet: data.rate_limit_register_subnet,
+    register_subnet_per_second: not_zero(data.rate_limit_register_subnet_per_second),
+    register_asn: data.rate_limit_register_asn,
+    register_asn_per_second: not_zero(data.rate_limit_register_asn_per_second),
     ..Default::default()
   };
 
@@ -160,6 +197,24 @@ pub async fn update_site(
   if let Some(url_blocklist) = data.blocked_urls.clone() {
     let parsed_urls = check_urls_are_valid(&url_blocklist)?;
     LocalSiteUrlBlocklist::replace(&mut context.pool(), parsed_urls).await?;
+    CacheInvalidation::UrlBlocklist
+      .notify(&mut context.pool())
+      .await?;
+  }
+
+  if let Some(allowed) = &data.allowed_email_domains {
+    let domains = allowed
+      .iter()
+      .map(|d| clean_email_domain(d))
+      .collect::<LemmyResult<Vec<_>>>()?;
+    LocalSiteEmailDomain::replace(&mut context.pool(), true, domains).await?;
+  }
+  if let Some(blocked) = &data.blocked_email_domains {
+    let domains = blocked
+      .iter()
+      .map(|d| clean_email_domain(d))
+      .collect::<LemmyResult<Vec<_>>>()?;
+    LocalSiteEmailDomain::replace(&mut context.pool(), false, domains).await?;
   }
 
   // TODO can't think of a better way to do this.
@@ -196,13 +251,25 @@ pub async fn update_site(
     local_site_rate_limit_to_rate_limit_config(&site_view.local_site_rate_limit);
   context.rate_limit_cell().set_config(rate_limit_config);
 
+  // Let other Lemmy processes using the same database pick up the changes
+  CacheInvalidation::SiteView
+    .notify(&mut context.pool())
+    .await?;
+  CacheInvalidation::RateLimits
+    .notify(&mut context.pool())
+    .await?;
+
   Ok(Json(SiteResponse {
     site_view,
     taglines: vec![],
   }))
 }
 
-fn validate_update_payload(local_site: &LocalSite, edit_site: &EditSite) -> LemmyResult<()> {
+fn validate_update_payload(
+  local_site: &LocalSite,
+  edit_site: &EditSite,
+  has_registration_questions: bool,
+) -> LemmyResult<()> {
   // Check that the slur regex compiles, and return the regex if valid...
   // Prioritize using new slur regex from the request; if not provided, use the existing regex.
   let slur_regex = build_and_check_regex(
@@ -210,7 +277,13 @@ fn validate_update_payload(local_site: &LocalSite, edit_site: &EditSite) -> Lemm
       .slur_filter_regex
       .as_deref()
       .or(local_site.slur_filter_regex.as_deref()),
-  )?;
+  )?
+  .map(|regex| {
+    let normalize = edit_site
+      .slur_filter_normalize
+      .unwrap_or(local_site.slur_filter_normalize);
+    SlurRegex::new(regex, normalize)
+  });
 
   if let Some(name) = &edit_site.name {
     // The name doesn't need to be updated, but if provided it cannot be blanked out...
@@ -237,9 +310,19 @@ fn validate_update_payload(local_site: &LocalSite, edit_site: &EditSite) -> Lemm
     is_valid_body_field(body, false)?;
   }
 
+  is_valid_ldap_settings(
+    edit_site
+      .ldap_url
+      .as_deref()
+      .or(local_site.ldap_url.as_deref()),
+    edit_site
+      .ldap_user_dn_template
+      .as_deref()
+      .or(local_site.ldap_user_dn_template.as_deref()),
+  )?;
+
   application_question_check(
-    &local_site.application_question,
-    &edit_site.application_question,
+    has_registration_questions,
     edit_site
       .registration_mode
       .unwrap_or(local_site.registration_mode),
@@ -340,7 +423,7 @@ mod tests {
         },
       ),
       (
-        "EditSite requires application, but neither it nor LocalSite has an application question",
+        "EditSite requires application, but there are no registration questions",
         LemmyErrorType::ApplicationQuestionRequired,
         &LocalSite {
           private_instance: true,
@@ -361,7 +444,7 @@ mod tests {
          idx,
          &(reason, ref expected_err, local_site, edit_site),
        )| {
-        match validate_update_payload(local_site, edit_site) {
+        match validate_update_payload(local_site, edit_site, false) {
           Ok(_) => {
             panic!(
               "Got Ok, but validation should have failed with error: {} for reason: {}. invalid_payloads.nth({})",
@@ -408,7 +491,6 @@ mod tests {
           name: Some(String::from("site_name")),
           sidebar: Some(String::new()),
           description: Some(String::new()),
-          application_question: Some(String::new()),
           private_instance: Some(false),
           default_post_listing_type: Some(ListingType::All),
           default_post_sort_type: Some(PostSortType::Active),
@@ -434,9 +516,8 @@ mod tests {
         },
       ),
       (
-        "LocalSite has application question and EditSite now requires applications,",
+        "Registration questions exist and EditSite now requires applications,",
         &LocalSite {
-          application_question: Some(String::from("question")),
           private_instance: true,
           federation_enabled: false,
           registration_mode: RegistrationMode::Open,
@@ -455,7 +536,7 @@ mod tests {
       .enumerate()
       .for_each(|(idx, &(reason, local_site, edit_site))| {
         assert!(
-          validate_update_payload(local_site, edit_site).is_ok(),
+          validate_update_payload(local_site, edit_site, true).is_ok(),
           "Got Err, but should have got Ok for reason: {}. valid_payloads.nth({})",
           reason,
           idx
@@ -1,3 +1,4 @@
+use super::registration_throttle::RegistrationNetwork;
 use activitypub_federation::{config::Data, http_signatures::generate_actor_keypair};
 use actix_web::{web::Json, HttpRequest};
 use lemmy_api_common::{
@@ -5,10 +6,11 @@ use lemmy_api_common::{
   context::LemmyContext,
   oauth_provider::AuthenticateWithOauth,
   person::{LoginResponse, Register},
+  registration_question::RegistrationQuestionAnswer,
   utils::{
     check_email_verified,
+    check_local_user_valid,
     check_registration_application,
-    check_user_valid,
     generate_inbox_url,
     generate_local_apub_endpoint,
     honeypot_check,
@@ -21,17 +23,23 @@ use lemmy_api_common::{
 };
 use lemmy_db_schema::{
   aggregates::structs::PersonAggregates,
-  newtypes::{InstanceId, OAuthProviderId},
+  newtypes::{InstanceId, OAuthProviderId, RegistrationApplicationId},
   source::{
     captcha_answer::{CaptchaAnswer, CheckCaptchaAnswer},
     language::Language,
     local_site::LocalSite,
+    local_site_email_domain::LocalSiteEmailDomain,
     local_user::{LocalUser, LocalUserInsertForm},
     local_user_vote_display_mode::LocalUserVoteDisplayMode,
     oauth_account::{OAuthAccount, OAuthAccountInsertForm},
     oauth_provider::OAuthProvider,
     person::{Person, PersonInsertForm},
     registration_application::{RegistrationApplication, RegistrationApplicationInsertForm},
+    registration_question::{
+      RegistrationAnswer,
+      RegistrationAnswerInsertForm,
+      RegistrationQuestion,
+    },
   },
   traits::Crud,
   RegistrationMode,
@@ -40,13 +48,15 @@ use lemmy_db_views::structs::{LocalUserView, SiteView};
 use lemmy_utils::{
   error::{LemmyError, LemmyErrorExt, LemmyErrorType, LemmyResult},
   utils::{
-    slurs::{check_slurs, check_slurs_opt},
-    validation::is_valid_actor_name,
+    language::accept_language_tags,
+    slurs::{check_slurs, SlurRegex},
+    validation::{check_email_domain, is_valid_actor_name, is_valid_body_field},
   },
 };
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::collections::HashSet;
+use url::Url;
 
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -80,11 +90,6 @@ pub async fn register(
     Err(LemmyErrorType::EmailRequired)?
   }
 
-  // make sure the registration answer is provided when the registration application is required
-  if local_site.site_setup {
-    validate_registration_answer(require_registration_application, &data.answer)?;
-  }
-
   // Make sure passwords match
   if data.password != data.password_verify {
     Err(LemmyErrorType::PasswordsDoNotMatch)?
@@ -104,13 +109,36 @@ pub async fn register(
 
   let slur_regex = local_site_to_slur_regex(&local_site);
   check_slurs(&data.username, &slur_regex)?;
-  check_slurs_opt(&data.answer, &slur_regex)?;
+
+  // make sure the required questions are answered when the registration application is required
+  let answers = if local_site.site_setup {
+    validate_registration_answers(
+      require_registration_application,
+      &data.answers,
+      &slur_regex,
+      &context,
+    )
+    .await?
+  } else {
+    vec![]
+  };
 
   Person::check_username_taken(&mut context.pool(), &data.username).await?;
 
   if let Some(email) = &data.email {
     LocalUser::check_is_email_taken(&mut context.pool(), email).await?;
   }
+  if local_site.site_setup {
+    check_email_domain_allowed(data.email.as_deref(), &local_site, &context).await?;
+  }
+
+  // Spam waves usually come from a single provider, so registrations are limited per network
+  let network = RegistrationNetwork::from_request(&req, context.settings());
+  if local_site.site_setup {
+    network
+      .check_throttle(&site_view.local_site_rate_limit, &context)
+      .await?;
+  }
 
   // We have to create both a person, and local_user
   let inserted_person = create_person(
@@ -120,22 +148,24 @@ pub async fn register(
     &context,
   )
   .await?;
+  network.save(inserted_person.id, &context).await?;
 
   // Automatically set their application as accepted, if they created this with open registration.
   // Also fixes a bug which allows users to log in when registrations are changed to closed.
   let accepted_application = Some(!require_registration_application);
 
-  // Show nsfw content if param is true, or if content_warning exists
+  // Show nsfw content if param is true, or if content_warning exists. Otherwise the site default
+  // is used.
   let show_nsfw = data
     .show_nsfw
-    .unwrap_or(site_view.site.content_warning.is_some());
+    .or(site_view.site.content_warning.is_some().then_some(true));
 
-  let language_tags = get_language_tags(&req);
+  let language_tags = accept_language_tags(&req);
 
   // Create the local user
   let local_user_form = LocalUserInsertForm {
     email: data.email.as_deref().map(str::to_lowercase),
-    show_nsfw: Some(show_nsfw),
+    show_nsfw,
     accepted_application,
     default_listing_type: Some(local_site.default_post_listing_type),
     post_listing_mode: Some(local_site.default_post_listing_mode),
@@ -151,11 +181,10 @@ pub async fn register(
     // Create the registration application
     let form = RegistrationApplicationInsertForm {
       local_user_id: inserted_local_user.id,
-      // We already made sure answer was not null above
-      answer: data.answer.clone().expect("must have an answer"),
     };
 
-    RegistrationApplication::create(&mut context.pool(), &form).await?;
+    let application = RegistrationApplication::create(&mut context.pool(), &form).await?;
+    save_registration_answers(application.id, answers, &context).await?;
   }
 
   // Email the admins, only if email verification is not required
@@ -203,39 +232,11 @@ pub async fn authenticate_with_oauth(
   let site_view = SiteView::read_local(&mut context.pool()).await?;
   let local_site = site_view.local_site.clone();
 
-  // validate inputs
-  if data.oauth_provider_id == OAuthProviderId(0) || data.code.is_empty() || data.code.len() > 300 {
-    return Err(LemmyErrorType::OauthAuthorizationInvalid)?;
-  }
-
-  // validate the redirect_uri
-  let redirect_uri = &data.redirect_uri;
-  if redirect_uri.host_str().unwrap_or("").is_empty()
-    || !redirect_uri.path().eq(&String::from("/oauth/callback"))
-    || !redirect_uri.query().unwrap_or("").is_empty()
-  {
-    Err(LemmyErrorType::OauthAuthorizationInvalid)?
-  }
-
-  // Fetch the OAUTH provider and make sure it's enabled
-  let oauth_provider_id = data.oauth_provider_id;
-  let oauth_provider = OAuthProvider::read(&mut context.pool(), oauth_provider_id)
-    .await
-    .ok()
-    .ok_or(LemmyErrorType::OauthAuthorizationInvalid)?;
-
-  if !oauth_provider.enabled {
-    return Err(LemmyErrorType::OauthAuthorizationInvalid)?;
-  }
-
-  let token_response =
-    oauth_request_access_token(&context, &oauth_provider, &data.code, redirect_uri.as_str())
-      .await?;
-
-  let user_info = oidc_get_user_info(
+  let (oauth_provider, user_info) = fetch_oauth_user_info(
     &context,
-    &oauth_provider,
-    token_response.access_token.as_str(),
+    data.oauth_provider_id,
+    &data.code,
+    &data.redirect_uri,
   )
   .await?;
 
@@ -256,7 +257,7 @@ pub async fn authenticate_with_oauth(
     // user found by oauth_user_id => Login user
     local_user = user_view.clone().local_user;
 
-    check_user_valid(&user_view.person)?;
+    check_local_user_valid(&user_view)?;
     check_email_verified(&user_view, &site_view)?;
     check_registration_application(&user_view, &site_view.local_site, &mut context.pool()).await?;
   } else {
@@ -293,7 +294,7 @@ pub async fn authenticate_with_oauth(
         // users who signed up before the switch could have accounts with unverified emails falsely
         // marked as verified.
 
-        check_user_valid(&user_view.person)?;
+        check_local_user_valid(&user_view)?;
         check_email_verified(&user_view, &site_view)?;
         check_registration_application(&user_view, &site_view.local_site, &mut context.pool())
           .await?;
@@ -313,9 +314,6 @@ pub async fn authenticate_with_oauth(
     } else {
       // No user was found by email => Register as new user
 
-      // make sure the registration answer is provided when the registration application is required
-      validate_registration_answer(require_registration_application, &data.answer)?;
-
       // make sure the username is provided
       let username = data
         .username
@@ -324,9 +322,24 @@ pub async fn authenticate_with_oauth(
 
       let slur_regex = local_site_to_slur_regex(&local_site);
       check_slurs(username, &slur_regex)?;
-      check_slurs_opt(&data.answer, &slur_regex)?;
+
+      // make sure the required questions are answered when the registration application is
+      // required
+      let answers = validate_registration_answers(
+        require_registration_application,
+        &data.answers,
+        &slur_regex,
+        &context,
+      )
+      .await?;
 
       Person::check_username_taken(&mut context.pool(), username).await?;
+      check_email_domain_allowed(Some(email.as_str()), &local_site, &context).await?;
+
+      let network = RegistrationNetwork::from_request(&req, context.settings());
+      network
+        .check_throttle(&site_view.local_site_rate_limit, &context)
+        .await?;
 
       // We have to create a person, a local_user, and an oauth_account
       person = create_person(
@@ -336,18 +349,20 @@ pub async fn authenticate_with_oauth(
         &context,
       )
       .await?;
+      network.save(person.id, &context).await?;
 
-      // Show nsfw content if param is true, or if content_warning exists
+      // Show nsfw content if param is true, or if content_warning exists. Otherwise the site
+      // default is used.
       let show_nsfw = data
         .show_nsfw
-        .unwrap_or(site_view.site.content_warning.is_some());
+        .or(site_view.site.content_warning.is_some().then_some(true));
 
-      let language_tags = get_language_tags(&req);
+      let language_tags = accept_language_tags(&req);
 
       // Create the local user
       let local_user_form = LocalUserInsertForm {
         email: Some(str::to_lowercase(&email)),
-        show_nsfw: Some(show_nsfw),
+        show_nsfw,
         accepted_application: Some(!require_registration_application),
         email_verified: Some(oauth_provider.auto_verify_email),
         post_listing_mode: Some(local_site.default_post_listing_mode),
@@ -374,14 +389,14 @@ pub async fn authenticate_with_oauth(
         && !local_user.admin
       {
         // Create the registration application
-        RegistrationApplication::create(
+        let application = RegistrationApplication::create(
           &mut context.pool(),
           &RegistrationApplicationInsertForm {
             local_user_id: local_user.id,
-            answer: data.answer.clone().expect("must have an answer"),
           },
         )
         .await?;
+        save_registration_answers(application.id, answers, &context).await?;
 
         login_response.registration_created = true;
       }
@@ -430,18 +445,6 @@ async fn create_person(
   Ok(inserted_person)
 }
 
-fn get_language_tags(req: &HttpRequest) -> Vec<String> {
-  req
-    .headers()
-    .get("Accept-Language")
-    .map(|hdr| accept_language::parse(hdr.to_str().unwrap_or_default()))
-    .iter()
-    .flatten()
-    // Remove the optional region code
-    .map(|lang_str| lang_str.split('-').next().unwrap_or_default().to_string())
-    .collect::<Vec<String>>()
-}
-
 async fn create_local_user(
   context: &Data<LemmyContext>,
   language_tags: Vec<String>,
@@ -494,17 +497,144 @@ async fn send_verification_email_if_required(
   Ok(sent)
 }
 
-fn validate_registration_answer(
-  require_registration_application: bool,
-  answer: &Option<String>,
+/// Rejects signups with an email whose domain is blocked, or which is missing from the allowlist
+/// if there is one.
+async fn check_email_domain_allowed(
+  email: Option<&str>,
+  local_site: &LocalSite,
+  context: &LemmyContext,
 ) -> LemmyResult<()> {
-  if require_registration_application && answer.is_none() {
-    Err(LemmyErrorType::RegistrationApplicationAnswerRequired)?
+  let (allowed, blocked): (Vec<_>, Vec<_>) = LocalSiteEmailDomain::get_all(&mut context.pool())
+    .await?
+    .into_iter()
+    .map(|d| (d.allowed, d.domain))
+    .partition(|(allowed, _)| *allowed);
+  let allowed = allowed.into_iter().map(|(_, d)| d).collect::<Vec<_>>();
+  let blocked = blocked.into_iter().map(|(_, d)| d).collect::<Vec<_>>();
+
+  match email {
+    Some(email) => check_email_domain(
+      email,
+      &allowed,
+      &blocked,
+      local_site.block_disposable_email_domains,
+    ),
+    // Without an email the allowlist could be bypassed
+    None if !allowed.is_empty() => Err(LemmyErrorType::EmailRequired)?,
+    None => Ok(()),
+  }
+}
+
+/// Checks the answers against the registration questions of the site. Returns the non-empty
+/// answers together with the question they belong to.
+async fn validate_registration_answers(
+  require_registration_application: bool,
+  answers: &Option<Vec<RegistrationQuestionAnswer>>,
+  slur_regex: &Option<SlurRegex>,
+  context: &LemmyContext,
+) -> LemmyResult<Vec<(RegistrationQuestion, String)>> {
+  if !require_registration_application {
+    return Ok(vec![]);
+  }
+  let answers = answers.as_deref().unwrap_or_default();
+  let questions = RegistrationQuestion::list(&mut context.pool()).await?;
+
+  if answers
+    .iter()
+    .any(|a| !questions.iter().any(|q| q.id == a.question_id))
+  {
+    Err(LemmyErrorType::UnknownRegistrationQuestion)?
+  }
+
+  let mut validated = vec![];
+  for question in questions {
+    let answer = answers
+      .iter()
+      .find(|a| a.question_id == question.id)
+      .map(|a| a.answer.trim())
+      .filter(|a| !a.is_empty());
+    match answer {
+      Some(answer) => {
+        is_valid_body_field(answer, false)?;
+        check_slurs(answer, slur_regex)?;
+        validated.push((question, answer.to_string()));
+      }
+      None if question.required => Err(LemmyErrorType::RegistrationApplicationAnswerRequired)?,
+      None => {}
+    }
   }
+  Ok(validated)
+}
 
+/// Stores the answers of a registration application, with a copy of the question text so that
+/// admins can still read them if the question is edited or deleted later.
+async fn save_registration_answers(
+  registration_application_id: RegistrationApplicationId,
+  answers: Vec<(RegistrationQuestion, String)>,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  if answers.is_empty() {
+    return Ok(());
+  }
+  let forms = answers
+    .into_iter()
+    .map(|(question, answer)| {
+      RegistrationAnswerInsertForm::new(
+        registration_application_id,
+        Some(question.id),
+        question.question,
+        answer,
+      )
+    })
+    .collect::<Vec<_>>();
+  RegistrationAnswer::create_many(&mut context.pool(), &forms).await?;
   Ok(())
 }
 
+/// Validates the authorization, exchanges its code for an access token and fetches the user info
+/// with it.
+pub(crate) async fn fetch_oauth_user_info(
+  context: &Data<LemmyContext>,
+  oauth_provider_id: OAuthProviderId,
+  code: &str,
+  redirect_uri: &Url,
+) -> LemmyResult<(OAuthProvider, serde_json::Value)> {
+  // validate inputs
+  if oauth_provider_id == OAuthProviderId(0) || code.is_empty() || code.len() > 300 {
+    return Err(LemmyErrorType::OauthAuthorizationInvalid)?;
+  }
+
+  // validate the redirect_uri
+  if redirect_uri.host_str().unwrap_or("").is_empty()
+    || !redirect_uri.path().eq(&String::from("/oauth/callback"))
+    || !redirect_uri.query().unwrap_or("").is_empty()
+  {
+    Err(LemmyErrorType::OauthAuthorizationInvalid)?
+  }
+
+  // Fetch the OAUTH provider and make sure it's enabled
+  let oauth_provider = OAuthProvider::read(&mut context.pool(), oauth_provider_id)
+    .await
+    .ok()
+    .ok_or(LemmyErrorType::OauthAuthorizationInvalid)?;
+
+  if !oauth_provider.enabled {
+    return Err(LemmyErrorType::OauthAuthorizationInvalid)?;
+  }
+
+  let token_response =
+    oauth_request_access_token(context, &oauth_provider, code, redirect_uri.as_str()).await?;
+
+  let user_info = oidc_get_user_info(
+    context,
+    &oauth_provider,
+    token_response.access_token.as_str(),
+  )
+  .await?;
+
+  Ok((oauth_provider, user_info))
+}
+
 async fn oauth_request_access_token(
   context: &Data<LemmyContext>,
   oauth_provider: &OAuthProvider,
@@ -568,7 +698,7 @@ async fn oidc_get_user_info(
   Ok(user_info)
 }
 
-fn read_user_info(user_info: &serde_json::Value, key: &str) -> LemmyResult<String> {
+pub(crate) fn read_user_info(user_info: &serde_json::Value, key: &str) -> LemmyResult<String> {
   if let Some(value) = user_info.get(key) {
     let result = serde_json::from_value::<String>(value.clone())
       .map_err(|_| LemmyErrorType::OauthLoginFailed)?;
@@ -5,7 +5,7 @@ use lemmy_api_common::{
   context::LemmyContext,
   person::DeleteAccount,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::purge_user_account,
+  utils::{anonymize_user_account, purge_user_account},
   SuccessResponse,
 };
 use lemmy_db_schema::source::{
@@ -35,6 +35,8 @@ pub async fn delete_account(
 
   if data.delete_content {
     purge_user_account(local_user_view.person.id, &context).await?;
+  } else if data.anonymize_content.unwrap_or_default() {
+    anonymize_user_account(local_user_view.person.id, &context).await?;
   } else {
     OAuthAccount::delete_user_accounts(&mut context.pool(), local_user_view.local_user.id).await?;
     Person::delete_account(&mut context.pool(), local_user_view.person.id).await?;
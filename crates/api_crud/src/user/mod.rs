@@ -1,2 +1,4 @@
 pub mod create;
 pub mod delete;
+pub mod oauth_account;
+mod registration_throttle;
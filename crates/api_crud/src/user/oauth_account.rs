@@ -0,0 +1,83 @@
+use super::create::{fetch_oauth_user_info, read_user_info};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  oauth_provider::{LinkOAuthAccount, ListOAuthAccountsResponse, UnlinkOAuthAccount},
+  SuccessResponse,
+};
+use lemmy_db_schema::source::{
+  oauth_account::{OAuthAccount, OAuthAccountInsertForm},
+  webauthn_credential::WebauthnCredential,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+
+/// Links an account of an OAuth provider to the logged in user. Unlike linking on login, this
+/// doesn't depend on matching email addresses, as the user proves access to both accounts.
+#[tracing::instrument(skip(context))]
+pub async fn link_oauth_account(
+  data: Json<LinkOAuthAccount>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListOAuthAccountsResponse>> {
+  let (oauth_provider, user_info) = fetch_oauth_user_info(
+    &context,
+    data.oauth_provider_id,
+    &data.code,
+    &data.redirect_uri,
+  )
+  .await?;
+  let oauth_user_id = read_user_info(&user_info, oauth_provider.id_claim.as_str())?;
+
+  let local_user_id = local_user_view.local_user.id;
+  let form = OAuthAccountInsertForm::new(local_user_id, oauth_provider.id, oauth_user_id);
+  OAuthAccount::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::OauthAccountAlreadyLinked)?;
+
+  let oauth_accounts =
+    OAuthAccount::list_for_local_user(&mut context.pool(), local_user_id).await?;
+  Ok(Json(ListOAuthAccountsResponse { oauth_accounts }))
+}
+
+/// Unlinks an account of an OAuth provider, unless the user couldn't log in anymore without it.
+#[tracing::instrument(skip(context))]
+pub async fn unlink_oauth_account(
+  data: Json<UnlinkOAuthAccount>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let local_user_id = local_user_view.local_user.id;
+  let oauth_accounts =
+    OAuthAccount::list_for_local_user(&mut context.pool(), local_user_id).await?;
+  let passkeys =
+    WebauthnCredential::list_for_local_user(&mut context.pool(), local_user_id).await?;
+  let has_other_login = local_user_view.local_user.password_encrypted.is_some()
+    || !passkeys.is_empty()
+    || oauth_accounts
+      .iter()
+      .any(|a| a.oauth_provider_id != data.oauth_provider_id);
+  if !has_other_login {
+    Err(LemmyErrorType::CantUnlinkLastLoginMethod)?
+  }
+
+  let deleted =
+    OAuthAccount::delete(&mut context.pool(), local_user_id, data.oauth_provider_id).await?;
+  if deleted == 0 {
+    Err(LemmyErrorType::NotFound)?
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
+
+#[tracing::instrument(skip(context))]
+pub async fn list_oauth_accounts(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> LemmyResult<Json<ListOAuthAccountsResponse>> {
+  let oauth_accounts =
+    OAuthAccount::list_for_local_user(&mut context.pool(), local_user_view.local_user.id).await?;
+
+  Ok(Json(ListOAuthAccountsResponse { oauth_accounts }))
+}
@@ -0,0 +1,131 @@
+use actix_web::HttpRequest;
+use chrono::{TimeDelta, Utc};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  newtypes::PersonId,
+  source::{
+    local_site_rate_limit::LocalSiteRateLimit,
+    registration_source::{RegistrationSource, RegistrationSourceForm},
+  },
+};
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  rate_limit::get_ip,
+  settings::structs::Settings,
+};
+use maxminddb::{geoip2, Reader};
+use std::{
+  net::{IpAddr, Ipv4Addr, Ipv6Addr},
+  sync::OnceLock,
+};
+use tracing::warn;
+
+/// Opened on first use, so that a missing or broken database is only logged once.
+static ASN_DATABASE: OnceLock<Option<Reader<Vec<u8>>>> = OnceLock::new();
+
+/// The network from which a registration request is made.
+pub(super) struct RegistrationNetwork {
+  ip_subnet: String,
+  asn: Option<i32>,
+  asn_organization: Option<String>,
+}
+
+impl RegistrationNetwork {
+  pub(super) fn from_request(req: &HttpRequest, settings: &Settings) -> Self {
+    let ip = get_ip(&req.connection_info());
+    let asn = asn_database(settings).and_then(|reader| reader.lookup::<geoip2::Asn>(ip).ok());
+    RegistrationNetwork {
+      ip_subnet: ip_subnet(ip),
+      asn: asn
+        .as_ref()
+        .and_then(|a| a.autonomous_system_number)
+        .and_then(|n| i32::try_from(n).ok()),
+      asn_organization: asn
+        .and_then(|a| a.autonomous_system_organization)
+        .map(ToString::to_string),
+    }
+  }
+
+  /// Fails if too many accounts were recently registered from the same subnet or autonomous
+  /// system.
+  pub(super) async fn check_throttle(
+    &self,
+    rate_limit: &LocalSiteRateLimit,
+    context: &LemmyContext,
+  ) -> LemmyResult<()> {
+    if rate_limit.register_subnet > 0 {
+      let since = Utc::now() - TimeDelta::seconds(rate_limit.register_subnet_per_second.into());
+      let count =
+        RegistrationSource::count_for_subnet(&mut context.pool(), &self.ip_subnet, since).await?;
+      if count >= rate_limit.register_subnet.into() {
+        Err(LemmyErrorType::RegistrationThrottled)?
+      }
+    }
+    if let Some(asn) = self.asn.filter(|_| rate_limit.register_asn > 0) {
+      let since = Utc::now() - TimeDelta::seconds(rate_limit.register_asn_per_second.into());
+      let count = RegistrationSource::count_for_asn(&mut context.pool(), asn, since).await?;
+      if count >= rate_limit.register_asn.into() {
+        Err(LemmyErrorType::RegistrationThrottled)?
+      }
+    }
+    Ok(())
+  }
+
+  /// Stores the network of the new account, so that it counts towards the limits.
+  pub(super) async fn save(self, person_id: PersonId, context: &LemmyContext) -> LemmyResult<()> {
+    let form = RegistrationSourceForm {
+      asn: self.asn,
+      asn_organization: self.asn_organization,
+      ..RegistrationSourceForm::new(person_id, self.ip_subnet)
+    };
+    RegistrationSource::create(&mut context.pool(), &form).await?;
+    Ok(())
+  }
+}
+
+fn asn_database(settings: &Settings) -> Option<&'static Reader<Vec<u8>>> {
+  ASN_DATABASE
+    .get_or_init(|| {
+      let path = settings.maxmind_asn_database.as_ref()?;
+      Reader::open_readfile(path)
+        .inspect_err(|e| warn!("Failed to open MaxMind ASN database {path}: {e}"))
+        .ok()
+    })
+    .as_ref()
+}
+
+/// The /24 subnet for IPv4 and the /48 subnet for IPv6. Customers of the same hosting provider
+/// usually get addresses from a shared range, so limiting single addresses isn't enough.
+fn ip_subnet(ip: IpAddr) -> String {
+  match ip.to_canonical() {
+    IpAddr::V4(ip) => {
+      let [a, b, c, _] = ip.octets();
+      format!("{}/24", Ipv4Addr::new(a, b, c, 0))
+    }
+    IpAddr::V6(ip) => {
+      let [a, b, c, ..] = ip.segments();
+      format!("{}/48", Ipv6Addr::new(a, b, c, 0, 0, 0, 0, 0))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::ip_subnet;
+  use std::net::IpAddr;
+
+  #[test]
+  fn test_ip_subnet() -> Result<(), std::net::AddrParseError> {
+    assert_eq!("192.0.2.0/24", ip_subnet("192.0.2.77".parse::<IpAddr>()?));
+    assert_eq!(
+      "192.0.2.0/24",
+      ip_subnet("::ffff:192.0.2.77".parse::<IpAddr>()?)
+    );
+    assert_eq!(
+      "2001:db8:1::/48",
+      ip_subnet("2001:db8:1:2:3:4:5:6".parse::<IpAddr>()?)
+    );
+    Ok(())
+  }
+}
@@ -2,7 +2,7 @@ use super::to_and_audience;
 use crate::{
   activities::{
     block::{generate_cc, SiteOrCommunity},
-    community::send_activity_in_community,
+    community::{mirror::mirror_ban, send_activity_in_community},
     generate_activity_id,
     send_lemmy_activity,
     verify_is_public,
@@ -189,6 +189,8 @@ impl ActivityHandler for BlockUser {
           community_id: community.id,
           person_id: blocked_person.id,
           expires: Some(expires),
+          reason: Some(reason.clone()),
+          appeal: Some(None),
         };
         CommunityPersonBan::ban(&mut context.pool(), &community_user_ban_form).await?;
 
@@ -220,6 +222,16 @@ impl ActivityHandler for BlockUser {
           expires,
         };
         ModBanFromCommunity::create(&mut context.pool(), &form).await?;
+
+        mirror_ban(
+          &community,
+          &mod_person,
+          &blocked_person,
+          &form.reason,
+          expires,
+          context,
+        )
+        .await?;
       }
     }
 
@@ -138,6 +138,8 @@ impl ActivityHandler for UndoBlockUser {
           community_id: community.id,
           person_id: blocked_person.id,
           expires: None,
+          reason: None,
+          appeal: None,
         };
         CommunityPersonBan::unban(&mut context.pool(), &community_user_ban_form).await?;
 
@@ -3,13 +3,15 @@ use crate::{
     community::send_activity_in_community,
     generate_activity_id,
     generate_to,
+    mod_action_actor,
     verify_mod_action,
     verify_person_in_community,
     verify_visibility,
   },
   activity_lists::AnnouncableActivities,
+  fetcher::post_or_comment::PostOrComment,
   insert_received_activity,
-  objects::{community::ApubCommunity, person::ApubPerson, post::ApubPost},
+  objects::{community::ApubCommunity, person::ApubPerson},
   protocol::{
     activities::community::{collection_add::CollectionAdd, collection_remove::CollectionRemove},
     InCommunity,
@@ -30,6 +32,7 @@ use lemmy_db_schema::{
   newtypes::{CommunityId, PersonId},
   source::{
     activity::ActivitySendTargets,
+    comment::{Comment, CommentUpdateForm},
     community::{Community, CommunityModerator, CommunityModeratorForm},
     moderator::{ModAddCommunity, ModAddCommunityForm},
     person::Person,
@@ -37,7 +40,7 @@ use lemmy_db_schema::{
   },
   traits::{Crud, Joinable},
 };
-use lemmy_utils::error::{LemmyError, LemmyResult};
+use lemmy_utils::error::{FederationError, LemmyError, LemmyResult};
 use url::Url;
 
 impl CollectionAdd {
@@ -68,10 +71,13 @@ impl CollectionAdd {
     send_activity_in_community(activity, actor, community, inboxes, true, context).await
   }
 
-  pub async fn send_add_featured_post(
+  /// Adds a post or a pinned comment to the featured collection. The `activity_actor` differs
+  /// from `actor` for actions of community bots, which are sent in the name of the community.
+  pub async fn send_add_featured(
     community: &ApubCommunity,
-    featured_post: &ApubPost,
+    featured_id: Url,
     actor: &ApubPerson,
+    activity_actor: ObjectId<ApubPerson>,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<()> {
     let id = generate_activity_id(
@@ -79,9 +85,9 @@ impl CollectionAdd {
       &context.settings().get_protocol_and_hostname(),
     )?;
     let add = CollectionAdd {
-      actor: actor.id().into(),
+      actor: activity_actor,
       to: vec![generate_to(community)?],
-      object: featured_post.ap_id.clone().into(),
+      object: featured_id,
       target: generate_featured_url(&community.actor_id)?.into(),
       cc: vec![community.id()],
       kind: AddType::Add,
@@ -119,7 +125,7 @@ impl ActivityHandler for CollectionAdd {
     let community = self.community(context).await?;
     verify_visibility(&self.to, &self.cc, &community)?;
     verify_person_in_community(&self.actor, &community, context).await?;
-    verify_mod_action(&self.actor, &community, context).await?;
+    verify_featured_action(&self.actor, &self.object, &self.target, &community, context).await?;
     Ok(())
   }
 
@@ -160,14 +166,7 @@ impl ActivityHandler for CollectionAdd {
         // TODO: send websocket notification about added mod
       }
       CollectionType::Featured => {
-        let post = ObjectId::<ApubPost>::from(self.object)
-          .dereference(context)
-          .await?;
-        let form = PostUpdateForm {
-          featured_community: Some(true),
-          ..Default::default()
-        };
-        Post::update(&mut context.pool(), post.id, &form).await?;
+        receive_featured(self.object, &community, true, context).await?;
       }
     }
     Ok(())
@@ -199,16 +198,119 @@ pub(crate) async fn send_feature_post(
   post: Post,
   actor: Person,
   featured: bool,
+  automod: bool,
   context: Data<LemmyContext>,
 ) -> LemmyResult<()> {
+  let community: ApubCommunity = Community::read(&mut context.pool(), post.community_id)
+    .await?
+    .into();
+  let activity_actor = mod_action_actor(&actor, &community, automod);
   let actor: ApubPerson = actor.into();
-  let post: ApubPost = post.into();
+  let featured_id: Url = post.ap_id.into();
+  if featured {
+    CollectionAdd::send_add_featured(&community, featured_id, &actor, activity_actor, &context)
+      .await
+  } else {
+    CollectionRemove::send_remove_featured(
+      &community,
+      featured_id,
+      &actor,
+      activity_actor,
+      &context,
+    )
+    .await
+  }
+}
+
+/// Pinned comments are federated like featured posts, by adding them to the featured collection
+/// of the community.
+pub(crate) async fn send_pin_comment(
+  comment: Comment,
+  actor: Person,
+  pinned: bool,
+  context: Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let actor: ApubPerson = actor.into();
+  let post = Post::read(&mut context.pool(), comment.post_id).await?;
   let community = Community::read(&mut context.pool(), post.community_id)
     .await?
     .into();
-  if featured {
-    CollectionAdd::send_add_featured_post(&community, &post, &actor, &context).await
+  let featured_id: Url = comment.ap_id.into();
+  let activity_actor = actor.id().into();
+  if pinned {
+    CollectionAdd::send_add_featured(&community, featured_id, &actor, activity_actor, &context)
+      .await
   } else {
-    CollectionRemove::send_remove_featured_post(&community, &post, &actor, &context).await
+    CollectionRemove::send_remove_featured(
+      &community,
+      featured_id,
+      &actor,
+      activity_actor,
+      &context,
+    )
+    .await
+  }
+}
+
+/// Verifies that the actor may add the object to or remove it from the collection. This is
+/// allowed for mods, and for post creators who pin a comment in their own post.
+pub(crate) async fn verify_featured_action(
+  actor: &ObjectId<ApubPerson>,
+  object: &Url,
+  target: &Url,
+  community: &Community,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let Err(e) = verify_mod_action(actor, community, context).await else {
+    return Ok(());
+  };
+  let (_, collection_type) =
+    Community::get_by_collection_url(&mut context.pool(), &target.clone().into()).await?;
+  if matches!(collection_type, CollectionType::Featured) {
+    let object = ObjectId::<PostOrComment>::from(object.clone())
+      .dereference(context)
+      .await?;
+    if let PostOrComment::Comment(comment) = object {
+      let post = Post::read(&mut context.pool(), comment.post_id).await?;
+      let actor = actor.dereference(context).await?;
+      if post.creator_id == actor.id {
+        return Ok(());
+      }
+    }
+  }
+  Err(e)
+}
+
+/// Features the post or pins the comment which was added to or removed from the featured
+/// collection.
+pub(crate) async fn receive_featured(
+  object: Url,
+  community: &Community,
+  featured: bool,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  match ObjectId::<PostOrComment>::from(object)
+    .dereference(context)
+    .await?
+  {
+    PostOrComment::Post(post) => {
+      let form = PostUpdateForm {
+        featured_community: Some(featured),
+        ..Default::default()
+      };
+      Post::update(&mut context.pool(), post.id, &form).await?;
+    }
+    PostOrComment::Comment(comment) => {
+      let post = Post::read(&mut context.pool(), comment.post_id).await?;
+      if post.community_id != community.id {
+        Err(FederationError::InvalidCommunity)?
+      }
+      let form = CommentUpdateForm {
+        distinguished_pinned: Some(featured),
+        ..Default::default()
+      };
+      Comment::update(&mut context.pool(), comment.id, &form).await?;
+    }
   }
+  Ok(())
 }
@@ -1,15 +1,17 @@
 use crate::{
   activities::{
-    community::send_activity_in_community,
+    community::{
+      collection_add::{receive_featured, verify_featured_action},
+      send_activity_in_community,
+    },
     generate_activity_id,
     generate_to,
-    verify_mod_action,
     verify_person_in_community,
     verify_visibility,
   },
   activity_lists::AnnouncableActivities,
   insert_received_activity,
-  objects::{community::ApubCommunity, person::ApubPerson, post::ApubPost},
+  objects::{community::ApubCommunity, person::ApubPerson},
   protocol::{activities::community::collection_remove::CollectionRemove, InCommunity},
 };
 use activitypub_federation::{
@@ -28,7 +30,6 @@ use lemmy_db_schema::{
     activity::ActivitySendTargets,
     community::{Community, CommunityModerator, CommunityModeratorForm},
     moderator::{ModAddCommunity, ModAddCommunityForm},
-    post::{Post, PostUpdateForm},
   },
   traits::{Crud, Joinable},
 };
@@ -63,10 +64,13 @@ impl CollectionRemove {
     send_activity_in_community(activity, actor, community, inboxes, true, context).await
   }
 
-  pub async fn send_remove_featured_post(
+  /// Removes a post or a pinned comment from the featured collection. See
+  /// [CollectionAdd::send_add_featured] for `activity_actor`.
+  pub async fn send_remove_featured(
     community: &ApubCommunity,
-    featured_post: &ApubPost,
+    featured_id: Url,
     actor: &ApubPerson,
+    activity_actor: ObjectId<ApubPerson>,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<()> {
     let id = generate_activity_id(
@@ -74,9 +78,9 @@ impl CollectionRemove {
       &context.settings().get_protocol_and_hostname(),
     )?;
     let remove = CollectionRemove {
-      actor: actor.id().into(),
+      actor: activity_actor,
       to: vec![generate_to(community)?],
-      object: featured_post.ap_id.clone().into(),
+      object: featured_id,
       target: generate_featured_url(&community.actor_id)?.into(),
       cc: vec![community.id()],
       kind: RemoveType::Remove,
@@ -114,7 +118,7 @@ impl ActivityHandler for CollectionRemove {
     let community = self.community(context).await?;
     verify_visibility(&self.to, &self.cc, &community)?;
     verify_person_in_community(&self.actor, &community, context).await?;
-    verify_mod_action(&self.actor, &community, context).await?;
+    verify_featured_action(&self.actor, &self.object, &self.target, &community, context).await?;
     Ok(())
   }
 
@@ -148,14 +152,7 @@ impl ActivityHandler for CollectionRemove {
         // TODO: send websocket notification about removed mod
       }
       CollectionType::Featured => {
-        let post = ObjectId::<ApubPost>::from(self.object)
-          .dereference(context)
-          .await?;
-        let form = PostUpdateForm {
-          featured_community: Some(false),
-          ..Default::default()
-        };
-        Post::update(&mut context.pool(), post.id, &form).await?;
+        receive_featured(self.object, &community, false, context).await?;
       }
     }
     Ok(())
@@ -4,6 +4,8 @@ use crate::{
     community::send_activity_in_community,
     generate_activity_id,
     generate_to,
+    mod_action_actor,
+    mod_log_actor,
     verify_mod_action,
     verify_person_in_community,
     verify_visibility,
@@ -68,10 +70,12 @@ impl ActivityHandler for LockPage {
     let post = self.object.dereference(context).await?;
     Post::update(&mut context.pool(), post.id, &form).await?;
 
+    let (actor, automod) = mod_log_actor(&self.actor, context).await?;
     let form = ModLockPostForm {
-      mod_person_id: self.actor.dereference(context).await?.id,
+      mod_person_id: actor.id,
       post_id: post.id,
       locked,
+      automod,
     };
     ModLockPost::create(&mut context.pool(), &form).await?;
 
@@ -106,15 +110,18 @@ impl ActivityHandler for UndoLockPage {
     let locked = Some(false);
     let form = PostUpdateForm {
       locked,
+      lock_expires: Some(None),
       ..Default::default()
     };
     let post = self.object.object.dereference(context).await?;
     Post::update(&mut context.pool(), post.id, &form).await?;
 
+    let (actor, automod) = mod_log_actor(&self.actor, context).await?;
     let form = ModLockPostForm {
-      mod_person_id: self.actor.dereference(context).await?.id,
+      mod_person_id: actor.id,
       post_id: post.id,
       locked,
+      automod,
     };
     ModLockPost::create(&mut context.pool(), &form).await?;
 
@@ -126,6 +133,7 @@ pub(crate) async fn send_lock_post(
   post: Post,
   actor: Person,
   locked: bool,
+  automod: bool,
   context: Data<LemmyContext>,
 ) -> LemmyResult<()> {
   let community: ApubCommunity = Community::read(&mut context.pool(), post.community_id)
@@ -137,7 +145,7 @@ pub(crate) async fn send_lock_post(
   )?;
   let community_id = community.actor_id.inner().clone();
   let lock = LockPage {
-    actor: actor.actor_id.clone().into(),
+    actor: mod_action_actor(&actor, &community, automod),
     to: vec![generate_to(&community)?],
     object: ObjectId::from(post.ap_id),
     cc: vec![community_id.clone()],
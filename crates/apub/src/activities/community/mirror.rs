@@ -0,0 +1,142 @@
+use chrono::{DateTime, Utc};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  source::{
+    community::{
+      Community,
+      CommunityFollower,
+      CommunityFollowerForm,
+      CommunityPersonBan,
+      CommunityPersonBanForm,
+    },
+    community_mirror::{CommunityMirror, CommunityMirrorAction, CommunityMirrorActionForm},
+    moderator::{ModBanFromCommunity, ModBanFromCommunityForm, ModRemovePost, ModRemovePostForm},
+    person::Person,
+    post::{Post, PostUpdateForm},
+    post_report::PostReport,
+  },
+  traits::{Bannable, Crud, Followable, Reportable},
+};
+use lemmy_db_views_actor::structs::CommunityView;
+use lemmy_utils::error::LemmyResult;
+
+/// Applies a ban from a remote community to the local communities which mirror it. The ban is
+/// only applied locally, and moderators or admins of the local community are never banned.
+/// Unbans aren't mirrored, those are left to the local moderators.
+pub(crate) async fn mirror_ban(
+  partner: &Community,
+  mod_person: &Person,
+  banned_person: &Person,
+  reason: &Option<String>,
+  expires: Option<DateTime<Utc>>,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  if partner.local {
+    return Ok(());
+  }
+  let mirrors = CommunityMirror::list_enabled_for_partner(&mut context.pool(), partner.id).await?;
+  for mirror in mirrors.into_iter().filter(|m| m.mirror_bans) {
+    if CommunityView::check_is_mod_or_admin(
+      &mut context.pool(),
+      banned_person.id,
+      mirror.community_id,
+    )
+    .await
+    .is_ok()
+    {
+      continue;
+    }
+
+    let community_user_ban_form = CommunityPersonBanForm {
+      community_id: mirror.community_id,
+      person_id: banned_person.id,
+      expires: Some(expires),
+      reason: Some(reason.clone()),
+      appeal: Some(None),
+    };
+    CommunityPersonBan::ban(&mut context.pool(), &community_user_ban_form).await?;
+
+    // Also unsubscribe them from the community, if they are subscribed
+    let community_follower_form = CommunityFollowerForm::new(mirror.community_id, banned_person.id);
+    CommunityFollower::unfollow(&mut context.pool(), &community_follower_form)
+      .await
+      .ok();
+
+    let form = ModBanFromCommunityForm {
+      mod_person_id: mod_person.id,
+      other_person_id: banned_person.id,
+      community_id: mirror.community_id,
+      reason: reason.clone(),
+      banned: Some(true),
+      expires,
+    };
+    ModBanFromCommunity::create(&mut context.pool(), &form).await?;
+
+    let form = CommunityMirrorActionForm {
+      mirror_id: mirror.id,
+      mod_person_id: mod_person.id,
+      other_person_id: Some(banned_person.id),
+      post_id: None,
+      reason: reason.clone(),
+    };
+    CommunityMirrorAction::create(&mut context.pool(), &form).await?;
+  }
+  Ok(())
+}
+
+/// Applies the removal of a post in a remote community to cross-posts of the same link by the same
+/// creator, in the local communities which mirror it.
+pub(crate) async fn mirror_post_removal(
+  mod_person: &Person,
+  post: &Post,
+  reason: &Option<String>,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  let Some(url) = &post.url else {
+    return Ok(());
+  };
+  let partner = Community::read(&mut context.pool(), post.community_id).await?;
+  if partner.local {
+    return Ok(());
+  }
+  let mirrors = CommunityMirror::list_enabled_for_partner(&mut context.pool(), partner.id).await?;
+  for mirror in mirrors.into_iter().filter(|m| m.mirror_removals) {
+    let crossposts = Post::list_crossposts_in_community(
+      &mut context.pool(),
+      post.creator_id,
+      mirror.community_id,
+      url,
+    )
+    .await?;
+    for crosspost in crossposts {
+      PostReport::resolve_all_for_object(&mut context.pool(), crosspost.id, mod_person.id).await?;
+      let form = ModRemovePostForm {
+        mod_person_id: mod_person.id,
+        post_id: crosspost.id,
+        removed: Some(true),
+        reason: reason.clone(),
+        automod: false,
+      };
+      ModRemovePost::create(&mut context.pool(), &form).await?;
+      Post::update(
+        &mut context.pool(),
+        crosspost.id,
+        &PostUpdateForm {
+          removed: Some(true),
+          ..Default::default()
+        },
+      )
+      .await?;
+
+      let form = CommunityMirrorActionForm {
+        mirror_id: mirror.id,
+        mod_person_id: mod_person.id,
+        other_person_id: None,
+        post_id: Some(crosspost.id),
+        reason: reason.clone(),
+      };
+      CommunityMirrorAction::create(&mut context.pool(), &form).await?;
+    }
+  }
+  Ok(())
+}
@@ -16,6 +16,7 @@ pub mod announce;
 pub mod collection_add;
 pub mod collection_remove;
 pub mod lock_page;
+pub mod mirror;
 pub mod report;
 pub mod update;
 
@@ -46,6 +47,11 @@ pub(crate) async fn send_activity_in_community(
     return Ok(());
   }
 
+  // Actions of shadow banned persons stay on this instance
+  if actor.shadow_banned {
+    return Ok(());
+  }
+
   // send to any users which are mentioned or affected directly
   let mut inboxes = extra_inboxes;
 
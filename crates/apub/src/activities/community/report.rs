@@ -127,6 +127,8 @@ impl ActivityHandler for Report {
           comment_id: comment.id,
           original_comment_text: comment.content.clone(),
           reason,
+          severity: None,
+          category_id: None,
         };
         CommentReport::report(&mut context.pool(), &report_form).await?;
       }
@@ -159,7 +159,8 @@ impl ActivityHandler for CreateOrUpdateNote {
     CommentLike::like(&mut context.pool(), &like_form).await?;
 
     // Calculate initial hot_rank
-    CommentAggregates::update_hot_rank(&mut context.pool(), comment.id).await?;
+    CommentAggregates::update_hot_rank(&mut context.pool(), comment.id, context.clock().now())
+      .await?;
 
     let do_send_email = self.kind == CreateOrUpdateType::Create;
     let actor = self.actor.dereference(context).await?;
@@ -1,7 +1,10 @@
 use crate::{
   activities::{generate_activity_id, send_lemmy_activity, verify_person},
   insert_received_activity,
-  objects::{person::ApubPerson, private_message::ApubPrivateMessage},
+  objects::{
+    person::ApubPerson,
+    private_message::{pm_recipients, ApubPrivateMessage},
+  },
   protocol::activities::{
     create_or_update::chat_message::CreateOrUpdateChatMessage,
     CreateOrUpdateType,
@@ -12,6 +15,7 @@ use activitypub_federation::{
   protocol::verification::{verify_domains_match, verify_urls_match},
   traits::{ActivityHandler, Actor, Object},
 };
+use anyhow::anyhow;
 use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::source::activity::ActivitySendTargets;
 use lemmy_db_views::structs::PrivateMessageView;
@@ -23,8 +27,13 @@ pub(crate) async fn send_create_or_update_pm(
   kind: CreateOrUpdateType,
   context: Data<LemmyContext>,
 ) -> LemmyResult<()> {
+  // Messages of shadow banned persons stay on this instance
+  if pm_view.creator.shadow_banned {
+    return Ok(());
+  }
   let actor: ApubPerson = pm_view.creator.into();
-  let recipient: ApubPerson = pm_view.recipient.into();
+  let (recipients, _) = pm_recipients(&pm_view.private_message, &context).await?;
+  let recipients: Vec<ApubPerson> = recipients.into_iter().map(Into::into).collect();
 
   let id = generate_activity_id(
     kind.clone(),
@@ -33,14 +42,15 @@ pub(crate) async fn send_create_or_update_pm(
   let create_or_update = CreateOrUpdateChatMessage {
     id: id.clone(),
     actor: actor.id().into(),
-    to: [recipient.id().into()],
+    to: recipients.iter().map(|r| r.id().into()).collect(),
     object: ApubPrivateMessage(pm_view.private_message.clone())
       .into_json(&context)
       .await?,
     kind,
   };
-  let inbox = ActivitySendTargets::to_inbox(recipient.shared_inbox_or_inbox());
-  send_lemmy_activity(&context, create_or_update, &actor, inbox, true).await
+  let mut inboxes = ActivitySendTargets::empty();
+  inboxes.add_inboxes(recipients.iter().map(ApubPerson::shared_inbox_or_inbox));
+  send_lemmy_activity(&context, create_or_update, &actor, inboxes, true).await
 }
 
 #[async_trait::async_trait]
@@ -60,7 +70,12 @@ impl ActivityHandler for CreateOrUpdateChatMessage {
   async fn verify(&self, context: &Data<Self::DataType>) -> LemmyResult<()> {
     verify_person(&self.actor, context).await?;
     verify_domains_match(self.actor.inner(), self.object.id.inner())?;
-    verify_domains_match(self.to[0].inner(), self.object.to[0].inner())?;
+    if self.to.len() != self.object.to.len() {
+      Err(anyhow!("Activity and message have different recipients"))?
+    }
+    for (activity_to, object_to) in self.to.iter().zip(&self.object.to) {
+      verify_domains_match(activity_to.inner(), object_to.inner())?;
+    }
     verify_urls_match(self.actor.inner(), self.object.attributed_to.inner())?;
     ApubPrivateMessage::verify(&self.object, self.actor.inner(), context).await?;
     Ok(())
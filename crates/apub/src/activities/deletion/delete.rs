@@ -1,7 +1,9 @@
 use crate::{
   activities::{
+    community::mirror::mirror_post_removal,
     deletion::{receive_delete_action, verify_delete_activity, DeletableObjects},
     generate_activity_id,
+    mod_log_actor,
   },
   insert_received_activity,
   objects::person::ApubPerson,
@@ -60,13 +62,8 @@ impl ActivityHandler for Delete {
       } else {
         Some(reason)
       };
-      receive_remove_action(
-        &self.actor.dereference(context).await?,
-        self.object.id(),
-        reason,
-        context,
-      )
-      .await
+      let (actor, automod) = mod_log_actor(&self.actor, context).await?;
+      receive_remove_action(&actor, automod, self.object.id(), reason, context).await
     } else {
       receive_delete_action(
         self.object.id(),
@@ -111,6 +108,7 @@ impl Delete {
 #[tracing::instrument(skip_all)]
 pub(in crate::activities) async fn receive_remove_action(
   actor: &ApubPerson,
+  automod: bool,
   object: &Url,
   reason: Option<String>,
   context: &Data<LemmyContext>,
@@ -144,6 +142,7 @@ pub(in crate::activities) async fn receive_remove_action(
         post_id: post.id,
         removed: Some(true),
         reason,
+        automod,
       };
       ModRemovePost::create(&mut context.pool(), &form).await?;
       Post::update(
@@ -155,6 +154,7 @@ pub(in crate::activities) async fn receive_remove_action(
         },
       )
       .await?;
+      mirror_post_removal(actor, &post, &form.reason, context).await?;
     }
     DeletableObjects::Comment(comment) => {
       CommentReport::resolve_all_for_object(&mut context.pool(), comment.id, actor.id).await?;
@@ -163,6 +163,7 @@ pub(in crate::activities) async fn receive_remove_action(
         comment_id: comment.id,
         removed: Some(true),
         reason,
+        automod,
       };
       ModRemoveComment::create(&mut context.pool(), &form).await?;
       Comment::update(
@@ -2,6 +2,7 @@ use super::{generate_to, verify_is_public};
 use crate::{
   activities::{
     community::send_activity_in_community,
+    mod_action_actor,
     send_lemmy_activity,
     verify_mod_action,
     verify_person,
@@ -14,7 +15,7 @@ use crate::{
     community::ApubCommunity,
     person::ApubPerson,
     post::ApubPost,
-    private_message::ApubPrivateMessage,
+    private_message::{pm_recipients, ApubPrivateMessage},
   },
   protocol::{
     activities::deletion::{delete::Delete, undo_delete::UndoDelete},
@@ -48,7 +49,8 @@ pub mod delete;
 pub mod undo_delete;
 
 /// Parameter `reason` being set indicates that this is a removal by a mod. If its unset, this
-/// action was done by a normal user.
+/// action was done by a normal user. With `automod` the removal is sent in the name of the
+/// community.
 #[tracing::instrument(skip_all)]
 pub(crate) async fn send_apub_delete_in_community(
   actor: Person,
@@ -56,16 +58,21 @@ pub(crate) async fn send_apub_delete_in_community(
   object: DeletableObjects,
   reason: Option<String>,
   deleted: bool,
+  automod: bool,
   context: &Data<LemmyContext>,
 ) -> LemmyResult<()> {
+  let activity_actor = mod_action_actor(&actor, &community, automod);
   let actor = ApubPerson::from(actor);
   let is_mod_action = reason.is_some();
   let to = vec![generate_to(&community)?];
   let activity = if deleted {
-    let delete = Delete::new(&actor, object, to, Some(&community), reason, context)?;
+    let mut delete = Delete::new(&actor, object, to, Some(&community), reason, context)?;
+    delete.actor = activity_actor;
     AnnouncableActivities::Delete(delete)
   } else {
-    let undo = UndoDelete::new(&actor, object, to, Some(&community), reason, context)?;
+    let mut undo = UndoDelete::new(&actor, object, to, Some(&community), reason, context)?;
+    undo.object.actor = activity_actor.clone();
+    undo.actor = activity_actor;
     AnnouncableActivities::UndoDelete(undo)
   };
   send_activity_in_community(
@@ -86,19 +93,19 @@ pub(crate) async fn send_apub_delete_private_message(
   deleted: bool,
   context: Data<LemmyContext>,
 ) -> LemmyResult<()> {
-  let recipient_id = pm.recipient_id;
-  let recipient: ApubPerson = Person::read(&mut context.pool(), recipient_id)
-    .await?
-    .into();
+  let (recipients, _) = pm_recipients(&pm, &context).await?;
+  let recipients: Vec<ApubPerson> = recipients.into_iter().map(Into::into).collect();
+  let to: Vec<_> = recipients.iter().map(ApubPerson::id).collect();
 
   let deletable = DeletableObjects::PrivateMessage(pm.into());
-  let inbox = ActivitySendTargets::to_inbox(recipient.shared_inbox_or_inbox());
+  let mut inboxes = ActivitySendTargets::empty();
+  inboxes.add_inboxes(recipients.iter().map(ApubPerson::shared_inbox_or_inbox));
   if deleted {
-    let delete: Delete = Delete::new(actor, deletable, vec![recipient.id()], None, None, &context)?;
-    send_lemmy_activity(&context, delete, actor, inbox, true).await?;
+    let delete: Delete = Delete::new(actor, deletable, to, None, None, &context)?;
+    send_lemmy_activity(&context, delete, actor, inboxes, true).await?;
   } else {
-    let undo = UndoDelete::new(actor, deletable, vec![recipient.id()], None, None, &context)?;
-    send_lemmy_activity(&context, undo, actor, inbox, true).await?;
+    let undo = UndoDelete::new(actor, deletable, to, None, None, &context)?;
+    send_lemmy_activity(&context, undo, actor, inboxes, true).await?;
   };
   Ok(())
 }
@@ -251,7 +258,7 @@ async fn receive_delete_action(
         let mod_: Person = actor.dereference(context).await?.deref().clone();
         let object = DeletableObjects::Community(community.clone());
         let c: Community = community.deref().clone();
-        send_apub_delete_in_community(mod_, c, object, None, true, context).await?;
+        send_apub_delete_in_community(mod_, c, object, None, true, false, context).await?;
       }
 
       Community::update(
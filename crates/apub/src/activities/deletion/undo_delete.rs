@@ -2,6 +2,7 @@ use crate::{
   activities::{
     deletion::{receive_delete_action, verify_delete_activity, DeletableObjects},
     generate_activity_id,
+    mod_log_actor,
   },
   insert_received_activity,
   objects::person::ApubPerson,
@@ -51,12 +52,9 @@ impl ActivityHandler for UndoDelete {
   async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
     insert_received_activity(&self.id, context).await?;
     if self.object.summary.is_some() {
-      UndoDelete::receive_undo_remove_action(
-        &self.actor.dereference(context).await?,
-        self.object.object.id(),
-        context,
-      )
-      .await
+      let (actor, automod) = mod_log_actor(&self.actor, context).await?;
+      UndoDelete::receive_undo_remove_action(&actor, automod, self.object.object.id(), context)
+        .await
     } else {
       receive_delete_action(self.object.object.id(), &self.actor, false, None, context).await
     }
@@ -94,6 +92,7 @@ impl UndoDelete {
   #[tracing::instrument(skip_all)]
   pub(in crate::activities) async fn receive_undo_remove_action(
     actor: &ApubPerson,
+    automod: bool,
     object: &Url,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<()> {
@@ -125,6 +124,7 @@ impl UndoDelete {
           post_id: post.id,
           removed: Some(false),
           reason: None,
+          automod,
         };
         ModRemovePost::create(&mut context.pool(), &form).await?;
         Post::update(
@@ -143,6 +143,7 @@ impl UndoDelete {
           comment_id: comment.id,
           removed: Some(false),
           reason: None,
+          automod,
         };
         ModRemoveComment::create(&mut context.pool(), &form).await?;
         Comment::update(
@@ -1,5 +1,6 @@
 use crate::{
   activities::{
+    following::follow_inbox,
     generate_activity_id,
     send_lemmy_activity,
     verify_person,
@@ -7,7 +8,7 @@ use crate::{
   },
   fetcher::user_or_community::UserOrCommunity,
   insert_received_activity,
-  objects::{community::ApubCommunity, person::ApubPerson},
+  objects::person::ApubPerson,
   protocol::activities::following::{accept::AcceptFollow, follow::Follow},
 };
 use activitypub_federation::{
@@ -19,7 +20,6 @@ use activitypub_federation::{
 use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::{
   source::{
-    activity::ActivitySendTargets,
     community::{CommunityFollower, CommunityFollowerForm, CommunityFollowerState},
     person::{PersonFollower, PersonFollowerForm},
   },
@@ -32,13 +32,13 @@ use url::Url;
 impl Follow {
   pub(in crate::activities::following) fn new(
     actor: &ApubPerson,
-    community: &ApubCommunity,
+    target: &UserOrCommunity,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<Follow> {
     Ok(Follow {
       actor: actor.id().into(),
-      object: community.id().into(),
-      to: Some([community.id().into()]),
+      object: target.id().into(),
+      to: Some([target.id().into()]),
       kind: FollowType::Follow,
       id: generate_activity_id(
         FollowType::Follow,
@@ -50,15 +50,11 @@ impl Follow {
   #[tracing::instrument(skip_all)]
   pub async fn send(
     actor: &ApubPerson,
-    community: &ApubCommunity,
+    target: &UserOrCommunity,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<()> {
-    let follow = Follow::new(actor, community, context)?;
-    let inbox = if community.local {
-      ActivitySendTargets::empty()
-    } else {
-      ActivitySendTargets::to_inbox(community.shared_inbox_or_inbox())
-    };
+    let follow = Follow::new(actor, target, context)?;
+    let inbox = follow_inbox(target);
     send_lemmy_activity(context, follow, actor, inbox, true).await
   }
 }
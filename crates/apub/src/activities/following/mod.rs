@@ -1,5 +1,6 @@
 use super::generate_activity_id;
 use crate::{
+  fetcher::user_or_community::UserOrCommunity,
   objects::{community::ApubCommunity, person::ApubPerson},
   protocol::activities::following::{
     accept::AcceptFollow,
@@ -8,11 +9,11 @@ use crate::{
     undo_follow::UndoFollow,
   },
 };
-use activitypub_federation::{config::Data, kinds::activity::FollowType};
+use activitypub_federation::{config::Data, kinds::activity::FollowType, traits::Actor};
 use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::{
   newtypes::{CommunityId, PersonId},
-  source::{community::Community, person::Person},
+  source::{activity::ActivitySendTargets, community::Community, person::Person},
   traits::Crud,
 };
 use lemmy_utils::error::LemmyResult;
@@ -28,7 +29,7 @@ pub async fn send_follow_community(
   follow: bool,
   context: &Data<LemmyContext>,
 ) -> LemmyResult<()> {
-  let community: ApubCommunity = community.into();
+  let community = UserOrCommunity::Community(ApubCommunity::from(community));
   let actor: ApubPerson = person.into();
   if follow {
     Follow::send(&actor, &community, context).await
@@ -37,6 +38,35 @@ pub async fn send_follow_community(
   }
 }
 
+pub async fn send_follow_person(
+  target: Person,
+  person: Person,
+  follow: bool,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let target = UserOrCommunity::User(ApubPerson::from(target));
+  let actor: ApubPerson = person.into();
+  if follow {
+    Follow::send(&actor, &target, context).await
+  } else {
+    UndoFollow::send(&actor, &target, context).await
+  }
+}
+
+/// Follows of local persons and communities are handled directly, so they are only sent to
+/// remote inboxes.
+fn follow_inbox(target: &UserOrCommunity) -> ActivitySendTargets {
+  let local = match target {
+    UserOrCommunity::User(u) => u.local,
+    UserOrCommunity::Community(c) => c.local,
+  };
+  if local {
+    ActivitySendTargets::empty()
+  } else {
+    ActivitySendTargets::to_inbox(target.shared_inbox_or_inbox())
+  }
+}
+
 pub async fn send_accept_or_reject_follow(
   community_id: CommunityId,
   person_id: PersonId,
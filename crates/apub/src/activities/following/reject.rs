@@ -1,5 +1,6 @@
 use crate::{
   activities::{generate_activity_id, send_lemmy_activity},
+  fetcher::user_or_community::UserOrCommunity,
   insert_received_activity,
   protocol::activities::following::{follow::Follow, reject::RejectFollow},
 };
@@ -14,6 +15,7 @@ use lemmy_db_schema::{
   source::{
     activity::ActivitySendTargets,
     community::{CommunityFollower, CommunityFollowerForm},
+    person::{PersonFollower, PersonFollowerForm},
   },
   traits::Followable,
 };
@@ -67,12 +69,24 @@ impl ActivityHandler for RejectFollow {
   #[tracing::instrument(skip_all)]
   async fn receive(self, context: &Data<LemmyContext>) -> LemmyResult<()> {
     insert_received_activity(&self.id, context).await?;
-    let community = self.actor.dereference(context).await?;
+    let actor = self.actor.dereference(context).await?;
     let person = self.object.actor.dereference(context).await?;
 
     // remove the follow
-    let form = CommunityFollowerForm::new(community.id, person.id);
-    CommunityFollower::unfollow(&mut context.pool(), &form).await?;
+    match actor {
+      UserOrCommunity::User(u) => {
+        let form = PersonFollowerForm {
+          person_id: u.id,
+          follower_id: person.id,
+          pending: false,
+        };
+        PersonFollower::unfollow(&mut context.pool(), &form).await?;
+      }
+      UserOrCommunity::Community(c) => {
+        let form = CommunityFollowerForm::new(c.id, person.id);
+        CommunityFollower::unfollow(&mut context.pool(), &form).await?;
+      }
+    }
 
     Ok(())
   }
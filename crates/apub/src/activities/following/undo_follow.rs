@@ -1,8 +1,8 @@
 use crate::{
-  activities::{generate_activity_id, send_lemmy_activity, verify_person},
+  activities::{following::follow_inbox, generate_activity_id, send_lemmy_activity, verify_person},
   fetcher::user_or_community::UserOrCommunity,
   insert_received_activity,
-  objects::{community::ApubCommunity, person::ApubPerson},
+  objects::person::ApubPerson,
   protocol::activities::following::{follow::Follow, undo_follow::UndoFollow},
 };
 use activitypub_federation::{
@@ -14,7 +14,6 @@ use activitypub_federation::{
 use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::{
   source::{
-    activity::ActivitySendTargets,
     community::{CommunityFollower, CommunityFollowerForm},
     person::{PersonFollower, PersonFollowerForm},
   },
@@ -27,13 +26,13 @@ impl UndoFollow {
   #[tracing::instrument(skip_all)]
   pub async fn send(
     actor: &ApubPerson,
-    community: &ApubCommunity,
+    target: &UserOrCommunity,
     context: &Data<LemmyContext>,
   ) -> LemmyResult<()> {
-    let object = Follow::new(actor, community, context)?;
+    let object = Follow::new(actor, target, context)?;
     let undo = UndoFollow {
       actor: actor.id().into(),
-      to: Some([community.id().into()]),
+      to: Some([target.id().into()]),
       object,
       kind: UndoType::Undo,
       id: generate_activity_id(
@@ -41,11 +40,7 @@ impl UndoFollow {
         &context.settings().get_protocol_and_hostname(),
       )?,
     };
-    let inbox = if community.local {
-      ActivitySendTargets::empty()
-    } else {
-      ActivitySendTargets::to_inbox(community.shared_inbox_or_inbox())
-    };
+    let inbox = follow_inbox(target);
     send_lemmy_activity(context, undo, actor, inbox, true).await
   }
 }
@@ -1,9 +1,9 @@
-use self::following::send_follow_community;
+use self::following::{send_follow_community, send_follow_person};
 use crate::{
   activities::{
     block::{send_ban_from_community, send_ban_from_site},
     community::{
-      collection_add::{send_add_mod_to_community, send_feature_post},
+      collection_add::{send_add_mod_to_community, send_feature_post, send_pin_comment},
       lock_page::send_lock_post,
       update::send_update_community,
     },
@@ -16,6 +16,7 @@ use crate::{
     },
     voting::send_like_activity,
   },
+  is_trusted_instance,
   objects::{community::ApubCommunity, person::ApubPerson},
   protocol::activities::{
     community::report::Report,
@@ -33,18 +34,27 @@ use anyhow::anyhow;
 use following::send_accept_or_reject_follow;
 use lemmy_api_common::{
   context::LemmyContext,
+  hooks::{call_hooks, HookOutcome},
   send_activity::{ActivityChannel, SendActivityData},
 };
 use lemmy_db_schema::{
   source::{
     activity::{ActivitySendTargets, ActorType, SentActivity, SentActivityForm},
     community::Community,
+    person::Person,
   },
-  traits::Crud,
+  traits::{ApubActor, Crud},
   CommunityVisibility,
 };
-use lemmy_db_views_actor::structs::{CommunityPersonBanView, CommunityView};
-use lemmy_utils::error::{FederationError, LemmyError, LemmyErrorExt, LemmyErrorType, LemmyResult};
+use lemmy_db_views_actor::structs::{
+  CommunityModeratorView,
+  CommunityPersonBanView,
+  CommunityView,
+};
+use lemmy_utils::{
+  error::{FederationError, LemmyError, LemmyErrorExt, LemmyErrorType, LemmyResult},
+  settings::structs::HookPoint,
+};
 use serde::Serialize;
 use tracing::info;
 use url::{ParseError, Url};
@@ -57,6 +67,21 @@ pub mod deletion;
 pub mod following;
 pub mod voting;
 
+/// Fetches the person who performed an activity. Persons from trusted instances are read from the
+/// database if they exist there, so they aren't refetched when outdated. In that case the activity
+/// is only verified by its signature.
+async fn dereference_actor(
+  person_id: &ObjectId<ApubPerson>,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<ApubPerson> {
+  if is_trusted_instance(person_id.inner(), context).await? {
+    if let Ok(person) = person_id.dereference_local(context).await {
+      return Ok(person);
+    }
+  }
+  person_id.dereference(context).await
+}
+
 /// Checks that the specified Url actually identifies a Person (by fetching it), and that the person
 /// doesn't have a site ban.
 #[tracing::instrument(skip_all)]
@@ -64,7 +89,7 @@ async fn verify_person(
   person_id: &ObjectId<ApubPerson>,
   context: &Data<LemmyContext>,
 ) -> LemmyResult<()> {
-  let person = person_id.dereference(context).await?;
+  let person = dereference_actor(person_id, context).await?;
   if person.banned {
     Err(anyhow!("Person {} is banned", person_id))
       .with_lemmy_type(LemmyErrorType::CouldntUpdateComment)
@@ -81,7 +106,11 @@ pub(crate) async fn verify_person_in_community(
   community: &ApubCommunity,
   context: &Data<LemmyContext>,
 ) -> LemmyResult<()> {
-  let person = person_id.dereference(context).await?;
+  // Mod actions of community bots are sent with the community itself as actor
+  if person_id.inner() == community.actor_id.inner() {
+    return Ok(());
+  }
+  let person = dereference_actor(person_id, context).await?;
   if person.banned {
     Err(FederationError::PersonIsBannedFromSite(
       person.actor_id.to_string(),
@@ -110,10 +139,44 @@ pub(crate) async fn verify_mod_action(
     return Ok(());
   }
 
-  let mod_ = mod_id.dereference(context).await?;
+  let mod_ = dereference_actor(mod_id, context).await?;
   CommunityView::check_is_mod_or_admin(&mut context.pool(), mod_.id, community.id).await
 }
 
+/// Mod actions which a community bot performed through a delegation are attributed to the
+/// community, so they are sent with the community as actor.
+pub(crate) fn mod_action_actor(
+  moderator: &Person,
+  community: &Community,
+  automod: bool,
+) -> ObjectId<ApubPerson> {
+  if automod {
+    community.actor_id.clone().into()
+  } else {
+    moderator.actor_id.clone().into()
+  }
+}
+
+/// Returns the person under which a received mod action is written to the mod log, and whether
+/// the community itself performed it. Such actions are logged under the top moderator of the
+/// community, who isn't shown in the mod log for them.
+pub(crate) async fn mod_log_actor(
+  actor: &ObjectId<ApubPerson>,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<(ApubPerson, bool)> {
+  let actor_id = actor.inner().clone().into();
+  if let Some(community) = Community::read_from_apub_id(&mut context.pool(), &actor_id).await? {
+    let top_mod = CommunityModeratorView::for_community(&mut context.pool(), community.id)
+      .await?
+      .into_iter()
+      .next()
+      .ok_or(LemmyErrorType::NotAModOrAdmin)?;
+    Ok((top_mod.moderator.into(), true))
+  } else {
+    Ok((actor.dereference(context).await?, false))
+  }
+}
+
 pub(crate) fn verify_is_public(to: &[Url], cc: &[Url]) -> LemmyResult<()> {
   if ![to, cc].iter().any(|set| set.contains(&public())) {
     Err(FederationError::ObjectIsNotPublic)?
@@ -213,9 +276,24 @@ where
 {
   info!("Saving outgoing activity to queue {}", activity.id());
 
+  let ap_id = activity.id().clone();
+  let activity = match call_hooks(
+    HookPoint::PreFederationSend,
+    serde_json::to_value(activity)?,
+    data,
+  )
+  .await?
+  {
+    HookOutcome::Allow(activity) => activity,
+    HookOutcome::Deny(reason) => {
+      info!("Hook prevented sending of activity {ap_id}: {reason:?}");
+      return Ok(());
+    }
+  };
+
   let form = SentActivityForm {
-    ap_id: activity.id().clone().into(),
-    data: serde_json::to_value(activity)?,
+    ap_id: ap_id.into(),
+    data: activity,
     sensitive,
     send_inboxes: send_targets
       .inboxes
@@ -273,6 +351,7 @@ pub async fn match_outgoing_activities(
         moderator,
         reason,
         removed,
+        automod,
       } => {
         let community = Community::read(&mut context.pool(), post.community_id).await?;
         send_apub_delete_in_community(
@@ -281,12 +360,18 @@ pub async fn match_outgoing_activities(
           DeletableObjects::Post(post.into()),
           reason.or_else(|| Some(String::new())),
           removed,
+          automod,
           &context,
         )
         .await
       }
-      LockPost(post, actor, locked) => send_lock_post(post, actor, locked, context).await,
-      FeaturePost(post, actor, featured) => send_feature_post(post, actor, featured, context).await,
+      LockPost(post, actor, locked, automod) => {
+        send_lock_post(post, actor, locked, automod, context).await
+      }
+      FeaturePost(post, actor, featured, automod) => {
+        send_feature_post(post, actor, featured, automod, context).await
+      }
+      PinComment(comment, actor, pinned) => send_pin_comment(comment, actor, pinned, context).await,
       CreateComment(comment) => {
         let creator_id = comment.creator_id;
         CreateOrUpdateNote::send(comment, creator_id, CreateOrUpdateType::Create, context).await
@@ -298,18 +383,22 @@ pub async fn match_outgoing_activities(
       DeleteComment(comment, actor, community) => {
         let is_deleted = comment.deleted;
         let deletable = DeletableObjects::Comment(comment.into());
-        send_apub_delete_in_community(actor, community, deletable, None, is_deleted, &context).await
+        send_apub_delete_in_community(
+          actor, community, deletable, None, is_deleted, false, &context,
+        )
+        .await
       }
       RemoveComment {
         comment,
         moderator,
         community,
         reason,
+        automod,
       } => {
         let is_removed = comment.removed;
         let deletable = DeletableObjects::Comment(comment.into());
         send_apub_delete_in_community(
-          moderator, community, deletable, reason, is_removed, &context,
+          moderator, community, deletable, reason, is_removed, automod, &context,
         )
         .await
       }
@@ -322,10 +411,14 @@ pub async fn match_outgoing_activities(
       FollowCommunity(community, person, follow) => {
         send_follow_community(community, person, follow, &context).await
       }
+      FollowPerson(target, person, follow) => {
+        send_follow_person(target, person, follow, &context).await
+      }
       UpdateCommunity(actor, community) => send_update_community(community, actor, context).await,
       DeleteCommunity(actor, community, removed) => {
         let deletable = DeletableObjects::Community(community.clone().into());
-        send_apub_delete_in_community(actor, community, deletable, None, removed, &context).await
+        send_apub_delete_in_community(actor, community, deletable, None, removed, false, &context)
+          .await
       }
       RemoveCommunity {
         moderator,
@@ -340,6 +433,7 @@ pub async fn match_outgoing_activities(
           deletable,
           reason.clone().or_else(|| Some(String::new())),
           removed,
+          false,
           &context,
         )
         .await
@@ -402,3 +496,57 @@ pub async fn match_outgoing_activities(
   fed_task.await?;
   Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::invalidate_local_site_data_cache;
+  use chrono::{TimeDelta, Utc};
+  use lemmy_db_schema::{
+    source::{
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    InstanceTrustLevel,
+  };
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_dereference_trusted_actor() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    invalidate_local_site_data_cache();
+    let instance = Instance::read_or_create(&mut context.pool(), "trusted.tld".to_string()).await?;
+
+    // The person is outdated, so it would normally be refetched
+    let actor_id = Url::parse("https://trusted.tld/u/outdated")?;
+    let person_form = PersonInsertForm {
+      actor_id: Some(actor_id.clone().into()),
+      local: Some(false),
+      last_refreshed_at: Some(Utc::now() - TimeDelta::days(30)),
+      ..PersonInsertForm::test_form(instance.id, "outdated")
+    };
+    let person = Person::create(&mut context.pool(), &person_form).await?;
+    assert!(!is_trusted_instance(&actor_id, &context).await?);
+
+    Instance::set_trust_level(
+      &mut context.pool(),
+      instance.id,
+      InstanceTrustLevel::Trusted,
+    )
+    .await?;
+    invalidate_local_site_data_cache();
+    assert!(is_trusted_instance(&actor_id, &context).await?);
+
+    // Persons from trusted instances are read from the database without any request
+    let actor = dereference_actor(&actor_id.into(), &context).await?;
+    assert_eq!(person.id, actor.id);
+    assert_eq!(0, context.request_count());
+
+    Instance::delete(&mut context.pool(), instance.id).await?;
+    invalidate_local_site_data_cache();
+    Ok(())
+  }
+}
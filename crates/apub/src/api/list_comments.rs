@@ -81,8 +81,10 @@ pub async fn list_comments(
     sort,
     max_depth,
     saved_only,
+    saved_notes: data.saved_notes.clone(),
     liked_only,
     disliked_only,
+    followed_persons_only: data.followed_persons_only,
     community_id,
     parent_path: parent_path_cloned,
     post_id,
@@ -8,11 +8,13 @@ use actix_web::web::{Json, Query};
 use lemmy_api_common::{
   context::LemmyContext,
   post::{GetPosts, GetPostsResponse},
-  utils::{check_conflicting_like_filters, check_private_instance},
+  utils::{check_conflicting_like_filters, check_custom_feed_visible, check_private_instance},
 };
 use lemmy_db_schema::{
   newtypes::PostId,
-  source::{community::Community, post::PostRead},
+  source::{community::Community, custom_feed::CustomFeed, post::PostRead},
+  traits::Crud,
+  ListingType,
 };
 use lemmy_db_views::{
   post_view::PostQuery,
@@ -51,13 +53,23 @@ pub async fn list_posts(
   let disliked_only = data.disliked_only;
   check_conflicting_like_filters(liked_only, disliked_only)?;
 
+  if let Some(custom_feed_id) = data.custom_feed_id {
+    let custom_feed = CustomFeed::read(&mut context.pool(), custom_feed_id).await?;
+    check_custom_feed_visible(&custom_feed, &local_user_view)?;
+  }
+
   let local_user = local_user_view.as_ref().map(|u| &u.local_user);
-  let listing_type = Some(listing_type_with_default(
-    data.type_,
-    local_user,
-    &local_site.local_site,
-    community_id,
-  ));
+  let listing_type = if data.custom_feed_id.is_some() {
+    // A custom feed shows all posts of its communities
+    Some(ListingType::All)
+  } else {
+    Some(listing_type_with_default(
+      data.type_,
+      local_user,
+      &local_site.local_site,
+      community_id,
+    ))
+  };
 
   let sort = Some(post_sort_type_with_default(
     data.sort,
@@ -77,9 +89,13 @@ pub async fn list_posts(
     listing_type,
     sort,
     community_id,
+    tag_id: data.tag_id,
+    custom_feed_id: data.custom_feed_id,
     saved_only,
+    saved_notes: data.saved_notes.clone(),
     liked_only,
     disliked_only,
+    followed_persons_only: data.followed_persons_only,
     page,
     page_after,
     limit,
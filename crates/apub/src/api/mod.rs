@@ -12,6 +12,7 @@ pub mod read_community;
 pub mod read_person;
 pub mod resolve_object;
 pub mod search;
+pub mod user_data_export;
 pub mod user_settings_backup;
 
 /// Returns default listing type, depending if the query is for frontpage or community.
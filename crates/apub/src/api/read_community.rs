@@ -1,15 +1,21 @@
 use crate::{fetcher::resolve_actor_identifier, objects::community::ApubCommunity};
 use activitypub_federation::config::Data;
-use actix_web::web::{Json, Query};
+use actix_web::{
+  web::{Json, Query},
+  HttpRequest,
+};
 use lemmy_api_common::{
   community::{GetCommunity, GetCommunityResponse},
   context::LemmyContext,
+  description_translation::{localize_community, preferred_language_ids},
   utils::{check_private_instance, is_mod_or_admin_opt, read_site_for_actor},
 };
 use lemmy_db_schema::source::{
   actor_language::CommunityLanguage,
   community::Community,
+  description_translation::CommunityDescriptionTranslation,
   local_site::LocalSite,
+  local_user_community_settings::LocalUserCommunitySettings,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_db_views_actor::structs::{CommunityModeratorView, CommunityView};
@@ -18,6 +24,7 @@ use lemmy_utils::error::{LemmyErrorType, LemmyResult};
 #[tracing::instrument(skip(context))]
 pub async fn get_community(
   data: Query<GetCommunity>,
+  req: HttpRequest,
   context: Data<LemmyContext>,
   local_user_view: Option<LocalUserView>,
 ) -> LemmyResult<Json<GetCommunityResponse>> {
@@ -49,7 +56,7 @@ pub async fn get_community(
   .await
   .is_ok();
 
-  let community_view = CommunityView::read(
+  let mut community_view = CommunityView::read(
     &mut context.pool(),
     community_id,
     local_user,
@@ -64,10 +71,28 @@ pub async fn get_community(
   let community_id = community_view.community.id;
   let discussion_languages = CommunityLanguage::read(&mut context.pool(), community_id).await?;
 
+  let description_translations =
+    CommunityDescriptionTranslation::list(&mut context.pool(), community_id).await?;
+  let language_ids = preferred_language_ids(local_user_view.as_ref(), &req, &context).await?;
+  localize_community(
+    &mut community_view.community,
+    &description_translations,
+    &language_ids,
+  );
+
+  let my_settings = match local_user {
+    Some(local_user) => {
+      LocalUserCommunitySettings::read(&mut context.pool(), local_user.id, community_id).await?
+    }
+    None => None,
+  };
+
   Ok(Json(GetCommunityResponse {
     community_view,
     site,
     moderators,
     discussion_languages,
+    description_translations,
+    my_settings,
   }))
 }
@@ -4,13 +4,20 @@ use actix_web::web::{Json, Query};
 use lemmy_api_common::{
   context::LemmyContext,
   person::{GetPersonDetails, GetPersonDetailsResponse},
-  utils::{check_private_instance, read_site_for_actor},
+  utils::{
+    check_community_mod_of_any_or_admin_action,
+    check_private_instance,
+    read_site_for_actor,
+  },
+};
+use lemmy_db_schema::{
+  source::{moderator::ModWarnPerson, person::Person, person_pin::PersonPin},
+  utils::post_to_comment_sort_type,
 };
-use lemmy_db_schema::{source::person::Person, utils::post_to_comment_sort_type};
 use lemmy_db_views::{
   comment_view::CommentQuery,
   post_view::PostQuery,
-  structs::{LocalUserView, SiteView},
+  structs::{CommentView, LocalUserView, PersonModNoteView, PostView, SiteView},
 };
 use lemmy_db_views_actor::structs::{CommunityModeratorView, PersonView};
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
@@ -88,6 +95,48 @@ pub async fn read_person(
   .list(&local_site.site, &mut context.pool())
   .await?;
 
+  // Pinned content which can't be read anymore (eg removed) is skipped
+  let mut pinned_posts = vec![];
+  let mut pinned_comments = vec![];
+  for pin in PersonPin::list_for_person(&mut context.pool(), person_details_id).await? {
+    if let Some(post_id) = pin.post_id {
+      if let Ok(post) = PostView::read(&mut context.pool(), post_id, local_user, false).await {
+        pinned_posts.push(post);
+      }
+    } else if let Some(comment_id) = pin.comment_id {
+      if let Ok(comment) = CommentView::read(&mut context.pool(), comment_id, local_user).await {
+        pinned_comments.push(comment);
+      }
+    }
+  }
+
+  // Private notes and warning strikes are only included for moderators and admins
+  let mut mod_notes = None;
+  let mut strikes = None;
+  if let Some(local_user_view) = &local_user_view {
+    if check_community_mod_of_any_or_admin_action(local_user_view, &mut context.pool())
+      .await
+      .is_ok()
+    {
+      mod_notes = Some(
+        PersonModNoteView::list_for_persons(
+          &mut context.pool(),
+          &[person_details_id],
+          local_user_view,
+        )
+        .await?,
+      );
+      strikes = Some(
+        ModWarnPerson::all_active_strikes(
+          &mut context.pool(),
+          person_details_id,
+          context.clock().now(),
+        )
+        .await?,
+      );
+    }
+  }
+
   let moderates = CommunityModeratorView::for_person(
     &mut context.pool(),
     person_details_id,
@@ -104,5 +153,9 @@ pub async fn read_person(
     moderates,
     comments,
     posts,
+    pinned_posts,
+    pinned_comments,
+    mod_notes,
+    strikes,
   }))
 }
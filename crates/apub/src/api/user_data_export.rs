@@ -0,0 +1,112 @@
+use crate::api::user_settings_backup::{export_settings, UserSettingsBackup};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  newtypes::DbUrl,
+  source::{comment::Comment, local_user::LocalUser, post::Post},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyResult;
+use serde::{Deserialize, Serialize};
+
+/// Complete export of everything the instance stores about a user, for data portability. Unlike
+/// [UserSettingsBackup] this is not meant to be imported again.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UserDataExport {
+  pub settings: UserSettingsBackup,
+  pub posts: Vec<Post>,
+  pub comments: Vec<Comment>,
+  pub post_votes: Vec<ExportedVote>,
+  pub comment_votes: Vec<ExportedVote>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExportedVote {
+  pub object: DbUrl,
+  pub score: i16,
+}
+
+#[tracing::instrument(skip(context))]
+pub async fn export_data(
+  local_user_view: LocalUserView,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<UserDataExport>> {
+  let content = LocalUser::export_content(&mut context.pool(), local_user_view.person.id).await?;
+  let Json(settings) = export_settings(local_user_view, context).await?;
+
+  let into_votes = |votes: Vec<(DbUrl, i16)>| {
+    votes
+      .into_iter()
+      .map(|(object, score)| ExportedVote { object, score })
+      .collect()
+  };
+  Ok(Json(UserDataExport {
+    settings,
+    posts: content.posts,
+    comments: content.comments,
+    post_votes: into_votes(content.post_votes),
+    comment_votes: into_votes(content.comment_votes),
+  }))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::api::user_data_export::export_data;
+  use lemmy_api_common::context::LemmyContext;
+  use lemmy_db_schema::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      local_user::LocalUser,
+      post::{Post, PostInsertForm, PostLike, PostLikeForm},
+    },
+    traits::{Crud, Likeable},
+  };
+  use lemmy_db_views::structs::LocalUserView;
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_data_export() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+
+    let user = LocalUserView::create_test_user(pool, "gina", "gina bio", false).await?;
+    let community_form = CommunityInsertForm::new(
+      user.person.instance_id,
+      "exportcom".to_string(),
+      "exportcom".to_string(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let post_form = PostInsertForm::new("exported".into(), user.person.id, community.id);
+    let post = Post::create(pool, &post_form).await?;
+    let like_form = PostLikeForm {
+      post_id: post.id,
+      person_id: user.person.id,
+      score: 1,
+    };
+    PostLike::like(pool, &like_form).await?;
+
+    let export = export_data(user.clone(), context.reset_request_count()).await?;
+
+    assert_eq!(
+      vec![post.ap_id.clone()],
+      export
+        .posts
+        .iter()
+        .map(|p| p.ap_id.clone())
+        .collect::<Vec<_>>()
+    );
+    assert_eq!(1, export.post_votes.len());
+    assert_eq!(post.ap_id, export.post_votes[0].object);
+    assert!(export.comments.is_empty());
+    assert_eq!(user.person.bio, export.settings.bio);
+
+    Community::delete(pool, community.id).await?;
+    LocalUser::delete(pool, user.local_user.id).await?;
+    Ok(())
+  }
+}
@@ -21,7 +21,7 @@ use lemmy_db_schema::{
     local_user_vote_display_mode::{LocalUserVoteDisplayMode, LocalUserVoteDisplayModeUpdateForm},
     person::{Person, PersonUpdateForm},
     person_block::{PersonBlock, PersonBlockForm},
-    post::{PostSaved, PostSavedForm},
+    post::{PostRead, PostSaved, PostSavedForm},
   },
   traits::{Blockable, Crud, Followable, Saveable},
 };
@@ -31,10 +31,15 @@ use lemmy_utils::{
   spawn_try_task,
 };
 use serde::{Deserialize, Serialize};
-use std::future::Future;
+use std::{future::Future, time::Duration};
+use tokio::time::sleep;
 use tracing::info;
 
 const PARALLELISM: usize = 10;
+/// How often fetching a remote object is attempted, in case its instance is temporarily
+/// unreachable.
+const FETCH_ATTEMPTS: u32 = 3;
+const FETCH_RETRY_DELAY: Duration = Duration::from_secs(2);
 
 /// Backup of user data. This struct should never be changed so that the data can be used as a
 /// long-term backup in case the instance goes down unexpectedly. All fields are optional to allow
@@ -61,6 +66,8 @@ pub struct UserSettingsBackup {
   #[serde(default)]
   pub saved_posts: Vec<ObjectId<ApubPost>>,
   #[serde(default)]
+  pub read_posts: Vec<ObjectId<ApubPost>>,
+  #[serde(default)]
   pub saved_comments: Vec<ObjectId<ApubComment>>,
   #[serde(default)]
   pub blocked_communities: Vec<ObjectId<ApubCommunity>>,
@@ -92,6 +99,7 @@ pub async fn export_settings(
     blocked_instances: lists.blocked_instances,
     blocked_users: lists.blocked_users.into_iter().map(Into::into).collect(),
     saved_posts: lists.saved_posts.into_iter().map(Into::into).collect(),
+    read_posts: lists.read_posts.into_iter().map(Into::into).collect(),
     saved_comments: lists.saved_comments.into_iter().map(Into::into).collect(),
   }))
 }
@@ -134,6 +142,12 @@ pub async fn import_settings(
     post_listing_mode: data.settings.as_ref().map(|s| s.post_listing_mode),
     ..Default::default()
   };
+  let local_user_form = LocalUserUpdateForm {
+    customized_settings: local_user_view
+      .local_user
+      .customized_settings_after(&local_user_form),
+    ..local_user_form
+  };
   LocalUser::update(
     &mut context.pool(),
     local_user_view.local_user.id,
@@ -167,6 +181,7 @@ pub async fn import_settings(
     + data.blocked_users.len()
     + data.blocked_instances.len()
     + data.saved_posts.len()
+    + data.read_posts.len()
     + data.saved_comments.len();
   if url_count > MAX_API_PARAM_ELEMENTS {
     Err(LemmyErrorType::TooManyItems)?;
@@ -203,6 +218,7 @@ pub async fn import_settings(
         let form = PostSavedForm {
           person_id,
           post_id: post.id,
+          saved_note: None,
         };
         PostSaved::save(&mut context.pool(), &form).await?;
         LemmyResult::Ok(())
@@ -210,6 +226,17 @@ pub async fn import_settings(
     )
     .await?;
 
+    let failed_read_posts = fetch_and_import(
+      data.read_posts.clone(),
+      &context,
+      |(read, context)| async move {
+        let post = read.dereference(&context).await?;
+        PostRead::mark_as_read(&mut context.pool(), post.id, person_id).await?;
+        LemmyResult::Ok(())
+      },
+    )
+    .await?;
+
     let failed_saved_comments = fetch_and_import(
       data.saved_comments.clone(),
       &context,
@@ -218,6 +245,7 @@ pub async fn import_settings(
         let form = CommentSavedForm {
           person_id,
           comment_id: comment.id,
+          saved_note: None,
         };
         CommentSaved::save(&mut context.pool(), &form).await?;
         LemmyResult::Ok(())
@@ -267,7 +295,7 @@ pub async fn import_settings(
     }))
     .await?;
 
-    info!("Settings import completed for {}, the following items failed: {failed_followed_communities}, {failed_saved_posts}, {failed_saved_comments}, {failed_community_blocks}, {failed_user_blocks}",
+    info!("Settings import completed for {}, the following items failed: {failed_followed_communities}, {failed_saved_posts}, {failed_read_posts}, {failed_saved_comments}, {failed_community_blocks}, {failed_user_blocks}",
     local_user_view.person.name);
 
     Ok(())
@@ -279,34 +307,39 @@ pub async fn import_settings(
 async fn fetch_and_import<Kind, Fut>(
   objects: Vec<ObjectId<Kind>>,
   context: &Data<LemmyContext>,
-  import_fn: impl FnMut((ObjectId<Kind>, Data<LemmyContext>)) -> Fut,
+  import_fn: impl Fn((ObjectId<Kind>, Data<LemmyContext>)) -> Fut,
 ) -> LemmyResult<String>
 where
   Kind: Object + Send + 'static,
   for<'de2> <Kind as Object>::Kind: Deserialize<'de2>,
   Fut: Future<Output = LemmyResult<()>>,
 {
-  let mut failed_items = vec![];
-  futures::stream::iter(
-    objects
-      .clone()
-      .into_iter()
+  let import_fn = &import_fn;
+  let failed_items = futures::stream::iter(objects.into_iter().map(|object| async move {
+    // Retry with increasing delay, so that objects from instances which are temporarily down
+    // still get imported
+    let mut delay = FETCH_RETRY_DELAY;
+    for _ in 1..FETCH_ATTEMPTS {
       // need to reset outgoing request count to avoid running into limit
-      .map(|s| (s, context.reset_request_count()))
-      .map(import_fn),
-  )
-  .buffer_unordered(PARALLELISM)
-  .collect::<Vec<_>>()
-  .await
-  .into_iter()
-  .enumerate()
-  .for_each(|(i, r): (usize, LemmyResult<()>)| {
-    if r.is_err() {
-      if let Some(object) = objects.get(i) {
-        failed_items.push(object.inner().clone());
+      if import_fn((object.clone(), context.reset_request_count()))
+        .await
+        .is_ok()
+      {
+        return None;
       }
+      sleep(delay).await;
+      delay *= 2;
     }
-  });
+    let url = object.inner().clone();
+    import_fn((object, context.reset_request_count()))
+      .await
+      .err()
+      .map(|_| url)
+  }))
+  .buffer_unordered(PARALLELISM)
+  .filter_map(|failed| async move { failed })
+  .collect::<Vec<_>>()
+  .await;
   Ok(failed_items.into_iter().join(","))
 }
 
@@ -0,0 +1,109 @@
+use crate::{
+  fetcher::post_or_comment::PostOrComment,
+  objects::{comment::ApubComment, person::ApubPerson, post::ApubPost},
+  protocol::collections::person_featured::PersonFeatured,
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::collection::OrderedCollectionType,
+  protocol::verification::verify_domains_match,
+  traits::{Collection, Object},
+};
+use futures::future::{join_all, try_join_all};
+use lemmy_api_common::{context::LemmyContext, utils::generate_featured_url};
+use lemmy_db_schema::{
+  source::{comment::Comment, person_pin::PersonPin, post::Post},
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyError, LemmyResult};
+use url::Url;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ApubPersonFeatured(());
+
+#[async_trait::async_trait]
+impl Collection for ApubPersonFeatured {
+  type Owner = ApubPerson;
+  type DataType = LemmyContext;
+  type Kind = PersonFeatured;
+  type Error = LemmyError;
+
+  async fn read_local(
+    owner: &Self::Owner,
+    data: &Data<Self::DataType>,
+  ) -> Result<Self::Kind, Self::Error> {
+    let pins = PersonPin::list_for_person(&mut data.pool(), owner.id).await?;
+    let ordered_items = try_join_all(pins.into_iter().map(|pin| async move {
+      let item = match (pin.post_id, pin.comment_id) {
+        (Some(post_id), _) => {
+          PostOrComment::Post(ApubPost::from(Post::read(&mut data.pool(), post_id).await?))
+        }
+        (None, Some(comment_id)) => PostOrComment::Comment(ApubComment::from(
+          Comment::read(&mut data.pool(), comment_id).await?,
+        )),
+        (None, None) => return LemmyResult::Ok(None),
+      };
+      item.into_json(data).await.map(Some)
+    }))
+    .await?
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    Ok(PersonFeatured {
+      r#type: OrderedCollectionType::OrderedCollection,
+      id: generate_featured_url(&owner.actor_id)?.into(),
+      total_items: ordered_items.len() as i32,
+      ordered_items,
+    })
+  }
+
+  async fn verify(
+    apub: &Self::Kind,
+    expected_domain: &Url,
+    _data: &Data<Self::DataType>,
+  ) -> Result<(), Self::Error> {
+    verify_domains_match(expected_domain, &apub.id)?;
+    Ok(())
+  }
+
+  async fn from_json(
+    apub: Self::Kind,
+    owner: &Self::Owner,
+    context: &Data<Self::DataType>,
+  ) -> Result<Self, Self::Error>
+  where
+    Self: Sized,
+  {
+    let items = apub
+      .ordered_items
+      .into_iter()
+      .take(PersonPin::LIMIT as usize);
+
+    // process items in parallel, to avoid long delay from fetch_site_metadata() and other
+    // processing
+    let items: Vec<PostOrComment> = join_all(items.map(|item| async {
+      PostOrComment::verify(&item, &apub.id, context).await?;
+      PostOrComment::from_json(item, context).await
+    }))
+    .await
+    // ignore any failed or unparseable items
+    .into_iter()
+    .filter_map(Result::ok)
+    .collect();
+
+    // only content which was written by the person itself can be pinned
+    let mut post_ids = vec![];
+    let mut comment_ids = vec![];
+    for item in items {
+      match item {
+        PostOrComment::Post(p) if p.creator_id == owner.id => post_ids.push(p.id),
+        PostOrComment::Comment(c) if c.creator_id == owner.id => comment_ids.push(c.id),
+        _ => {}
+      }
+    }
+    PersonPin::set_for_person(&mut context.pool(), owner.id, post_ids, comment_ids).await?;
+
+    // This return value is unused, so just set an empty vec
+    Ok(ApubPersonFeatured(()))
+  }
+}
@@ -8,20 +8,27 @@ use activitypub_federation::{
   actix_web::{inbox::receive_activity, signing_actor},
   config::Data,
   protocol::context::WithContext,
+  traits::{ActivityHandler, Actor},
   FEDERATION_CONTENT_TYPE,
 };
 use actix_web::{web, web::Bytes, HttpRequest, HttpResponse};
 use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::{
   newtypes::DbUrl,
-  source::{activity::SentActivity, community::Community},
+  source::{
+    activity::{QueuedActivity, QueuedActivityForm, SentActivity},
+    community::Community,
+    local_site::LocalSite,
+  },
   CommunityVisibility,
 };
 use lemmy_db_views_actor::structs::CommunityFollowerView;
 use lemmy_utils::error::{FederationError, LemmyErrorType, LemmyResult};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::{ops::Deref, time::Duration};
 use tokio::time::timeout;
+use tracing::warn;
 use url::Url;
 
 mod comment;
@@ -33,11 +40,18 @@ pub mod site;
 
 const INCOMING_ACTIVITY_TIMEOUT: Duration = Duration::from_secs(9);
 
+/// How many queued activities are processed at once after maintenance mode is turned off.
+const QUEUED_ACTIVITY_BATCH_SIZE: i64 = 100;
+
 pub async fn shared_inbox(
   request: HttpRequest,
   body: Bytes,
   data: Data<LemmyContext>,
 ) -> LemmyResult<HttpResponse> {
+  if LocalSite::read(&mut data.pool()).await?.maintenance_mode {
+    return queue_activity(&request, body, &data).await;
+  }
+
   let receive_fut =
     receive_activity::<SharedInboxActivities, UserOrCommunity, LemmyContext>(request, body, &data);
   // Set a timeout shorter than `REQWEST_TIMEOUT` for processing incoming activities. This is to
@@ -49,6 +63,58 @@ pub async fn shared_inbox(
     .map_err(|_| FederationError::InboxTimeout)?
 }
 
+/// Verifies the signature of an activity which was received during maintenance mode, and stores
+/// it so that it can be processed later.
+async fn queue_activity(
+  request: &HttpRequest,
+  body: Bytes,
+  data: &Data<LemmyContext>,
+) -> LemmyResult<HttpResponse> {
+  let actor = signing_actor::<UserOrCommunity>(request, Some(body.clone()), data).await?;
+  let form = QueuedActivityForm {
+    actor_id: actor.id().into(),
+    data: serde_json::from_slice::<Value>(&body)?,
+  };
+  QueuedActivity::create(&mut data.pool(), &form).await?;
+  Ok(HttpResponse::Accepted().finish())
+}
+
+/// Processes the activities which were queued during maintenance mode, oldest first. Does
+/// nothing while maintenance mode is still on.
+pub async fn process_queued_activities(data: &Data<LemmyContext>) -> LemmyResult<()> {
+  loop {
+    if LocalSite::read(&mut data.pool()).await?.maintenance_mode {
+      return Ok(());
+    }
+    let queued = QueuedActivity::list_oldest(&mut data.pool(), QUEUED_ACTIVITY_BATCH_SIZE).await?;
+    if queued.is_empty() {
+      return Ok(());
+    }
+    for queued_activity in queued {
+      let id = queued_activity.id;
+      if let Err(e) = receive_queued_activity(queued_activity, data).await {
+        warn!("Failed to process queued activity {id}: {e}");
+      }
+      // Failed activities are dropped, the same as an activity which failed in the inbox
+      QueuedActivity::delete(&mut data.pool(), id).await?;
+    }
+  }
+}
+
+async fn receive_queued_activity(
+  queued_activity: QueuedActivity,
+  data: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let activity: SharedInboxActivities = serde_json::from_value(queued_activity.data)?;
+  // The signature was checked when the activity was queued, this ensures it was signed by the
+  // actor of the activity.
+  if *activity.actor() != *queued_activity.actor_id.inner() {
+    Err(FederationError::ActivityActorMismatch)?
+  }
+  activity.verify(data).await?;
+  activity.receive(data).await
+}
+
 /// Convert the data to json and turn it into an HTTP Response with the correct ActivityPub
 /// headers.
 ///
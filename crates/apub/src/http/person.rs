@@ -1,9 +1,13 @@
 use crate::{
+  collections::person_featured::ApubPersonFeatured,
   http::{create_apub_response, create_apub_tombstone_response},
   objects::person::ApubPerson,
   protocol::collections::empty_outbox::EmptyOutbox,
 };
-use activitypub_federation::{config::Data, traits::Object};
+use activitypub_federation::{
+  config::Data,
+  traits::{Collection, Object},
+};
 use actix_web::{web, HttpResponse};
 use lemmy_api_common::{context::LemmyContext, utils::generate_outbox_url};
 use lemmy_db_schema::{source::person::Person, traits::ApubActor};
@@ -49,3 +53,17 @@ pub(crate) async fn get_apub_person_outbox(
   let outbox = EmptyOutbox::new(outbox_id)?;
   create_apub_response(&outbox)
 }
+
+/// Posts and comments which the person pinned to their profile.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn get_apub_person_featured(
+  info: web::Path<PersonQuery>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<HttpResponse> {
+  let person: ApubPerson = Person::read_from_name(&mut context.pool(), &info.user_name, false)
+    .await?
+    .ok_or(LemmyErrorType::NotFound)?
+    .into();
+  let featured = ApubPersonFeatured::read_local(&person, &context).await?;
+  create_apub_response(&featured)
+}
@@ -8,7 +8,7 @@ use crate::http::{
     get_apub_community_outbox,
   },
   get_activity,
-  person::{get_apub_person_http, get_apub_person_outbox},
+  person::{get_apub_person_featured, get_apub_person_http, get_apub_person_outbox},
   post::get_apub_post,
   shared_inbox,
   site::{get_apub_site_http, get_apub_site_outbox},
@@ -48,6 +48,10 @@ pub fn config(cfg: &mut web::ServiceConfig) {
       "/u/{user_name}/outbox",
       web::get().to(get_apub_person_outbox),
     )
+    .route(
+      "/u/{user_name}/featured",
+      web::get().to(get_apub_person_featured),
+    )
     .route("/post/{post_id}", web::get().to(get_apub_post))
     .route("/comment/{comment_id}", web::get().to(get_apub_comment))
     .route("/activities/{type_}/{id}", web::get().to(get_activity));
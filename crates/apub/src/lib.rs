@@ -129,44 +129,71 @@ pub(crate) struct LocalSiteData {
   local_site: Option<LocalSite>,
   allowed_instances: Vec<Instance>,
   blocked_instances: Vec<Instance>,
+  trusted_instances: Vec<Instance>,
 }
 
+// All incoming and outgoing federation actions read the blocklist/allowlist and slur filters
+// multiple times. This causes a huge number of database reads if we hit the db directly. So we
+// cache these values for a short time, which will already make a huge difference and ensures that
+// changes take effect quickly.
+static LOCAL_SITE_DATA: LazyLock<Cache<(), Arc<LocalSiteData>>> = LazyLock::new(|| {
+  Cache::builder()
+    .max_capacity(1)
+    .time_to_live(CACHE_DURATION_FEDERATION)
+    .build()
+});
+
 pub(crate) async fn local_site_data_cached(
   pool: &mut DbPool<'_>,
 ) -> LemmyResult<Arc<LocalSiteData>> {
-  // All incoming and outgoing federation actions read the blocklist/allowlist and slur filters
-  // multiple times. This causes a huge number of database reads if we hit the db directly. So we
-  // cache these values for a short time, which will already make a huge difference and ensures that
-  // changes take effect quickly.
-  static CACHE: LazyLock<Cache<(), Arc<LocalSiteData>>> = LazyLock::new(|| {
-    Cache::builder()
-      .max_capacity(1)
-      .time_to_live(CACHE_DURATION_FEDERATION)
-      .build()
-  });
   Ok(
-    CACHE
+    LOCAL_SITE_DATA
       .try_get_with((), async {
-        let (local_site, allowed_instances, blocked_instances) =
+        let (local_site, allowed_instances, blocked_instances, trusted_instances) =
           lemmy_db_schema::try_join_with_pool!(pool => (
             // LocalSite may be missing
             |pool| async {
               Ok(LocalSite::read(pool).await.ok())
             },
             Instance::allowlist,
-            Instance::blocklist
+            Instance::blocklist,
+            Instance::trusted_list
           ))?;
 
         Ok::<_, diesel::result::Error>(Arc::new(LocalSiteData {
           local_site,
           allowed_instances,
           blocked_instances,
+          trusted_instances,
         }))
       })
       .await?,
   )
 }
 
+/// Whether the URL belongs to an instance which admins marked as trusted.
+pub(crate) async fn is_trusted_instance(
+  apub_id: &Url,
+  context: &LemmyContext,
+) -> LemmyResult<bool> {
+  let Some(domain) = apub_id.domain() else {
+    return Ok(false);
+  };
+  let local_site_data = local_site_data_cached(&mut context.pool()).await?;
+  Ok(
+    local_site_data
+      .trusted_instances
+      .iter()
+      .any(|i| i.domain.eq_ignore_ascii_case(domain)),
+  )
+}
+
+/// Drops the cached local site data used for federation, so that the next read gets it from the
+/// database.
+pub fn invalidate_local_site_data_cache() {
+  LOCAL_SITE_DATA.invalidate_all();
+}
+
 pub(crate) async fn check_apub_id_valid_with_strictness(
   apub_id: &Url,
   is_strict: bool,
@@ -90,7 +90,8 @@ impl Object for ApubComment {
   }
 
   #[tracing::instrument(skip_all)]
-  async fn into_json(self, context: &Data<Self::DataType>) -> LemmyResult<Note> {
+  async fn into_json(mut self, context: &Data<Self::DataType>) -> LemmyResult<Note> {
+    Comment::restore_archived(&mut context.pool(), [&mut self.0]).await?;
     let creator_id = self.creator_id;
     let creator = Person::read(&mut context.pool(), creator_id).await?;
 
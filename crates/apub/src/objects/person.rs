@@ -20,6 +20,7 @@ use chrono::{DateTime, Utc};
 use lemmy_api_common::{
   context::LemmyContext,
   utils::{
+    generate_featured_url,
     generate_outbox_url,
     get_url_blocklist,
     local_site_opt_to_slur_regex,
@@ -39,6 +40,7 @@ use lemmy_db_schema::{
 };
 use lemmy_utils::{
   error::{LemmyError, LemmyResult},
+  spawn_try_task,
   utils::{
     markdown::markdown_to_html,
     slurs::{check_slurs, check_slurs_opt},
@@ -119,6 +121,7 @@ impl Object for ApubPerson {
       public_key: self.public_key(),
       updated: self.updated,
       inbox: self.inbox_url.clone().into(),
+      featured: Some(generate_featured_url(&self.actor_id)?.into()),
     };
     Ok(person)
   }
@@ -160,6 +163,7 @@ impl Object for ApubPerson {
     // https://github.com/mastodon/mastodon/issues/25233
     let display_name = person.name.filter(|n| !n.is_empty());
 
+    let featured = person.featured;
     let person_form = PersonInsertForm {
       name: person.preferred_username,
       display_name,
@@ -187,9 +191,20 @@ impl Object for ApubPerson {
       matrix_user_id: person.matrix_user_id,
       instance_id,
     };
-    let person = DbPerson::upsert(&mut context.pool(), &person_form).await?;
+    let person: ApubPerson = DbPerson::upsert(&mut context.pool(), &person_form)
+      .await?
+      .into();
+
+    let person_ = person.clone();
+    let context_ = context.reset_request_count();
+    spawn_try_task(async move {
+      if let Some(featured) = featured {
+        featured.dereference(&person_, &context_).await.ok();
+      }
+      Ok(())
+    });
 
-    Ok(person.into())
+    Ok(person)
   }
 }
 
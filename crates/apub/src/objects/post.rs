@@ -6,7 +6,7 @@ use crate::{
   objects::{read_from_string_or_source_opt, verify_is_remote_object},
   protocol::{
     objects::{
-      page::{Attachment, AttributedTo, Hashtag, HashtagType, Page, PageType},
+      page::{Attachment, AttributedTo, Hashtag, HashtagType, Page, PageType, QuestionOption},
       LanguageTag,
     },
     ImageObject,
@@ -32,7 +32,9 @@ use lemmy_db_schema::{
     community::Community,
     local_site::LocalSite,
     person::Person,
+    poll::{Poll, PollInsertForm, PollOption},
     post::{Post, PostInsertForm, PostUpdateForm},
+    post_media::{PostMedia, PostMediaForm},
   },
   traits::Crud,
   utils::naive_now,
@@ -43,7 +45,7 @@ use lemmy_utils::{
   spawn_try_task,
   utils::{
     markdown::markdown_to_html,
-    slurs::check_slurs_opt,
+    slurs::{check_slurs, check_slurs_opt},
     validation::{is_url_blocked, is_valid_url},
   },
 };
@@ -52,6 +54,8 @@ use stringreader::StringReader;
 use url::Url;
 
 const MAX_TITLE_LENGTH: usize = 200;
+const MAX_POLL_OPTIONS: usize = 20;
+const MAX_GALLERY_IMAGES: usize = 20;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ApubPost(pub(crate) Post);
@@ -105,13 +109,16 @@ impl Object for ApubPost {
 
   // Turn a Lemmy post into an ActivityPub page that can be sent out over the network.
   #[tracing::instrument(skip_all)]
-  async fn into_json(self, context: &Data<Self::DataType>) -> LemmyResult<Page> {
+  async fn into_json(mut self, context: &Data<Self::DataType>) -> LemmyResult<Page> {
+    Post::restore_archived(&mut context.pool(), [&mut self.0]).await?;
     let creator_id = self.creator_id;
     let creator = Person::read(&mut context.pool(), creator_id).await?;
     let community_id = self.community_id;
     let community = Community::read(&mut context.pool(), community_id).await?;
     let language = Some(LanguageTag::new_single(self.language_id, &mut context.pool()).await?);
 
+    // Gallery images are sent as additional attachments after the post url
+    let gallery = PostMedia::list_for_posts(&mut context.pool(), &[self.id]).await?;
     let attachment = self
       .url
       .clone()
@@ -123,6 +130,13 @@ impl Object for ApubPost {
         )
       })
       .into_iter()
+      .chain(gallery.into_iter().map(|media| {
+        Attachment::new(
+          media.url.into(),
+          Some(String::from("image")),
+          media.alt_text,
+        )
+      }))
       .collect();
     let hashtag = Hashtag {
       href: self.ap_id.clone().into(),
@@ -130,8 +144,26 @@ impl Object for ApubPost {
       kind: HashtagType::Hashtag,
     };
 
+    // Polls are sent as Question, like Mastodon does
+    let poll = Poll::read_for_post(&mut context.pool(), self.id).await?;
+    let poll_options = if let Some(poll) = &poll {
+      let options = PollOption::list_for_poll(&mut context.pool(), poll.id)
+        .await?
+        .into_iter()
+        .map(|o| QuestionOption::new(o.name, o.vote_count))
+        .collect::<Vec<_>>();
+      Some(options)
+    } else {
+      None
+    };
+    let multiple_choice = poll.as_ref().is_some_and(|p| p.multiple_choice);
+
     let page = Page {
-      kind: PageType::Page,
+      kind: if poll.is_some() {
+        PageType::Question
+      } else {
+        PageType::Page
+      },
       id: self.ap_id.clone().into(),
       attributed_to: AttributedTo::Lemmy(creator.actor_id.into()),
       to: vec![generate_to(&community)?],
@@ -149,6 +181,9 @@ impl Object for ApubPost {
       audience: Some(community.actor_id.into()),
       in_reply_to: None,
       tag: vec![hashtag],
+      one_of: poll_options.clone().filter(|_| !multiple_choice),
+      any_of: poll_options.filter(|_| multiple_choice),
+      end_time: poll.and_then(|p| p.end_time),
     };
     Ok(page)
   }
@@ -169,6 +204,9 @@ impl Object for ApubPost {
     let local_site_data = local_site_data_cached(&mut context.pool()).await?;
     let slur_regex = &local_site_opt_to_slur_regex(&local_site_data.local_site);
     check_slurs_opt(&page.name, slur_regex)?;
+    for option in page.one_of.iter().chain(page.any_of.iter()).flatten() {
+      check_slurs(&option.name, slur_regex)?;
+    }
 
     verify_domains_match(page.creator()?.inner(), page.id.inner())?;
     verify_visibility(&page.to, &page.cc, &community)?;
@@ -262,6 +300,39 @@ impl Object for ApubPost {
 
     let timestamp = page.updated.or(page.published).unwrap_or_else(naive_now);
     let post = Post::insert_apub(&mut context.pool(), timestamp, &form).await?;
+
+    if let Some(options) = page.one_of.clone().or(page.any_of.clone()) {
+      let options = options
+        .into_iter()
+        .take(MAX_POLL_OPTIONS)
+        .map(|o| (o.name, o.replies.total_items))
+        .collect();
+      let poll_form = PollInsertForm {
+        end_time: page.end_time,
+        ..PollInsertForm::new(post.id, page.any_of.is_some())
+      };
+      Poll::upsert_with_vote_counts(&mut context.pool(), &poll_form, options).await?;
+    }
+
+    // Further image attachments make up the gallery. Blocked or invalid urls are left out.
+    let gallery = page
+      .attachment
+      .iter()
+      .skip(1)
+      .filter(|a| a.is_image())
+      .take(MAX_GALLERY_IMAGES)
+      .cloned()
+      .filter_map(|a| {
+        let url = a.clone().url();
+        let valid = is_url_blocked(&url, &url_blocklist).is_ok() && is_valid_url(&url).is_ok();
+        valid.then(|| PostMediaForm {
+          alt_text: a.alt_text(),
+          ..PostMediaForm::new(url.into())
+        })
+      })
+      .collect();
+    PostMedia::replace_for_post(&mut context.pool(), post.id, gallery).await?;
+
     let post_ = post.clone();
     let context_ = context.reset_request_count();
 
@@ -286,6 +357,7 @@ mod tests {
     protocol::tests::file_to_json_object,
   };
   use lemmy_db_schema::source::site::Site;
+  use lemmy_utils::error::LemmyErrorType;
   use pretty_assertions::assert_eq;
   use serial_test::serial;
 
@@ -335,4 +407,43 @@ mod tests {
     Community::delete(&mut context.pool(), community.id).await?;
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_parse_mastodon_poll() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let community = parse_lemmy_community(&context).await?;
+
+    let json = file_to_json_object("assets/mastodon/objects/person.json")?;
+    let person = ApubPerson::from_json(json, &context).await?;
+
+    let json = file_to_json_object("assets/mastodon/objects/question.json")?;
+    let post = ApubPost::from_json(json, &context).await?;
+
+    assert_eq!(post.name, "Which generator should I work on next?");
+    let poll = Poll::read_for_post(&mut context.pool(), post.id)
+      .await?
+      .ok_or(LemmyErrorType::NotFound)?;
+    assert!(!poll.multiple_choice);
+    let options = PollOption::list_for_poll(&mut context.pool(), poll.id)
+      .await?
+      .into_iter()
+      .map(|o| (o.name, o.vote_count))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      vec![("Characters".to_string(), 5), ("Dungeons".to_string(), 2)],
+      options
+    );
+
+    // The poll is sent back out as Question
+    let page = post.clone().into_json(&context).await?;
+    assert_eq!(PageType::Question, page.kind);
+    assert_eq!(Some(2), page.one_of.map(|o| o.len()));
+    assert!(page.any_of.is_none());
+
+    Post::delete(&mut context.pool(), post.id).await?;
+    Person::delete(&mut context.pool(), person.id).await?;
+    Community::delete(&mut context.pool(), community.id).await?;
+    Ok(())
+  }
 }
@@ -2,7 +2,7 @@ use super::verify_is_remote_object;
 use crate::{
   check_apub_id_valid_with_strictness,
   fetcher::markdown_links::markdown_rewrite_remote_links,
-  objects::read_from_string_or_source,
+  objects::{person::ApubPerson, read_from_string_or_source},
   protocol::{
     objects::chat_message::{ChatMessage, ChatMessageType},
     Source,
@@ -10,6 +10,7 @@ use crate::{
 };
 use activitypub_federation::{
   config::Data,
+  fetch::object_id::ObjectId,
   protocol::{values::MediaTypeHtml, verification::verify_domains_match},
   traits::Object,
 };
@@ -17,8 +18,10 @@ use chrono::{DateTime, Utc};
 use lemmy_api_common::{
   context::LemmyContext,
   utils::{
+    check_private_messages_allowed,
     check_private_messages_enabled,
     get_url_blocklist,
+    is_private_message_request,
     local_site_opt_to_slur_regex,
     process_markdown,
   },
@@ -28,6 +31,7 @@ use lemmy_db_schema::{
     local_site::LocalSite,
     person::Person,
     person_block::PersonBlock,
+    pm_conversation::{PmConversation, PmConversationParticipant, PmGroupConversationInsertForm},
     private_message::{PrivateMessage, PrivateMessageInsertForm},
   },
   traits::Crud,
@@ -37,6 +41,7 @@ use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::{
   error::{FederationError, LemmyError, LemmyErrorType, LemmyResult},
   utils::markdown::markdown_to_html,
+  MAX_PM_GROUP_PARTICIPANTS,
 };
 use std::ops::Deref;
 use url::Url;
@@ -89,19 +94,19 @@ impl Object for ApubPrivateMessage {
     let creator_id = self.creator_id;
     let creator = Person::read(&mut context.pool(), creator_id).await?;
 
-    let recipient_id = self.recipient_id;
-    let recipient = Person::read(&mut context.pool(), recipient_id).await?;
+    let (recipients, conversation) = pm_recipients(&self, context).await?;
 
     let note = ChatMessage {
       r#type: ChatMessageType::ChatMessage,
       id: self.ap_id.clone().into(),
       attributed_to: creator.actor_id.into(),
-      to: [recipient.actor_id.into()],
+      to: recipients.into_iter().map(|r| r.actor_id.into()).collect(),
       content: markdown_to_html(&self.content),
       media_type: Some(MediaTypeHtml::Html),
       source: Some(Source::new(self.content.clone())),
       published: Some(self.published),
       updated: self.updated,
+      context: conversation.and_then(|c| c.ap_id).map(Into::into),
     };
     Ok(note)
   }
@@ -133,15 +138,32 @@ impl Object for ApubPrivateMessage {
     context: &Data<Self::DataType>,
   ) -> LemmyResult<ApubPrivateMessage> {
     let creator = note.attributed_to.dereference(context).await?;
-    let recipient = note.to[0].dereference(context).await?;
-    PersonBlock::read(&mut context.pool(), recipient.id, creator.id).await?;
+    let mut is_request = false;
+    let (recipient_id, conversation_id) = if let Some(group_id) = &note.context {
+      let conversation = receive_group_conversation(group_id, &creator, &note.to, context).await?;
+      (None, Some(conversation.id))
+    } else {
+      let recipient = note
+        .to
+        .first()
+        .ok_or(LemmyErrorType::InvalidPrivateMessageTarget)?
+        .dereference(context)
+        .await?;
+      PersonBlock::read(&mut context.pool(), recipient.id, creator.id).await?;
 
-    // Check that they can receive private messages
-    if let Ok(recipient_local_user) =
-      LocalUserView::read_person(&mut context.pool(), recipient.id).await
-    {
-      check_private_messages_enabled(&recipient_local_user)?;
-    }
+      // Check that they can receive private messages
+      if let Ok(recipient_local_user) =
+        LocalUserView::read_person(&mut context.pool(), recipient.id).await
+      {
+        check_private_messages_enabled(&recipient_local_user)?;
+        check_private_messages_allowed(creator.id, &recipient_local_user, &mut context.pool())
+          .await?;
+        is_request =
+          is_private_message_request(creator.id, &recipient_local_user, &mut context.pool())
+            .await?;
+      }
+      (Some(recipient.id), None)
+    };
     let local_site = LocalSite::read(&mut context.pool()).await.ok();
     let slur_regex = &local_site_opt_to_slur_regex(&local_site);
     let url_blocklist = get_url_blocklist(context).await?;
@@ -152,7 +174,7 @@ impl Object for ApubPrivateMessage {
 
     let form = PrivateMessageInsertForm {
       creator_id: creator.id,
-      recipient_id: recipient.id,
+      recipient_id,
       content,
       published: note.published.map(Into::into),
       updated: note.updated.map(Into::into),
@@ -160,13 +182,94 @@ impl Object for ApubPrivateMessage {
       read: None,
       ap_id: Some(note.id.into()),
       local: Some(false),
+      conversation_id,
     };
     let timestamp = note.updated.or(note.published).unwrap_or_else(naive_now);
     let pm = PrivateMessage::insert_apub(&mut context.pool(), timestamp, &form).await?;
+    if let (true, Some(recipient_id)) = (is_request, pm.recipient_id) {
+      PmConversation::mark_as_request(&mut context.pool(), pm.conversation_id, recipient_id)
+        .await?;
+    }
     Ok(pm.into())
   }
 }
 
+/// Returns the recipients of a private message, which are all participants except the creator
+/// for group messages. The group conversation is also returned.
+pub(crate) async fn pm_recipients(
+  pm: &PrivateMessage,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<(Vec<Person>, Option<PmConversation>)> {
+  if let Some(recipient_id) = pm.recipient_id {
+    let recipient = Person::read(&mut context.pool(), recipient_id).await?;
+    return Ok((vec![recipient], None));
+  }
+  let conversation = PmConversation::read(&mut context.pool(), pm.conversation_id).await?;
+  let mut recipients = conversation.list_participants(&mut context.pool()).await?;
+  recipients.retain(|p| p.id != pm.creator_id);
+  Ok((recipients, Some(conversation)))
+}
+
+/// Finds the group conversation of a received message, or creates it if the group is hosted on
+/// another instance. Participants of remote groups are added as they appear in messages, while
+/// the participants of local groups can only be changed by their creator.
+async fn receive_group_conversation(
+  group_id: &Url,
+  creator: &ApubPerson,
+  to: &[ObjectId<ApubPerson>],
+  context: &Data<LemmyContext>,
+) -> LemmyResult<PmConversation> {
+  let is_local = group_id.domain() == Some(context.settings().hostname.as_str());
+  let conversation =
+    match PmConversation::read_from_apub_id(&mut context.pool(), group_id.clone()).await? {
+      Some(c) => c,
+      None if is_local => Err(LemmyErrorType::NotFound)?,
+      None => {
+        let form = PmGroupConversationInsertForm {
+          ap_id: Some(group_id.clone().into()),
+          local: Some(false),
+          ..PmGroupConversationInsertForm::new(None)
+        };
+        PmConversation::create_group(&mut context.pool(), &form, &[]).await?
+      }
+    };
+  if !conversation.is_group {
+    Err(LemmyErrorType::PmConversationNotAGroup)?
+  }
+
+  if conversation.local {
+    if !conversation
+      .is_participant(&mut context.pool(), creator.id)
+      .await?
+    {
+      Err(LemmyErrorType::NotPmConversationParticipant)?
+    }
+  } else {
+    let mut participant_ids = vec![creator.id];
+    for person in to.iter().take(MAX_PM_GROUP_PARTICIPANTS) {
+      let person = person.dereference(context).await?;
+      // Dont add persons to the group who blocked its creator, or who don't accept messages from
+      // them
+      let blocked = PersonBlock::read(&mut context.pool(), person.id, creator.id)
+        .await
+        .is_err();
+      let not_allowed = match LocalUserView::read_person(&mut context.pool(), person.id).await {
+        Ok(local_user_view) => {
+          check_private_messages_allowed(creator.id, &local_user_view, &mut context.pool())
+            .await
+            .is_err()
+        }
+        Err(_) => false,
+      };
+      if !blocked && !not_allowed {
+        participant_ids.push(person.id);
+      }
+    }
+    PmConversationParticipant::add(&mut context.pool(), conversation.id, &participant_ids).await?;
+  }
+  Ok(conversation)
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
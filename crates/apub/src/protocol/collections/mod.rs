@@ -3,6 +3,7 @@ pub(crate) mod group_featured;
 pub(crate) mod group_followers;
 pub(crate) mod group_moderators;
 pub(crate) mod group_outbox;
+pub(crate) mod person_featured;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,14 @@
+use crate::fetcher::post_or_comment::PageOrNote;
+use activitypub_federation::kinds::collection::OrderedCollectionType;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Posts and comments which a person pinned to their profile.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonFeatured {
+  pub(crate) r#type: OrderedCollectionType,
+  pub(crate) id: Url,
+  pub(crate) total_items: i32,
+  pub(crate) ordered_items: Vec<PageOrNote>,
+}
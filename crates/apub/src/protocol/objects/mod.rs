@@ -123,6 +123,7 @@ mod tests {
     test_parse_lemmy_item::<Page>("assets/lemmy/objects/page.json")?;
     test_parse_lemmy_item::<Note>("assets/lemmy/objects/note.json")?;
     test_parse_lemmy_item::<ChatMessage>("assets/lemmy/objects/chat_message.json")?;
+    test_parse_lemmy_item::<ChatMessage>("assets/lemmy/objects/group_chat_message.json")?;
     test_parse_lemmy_item::<Tombstone>("assets/lemmy/objects/tombstone.json")?;
     Ok(())
   }
@@ -148,6 +149,7 @@ mod tests {
     test_json::<Note>("assets/mastodon/objects/note_1.json")?;
     test_json::<Note>("assets/mastodon/objects/note_2.json")?;
     test_json::<Page>("assets/mastodon/objects/page.json")?;
+    test_json::<Page>("assets/mastodon/objects/question.json")?;
     Ok(())
   }
 
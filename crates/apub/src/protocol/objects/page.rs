@@ -8,8 +8,9 @@ use activitypub_federation::{
   config::Data,
   fetch::object_id::ObjectId,
   kinds::{
+    collection::CollectionType,
     link::LinkType,
-    object::{DocumentType, ImageType},
+    object::{DocumentType, ImageType, NoteType},
   },
   protocol::{
     helpers::{deserialize_one_or_many, deserialize_skip_error},
@@ -32,6 +33,7 @@ pub enum PageType {
   Note,
   Video,
   Event,
+  Question,
 }
 
 #[skip_serializing_none]
@@ -67,6 +69,44 @@ pub struct Page {
   pub(crate) audience: Option<ObjectId<ApubCommunity>>,
   #[serde(deserialize_with = "deserialize_skip_error", default)]
   pub(crate) tag: Vec<Hashtag>,
+  /// Poll options, if only one of them can be picked
+  pub(crate) one_of: Option<Vec<QuestionOption>>,
+  /// Poll options, if several of them can be picked
+  pub(crate) any_of: Option<Vec<QuestionOption>>,
+  /// Time when the poll closes
+  pub(crate) end_time: Option<DateTime<Utc>>,
+}
+
+/// A poll option of a Question, in the format used by Mastodon.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct QuestionOption {
+  #[serde(rename = "type")]
+  pub(crate) kind: NoteType,
+  pub(crate) name: String,
+  pub(crate) replies: QuestionReplies,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct QuestionReplies {
+  #[serde(rename = "type")]
+  pub(crate) kind: CollectionType,
+  /// The number of votes for the option
+  pub(crate) total_items: i32,
+}
+
+impl QuestionOption {
+  pub(crate) fn new(name: String, vote_count: i32) -> Self {
+    QuestionOption {
+      kind: Default::default(),
+      name,
+      replies: QuestionReplies {
+        kind: Default::default(),
+        total_items: vote_count,
+      },
+    }
+  }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -126,6 +166,17 @@ impl Attachment {
     }
   }
 
+  pub(crate) fn is_image(&self) -> bool {
+    let media_type = match self {
+      Attachment::Image(_) => return true,
+      Attachment::Document(d) => &d.media_type,
+      Attachment::Link(l) => &l.media_type,
+    };
+    media_type
+      .as_deref()
+      .is_some_and(|m| m.starts_with("image"))
+  }
+
   pub(crate) async fn as_markdown(&self, context: &Data<LemmyContext>) -> LemmyResult<String> {
     let (url, name, media_type) = match self {
       Attachment::Image(i) => (i.url.clone(), i.name.clone(), Some(String::from("image"))),
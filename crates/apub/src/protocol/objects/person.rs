@@ -1,9 +1,10 @@
 use crate::{
+  collections::person_featured::ApubPersonFeatured,
   objects::person::ApubPerson,
   protocol::{objects::Endpoints, ImageObject, Source},
 };
 use activitypub_federation::{
-  fetch::object_id::ObjectId,
+  fetch::{collection_id::CollectionId, object_id::ObjectId},
   protocol::{helpers::deserialize_skip_error, public_key::PublicKey},
 };
 use chrono::{DateTime, Utc};
@@ -46,4 +47,6 @@ pub struct Person {
   pub(crate) endpoints: Option<Endpoints>,
   pub(crate) published: Option<DateTime<Utc>>,
   pub(crate) updated: Option<DateTime<Utc>>,
+  /// posts and comments pinned to the profile
+  pub(crate) featured: Option<CollectionId<ApubPersonFeatured>>,
 }
@@ -606,6 +606,24 @@ BEGIN
     IF NEW.local THEN
         NEW.ap_id = coalesce(NEW.ap_id, r.local_url ('/private_message/' || NEW.id::text));
     END IF;
+    IF NEW.conversation_id IS NULL THEN
+        -- Add the message to the conversation between both persons, creating it if necessary
+        INSERT INTO pm_conversation (person_a_id, person_b_id, last_message_published)
+            VALUES (least (NEW.creator_id, NEW.recipient_id), greatest (NEW.creator_id, NEW.recipient_id), NEW.published)
+        ON CONFLICT (person_a_id, person_b_id)
+            DO UPDATE SET
+                last_message_published = greatest (pm_conversation.last_message_published, excluded.last_message_published)
+            RETURNING
+                id INTO NEW.conversation_id;
+    ELSE
+        -- Group messages are inserted with the conversation already set
+        UPDATE
+            pm_conversation
+        SET
+            last_message_published = greatest (last_message_published, NEW.published)
+        WHERE
+            id = NEW.conversation_id;
+    END IF;
     RETURN NEW;
 END
 $$;
@@ -615,3 +633,21 @@ CREATE TRIGGER change_values
     FOR EACH ROW
     EXECUTE FUNCTION r.private_message_change_values ();
 
+
+CREATE FUNCTION r.pm_conversation_change_values ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    -- Set local ap_id of group conversations
+    IF NEW.local AND NEW.is_group THEN
+        NEW.ap_id = coalesce(NEW.ap_id, r.local_url ('/private_message_group/' || NEW.id::text));
+    END IF;
+    RETURN NEW;
+END
+$$;
+
+CREATE TRIGGER change_values
+    BEFORE INSERT ON pm_conversation
+    FOR EACH ROW
+    EXECUTE FUNCTION r.pm_conversation_change_values ();
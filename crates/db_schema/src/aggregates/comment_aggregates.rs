@@ -4,6 +4,7 @@ use crate::{
   schema::comment_aggregates,
   utils::{functions::hot_rank, get_conn, DbPool},
 };
+use chrono::{DateTime, Utc};
 use diesel::{result::Error, ExpressionMethods, QueryDsl};
 use diesel_async::RunQueryDsl;
 
@@ -16,6 +17,7 @@ impl CommentAggregates {
   pub async fn update_hot_rank(
     pool: &mut DbPool<'_>,
     comment_id: CommentId,
+    now: DateTime<Utc>,
   ) -> Result<Self, Error> {
     let conn = &mut get_conn(pool).await?;
 
@@ -23,6 +25,7 @@ impl CommentAggregates {
       .set(comment_aggregates::hot_rank.eq(hot_rank(
         comment_aggregates::score,
         comment_aggregates::published,
+        now,
       )))
       .get_result::<Self>(conn)
       .await
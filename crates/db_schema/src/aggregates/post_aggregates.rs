@@ -8,6 +8,7 @@ use crate::{
     DbPool,
   },
 };
+use chrono::{DateTime, Utc};
 use diesel::{result::Error, ExpressionMethods, JoinOnDsl, QueryDsl};
 use diesel_async::RunQueryDsl;
 
@@ -17,7 +18,11 @@ impl PostAggregates {
     post_aggregates::table.find(post_id).first(conn).await
   }
 
-  pub async fn update_ranks(pool: &mut DbPool<'_>, post_id: PostId) -> Result<Self, Error> {
+  pub async fn update_ranks(
+    pool: &mut DbPool<'_>,
+    post_id: PostId,
+    now: DateTime<Utc>,
+  ) -> Result<Self, Error> {
     let conn = &mut get_conn(pool).await?;
 
     // Diesel can't update based on a join, which is necessary for the scaled_rank
@@ -32,15 +37,21 @@ impl PostAggregates {
 
     diesel::update(post_aggregates::table.find(post_id))
       .set((
-        post_aggregates::hot_rank.eq(hot_rank(post_aggregates::score, post_aggregates::published)),
+        post_aggregates::hot_rank.eq(hot_rank(
+          post_aggregates::score,
+          post_aggregates::published,
+          now,
+        )),
         post_aggregates::hot_rank_active.eq(hot_rank(
           post_aggregates::score,
           post_aggregates::newest_comment_time_necro,
+          now,
         )),
         post_aggregates::scaled_rank.eq(scaled_rank(
           post_aggregates::score,
           post_aggregates::published,
           users_active_month,
+          now,
         )),
       ))
       .get_result::<Self>(conn)
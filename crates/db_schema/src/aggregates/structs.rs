@@ -92,6 +92,10 @@ pub struct PersonAggregates {
   pub comment_count: i64,
   #[serde(skip)]
   pub comment_score: i64,
+  /// Post and comment score where older votes count less. Only computed if the site has a
+  /// reputation half-life.
+  #[serde(skip)]
+  pub reputation: i64,
 }
 
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
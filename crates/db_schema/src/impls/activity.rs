@@ -1,7 +1,13 @@
 use crate::{
   diesel::OptionalExtension,
   newtypes::{ActivityId, DbUrl},
-  source::activity::{ReceivedActivity, SentActivity, SentActivityForm},
+  source::activity::{
+    QueuedActivity,
+    QueuedActivityForm,
+    ReceivedActivity,
+    SentActivity,
+    SentActivityForm,
+  },
   utils::{get_conn, DbPool},
 };
 use diesel::{
@@ -57,6 +63,32 @@ impl ReceivedActivity {
   }
 }
 
+impl QueuedActivity {
+  pub async fn create(pool: &mut DbPool<'_>, form: &QueuedActivityForm) -> Result<Self, Error> {
+    use crate::schema::queued_activity::dsl::queued_activity;
+    let conn = &mut get_conn(pool).await?;
+    insert_into(queued_activity)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// The oldest queued activities, so that they are processed in the order they were received.
+  pub async fn list_oldest(pool: &mut DbPool<'_>, limit: i64) -> Result<Vec<Self>, Error> {
+    use crate::schema::queued_activity::dsl::{id, queued_activity};
+    let conn = &mut get_conn(pool).await?;
+    queued_activity.order_by(id).limit(limit).load(conn).await
+  }
+
+  pub async fn delete(pool: &mut DbPool<'_>, queued_id: i32) -> Result<usize, Error> {
+    use crate::schema::queued_activity::dsl::queued_activity;
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(queued_activity.find(queued_id))
+      .execute(conn)
+      .await
+  }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -115,4 +147,41 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn queued_activity_order() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let actor_id: DbUrl = Url::parse("http://example.com/u/exampleuser")?.into();
+
+    let first = QueuedActivity::create(
+      pool,
+      &QueuedActivityForm {
+        actor_id: actor_id.clone(),
+        data: json!({"id": "http://example.com/activity/1"}),
+      },
+    )
+    .await?;
+    let second = QueuedActivity::create(
+      pool,
+      &QueuedActivityForm {
+        actor_id,
+        data: json!({"id": "http://example.com/activity/2"}),
+      },
+    )
+    .await?;
+
+    let queued = QueuedActivity::list_oldest(pool, 10).await?;
+    assert_eq!(
+      vec![first.id, second.id],
+      queued.iter().map(|q| q.id).collect::<Vec<_>>()
+    );
+
+    QueuedActivity::delete(pool, first.id).await?;
+    QueuedActivity::delete(pool, second.id).await?;
+    assert!(QueuedActivity::list_oldest(pool, 10).await?.is_empty());
+
+    Ok(())
+  }
 }
@@ -0,0 +1,64 @@
+use crate::{
+  newtypes::{BanAppealId, CommunityId, PersonId},
+  schema::ban_appeal,
+  source::ban_appeal::{BanAppeal, BanAppealInsertForm, BanAppealUpdateForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use chrono::{DateTime, Utc};
+use diesel::{
+  dsl::{exists, insert_into},
+  result::Error,
+  select,
+  ExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for BanAppeal {
+  type InsertForm = BanAppealInsertForm;
+  type UpdateForm = BanAppealUpdateForm;
+  type IdType = BanAppealId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(ban_appeal::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    id: BanAppealId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(ban_appeal::table.find(id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl BanAppeal {
+  /// Checks if the person already appealed a ban from the community which started at the given
+  /// time. Each ban can only be appealed once.
+  pub async fn exists_for_ban(
+    pool: &mut DbPool<'_>,
+    creator_id: PersonId,
+    community_id: CommunityId,
+    banned_since: DateTime<Utc>,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    select(exists(
+      ban_appeal::table
+        .filter(ban_appeal::creator_id.eq(creator_id))
+        .filter(ban_appeal::community_id.eq(community_id))
+        .filter(ban_appeal::published.ge(banned_since)),
+    ))
+    .get_result(conn)
+    .await
+  }
+}
@@ -0,0 +1,481 @@
+use crate::{
+  newtypes::{BanListId, CommunityId, PersonId},
+  schema::{ban_list, ban_list_community, ban_list_entry, ban_list_exemption, community_actions},
+  source::ban_list::{
+    BanList,
+    BanListCommunity,
+    BanListCommunityForm,
+    BanListEntry,
+    BanListEntryForm,
+    BanListExemption,
+    BanListExemptionForm,
+    BanListInsertForm,
+    BanListUpdateForm,
+  },
+  traits::Crud,
+  utils::{get_conn, now, uplete, DbPool},
+};
+use chrono::{DateTime, Utc};
+use diesel::{
+  dsl::{exists, insert_into, not},
+  result::Error,
+  select,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  NullableExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for BanList {
+  type InsertForm = BanListInsertForm;
+  type UpdateForm = BanListUpdateForm;
+  type IdType = BanListId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(ban_list::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(ban_list::table.find(ban_list_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl BanList {
+  /// All ban lists, or only those a community is subscribed to.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    community_id: Option<CommunityId>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = ban_list::table.into_boxed();
+    if let Some(community_id) = community_id {
+      query = query.filter(
+        ban_list::id.eq_any(
+          ban_list_community::table
+            .filter(ban_list_community::community_id.eq(community_id))
+            .select(ban_list_community::ban_list_id),
+        ),
+      );
+    }
+    query.order_by(ban_list::name).load::<Self>(conn).await
+  }
+
+  /// The creator of a list and the moderators of its subscribed communities can manage it.
+  pub async fn is_manager(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+    person_id: PersonId,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let is_creator = ban_list::table
+      .find(ban_list_id)
+      .filter(ban_list::creator_id.eq(person_id));
+    let is_team_mod = community_actions::table
+      .filter(community_actions::person_id.eq(person_id))
+      .filter(community_actions::became_moderator.is_not_null())
+      .filter(
+        community_actions::community_id.eq_any(
+          ban_list_community::table
+            .filter(ban_list_community::ban_list_id.eq(ban_list_id))
+            .select(ban_list_community::community_id),
+        ),
+      );
+    select(exists(is_creator).or(exists(is_team_mod)))
+      .get_result(conn)
+      .await
+  }
+
+  /// Ban a person from a community on behalf of the list. A direct ban by the community's
+  /// moderators takes precedence and is left untouched.
+  pub async fn apply_ban(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+    community_id: CommunityId,
+    person_id: PersonId,
+    expires: Option<DateTime<Utc>>,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let direct_ban = community_actions::table
+      .find((person_id, community_id))
+      .filter(community_actions::received_ban.is_not_null())
+      .filter(community_actions::ban_list_id.is_null());
+    if select(exists(direct_ban)).get_result(conn).await? {
+      return Ok(false);
+    }
+
+    let form = (
+      community_actions::community_id.eq(community_id),
+      community_actions::person_id.eq(person_id),
+      community_actions::received_ban.eq(now().nullable()),
+      community_actions::ban_expires.eq(expires),
+      community_actions::ban_list_id.eq(Some(ban_list_id)),
+      community_actions::ban_reason.eq(None::<String>),
+      community_actions::ban_appeal.eq(None::<String>),
+    );
+    insert_into(community_actions::table)
+      .values(form)
+      .on_conflict((
+        community_actions::person_id,
+        community_actions::community_id,
+      ))
+      .do_update()
+      .set(form)
+      .execute(conn)
+      .await?;
+    Ok(true)
+  }
+
+  /// Lift a ban which this list applied in a community.
+  pub async fn lift_ban(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+    community_id: CommunityId,
+    person_id: PersonId,
+  ) -> Result<uplete::Count, Error> {
+    let conn = &mut get_conn(pool).await?;
+    uplete::new(
+      community_actions::table
+        .find((person_id, community_id))
+        .filter(community_actions::ban_list_id.eq(ban_list_id)),
+    )
+    .set_null(community_actions::received_ban)
+    .set_null(community_actions::ban_expires)
+    .set_null(community_actions::ban_list_id)
+    .set_null(community_actions::ban_reason)
+    .set_null(community_actions::ban_appeal)
+    .get_result(conn)
+    .await
+  }
+
+  /// The subscribed communities which haven't exempted the person.
+  pub async fn communities_to_ban(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+    person_id: PersonId,
+  ) -> Result<Vec<CommunityId>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    ban_list_community::table
+      .filter(ban_list_community::ban_list_id.eq(ban_list_id))
+      .filter(not(
+        ban_list_community::community_id.eq_any(
+          ban_list_exemption::table
+            .filter(ban_list_exemption::ban_list_id.eq(ban_list_id))
+            .filter(ban_list_exemption::person_id.eq(person_id))
+            .select(ban_list_exemption::community_id),
+        ),
+      ))
+      .select(ban_list_community::community_id)
+      .load::<CommunityId>(conn)
+      .await
+  }
+
+  /// The entries which apply to a community, ie without those it has exempted.
+  pub async fn entries_to_apply(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+    community_id: CommunityId,
+  ) -> Result<Vec<BanListEntry>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    ban_list_entry::table
+      .filter(ban_list_entry::ban_list_id.eq(ban_list_id))
+      .filter(not(
+        ban_list_entry::person_id.eq_any(
+          ban_list_exemption::table
+            .filter(ban_list_exemption::ban_list_id.eq(ban_list_id))
+            .filter(ban_list_exemption::community_id.eq(community_id))
+            .select(ban_list_exemption::person_id),
+        ),
+      ))
+      .load::<BanListEntry>(conn)
+      .await
+  }
+}
+
+impl BanListCommunity {
+  pub async fn subscribe(
+    pool: &mut DbPool<'_>,
+    form: &BanListCommunityForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(ban_list_community::table)
+      .values(form)
+      .on_conflict((
+        ban_list_community::ban_list_id,
+        ban_list_community::community_id,
+      ))
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn unsubscribe(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+    community_id: CommunityId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(ban_list_community::table.find((ban_list_id, community_id)))
+      .execute(conn)
+      .await
+  }
+
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+    community_id: CommunityId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    ban_list_community::table
+      .find((ban_list_id, community_id))
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  pub async fn list_for_ban_list(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    ban_list_community::table
+      .filter(ban_list_community::ban_list_id.eq(ban_list_id))
+      .order_by(ban_list_community::published)
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl BanListEntry {
+  /// Add a person to the list, or update the reason and expiry if they are already on it.
+  pub async fn upsert(pool: &mut DbPool<'_>, form: &BanListEntryForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(ban_list_entry::table)
+      .values(form)
+      .on_conflict((ban_list_entry::ban_list_id, ban_list_entry::person_id))
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+    person_id: PersonId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    ban_list_entry::table
+      .find((ban_list_id, person_id))
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+    person_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(ban_list_entry::table.find((ban_list_id, person_id)))
+      .execute(conn)
+      .await
+  }
+
+  pub async fn list_for_ban_list(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    ban_list_entry::table
+      .filter(ban_list_entry::ban_list_id.eq(ban_list_id))
+      .order_by(ban_list_entry::published.desc())
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl BanListExemption {
+  pub async fn create(pool: &mut DbPool<'_>, form: &BanListExemptionForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(ban_list_exemption::table)
+      .values(form)
+      .on_conflict((
+        ban_list_exemption::ban_list_id,
+        ban_list_exemption::community_id,
+        ban_list_exemption::person_id,
+      ))
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn delete(pool: &mut DbPool<'_>, form: &BanListExemptionForm) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(ban_list_exemption::table.find((
+      form.ban_list_id,
+      form.community_id,
+      form.person_id,
+    )))
+    .execute(conn)
+    .await
+  }
+
+  pub async fn list_for_ban_list(
+    pool: &mut DbPool<'_>,
+    ban_list_id: BanListId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    ban_list_exemption::table
+      .filter(ban_list_exemption::ban_list_id.eq(ban_list_id))
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    schema::community_actions,
+    source::{
+      ban_list::{
+        BanList,
+        BanListCommunity,
+        BanListCommunityForm,
+        BanListEntry,
+        BanListEntryForm,
+        BanListExemption,
+        BanListExemptionForm,
+        BanListInsertForm,
+      },
+      community::{Community, CommunityInsertForm, CommunityPersonBan, CommunityPersonBanForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    traits::{Bannable, Crud},
+    utils::{build_db_pool_for_tests, get_conn},
+  };
+  use diesel::{ExpressionMethods, QueryDsl};
+  use diesel_async::RunQueryDsl;
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_ban_list_propagation() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let mod_form = PersonInsertForm::test_form(inserted_instance.id, "ban_list_mod");
+    let inserted_mod = Person::create(pool, &mod_form).await?;
+    let troll_form = PersonInsertForm::test_form(inserted_instance.id, "ban_list_troll");
+    let inserted_troll = Person::create(pool, &troll_form).await?;
+
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "ban_list_a".into(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community_a = Community::create(pool, &community_form).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "ban_list_b".into(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community_b = Community::create(pool, &community_form).await?;
+
+    let ban_list = BanList::create(
+      pool,
+      &BanListInsertForm::new("shared".into(), inserted_mod.id),
+    )
+    .await?;
+    assert!(BanList::is_manager(pool, ban_list.id, inserted_mod.id).await?);
+    assert!(!BanList::is_manager(pool, ban_list.id, inserted_troll.id).await?);
+
+    for community_id in [community_a.id, community_b.id] {
+      let form = BanListCommunityForm {
+        ban_list_id: ban_list.id,
+        community_id,
+      };
+      BanListCommunity::subscribe(pool, &form).await?;
+    }
+
+    let entry_form = BanListEntryForm {
+      ban_list_id: ban_list.id,
+      person_id: inserted_troll.id,
+      creator_id: inserted_mod.id,
+      reason: None,
+      expires: None,
+    };
+    BanListEntry::upsert(pool, &entry_form).await?;
+
+    // Community b opts out of the ban
+    let exemption_form = BanListExemptionForm {
+      ban_list_id: ban_list.id,
+      community_id: community_b.id,
+      person_id: inserted_troll.id,
+    };
+    BanListExemption::create(pool, &exemption_form).await?;
+    let to_ban = BanList::communities_to_ban(pool, ban_list.id, inserted_troll.id).await?;
+    assert_eq!(vec![community_a.id], to_ban);
+    let entries = BanList::entries_to_apply(pool, ban_list.id, community_b.id).await?;
+    assert!(entries.is_empty());
+
+    assert!(BanList::apply_ban(pool, ban_list.id, community_a.id, inserted_troll.id, None).await?);
+    let banned_by_list = community_actions::table
+      .find((inserted_troll.id, community_a.id))
+      .select(community_actions::ban_list_id)
+      .first::<Option<_>>(&mut get_conn(pool).await?)
+      .await?;
+    assert_eq!(Some(ban_list.id), banned_by_list);
+
+    // Lifting the list ban doesn't touch a direct ban
+    let direct_ban_form = CommunityPersonBanForm {
+      community_id: community_a.id,
+      person_id: inserted_troll.id,
+      expires: None,
+      reason: None,
+      appeal: None,
+    };
+    CommunityPersonBan::ban(pool, &direct_ban_form).await?;
+    assert!(!BanList::apply_ban(pool, ban_list.id, community_a.id, inserted_troll.id, None).await?);
+    let lifted = BanList::lift_ban(pool, ban_list.id, community_a.id, inserted_troll.id).await?;
+    assert_eq!(0, lifted.updated + lifted.deleted);
+    let still_banned = community_actions::table
+      .find((inserted_troll.id, community_a.id))
+      .filter(community_actions::received_ban.is_not_null())
+      .count()
+      .get_result::<i64>(&mut get_conn(pool).await?)
+      .await?;
+    assert_eq!(1, still_banned);
+
+    Person::delete(pool, inserted_mod.id).await?;
+    Person::delete(pool, inserted_troll.id).await?;
+    Community::delete(pool, community_a.id).await?;
+    Community::delete(pool, community_b.id).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
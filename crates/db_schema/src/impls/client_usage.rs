@@ -0,0 +1,145 @@
+use crate::{
+  schema::{client_restriction, client_usage},
+  source::client_usage::{ClientRestriction, ClientRestrictionForm, ClientUsage, ClientUsageForm},
+  utils::{get_conn, DbPool},
+};
+use chrono::{DateTime, Utc};
+use diesel::{dsl::insert_into, result::Error, upsert::excluded, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl ClientUsage {
+  /// Adds the given request counts to the existing counts of the same client and day.
+  pub async fn increment(pool: &mut DbPool<'_>, forms: &[ClientUsageForm]) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(client_usage::table)
+      .values(forms)
+      .on_conflict((client_usage::client, client_usage::day))
+      .do_update()
+      .set(
+        client_usage::request_count
+          .eq(client_usage::request_count + excluded(client_usage::request_count)),
+      )
+      .execute(conn)
+      .await
+  }
+
+  /// Usage since the given time, newest days and busiest clients first.
+  pub async fn list(pool: &mut DbPool<'_>, since: DateTime<Utc>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    client_usage::table
+      .filter(client_usage::day.ge(since))
+      .order_by((client_usage::day.desc(), client_usage::request_count.desc()))
+      .load(conn)
+      .await
+  }
+
+  pub async fn delete_older_than(
+    pool: &mut DbPool<'_>,
+    before: DateTime<Utc>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(client_usage::table.filter(client_usage::day.lt(before)))
+      .execute(conn)
+      .await
+  }
+}
+
+impl ClientRestriction {
+  /// Creates the restriction, or replaces an existing one for the same client.
+  pub async fn upsert(pool: &mut DbPool<'_>, form: &ClientRestrictionForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(client_restriction::table)
+      .values(form)
+      .on_conflict(client_restriction::client)
+      .do_update()
+      .set((
+        client_restriction::requests_per_minute.eq(form.requests_per_minute),
+        client_restriction::reason.eq(form.reason.clone()),
+      ))
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn delete(pool: &mut DbPool<'_>, client: &str) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(client_restriction::table.find(client))
+      .execute(conn)
+      .await
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    client_restriction::table
+      .order_by(client_restriction::client)
+      .load(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::client_usage::{
+      ClientRestriction,
+      ClientRestrictionForm,
+      ClientUsage,
+      ClientUsageForm,
+    },
+    utils::build_db_pool_for_tests,
+  };
+  use chrono::{NaiveTime, TimeDelta, Utc};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_client_usage() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let day = (Utc::now() - TimeDelta::days(400))
+      .date_naive()
+      .and_time(NaiveTime::MIN)
+      .and_utc();
+    let form = |request_count| ClientUsageForm {
+      client: "test-app".to_string(),
+      day,
+      request_count,
+    };
+    ClientUsage::increment(pool, &[form(3)]).await?;
+    ClientUsage::increment(pool, &[form(4)]).await?;
+
+    let usage = ClientUsage::list(pool, day).await?;
+    let usage = usage
+      .iter()
+      .find(|u| u.client == "test-app")
+      .map(|u| u.request_count);
+    assert_eq!(Some(7), usage);
+
+    let restriction_form = ClientRestrictionForm {
+      client: "test-app".to_string(),
+      requests_per_minute: None,
+      reason: Some("spam".to_string()),
+    };
+    ClientRestriction::upsert(pool, &restriction_form).await?;
+    let restriction_form = ClientRestrictionForm {
+      requests_per_minute: Some(10),
+      ..restriction_form
+    };
+    let restriction = ClientRestriction::upsert(pool, &restriction_form).await?;
+    assert_eq!(Some(10), restriction.requests_per_minute);
+    assert_eq!(1, ClientRestriction::list(pool).await?.len());
+
+    ClientRestriction::delete(pool, "test-app").await?;
+    ClientUsage::delete_older_than(pool, day + TimeDelta::days(1)).await?;
+    assert!(ClientRestriction::list(pool).await?.is_empty());
+    assert!(ClientUsage::list(pool, day)
+      .await?
+      .iter()
+      .all(|u| u.client != "test-app"));
+
+    Ok(())
+  }
+}
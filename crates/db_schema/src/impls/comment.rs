@@ -1,7 +1,15 @@
 use crate::{
   diesel::{DecoratableTarget, OptionalExtension},
-  newtypes::{CommentId, DbUrl, PersonId},
-  schema::{comment, comment_actions},
+  newtypes::{CommentId, DbUrl, PersonId, PostId},
+  schema::{
+    comment,
+    comment_actions,
+    comment_aggregates,
+    comment_archive,
+    local_user,
+    person,
+    person_aggregates,
+  },
   source::comment::{
     Comment,
     CommentInsertForm,
@@ -24,18 +32,89 @@ use crate::{
 };
 use chrono::{DateTime, Utc};
 use diesel::{
-  dsl::insert_into,
+  dsl::{count, exists, insert_into},
   expression::SelectableHelper,
   result::Error,
+  upsert::excluded,
   ExpressionMethods,
+  JoinOnDsl,
   NullableExpressionMethods,
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
 use diesel_ltree::Ltree;
+use std::collections::HashMap;
 use url::Url;
 
 impl Comment {
+  /// Counts comments by the same creator on other posts since the given time, which have the
+  /// same content.
+  pub async fn count_recent_duplicates(
+    pool: &mut DbPool<'_>,
+    creator_id: PersonId,
+    post_id: PostId,
+    content: &str,
+    since: DateTime<Utc>,
+  ) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    comment::table
+      .filter(comment::creator_id.eq(creator_id))
+      .filter(comment::post_id.ne(post_id))
+      .filter(comment::published.gt(since))
+      .filter(comment::content.eq(content))
+      .select(count(comment::id))
+      .first::<i64>(conn)
+      .await
+  }
+
+  /// The newest local comments which aren't deleted, together with the time when the creator's
+  /// account was created, whether the creator is an admin and the creator's reputation. Used to
+  /// preview changes of the content filters.
+  pub async fn list_recent_local(
+    pool: &mut DbPool<'_>,
+    limit: i64,
+  ) -> Result<Vec<(Self, DateTime<Utc>, bool, i64)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    comment::table
+      .inner_join(person::table)
+      .inner_join(person_aggregates::table.on(person_aggregates::person_id.eq(person::id)))
+      .left_join(local_user::table.on(local_user::person_id.eq(person::id)))
+      .filter(comment::local.eq(true))
+      .filter(comment::deleted.eq(false))
+      .order_by(comment::published.desc())
+      .limit(limit)
+      .select((
+        Self::as_select(),
+        person::published,
+        coalesce(local_user::admin.nullable(), false),
+        person_aggregates::reputation,
+      ))
+      .load(conn)
+      .await
+  }
+
+  /// A batch of the comments of a post in tree order, starting after the given path. Parents
+  /// always come before their children. Used to page through an entire comment tree.
+  pub async fn list_for_export(
+    pool: &mut DbPool<'_>,
+    post_id: PostId,
+    after: Option<&Ltree>,
+    limit: i64,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = comment::table
+      .filter(comment::post_id.eq(post_id))
+      .into_boxed();
+    if let Some(after) = after {
+      query = query.filter(comment::path.gt(after));
+    }
+    query
+      .order_by(comment::path)
+      .limit(limit)
+      .load::<Self>(conn)
+      .await
+  }
+
   pub async fn permadelete_for_creator(
     pool: &mut DbPool<'_>,
     for_creator_id: PersonId,
@@ -45,6 +124,7 @@ impl Comment {
     diesel::update(comment::table.filter(comment::creator_id.eq(for_creator_id)))
       .set((
         comment::content.eq(DELETED_REPLACEMENT_TEXT),
+        comment::archived.eq(false),
         comment::deleted.eq(true),
         comment::updated.eq(naive_now()),
       ))
@@ -90,7 +170,7 @@ impl Comment {
         .on_conflict(comment::ap_id)
         .filter_target(coalesce(comment::updated, comment::published).lt(timestamp))
         .do_update()
-        .set(comment_form)
+        .set((comment_form, comment::archived.eq(false)))
         .get_result::<Self>(conn)
         .await
     } else {
@@ -114,6 +194,93 @@ impl Comment {
       .optional()
   }
 
+  /// Moves the content of old comments with few votes and replies into the compressed archive
+  /// table, to keep the comment table small on old instances. Returns the number of archived
+  /// comments.
+  pub async fn archive_old(
+    pool: &mut DbPool<'_>,
+    published_before: DateTime<Utc>,
+    max_score: i64,
+    max_replies: i32,
+    limit: i64,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let comments: Vec<(CommentId, String)> = comment::table
+            .filter(comment::published.lt(published_before))
+            .filter(comment::archived.eq(false))
+            .filter(exists(
+              comment_aggregates::table
+                .filter(comment_aggregates::comment_id.eq(comment::id))
+                .filter(comment_aggregates::score.le(max_score))
+                .filter(comment_aggregates::child_count.le(max_replies)),
+            ))
+            .select((comment::id, comment::content))
+            .limit(limit)
+            .for_update()
+            .skip_locked()
+            .load(conn)
+            .await?;
+          if comments.is_empty() {
+            return Ok(0);
+          }
+          let ids = comments.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+          let forms = comments
+            .into_iter()
+            .map(|(id, content)| {
+              (
+                comment_archive::comment_id.eq(id),
+                comment_archive::content.eq(content),
+              )
+            })
+            .collect::<Vec<_>>();
+
+          insert_into(comment_archive::table)
+            .values(forms)
+            .on_conflict(comment_archive::comment_id)
+            .do_update()
+            .set(comment_archive::content.eq(excluded(comment_archive::content)))
+            .execute(conn)
+            .await?;
+          diesel::update(comment::table.filter(comment::id.eq_any(ids)))
+            .set((comment::content.eq(""), comment::archived.eq(true)))
+            .execute(conn)
+            .await
+        }) as _
+      })
+      .await
+  }
+
+  /// Fills in the content of archived comments from the archive table.
+  pub async fn restore_archived<'a>(
+    pool: &mut DbPool<'_>,
+    comments: impl IntoIterator<Item = &'a mut Comment>,
+  ) -> Result<(), Error> {
+    let archived = comments
+      .into_iter()
+      .filter(|c| c.archived)
+      .collect::<Vec<_>>();
+    if archived.is_empty() {
+      return Ok(());
+    }
+
+    let conn = &mut get_conn(pool).await?;
+    let ids = archived.iter().map(|c| c.id).collect::<Vec<_>>();
+    let mut contents: HashMap<CommentId, String> = comment_archive::table
+      .filter(comment_archive::comment_id.eq_any(ids))
+      .load::<(CommentId, String)>(conn)
+      .await?
+      .into_iter()
+      .collect();
+    for comment in archived {
+      comment.content = contents.remove(&comment.id).unwrap_or_default();
+    }
+    Ok(())
+  }
+
   pub fn parent_comment_id(&self) -> Option<CommentId> {
     let mut ltree_split: Vec<&str> = self.path.0.split('.').collect();
     ltree_split.remove(0); // The first is always 0
@@ -144,8 +311,13 @@ impl Crud for Comment {
     comment_form: &Self::UpdateForm,
   ) -> Result<Self, Error> {
     let conn = &mut get_conn(pool).await?;
+    // New content replaces the archived one
+    let unarchive = comment_form
+      .content
+      .as_ref()
+      .map(|_| comment::archived.eq(false));
     diesel::update(comment::table.find(comment_id))
-      .set(comment_form)
+      .set((comment_form, unarchive))
       .get_result::<Self>(conn)
       .await
   }
@@ -214,6 +386,7 @@ impl Saveable for CommentSaved {
       comment_actions::table.find((comment_saved_form.person_id, comment_saved_form.comment_id)),
     )
     .set_null(comment_actions::saved)
+    .set_null(comment_actions::saved_note)
     .get_result(conn)
     .await
   }
@@ -300,6 +473,8 @@ mod tests {
       distinguished: false,
       local: true,
       language_id: LanguageId::default(),
+      archived: false,
+      distinguished_pinned: false,
     };
 
     let child_comment_form = CommentInsertForm::new(
@@ -330,6 +505,7 @@ mod tests {
     let comment_saved_form = CommentSavedForm {
       comment_id: inserted_comment.id,
       person_id: inserted_person.id,
+      saved_note: None,
     };
 
     let inserted_comment_saved = CommentSaved::save(pool, &comment_saved_form).await?;
@@ -338,6 +514,7 @@ mod tests {
       comment_id: inserted_comment.id,
       person_id: inserted_person.id,
       published: inserted_comment_saved.published,
+      saved_note: None,
     };
 
     let comment_update_form = CommentUpdateForm {
@@ -372,4 +549,57 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_list_for_export() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "archivist"),
+    )
+    .await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "archive community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let post_form = PostInsertForm::new("A post to export".into(), person.id, community.id);
+    let post = Post::create(pool, &post_form).await?;
+
+    let first_form = CommentInsertForm::new(person.id, post.id, "first".into());
+    let first = Comment::create(pool, &first_form, None).await?;
+    let second_form = CommentInsertForm::new(person.id, post.id, "second".into());
+    let second = Comment::create(pool, &second_form, None).await?;
+    let reply_form = CommentInsertForm::new(person.id, post.id, "reply".into());
+    let reply = Comment::create(pool, &reply_form, Some(&first.path)).await?;
+
+    // Page through the tree one comment at a time
+    let mut after = None;
+    let mut exported = vec![];
+    loop {
+      let batch = Comment::list_for_export(pool, post.id, after.as_ref(), 1).await?;
+      let Some(comment) = batch.into_iter().next() else {
+        break;
+      };
+      after = Some(comment.path.clone());
+      exported.push(comment.id);
+    }
+    // Every comment is exported once, and the reply directly follows its parent. The order of
+    // top level comments depends on how their ids compare as ltree labels.
+    assert_eq!(3, exported.len());
+    assert!(exported.contains(&second.id));
+    let first_position = exported.iter().position(|id| *id == first.id);
+    let reply_position = exported.iter().position(|id| *id == reply.id);
+    assert_eq!(first_position.map(|p| p + 1), reply_position);
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
 }
@@ -1,12 +1,16 @@
 use crate::{
-  newtypes::{CommentId, CommentReportId, PersonId},
-  schema::comment_report::{
-    comment_id,
-    dsl::{comment_report, resolved, resolver_id, updated},
+  newtypes::{CommentId, CommentReportId, PersonId, PostId},
+  schema::{
+    comment,
+    comment_report::{
+      comment_id,
+      dsl::{assigned_to, comment_report, outcome, resolved, resolver_id, updated},
+    },
   },
   source::comment_report::{CommentReport, CommentReportForm},
   traits::Reportable,
   utils::{get_conn, naive_now, DbPool},
+  ReportOutcome,
 };
 use diesel::{
   dsl::{insert_into, update},
@@ -45,11 +49,13 @@ impl Reportable for CommentReport {
     pool: &mut DbPool<'_>,
     report_id_: Self::IdType,
     by_resolver_id: PersonId,
+    outcome_: ReportOutcome,
   ) -> Result<usize, Error> {
     let conn = &mut get_conn(pool).await?;
     update(comment_report.find(report_id_))
       .set((
         resolved.eq(true),
+        outcome.eq(Some(outcome_)),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
@@ -63,14 +69,19 @@ impl Reportable for CommentReport {
     by_resolver_id: PersonId,
   ) -> Result<usize, Error> {
     let conn = &mut get_conn(pool).await?;
-    update(comment_report.filter(comment_id.eq(comment_id_)))
-      .set((
-        resolved.eq(true),
-        resolver_id.eq(by_resolver_id),
-        updated.eq(naive_now()),
-      ))
-      .execute(conn)
-      .await
+    update(
+      comment_report
+        .filter(comment_id.eq(comment_id_))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      outcome.eq(Some(ReportOutcome::ResolvedRemoved)),
+      resolver_id.eq(by_resolver_id),
+      updated.eq(naive_now()),
+    ))
+    .execute(conn)
+    .await
   }
 
   /// unresolve a comment report
@@ -87,10 +98,99 @@ impl Reportable for CommentReport {
     update(comment_report.find(report_id_))
       .set((
         resolved.eq(false),
+        outcome.eq(None::<ReportOutcome>),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
       .execute(conn)
       .await
   }
+
+  async fn assign(
+    pool: &mut DbPool<'_>,
+    report_id_: Self::IdType,
+    assignee_id: Option<PersonId>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(comment_report.find(report_id_))
+      .set(assigned_to.eq(assignee_id))
+      .execute(conn)
+      .await
+  }
+}
+
+impl CommentReport {
+  /// Resolves all open reports against a comment with the given outcome, and returns how many
+  /// were resolved.
+  pub async fn resolve_all_for_comment(
+    pool: &mut DbPool<'_>,
+    comment_id_: CommentId,
+    by_resolver_id: PersonId,
+    outcome_: ReportOutcome,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      comment_report
+        .filter(comment_id.eq(comment_id_))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      outcome.eq(Some(outcome_)),
+      resolver_id.eq(by_resolver_id),
+      updated.eq(naive_now()),
+    ))
+    .execute(conn)
+    .await
+  }
+
+  /// Resolves all pending reports against the given comments, for example when they are removed
+  /// together with a ban.
+  pub async fn resolve_all_for_comments(
+    pool: &mut DbPool<'_>,
+    comment_ids: &[CommentId],
+    by_resolver_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      comment_report
+        .filter(comment_id.eq_any(comment_ids))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      outcome.eq(Some(ReportOutcome::ResolvedRemoved)),
+      resolver_id.eq(by_resolver_id),
+      updated.eq(naive_now()),
+    ))
+    .execute(conn)
+    .await
+  }
+
+  /// Resolves all open reports against the comments of a post with the given outcome, and returns
+  /// how many were resolved.
+  pub async fn resolve_all_for_post(
+    pool: &mut DbPool<'_>,
+    post_id: PostId,
+    by_resolver_id: PersonId,
+    outcome_: ReportOutcome,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let post_comments = comment::table
+      .filter(comment::post_id.eq(post_id))
+      .select(comment::id);
+    update(
+      comment_report
+        .filter(comment_id.eq_any(post_comments))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      outcome.eq(Some(outcome_)),
+      resolver_id.eq(by_resolver_id),
+      updated.eq(naive_now()),
+    ))
+    .execute(conn)
+    .await
+  }
 }
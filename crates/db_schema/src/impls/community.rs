@@ -1,7 +1,14 @@
 use crate::{
   diesel::{DecoratableTarget, OptionalExtension},
-  newtypes::{CommunityId, DbUrl, PersonId},
-  schema::{community, community_actions, instance, post},
+  newtypes::{BanListId, CommunityId, DbUrl, PersonId},
+  schema::{
+    community,
+    community_actions,
+    community_aggregates,
+    community_name_alias,
+    instance,
+    post,
+  },
   source::{
     actor_language::CommunityLanguage,
     community::{
@@ -14,6 +21,8 @@ use crate::{
       CommunityModeratorForm,
       CommunityPersonBan,
       CommunityPersonBanForm,
+      CommunitySuppressedRecommendation,
+      CommunitySuppressedRecommendationForm,
       CommunityUpdateForm,
     },
     post::Post,
@@ -206,6 +215,25 @@ impl Community {
     Ok(())
   }
 
+  /// Ends the soft launch of a community once it has at least `min_posts` posts.
+  pub async fn end_soft_launch_if_enough_posts(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    min_posts: i64,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    let enough_posts = community_aggregates::table
+      .filter(community_aggregates::community_id.eq(community_id))
+      .filter(community_aggregates::posts.ge(min_posts));
+    update(community::table.find(community_id))
+      .filter(community::soft_launched)
+      .filter(exists(enough_posts))
+      .set(community::soft_launched.eq(false))
+      .execute(conn)
+      .await?;
+    Ok(())
+  }
+
   pub async fn get_random_community_id(
     pool: &mut DbPool<'_>,
     type_: &Option<ListingType>,
@@ -216,6 +244,7 @@ impl Community {
     let mut query = community::table
       .filter(not(community::deleted))
       .filter(not(community::removed))
+      .filter(not(community::soft_launched))
       .into_boxed();
 
     if let Some(ListingType::Local) = type_ {
@@ -312,6 +341,8 @@ impl Bannable for CommunityPersonBan {
     let community_person_ban_form = (
       community_person_ban_form,
       community_actions::received_ban.eq(now().nullable()),
+      // A direct ban replaces one which was applied by a ban list
+      community_actions::ban_list_id.eq(None::<BanListId>),
     );
     insert_into(community_actions::table)
       .values(community_person_ban_form)
@@ -337,11 +368,30 @@ impl Bannable for CommunityPersonBan {
     )))
     .set_null(community_actions::received_ban)
     .set_null(community_actions::ban_expires)
+    .set_null(community_actions::ban_list_id)
+    .set_null(community_actions::ban_reason)
+    .set_null(community_actions::ban_appeal)
     .get_result(conn)
     .await
   }
 }
 
+impl CommunityPersonBan {
+  /// The current ban of the person in the community, if any.
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    community_id: CommunityId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    find_action(community_actions::received_ban, (person_id, community_id))
+      .select(Self::as_select())
+      .first(conn)
+      .await
+      .optional()
+  }
+}
+
 impl CommunityFollower {
   pub fn select_subscribed_type() -> dsl::Nullable<community_actions::follow_state> {
     community_actions::follow_state.nullable()
@@ -364,6 +414,8 @@ impl CommunityFollower {
     .ok_or(LemmyErrorType::CommunityHasNoFollowers.into())
   }
 
+  /// Accepts a pending follow request. Fails if the person hasn't requested to follow, or was
+  /// already approved.
   pub async fn approve(
     pool: &mut DbPool<'_>,
     community_id: CommunityId,
@@ -371,16 +423,19 @@ impl CommunityFollower {
     approver_id: PersonId,
   ) -> LemmyResult<()> {
     let conn = &mut get_conn(pool).await?;
-    diesel::update(find_action(
-      community_actions::followed,
-      (follower_id, community_id),
-    ))
+    let updated = diesel::update(
+      find_action(community_actions::followed, (follower_id, community_id))
+        .filter(community_actions::follow_state.eq(CommunityFollowerState::ApprovalRequired)),
+    )
     .set((
       community_actions::follow_state.eq(CommunityFollowerState::Accepted),
       community_actions::follow_approver_id.eq(approver_id),
     ))
     .execute(conn)
     .await?;
+    if updated == 0 {
+      Err(LemmyErrorType::NotFound)?
+    }
     Ok(())
   }
 }
@@ -446,6 +501,47 @@ impl Followable for CommunityFollower {
   }
 }
 
+impl CommunitySuppressedRecommendation {
+  pub async fn suppress(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+
+    let form = (
+      &CommunitySuppressedRecommendationForm {
+        community_id,
+        person_id,
+      },
+      community_actions::suppressed_recommendation.eq(now().nullable()),
+    );
+    insert_into(community_actions::table)
+      .values(form)
+      .on_conflict((
+        community_actions::person_id,
+        community_actions::community_id,
+      ))
+      .do_update()
+      .set(form)
+      .execute(conn)
+      .await
+  }
+
+  pub async fn unsuppress(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+  ) -> Result<uplete::Count, Error> {
+    let conn = &mut get_conn(pool).await?;
+
+    uplete::new(community_actions::table.find((person_id, community_id)))
+      .set_null(community_actions::suppressed_recommendation)
+      .get_result(conn)
+      .await
+  }
+}
+
 #[async_trait]
 impl ApubActor for Community {
   async fn read_from_apub_id(
@@ -466,10 +562,19 @@ impl ApubActor for Community {
     include_deleted: bool,
   ) -> Result<Option<Self>, Error> {
     let conn = &mut get_conn(pool).await?;
+    let community_name = community_name.to_lowercase();
+    // Renamed communities can still be found with their old names
+    let alias = community_name_alias::table
+      .filter(community_name_alias::name.eq(community_name.clone()))
+      .select(community_name_alias::community_id);
     let mut q = community::table
       .into_boxed()
       .filter(community::local.eq(true))
-      .filter(lower(community::name).eq(community_name.to_lowercase()));
+      .filter(
+        lower(community::name)
+          .eq(community_name)
+          .or(community::id.nullable().eq_any(alias)),
+      );
     if !include_deleted {
       q = q
         .filter(community::deleted.eq(false))
@@ -569,6 +674,18 @@ mod tests {
       featured_url: None,
       hidden: false,
       posting_restricted_to_mods: false,
+      comment_export_public: false,
+      soft_launched: false,
+      image_text_search: true,
+      posting_min_reputation: None,
+      welcome_message: None,
+      posts_require_approval: false,
+      warning_ban_strikes: None,
+      warning_ban_days: 7,
+      new_account_min_age_days: None,
+      new_account_min_comments: None,
+      new_account_require_verified_email: false,
+      new_account_restrictions: vec![],
       instance_id: inserted_instance.id,
       visibility: CommunityVisibility::Public,
     };
@@ -648,6 +765,8 @@ mod tests {
       community_id: inserted_community.id,
       person_id: inserted_bobby.id,
       expires: None,
+      reason: Some(Some("spam".to_string())),
+      appeal: None,
     };
 
     let inserted_community_person_ban =
@@ -658,6 +777,8 @@ mod tests {
       person_id: inserted_bobby.id,
       published: inserted_community_person_ban.published,
       expires: None,
+      reason: Some("spam".to_string()),
+      appeal: None,
     };
 
     let read_community = Community::read(pool, inserted_community.id).await?;
@@ -691,4 +812,46 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_approve_follow() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let mod_person =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "gerda")).await?;
+    let follower =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "hubert")).await?;
+    let community_form = CommunityInsertForm {
+      visibility: Some(CommunityVisibility::Private),
+      ..CommunityInsertForm::new(
+        instance.id,
+        "private_approve".into(),
+        "nada".to_owned(),
+        "pubkey".to_string(),
+      )
+    };
+    let community = Community::create(pool, &community_form).await?;
+
+    // Nothing to approve before the follow request is made
+    let approve = CommunityFollower::approve(pool, community.id, follower.id, mod_person.id).await;
+    assert!(approve.is_err());
+
+    let follower_form = CommunityFollowerForm {
+      state: Some(CommunityFollowerState::ApprovalRequired),
+      ..CommunityFollowerForm::new(community.id, follower.id)
+    };
+    CommunityFollower::follow(pool, &follower_form).await?;
+    CommunityFollower::approve(pool, community.id, follower.id, mod_person.id).await?;
+
+    // Approving twice fails
+    let approve = CommunityFollower::approve(pool, community.id, follower.id, mod_person.id).await;
+    assert!(approve.is_err());
+
+    Community::delete(pool, community.id).await?;
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
 }
@@ -0,0 +1,97 @@
+use crate::{
+  newtypes::{CommunityBotDelegationId, CommunityId},
+  schema::{community_bot_action, community_bot_delegation},
+  source::community_bot::{
+    CommunityBotAction,
+    CommunityBotActionForm,
+    CommunityBotDelegation,
+    CommunityBotDelegationInsertForm,
+    CommunityBotDelegationUpdateForm,
+  },
+  traits::Crud,
+  utils::{get_conn, limit_and_offset, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for CommunityBotDelegation {
+  type InsertForm = CommunityBotDelegationInsertForm;
+  type UpdateForm = CommunityBotDelegationUpdateForm;
+  type IdType = CommunityBotDelegationId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_bot_delegation::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    delegation_id: CommunityBotDelegationId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(community_bot_delegation::table.find(delegation_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl CommunityBotDelegation {
+  /// The delegation which a bot authenticates with, unless it was revoked.
+  pub async fn read_from_token(pool: &mut DbPool<'_>, token: &str) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_bot_delegation::table
+      .filter(community_bot_delegation::token.eq(token))
+      .filter(community_bot_delegation::revoked.eq(false))
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  /// All delegations of a community, including revoked ones.
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_bot_delegation::table
+      .filter(community_bot_delegation::community_id.eq(community_id))
+      .order_by(community_bot_delegation::published.desc())
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl CommunityBotAction {
+  pub async fn create(pool: &mut DbPool<'_>, form: &CommunityBotActionForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_bot_action::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    page: Option<i64>,
+    limit: Option<i64>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (limit, offset) = limit_and_offset(page, limit)?;
+    community_bot_action::table
+      .inner_join(community_bot_delegation::table)
+      .filter(community_bot_delegation::community_id.eq(community_id))
+      .select(community_bot_action::all_columns)
+      .order_by(community_bot_action::published.desc())
+      .limit(limit)
+      .offset(offset)
+      .load::<Self>(conn)
+      .await
+  }
+}
@@ -0,0 +1,137 @@
+use crate::{
+  newtypes::CommunityId,
+  schema::community_domain_block,
+  source::community_domain_block::{CommunityDomainBlock, CommunityDomainBlockForm},
+  utils::{get_conn, DbPool},
+};
+use diesel::{
+  dsl::{exists, insert_into},
+  result::Error,
+  select,
+  ExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+impl CommunityDomainBlock {
+  /// Blocking a domain again replaces the reason.
+  pub async fn block(
+    pool: &mut DbPool<'_>,
+    form: &CommunityDomainBlockForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_domain_block::table)
+      .values(form)
+      .on_conflict((
+        community_domain_block::community_id,
+        community_domain_block::domain,
+      ))
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn unblock(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    domain: &str,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(community_domain_block::table.find((community_id, domain)))
+      .execute(conn)
+      .await
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>, community_id: CommunityId) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_domain_block::table
+      .filter(community_domain_block::community_id.eq(community_id))
+      .order_by(community_domain_block::domain)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Whether the host or one of its parent domains is blocked in the community.
+  pub async fn is_blocked(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    host: &str,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let host = host.to_lowercase();
+    let domains = host
+      .match_indices('.')
+      .map(|(i, _)| &host[i + 1..])
+      .chain([host.as_str()])
+      .collect::<Vec<_>>();
+    select(exists(
+      community_domain_block::table
+        .filter(community_domain_block::community_id.eq(community_id))
+        .filter(community_domain_block::domain.eq_any(domains)),
+    ))
+    .get_result(conn)
+    .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      community_domain_block::{CommunityDomainBlock, CommunityDomainBlockForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_domain_block() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person = Person::create(pool, &PersonInsertForm::test_form(instance.id, "mira")).await?;
+    let community_form = CommunityInsertForm::new(
+      instance.id,
+      "domain_block".into(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+
+    let form = CommunityDomainBlockForm::new(community.id, "example.com".to_string(), person.id);
+    let block = CommunityDomainBlock::block(pool, &form).await?;
+    assert_eq!(
+      vec![block],
+      CommunityDomainBlock::list(pool, community.id).await?
+    );
+
+    for (host, expected) in [
+      ("example.com", true),
+      ("www.Example.com", true),
+      ("notexample.com", false),
+      ("example.com.evil.org", false),
+    ] {
+      assert_eq!(
+        expected,
+        CommunityDomainBlock::is_blocked(pool, community.id, host).await?
+      );
+    }
+
+    CommunityDomainBlock::unblock(pool, community.id, "example.com").await?;
+    assert!(!CommunityDomainBlock::is_blocked(pool, community.id, "example.com").await?);
+
+    Community::delete(pool, community.id).await?;
+    Person::delete(pool, person.id).await?;
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
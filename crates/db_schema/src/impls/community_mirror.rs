@@ -0,0 +1,102 @@
+use crate::{
+  newtypes::{CommunityId, CommunityMirrorId},
+  schema::{community_mirror, community_mirror_action},
+  source::community_mirror::{
+    CommunityMirror,
+    CommunityMirrorAction,
+    CommunityMirrorActionForm,
+    CommunityMirrorInsertForm,
+    CommunityMirrorUpdateForm,
+  },
+  traits::Crud,
+  utils::{get_conn, limit_and_offset, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for CommunityMirror {
+  type InsertForm = CommunityMirrorInsertForm;
+  type UpdateForm = CommunityMirrorUpdateForm;
+  type IdType = CommunityMirrorId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_mirror::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    mirror_id: CommunityMirrorId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(community_mirror::table.find(mirror_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl CommunityMirror {
+  /// All mirror agreements of a local community, including disabled ones.
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_mirror::table
+      .filter(community_mirror::community_id.eq(community_id))
+      .order_by(community_mirror::published.desc())
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// The enabled agreements which mirror the given partner community.
+  pub async fn list_enabled_for_partner(
+    pool: &mut DbPool<'_>,
+    partner_community_id: CommunityId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_mirror::table
+      .filter(community_mirror::partner_community_id.eq(partner_community_id))
+      .filter(community_mirror::enabled.eq(true))
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl CommunityMirrorAction {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &CommunityMirrorActionForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_mirror_action::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    page: Option<i64>,
+    limit: Option<i64>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (limit, offset) = limit_and_offset(page, limit)?;
+    community_mirror_action::table
+      .inner_join(community_mirror::table)
+      .filter(community_mirror::community_id.eq(community_id))
+      .select(community_mirror_action::all_columns)
+      .order_by(community_mirror_action::when_.desc())
+      .limit(limit)
+      .offset(offset)
+      .load::<Self>(conn)
+      .await
+  }
+}
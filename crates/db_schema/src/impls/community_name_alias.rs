@@ -0,0 +1,167 @@
+use crate::{
+  newtypes::CommunityId,
+  schema::{community, community_name_alias},
+  source::community_name_alias::{CommunityNameAlias, CommunityNameAliasForm},
+  utils::{functions::lower, get_conn, DbPool},
+};
+use diesel::{
+  dsl::{exists, insert_into},
+  result::Error,
+  select,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+impl CommunityNameAlias {
+  /// The name is stored in lowercase, as community names are case insensitive.
+  pub async fn create(pool: &mut DbPool<'_>, form: &CommunityNameAliasForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let form = CommunityNameAliasForm {
+      name: form.name.to_lowercase(),
+      ..form.clone()
+    };
+    insert_into(community_name_alias::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn read(pool: &mut DbPool<'_>, name: &str) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_name_alias::table
+      .find(name.to_lowercase())
+      .first(conn)
+      .await
+      .optional()
+  }
+
+  pub async fn delete(pool: &mut DbPool<'_>, name: &str) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(community_name_alias::table.find(name.to_lowercase()))
+      .execute(conn)
+      .await
+  }
+
+  /// Whether a new local community, or a renamed one, can't use this name because it is taken by
+  /// another community, an alias or a reservation.
+  pub async fn is_name_taken(pool: &mut DbPool<'_>, name: &str) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let name = name.to_lowercase();
+    let community_exists = community::table
+      .filter(community::local.eq(true))
+      .filter(lower(community::name).eq(name.clone()));
+    let alias_exists = community_name_alias::table.filter(community_name_alias::name.eq(name));
+    select(exists(community_exists).or(exists(alias_exists)))
+      .get_result(conn)
+      .await
+  }
+
+  /// Names which were reserved by admins, not belonging to any community.
+  pub async fn list_reserved(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_name_alias::table
+      .filter(community_name_alias::community_id.is_null())
+      .order_by(community_name_alias::name)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Old names of a renamed community.
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_name_alias::table
+      .filter(community_name_alias::community_id.eq(community_id))
+      .order_by(community_name_alias::published.desc())
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm, CommunityUpdateForm},
+      community_name_alias::{CommunityNameAlias, CommunityNameAliasForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    traits::{ApubActor, Crud},
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_rename_and_reserve() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let admin_form = PersonInsertForm::test_form(inserted_instance.id, "alias_admin");
+    let inserted_admin = Person::create(pool, &admin_form).await?;
+
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "rsut".into(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+    assert!(CommunityNameAlias::is_name_taken(pool, "RSUT").await?);
+
+    // Rename the community, the old name keeps resolving to it
+    let update_form = CommunityUpdateForm {
+      name: Some("rust".into()),
+      ..Default::default()
+    };
+    Community::update(pool, inserted_community.id, &update_form).await?;
+    let alias_form = CommunityNameAliasForm::new(
+      "Rsut".into(),
+      Some(inserted_community.id),
+      inserted_admin.id,
+    );
+    CommunityNameAlias::create(pool, &alias_form).await?;
+
+    let by_old_name = Community::read_from_name(pool, "rsut", false).await?;
+    assert_eq!(Some(inserted_community.id), by_old_name.map(|c| c.id));
+    let by_new_name = Community::read_from_name(pool, "rust", false).await?;
+    assert_eq!(Some(inserted_community.id), by_new_name.map(|c| c.id));
+    assert!(CommunityNameAlias::is_name_taken(pool, "rsut").await?);
+    assert_eq!(
+      1,
+      CommunityNameAlias::list_for_community(pool, inserted_community.id)
+        .await?
+        .len()
+    );
+
+    // Reserve a name
+    assert!(!CommunityNameAlias::is_name_taken(pool, "admin").await?);
+    let reserve_form = CommunityNameAliasForm::new("admin".into(), None, inserted_admin.id);
+    CommunityNameAlias::create(pool, &reserve_form).await?;
+    assert!(CommunityNameAlias::is_name_taken(pool, "Admin").await?);
+    let reserved = CommunityNameAlias::list_reserved(pool).await?;
+    assert_eq!(
+      vec!["admin".to_string()],
+      reserved.into_iter().map(|r| r.name).collect::<Vec<_>>()
+    );
+    assert!(Community::read_from_name(pool, "admin", false)
+      .await?
+      .is_none());
+
+    CommunityNameAlias::delete(pool, "admin").await?;
+    assert!(!CommunityNameAlias::is_name_taken(pool, "admin").await?);
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
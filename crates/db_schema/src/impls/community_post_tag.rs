@@ -0,0 +1,102 @@
+use crate::{
+  newtypes::{CommunityId, CommunityPostTagId, PostId},
+  schema::{community_post_tag, post_tag},
+  source::community_post_tag::{
+    CommunityPostTag,
+    CommunityPostTagInsertForm,
+    CommunityPostTagUpdateForm,
+    PostTag,
+  },
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for CommunityPostTag {
+  type InsertForm = CommunityPostTagInsertForm;
+  type UpdateForm = CommunityPostTagUpdateForm;
+  type IdType = CommunityPostTagId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_post_tag::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    tag_id: CommunityPostTagId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(community_post_tag::table.find(tag_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl CommunityPostTag {
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_post_tag::table
+      .filter(community_post_tag::community_id.eq(community_id))
+      .order_by(community_post_tag::name)
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl PostTag {
+  /// Replaces the tags of a post.
+  pub async fn set(
+    pool: &mut DbPool<'_>,
+    post_id: PostId,
+    tag_ids: Vec<CommunityPostTagId>,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          diesel::delete(post_tag::table.filter(post_tag::post_id.eq(post_id)))
+            .execute(conn)
+            .await?;
+          if !tag_ids.is_empty() {
+            let forms = tag_ids
+              .into_iter()
+              .map(|tag_id| (post_tag::post_id.eq(post_id), post_tag::tag_id.eq(tag_id)))
+              .collect::<Vec<_>>();
+            insert_into(post_tag::table)
+              .values(forms)
+              .execute(conn)
+              .await?;
+          }
+          Ok(())
+        }) as _
+      })
+      .await
+  }
+
+  /// The tags of the given posts, ordered by name.
+  pub async fn list_for_posts(
+    pool: &mut DbPool<'_>,
+    post_ids: &[PostId],
+  ) -> Result<Vec<(PostId, CommunityPostTag)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    post_tag::table
+      .inner_join(community_post_tag::table)
+      .filter(post_tag::post_id.eq_any(post_ids))
+      .order_by(community_post_tag::name)
+      .select((post_tag::post_id, community_post_tag::all_columns))
+      .load::<(PostId, CommunityPostTag)>(conn)
+      .await
+  }
+}
@@ -0,0 +1,105 @@
+use crate::{
+  newtypes::{CommunityRequestId, PersonId},
+  schema::{community_request, community_request_comment, community_request_vote},
+  source::community_request::{
+    CommunityRequest,
+    CommunityRequestComment,
+    CommunityRequestCommentForm,
+    CommunityRequestInsertForm,
+    CommunityRequestUpdateForm,
+    CommunityRequestVote,
+    CommunityRequestVoteForm,
+  },
+  traits::Crud,
+  utils::{functions::lower, get_conn, DbPool},
+  CommunityRequestStatus,
+};
+use diesel::{
+  dsl::{exists, insert_into},
+  result::Error,
+  select,
+  ExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for CommunityRequest {
+  type InsertForm = CommunityRequestInsertForm;
+  type UpdateForm = CommunityRequestUpdateForm;
+  type IdType = CommunityRequestId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_request::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    id: CommunityRequestId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(community_request::table.find(id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl CommunityRequest {
+  /// Checks if there is already a pending request for a community with this name.
+  pub async fn pending_name_exists(pool: &mut DbPool<'_>, name: &str) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    select(exists(
+      community_request::table
+        .filter(community_request::status.eq(CommunityRequestStatus::Pending))
+        .filter(lower(community_request::name).eq(name.to_lowercase())),
+    ))
+    .get_result(conn)
+    .await
+  }
+}
+
+impl CommunityRequestVote {
+  pub async fn vote(pool: &mut DbPool<'_>, form: &CommunityRequestVoteForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_request_vote::table)
+      .values(form)
+      .on_conflict((
+        community_request_vote::person_id,
+        community_request_vote::community_request_id,
+      ))
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn remove(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    community_request_id: CommunityRequestId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(community_request_vote::table.find((person_id, community_request_id)))
+      .execute(conn)
+      .await
+  }
+}
+
+impl CommunityRequestComment {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &CommunityRequestCommentForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_request_comment::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
@@ -0,0 +1,245 @@
+use crate::{
+  diesel::OptionalExtension,
+  newtypes::{CommunityId, PersonId},
+  schema::{community_actions, community_role_permission},
+  source::community_role::{CommunityRolePermission, CommunityRolePermissionForm},
+  utils::{action_query, find_action, get_conn, uplete, DbPool},
+  CommunityRole,
+};
+use diesel::{
+  dsl::insert_into,
+  result::Error,
+  ExpressionMethods,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+impl CommunityRole {
+  /// The role of a person in a community. Owner and moderators come from the moderator list,
+  /// where the first moderator is the owner.
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let moderators = action_query(community_actions::became_moderator)
+      .filter(community_actions::community_id.eq(community_id))
+      .order_by(community_actions::became_moderator)
+      .select(community_actions::person_id)
+      .load::<PersonId>(conn)
+      .await?;
+    if moderators.first() == Some(&person_id) {
+      return Ok(CommunityRole::Owner);
+    }
+    if moderators.contains(&person_id) {
+      return Ok(CommunityRole::Moderator);
+    }
+
+    let role = find_action(community_actions::role, (person_id, community_id))
+      .select(community_actions::role.assume_not_null())
+      .first::<Self>(conn)
+      .await
+      .optional()?;
+    Ok(role.unwrap_or(CommunityRole::Member))
+  }
+
+  /// Stores a role which isn't derived from the moderator list. Setting a member removes the
+  /// stored role.
+  pub async fn set(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    person_id: PersonId,
+    role: CommunityRole,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    if role == CommunityRole::Member {
+      uplete::new(community_actions::table.find((person_id, community_id)))
+        .set_null(community_actions::role)
+        .get_result::<uplete::Count>(conn)
+        .await?;
+    } else {
+      insert_into(community_actions::table)
+        .values((
+          community_actions::community_id.eq(community_id),
+          community_actions::person_id.eq(person_id),
+          community_actions::role.eq(role),
+        ))
+        .on_conflict((
+          community_actions::person_id,
+          community_actions::community_id,
+        ))
+        .do_update()
+        .set(community_actions::role.eq(role))
+        .execute(conn)
+        .await?;
+    }
+    Ok(())
+  }
+}
+
+impl CommunityRolePermission {
+  /// The permissions of a role, or its defaults if the owner didn't change them.
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    role: CommunityRole,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let permission = community_role_permission::table
+      .find((community_id, role))
+      .first::<Self>(conn)
+      .await
+      .optional()?;
+    Ok(permission.unwrap_or_else(|| Self::default_for(community_id, role)))
+  }
+
+  /// The permissions of all roles in a community.
+  pub async fn list(pool: &mut DbPool<'_>, community_id: CommunityId) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let changed = community_role_permission::table
+      .filter(community_role_permission::community_id.eq(community_id))
+      .load::<Self>(conn)
+      .await?;
+    Ok(
+      [
+        CommunityRole::Owner,
+        CommunityRole::Moderator,
+        CommunityRole::TrustedContributor,
+        CommunityRole::Member,
+      ]
+      .into_iter()
+      .map(|role| {
+        changed
+          .iter()
+          .find(|p| p.role == role)
+          .cloned()
+          .unwrap_or_else(|| Self::default_for(community_id, role))
+      })
+      .collect(),
+    )
+  }
+
+  pub async fn upsert(
+    pool: &mut DbPool<'_>,
+    form: &CommunityRolePermissionForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_role_permission::table)
+      .values(form)
+      .on_conflict((
+        community_role_permission::community_id,
+        community_role_permission::role,
+      ))
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm, CommunityModerator, CommunityModeratorForm},
+      community_role::{CommunityPermission, CommunityRolePermission, CommunityRolePermissionForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    traits::{Crud, Joinable},
+    utils::build_db_pool_for_tests,
+    CommunityRole,
+  };
+  use chrono::Utc;
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_roles_and_permissions() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let owner = Person::create(pool, &PersonInsertForm::test_form(instance.id, "ilse")).await?;
+    let moderator =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "jonas")).await?;
+    let member = Person::create(pool, &PersonInsertForm::test_form(instance.id, "klara")).await?;
+    let community_form = CommunityInsertForm::new(
+      instance.id,
+      "roles".into(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    for person_id in [owner.id, moderator.id] {
+      let form = CommunityModeratorForm {
+        community_id: community.id,
+        person_id,
+      };
+      CommunityModerator::join(pool, &form).await?;
+    }
+
+    for (person_id, expected) in [
+      (owner.id, CommunityRole::Owner),
+      (moderator.id, CommunityRole::Moderator),
+      (member.id, CommunityRole::Member),
+    ] {
+      assert_eq!(
+        expected,
+        CommunityRole::read(pool, community.id, person_id).await?
+      );
+    }
+
+    CommunityRole::set(
+      pool,
+      community.id,
+      member.id,
+      CommunityRole::TrustedContributor,
+    )
+    .await?;
+    assert_eq!(
+      CommunityRole::TrustedContributor,
+      CommunityRole::read(pool, community.id, member.id).await?
+    );
+
+    // Trusted contributors can't do anything until the owner allows it
+    let permission =
+      CommunityRolePermission::read(pool, community.id, CommunityRole::TrustedContributor).await?;
+    assert!(!permission.allows(CommunityPermission::RemoveComment));
+    let form = CommunityRolePermissionForm {
+      community_id: community.id,
+      role: CommunityRole::TrustedContributor,
+      can_feature_posts: false,
+      can_remove_comments: true,
+      can_edit_sidebar: false,
+      updated: Utc::now(),
+      can_view_votes: false,
+    };
+    CommunityRolePermission::upsert(pool, &form).await?;
+    let permission =
+      CommunityRolePermission::read(pool, community.id, CommunityRole::TrustedContributor).await?;
+    assert!(permission.allows(CommunityPermission::RemoveComment));
+    assert!(!permission.allows(CommunityPermission::FeaturePost));
+    assert!(!permission.allows(CommunityPermission::ViewVotes));
+
+    let permissions = CommunityRolePermission::list(pool, community.id).await?;
+    assert_eq!(4, permissions.len());
+    assert!(permissions
+      .iter()
+      .all(|p| p.updated.is_some() == (p.role == CommunityRole::TrustedContributor)));
+
+    CommunityRole::set(pool, community.id, member.id, CommunityRole::Member).await?;
+    assert_eq!(
+      CommunityRole::Member,
+      CommunityRole::read(pool, community.id, member.id).await?
+    );
+
+    Community::delete(pool, community.id).await?;
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+}
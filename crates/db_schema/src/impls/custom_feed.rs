@@ -0,0 +1,194 @@
+use crate::{
+  diesel::OptionalExtension,
+  newtypes::{CommunityId, CustomFeedId, PersonId},
+  schema::{community, custom_feed, custom_feed_community},
+  source::{
+    community::Community,
+    custom_feed::{
+      CustomFeed,
+      CustomFeedCommunityForm,
+      CustomFeedInsertForm,
+      CustomFeedUpdateForm,
+    },
+  },
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for CustomFeed {
+  type InsertForm = CustomFeedInsertForm;
+  type UpdateForm = CustomFeedUpdateForm;
+  type IdType = CustomFeedId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(custom_feed::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    custom_feed_id: CustomFeedId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(custom_feed::table.find(custom_feed_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl CustomFeed {
+  pub async fn read_from_slug(pool: &mut DbPool<'_>, slug: &str) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    custom_feed::table
+      .filter(custom_feed::slug.eq(slug))
+      .first(conn)
+      .await
+      .optional()
+  }
+
+  /// The feeds created by the person, ordered by name.
+  pub async fn list_for_person(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    custom_feed::table
+      .filter(custom_feed::creator_id.eq(person_id))
+      .order_by(custom_feed::name)
+      .load(conn)
+      .await
+  }
+
+  /// The communities whose posts are shown in the feed, ordered by name.
+  pub async fn communities(
+    pool: &mut DbPool<'_>,
+    custom_feed_id: CustomFeedId,
+  ) -> Result<Vec<Community>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    custom_feed_community::table
+      .inner_join(community::table)
+      .filter(custom_feed_community::custom_feed_id.eq(custom_feed_id))
+      .order_by(community::name)
+      .select(community::all_columns)
+      .load(conn)
+      .await
+  }
+
+  /// Replaces the communities of the feed.
+  pub async fn set_communities(
+    pool: &mut DbPool<'_>,
+    custom_feed_id: CustomFeedId,
+    community_ids: &[CommunityId],
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    let forms = community_ids
+      .iter()
+      .map(|community_id| CustomFeedCommunityForm {
+        custom_feed_id,
+        community_id: *community_id,
+      })
+      .collect::<Vec<_>>();
+
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          diesel::delete(
+            custom_feed_community::table
+              .filter(custom_feed_community::custom_feed_id.eq(custom_feed_id)),
+          )
+          .execute(conn)
+          .await?;
+          insert_into(custom_feed_community::table)
+            .values(forms)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+          Ok(())
+        }) as _
+      })
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::*;
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_custom_feed() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "feed_creator");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let community_form = |name: &str| {
+      CommunityInsertForm::new(
+        inserted_instance.id,
+        name.to_string(),
+        "nada".to_owned(),
+        "pubkey".to_string(),
+      )
+    };
+    let rust = Community::create(pool, &community_form("feed_rust")).await?;
+    let go = Community::create(pool, &community_form("feed_go")).await?;
+    let cooking = Community::create(pool, &community_form("feed_cooking")).await?;
+
+    let form = CustomFeedInsertForm {
+      public: Some(true),
+      slug: Some("programming".to_string()),
+      ..CustomFeedInsertForm::new(inserted_person.id, "Programming".to_string())
+    };
+    let feed = CustomFeed::create(pool, &form).await?;
+    CustomFeed::set_communities(pool, feed.id, &[rust.id, go.id]).await?;
+    assert_eq!(
+      vec![go, rust.clone()],
+      CustomFeed::communities(pool, feed.id).await?
+    );
+
+    // Setting the communities replaces the previous ones
+    CustomFeed::set_communities(pool, feed.id, &[rust.id, cooking.id]).await?;
+    assert_eq!(
+      vec![cooking, rust],
+      CustomFeed::communities(pool, feed.id).await?
+    );
+
+    assert_eq!(
+      Some(feed.clone()),
+      CustomFeed::read_from_slug(pool, "programming").await?
+    );
+    assert_eq!(
+      vec![feed.clone()],
+      CustomFeed::list_for_person(pool, inserted_person.id).await?
+    );
+
+    CustomFeed::delete(pool, feed.id).await?;
+    assert_eq!(None, CustomFeed::read_from_slug(pool, "programming").await?);
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
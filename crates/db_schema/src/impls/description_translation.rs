@@ -0,0 +1,168 @@
+use crate::{
+  newtypes::{CommunityId, LanguageId, SiteId},
+  schema::{community_description_translation, site_description_translation},
+  source::description_translation::{
+    CommunityDescriptionTranslation,
+    CommunityDescriptionTranslationForm,
+    SiteDescriptionTranslation,
+    SiteDescriptionTranslationForm,
+  },
+  utils::{get_conn, naive_now, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl CommunityDescriptionTranslation {
+  /// Creates the translation, or replaces an existing one in the same language.
+  pub async fn upsert(
+    pool: &mut DbPool<'_>,
+    form: &CommunityDescriptionTranslationForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_description_translation::table)
+      .values(form)
+      .on_conflict((
+        community_description_translation::community_id,
+        community_description_translation::language_id,
+      ))
+      .do_update()
+      .set((
+        community_description_translation::sidebar.eq(&form.sidebar),
+        community_description_translation::description.eq(&form.description),
+        community_description_translation::updated.eq(naive_now()),
+      ))
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+    language_id: LanguageId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(community_description_translation::table.find((community_id, language_id)))
+      .execute(conn)
+      .await
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>, community_id: CommunityId) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_description_translation::table
+      .filter(community_description_translation::community_id.eq(community_id))
+      .order_by(community_description_translation::language_id)
+      .load(conn)
+      .await
+  }
+}
+
+impl SiteDescriptionTranslation {
+  /// Creates the translation, or replaces an existing one in the same language.
+  pub async fn upsert(
+    pool: &mut DbPool<'_>,
+    form: &SiteDescriptionTranslationForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(site_description_translation::table)
+      .values(form)
+      .on_conflict((
+        site_description_translation::site_id,
+        site_description_translation::language_id,
+      ))
+      .do_update()
+      .set((
+        site_description_translation::sidebar.eq(&form.sidebar),
+        site_description_translation::description.eq(&form.description),
+        site_description_translation::updated.eq(naive_now()),
+      ))
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    site_id: SiteId,
+    language_id: LanguageId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(site_description_translation::table.find((site_id, language_id)))
+      .execute(conn)
+      .await
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>, site_id: SiteId) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    site_description_translation::table
+      .filter(site_description_translation::site_id.eq(site_id))
+      .order_by(site_description_translation::language_id)
+      .load(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      description_translation::{
+        CommunityDescriptionTranslation,
+        CommunityDescriptionTranslationForm,
+      },
+      instance::Instance,
+      language::Language,
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_community_description_translation() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "translated".into(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+    let german = Language::read_id_from_code(pool, "de").await?;
+
+    let form = CommunityDescriptionTranslationForm {
+      sidebar: Some("Seitenleiste".to_string()),
+      ..CommunityDescriptionTranslationForm::new(inserted_community.id, german)
+    };
+    CommunityDescriptionTranslation::upsert(pool, &form).await?;
+
+    // Upserting again replaces the translation
+    let form = CommunityDescriptionTranslationForm {
+      description: Some("Beschreibung".to_string()),
+      ..CommunityDescriptionTranslationForm::new(inserted_community.id, german)
+    };
+    let updated = CommunityDescriptionTranslation::upsert(pool, &form).await?;
+    assert_eq!(None, updated.sidebar);
+    assert!(updated.updated.is_some());
+
+    let translations = CommunityDescriptionTranslation::list(pool, inserted_community.id).await?;
+    assert_eq!(vec![updated], translations);
+
+    CommunityDescriptionTranslation::delete(pool, inserted_community.id, german).await?;
+    assert!(
+      CommunityDescriptionTranslation::list(pool, inserted_community.id)
+        .await?
+        .is_empty()
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
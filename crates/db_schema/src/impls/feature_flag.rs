@@ -0,0 +1,85 @@
+use crate::{
+  schema::feature_flag,
+  source::feature_flag::{FeatureFlag, FeatureFlagForm},
+  utils::{get_conn, naive_now, DbPool},
+  FeatureFlagName,
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl FeatureFlag {
+  /// Creates the flag, or replaces the configuration of an existing one for the same feature.
+  pub async fn upsert(pool: &mut DbPool<'_>, form: &FeatureFlagForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(feature_flag::table)
+      .values(form)
+      .on_conflict(feature_flag::feature)
+      .do_update()
+      .set((
+        feature_flag::enabled.eq(form.enabled),
+        feature_flag::rollout_percentage.eq(form.rollout_percentage),
+        feature_flag::updated.eq(naive_now()),
+      ))
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn delete(pool: &mut DbPool<'_>, feature: FeatureFlagName) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(feature_flag::table.find(feature))
+      .execute(conn)
+      .await
+  }
+
+  pub async fn list(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    feature_flag::table
+      .order_by(feature_flag::feature)
+      .load(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::feature_flag::{FeatureFlag, FeatureFlagForm},
+    utils::build_db_pool_for_tests,
+    FeatureFlagName,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_feature_flag_upsert() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let form = FeatureFlagForm {
+      feature: FeatureFlagName::Polls,
+      enabled: true,
+      rollout_percentage: 10,
+    };
+    let created = FeatureFlag::upsert(pool, &form).await?;
+    assert!(created.updated.is_none());
+
+    let form = FeatureFlagForm {
+      rollout_percentage: 50,
+      ..form
+    };
+    let updated = FeatureFlag::upsert(pool, &form).await?;
+    assert_eq!(50, updated.rollout_percentage);
+    assert!(updated.updated.is_some());
+
+    let flags = FeatureFlag::list(pool).await?;
+    assert_eq!(vec![updated], flags);
+
+    FeatureFlag::delete(pool, FeatureFlagName::Polls).await?;
+    assert!(FeatureFlag::list(pool).await?.is_empty());
+
+    Ok(())
+  }
+}
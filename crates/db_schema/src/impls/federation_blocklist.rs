@@ -1,15 +1,22 @@
 use crate::{
+  newtypes::InstanceId,
   schema::federation_blocklist,
   source::{
-    federation_blocklist::{FederationBlockList, FederationBlockListForm},
+    federation_blocklist::{
+      FederationBlockList,
+      FederationBlockListForm,
+      FederationBlockListUpdateForm,
+    },
     instance::Instance,
   },
   utils::{get_conn, DbPool},
 };
-use diesel::{dsl::insert_into, result::Error};
-use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
 
 impl FederationBlockList {
+  /// Replaces the blocklist with the given domains. Entries which are already blocked keep their
+  /// original block date and reason.
   pub async fn replace(pool: &mut DbPool<'_>, list_opt: Option<Vec<String>>) -> Result<(), Error> {
     let conn = &mut get_conn(pool).await?;
     conn
@@ -17,8 +24,7 @@ impl FederationBlockList {
       .run(|conn| {
         Box::pin(async move {
           if let Some(list) = list_opt {
-            Self::clear(conn).await?;
-
+            let mut instance_ids = Vec::with_capacity(list.len());
             for domain in list {
               // Upsert all of these as instances
               let instance = Instance::read_or_create(&mut conn.into(), domain).await?;
@@ -29,9 +35,18 @@ impl FederationBlockList {
               };
               insert_into(federation_blocklist::table)
                 .values(form)
-                .get_result::<Self>(conn)
+                .on_conflict_do_nothing()
+                .execute(conn)
                 .await?;
+              instance_ids.push(instance.id);
             }
+
+            diesel::delete(
+              federation_blocklist::table
+                .filter(federation_blocklist::instance_id.ne_all(instance_ids)),
+            )
+            .execute(conn)
+            .await?;
             Ok(())
           } else {
             Ok(())
@@ -41,9 +56,15 @@ impl FederationBlockList {
       .await
   }
 
-  async fn clear(conn: &mut AsyncPgConnection) -> Result<usize, Error> {
-    diesel::delete(federation_blocklist::table)
-      .execute(conn)
+  pub async fn update(
+    pool: &mut DbPool<'_>,
+    instance_id: InstanceId,
+    form: &FederationBlockListUpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(federation_blocklist::table.find(instance_id))
+      .set(form)
+      .get_result::<Self>(conn)
       .await
   }
 }
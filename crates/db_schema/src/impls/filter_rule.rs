@@ -0,0 +1,154 @@
+use crate::{
+  newtypes::{CommunityId, FilterRuleId},
+  schema::filter_rule,
+  source::filter_rule::{FilterRule, FilterRuleInsertForm, FilterRuleUpdateForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for FilterRule {
+  type InsertForm = FilterRuleInsertForm;
+  type UpdateForm = FilterRuleUpdateForm;
+  type IdType = FilterRuleId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(filter_rule::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    rule_id: FilterRuleId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(filter_rule::table.find(rule_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl FilterRule {
+  /// The rules of a single community, or the site wide rules if no community is given.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    community_id: Option<CommunityId>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let query = filter_rule::table.order_by(filter_rule::id).into_boxed();
+    let query = if let Some(community_id) = community_id {
+      query.filter(filter_rule::community_id.eq(community_id))
+    } else {
+      query.filter(filter_rule::community_id.is_null())
+    };
+    query.load::<Self>(conn).await
+  }
+
+  /// The rules which apply to content in the given community. A community rule replaces the site
+  /// wide rule with the same pattern and conditions.
+  pub async fn for_community(
+    pool: &mut DbPool<'_>,
+    community_id: CommunityId,
+  ) -> Result<Vec<Self>, Error> {
+    let community_rules = Self::list(pool, Some(community_id)).await?;
+    let site_rules = Self::list(pool, None).await?;
+    let mut rules = site_rules
+      .into_iter()
+      .filter(|s| !community_rules.iter().any(|c| c.same_trigger(s)))
+      .collect::<Vec<_>>();
+    rules.extend(community_rules);
+    Ok(rules)
+  }
+
+  /// Whether both rules match the same content.
+  fn same_trigger(&self, other: &Self) -> bool {
+    self.pattern == other.pattern
+      && self.link_domain == other.link_domain
+      && self.max_account_age_days == other.max_account_age_days
+      && self.max_reputation == other.max_reputation
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::*;
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    utils::build_db_pool_for_tests,
+    FilterRuleAction,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_community_overrides_site_rule() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "filter_admin");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "filter_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+
+    let site_form = |pattern: &str| {
+      FilterRuleInsertForm::new(
+        None,
+        pattern.to_string(),
+        false,
+        FilterRuleAction::Reject,
+        inserted_person.id,
+      )
+    };
+    let overridden = FilterRule::create(pool, &site_form("spoiler")).await?;
+    let kept = FilterRule::create(pool, &site_form("slur")).await?;
+    let override_form = FilterRuleInsertForm::new(
+      Some(inserted_community.id),
+      "spoiler".to_string(),
+      false,
+      FilterRuleAction::Report,
+      inserted_person.id,
+    );
+    let community_rule = FilterRule::create(pool, &override_form).await?;
+
+    let site_rules = FilterRule::list(pool, None).await?;
+    assert_eq!(vec![overridden, kept.clone()], site_rules);
+
+    let effective = FilterRule::for_community(pool, inserted_community.id).await?;
+    assert_eq!(vec![kept.clone(), community_rule.clone()], effective);
+
+    // A community rule with the same pattern but other conditions doesn't replace the site rule
+    let domain_form = FilterRuleInsertForm {
+      link_domain: Some("example.com".to_string()),
+      ..override_form
+    };
+    let domain_rule = FilterRule::create(pool, &domain_form).await?;
+    let effective = FilterRule::for_community(pool, inserted_community.id).await?;
+    assert_eq!(vec![kept, community_rule, domain_rule], effective);
+
+    Community::delete(pool, inserted_community.id).await?;
+    Person::delete(pool, inserted_person.id).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
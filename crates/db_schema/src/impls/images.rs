@@ -1,7 +1,15 @@
 use crate::{
   newtypes::DbUrl,
-  schema::{image_details, local_image, remote_image},
-  source::images::{ImageDetails, ImageDetailsForm, LocalImage, LocalImageForm, RemoteImage},
+  schema::{image_details, image_text, local_image, remote_image},
+  source::images::{
+    ImageDetails,
+    ImageDetailsForm,
+    ImageText,
+    ImageTextForm,
+    LocalImage,
+    LocalImageForm,
+    RemoteImage,
+  },
   utils::{get_conn, DbPool},
 };
 use diesel::{
@@ -94,3 +102,16 @@ impl ImageDetails {
       .await
   }
 }
+
+impl ImageText {
+  pub async fn upsert(pool: &mut DbPool<'_>, form: &ImageTextForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(image_text::table)
+      .values(form)
+      .on_conflict(image_text::pictrs_alias)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
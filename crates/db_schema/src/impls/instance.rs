@@ -10,6 +10,8 @@ use crate::{
     site,
   },
   source::{
+    federation_allowlist::FederationAllowList,
+    federation_blocklist::FederationBlockList,
     federation_queue_state::FederationQueueState,
     instance::{Instance, InstanceForm},
   },
@@ -20,6 +22,7 @@ use crate::{
     now,
     DbPool,
   },
+  InstanceTrustLevel,
 };
 use diesel::{
   dsl::{count_star, insert_into},
@@ -91,6 +94,18 @@ impl Instance {
       .await
   }
 
+  pub async fn set_trust_level(
+    pool: &mut DbPool<'_>,
+    instance_id: InstanceId,
+    trust_level: InstanceTrustLevel,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(instance::table.find(instance_id))
+      .set(instance::trust_level.eq(trust_level))
+      .get_result(conn)
+      .await
+  }
+
   pub async fn read_all(pool: &mut DbPool<'_>) -> Result<Vec<Instance>, Error> {
     let conn = &mut get_conn(pool).await?;
     instance::table
@@ -126,6 +141,41 @@ impl Instance {
       .await
   }
 
+  pub async fn trusted_list(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    instance::table
+      .filter(instance::trust_level.eq(InstanceTrustLevel::Trusted))
+      .select(Self::as_select())
+      .get_results(conn)
+      .await
+  }
+
+  /// Blocked instances together with the date and reason of the block, ordered by domain
+  pub async fn blocklist_with_details(
+    pool: &mut DbPool<'_>,
+  ) -> Result<Vec<(Self, FederationBlockList)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    instance::table
+      .inner_join(federation_blocklist::table)
+      .select((Self::as_select(), FederationBlockList::as_select()))
+      .order_by(instance::domain)
+      .get_results(conn)
+      .await
+  }
+
+  /// Allowed instances together with the date they were allowed, ordered by domain
+  pub async fn allowlist_with_details(
+    pool: &mut DbPool<'_>,
+  ) -> Result<Vec<(Self, FederationAllowList)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    instance::table
+      .inner_join(federation_allowlist::table)
+      .select((Self::as_select(), FederationAllowList::as_select()))
+      .order_by(instance::domain)
+      .get_results(conn)
+      .await
+  }
+
   /// returns a list of all instances, each with a flag of whether the instance is allowed or not
   /// and dead or not ordered by id
   pub async fn read_federated_with_blocked_and_dead(
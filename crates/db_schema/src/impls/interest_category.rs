@@ -0,0 +1,103 @@
+use crate::{
+  newtypes::{CommunityId, InterestCategoryId},
+  schema::{interest_category, interest_category_community},
+  source::interest_category::{
+    InterestCategory,
+    InterestCategoryCommunityForm,
+    InterestCategoryInsertForm,
+    InterestCategoryUpdateForm,
+  },
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{delete, insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for InterestCategory {
+  type InsertForm = InterestCategoryInsertForm;
+  type UpdateForm = InterestCategoryUpdateForm;
+  type IdType = InterestCategoryId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(interest_category::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    category_id: InterestCategoryId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(interest_category::table.find(category_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl InterestCategory {
+  pub async fn list(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    interest_category::table
+      .order(interest_category::name)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// The communities which admins picked for this category.
+  pub async fn communities(
+    pool: &mut DbPool<'_>,
+    category_id: InterestCategoryId,
+  ) -> Result<Vec<CommunityId>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    interest_category_community::table
+      .filter(interest_category_community::category_id.eq(category_id))
+      .order(interest_category_community::published)
+      .select(interest_category_community::community_id)
+      .load::<CommunityId>(conn)
+      .await
+  }
+
+  /// Replaces the communities which admins picked for this category.
+  pub async fn set_communities(
+    pool: &mut DbPool<'_>,
+    category_id: InterestCategoryId,
+    community_ids: Vec<CommunityId>,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          // Keep existing picks, so that their order is preserved
+          delete(
+            interest_category_community::table
+              .filter(interest_category_community::category_id.eq(category_id))
+              .filter(interest_category_community::community_id.ne_all(&community_ids)),
+          )
+          .execute(conn)
+          .await?;
+
+          let forms = community_ids
+            .iter()
+            .map(|&community_id| InterestCategoryCommunityForm {
+              category_id,
+              community_id,
+            })
+            .collect::<Vec<_>>();
+          insert_into(interest_category_community::table)
+            .values(forms)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+          Ok(())
+        }) as _
+      })
+      .await
+  }
+}
@@ -9,6 +9,13 @@ use lemmy_utils::{error::LemmyResult, CACHE_DURATION_API};
 use moka::future::Cache;
 use std::sync::LazyLock;
 
+static CACHE: LazyLock<Cache<(), LocalSite>> = LazyLock::new(|| {
+  Cache::builder()
+    .max_capacity(1)
+    .time_to_live(CACHE_DURATION_API)
+    .build()
+});
+
 impl LocalSite {
   pub async fn create(pool: &mut DbPool<'_>, form: &LocalSiteInsertForm) -> Result<Self, Error> {
     let conn = &mut get_conn(pool).await?;
@@ -18,12 +25,6 @@ impl LocalSite {
       .await
   }
   pub async fn read(pool: &mut DbPool<'_>) -> LemmyResult<Self> {
-    static CACHE: LazyLock<Cache<(), LocalSite>> = LazyLock::new(|| {
-      Cache::builder()
-        .max_capacity(1)
-        .time_to_live(CACHE_DURATION_API)
-        .build()
-    });
     Ok(
       CACHE
         .try_get_with((), async {
@@ -33,6 +34,10 @@ impl LocalSite {
         .await?,
     )
   }
+  /// Drops the cached local site, so that the next read gets it from the database.
+  pub fn invalidate_cache() {
+    CACHE.invalidate_all();
+  }
   pub async fn update(pool: &mut DbPool<'_>, form: &LocalSiteUpdateForm) -> Result<Self, Error> {
     let conn = &mut get_conn(pool).await?;
     diesel::update(local_site::table)
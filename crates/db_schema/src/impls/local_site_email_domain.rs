@@ -0,0 +1,96 @@
+use crate::{
+  schema::local_site_email_domain,
+  source::local_site_email_domain::{LocalSiteEmailDomain, LocalSiteEmailDomainForm},
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl LocalSiteEmailDomain {
+  /// Replaces either the allowlist or the blocklist of email domains.
+  pub async fn replace(
+    pool: &mut DbPool<'_>,
+    allowed: bool,
+    domains: Vec<String>,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          diesel::delete(
+            local_site_email_domain::table.filter(local_site_email_domain::allowed.eq(allowed)),
+          )
+          .execute(conn)
+          .await?;
+
+          let forms = domains
+            .into_iter()
+            .map(|domain| LocalSiteEmailDomainForm { domain, allowed })
+            .collect::<Vec<_>>();
+
+          insert_into(local_site_email_domain::table)
+            .values(forms)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+
+          Ok(())
+        }) as _
+      })
+      .await
+  }
+
+  pub async fn get_all(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    local_site_email_domain::table
+      .order_by(local_site_email_domain::domain)
+      .get_results::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::local_site_email_domain::LocalSiteEmailDomain,
+    utils::build_db_pool_for_tests,
+  };
+  use diesel::result::Error;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_replace_email_domains() -> Result<(), Error> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    LocalSiteEmailDomain::replace(pool, true, vec!["example.com".to_string()]).await?;
+    LocalSiteEmailDomain::replace(
+      pool,
+      false,
+      vec!["*.spam.example".to_string(), "*.spam.example".to_string()],
+    )
+    .await?;
+    assert_eq!(2, LocalSiteEmailDomain::get_all(pool).await?.len());
+
+    // Replacing the blocklist keeps the allowlist
+    LocalSiteEmailDomain::replace(pool, false, vec![]).await?;
+    let domains = LocalSiteEmailDomain::get_all(pool).await?;
+    assert_eq!(
+      vec![("example.com", true)],
+      domains
+        .iter()
+        .map(|d| (d.domain.as_str(), d.allowed))
+        .collect::<Vec<_>>()
+    );
+
+    LocalSiteEmailDomain::replace(pool, true, vec![]).await?;
+    assert!(LocalSiteEmailDomain::get_all(pool).await?.is_empty());
+
+    Ok(())
+  }
+}
@@ -58,6 +58,10 @@ impl LocalSiteRateLimitUpdateForm {
       && self.comment_per_second.is_none()
       && self.search.is_none()
       && self.search_per_second.is_none()
+      && self.register_subnet.is_none()
+      && self.register_subnet_per_second.is_none()
+      && self.register_asn.is_none()
+      && self.register_asn_per_second.is_none()
       && self.updated.is_none()
   }
 }
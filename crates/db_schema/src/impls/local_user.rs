@@ -3,8 +3,11 @@ use crate::{
   schema::{community, community_actions, local_user, person, registration_application},
   source::{
     actor_language::LocalUserLanguage,
+    comment::Comment,
+    local_site::LocalSite,
     local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
     local_user_vote_display_mode::{LocalUserVoteDisplayMode, LocalUserVoteDisplayModeInsertForm},
+    post::Post,
     site::Site,
   },
   utils::{
@@ -15,6 +18,7 @@ use crate::{
     DbPool,
   },
   CommunityVisibility,
+  DefaultUserSetting,
 };
 use bcrypt::{hash, DEFAULT_COST};
 use diesel::{
@@ -23,7 +27,10 @@ use diesel::{
   CombineDsl,
   ExpressionMethods,
   JoinOnDsl,
+  NullableExpressionMethods,
+  PgArrayExpressionMethods,
   QueryDsl,
+  SelectableHelper,
 };
 use diesel_async::RunQueryDsl;
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
@@ -34,8 +41,12 @@ impl LocalUser {
     form: &LocalUserInsertForm,
     languages: Vec<LanguageId>,
   ) -> Result<LocalUser, Error> {
-    let conn = &mut get_conn(pool).await?;
     let mut form_with_encrypted_password = form.clone();
+    // Settings which aren't given explicitly come from the defaults configured by the admins
+    if let Ok(local_site) = LocalSite::read(pool).await {
+      form_with_encrypted_password.apply_site_defaults(&local_site);
+    }
+    let conn = &mut get_conn(pool).await?;
 
     if let Some(password_encrypted) = &form.password_encrypted {
       let password_hash = hash(password_encrypted, DEFAULT_COST).expect("Couldn't hash password");
@@ -73,6 +84,123 @@ impl LocalUser {
     }
   }
 
+  /// Sets the setting to the site default for all users who didn't change it themselves.
+  pub async fn apply_site_default(
+    pool: &mut DbPool<'_>,
+    setting: DefaultUserSetting,
+    local_site: &LocalSite,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let users =
+      local_user::table.filter(not(local_user::customized_settings.contains(vec![setting])));
+    let update = diesel::update(users);
+    match setting {
+      DefaultUserSetting::PostSortType => {
+        update
+          .set(local_user::default_post_sort_type.eq(local_site.default_post_sort_type))
+          .execute(conn)
+          .await
+      }
+      DefaultUserSetting::CommentSortType => {
+        update
+          .set(local_user::default_comment_sort_type.eq(local_site.default_comment_sort_type))
+          .execute(conn)
+          .await
+      }
+      DefaultUserSetting::ListingType => {
+        update
+          .set(local_user::default_listing_type.eq(local_site.default_post_listing_type))
+          .execute(conn)
+          .await
+      }
+      DefaultUserSetting::ShowNsfw => {
+        update
+          .set(local_user::show_nsfw.eq(local_site.default_show_nsfw))
+          .execute(conn)
+          .await
+      }
+      DefaultUserSetting::ShowBotAccounts => {
+        update
+          .set(local_user::show_bot_accounts.eq(local_site.default_show_bot_accounts))
+          .execute(conn)
+          .await
+      }
+      DefaultUserSetting::SendNotificationsToEmail => {
+        update
+          .set(
+            local_user::send_notifications_to_email
+              .eq(local_site.default_send_notifications_to_email),
+          )
+          .execute(conn)
+          .await
+      }
+      DefaultUserSetting::InterfaceLanguage => {
+        update
+          .set(local_user::interface_language.eq(&local_site.default_interface_language))
+          .execute(conn)
+          .await
+      }
+    }
+  }
+
+  /// The settings which the user customized after applying the form, or none if the form doesn't
+  /// change any setting which has a site default.
+  pub fn customized_settings_after(
+    &self,
+    form: &LocalUserUpdateForm,
+  ) -> Option<Vec<DefaultUserSetting>> {
+    let changes = [
+      (
+        DefaultUserSetting::PostSortType,
+        form
+          .default_post_sort_type
+          .is_some_and(|v| v != self.default_post_sort_type),
+      ),
+      (
+        DefaultUserSetting::CommentSortType,
+        form
+          .default_comment_sort_type
+          .is_some_and(|v| v != self.default_comment_sort_type),
+      ),
+      (
+        DefaultUserSetting::ListingType,
+        form
+          .default_listing_type
+          .is_some_and(|v| v != self.default_listing_type),
+      ),
+      (
+        DefaultUserSetting::ShowNsfw,
+        form.show_nsfw.is_some_and(|v| v != self.show_nsfw),
+      ),
+      (
+        DefaultUserSetting::ShowBotAccounts,
+        form
+          .show_bot_accounts
+          .is_some_and(|v| v != self.show_bot_accounts),
+      ),
+      (
+        DefaultUserSetting::SendNotificationsToEmail,
+        form
+          .send_notifications_to_email
+          .is_some_and(|v| v != self.send_notifications_to_email),
+      ),
+      (
+        DefaultUserSetting::InterfaceLanguage,
+        form
+          .interface_language
+          .as_ref()
+          .is_some_and(|v| *v != self.interface_language),
+      ),
+    ];
+    let mut settings = self.customized_settings.clone();
+    for (setting, changed) in changes {
+      if changed && !settings.contains(&setting) {
+        settings.push(setting);
+      }
+    }
+    (settings.len() != self.customized_settings.len()).then_some(settings)
+  }
+
   pub async fn delete(pool: &mut DbPool<'_>, id: LocalUserId) -> Result<usize, Error> {
     let conn = &mut *get_conn(pool).await?;
     diesel::delete(local_user::table.find(id))
@@ -181,6 +309,13 @@ impl LocalUser {
       .get_results(conn)
       .await?;
 
+    let read_posts = action_query(post_actions::read)
+      .filter(post_actions::person_id.eq(person_id_))
+      .inner_join(post::table)
+      .select(post::ap_id)
+      .get_results(conn)
+      .await?;
+
     let saved_comments = action_query(comment_actions::saved)
       .filter(comment_actions::person_id.eq(person_id_))
       .inner_join(comment::table)
@@ -214,6 +349,7 @@ impl LocalUser {
     Ok(UserBackupLists {
       followed_communities,
       saved_posts,
+      read_posts,
       saved_comments,
       blocked_communities,
       blocked_users,
@@ -221,6 +357,57 @@ impl LocalUser {
     })
   }
 
+  /// Everything the user has written or voted on, for a full export of their data.
+  pub async fn export_content(
+    pool: &mut DbPool<'_>,
+    person_id_: PersonId,
+  ) -> Result<UserContentLists, Error> {
+    use crate::schema::{comment, comment_actions, post, post_actions};
+    let conn = &mut get_conn(pool).await?;
+
+    let mut posts = post::table
+      .filter(post::creator_id.eq(person_id_))
+      .order_by(post::published)
+      .select(Post::as_select())
+      .get_results(conn)
+      .await?;
+
+    let mut comments = comment::table
+      .filter(comment::creator_id.eq(person_id_))
+      .order_by(comment::published)
+      .select(Comment::as_select())
+      .get_results(conn)
+      .await?;
+
+    // Export the original text of archived content
+    Post::restore_archived(&mut conn.into(), &mut posts).await?;
+    Comment::restore_archived(&mut conn.into(), &mut comments).await?;
+
+    let post_votes = action_query(post_actions::like_score)
+      .filter(post_actions::person_id.eq(person_id_))
+      .inner_join(post::table)
+      .select((post::ap_id, post_actions::like_score.assume_not_null()))
+      .get_results(conn)
+      .await?;
+
+    let comment_votes = action_query(comment_actions::like_score)
+      .filter(comment_actions::person_id.eq(person_id_))
+      .inner_join(comment::table)
+      .select((
+        comment::ap_id,
+        comment_actions::like_score.assume_not_null(),
+      ))
+      .get_results(conn)
+      .await?;
+
+    Ok(UserContentLists {
+      posts,
+      comments,
+      post_votes,
+      comment_votes,
+    })
+  }
+
   /// Checks to make sure the acting admin is higher than the target admin
   pub async fn is_higher_admin_check(
     pool: &mut DbPool<'_>,
@@ -293,6 +480,7 @@ pub trait LocalUserOptionHelper {
   fn person_id(&self) -> Option<PersonId>;
   fn local_user_id(&self) -> Option<LocalUserId>;
   fn show_bot_accounts(&self) -> bool;
+  fn downrank_bot_accounts(&self) -> bool;
   fn show_read_posts(&self) -> bool;
   fn is_admin(&self) -> bool;
   fn show_nsfw(&self, site: &Site) -> bool;
@@ -317,6 +505,10 @@ impl LocalUserOptionHelper for Option<&LocalUser> {
     self.map(|l| l.show_bot_accounts).unwrap_or(true)
   }
 
+  fn downrank_bot_accounts(&self) -> bool {
+    self.is_some_and(|l| l.downrank_bot_accounts)
+  }
+
   fn show_read_posts(&self) -> bool {
     self.map(|l| l.show_read_posts).unwrap_or(true)
   }
@@ -348,6 +540,30 @@ impl LocalUserOptionHelper for Option<&LocalUser> {
 }
 
 impl LocalUserInsertForm {
+  /// Fills in the settings which weren't set explicitly with the defaults configured by the admins.
+  fn apply_site_defaults(&mut self, local_site: &LocalSite) {
+    self.default_post_sort_type = self
+      .default_post_sort_type
+      .or(Some(local_site.default_post_sort_type));
+    self.default_comment_sort_type = self
+      .default_comment_sort_type
+      .or(Some(local_site.default_comment_sort_type));
+    self.default_listing_type = self
+      .default_listing_type
+      .or(Some(local_site.default_post_listing_type));
+    self.show_nsfw = self.show_nsfw.or(Some(local_site.default_show_nsfw));
+    self.show_bot_accounts = self
+      .show_bot_accounts
+      .or(Some(local_site.default_show_bot_accounts));
+    self.send_notifications_to_email = self
+      .send_notifications_to_email
+      .or(Some(local_site.default_send_notifications_to_email));
+    self.interface_language = self
+      .interface_language
+      .take()
+      .or_else(|| Some(local_site.default_interface_language.clone()));
+  }
+
   pub fn test_form(person_id: PersonId) -> Self {
     Self::new(person_id, Some(String::new()))
   }
@@ -363,26 +579,65 @@ impl LocalUserInsertForm {
 pub struct UserBackupLists {
   pub followed_communities: Vec<DbUrl>,
   pub saved_posts: Vec<DbUrl>,
+  pub read_posts: Vec<DbUrl>,
   pub saved_comments: Vec<DbUrl>,
   pub blocked_communities: Vec<DbUrl>,
   pub blocked_users: Vec<DbUrl>,
   pub blocked_instances: Vec<String>,
 }
 
+pub struct UserContentLists {
+  pub posts: Vec<Post>,
+  pub comments: Vec<Comment>,
+  /// Voted objects with the score of the vote
+  pub post_votes: Vec<(DbUrl, i16)>,
+  pub comment_votes: Vec<(DbUrl, i16)>,
+}
+
 #[cfg(test)]
 mod tests {
   use crate::{
     source::{
       instance::Instance,
-      local_user::{LocalUser, LocalUserInsertForm},
+      local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
       person::{Person, PersonInsertForm},
     },
     traits::Crud,
     utils::build_db_pool_for_tests,
+    DefaultUserSetting,
   };
   use lemmy_utils::error::LemmyResult;
   use serial_test::serial;
 
+  #[test]
+  fn test_customized_settings_after() {
+    let local_user = LocalUser {
+      show_nsfw: false,
+      customized_settings: vec![DefaultUserSetting::ShowBotAccounts],
+      ..Default::default()
+    };
+
+    // Saving the same value doesn't count as customization
+    let unchanged = LocalUserUpdateForm {
+      show_nsfw: Some(false),
+      theme: Some("darkly".to_string()),
+      ..Default::default()
+    };
+    assert_eq!(None, local_user.customized_settings_after(&unchanged));
+
+    let changed = LocalUserUpdateForm {
+      show_nsfw: Some(true),
+      ..Default::default()
+    };
+    assert_eq!(
+      Some(vec![
+        DefaultUserSetting::ShowBotAccounts,
+        DefaultUserSetting::ShowNsfw
+      ]),
+      local_user.customized_settings_after(&changed)
+    );
+  }
+
   #[tokio::test]
   #[serial]
   async fn test_admin_higher_check() -> LemmyResult<()> {
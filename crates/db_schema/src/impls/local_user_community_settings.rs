@@ -0,0 +1,118 @@
+use crate::{
+  diesel::OptionalExtension,
+  newtypes::{CommunityId, LocalUserId},
+  schema::local_user_community_settings,
+  source::local_user_community_settings::{
+    LocalUserCommunitySettings,
+    LocalUserCommunitySettingsForm,
+  },
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl LocalUserCommunitySettings {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    community_id: CommunityId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    local_user_community_settings::table
+      .find((local_user_id, community_id))
+      .first(conn)
+      .await
+      .optional()
+  }
+
+  /// Replaces the settings of the user for the community.
+  pub async fn save(
+    pool: &mut DbPool<'_>,
+    form: &LocalUserCommunitySettingsForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(local_user_community_settings::table)
+      .values(form)
+      .on_conflict((
+        local_user_community_settings::local_user_id,
+        local_user_community_settings::community_id,
+      ))
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::*;
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+    PostListingMode,
+    PostSortType,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_community_settings() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "settings_user");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(inserted_person.id);
+    let inserted_local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "settings_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+
+    let local_user_id = inserted_local_user.id;
+    let community_id = inserted_community.id;
+    assert_eq!(
+      None,
+      LocalUserCommunitySettings::read(pool, local_user_id, community_id).await?
+    );
+
+    let form = LocalUserCommunitySettingsForm {
+      post_sort_type: Some(PostSortType::TopWeek),
+      post_listing_mode: Some(PostListingMode::Card),
+      ..LocalUserCommunitySettingsForm::new(local_user_id, community_id)
+    };
+    let saved = LocalUserCommunitySettings::save(pool, &form).await?;
+    assert_eq!(Some(PostSortType::TopWeek), saved.post_sort_type);
+    assert_eq!(
+      Some(saved),
+      LocalUserCommunitySettings::read(pool, local_user_id, community_id).await?
+    );
+
+    // Saving again replaces the previous settings
+    let form = LocalUserCommunitySettingsForm {
+      post_sort_type: Some(PostSortType::New),
+      ..LocalUserCommunitySettingsForm::new(local_user_id, community_id)
+    };
+    let saved = LocalUserCommunitySettings::save(pool, &form).await?;
+    assert_eq!(Some(PostSortType::New), saved.post_sort_type);
+    assert_eq!(None, saved.post_listing_mode);
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
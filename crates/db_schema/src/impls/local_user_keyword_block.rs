@@ -0,0 +1,180 @@
+use crate::{
+  newtypes::LocalUserId,
+  schema::local_user_keyword_block,
+  source::local_user_keyword_block::{LocalUserKeywordBlock, LocalUserKeywordBlockForm},
+  utils::{functions::RegexMatchInsensitive, get_conn, DbPool},
+};
+use diesel::{
+  dsl::insert_into,
+  result::Error,
+  sql_types::Text,
+  ExpressionMethods,
+  IntoSql,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+impl LocalUserKeywordBlock {
+  pub async fn block(
+    pool: &mut DbPool<'_>,
+    form: &LocalUserKeywordBlockForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(local_user_keyword_block::table)
+      .values(form)
+      .on_conflict((
+        local_user_keyword_block::local_user_id,
+        local_user_keyword_block::keyword,
+      ))
+      .do_update()
+      .set(local_user_keyword_block::is_regex.eq(form.is_regex))
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn unblock(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    keyword: &str,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(
+      local_user_keyword_block::table
+        .filter(local_user_keyword_block::local_user_id.eq(local_user_id))
+        .filter(local_user_keyword_block::keyword.eq(keyword)),
+    )
+    .execute(conn)
+    .await
+  }
+
+  pub async fn for_local_user(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    local_user_keyword_block::table
+      .filter(local_user_keyword_block::local_user_id.eq(local_user_id))
+      .order_by(local_user_keyword_block::id)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// A single case insensitive Postgres regular expression which matches any of the keyword
+  /// blocks of the user, or None if there are none.
+  pub async fn read_pattern(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+  ) -> Result<Option<String>, Error> {
+    let blocks = Self::for_local_user(pool, local_user_id).await?;
+    if blocks.is_empty() {
+      return Ok(None);
+    }
+    let pattern = blocks
+      .iter()
+      .map(|b| {
+        if b.is_regex {
+          format!("(?:{})", b.keyword)
+        } else {
+          format!("(?:{})", escape_keyword(&b.keyword))
+        }
+      })
+      .collect::<Vec<_>>()
+      .join("|");
+    Ok(Some(pattern))
+  }
+
+  /// Whether Postgres accepts the pattern as regular expression. A keyword block with an invalid
+  /// regex would break all listings of the user.
+  pub async fn is_valid_regex(pool: &mut DbPool<'_>, pattern: &str) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let matches = diesel::select(RegexMatchInsensitive::new(
+      "".into_sql::<Text>(),
+      pattern.into_sql::<Text>(),
+    ))
+    .get_result::<bool>(conn)
+    .await;
+    match matches {
+      Ok(_) => Ok(true),
+      Err(Error::DatabaseError(..)) => Ok(false),
+      Err(e) => Err(e),
+    }
+  }
+}
+
+/// Escapes all characters which have a special meaning in Postgres regular expressions.
+fn escape_keyword(keyword: &str) -> String {
+  keyword
+    .chars()
+    .flat_map(|c| {
+      let escape = c.is_ascii() && !c.is_ascii_alphanumeric();
+      escape.then_some('\\').into_iter().chain([c])
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::*;
+  use crate::{
+    source::{
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[test]
+  fn test_escape_keyword() {
+    assert_eq!("c\\+\\+", escape_keyword("c++"));
+    assert_eq!("naïve\\ café", escape_keyword("naïve café"));
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_keyword_pattern() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "keyword_blocker");
+    let person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(person.id);
+    let local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    assert_eq!(
+      None,
+      LocalUserKeywordBlock::read_pattern(pool, local_user.id).await?
+    );
+
+    let form = LocalUserKeywordBlockForm::new(local_user.id, "c++".into(), false);
+    LocalUserKeywordBlock::block(pool, &form).await?;
+    let form = LocalUserKeywordBlockForm::new(local_user.id, "^spoiler".into(), true);
+    LocalUserKeywordBlock::block(pool, &form).await?;
+    assert_eq!(
+      Some("(?:c\\+\\+)|(?:^spoiler)".to_string()),
+      LocalUserKeywordBlock::read_pattern(pool, local_user.id).await?
+    );
+
+    assert!(LocalUserKeywordBlock::is_valid_regex(pool, "^spoiler").await?);
+    assert!(!LocalUserKeywordBlock::is_valid_regex(pool, "(unclosed").await?);
+
+    LocalUserKeywordBlock::unblock(pool, local_user.id, "c++").await?;
+    assert_eq!(
+      1,
+      LocalUserKeywordBlock::for_local_user(pool, local_user.id)
+        .await?
+        .len()
+    );
+
+    Person::delete(pool, person.id).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,102 @@
+use crate::{
+  newtypes::LocalUserId,
+  schema::{local_user, local_user_link, person},
+  source::{
+    local_user_link::{LocalUserLink, LocalUserLinkForm},
+    person::Person,
+  },
+  utils::{get_conn, DbPool},
+};
+use diesel::{
+  delete,
+  dsl::{exists, insert_into},
+  result::Error,
+  select,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  JoinOnDsl,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+impl LocalUserLink {
+  /// Links both accounts with each other.
+  pub async fn link(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    linked_local_user_id: LocalUserId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let forms = [
+      LocalUserLinkForm {
+        local_user_id,
+        linked_local_user_id,
+      },
+      LocalUserLinkForm {
+        local_user_id: linked_local_user_id,
+        linked_local_user_id: local_user_id,
+      },
+    ];
+    insert_into(local_user_link::table)
+      .values(&forms)
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+  }
+
+  pub async fn unlink(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    linked_local_user_id: LocalUserId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(
+      local_user_link::table.filter(
+        local_user_link::local_user_id
+          .eq(local_user_id)
+          .and(local_user_link::linked_local_user_id.eq(linked_local_user_id))
+          .or(
+            local_user_link::local_user_id
+              .eq(linked_local_user_id)
+              .and(local_user_link::linked_local_user_id.eq(local_user_id)),
+          ),
+      ),
+    )
+    .execute(conn)
+    .await
+  }
+
+  /// Returns an error if the accounts aren't linked.
+  pub async fn check_linked(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    linked_local_user_id: LocalUserId,
+  ) -> LemmyResult<()> {
+    let conn = &mut get_conn(pool).await?;
+    select(exists(
+      local_user_link::table.find((local_user_id, linked_local_user_id)),
+    ))
+    .get_result::<bool>(conn)
+    .await?
+    .then_some(())
+    .ok_or(LemmyErrorType::AccountsNotLinked.into())
+  }
+
+  /// The persons of all accounts linked to the given one.
+  pub async fn list_persons(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+  ) -> Result<Vec<Person>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    local_user_link::table
+      .inner_join(local_user::table.on(local_user_link::linked_local_user_id.eq(local_user::id)))
+      .inner_join(person::table.on(local_user::person_id.eq(person::id)))
+      .filter(local_user_link::local_user_id.eq(local_user_id))
+      .filter(person::deleted.eq(false))
+      .order_by(local_user_link::published)
+      .select(person::all_columns)
+      .load::<Person>(conn)
+      .await
+  }
+}
@@ -1,7 +1,7 @@
 use crate::{
   diesel::{ExpressionMethods, QueryDsl},
-  newtypes::LocalUserId,
-  schema::login_token::{dsl::login_token, user_id},
+  newtypes::{LocalUserId, LoginTokenId},
+  schema::login_token::{dsl::login_token, id, user_id},
   source::login_token::{LoginToken, LoginTokenCreateForm},
   utils::{get_conn, DbPool},
 };
@@ -52,6 +52,18 @@ impl LoginToken {
     delete(login_token.find(token_)).execute(conn).await
   }
 
+  /// Invalidate a single login of the user, for example one made from a lost device.
+  pub async fn invalidate_by_id(
+    pool: &mut DbPool<'_>,
+    user_id_: LocalUserId,
+    id_: LoginTokenId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    delete(login_token.filter(id.eq(id_)).filter(user_id.eq(user_id_)))
+      .execute(conn)
+      .await
+  }
+
   /// Invalidate all logins of given user on password reset/change, account deletion or site ban.
   pub async fn invalidate_all(
     pool: &mut DbPool<'_>,
@@ -63,3 +75,75 @@ impl LoginToken {
       .await
   }
 }
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing)]
+mod tests {
+
+  use crate::{
+    source::{
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      login_token::{LoginToken, LoginTokenCreateForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_invalidate_by_id() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "login_token_owner");
+    let person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(person.id);
+    let local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+    let other_person_form = PersonInsertForm::test_form(inserted_instance.id, "login_token_other");
+    let other_person = Person::create(pool, &other_person_form).await?;
+    let other_local_user_form = LocalUserInsertForm::test_form(other_person.id);
+    let other_local_user = LocalUser::create(pool, &other_local_user_form, vec![]).await?;
+
+    // Log in from two devices
+    let mut logins = vec![];
+    for (token, user_agent) in [("token_phone", "phone"), ("token_laptop", "laptop")] {
+      let form = LoginTokenCreateForm {
+        token: token.to_string().into(),
+        user_id: local_user.id,
+        ip: None,
+        user_agent: Some(user_agent.to_string()),
+        linked_from_user_id: None,
+      };
+      logins.push(LoginToken::create(pool, form).await?);
+    }
+    assert_eq!(2, LoginToken::list(pool, local_user.id).await?.len());
+    let phone_id = logins[0].id;
+
+    // Other users can't revoke the login
+    let revoked = LoginToken::invalidate_by_id(pool, other_local_user.id, phone_id).await?;
+    assert_eq!(0, revoked);
+    assert!(LoginToken::validate(pool, local_user.id, "token_phone")
+      .await
+      .is_ok());
+
+    // Revoking the lost phone keeps the laptop logged in
+    let revoked = LoginToken::invalidate_by_id(pool, local_user.id, phone_id).await?;
+    assert_eq!(1, revoked);
+    assert!(LoginToken::validate(pool, local_user.id, "token_phone")
+      .await
+      .is_err());
+    assert!(LoginToken::validate(pool, local_user.id, "token_laptop")
+      .await
+      .is_ok());
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
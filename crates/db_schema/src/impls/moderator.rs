@@ -1,5 +1,8 @@
 use crate::{
+  newtypes::{CommunityId, PersonId},
   source::moderator::{
+    AdminFreezeAccount,
+    AdminFreezeAccountForm,
     AdminPurgeComment,
     AdminPurgeCommentForm,
     AdminPurgeCommunity,
@@ -8,6 +11,8 @@ use crate::{
     AdminPurgePersonForm,
     AdminPurgePost,
     AdminPurgePostForm,
+    AdminShadowBanPerson,
+    AdminShadowBanPersonForm,
     ModAdd,
     ModAddCommunity,
     ModAddCommunityForm,
@@ -28,13 +33,24 @@ use crate::{
     ModRemoveCommunityForm,
     ModRemovePost,
     ModRemovePostForm,
+    ModResolveBanAppeal,
+    ModResolveBanAppealForm,
     ModTransferCommunity,
     ModTransferCommunityForm,
+    ModWarnPerson,
+    ModWarnPersonForm,
   },
   traits::Crud,
   utils::{get_conn, DbPool},
 };
-use diesel::{dsl::insert_into, result::Error, QueryDsl};
+use chrono::{DateTime, Utc};
+use diesel::{
+  dsl::{insert_into, sum},
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  QueryDsl,
+};
 use diesel_async::RunQueryDsl;
 
 #[async_trait]
@@ -376,6 +392,231 @@ impl Crud for ModAdd {
   }
 }
 
+#[async_trait]
+impl Crud for ModWarnPerson {
+  type InsertForm = ModWarnPersonForm;
+  type UpdateForm = ModWarnPersonForm;
+  type IdType = i32;
+
+  async fn create(pool: &mut DbPool<'_>, form: &ModWarnPersonForm) -> Result<Self, Error> {
+    use crate::schema::mod_warn_person::dsl::mod_warn_person;
+    let conn = &mut get_conn(pool).await?;
+    insert_into(mod_warn_person)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    from_id: i32,
+    form: &ModWarnPersonForm,
+  ) -> Result<Self, Error> {
+    use crate::schema::mod_warn_person::dsl::mod_warn_person;
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(mod_warn_person.find(from_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl ModWarnPerson {
+  /// The strikes of a person's warnings which haven't expired at the given time. Only warnings of
+  /// the given community are counted, or site-wide warnings if no community is given.
+  pub async fn active_strikes(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    community_id: Option<CommunityId>,
+    now: DateTime<Utc>,
+  ) -> Result<i64, Error> {
+    use crate::schema::mod_warn_person;
+    let conn = &mut get_conn(pool).await?;
+    let mut query = mod_warn_person::table
+      .filter(mod_warn_person::other_person_id.eq(person_id))
+      .filter(
+        mod_warn_person::expires
+          .is_null()
+          .or(mod_warn_person::expires.gt(now)),
+      )
+      .select(sum(mod_warn_person::strikes))
+      .into_boxed();
+    query = match community_id {
+      Some(community_id) => query.filter(mod_warn_person::community_id.eq(community_id)),
+      None => query.filter(mod_warn_person::community_id.is_null()),
+    };
+    Ok(query.first::<Option<i64>>(conn).await?.unwrap_or_default())
+  }
+
+  /// The strikes of all a person's warnings which haven't expired at the given time, in any
+  /// community or site-wide.
+  pub async fn all_active_strikes(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    now: DateTime<Utc>,
+  ) -> Result<i64, Error> {
+    use crate::schema::mod_warn_person;
+    let conn = &mut get_conn(pool).await?;
+    let strikes = mod_warn_person::table
+      .filter(mod_warn_person::other_person_id.eq(person_id))
+      .filter(
+        mod_warn_person::expires
+          .is_null()
+          .or(mod_warn_person::expires.gt(now)),
+      )
+      .select(sum(mod_warn_person::strikes))
+      .first::<Option<i64>>(conn)
+      .await?;
+    Ok(strikes.unwrap_or_default())
+  }
+
+  pub async fn get_unread_count(pool: &mut DbPool<'_>, person_id: PersonId) -> Result<i64, Error> {
+    use crate::schema::mod_warn_person;
+    let conn = &mut get_conn(pool).await?;
+    mod_warn_person::table
+      .filter(mod_warn_person::other_person_id.eq(person_id))
+      .filter(mod_warn_person::read.eq(false))
+      .count()
+      .get_result(conn)
+      .await
+  }
+
+  pub async fn mark_all_as_read(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+  ) -> Result<usize, Error> {
+    use crate::schema::mod_warn_person;
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      mod_warn_person::table
+        .filter(mod_warn_person::other_person_id.eq(person_id))
+        .filter(mod_warn_person::read.eq(false)),
+    )
+    .set(mod_warn_person::read.eq(true))
+    .execute(conn)
+    .await
+  }
+}
+
+#[async_trait]
+impl Crud for ModResolveBanAppeal {
+  type InsertForm = ModResolveBanAppealForm;
+  type UpdateForm = ModResolveBanAppealForm;
+  type IdType = i32;
+
+  async fn create(pool: &mut DbPool<'_>, form: &ModResolveBanAppealForm) -> Result<Self, Error> {
+    use crate::schema::mod_resolve_ban_appeal::dsl::mod_resolve_ban_appeal;
+    let conn = &mut get_conn(pool).await?;
+    insert_into(mod_resolve_ban_appeal)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    from_id: i32,
+    form: &ModResolveBanAppealForm,
+  ) -> Result<Self, Error> {
+    use crate::schema::mod_resolve_ban_appeal::dsl::mod_resolve_ban_appeal;
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(mod_resolve_ban_appeal.find(from_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+#[async_trait]
+impl Crud for AdminFreezeAccount {
+  type InsertForm = AdminFreezeAccountForm;
+  type UpdateForm = AdminFreezeAccountForm;
+  type IdType = i32;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    use crate::schema::admin_freeze_account::dsl::admin_freeze_account;
+    let conn = &mut get_conn(pool).await?;
+    insert_into(admin_freeze_account)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    from_id: i32,
+    form: &Self::InsertForm,
+  ) -> Result<Self, Error> {
+    use crate::schema::admin_freeze_account::dsl::admin_freeze_account;
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(admin_freeze_account.find(from_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl AdminFreezeAccount {
+  /// The freeze history of a person, newest first.
+  pub async fn list_for_person(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+  ) -> Result<Vec<Self>, Error> {
+    use crate::schema::admin_freeze_account;
+    let conn = &mut get_conn(pool).await?;
+    admin_freeze_account::table
+      .filter(admin_freeze_account::other_person_id.eq(person_id))
+      .order_by(admin_freeze_account::when_.desc())
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+#[async_trait]
+impl Crud for AdminShadowBanPerson {
+  type InsertForm = AdminShadowBanPersonForm;
+  type UpdateForm = AdminShadowBanPersonForm;
+  type IdType = i32;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    use crate::schema::admin_shadow_ban_person::dsl::admin_shadow_ban_person;
+    let conn = &mut get_conn(pool).await?;
+    insert_into(admin_shadow_ban_person)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    from_id: i32,
+    form: &Self::InsertForm,
+  ) -> Result<Self, Error> {
+    use crate::schema::admin_shadow_ban_person::dsl::admin_shadow_ban_person;
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(admin_shadow_ban_person.find(from_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl AdminShadowBanPerson {
+  /// The shadow ban history of a person, newest first.
+  pub async fn list_for_person(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+  ) -> Result<Vec<Self>, Error> {
+    use crate::schema::admin_shadow_ban_person;
+    let conn = &mut get_conn(pool).await?;
+    admin_shadow_ban_person::table
+      .filter(admin_shadow_ban_person::other_person_id.eq(person_id))
+      .order_by(admin_shadow_ban_person::when_.desc())
+      .load::<Self>(conn)
+      .await
+  }
+}
+
 #[async_trait]
 impl Crud for AdminPurgePerson {
   type InsertForm = AdminPurgePersonForm;
@@ -519,6 +760,8 @@ mod tests {
         ModRemoveCommunityForm,
         ModRemovePost,
         ModRemovePostForm,
+        ModWarnPerson,
+        ModWarnPersonForm,
       },
       person::{Person, PersonInsertForm},
       post::{Post, PostInsertForm},
@@ -526,6 +769,7 @@ mod tests {
     traits::Crud,
     utils::build_db_pool_for_tests,
   };
+  use chrono::{Days, Utc};
   use diesel::result::Error;
   use pretty_assertions::assert_eq;
   use serial_test::serial;
@@ -577,6 +821,7 @@ mod tests {
       post_id: inserted_post.id,
       reason: None,
       removed: None,
+      automod: false,
     };
     let inserted_mod_remove_post = ModRemovePost::create(pool, &mod_remove_post_form).await?;
     let read_mod_remove_post = ModRemovePost::read(pool, inserted_mod_remove_post.id).await?;
@@ -587,6 +832,7 @@ mod tests {
       reason: None,
       removed: true,
       when_: inserted_mod_remove_post.when_,
+      automod: false,
     };
 
     // lock post
@@ -595,6 +841,7 @@ mod tests {
       mod_person_id: inserted_mod.id,
       post_id: inserted_post.id,
       locked: None,
+      automod: false,
     };
     let inserted_mod_lock_post = ModLockPost::create(pool, &mod_lock_post_form).await?;
     let read_mod_lock_post = ModLockPost::read(pool, inserted_mod_lock_post.id).await?;
@@ -604,6 +851,7 @@ mod tests {
       mod_person_id: inserted_mod.id,
       locked: true,
       when_: inserted_mod_lock_post.when_,
+      automod: false,
     };
 
     // feature post
@@ -613,6 +861,7 @@ mod tests {
       post_id: inserted_post.id,
       featured: false,
       is_featured_community: true,
+      automod: false,
     };
     let inserted_mod_feature_post = ModFeaturePost::create(pool, &mod_feature_post_form).await?;
     let read_mod_feature_post = ModFeaturePost::read(pool, inserted_mod_feature_post.id).await?;
@@ -623,6 +872,7 @@ mod tests {
       featured: false,
       is_featured_community: true,
       when_: inserted_mod_feature_post.when_,
+      automod: false,
     };
 
     // comment
@@ -632,6 +882,7 @@ mod tests {
       comment_id: inserted_comment.id,
       reason: None,
       removed: None,
+      automod: false,
     };
     let inserted_mod_remove_comment =
       ModRemoveComment::create(pool, &mod_remove_comment_form).await?;
@@ -644,6 +895,7 @@ mod tests {
       reason: None,
       removed: true,
       when_: inserted_mod_remove_comment.when_,
+      automod: false,
     };
 
     // community
@@ -768,4 +1020,75 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_warning_strikes() -> Result<(), Error> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let moderator =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "warner")).await?;
+    let person = Person::create(pool, &PersonInsertForm::test_form(instance.id, "warned")).await?;
+    let community_form = CommunityInsertForm::new(
+      instance.id,
+      "warn_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+
+    let now = Utc::now();
+    let tomorrow = now.checked_add_days(Days::new(1));
+    let yesterday = now.checked_sub_days(Days::new(1));
+    for (community_id, strikes, expires) in [
+      (Some(community.id), Some(2), None),
+      (Some(community.id), None, yesterday),
+      (None, None, tomorrow),
+    ] {
+      let form = ModWarnPersonForm {
+        mod_person_id: moderator.id,
+        other_person_id: person.id,
+        community_id,
+        reason: "spam".to_string(),
+        strikes,
+        expires,
+      };
+      ModWarnPerson::create(pool, &form).await?;
+    }
+
+    // Expired warnings don't count, and each scope is counted separately
+    assert_eq!(
+      2,
+      ModWarnPerson::active_strikes(pool, person.id, Some(community.id), now).await?
+    );
+    assert_eq!(
+      1,
+      ModWarnPerson::active_strikes(pool, person.id, None, now).await?
+    );
+    assert_eq!(
+      3,
+      ModWarnPerson::all_active_strikes(pool, person.id, now).await?
+    );
+
+    // Expiry is checked against the given time, not the time of the database
+    let in_two_days = now.checked_add_days(Days::new(2)).ok_or(Error::NotFound)?;
+    assert_eq!(
+      0,
+      ModWarnPerson::active_strikes(pool, person.id, None, in_two_days).await?
+    );
+    assert_eq!(
+      2,
+      ModWarnPerson::all_active_strikes(pool, person.id, in_two_days).await?
+    );
+
+    assert_eq!(3, ModWarnPerson::get_unread_count(pool, person.id).await?);
+    ModWarnPerson::mark_all_as_read(pool, person.id).await?;
+    assert_eq!(0, ModWarnPerson::get_unread_count(pool, person.id).await?);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
 }
@@ -1,5 +1,5 @@
 use crate::{
-  newtypes::LocalUserId,
+  newtypes::{LocalUserId, OAuthProviderId},
   schema::{oauth_account, oauth_account::dsl::local_user_id},
   source::oauth_account::{OAuthAccount, OAuthAccountInsertForm},
   utils::{get_conn, DbPool},
@@ -16,6 +16,29 @@ impl OAuthAccount {
       .await
   }
 
+  pub async fn list_for_local_user(
+    pool: &mut DbPool<'_>,
+    for_local_user_id: LocalUserId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    oauth_account::table
+      .filter(local_user_id.eq(for_local_user_id))
+      .order_by(oauth_account::published)
+      .load::<Self>(conn)
+      .await
+  }
+
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    for_local_user_id: LocalUserId,
+    oauth_provider_id: OAuthProviderId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(oauth_account::table.find((oauth_provider_id, for_local_user_id)))
+      .execute(conn)
+      .await
+  }
+
   pub async fn delete_user_accounts(
     pool: &mut DbPool<'_>,
     for_local_user_id: LocalUserId,
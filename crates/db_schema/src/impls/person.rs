@@ -10,10 +10,10 @@ use crate::{
     PersonUpdateForm,
   },
   traits::{ApubActor, Crud, Followable},
-  utils::{action_query, functions::lower, get_conn, naive_now, now, uplete, DbPool},
+  utils::{action_query, find_action, functions::lower, get_conn, naive_now, now, uplete, DbPool},
 };
 use diesel::{
-  dsl::{insert_into, not},
+  dsl::{exists, insert_into, not, select},
   expression::SelectableHelper,
   result::Error,
   CombineDsl,
@@ -62,6 +62,10 @@ impl Crud for Person {
 }
 
 impl Person {
+  /// Name of the placeholder account which anonymized content is attributed to. It isn't a valid
+  /// username, so nobody can register it.
+  pub const TOMBSTONE_NAME: &'static str = "deleted-user";
+
   /// Update or insert the person.
   ///
   /// This is necessary for federation, because Activitypub doesn't distinguish between these
@@ -99,6 +103,25 @@ impl Person {
       .await
   }
 
+  /// Moves all posts and comments of a creator to another person, used to anonymize content when
+  /// an account is deleted.
+  pub async fn reassign_content(
+    pool: &mut DbPool<'_>,
+    from_creator_id: PersonId,
+    to_creator_id: PersonId,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(post::table.filter(post::creator_id.eq(from_creator_id)))
+      .set(post::creator_id.eq(to_creator_id))
+      .execute(conn)
+      .await?;
+    diesel::update(comment::table.filter(comment::creator_id.eq(from_creator_id)))
+      .set(comment::creator_id.eq(to_creator_id))
+      .execute(conn)
+      .await?;
+    Ok(())
+  }
+
   /// Lists local community ids for all posts and comments for a given creator.
   pub async fn list_local_community_ids(
     pool: &mut DbPool<'_>,
@@ -241,6 +264,53 @@ impl PersonFollower {
       .load(conn)
       .await
   }
+
+  /// Lists the persons which the follower follows.
+  pub async fn list_followed(
+    pool: &mut DbPool<'_>,
+    follower_id: PersonId,
+  ) -> Result<Vec<Person>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    action_query(person_actions::followed)
+      .inner_join(person::table.on(person_actions::target_id.eq(person::id)))
+      .filter(person_actions::person_id.eq(follower_id))
+      .select(person::all_columns)
+      .load(conn)
+      .await
+  }
+
+  /// Marks the pending follow of a remote person as accepted. Throws an error if no follow was
+  /// requested.
+  pub async fn accept_follow(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    follower_id: PersonId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(find_action(
+      person_actions::followed,
+      (follower_id, person_id),
+    ))
+    .set(person_actions::follow_pending.eq(false))
+    .returning(Self::as_select())
+    .get_result::<Self>(conn)
+    .await
+  }
+
+  /// Returns true if the follower follows the person.
+  pub async fn is_following(
+    pool: &mut DbPool<'_>,
+    follower_id: PersonId,
+    person_id: PersonId,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    select(exists(find_action(
+      person_actions::followed,
+      (follower_id, person_id),
+    )))
+    .get_result(conn)
+    .await
+  }
 }
 
 #[cfg(test)]
@@ -290,6 +360,7 @@ mod tests {
       inbox_url: inserted_person.inbox_url.clone(),
       matrix_user_id: None,
       ban_expires: None,
+      shadow_banned: false,
       instance_id: inserted_instance.id,
     };
 
@@ -333,12 +404,27 @@ mod tests {
     assert_eq!(person_1.id, person_follower.person_id);
     assert_eq!(person_2.id, person_follower.follower_id);
     assert!(!person_follower.pending);
+    assert!(PersonFollower::is_following(pool, person_2.id, person_1.id).await?);
+    assert!(!PersonFollower::is_following(pool, person_1.id, person_2.id).await?);
 
+    let followed = PersonFollower::list_followed(pool, person_2.id).await?;
+    assert_eq!(vec![person_1.clone()], followed);
     let followers = PersonFollower::list_followers(pool, person_1.id).await?;
     assert_eq!(vec![person_2], followers);
 
+    // Follows of remote persons are pending until they are accepted
+    let pending_form = PersonFollowerForm {
+      pending: true,
+      ..follow_form.clone()
+    };
+    assert!(PersonFollower::follow(pool, &pending_form).await?.pending);
+    let accepted =
+      PersonFollower::accept_follow(pool, person_1.id, follow_form.follower_id).await?;
+    assert!(!accepted.pending);
+
     let unfollow = PersonFollower::unfollow(pool, &follow_form).await?;
     assert_eq!(uplete::Count::only_deleted(1), unfollow);
+    assert!(!PersonFollower::is_following(pool, follow_form.follower_id, person_1.id).await?);
 
     Ok(())
   }
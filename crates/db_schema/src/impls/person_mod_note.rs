@@ -0,0 +1,36 @@
+use crate::{
+  newtypes::PersonModNoteId,
+  schema::person_mod_note,
+  source::person_mod_note::{PersonModNote, PersonModNoteInsertForm, PersonModNoteUpdateForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{insert_into, result::Error, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for PersonModNote {
+  type InsertForm = PersonModNoteInsertForm;
+  type UpdateForm = PersonModNoteUpdateForm;
+  type IdType = PersonModNoteId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(person_mod_note::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    note_id: PersonModNoteId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(person_mod_note::table.find(note_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
@@ -0,0 +1,188 @@
+use crate::{
+  newtypes::{CommentId, PersonId, PostId},
+  schema::person_pin,
+  source::person_pin::{PersonPin, PersonPinForm},
+  utils::{get_conn, DbPool},
+};
+use diesel::{
+  dsl::{count_star, delete, insert_into},
+  result::Error,
+  ExpressionMethods,
+  QueryDsl,
+  SelectableHelper,
+};
+use diesel_async::RunQueryDsl;
+
+impl PersonPin {
+  /// How many posts and comments a person can pin to their profile.
+  pub const LIMIT: i64 = 5;
+
+  /// Pins a post or comment to the profile. Pinning it again does nothing.
+  pub async fn pin(pool: &mut DbPool<'_>, form: &PersonPinForm) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(person_pin::table)
+      .values(form)
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+  }
+
+  pub async fn unpin(pool: &mut DbPool<'_>, form: &PersonPinForm) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let query = delete(person_pin::table).filter(person_pin::person_id.eq(form.person_id));
+    match (form.post_id, form.comment_id) {
+      (Some(post_id), _) => {
+        query
+          .filter(person_pin::post_id.eq(post_id))
+          .execute(conn)
+          .await
+      }
+      (None, Some(comment_id)) => {
+        query
+          .filter(person_pin::comment_id.eq(comment_id))
+          .execute(conn)
+          .await
+      }
+      (None, None) => Ok(0),
+    }
+  }
+
+  /// The pinned posts and comments of a person, newest first.
+  pub async fn list_for_person(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    person_pin::table
+      .filter(person_pin::person_id.eq(person_id))
+      .order_by(person_pin::published.desc())
+      .select(Self::as_select())
+      .load(conn)
+      .await
+  }
+
+  pub async fn count_for_person(pool: &mut DbPool<'_>, person_id: PersonId) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    person_pin::table
+      .filter(person_pin::person_id.eq(person_id))
+      .select(count_star())
+      .first(conn)
+      .await
+  }
+
+  /// Replaces all pins of a person, used for the featured collection of remote persons.
+  pub async fn set_for_person(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    post_ids: Vec<PostId>,
+    comment_ids: Vec<CommentId>,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    let forms = post_ids
+      .into_iter()
+      .map(|post_id| PersonPinForm::new(person_id, Some(post_id), None))
+      .chain(
+        comment_ids
+          .into_iter()
+          .map(|comment_id| PersonPinForm::new(person_id, None, Some(comment_id))),
+      )
+      .collect::<Vec<_>>();
+
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          delete(person_pin::table)
+            .filter(person_pin::person_id.eq(person_id))
+            .execute(conn)
+            .await?;
+          insert_into(person_pin::table)
+            .values(forms)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+          Ok(())
+        }) as _
+      })
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      person_pin::{PersonPin, PersonPinForm},
+      post::{Post, PostInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_person_pin() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let new_person = PersonInsertForm::test_form(inserted_instance.id, "pinner");
+    let inserted_person = Person::create(pool, &new_person).await?;
+    let new_community = CommunityInsertForm::new(
+      inserted_instance.id,
+      "test community pins".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &new_community).await?;
+    let post_form = PostInsertForm::new(
+      "A test post".into(),
+      inserted_person.id,
+      inserted_community.id,
+    );
+    let inserted_post = Post::create(pool, &post_form).await?;
+
+    let form = PersonPinForm::new(inserted_person.id, Some(inserted_post.id), None);
+    assert_eq!(1, PersonPin::pin(pool, &form).await?);
+    // Pinning again is ignored
+    assert_eq!(0, PersonPin::pin(pool, &form).await?);
+    assert_eq!(
+      1,
+      PersonPin::count_for_person(pool, inserted_person.id).await?
+    );
+
+    let pins = PersonPin::list_for_person(pool, inserted_person.id).await?;
+    assert_eq!(
+      vec![Some(inserted_post.id)],
+      pins.iter().map(|p| p.post_id).collect::<Vec<_>>()
+    );
+
+    assert_eq!(1, PersonPin::unpin(pool, &form).await?);
+    assert_eq!(
+      0,
+      PersonPin::count_for_person(pool, inserted_person.id).await?
+    );
+
+    PersonPin::set_for_person(pool, inserted_person.id, vec![inserted_post.id], vec![]).await?;
+    assert_eq!(
+      1,
+      PersonPin::count_for_person(pool, inserted_person.id).await?
+    );
+    PersonPin::set_for_person(pool, inserted_person.id, vec![], vec![]).await?;
+    assert_eq!(
+      0,
+      PersonPin::count_for_person(pool, inserted_person.id).await?
+    );
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,314 @@
+use crate::{
+  diesel::OptionalExtension,
+  newtypes::{DbUrl, PersonId, PmConversationId},
+  schema::{person, pm_conversation, pm_conversation_participant},
+  source::{
+    person::Person,
+    pm_conversation::{
+      PmConversation,
+      PmConversationParticipant,
+      PmConversationParticipantForm,
+      PmGroupConversationInsertForm,
+    },
+  },
+  utils::{get_conn, now, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use url::Url;
+
+impl PmConversation {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    conversation_id: PmConversationId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    pm_conversation::table
+      .find(conversation_id)
+      .first(conn)
+      .await
+  }
+
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: Url,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let object_id: DbUrl = object_id.into();
+    pm_conversation::table
+      .filter(pm_conversation::ap_id.eq(object_id))
+      .first(conn)
+      .await
+      .optional()
+  }
+
+  /// Reads the conversation between two persons, if they ever wrote each other.
+  pub async fn read_direct(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    other_person_id: PersonId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    // The person with the lower id is always stored as person_a
+    let (person_a_id, person_b_id) = if person_id.0 <= other_person_id.0 {
+      (person_id, other_person_id)
+    } else {
+      (other_person_id, person_id)
+    };
+    pm_conversation::table
+      .filter(pm_conversation::person_a_id.eq(person_a_id))
+      .filter(pm_conversation::person_b_id.eq(person_b_id))
+      .first(conn)
+      .await
+      .optional()
+  }
+
+  /// Holds back the messages of the conversation until the recipient accepts it.
+  pub async fn mark_as_request(
+    pool: &mut DbPool<'_>,
+    conversation_id: PmConversationId,
+    recipient_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(pm_conversation::table.find(conversation_id))
+      .set(pm_conversation::request_recipient_id.eq(recipient_id))
+      .execute(conn)
+      .await
+  }
+
+  /// Accepts the message request, if the person is its recipient.
+  pub async fn accept_request(
+    pool: &mut DbPool<'_>,
+    conversation_id: PmConversationId,
+    person_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      pm_conversation::table
+        .find(conversation_id)
+        .filter(pm_conversation::request_recipient_id.eq(person_id)),
+    )
+    .set(pm_conversation::request_recipient_id.eq(None::<PersonId>))
+    .execute(conn)
+    .await
+  }
+
+  /// Stores the Matrix room of the conversation, unless another room was stored in the meantime.
+  pub async fn set_matrix_room_id(
+    pool: &mut DbPool<'_>,
+    conversation_id: PmConversationId,
+    matrix_room_id: &str,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      pm_conversation::table
+        .find(conversation_id)
+        .filter(pm_conversation::matrix_room_id.is_null()),
+    )
+    .set(pm_conversation::matrix_room_id.eq(matrix_room_id))
+    .execute(conn)
+    .await
+  }
+
+  /// Deletes the conversation together with all of its messages.
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    conversation_id: PmConversationId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(pm_conversation::table.find(conversation_id))
+      .execute(conn)
+      .await
+  }
+
+  /// Creates a group conversation with the given participants.
+  pub async fn create_group(
+    pool: &mut DbPool<'_>,
+    form: &PmGroupConversationInsertForm,
+    participant_ids: &[PersonId],
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let form = form.clone();
+    let participant_ids = participant_ids.to_vec();
+
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let conversation = insert_into(pm_conversation::table)
+            .values(form)
+            .get_result::<Self>(conn)
+            .await?;
+
+          let forms = participant_ids
+            .into_iter()
+            .map(|person_id| PmConversationParticipantForm::new(conversation.id, person_id))
+            .collect::<Vec<_>>();
+          insert_into(pm_conversation_participant::table)
+            .values(forms)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+
+          Ok(conversation)
+        }) as _
+      })
+      .await
+  }
+
+  /// Returns true if the given person takes part in this conversation.
+  pub async fn is_participant(
+    &self,
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+  ) -> Result<bool, Error> {
+    if !self.is_group {
+      return Ok(self.person_a_id == Some(person_id) || self.person_b_id == Some(person_id));
+    }
+    let conn = &mut get_conn(pool).await?;
+    pm_conversation_participant::table
+      .find((self.id, person_id))
+      .first::<PmConversationParticipant>(conn)
+      .await
+      .optional()
+      .map(|p| p.is_some())
+  }
+
+  /// The participant of a conversation between two persons who isn't the given person.
+  pub fn other_participant(&self, person_id: PersonId) -> Option<PersonId> {
+    if self.person_a_id == Some(person_id) {
+      self.person_b_id
+    } else {
+      self.person_a_id
+    }
+  }
+
+  /// Lists all persons who take part in the conversation.
+  pub async fn list_participants(&self, pool: &mut DbPool<'_>) -> Result<Vec<Person>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    if self.is_group {
+      pm_conversation_participant::table
+        .inner_join(person::table)
+        .filter(pm_conversation_participant::conversation_id.eq(self.id))
+        .order_by(pm_conversation_participant::published)
+        .select(person::all_columns)
+        .load(conn)
+        .await
+    } else {
+      let ids = [self.person_a_id, self.person_b_id]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+      person::table
+        .filter(person::id.eq_any(ids))
+        .load(conn)
+        .await
+    }
+  }
+}
+
+impl PmConversationParticipant {
+  /// Adds persons to a group conversation, ignoring those who are already participants.
+  pub async fn add(
+    pool: &mut DbPool<'_>,
+    conversation_id: PmConversationId,
+    person_ids: &[PersonId],
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let forms = person_ids
+      .iter()
+      .map(|person_id| PmConversationParticipantForm::new(conversation_id, *person_id))
+      .collect::<Vec<_>>();
+    insert_into(pm_conversation_participant::table)
+      .values(forms)
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+  }
+
+  pub async fn remove(
+    pool: &mut DbPool<'_>,
+    conversation_id: PmConversationId,
+    person_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(pm_conversation_participant::table.find((conversation_id, person_id)))
+      .execute(conn)
+      .await
+  }
+
+  /// Marks all messages of the group conversation as read for the participant.
+  pub async fn mark_as_read(
+    pool: &mut DbPool<'_>,
+    conversation_id: PmConversationId,
+    person_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(pm_conversation_participant::table.find((conversation_id, person_id)))
+      .set(pm_conversation_participant::last_read.eq(now()))
+      .execute(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      pm_conversation::{PmConversation, PmConversationParticipant, PmGroupConversationInsertForm},
+      private_message::{PrivateMessage, PrivateMessageInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_group_conversation() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let alice =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "alice_grp")).await?;
+    let bob = Person::create(pool, &PersonInsertForm::test_form(instance.id, "bob_grp")).await?;
+    let carol =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "carol_grp")).await?;
+
+    let mut form = PmGroupConversationInsertForm::new(Some("friends".into()));
+    form.creator_id = Some(alice.id);
+    let group = PmConversation::create_group(pool, &form, &[alice.id, bob.id]).await?;
+    assert!(group.is_group);
+    assert!(group.local);
+    assert!(group.ap_id.is_some());
+    assert!(group.is_participant(pool, bob.id).await?);
+    assert!(!group.is_participant(pool, carol.id).await?);
+
+    PmConversationParticipant::add(pool, group.id, &[bob.id, carol.id]).await?;
+    assert_eq!(3, group.list_participants(pool).await?.len());
+    PmConversationParticipant::remove(pool, group.id, bob.id).await?;
+    let participants = group.list_participants(pool).await?;
+    assert_eq!(
+      vec![alice.id, carol.id],
+      participants.iter().map(|p| p.id).collect::<Vec<_>>()
+    );
+
+    // Group messages don't create a separate conversation
+    let mut message = PrivateMessageInsertForm::new(alice.id, None, "hi all".into());
+    message.conversation_id = Some(group.id);
+    let message = PrivateMessage::create(pool, &message).await?;
+    assert_eq!(group.id, message.conversation_id);
+    let group = PmConversation::read(pool, group.id).await?;
+    assert_eq!(message.published, group.last_message_published);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,309 @@
+use crate::{
+  newtypes::{PersonId, PollId, PollOptionId, PostId},
+  schema::{poll, poll_option, poll_vote},
+  source::poll::{Poll, PollInsertForm, PollOption, PollVote},
+  utils::{get_conn, naive_now, DbPool},
+};
+use diesel::{
+  dsl::{insert_into, update},
+  result::Error,
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+
+impl Poll {
+  /// Creates a poll with the given options, which are shown in this order.
+  pub async fn create_with_options(
+    pool: &mut DbPool<'_>,
+    form: &PollInsertForm,
+    options: Vec<String>,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let form = form.clone();
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let poll = insert_into(poll::table)
+            .values(&form)
+            .get_result::<Self>(conn)
+            .await?;
+          let options = options.into_iter().map(|name| (name, 0)).collect();
+          PollOption::insert(conn, poll.id, options).await?;
+          Ok(poll)
+        }) as _
+      })
+      .await
+  }
+
+  /// Creates or updates a poll received over federation. The vote counts are taken from the
+  /// remote instance. If the options were changed, all existing votes are dropped.
+  pub async fn upsert_with_vote_counts(
+    pool: &mut DbPool<'_>,
+    form: &PollInsertForm,
+    options: Vec<(String, i32)>,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let form = form.clone();
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let poll = insert_into(poll::table)
+            .values(&form)
+            .on_conflict(poll::post_id)
+            .do_update()
+            .set((
+              poll::multiple_choice.eq(form.multiple_choice),
+              poll::end_time.eq(form.end_time),
+              poll::updated.eq(naive_now()),
+            ))
+            .get_result::<Self>(conn)
+            .await?;
+
+          let existing_names = poll_option::table
+            .filter(poll_option::poll_id.eq(poll.id))
+            .order_by(poll_option::position)
+            .select(poll_option::name)
+            .load::<String>(conn)
+            .await?;
+          let unchanged = existing_names
+            .iter()
+            .eq(options.iter().map(|(name, _)| name));
+
+          if unchanged {
+            for (position, (_, vote_count)) in options.into_iter().enumerate() {
+              update(
+                poll_option::table
+                  .filter(poll_option::poll_id.eq(poll.id))
+                  .filter(poll_option::position.eq(option_position(position))),
+              )
+              .set(poll_option::vote_count.eq(vote_count))
+              .execute(conn)
+              .await?;
+            }
+          } else {
+            diesel::delete(poll_option::table.filter(poll_option::poll_id.eq(poll.id)))
+              .execute(conn)
+              .await?;
+            PollOption::insert(conn, poll.id, options).await?;
+          }
+          Ok(poll)
+        }) as _
+      })
+      .await
+  }
+
+  pub async fn read(pool: &mut DbPool<'_>, poll_id: PollId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    poll::table.find(poll_id).first::<Self>(conn).await
+  }
+
+  pub async fn read_for_post(
+    pool: &mut DbPool<'_>,
+    post_id: PostId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    poll::table
+      .filter(poll::post_id.eq(post_id))
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+}
+
+impl PollOption {
+  async fn insert(
+    conn: &mut AsyncPgConnection,
+    poll_id: PollId,
+    options: Vec<(String, i32)>,
+  ) -> Result<usize, Error> {
+    let forms = options
+      .into_iter()
+      .enumerate()
+      .map(|(position, (name, vote_count))| {
+        (
+          poll_option::poll_id.eq(poll_id),
+          poll_option::name.eq(name),
+          poll_option::position.eq(option_position(position)),
+          poll_option::vote_count.eq(vote_count),
+        )
+      })
+      .collect::<Vec<_>>();
+    insert_into(poll_option::table)
+      .values(forms)
+      .execute(conn)
+      .await
+  }
+
+  /// The options of a poll, in the order in which they are shown.
+  pub async fn list_for_poll(pool: &mut DbPool<'_>, poll_id: PollId) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    poll_option::table
+      .filter(poll_option::poll_id.eq(poll_id))
+      .order_by(poll_option::position)
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl PollVote {
+  /// Replaces the votes of a person in a poll, and updates the vote counts of the options. An
+  /// empty list of options takes back the vote.
+  pub async fn vote(
+    pool: &mut DbPool<'_>,
+    poll_id: PollId,
+    person_id: PersonId,
+    option_ids: Vec<PollOptionId>,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let previous_option_ids = diesel::delete(
+            poll_vote::table
+              .filter(poll_vote::poll_id.eq(poll_id))
+              .filter(poll_vote::person_id.eq(person_id)),
+          )
+          .returning(poll_vote::poll_option_id)
+          .get_results::<PollOptionId>(conn)
+          .await?;
+          update(poll_option::table.filter(poll_option::id.eq_any(previous_option_ids)))
+            .set(poll_option::vote_count.eq(poll_option::vote_count - 1))
+            .execute(conn)
+            .await?;
+
+          if !option_ids.is_empty() {
+            let forms = option_ids
+              .iter()
+              .map(|option_id| {
+                (
+                  poll_vote::poll_id.eq(poll_id),
+                  poll_vote::poll_option_id.eq(*option_id),
+                  poll_vote::person_id.eq(person_id),
+                )
+              })
+              .collect::<Vec<_>>();
+            insert_into(poll_vote::table)
+              .values(forms)
+              .execute(conn)
+              .await?;
+            update(poll_option::table.filter(poll_option::id.eq_any(option_ids)))
+              .set(poll_option::vote_count.eq(poll_option::vote_count + 1))
+              .execute(conn)
+              .await?;
+          }
+          Ok(())
+        }) as _
+      })
+      .await
+  }
+
+  /// The options which the person voted for.
+  pub async fn list_for_person(
+    pool: &mut DbPool<'_>,
+    poll_id: PollId,
+    person_id: PersonId,
+  ) -> Result<Vec<PollOptionId>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    poll_vote::table
+      .filter(poll_vote::poll_id.eq(poll_id))
+      .filter(poll_vote::person_id.eq(person_id))
+      .select(poll_vote::poll_option_id)
+      .load::<PollOptionId>(conn)
+      .await
+  }
+}
+
+fn option_position(position: usize) -> i32 {
+  i32::try_from(position).unwrap_or(i32::MAX)
+}
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing)]
+mod tests {
+
+  use super::*;
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_poll_votes() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "poll_voter");
+    let person = Person::create(pool, &person_form).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "test community poll".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let post_form = PostInsertForm::new("A poll".into(), person.id, community.id);
+    let post = Post::create(pool, &post_form).await?;
+
+    let poll_form = PollInsertForm::new(post.id, false);
+    let poll = Poll::create_with_options(pool, &poll_form, vec!["yes".into(), "no".into()]).await?;
+    assert_eq!(
+      Some(poll.clone()),
+      Poll::read_for_post(pool, post.id).await?
+    );
+
+    let options = PollOption::list_for_poll(pool, poll.id).await?;
+    let names = options.iter().map(|o| o.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(vec!["yes", "no"], names);
+    let (yes, no) = (options[0].id, options[1].id);
+
+    // Voting again replaces the previous vote
+    PollVote::vote(pool, poll.id, person.id, vec![yes]).await?;
+    PollVote::vote(pool, poll.id, person.id, vec![no]).await?;
+    assert_eq!(
+      vec![no],
+      PollVote::list_for_person(pool, poll.id, person.id).await?
+    );
+    let counts = PollOption::list_for_poll(pool, poll.id)
+      .await?
+      .into_iter()
+      .map(|o| o.vote_count)
+      .collect::<Vec<_>>();
+    assert_eq!(vec![0, 1], counts);
+
+    // Federated vote counts are taken over as long as the options stay the same
+    let options = vec![("yes".to_string(), 5), ("no".to_string(), 3)];
+    Poll::upsert_with_vote_counts(pool, &poll_form, options).await?;
+    let counts = PollOption::list_for_poll(pool, poll.id)
+      .await?
+      .into_iter()
+      .map(|o| o.vote_count)
+      .collect::<Vec<_>>();
+    assert_eq!(vec![5, 3], counts);
+    assert_eq!(
+      vec![no],
+      PollVote::list_for_person(pool, poll.id, person.id).await?
+    );
+
+    Community::delete(pool, community.id).await?;
+    Person::delete(pool, person.id).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
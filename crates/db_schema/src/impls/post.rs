@@ -1,7 +1,16 @@
 use crate::{
   diesel::{BoolExpressionMethods, OptionalExtension},
   newtypes::{CommunityId, DbUrl, PersonId, PostId},
-  schema::{community, person, post, post_actions},
+  schema::{
+    community,
+    local_user,
+    person,
+    person_aggregates,
+    post,
+    post_actions,
+    post_aggregates,
+    post_archive,
+  },
   source::post::{
     Post,
     PostHide,
@@ -32,17 +41,20 @@ use crate::{
 use ::url::Url;
 use chrono::{DateTime, Utc};
 use diesel::{
-  dsl::{count, insert_into, not},
+  dsl::{count, exists, insert_into, not},
   expression::SelectableHelper,
   result::Error,
+  upsert::excluded,
   DecoratableTarget,
   ExpressionMethods,
+  JoinOnDsl,
   NullableExpressionMethods,
   QueryDsl,
   TextExpressionMethods,
 };
 use diesel_async::RunQueryDsl;
 use lemmy_utils::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
+use std::collections::HashMap;
 
 #[async_trait]
 impl Crud for Post {
@@ -64,8 +76,10 @@ impl Crud for Post {
     new_post: &Self::UpdateForm,
   ) -> Result<Self, Error> {
     let conn = &mut get_conn(pool).await?;
+    // A new body replaces the archived one
+    let unarchive = new_post.body.as_ref().map(|_| post::archived.eq(false));
     diesel::update(post::table.find(post_id))
-      .set(new_post)
+      .set((new_post, unarchive))
       .get_result::<Self>(conn)
       .await
   }
@@ -87,7 +101,7 @@ impl Post {
       .on_conflict(post::ap_id)
       .filter_target(coalesce(post::updated, post::published).lt(timestamp))
       .do_update()
-      .set(form)
+      .set((form, post::archived.eq(false)))
       .get_result::<Self>(conn)
       .await
   }
@@ -137,6 +151,7 @@ impl Post {
         post::name.eq(DELETED_REPLACEMENT_TEXT),
         post::url.eq(Option::<&str>::None),
         post::body.eq(DELETED_REPLACEMENT_TEXT),
+        post::archived.eq(false),
         post::deleted.eq(true),
         post::updated.eq(naive_now()),
       ))
@@ -273,6 +288,159 @@ impl Post {
       .first::<i64>(conn)
       .await
   }
+
+  /// The newest local posts which aren't deleted, together with the time when the creator's
+  /// account was created, whether the creator is an admin and the creator's reputation. Used to
+  /// preview changes of the content filters.
+  pub async fn list_recent_local(
+    pool: &mut DbPool<'_>,
+    limit: i64,
+  ) -> Result<Vec<(Self, DateTime<Utc>, bool, i64)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    post::table
+      .inner_join(person::table)
+      .inner_join(person_aggregates::table.on(person_aggregates::person_id.eq(person::id)))
+      .left_join(local_user::table.on(local_user::person_id.eq(person::id)))
+      .filter(post::local.eq(true))
+      .filter(post::deleted.eq(false))
+      .order_by(post::published.desc())
+      .limit(limit)
+      .select((
+        Self::as_select(),
+        person::published,
+        coalesce(local_user::admin.nullable(), false),
+        person_aggregates::reputation,
+      ))
+      .load(conn)
+      .await
+  }
+
+  /// Counts posts by the same creator in other communities since the given time, which have the
+  /// same title or link.
+  pub async fn count_recent_duplicates(
+    pool: &mut DbPool<'_>,
+    creator_id: PersonId,
+    community_id: CommunityId,
+    name: &str,
+    url: Option<&DbUrl>,
+    since: DateTime<Utc>,
+  ) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = post::table
+      .filter(post::creator_id.eq(creator_id))
+      .filter(post::community_id.ne(community_id))
+      .filter(post::published.gt(since))
+      .into_boxed();
+    query = match url {
+      Some(url) => query.filter(post::name.eq(name).or(post::url.eq(url))),
+      None => query.filter(post::name.eq(name)),
+    };
+    query.select(count(post::id)).first::<i64>(conn).await
+  }
+
+  /// Moves the body of old posts with few votes and comments into the compressed archive table,
+  /// to keep the post table small on old instances. Featured posts are never archived. Returns
+  /// the number of archived posts.
+  pub async fn archive_old(
+    pool: &mut DbPool<'_>,
+    published_before: DateTime<Utc>,
+    max_score: i64,
+    max_comments: i64,
+    limit: i64,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let posts: Vec<(PostId, Option<String>)> = post::table
+            .filter(post::published.lt(published_before))
+            .filter(post::archived.eq(false))
+            .filter(post::body.is_not_null())
+            .filter(post::featured_community.eq(false))
+            .filter(post::featured_local.eq(false))
+            .filter(exists(
+              post_aggregates::table
+                .filter(post_aggregates::post_id.eq(post::id))
+                .filter(post_aggregates::score.le(max_score))
+                .filter(post_aggregates::comments.le(max_comments)),
+            ))
+            .select((post::id, post::body))
+            .limit(limit)
+            .for_update()
+            .skip_locked()
+            .load(conn)
+            .await?;
+          let (ids, forms): (Vec<_>, Vec<_>) = posts
+            .into_iter()
+            .filter_map(|(id, body)| {
+              let form = (post_archive::post_id.eq(id), post_archive::body.eq(body?));
+              Some((id, form))
+            })
+            .unzip();
+          if ids.is_empty() {
+            return Ok(0);
+          }
+
+          insert_into(post_archive::table)
+            .values(forms)
+            .on_conflict(post_archive::post_id)
+            .do_update()
+            .set(post_archive::body.eq(excluded(post_archive::body)))
+            .execute(conn)
+            .await?;
+          diesel::update(post::table.filter(post::id.eq_any(ids)))
+            .set((
+              post::body.eq(Option::<String>::None),
+              post::archived.eq(true),
+            ))
+            .execute(conn)
+            .await
+        }) as _
+      })
+      .await
+  }
+
+  /// Fills in the body of archived posts from the archive table.
+  pub async fn restore_archived<'a>(
+    pool: &mut DbPool<'_>,
+    posts: impl IntoIterator<Item = &'a mut Post>,
+  ) -> Result<(), Error> {
+    let archived = posts.into_iter().filter(|p| p.archived).collect::<Vec<_>>();
+    if archived.is_empty() {
+      return Ok(());
+    }
+
+    let conn = &mut get_conn(pool).await?;
+    let ids = archived.iter().map(|p| p.id).collect::<Vec<_>>();
+    let mut bodies: HashMap<PostId, String> = post_archive::table
+      .filter(post_archive::post_id.eq_any(ids))
+      .load::<(PostId, String)>(conn)
+      .await?
+      .into_iter()
+      .collect();
+    for post in archived {
+      post.body = bodies.remove(&post.id);
+    }
+    Ok(())
+  }
+
+  /// Cross-posts of a link by the same creator in a community, which haven't been removed yet.
+  pub async fn list_crossposts_in_community(
+    pool: &mut DbPool<'_>,
+    creator_id: PersonId,
+    community_id: CommunityId,
+    url: &DbUrl,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    post::table
+      .filter(post::creator_id.eq(creator_id))
+      .filter(post::community_id.eq(community_id))
+      .filter(post::url.eq(url))
+      .filter(post::removed.eq(false))
+      .load::<Self>(conn)
+      .await
+  }
 }
 
 #[async_trait]
@@ -327,6 +495,7 @@ impl Saveable for PostSaved {
     let conn = &mut get_conn(pool).await?;
     uplete::new(post_actions::table.find((post_saved_form.person_id, post_saved_form.post_id)))
       .set_null(post_actions::saved)
+      .set_null(post_actions::saved_note)
       .get_result(conn)
       .await
   }
@@ -379,33 +548,59 @@ impl PostHide {
     pool: &mut DbPool<'_>,
     post_id: PostId,
     person_id: PersonId,
+  ) -> Result<usize, Error> {
+    Self::hide_many(pool, &[post_id], person_id).await
+  }
+
+  pub async fn unhide(
+    pool: &mut DbPool<'_>,
+    post_id: PostId,
+    person_id: PersonId,
+  ) -> Result<uplete::Count, Error> {
+    Self::unhide_many(pool, &[post_id], person_id).await
+  }
+
+  /// Hides all of the posts at once.
+  pub async fn hide_many(
+    pool: &mut DbPool<'_>,
+    post_ids: &[PostId],
+    person_id: PersonId,
   ) -> Result<usize, Error> {
     let conn = &mut get_conn(pool).await?;
+    let hidden = naive_now();
+    let forms = post_ids
+      .iter()
+      .map(|post_id| {
+        (
+          PostHideForm {
+            post_id: *post_id,
+            person_id,
+          },
+          post_actions::hidden.eq(hidden),
+        )
+      })
+      .collect::<Vec<_>>();
 
-    let form = (
-      &PostHideForm { post_id, person_id },
-      post_actions::hidden.eq(now().nullable()),
-    );
     insert_into(post_actions::table)
-      .values(form)
+      .values(forms)
       .on_conflict((post_actions::person_id, post_actions::post_id))
       .do_update()
-      .set(form)
+      .set(post_actions::hidden.eq(excluded(post_actions::hidden)))
       .execute(conn)
       .await
   }
 
-  pub async fn unhide(
+  pub async fn unhide_many(
     pool: &mut DbPool<'_>,
-    post_id_: PostId,
-    person_id_: PersonId,
+    post_ids: &[PostId],
+    person_id: PersonId,
   ) -> Result<uplete::Count, Error> {
     let conn = &mut get_conn(pool).await?;
 
     uplete::new(
       post_actions::table
-        .filter(post_actions::post_id.eq(post_id_))
-        .filter(post_actions::person_id.eq(person_id_)),
+        .filter(post_actions::post_id.eq_any(post_ids.to_vec()))
+        .filter(post_actions::person_id.eq(person_id)),
     )
     .set_null(post_actions::hidden)
     .get_result(conn)
@@ -423,6 +618,7 @@ mod tests {
       person::{Person, PersonInsertForm},
       post::{
         Post,
+        PostHide,
         PostInsertForm,
         PostLike,
         PostLikeForm,
@@ -435,7 +631,7 @@ mod tests {
     traits::{Crud, Likeable, Saveable},
     utils::{build_db_pool_for_tests, uplete},
   };
-  use chrono::DateTime;
+  use chrono::{DateTime, TimeDelta, Utc};
   use lemmy_utils::error::LemmyResult;
   use pretty_assertions::assert_eq;
   use serial_test::serial;
@@ -507,6 +703,9 @@ mod tests {
       featured_local: false,
       url_content_type: None,
       scheduled_publish_time: None,
+      archived: false,
+      pending: false,
+      lock_expires: None,
     };
 
     // Post Like
@@ -529,6 +728,7 @@ mod tests {
     let post_saved_form = PostSavedForm {
       post_id: inserted_post.id,
       person_id: inserted_person.id,
+      saved_note: Some("read later".into()),
     };
 
     let inserted_post_saved = PostSaved::save(pool, &post_saved_form).await?;
@@ -537,6 +737,7 @@ mod tests {
       post_id: inserted_post.id,
       person_id: inserted_person.id,
       published: inserted_post_saved.published,
+      saved_note: Some("read later".into()),
     };
 
     // Mark 2 posts as read
@@ -568,6 +769,13 @@ mod tests {
       PostRead::mark_as_unread(pool, inserted_post2.id, inserted_person.id).await?;
     assert_eq!(uplete::Count::only_deleted(1), read_removed_2);
 
+    // Hide and unhide both posts at once
+    let post_ids = [inserted_post.id, inserted_post2.id];
+    let hidden = PostHide::hide_many(pool, &post_ids, inserted_person.id).await?;
+    assert_eq!(2, hidden);
+    let hide_removed = PostHide::unhide_many(pool, &post_ids, inserted_person.id).await?;
+    assert_eq!(uplete::Count::only_deleted(2), hide_removed);
+
     let num_deleted = Post::delete(pool, inserted_post.id).await?
       + Post::delete(pool, inserted_post2.id).await?
       + Post::delete(pool, inserted_scheduled_post.id).await?;
@@ -585,4 +793,67 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_archive() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let new_person = PersonInsertForm::test_form(inserted_instance.id, "archivist");
+    let inserted_person = Person::create(pool, &new_person).await?;
+    let new_community = CommunityInsertForm::new(
+      inserted_instance.id,
+      "archive".into(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &new_community).await?;
+
+    let old_post_form = PostInsertForm {
+      body: Some("old body".to_string()),
+      published: Some(Utc::now() - TimeDelta::days(400)),
+      ..PostInsertForm::new("old post".into(), inserted_person.id, inserted_community.id)
+    };
+    let old_post = Post::create(pool, &old_post_form).await?;
+    let new_post_form = PostInsertForm {
+      body: Some("new body".to_string()),
+      ..PostInsertForm::new("new post".into(), inserted_person.id, inserted_community.id)
+    };
+    let new_post = Post::create(pool, &new_post_form).await?;
+
+    let published_before = Utc::now() - TimeDelta::days(365);
+    assert_eq!(
+      1,
+      Post::archive_old(pool, published_before, 0, 0, 100).await?
+    );
+    assert_eq!(
+      0,
+      Post::archive_old(pool, published_before, 0, 0, 100).await?
+    );
+
+    let mut read_old_post = Post::read(pool, old_post.id).await?;
+    let mut read_new_post = Post::read(pool, new_post.id).await?;
+    assert!(read_old_post.archived);
+    assert_eq!(None, read_old_post.body);
+    assert!(!read_new_post.archived);
+
+    Post::restore_archived(pool, [&mut read_old_post, &mut read_new_post]).await?;
+    assert_eq!(Some("old body".to_string()), read_old_post.body);
+    assert_eq!(Some("new body".to_string()), read_new_post.body);
+
+    // Editing the body takes the post out of the archive
+    let post_update_form = PostUpdateForm {
+      body: Some(Some("edited body".to_string())),
+      ..Default::default()
+    };
+    let updated_post = Post::update(pool, old_post.id, &post_update_form).await?;
+    assert!(!updated_post.archived);
+    assert_eq!(Some("edited body".to_string()), updated_post.body);
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
 }
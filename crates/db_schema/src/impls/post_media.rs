@@ -0,0 +1,61 @@
+use crate::{
+  newtypes::PostId,
+  schema::post_media,
+  source::post_media::{PostMedia, PostMediaForm},
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl PostMedia {
+  /// Replaces the gallery of a post with the given images, which are shown in this order.
+  pub async fn replace_for_post(
+    pool: &mut DbPool<'_>,
+    post_id: PostId,
+    media: Vec<PostMediaForm>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          diesel::delete(post_media::table.filter(post_media::post_id.eq(post_id)))
+            .execute(conn)
+            .await?;
+          if media.is_empty() {
+            return Ok(vec![]);
+          }
+          let forms = media
+            .into_iter()
+            .enumerate()
+            .map(|(position, form)| {
+              (
+                post_media::post_id.eq(post_id),
+                post_media::position.eq(i32::try_from(position).unwrap_or(i32::MAX)),
+                post_media::url.eq(form.url),
+                post_media::alt_text.eq(form.alt_text),
+              )
+            })
+            .collect::<Vec<_>>();
+          insert_into(post_media::table)
+            .values(forms)
+            .get_results::<Self>(conn)
+            .await
+        }) as _
+      })
+      .await
+  }
+
+  /// The gallery images of the given posts, in the order in which they are shown.
+  pub async fn list_for_posts(
+    pool: &mut DbPool<'_>,
+    post_ids: &[PostId],
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    post_media::table
+      .filter(post_media::post_id.eq_any(post_ids))
+      .order_by((post_media::post_id, post_media::position))
+      .load::<Self>(conn)
+      .await
+  }
+}
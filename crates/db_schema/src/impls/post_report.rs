@@ -1,12 +1,13 @@
 use crate::{
   newtypes::{PersonId, PostId, PostReportId},
   schema::post_report::{
-    dsl::{post_report, resolved, resolver_id, updated},
+    dsl::{assigned_to, outcome, post_report, resolved, resolver_id, updated},
     post_id,
   },
   source::post_report::{PostReport, PostReportForm},
   traits::Reportable,
   utils::{get_conn, naive_now, DbPool},
+  ReportOutcome,
 };
 use diesel::{
   dsl::{insert_into, update},
@@ -34,11 +35,13 @@ impl Reportable for PostReport {
     pool: &mut DbPool<'_>,
     report_id: Self::IdType,
     by_resolver_id: PersonId,
+    outcome_: ReportOutcome,
   ) -> Result<usize, Error> {
     let conn = &mut get_conn(pool).await?;
     update(post_report.find(report_id))
       .set((
         resolved.eq(true),
+        outcome.eq(Some(outcome_)),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
@@ -52,14 +55,19 @@ impl Reportable for PostReport {
     by_resolver_id: PersonId,
   ) -> Result<usize, Error> {
     let conn = &mut get_conn(pool).await?;
-    update(post_report.filter(post_id.eq(post_id_)))
-      .set((
-        resolved.eq(true),
-        resolver_id.eq(by_resolver_id),
-        updated.eq(naive_now()),
-      ))
-      .execute(conn)
-      .await
+    update(
+      post_report
+        .filter(post_id.eq(post_id_))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      outcome.eq(Some(ReportOutcome::ResolvedRemoved)),
+      resolver_id.eq(by_resolver_id),
+      updated.eq(naive_now()),
+    ))
+    .execute(conn)
+    .await
   }
 
   async fn unresolve(
@@ -71,12 +79,50 @@ impl Reportable for PostReport {
     update(post_report.find(report_id))
       .set((
         resolved.eq(false),
+        outcome.eq(None::<ReportOutcome>),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
       .execute(conn)
       .await
   }
+
+  async fn assign(
+    pool: &mut DbPool<'_>,
+    report_id_: Self::IdType,
+    assignee_id: Option<PersonId>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(post_report.find(report_id_))
+      .set(assigned_to.eq(assignee_id))
+      .execute(conn)
+      .await
+  }
+}
+
+impl PostReport {
+  /// Resolves all pending reports against the given posts, for example when they are removed
+  /// together with a ban.
+  pub async fn resolve_all_for_posts(
+    pool: &mut DbPool<'_>,
+    post_ids: &[PostId],
+    by_resolver_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      post_report
+        .filter(post_id.eq_any(post_ids))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      outcome.eq(Some(ReportOutcome::ResolvedRemoved)),
+      resolver_id.eq(by_resolver_id),
+      updated.eq(naive_now()),
+    ))
+    .execute(conn)
+    .await
+  }
 }
 
 #[cfg(test)]
@@ -131,7 +177,8 @@ mod tests {
 
     let (person, report) = init(pool).await?;
 
-    let resolved_count = PostReport::resolve(pool, report.id, person.id).await?;
+    let resolved_count =
+      PostReport::resolve(pool, report.id, person.id, ReportOutcome::Dismissed).await?;
     assert_eq!(resolved_count, 1);
 
     let unresolved_count = PostReport::unresolve(pool, report.id, person.id).await?;
@@ -155,6 +202,11 @@ mod tests {
       PostReport::resolve_all_for_object(pool, report.post_id, person.id).await?;
     assert_eq!(resolved_count, 1);
 
+    // Reports which were already resolved keep their resolver
+    let resolved_count =
+      PostReport::resolve_all_for_posts(pool, &[report.post_id], person.id).await?;
+    assert_eq!(resolved_count, 0);
+
     Person::delete(pool, person.id).await?;
     Post::delete(pool, report.post_id).await?;
 
@@ -1,6 +1,6 @@
 use crate::{
   diesel::{DecoratableTarget, OptionalExtension},
-  newtypes::{DbUrl, PersonId, PrivateMessageId},
+  newtypes::{DbUrl, PersonId, PmConversationId, PrivateMessageId},
   schema::private_message,
   source::private_message::{PrivateMessage, PrivateMessageInsertForm, PrivateMessageUpdateForm},
   traits::Crud,
@@ -70,6 +70,24 @@ impl PrivateMessage {
     .await
   }
 
+  /// Marks all messages which the recipient received in the given conversation as read.
+  pub async fn mark_conversation_as_read(
+    pool: &mut DbPool<'_>,
+    conversation_id: PmConversationId,
+    for_recipient_id: PersonId,
+  ) -> Result<Vec<PrivateMessage>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      private_message::table
+        .filter(private_message::conversation_id.eq(conversation_id))
+        .filter(private_message::recipient_id.eq(for_recipient_id))
+        .filter(private_message::read.eq(false)),
+    )
+    .set(private_message::read.eq(true))
+    .get_results::<Self>(conn)
+    .await
+  }
+
   pub async fn read_from_apub_id(
     pool: &mut DbPool<'_>,
     object_id: Url,
@@ -91,6 +109,7 @@ mod tests {
     source::{
       instance::Instance,
       person::{Person, PersonInsertForm},
+      pm_conversation::PmConversation,
       private_message::{PrivateMessage, PrivateMessageInsertForm, PrivateMessageUpdateForm},
     },
     traits::Crud,
@@ -119,7 +138,7 @@ mod tests {
 
     let private_message_form = PrivateMessageInsertForm::new(
       inserted_creator.id,
-      inserted_recipient.id,
+      Some(inserted_recipient.id),
       "A test private message".into(),
     );
 
@@ -129,7 +148,7 @@ mod tests {
       id: inserted_private_message.id,
       content: "A test private message".into(),
       creator_id: inserted_creator.id,
-      recipient_id: inserted_recipient.id,
+      recipient_id: Some(inserted_recipient.id),
       deleted: false,
       read: false,
       updated: None,
@@ -140,6 +159,7 @@ mod tests {
       ))?
       .into(),
       local: true,
+      conversation_id: inserted_private_message.conversation_id,
     };
 
     let read_private_message = PrivateMessage::read(pool, inserted_private_message.id).await?;
@@ -185,4 +205,46 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_conversation() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let alice = Person::create(pool, &PersonInsertForm::test_form(instance.id, "alice_pm")).await?;
+    let bob = Person::create(pool, &PersonInsertForm::test_form(instance.id, "bob_pm")).await?;
+    let carol = Person::create(pool, &PersonInsertForm::test_form(instance.id, "carol_pm")).await?;
+
+    let to_bob = PrivateMessageInsertForm::new(alice.id, Some(bob.id), "hi bob".into());
+    let to_bob = PrivateMessage::create(pool, &to_bob).await?;
+    let to_alice = PrivateMessageInsertForm::new(bob.id, Some(alice.id), "hi alice".into());
+    let to_alice = PrivateMessage::create(pool, &to_alice).await?;
+    let to_carol = PrivateMessageInsertForm::new(alice.id, Some(carol.id), "hi carol".into());
+    let to_carol = PrivateMessage::create(pool, &to_carol).await?;
+
+    // Both directions share one conversation
+    assert_eq!(to_bob.conversation_id, to_alice.conversation_id);
+    assert_ne!(to_bob.conversation_id, to_carol.conversation_id);
+
+    let conversation = PmConversation::read(pool, to_bob.conversation_id).await?;
+    assert!(conversation.is_participant(pool, alice.id).await?);
+    assert!(!conversation.is_participant(pool, carol.id).await?);
+    assert_eq!(Some(bob.id), conversation.other_participant(alice.id));
+    assert_eq!(to_alice.published, conversation.last_message_published);
+
+    // Only the messages received in this conversation are marked as read
+    let marked = PrivateMessage::mark_conversation_as_read(pool, conversation.id, bob.id).await?;
+    assert_eq!(
+      vec![to_bob.id],
+      marked.iter().map(|m| m.id).collect::<Vec<_>>()
+    );
+    assert!(!PrivateMessage::read(pool, to_alice.id).await?.read);
+    assert!(!PrivateMessage::read(pool, to_carol.id).await?.read);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
 }
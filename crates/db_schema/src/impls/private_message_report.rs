@@ -1,9 +1,17 @@
 use crate::{
   newtypes::{PersonId, PrivateMessageId, PrivateMessageReportId},
-  schema::private_message_report::dsl::{private_message_report, resolved, resolver_id, updated},
+  schema::private_message_report::dsl::{
+    assigned_to,
+    outcome,
+    private_message_report,
+    resolved,
+    resolver_id,
+    updated,
+  },
   source::private_message_report::{PrivateMessageReport, PrivateMessageReportForm},
   traits::Reportable,
   utils::{get_conn, naive_now, DbPool},
+  ReportOutcome,
 };
 use diesel::{
   dsl::{insert_into, update},
@@ -34,11 +42,13 @@ impl Reportable for PrivateMessageReport {
     pool: &mut DbPool<'_>,
     report_id: Self::IdType,
     by_resolver_id: PersonId,
+    outcome_: ReportOutcome,
   ) -> Result<usize, Error> {
     let conn = &mut get_conn(pool).await?;
     update(private_message_report.find(report_id))
       .set((
         resolved.eq(true),
+        outcome.eq(Some(outcome_)),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
@@ -64,10 +74,23 @@ impl Reportable for PrivateMessageReport {
     update(private_message_report.find(report_id))
       .set((
         resolved.eq(false),
+        outcome.eq(None::<ReportOutcome>),
         resolver_id.eq(by_resolver_id),
         updated.eq(naive_now()),
       ))
       .execute(conn)
       .await
   }
+
+  async fn assign(
+    pool: &mut DbPool<'_>,
+    report_id_: Self::IdType,
+    assignee_id: Option<PersonId>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(private_message_report.find(report_id_))
+      .set(assigned_to.eq(assignee_id))
+      .execute(conn)
+      .await
+  }
 }
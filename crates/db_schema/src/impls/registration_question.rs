@@ -0,0 +1,140 @@
+use crate::{
+  newtypes::{RegistrationApplicationId, RegistrationQuestionId},
+  schema::{registration_answer, registration_question},
+  source::registration_question::{
+    RegistrationAnswer,
+    RegistrationAnswerInsertForm,
+    RegistrationQuestion,
+    RegistrationQuestionInsertForm,
+    RegistrationQuestionUpdateForm,
+  },
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for RegistrationQuestion {
+  type InsertForm = RegistrationQuestionInsertForm;
+  type UpdateForm = RegistrationQuestionUpdateForm;
+  type IdType = RegistrationQuestionId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(registration_question::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    id: RegistrationQuestionId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(registration_question::table.find(id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl RegistrationQuestion {
+  /// Lists all registration questions in the order in which they are shown to applicants.
+  pub async fn list(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    registration_question::table
+      .order((
+        registration_question::position.asc(),
+        registration_question::id.asc(),
+      ))
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl RegistrationAnswer {
+  pub async fn create_many(
+    pool: &mut DbPool<'_>,
+    forms: &[RegistrationAnswerInsertForm],
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(registration_answer::table)
+      .values(forms)
+      .get_results::<Self>(conn)
+      .await
+  }
+
+  pub async fn list_for_applications(
+    pool: &mut DbPool<'_>,
+    application_ids: &[RegistrationApplicationId],
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    registration_answer::table
+      .filter(registration_answer::registration_application_id.eq_any(application_ids))
+      .order(registration_answer::id.asc())
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+
+  use crate::{
+    source::registration_question::{
+      RegistrationQuestion,
+      RegistrationQuestionInsertForm,
+      RegistrationQuestionUpdateForm,
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_registration_questions() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let mut second_form = RegistrationQuestionInsertForm::new("Second?".to_string());
+    second_form.position = Some(2);
+    let second = RegistrationQuestion::create(pool, &second_form).await?;
+    let mut first_form = RegistrationQuestionInsertForm::new("First?".to_string());
+    first_form.position = Some(1);
+    first_form.required = Some(false);
+    let first = RegistrationQuestion::create(pool, &first_form).await?;
+    assert!(second.required);
+    assert!(!first.required);
+
+    let list = RegistrationQuestion::list(pool).await?;
+    assert_eq!(
+      vec![first.id, second.id],
+      list.iter().map(|q| q.id).collect::<Vec<_>>()
+    );
+
+    let updated = RegistrationQuestion::update(
+      pool,
+      second.id,
+      &RegistrationQuestionUpdateForm {
+        position: Some(0),
+        ..Default::default()
+      },
+    )
+    .await?;
+    let list = RegistrationQuestion::list(pool).await?;
+    assert_eq!(updated.id, list[0].id);
+
+    RegistrationQuestion::delete(pool, first.id).await?;
+    RegistrationQuestion::delete(pool, second.id).await?;
+    assert!(RegistrationQuestion::list(pool).await?.is_empty());
+
+    Ok(())
+  }
+}
@@ -0,0 +1,210 @@
+use crate::{
+  schema::registration_source,
+  source::registration_source::{RegistrationBurst, RegistrationSource, RegistrationSourceForm},
+  utils::{get_conn, DbPool},
+};
+use chrono::{DateTime, Utc};
+use diesel::{
+  dsl::{count_star, insert_into, max, min},
+  result::Error,
+  ExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+impl RegistrationSource {
+  pub async fn create(pool: &mut DbPool<'_>, form: &RegistrationSourceForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(registration_source::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Number of registrations from the subnet since the given time.
+  pub async fn count_for_subnet(
+    pool: &mut DbPool<'_>,
+    ip_subnet: &str,
+    since: DateTime<Utc>,
+  ) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    registration_source::table
+      .filter(registration_source::ip_subnet.eq(ip_subnet))
+      .filter(registration_source::published.gt(since))
+      .count()
+      .get_result(conn)
+      .await
+  }
+
+  /// Number of registrations from the autonomous system since the given time.
+  pub async fn count_for_asn(
+    pool: &mut DbPool<'_>,
+    asn: i32,
+    since: DateTime<Utc>,
+  ) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    registration_source::table
+      .filter(registration_source::asn.eq(asn))
+      .filter(registration_source::published.gt(since))
+      .count()
+      .get_result(conn)
+      .await
+  }
+
+  /// Subnets and autonomous systems with more than one registration since the given time, the
+  /// largest bursts first.
+  pub async fn list_bursts(
+    pool: &mut DbPool<'_>,
+    since: DateTime<Utc>,
+    limit: i64,
+  ) -> Result<Vec<RegistrationBurst>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let by_subnet = registration_source::table
+      .filter(registration_source::published.gt(since))
+      .group_by(registration_source::ip_subnet)
+      .having(count_star().gt(1))
+      .select((
+        registration_source::ip_subnet,
+        count_star(),
+        min(registration_source::published),
+        max(registration_source::published),
+      ))
+      .order_by(count_star().desc())
+      .limit(limit)
+      .load::<(String, i64, Option<DateTime<Utc>>, Option<DateTime<Utc>>)>(conn)
+      .await?;
+    let by_asn = registration_source::table
+      .filter(registration_source::published.gt(since))
+      .filter(registration_source::asn.is_not_null())
+      .group_by(registration_source::asn)
+      .having(count_star().gt(1))
+      .select((
+        registration_source::asn,
+        max(registration_source::asn_organization),
+        count_star(),
+        min(registration_source::published),
+        max(registration_source::published),
+      ))
+      .order_by(count_star().desc())
+      .limit(limit)
+      .load::<(
+        Option<i32>,
+        Option<String>,
+        i64,
+        Option<DateTime<Utc>>,
+        Option<DateTime<Utc>>,
+      )>(conn)
+      .await?;
+
+    let mut bursts: Vec<RegistrationBurst> = by_subnet
+      .into_iter()
+      .map(
+        |(ip_subnet, registrations, first, last)| RegistrationBurst {
+          ip_subnet: Some(ip_subnet),
+          asn: None,
+          asn_organization: None,
+          registrations,
+          first_registration: first.unwrap_or(since),
+          last_registration: last.unwrap_or(since),
+        },
+      )
+      .chain(
+        by_asn
+          .into_iter()
+          .map(
+            |(asn, asn_organization, registrations, first, last)| RegistrationBurst {
+              ip_subnet: None,
+              asn,
+              asn_organization,
+              registrations,
+              first_registration: first.unwrap_or(since),
+              last_registration: last.unwrap_or(since),
+            },
+          ),
+      )
+      .collect();
+    bursts.sort_by(|a, b| b.registrations.cmp(&a.registrations));
+    bursts.truncate(usize::try_from(limit).unwrap_or_default());
+    Ok(bursts)
+  }
+
+  pub async fn delete_older_than(
+    pool: &mut DbPool<'_>,
+    before: DateTime<Utc>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(registration_source::table.filter(registration_source::published.lt(before)))
+      .execute(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    source::{
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      registration_source::{RegistrationSource, RegistrationSourceForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use chrono::{TimeDelta, Utc};
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_registration_bursts() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let since = Utc::now() - TimeDelta::hours(1);
+    for name in ["rs_one", "rs_two", "rs_three"] {
+      let person = Person::create(
+        pool,
+        &PersonInsertForm::test_form(inserted_instance.id, name),
+      )
+      .await?;
+      let form = RegistrationSourceForm {
+        asn: Some(64496),
+        asn_organization: Some("Example Hosting".into()),
+        ..RegistrationSourceForm::new(person.id, "192.0.2.0/24".into())
+      };
+      RegistrationSource::create(pool, &form).await?;
+    }
+
+    assert_eq!(
+      3,
+      RegistrationSource::count_for_subnet(pool, "192.0.2.0/24", since).await?
+    );
+    assert_eq!(
+      0,
+      RegistrationSource::count_for_subnet(pool, "198.51.100.0/24", since).await?
+    );
+    assert_eq!(
+      3,
+      RegistrationSource::count_for_asn(pool, 64496, since).await?
+    );
+
+    let bursts = RegistrationSource::list_bursts(pool, since, 10).await?;
+    assert_eq!(2, bursts.len());
+    assert!(bursts.iter().all(|b| b.registrations == 3));
+    assert!(bursts
+      .iter()
+      .any(|b| b.asn_organization.as_deref() == Some("Example Hosting")));
+
+    RegistrationSource::delete_older_than(pool, Utc::now() + TimeDelta::minutes(1)).await?;
+    assert!(RegistrationSource::list_bursts(pool, since, 10)
+      .await?
+      .is_empty());
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
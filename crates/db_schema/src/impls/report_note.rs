@@ -0,0 +1,36 @@
+use crate::{
+  newtypes::ReportNoteId,
+  schema::report_note,
+  source::report_note::{ReportNote, ReportNoteInsertForm, ReportNoteUpdateForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{insert_into, result::Error, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for ReportNote {
+  type InsertForm = ReportNoteInsertForm;
+  type UpdateForm = ReportNoteUpdateForm;
+  type IdType = ReportNoteId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(report_note::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    note_id: ReportNoteId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(report_note::table.find(note_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
@@ -0,0 +1,50 @@
+use crate::{
+  newtypes::ReportReasonCategoryId,
+  schema::report_reason_category,
+  source::report_reason_category::{
+    ReportReasonCategory,
+    ReportReasonCategoryInsertForm,
+    ReportReasonCategoryUpdateForm,
+  },
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{insert_into, result::Error, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for ReportReasonCategory {
+  type InsertForm = ReportReasonCategoryInsertForm;
+  type UpdateForm = ReportReasonCategoryUpdateForm;
+  type IdType = ReportReasonCategoryId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(report_reason_category::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    category_id: ReportReasonCategoryId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(report_reason_category::table.find(category_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl ReportReasonCategory {
+  pub async fn list(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    report_reason_category::table
+      .order(report_reason_category::name)
+      .load::<Self>(conn)
+      .await
+  }
+}
@@ -0,0 +1,36 @@
+use crate::{
+  newtypes::SpamScoreId,
+  schema::spam_score,
+  source::spam_score::{SpamScore, SpamScoreInsertForm, SpamScoreUpdateForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for SpamScore {
+  type InsertForm = SpamScoreInsertForm;
+  type UpdateForm = SpamScoreUpdateForm;
+  type IdType = SpamScoreId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(spam_score::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    spam_score_id: SpamScoreId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(spam_score::table.find(spam_score_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
@@ -0,0 +1,283 @@
+use crate::{
+  newtypes::CommunityId,
+  schema::{
+    comment,
+    comment_report,
+    community,
+    mod_ban,
+    mod_ban_from_community,
+    mod_remove_comment,
+    mod_remove_community,
+    mod_remove_post,
+    post,
+    post_report,
+    private_message_report,
+    transparency_report,
+  },
+  source::transparency_report::{TransparencyReport, TransparencyReportForm},
+  utils::{get_conn, limit_and_offset, DbPool},
+};
+use chrono::{DateTime, Utc};
+use diesel::{
+  dsl::{count, exists, insert_into},
+  result::Error,
+  select,
+  ExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use std::collections::HashMap;
+
+/// Number of actions per community, and whether the community is local.
+type CommunityCounts = Vec<(CommunityId, bool, i64)>;
+
+impl TransparencyReport {
+  /// Counts the moderation actions and reports between the given times, and stores them as
+  /// reports for the instance and for each local community which had any of them.
+  pub async fn generate(
+    pool: &mut DbPool<'_>,
+    period_start: DateTime<Utc>,
+    period_end: DateTime<Utc>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+
+    let posts_removed: CommunityCounts = mod_remove_post::table
+      .inner_join(post::table.inner_join(community::table))
+      .filter(mod_remove_post::removed.eq(true))
+      .filter(mod_remove_post::when_.ge(period_start))
+      .filter(mod_remove_post::when_.lt(period_end))
+      .group_by((community::id, community::local))
+      .select((community::id, community::local, count(mod_remove_post::id)))
+      .load(conn)
+      .await?;
+    let comments_removed: CommunityCounts = mod_remove_comment::table
+      .inner_join(comment::table.inner_join(post::table.inner_join(community::table)))
+      .filter(mod_remove_comment::removed.eq(true))
+      .filter(mod_remove_comment::when_.ge(period_start))
+      .filter(mod_remove_comment::when_.lt(period_end))
+      .group_by((community::id, community::local))
+      .select((
+        community::id,
+        community::local,
+        count(mod_remove_comment::id),
+      ))
+      .load(conn)
+      .await?;
+    let community_bans: CommunityCounts = mod_ban_from_community::table
+      .inner_join(community::table)
+      .filter(mod_ban_from_community::banned.eq(true))
+      .filter(mod_ban_from_community::when_.ge(period_start))
+      .filter(mod_ban_from_community::when_.lt(period_end))
+      .group_by((community::id, community::local))
+      .select((
+        community::id,
+        community::local,
+        count(mod_ban_from_community::id),
+      ))
+      .load(conn)
+      .await?;
+    let post_reports: CommunityCounts = post_report::table
+      .inner_join(post::table.inner_join(community::table))
+      .filter(post_report::published.ge(period_start))
+      .filter(post_report::published.lt(period_end))
+      .group_by((community::id, community::local))
+      .select((community::id, community::local, count(post_report::id)))
+      .load(conn)
+      .await?;
+    let comment_reports: CommunityCounts = comment_report::table
+      .inner_join(comment::table.inner_join(post::table.inner_join(community::table)))
+      .filter(comment_report::published.ge(period_start))
+      .filter(comment_report::published.lt(period_end))
+      .group_by((community::id, community::local))
+      .select((community::id, community::local, count(comment_report::id)))
+      .load(conn)
+      .await?;
+
+    let mut instance_report = TransparencyReportForm {
+      period_start,
+      period_end,
+      ..Default::default()
+    };
+    let mut community_reports = HashMap::<CommunityId, TransparencyReportForm>::new();
+    let categories: [(CommunityCounts, fn(&mut TransparencyReportForm) -> &mut i64); 5] = [
+      (posts_removed, |r| &mut r.posts_removed),
+      (comments_removed, |r| &mut r.comments_removed),
+      (community_bans, |r| &mut r.community_bans),
+      (post_reports, |r| &mut r.post_reports),
+      (comment_reports, |r| &mut r.comment_reports),
+    ];
+    for (counts, field) in categories {
+      for (community_id, local, count) in counts {
+        *field(&mut instance_report) += count;
+        if local {
+          let report =
+            community_reports
+              .entry(community_id)
+              .or_insert_with(|| TransparencyReportForm {
+                community_id: Some(community_id),
+                period_start,
+                period_end,
+                ..Default::default()
+              });
+          *field(report) += count;
+        }
+      }
+    }
+
+    instance_report.communities_removed = mod_remove_community::table
+      .filter(mod_remove_community::removed.eq(true))
+      .filter(mod_remove_community::when_.ge(period_start))
+      .filter(mod_remove_community::when_.lt(period_end))
+      .select(count(mod_remove_community::id))
+      .first(conn)
+      .await?;
+    instance_report.site_bans = mod_ban::table
+      .filter(mod_ban::banned.eq(true))
+      .filter(mod_ban::when_.ge(period_start))
+      .filter(mod_ban::when_.lt(period_end))
+      .select(count(mod_ban::id))
+      .first(conn)
+      .await?;
+    instance_report.private_message_reports = private_message_report::table
+      .filter(private_message_report::published.ge(period_start))
+      .filter(private_message_report::published.lt(period_end))
+      .select(count(private_message_report::id))
+      .first(conn)
+      .await?;
+
+    let forms = std::iter::once(instance_report)
+      .chain(community_reports.into_values())
+      .collect::<Vec<_>>();
+    insert_into(transparency_report::table)
+      .values(forms)
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+  }
+
+  /// Whether the instance-wide report for the period starting at the given time exists already.
+  pub async fn exists_for_period(
+    pool: &mut DbPool<'_>,
+    period_start: DateTime<Utc>,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    select(exists(
+      transparency_report::table
+        .filter(transparency_report::community_id.is_null())
+        .filter(transparency_report::period_start.eq(period_start)),
+    ))
+    .get_result(conn)
+    .await
+  }
+
+  /// Reports of a community, or the instance-wide reports if no community is given. Newest first.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    community_id: Option<CommunityId>,
+    page: Option<i64>,
+    limit: Option<i64>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (limit, offset) = limit_and_offset(page, limit)?;
+    let mut query = transparency_report::table.into_boxed();
+    query = match community_id {
+      Some(community_id) => query.filter(transparency_report::community_id.eq(community_id)),
+      None => query.filter(transparency_report::community_id.is_null()),
+    };
+    query
+      .order_by(transparency_report::period_start.desc())
+      .limit(limit)
+      .offset(offset)
+      .load(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use crate::{
+    schema::transparency_report,
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      moderator::{ModRemovePost, ModRemovePostForm},
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+      transparency_report::TransparencyReport,
+    },
+    traits::Crud,
+    utils::{build_db_pool_for_tests, get_conn},
+  };
+  use chrono::{TimeDelta, Utc};
+  use diesel::{ExpressionMethods, QueryDsl};
+  use diesel_async::RunQueryDsl;
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_generate() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "transparent_mod");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let community_form = CommunityInsertForm {
+      local: Some(true),
+      ..CommunityInsertForm::new(
+        inserted_instance.id,
+        "transparent".into(),
+        "nada".to_owned(),
+        "pubkey".to_string(),
+      )
+    };
+    let inserted_community = Community::create(pool, &community_form).await?;
+    let post_form = PostInsertForm::new(
+      "removed post".into(),
+      inserted_person.id,
+      inserted_community.id,
+    );
+    let inserted_post = Post::create(pool, &post_form).await?;
+    let remove_form = ModRemovePostForm {
+      mod_person_id: inserted_person.id,
+      post_id: inserted_post.id,
+      reason: None,
+      removed: Some(true),
+      automod: false,
+    };
+    ModRemovePost::create(pool, &remove_form).await?;
+
+    let period_start = Utc::now() - TimeDelta::hours(1);
+    let period_end = Utc::now() + TimeDelta::hours(1);
+    assert!(!TransparencyReport::exists_for_period(pool, period_start).await?);
+    assert!(TransparencyReport::generate(pool, period_start, period_end).await? >= 2);
+    assert!(TransparencyReport::exists_for_period(pool, period_start).await?);
+    // Generating again doesn't create duplicates
+    assert_eq!(
+      0,
+      TransparencyReport::generate(pool, period_start, period_end).await?
+    );
+
+    let instance_reports = TransparencyReport::list(pool, None, None, None).await?;
+    assert!(!instance_reports.is_empty());
+    assert!(instance_reports[0].posts_removed >= 1);
+    let community_reports =
+      TransparencyReport::list(pool, Some(inserted_community.id), None, None).await?;
+    assert_eq!(1, community_reports.len());
+    assert_eq!(1, community_reports[0].posts_removed);
+    assert_eq!(0, community_reports[0].post_reports);
+
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(
+      transparency_report::table.filter(transparency_report::period_start.eq(period_start)),
+    )
+    .execute(conn)
+    .await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
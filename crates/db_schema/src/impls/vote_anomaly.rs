@@ -0,0 +1,104 @@
+use crate::{
+  newtypes::VoteAnomalyId,
+  schema::{comment, comment_actions, post, post_actions, vote_anomaly},
+  source::vote_anomaly::{VoteAnomaly, VoteAnomalyInsertForm, VoteAnomalyUpdateForm},
+  traits::Crud,
+  utils::{get_conn, uplete, DbPool},
+  VoteAnomalyKind,
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for VoteAnomaly {
+  type InsertForm = VoteAnomalyInsertForm;
+  type UpdateForm = VoteAnomalyUpdateForm;
+  type IdType = VoteAnomalyId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(vote_anomaly::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    vote_anomaly_id: VoteAnomalyId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(vote_anomaly::table.find(vote_anomaly_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl VoteAnomaly {
+  /// Removes the votes which were found suspicious: for `SingleTargetUpvotes` all votes of the
+  /// voters on posts and comments of the person, for `InstanceVoteBurst` the votes of the voters
+  /// on the post. The votes are only removed on this instance. Returns the number of removed
+  /// votes.
+  pub async fn remove_votes(&self, pool: &mut DbPool<'_>) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let voter_ids = self.voter_ids.clone();
+
+    match (self.kind, self.person_id, self.post_id) {
+      (VoteAnomalyKind::SingleTargetUpvotes, Some(person_id), _) => {
+        conn
+          .build_transaction()
+          .run(|conn| {
+            Box::pin(async move {
+              let posts = uplete::new(
+                post_actions::table
+                  .filter(post_actions::person_id.eq_any(voter_ids.clone()))
+                  .filter(
+                    post_actions::post_id.eq_any(
+                      post::table
+                        .filter(post::creator_id.eq(person_id))
+                        .select(post::id),
+                    ),
+                  ),
+              )
+              .set_null(post_actions::like_score)
+              .set_null(post_actions::liked)
+              .get_result::<uplete::Count>(conn)
+              .await?;
+              let comments = uplete::new(
+                comment_actions::table
+                  .filter(comment_actions::person_id.eq_any(voter_ids))
+                  .filter(
+                    comment_actions::comment_id.eq_any(
+                      comment::table
+                        .filter(comment::creator_id.eq(person_id))
+                        .select(comment::id),
+                    ),
+                  ),
+              )
+              .set_null(comment_actions::like_score)
+              .set_null(comment_actions::liked)
+              .get_result::<uplete::Count>(conn)
+              .await?;
+              Ok(posts.updated + posts.deleted + comments.updated + comments.deleted)
+            }) as _
+          })
+          .await
+      }
+      (VoteAnomalyKind::InstanceVoteBurst, _, Some(post_id)) => {
+        let count = uplete::new(
+          post_actions::table
+            .filter(post_actions::person_id.eq_any(voter_ids))
+            .filter(post_actions::post_id.eq(post_id)),
+        )
+        .set_null(post_actions::like_score)
+        .set_null(post_actions::liked)
+        .get_result::<uplete::Count>(conn)
+        .await?;
+        Ok(count.updated + count.deleted)
+      }
+      _ => Ok(0),
+    }
+  }
+}
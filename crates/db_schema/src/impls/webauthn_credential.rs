@@ -0,0 +1,170 @@
+use crate::{
+  newtypes::{LocalUserId, WebauthnCredentialId},
+  schema::{webauthn_challenge, webauthn_credential},
+  source::webauthn_credential::{
+    WebauthnChallenge,
+    WebauthnChallengeForm,
+    WebauthnCredential,
+    WebauthnCredentialInsertForm,
+  },
+  utils::{get_conn, naive_now, DbPool},
+};
+use chrono::{TimeDelta, Utc};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+/// How long a passkey registration or login may take.
+const CHALLENGE_TIMEOUT_MINUTES: i64 = 5;
+
+impl WebauthnCredential {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &WebauthnCredentialInsertForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(webauthn_credential::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn list_for_local_user(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    webauthn_credential::table
+      .filter(webauthn_credential::local_user_id.eq(local_user_id))
+      .order_by(webauthn_credential::id)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Stores the new signature counter after a login, so that cloned keys can be detected.
+  pub async fn mark_as_used(
+    pool: &mut DbPool<'_>,
+    id: WebauthnCredentialId,
+    passkey: &str,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(webauthn_credential::table.find(id))
+      .set((
+        webauthn_credential::passkey.eq(passkey),
+        webauthn_credential::last_used.eq(naive_now()),
+      ))
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    local_user_id: LocalUserId,
+    id: WebauthnCredentialId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(
+      webauthn_credential::table
+        .find(id)
+        .filter(webauthn_credential::local_user_id.eq(local_user_id)),
+    )
+    .execute(conn)
+    .await
+  }
+}
+
+impl WebauthnChallenge {
+  pub async fn create(pool: &mut DbPool<'_>, form: &WebauthnChallengeForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(webauthn_challenge::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Removes the challenge, so that it can only be answered once. Returns None if it doesn't
+  /// exist or has expired.
+  pub async fn take(pool: &mut DbPool<'_>, uuid: Uuid) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(webauthn_challenge::table.find(uuid).filter(
+      webauthn_challenge::published.gt(Utc::now() - TimeDelta::minutes(CHALLENGE_TIMEOUT_MINUTES)),
+    ))
+    .get_result::<Self>(conn)
+    .await
+    .optional()
+  }
+
+  pub async fn delete_expired(pool: &mut DbPool<'_>) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(webauthn_challenge::table.filter(
+      webauthn_challenge::published.lt(Utc::now() - TimeDelta::minutes(CHALLENGE_TIMEOUT_MINUTES)),
+    ))
+    .execute(conn)
+    .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::*;
+  use crate::{
+    source::{
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_webauthn_challenge_is_taken_once() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "passkey_user");
+    let person = Person::create(pool, &person_form).await?;
+    let local_user_form = LocalUserInsertForm::test_form(person.id);
+    let local_user = LocalUser::create(pool, &local_user_form, vec![]).await?;
+
+    let form = WebauthnChallengeForm::new(local_user.id, "{}".to_string());
+    let challenge = WebauthnChallenge::create(pool, &form).await?;
+    assert_eq!(
+      Some(challenge.clone()),
+      WebauthnChallenge::take(pool, challenge.uuid).await?
+    );
+    assert_eq!(None, WebauthnChallenge::take(pool, challenge.uuid).await?);
+
+    let form = WebauthnCredentialInsertForm::new(
+      local_user.id,
+      "credential".to_string(),
+      "{}".to_string(),
+      "My key".to_string(),
+    );
+    let credential = WebauthnCredential::create(pool, &form).await?;
+    let used = WebauthnCredential::mark_as_used(pool, credential.id, "{\"counter\":1}").await?;
+    assert!(used.last_used.is_some());
+    assert_eq!(
+      vec![used],
+      WebauthnCredential::list_for_local_user(pool, local_user.id).await?
+    );
+    WebauthnCredential::delete(pool, local_user.id, credential.id).await?;
+    assert_eq!(
+      0,
+      WebauthnCredential::list_for_local_user(pool, local_user.id)
+        .await?
+        .len()
+    );
+
+    Person::delete(pool, person.id).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
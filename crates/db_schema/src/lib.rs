@@ -30,8 +30,9 @@ pub mod sensitive;
 pub mod schema;
 #[cfg(feature = "full")]
 pub mod aliases {
-  use crate::schema::{community_actions, person};
+  use crate::schema::{comment, community_actions, person};
   diesel::alias!(
+    comment as comment1: Comment1,
     community_actions as creator_community_actions: CreatorCommunityActions,
     person as person1: Person1,
     person as person2: Person2,
@@ -104,6 +105,8 @@ pub enum CommentSortType {
   New,
   Old,
   Controversial,
+  /// Comments to which the post creator replied come first, then the highest scoring ones.
+  QandA,
 }
 
 #[derive(
@@ -127,6 +130,10 @@ pub enum ListingType {
   Subscribed,
   /// Content that you can moderate (because you are a moderator of the community it is posted to)
   ModeratorView,
+  /// Content from communities you've subscribed to, mixed with some posts from other communities
+  /// for discovery. Comment listings don't mix in other communities, for them this is the same as
+  /// `Subscribed`.
+  Home,
 }
 
 #[derive(
@@ -211,12 +218,212 @@ pub enum ModlogActionType {
   ModAdd,
   ModBan,
   ModHideCommunity,
+  ModWarnPerson,
+  ModResolveBanAppeal,
   AdminPurgePerson,
   AdminPurgeCommunity,
   AdminPurgePost,
   AdminPurgeComment,
 }
 
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::CommunityBotScopeEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// The moderation actions which a bot can be allowed to perform in a community.
+pub enum CommunityBotScope {
+  RemovePost,
+  RemoveComment,
+  LockPost,
+  FeaturePost,
+}
+
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::CommunityRoleEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// The role of a person in a community, which determines what they are allowed to do there.
+pub enum CommunityRole {
+  /// The top moderator, who can change the permissions of all other roles.
+  Owner,
+  Moderator,
+  /// A member who was given extra permissions by the owner.
+  TrustedContributor,
+  Member,
+}
+
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::SpamActionEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// What happened to new content because of its spam score.
+pub enum SpamAction {
+  None,
+  /// Removed until an admin approves it.
+  Hold,
+  Remove,
+}
+
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::VoteAnomalyKindEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// The kind of suspicious voting pattern which was found.
+pub enum VoteAnomalyKind {
+  /// Several accounts which only ever upvoted content of the same person.
+  SingleTargetUpvotes,
+  /// Many votes on a post from accounts of one instance within a few minutes.
+  InstanceVoteBurst,
+}
+
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::FilterRuleActionEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// What happens to new or edited content which matches a filter rule.
+pub enum FilterRuleAction {
+  /// The content is refused with an error.
+  Reject,
+  /// New content is held back until an admin approves it, like suspected spam. Edits are
+  /// reported instead.
+  Flag,
+  /// The content is published, and reported in the name of the rule creator.
+  Report,
+  /// New content is removed in the name of the rule creator, without review. Edits are reported
+  /// instead.
+  Remove,
+  /// New posts wait for approval by a moderator, new comments are held back like with `Flag`.
+  /// Edits are reported instead.
+  RequireApproval,
+  /// New posts are locked. Comments and edits are reported instead.
+  Lock,
+}
+
+#[derive(
+  EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash,
+)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::InstanceTrustLevelEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// How much activities from a federated instance are trusted.
+pub enum InstanceTrustLevel {
+  #[default]
+  Normal,
+  /// Actors are read from the database without refetching them when outdated, so activities are
+  /// only verified by their signatures.
+  Trusted,
+}
+
+#[derive(
+  EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash,
+)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::CommunityRequestStatusEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// The state of a request for a new community.
+pub enum CommunityRequestStatus {
+  #[default]
+  Pending,
+  /// The community was created, with the requester as moderator.
+  Approved,
+  Denied,
+}
+
+#[derive(
+  EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash,
+)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::BanAppealStatusEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// The state of an appeal against a community ban.
+pub enum BanAppealStatus {
+  #[default]
+  Pending,
+  /// The ban was lifted.
+  Accepted,
+  Denied,
+}
+
+#[derive(
+  EnumString,
+  Display,
+  Debug,
+  Serialize,
+  Deserialize,
+  Clone,
+  Copy,
+  Default,
+  PartialEq,
+  Eq,
+  PartialOrd,
+  Ord,
+  Hash,
+)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::ReportSeverityEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// How urgently a report needs to be handled by moderators.
+pub enum ReportSeverity {
+  Low,
+  #[default]
+  Normal,
+  Urgent,
+}
+
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::ReportOutcomeEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// How a moderator handled a report.
+pub enum ReportOutcome {
+  /// The reported content was removed.
+  ResolvedRemoved,
+  /// The report was valid, but the content was left as it is.
+  ResolvedNoAction,
+  /// The report was not valid.
+  Dismissed,
+}
+
 #[derive(
   EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash,
 )]
@@ -273,6 +480,80 @@ pub enum FederationMode {
   Disable,
 }
 
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::FeatureFlagNameEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// Experimental subsystems which admins can roll out gradually with feature flags.
+pub enum FeatureFlagName {
+  /// Emoji reactions on posts and comments.
+  Reactions,
+  /// Polls attached to posts.
+  Polls,
+  /// The ActivityPub client-to-server API.
+  ClientToServer,
+}
+
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::DefaultUserSettingEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// User settings for which the admins can set defaults.
+pub enum DefaultUserSetting {
+  PostSortType,
+  CommentSortType,
+  ListingType,
+  ShowNsfw,
+  ShowBotAccounts,
+  SendNotificationsToEmail,
+  InterfaceLanguage,
+}
+
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::NewAccountRestrictionEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// Actions which new accounts can't take until they meet the thresholds of the site or community.
+pub enum NewAccountRestriction {
+  /// Creating any post.
+  Posts,
+  /// Creating posts with a link.
+  Links,
+  /// Sending private messages. Only applies to the site thresholds.
+  PrivateMessages,
+}
+
+#[derive(
+  EnumString, Display, Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq, Hash,
+)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::AllowPrivateMessagesEnum"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// Who can send private messages to a user.
+pub enum AllowPrivateMessages {
+  #[default]
+  Everyone,
+  /// Only persons which the user follows.
+  Followed,
+  Nobody,
+}
+
 /// Wrapper for assert_eq! macro. Checks that vec matches the given length, and prints the
 /// vec on failure.
 #[macro_export]
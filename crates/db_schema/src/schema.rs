@@ -1,45 +1,112 @@
 // @generated automatically by Diesel CLI.
 
 pub mod sql_types {
-    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
-    #[diesel(postgres_type(name = "actor_type_enum"))]
-    pub struct ActorTypeEnum;
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "allow_private_messages_enum"))]
+  pub struct AllowPrivateMessagesEnum;
 
-    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
-    #[diesel(postgres_type(name = "comment_sort_type_enum"))]
-    pub struct CommentSortTypeEnum;
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "actor_type_enum"))]
+  pub struct ActorTypeEnum;
 
-    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
-    #[diesel(postgres_type(name = "community_follower_state"))]
-    pub struct CommunityFollowerState;
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "ban_appeal_status_enum"))]
+  pub struct BanAppealStatusEnum;
 
-    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
-    #[diesel(postgres_type(name = "community_visibility"))]
-    pub struct CommunityVisibility;
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "comment_sort_type_enum"))]
+  pub struct CommentSortTypeEnum;
 
-    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
-    #[diesel(postgres_type(name = "federation_mode_enum"))]
-    pub struct FederationModeEnum;
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "community_bot_scope_enum"))]
+  pub struct CommunityBotScopeEnum;
 
-    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
-    #[diesel(postgres_type(name = "listing_type_enum"))]
-    pub struct ListingTypeEnum;
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "community_follower_state"))]
+  pub struct CommunityFollowerState;
 
-    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
-    #[diesel(postgres_type(name = "ltree"))]
-    pub struct Ltree;
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "community_request_status_enum"))]
+  pub struct CommunityRequestStatusEnum;
 
-    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
-    #[diesel(postgres_type(name = "post_listing_mode_enum"))]
-    pub struct PostListingModeEnum;
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "community_role_enum"))]
+  pub struct CommunityRoleEnum;
 
-    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
-    #[diesel(postgres_type(name = "post_sort_type_enum"))]
-    pub struct PostSortTypeEnum;
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "community_visibility"))]
+  pub struct CommunityVisibility;
 
-    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
-    #[diesel(postgres_type(name = "registration_mode_enum"))]
-    pub struct RegistrationModeEnum;
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "default_user_setting_enum"))]
+  pub struct DefaultUserSettingEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "feature_flag_name_enum"))]
+  pub struct FeatureFlagNameEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "federation_mode_enum"))]
+  pub struct FederationModeEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "filter_rule_action_enum"))]
+  pub struct FilterRuleActionEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "instance_trust_level_enum"))]
+  pub struct InstanceTrustLevelEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "listing_type_enum"))]
+  pub struct ListingTypeEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "ltree"))]
+  pub struct Ltree;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "new_account_restriction_enum"))]
+  pub struct NewAccountRestrictionEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "post_listing_mode_enum"))]
+  pub struct PostListingModeEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "post_sort_type_enum"))]
+  pub struct PostSortTypeEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "registration_mode_enum"))]
+  pub struct RegistrationModeEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "report_outcome_enum"))]
+  pub struct ReportOutcomeEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "report_severity_enum"))]
+  pub struct ReportSeverityEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "spam_action_enum"))]
+  pub struct SpamActionEnum;
+
+  #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+  #[diesel(postgres_type(name = "vote_anomaly_kind_enum"))]
+  pub struct VoteAnomalyKindEnum;
+}
+
+diesel::table! {
+    admin_freeze_account (id) {
+        id -> Int4,
+        admin_person_id -> Int4,
+        other_person_id -> Int4,
+        reason -> Nullable<Text>,
+        frozen -> Bool,
+        when_ -> Timestamptz,
+    }
 }
 
 diesel::table! {
@@ -80,6 +147,74 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    admin_shadow_ban_person (id) {
+        id -> Int4,
+        admin_person_id -> Int4,
+        other_person_id -> Int4,
+        reason -> Nullable<Text>,
+        shadow_banned -> Bool,
+        when_ -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::BanAppealStatusEnum;
+
+    ban_appeal (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        community_id -> Int4,
+        reason -> Text,
+        status -> BanAppealStatusEnum,
+        deny_reason -> Nullable<Text>,
+        resolver_id -> Nullable<Int4>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    ban_list (id) {
+        id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        creator_id -> Int4,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    ban_list_community (ban_list_id, community_id) {
+        ban_list_id -> Int4,
+        community_id -> Int4,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    ban_list_entry (ban_list_id, person_id) {
+        ban_list_id -> Int4,
+        person_id -> Int4,
+        creator_id -> Int4,
+        reason -> Nullable<Text>,
+        expires -> Nullable<Timestamptz>,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    ban_list_exemption (ban_list_id, community_id, person_id) {
+        ban_list_id -> Int4,
+        community_id -> Int4,
+        person_id -> Int4,
+        published -> Timestamptz,
+    }
+}
+
 diesel::table! {
     captcha_answer (uuid) {
         uuid -> Uuid,
@@ -88,6 +223,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    client_restriction (client) {
+        client -> Text,
+        requests_per_minute -> Nullable<Int4>,
+        reason -> Nullable<Text>,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    client_usage (client, day) {
+        client -> Text,
+        day -> Timestamptz,
+        request_count -> Int8,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use diesel_ltree::sql_types::Ltree;
@@ -107,6 +259,8 @@ diesel::table! {
         path -> Ltree,
         distinguished -> Bool,
         language_id -> Int4,
+        archived -> Bool,
+        distinguished_pinned -> Bool,
     }
 }
 
@@ -117,6 +271,7 @@ diesel::table! {
         like_score -> Nullable<Int2>,
         liked -> Nullable<Timestamptz>,
         saved -> Nullable<Timestamptz>,
+        saved_note -> Nullable<Text>,
     }
 }
 
@@ -133,6 +288,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    comment_archive (comment_id) {
+        comment_id -> Int4,
+        content -> Text,
+    }
+}
+
 diesel::table! {
     comment_reply (id) {
         id -> Int4,
@@ -144,6 +306,9 @@ diesel::table! {
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::{ReportOutcomeEnum, ReportSeverityEnum};
+
     comment_report (id) {
         id -> Int4,
         creator_id -> Int4,
@@ -154,12 +319,17 @@ diesel::table! {
         resolver_id -> Nullable<Int4>,
         published -> Timestamptz,
         updated -> Nullable<Timestamptz>,
+        severity -> ReportSeverityEnum,
+        category_id -> Nullable<Int4>,
+        outcome -> Nullable<ReportOutcomeEnum>,
+        assigned_to -> Nullable<Int4>,
     }
 }
 
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::CommunityVisibility;
+    use super::sql_types::NewAccountRestrictionEnum;
 
     community (id) {
         id -> Int4,
@@ -195,12 +365,25 @@ diesel::table! {
         visibility -> CommunityVisibility,
         #[max_length = 150]
         description -> Nullable<Varchar>,
+        comment_export_public -> Bool,
+        soft_launched -> Bool,
+        image_text_search -> Bool,
+        posting_min_reputation -> Nullable<Int8>,
+        welcome_message -> Nullable<Text>,
+        posts_require_approval -> Bool,
+        warning_ban_strikes -> Nullable<Int4>,
+        warning_ban_days -> Int4,
+        new_account_min_age_days -> Nullable<Int4>,
+        new_account_min_comments -> Nullable<Int4>,
+        new_account_require_verified_email -> Bool,
+        new_account_restrictions -> Array<NewAccountRestrictionEnum>,
     }
 }
 
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::CommunityFollowerState;
+    use super::sql_types::CommunityRoleEnum;
 
     community_actions (person_id, community_id) {
         community_id -> Int4,
@@ -212,6 +395,11 @@ diesel::table! {
         became_moderator -> Nullable<Timestamptz>,
         received_ban -> Nullable<Timestamptz>,
         ban_expires -> Nullable<Timestamptz>,
+        suppressed_recommendation -> Nullable<Timestamptz>,
+        ban_list_id -> Nullable<Int4>,
+        role -> Nullable<CommunityRoleEnum>,
+        ban_reason -> Nullable<Text>,
+        ban_appeal -> Nullable<Text>,
     }
 }
 
@@ -231,6 +419,61 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CommunityBotScopeEnum;
+
+    community_bot_action (id) {
+        id -> Int4,
+        delegation_id -> Int4,
+        scope -> CommunityBotScopeEnum,
+        post_id -> Nullable<Int4>,
+        comment_id -> Nullable<Int4>,
+        enabled -> Bool,
+        reason -> Nullable<Text>,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CommunityBotScopeEnum;
+
+    community_bot_delegation (id) {
+        id -> Int4,
+        community_id -> Int4,
+        bot_person_id -> Int4,
+        creator_id -> Int4,
+        revoked -> Bool,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+        scopes -> Array<CommunityBotScopeEnum>,
+        token -> Text,
+    }
+}
+
+diesel::table! {
+    community_description_translation (community_id, language_id) {
+        community_id -> Int4,
+        language_id -> Int4,
+        sidebar -> Nullable<Text>,
+        #[max_length = 150]
+        description -> Nullable<Varchar>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    community_domain_block (community_id, domain) {
+        community_id -> Int4,
+        domain -> Text,
+        creator_id -> Int4,
+        reason -> Nullable<Text>,
+        published -> Timestamptz,
+    }
+}
+
 diesel::table! {
     community_language (community_id, language_id) {
         community_id -> Int4,
@@ -238,6 +481,107 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    community_mirror (id) {
+        id -> Int4,
+        community_id -> Int4,
+        partner_community_id -> Int4,
+        creator_id -> Int4,
+        mirror_bans -> Bool,
+        mirror_removals -> Bool,
+        enabled -> Bool,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    community_mirror_action (id) {
+        id -> Int4,
+        mirror_id -> Int4,
+        mod_person_id -> Int4,
+        other_person_id -> Nullable<Int4>,
+        post_id -> Nullable<Int4>,
+        reason -> Nullable<Text>,
+        when_ -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    community_name_alias (name) {
+        name -> Text,
+        community_id -> Nullable<Int4>,
+        creator_id -> Int4,
+        reason -> Nullable<Text>,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    community_post_tag (id) {
+        id -> Int4,
+        community_id -> Int4,
+        name -> Text,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CommunityRequestStatusEnum;
+
+    community_request (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 255]
+        title -> Varchar,
+        description -> Nullable<Text>,
+        status -> CommunityRequestStatusEnum,
+        deny_reason -> Nullable<Text>,
+        admin_id -> Nullable<Int4>,
+        community_id -> Nullable<Int4>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    community_request_comment (id) {
+        id -> Int4,
+        community_request_id -> Int4,
+        creator_id -> Int4,
+        content -> Text,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    community_request_vote (person_id, community_request_id) {
+        person_id -> Int4,
+        community_request_id -> Int4,
+        score -> Int2,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CommunityRoleEnum;
+
+    community_role_permission (community_id, role) {
+        community_id -> Int4,
+        role -> CommunityRoleEnum,
+        can_feature_posts -> Bool,
+        can_remove_comments -> Bool,
+        can_edit_sidebar -> Bool,
+        updated -> Nullable<Timestamptz>,
+        can_view_votes -> Bool,
+    }
+}
+
 diesel::table! {
     custom_emoji (id) {
         id -> Int4,
@@ -259,6 +603,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    custom_feed (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        name -> Text,
+        description -> Nullable<Text>,
+        public -> Bool,
+        slug -> Nullable<Text>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    custom_feed_community (custom_feed_id, community_id) {
+        custom_feed_id -> Int4,
+        community_id -> Int4,
+        published -> Timestamptz,
+    }
+}
+
 diesel::table! {
     email_verification (id) {
         id -> Int4,
@@ -269,6 +634,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::FeatureFlagNameEnum;
+
+    feature_flag (feature) {
+        feature -> FeatureFlagNameEnum,
+        enabled -> Bool,
+        rollout_percentage -> Int2,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     federation_allowlist (instance_id) {
         instance_id -> Int4,
@@ -282,6 +660,7 @@ diesel::table! {
         instance_id -> Int4,
         published -> Timestamptz,
         updated -> Nullable<Timestamptz>,
+        reason -> Nullable<Text>,
     }
 }
 
@@ -295,6 +674,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::FilterRuleActionEnum;
+
+    filter_rule (id) {
+        id -> Int4,
+        community_id -> Nullable<Int4>,
+        pattern -> Text,
+        is_regex -> Bool,
+        action -> FilterRuleActionEnum,
+        creator_id -> Int4,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+        link_domain -> Nullable<Text>,
+        max_account_age_days -> Nullable<Int4>,
+        max_reputation -> Nullable<Int8>,
+        condition -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     image_details (link) {
         link -> Text,
@@ -305,6 +704,18 @@ diesel::table! {
 }
 
 diesel::table! {
+    image_text (pictrs_alias) {
+        pictrs_alias -> Text,
+        link -> Text,
+        text -> Text,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::InstanceTrustLevelEnum;
+
     instance (id) {
         id -> Int4,
         #[max_length = 255]
@@ -315,6 +726,7 @@ diesel::table! {
         software -> Nullable<Varchar>,
         #[max_length = 255]
         version -> Nullable<Varchar>,
+        trust_level -> InstanceTrustLevelEnum,
     }
 }
 
@@ -326,6 +738,24 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    interest_category (id) {
+        id -> Int4,
+        #[max_length = 100]
+        name -> Varchar,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    interest_category_community (category_id, community_id) {
+        category_id -> Int4,
+        community_id -> Int4,
+        published -> Timestamptz,
+    }
+}
+
 diesel::table! {
     language (id) {
         id -> Int4,
@@ -352,6 +782,7 @@ diesel::table! {
     use super::sql_types::PostSortTypeEnum;
     use super::sql_types::CommentSortTypeEnum;
     use super::sql_types::FederationModeEnum;
+    use super::sql_types::NewAccountRestrictionEnum;
 
     local_site (id) {
         id -> Int4,
@@ -359,7 +790,6 @@ diesel::table! {
         site_setup -> Bool,
         community_creation_admin_only -> Bool,
         require_email_verification -> Bool,
-        application_question -> Nullable<Text>,
         private_instance -> Bool,
         default_theme -> Text,
         default_post_listing_type -> ListingTypeEnum,
@@ -385,6 +815,38 @@ diesel::table! {
         post_downvotes -> FederationModeEnum,
         comment_upvotes -> FederationModeEnum,
         comment_downvotes -> FederationModeEnum,
+        instance_blocks_public -> Bool,
+        slur_filter_normalize -> Bool,
+        spam_link_density_weight -> Int4,
+        spam_repetition_weight -> Int4,
+        spam_account_age_weight -> Int4,
+        spam_hold_threshold -> Nullable<Int4>,
+        spam_remove_threshold -> Nullable<Int4>,
+        maintenance_mode -> Bool,
+        community_soft_launch_posts -> Nullable<Int4>,
+        default_show_nsfw -> Bool,
+        default_show_bot_accounts -> Bool,
+        default_send_notifications_to_email -> Bool,
+        #[max_length = 20]
+        default_interface_language -> Varchar,
+        reputation_half_life_days -> Nullable<Int4>,
+        spam_low_reputation_weight -> Int4,
+        ldap_url -> Nullable<Text>,
+        ldap_user_dn_template -> Nullable<Text>,
+        block_disposable_email_domains -> Bool,
+        new_account_min_age_days -> Nullable<Int4>,
+        new_account_min_comments -> Nullable<Int4>,
+        new_account_require_verified_email -> Bool,
+        new_account_restrictions -> Array<NewAccountRestrictionEnum>,
+    }
+}
+
+diesel::table! {
+    local_site_email_domain (id) {
+        id -> Int4,
+        domain -> Text,
+        allowed -> Bool,
+        published -> Timestamptz,
     }
 }
 
@@ -407,6 +869,10 @@ diesel::table! {
         updated -> Nullable<Timestamptz>,
         import_user_settings -> Int4,
         import_user_settings_per_second -> Int4,
+        register_subnet -> Int4,
+        register_subnet_per_second -> Int4,
+        register_asn -> Int4,
+        register_asn_per_second -> Int4,
     }
 }
 
@@ -425,6 +891,8 @@ diesel::table! {
     use super::sql_types::ListingTypeEnum;
     use super::sql_types::PostListingModeEnum;
     use super::sql_types::CommentSortTypeEnum;
+    use super::sql_types::DefaultUserSettingEnum;
+    use super::sql_types::AllowPrivateMessagesEnum;
 
     local_user (id) {
         id -> Int4,
@@ -456,6 +924,45 @@ diesel::table! {
         collapse_bot_comments -> Bool,
         default_comment_sort_type -> CommentSortTypeEnum,
         auto_mark_fetched_posts_as_read -> Bool,
+        home_discovery_percent -> Int2,
+        frozen -> Bool,
+        image_text_recognition -> Bool,
+        customized_settings -> Array<DefaultUserSettingEnum>,
+        quiet_hours_enabled -> Bool,
+        quiet_hours_start -> Time,
+        quiet_hours_end -> Time,
+        quiet_hours_timezone -> Text,
+        mark_read_on_save -> Bool,
+        private_message_requests -> Bool,
+        allow_private_messages -> AllowPrivateMessagesEnum,
+        downrank_bot_accounts -> Bool,
+        ldap_login -> Bool,
+        ldap_admin_login -> Bool,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::PostSortTypeEnum;
+    use super::sql_types::PostListingModeEnum;
+
+    local_user_community_settings (local_user_id, community_id) {
+        local_user_id -> Int4,
+        community_id -> Int4,
+        post_sort_type -> Nullable<PostSortTypeEnum>,
+        post_listing_mode -> Nullable<PostListingModeEnum>,
+        updated -> Timestamptz,
+        show_bot_accounts -> Nullable<Bool>,
+    }
+}
+
+diesel::table! {
+    local_user_keyword_block (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        keyword -> Text,
+        is_regex -> Bool,
+        published -> Timestamptz,
     }
 }
 
@@ -466,6 +973,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    local_user_link (local_user_id, linked_local_user_id) {
+        local_user_id -> Int4,
+        linked_local_user_id -> Int4,
+        published -> Timestamptz,
+    }
+}
+
 diesel::table! {
     local_user_vote_display_mode (local_user_id) {
         local_user_id -> Int4,
@@ -483,6 +998,8 @@ diesel::table! {
         published -> Timestamptz,
         ip -> Nullable<Text>,
         user_agent -> Nullable<Text>,
+        linked_from_user_id -> Nullable<Int4>,
+        id -> Int4,
     }
 }
 
@@ -540,6 +1057,7 @@ diesel::table! {
         featured -> Bool,
         when_ -> Timestamptz,
         is_featured_community -> Bool,
+        automod -> Bool,
     }
 }
 
@@ -561,6 +1079,7 @@ diesel::table! {
         post_id -> Int4,
         locked -> Bool,
         when_ -> Timestamptz,
+        automod -> Bool,
     }
 }
 
@@ -572,6 +1091,7 @@ diesel::table! {
         reason -> Nullable<Text>,
         removed -> Bool,
         when_ -> Timestamptz,
+        automod -> Bool,
     }
 }
 
@@ -594,6 +1114,19 @@ diesel::table! {
         reason -> Nullable<Text>,
         removed -> Bool,
         when_ -> Timestamptz,
+        automod -> Bool,
+    }
+}
+
+diesel::table! {
+    mod_resolve_ban_appeal (id) {
+        id -> Int4,
+        mod_person_id -> Int4,
+        other_person_id -> Int4,
+        community_id -> Int4,
+        accepted -> Bool,
+        reason -> Nullable<Text>,
+        when_ -> Timestamptz,
     }
 }
 
@@ -607,6 +1140,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mod_warn_person (id) {
+        id -> Int4,
+        mod_person_id -> Int4,
+        other_person_id -> Int4,
+        community_id -> Nullable<Int4>,
+        reason -> Text,
+        strikes -> Int4,
+        expires -> Nullable<Timestamptz>,
+        read -> Bool,
+        when_ -> Timestamptz,
+    }
+}
+
 diesel::table! {
     oauth_account (oauth_provider_id, local_user_id) {
         local_user_id -> Int4,
@@ -672,6 +1219,7 @@ diesel::table! {
         bot_account -> Bool,
         ban_expires -> Nullable<Timestamptz>,
         instance_id -> Int4,
+        shadow_banned -> Bool,
     }
 }
 
@@ -692,6 +1240,7 @@ diesel::table! {
         post_score -> Int8,
         comment_count -> Int8,
         comment_score -> Int8,
+        reputation -> Int8,
     }
 }
 
@@ -712,6 +1261,85 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    person_mod_note (id) {
+        id -> Int4,
+        person_id -> Int4,
+        community_id -> Nullable<Int4>,
+        creator_id -> Int4,
+        content -> Text,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    person_pin (id) {
+        id -> Int4,
+        person_id -> Int4,
+        post_id -> Nullable<Int4>,
+        comment_id -> Nullable<Int4>,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    pm_conversation (id) {
+        id -> Int4,
+        person_a_id -> Nullable<Int4>,
+        person_b_id -> Nullable<Int4>,
+        published -> Timestamptz,
+        last_message_published -> Timestamptz,
+        is_group -> Bool,
+        name -> Nullable<Text>,
+        creator_id -> Nullable<Int4>,
+        #[max_length = 255]
+        ap_id -> Nullable<Varchar>,
+        local -> Bool,
+        request_recipient_id -> Nullable<Int4>,
+        matrix_room_id -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    pm_conversation_participant (conversation_id, person_id) {
+        conversation_id -> Int4,
+        person_id -> Int4,
+        published -> Timestamptz,
+        last_read -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    poll (id) {
+        id -> Int4,
+        post_id -> Int4,
+        multiple_choice -> Bool,
+        end_time -> Nullable<Timestamptz>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    poll_option (id) {
+        id -> Int4,
+        poll_id -> Int4,
+        name -> Text,
+        position -> Int4,
+        vote_count -> Int4,
+    }
+}
+
+diesel::table! {
+    poll_vote (poll_option_id, person_id) {
+        poll_id -> Int4,
+        poll_option_id -> Int4,
+        person_id -> Int4,
+        published -> Timestamptz,
+    }
+}
+
 diesel::table! {
     post (id) {
         id -> Int4,
@@ -741,6 +1369,9 @@ diesel::table! {
         url_content_type -> Nullable<Text>,
         alt_text -> Nullable<Text>,
         scheduled_publish_time -> Nullable<Timestamptz>,
+        archived -> Bool,
+        pending -> Bool,
+        lock_expires -> Nullable<Timestamptz>,
     }
 }
 
@@ -755,6 +1386,7 @@ diesel::table! {
         liked -> Nullable<Timestamptz>,
         like_score -> Nullable<Int2>,
         hidden -> Nullable<Timestamptz>,
+        saved_note -> Nullable<Text>,
     }
 }
 
@@ -781,6 +1413,27 @@ diesel::table! {
 }
 
 diesel::table! {
+    post_archive (post_id) {
+        post_id -> Int4,
+        body -> Text,
+    }
+}
+
+diesel::table! {
+    post_media (id) {
+        id -> Int4,
+        post_id -> Int4,
+        position -> Int4,
+        url -> Text,
+        alt_text -> Nullable<Text>,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ReportOutcomeEnum;
+
     post_report (id) {
         id -> Int4,
         creator_id -> Int4,
@@ -794,6 +1447,15 @@ diesel::table! {
         resolver_id -> Nullable<Int4>,
         published -> Timestamptz,
         updated -> Nullable<Timestamptz>,
+        outcome -> Nullable<ReportOutcomeEnum>,
+        assigned_to -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    post_tag (post_id, tag_id) {
+        post_id -> Int4,
+        tag_id -> Int4,
     }
 }
 
@@ -801,7 +1463,7 @@ diesel::table! {
     private_message (id) {
         id -> Int4,
         creator_id -> Int4,
-        recipient_id -> Int4,
+        recipient_id -> Nullable<Int4>,
         content -> Text,
         deleted -> Bool,
         read -> Bool,
@@ -810,10 +1472,14 @@ diesel::table! {
         #[max_length = 255]
         ap_id -> Varchar,
         local -> Bool,
+        conversation_id -> Int4,
     }
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ReportOutcomeEnum;
+
     private_message_report (id) {
         id -> Int4,
         creator_id -> Int4,
@@ -824,6 +1490,17 @@ diesel::table! {
         resolver_id -> Nullable<Int4>,
         published -> Timestamptz,
         updated -> Nullable<Timestamptz>,
+        outcome -> Nullable<ReportOutcomeEnum>,
+        assigned_to -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    queued_activity (id) {
+        id -> Int4,
+        actor_id -> Text,
+        data -> Jsonb,
+        published -> Timestamptz,
     }
 }
 
@@ -834,17 +1511,48 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    registration_answer (id) {
+        id -> Int4,
+        registration_application_id -> Int4,
+        question_id -> Nullable<Int4>,
+        question -> Text,
+        answer -> Text,
+    }
+}
+
 diesel::table! {
     registration_application (id) {
         id -> Int4,
         local_user_id -> Int4,
-        answer -> Text,
         admin_id -> Nullable<Int4>,
         deny_reason -> Nullable<Text>,
         published -> Timestamptz,
     }
 }
 
+diesel::table! {
+    registration_question (id) {
+        id -> Int4,
+        question -> Text,
+        required -> Bool,
+        position -> Int4,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    registration_source (id) {
+        id -> Int4,
+        person_id -> Int4,
+        ip_subnet -> Text,
+        asn -> Nullable<Int4>,
+        asn_organization -> Nullable<Text>,
+        published -> Timestamptz,
+    }
+}
+
 diesel::table! {
     remote_image (link) {
         link -> Text,
@@ -852,6 +1560,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    report_note (id) {
+        id -> Int4,
+        comment_report_id -> Int4,
+        creator_id -> Int4,
+        content -> Text,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    report_reason_category (id) {
+        id -> Int4,
+        #[max_length = 100]
+        name -> Varchar,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     secret (id) {
         id -> Int4,
@@ -915,6 +1644,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    site_description_translation (site_id, language_id) {
+        site_id -> Int4,
+        language_id -> Int4,
+        sidebar -> Nullable<Text>,
+        #[max_length = 150]
+        description -> Nullable<Varchar>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     site_language (site_id, language_id) {
         site_id -> Int4,
@@ -922,6 +1663,24 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::SpamActionEnum;
+
+    spam_score (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        post_id -> Int4,
+        comment_id -> Nullable<Int4>,
+        score -> Int4,
+        action -> SpamActionEnum,
+        approved -> Nullable<Bool>,
+        reviewer_id -> Nullable<Int4>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     tagline (id) {
         id -> Int4,
@@ -931,37 +1690,136 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    transparency_report (id) {
+        id -> Int4,
+        community_id -> Nullable<Int4>,
+        period_start -> Timestamptz,
+        period_end -> Timestamptz,
+        posts_removed -> Int8,
+        comments_removed -> Int8,
+        communities_removed -> Int8,
+        site_bans -> Int8,
+        community_bans -> Int8,
+        post_reports -> Int8,
+        comment_reports -> Int8,
+        private_message_reports -> Int8,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::VoteAnomalyKindEnum;
+
+    vote_anomaly (id) {
+        id -> Int4,
+        kind -> VoteAnomalyKindEnum,
+        person_id -> Nullable<Int4>,
+        instance_id -> Nullable<Int4>,
+        post_id -> Nullable<Int4>,
+        voter_ids -> Array<Int4>,
+        votes_removed -> Nullable<Bool>,
+        reviewer_id -> Nullable<Int4>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    webauthn_challenge (uuid) {
+        uuid -> Uuid,
+        local_user_id -> Int4,
+        state -> Text,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    webauthn_credential (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        credential_id -> Text,
+        passkey -> Text,
+        name -> Text,
+        published -> Timestamptz,
+        last_used -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::joinable!(admin_purge_comment -> person (admin_person_id));
 diesel::joinable!(admin_purge_comment -> post (post_id));
 diesel::joinable!(admin_purge_community -> person (admin_person_id));
 diesel::joinable!(admin_purge_person -> person (admin_person_id));
 diesel::joinable!(admin_purge_post -> community (community_id));
 diesel::joinable!(admin_purge_post -> person (admin_person_id));
+diesel::joinable!(ban_appeal -> community (community_id));
+diesel::joinable!(ban_list -> person (creator_id));
+diesel::joinable!(ban_list_community -> ban_list (ban_list_id));
+diesel::joinable!(ban_list_community -> community (community_id));
+diesel::joinable!(ban_list_entry -> ban_list (ban_list_id));
+diesel::joinable!(ban_list_exemption -> ban_list (ban_list_id));
+diesel::joinable!(ban_list_exemption -> community (community_id));
+diesel::joinable!(ban_list_exemption -> person (person_id));
 diesel::joinable!(comment -> language (language_id));
 diesel::joinable!(comment -> person (creator_id));
 diesel::joinable!(comment -> post (post_id));
 diesel::joinable!(comment_actions -> comment (comment_id));
 diesel::joinable!(comment_actions -> person (person_id));
 diesel::joinable!(comment_aggregates -> comment (comment_id));
+diesel::joinable!(comment_archive -> comment (comment_id));
 diesel::joinable!(comment_reply -> comment (comment_id));
 diesel::joinable!(comment_reply -> person (recipient_id));
 diesel::joinable!(comment_report -> comment (comment_id));
+diesel::joinable!(comment_report -> report_reason_category (category_id));
 diesel::joinable!(community -> instance (instance_id));
+diesel::joinable!(community_actions -> ban_list (ban_list_id));
 diesel::joinable!(community_actions -> community (community_id));
 diesel::joinable!(community_aggregates -> community (community_id));
+diesel::joinable!(community_bot_action -> comment (comment_id));
+diesel::joinable!(community_bot_action -> community_bot_delegation (delegation_id));
+diesel::joinable!(community_bot_action -> post (post_id));
+diesel::joinable!(community_bot_delegation -> community (community_id));
+diesel::joinable!(community_description_translation -> community (community_id));
+diesel::joinable!(community_description_translation -> language (language_id));
+diesel::joinable!(community_domain_block -> community (community_id));
+diesel::joinable!(community_domain_block -> person (creator_id));
 diesel::joinable!(community_language -> community (community_id));
 diesel::joinable!(community_language -> language (language_id));
+diesel::joinable!(community_mirror -> person (creator_id));
+diesel::joinable!(community_mirror_action -> community_mirror (mirror_id));
+diesel::joinable!(community_mirror_action -> post (post_id));
+diesel::joinable!(community_name_alias -> community (community_id));
+diesel::joinable!(community_name_alias -> person (creator_id));
+diesel::joinable!(community_post_tag -> community (community_id));
+diesel::joinable!(community_request -> community (community_id));
+diesel::joinable!(community_request_comment -> community_request (community_request_id));
+diesel::joinable!(community_request_comment -> person (creator_id));
+diesel::joinable!(community_request_vote -> community_request (community_request_id));
+diesel::joinable!(community_request_vote -> person (person_id));
+diesel::joinable!(community_role_permission -> community (community_id));
 diesel::joinable!(custom_emoji_keyword -> custom_emoji (custom_emoji_id));
+diesel::joinable!(custom_feed -> person (creator_id));
+diesel::joinable!(custom_feed_community -> community (community_id));
+diesel::joinable!(custom_feed_community -> custom_feed (custom_feed_id));
 diesel::joinable!(email_verification -> local_user (local_user_id));
 diesel::joinable!(federation_allowlist -> instance (instance_id));
 diesel::joinable!(federation_blocklist -> instance (instance_id));
 diesel::joinable!(federation_queue_state -> instance (instance_id));
+diesel::joinable!(filter_rule -> community (community_id));
+diesel::joinable!(filter_rule -> person (creator_id));
+diesel::joinable!(image_text -> local_image (pictrs_alias));
 diesel::joinable!(instance_actions -> instance (instance_id));
 diesel::joinable!(instance_actions -> person (person_id));
+diesel::joinable!(interest_category_community -> community (community_id));
+diesel::joinable!(interest_category_community -> interest_category (category_id));
 diesel::joinable!(local_image -> local_user (local_user_id));
 diesel::joinable!(local_site -> site (site_id));
 diesel::joinable!(local_site_rate_limit -> local_site (local_site_id));
 diesel::joinable!(local_user -> person (person_id));
+diesel::joinable!(local_user_community_settings -> community (community_id));
+diesel::joinable!(local_user_community_settings -> local_user (local_user_id));
+diesel::joinable!(local_user_keyword_block -> local_user (local_user_id));
 diesel::joinable!(local_user_language -> language (language_id));
 diesel::joinable!(local_user_language -> local_user (local_user_id));
 diesel::joinable!(local_user_vote_display_mode -> local_user (local_user_id));
@@ -980,7 +1838,9 @@ diesel::joinable!(mod_remove_community -> community (community_id));
 diesel::joinable!(mod_remove_community -> person (mod_person_id));
 diesel::joinable!(mod_remove_post -> person (mod_person_id));
 diesel::joinable!(mod_remove_post -> post (post_id));
+diesel::joinable!(mod_resolve_ban_appeal -> community (community_id));
 diesel::joinable!(mod_transfer_community -> community (community_id));
+diesel::joinable!(mod_warn_person -> community (community_id));
 diesel::joinable!(oauth_account -> local_user (local_user_id));
 diesel::joinable!(oauth_account -> oauth_provider (oauth_provider_id));
 diesel::joinable!(password_reset_request -> local_user (local_user_id));
@@ -989,6 +1849,17 @@ diesel::joinable!(person_aggregates -> person (person_id));
 diesel::joinable!(person_ban -> person (person_id));
 diesel::joinable!(person_mention -> comment (comment_id));
 diesel::joinable!(person_mention -> person (recipient_id));
+diesel::joinable!(person_mod_note -> community (community_id));
+diesel::joinable!(person_pin -> comment (comment_id));
+diesel::joinable!(person_pin -> person (person_id));
+diesel::joinable!(person_pin -> post (post_id));
+diesel::joinable!(pm_conversation_participant -> person (person_id));
+diesel::joinable!(pm_conversation_participant -> pm_conversation (conversation_id));
+diesel::joinable!(poll -> post (post_id));
+diesel::joinable!(poll_option -> poll (poll_id));
+diesel::joinable!(poll_vote -> person (person_id));
+diesel::joinable!(poll_vote -> poll (poll_id));
+diesel::joinable!(poll_vote -> poll_option (poll_option_id));
 diesel::joinable!(post -> community (community_id));
 diesel::joinable!(post -> language (language_id));
 diesel::joinable!(post -> person (creator_id));
@@ -998,80 +1869,156 @@ diesel::joinable!(post_aggregates -> community (community_id));
 diesel::joinable!(post_aggregates -> instance (instance_id));
 diesel::joinable!(post_aggregates -> person (creator_id));
 diesel::joinable!(post_aggregates -> post (post_id));
+diesel::joinable!(post_archive -> post (post_id));
+diesel::joinable!(post_media -> post (post_id));
 diesel::joinable!(post_report -> post (post_id));
+diesel::joinable!(post_tag -> community_post_tag (tag_id));
+diesel::joinable!(post_tag -> post (post_id));
+diesel::joinable!(private_message -> pm_conversation (conversation_id));
 diesel::joinable!(private_message_report -> private_message (private_message_id));
+diesel::joinable!(registration_answer -> registration_application (registration_application_id));
+diesel::joinable!(registration_answer -> registration_question (question_id));
 diesel::joinable!(registration_application -> local_user (local_user_id));
 diesel::joinable!(registration_application -> person (admin_id));
+diesel::joinable!(registration_source -> person (person_id));
+diesel::joinable!(report_note -> comment_report (comment_report_id));
+diesel::joinable!(report_note -> person (creator_id));
 diesel::joinable!(site -> instance (instance_id));
 diesel::joinable!(site_aggregates -> site (site_id));
+diesel::joinable!(site_description_translation -> language (language_id));
+diesel::joinable!(site_description_translation -> site (site_id));
 diesel::joinable!(site_language -> language (language_id));
 diesel::joinable!(site_language -> site (site_id));
+diesel::joinable!(spam_score -> comment (comment_id));
+diesel::joinable!(spam_score -> post (post_id));
+diesel::joinable!(transparency_report -> community (community_id));
+diesel::joinable!(vote_anomaly -> instance (instance_id));
+diesel::joinable!(vote_anomaly -> post (post_id));
+diesel::joinable!(webauthn_challenge -> local_user (local_user_id));
+diesel::joinable!(webauthn_credential -> local_user (local_user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
-    admin_purge_comment,
-    admin_purge_community,
-    admin_purge_person,
-    admin_purge_post,
-    captcha_answer,
-    comment,
-    comment_actions,
-    comment_aggregates,
-    comment_reply,
-    comment_report,
-    community,
-    community_actions,
-    community_aggregates,
-    community_language,
-    custom_emoji,
-    custom_emoji_keyword,
-    email_verification,
-    federation_allowlist,
-    federation_blocklist,
-    federation_queue_state,
-    image_details,
-    instance,
-    instance_actions,
-    language,
-    local_image,
-    local_site,
-    local_site_rate_limit,
-    local_site_url_blocklist,
-    local_user,
-    local_user_language,
-    local_user_vote_display_mode,
-    login_token,
-    mod_add,
-    mod_add_community,
-    mod_ban,
-    mod_ban_from_community,
-    mod_feature_post,
-    mod_hide_community,
-    mod_lock_post,
-    mod_remove_comment,
-    mod_remove_community,
-    mod_remove_post,
-    mod_transfer_community,
-    oauth_account,
-    oauth_provider,
-    password_reset_request,
-    person,
-    person_actions,
-    person_aggregates,
-    person_ban,
-    person_mention,
-    post,
-    post_actions,
-    post_aggregates,
-    post_report,
-    private_message,
-    private_message_report,
-    received_activity,
-    registration_application,
-    remote_image,
-    secret,
-    sent_activity,
-    site,
-    site_aggregates,
-    site_language,
-    tagline,
+  admin_freeze_account,
+  admin_purge_comment,
+  admin_purge_community,
+  admin_purge_person,
+  admin_purge_post,
+  admin_shadow_ban_person,
+  ban_appeal,
+  ban_list,
+  ban_list_community,
+  ban_list_entry,
+  ban_list_exemption,
+  captcha_answer,
+  client_restriction,
+  client_usage,
+  comment,
+  comment_actions,
+  comment_aggregates,
+  comment_archive,
+  comment_reply,
+  comment_report,
+  community,
+  community_actions,
+  community_aggregates,
+  community_bot_action,
+  community_bot_delegation,
+  community_description_translation,
+  community_domain_block,
+  community_language,
+  community_mirror,
+  community_mirror_action,
+  community_name_alias,
+  community_post_tag,
+  community_request,
+  community_request_comment,
+  community_request_vote,
+  community_role_permission,
+  custom_emoji,
+  custom_emoji_keyword,
+  custom_feed,
+  custom_feed_community,
+  email_verification,
+  feature_flag,
+  federation_allowlist,
+  federation_blocklist,
+  federation_queue_state,
+  filter_rule,
+  image_details,
+  image_text,
+  instance,
+  instance_actions,
+  interest_category,
+  interest_category_community,
+  language,
+  local_image,
+  local_site,
+  local_site_email_domain,
+  local_site_rate_limit,
+  local_site_url_blocklist,
+  local_user,
+  local_user_community_settings,
+  local_user_keyword_block,
+  local_user_language,
+  local_user_link,
+  local_user_vote_display_mode,
+  login_token,
+  mod_add,
+  mod_add_community,
+  mod_ban,
+  mod_ban_from_community,
+  mod_feature_post,
+  mod_hide_community,
+  mod_lock_post,
+  mod_remove_comment,
+  mod_remove_community,
+  mod_remove_post,
+  mod_resolve_ban_appeal,
+  mod_transfer_community,
+  mod_warn_person,
+  oauth_account,
+  oauth_provider,
+  password_reset_request,
+  person,
+  person_actions,
+  person_aggregates,
+  person_ban,
+  person_mention,
+  person_mod_note,
+  person_pin,
+  pm_conversation,
+  pm_conversation_participant,
+  poll,
+  poll_option,
+  poll_vote,
+  post,
+  post_actions,
+  post_aggregates,
+  post_archive,
+  post_media,
+  post_report,
+  post_tag,
+  private_message,
+  private_message_report,
+  queued_activity,
+  received_activity,
+  registration_answer,
+  registration_application,
+  registration_question,
+  registration_source,
+  remote_image,
+  report_note,
+  report_reason_category,
+  secret,
+  sent_activity,
+  site,
+  site_aggregates,
+  site_description_translation,
+  site_language,
+  spam_score,
+  tagline,
+  transparency_report,
+  vote_anomaly,
+  webauthn_challenge,
+  webauthn_credential,
 );
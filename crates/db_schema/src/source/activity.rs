@@ -1,6 +1,6 @@
 use crate::{
   newtypes::{ActivityId, CommunityId, DbUrl},
-  schema::{received_activity, sent_activity},
+  schema::{queued_activity, received_activity, sent_activity},
 };
 use chrono::{DateTime, Utc};
 use diesel::{sql_types::Nullable, Queryable};
@@ -98,3 +98,24 @@ pub struct ReceivedActivity {
   pub ap_id: DbUrl,
   pub published: DateTime<Utc>,
 }
+
+/// An incoming activity which was received while the site was in maintenance mode. Its signature
+/// was already verified, so it only needs to be processed once maintenance mode is turned off.
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = queued_activity))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct QueuedActivity {
+  pub id: i32,
+  /// The actor which signed the activity
+  pub actor_id: DbUrl,
+  pub data: Value,
+  pub published: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = queued_activity))]
+pub struct QueuedActivityForm {
+  pub actor_id: DbUrl,
+  pub data: Value,
+}
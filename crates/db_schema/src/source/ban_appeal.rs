@@ -0,0 +1,54 @@
+#[cfg(feature = "full")]
+use crate::schema::ban_appeal;
+use crate::{
+  newtypes::{BanAppealId, CommunityId, PersonId},
+  BanAppealStatus,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_appeal))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An appeal of a person against their ban from a community, which the moderators accept or deny.
+pub struct BanAppeal {
+  pub id: BanAppealId,
+  pub creator_id: PersonId,
+  pub community_id: CommunityId,
+  /// Why the ban should be lifted.
+  pub reason: String,
+  pub status: BanAppealStatus,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub deny_reason: Option<String>,
+  /// The moderator or admin who accepted or denied the appeal.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub resolver_id: Option<PersonId>,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_appeal))]
+pub struct BanAppealInsertForm {
+  pub creator_id: PersonId,
+  pub community_id: CommunityId,
+  pub reason: String,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_appeal))]
+pub struct BanAppealUpdateForm {
+  pub status: Option<BanAppealStatus>,
+  pub deny_reason: Option<Option<String>>,
+  pub resolver_id: Option<Option<PersonId>>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
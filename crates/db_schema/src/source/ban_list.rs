@@ -0,0 +1,124 @@
+use crate::newtypes::{BanListId, CommunityId, PersonId};
+#[cfg(feature = "full")]
+use crate::schema::{ban_list, ban_list_community, ban_list_entry, ban_list_exemption};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_list))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A ban list which is shared between communities. Bans on the list are applied to every
+/// subscribed community.
+pub struct BanList {
+  pub id: BanListId,
+  pub name: String,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub description: Option<String>,
+  pub creator_id: PersonId,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_list))]
+pub struct BanListInsertForm {
+  pub name: String,
+  pub creator_id: PersonId,
+  #[new(default)]
+  pub description: Option<String>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_list))]
+pub struct BanListUpdateForm {
+  pub name: Option<String>,
+  pub description: Option<Option<String>>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_list_community))]
+#[cfg_attr(feature = "full", diesel(primary_key(ban_list_id, community_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A community which applies the bans of a ban list.
+pub struct BanListCommunity {
+  pub ban_list_id: BanListId,
+  pub community_id: CommunityId,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_list_community))]
+pub struct BanListCommunityForm {
+  pub ban_list_id: BanListId,
+  pub community_id: CommunityId,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_list_entry))]
+#[cfg_attr(feature = "full", diesel(primary_key(ban_list_id, person_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A person banned by a ban list.
+pub struct BanListEntry {
+  pub ban_list_id: BanListId,
+  pub person_id: PersonId,
+  /// The moderator who added the ban.
+  pub creator_id: PersonId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub expires: Option<DateTime<Utc>>,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_list_entry))]
+pub struct BanListEntryForm {
+  pub ban_list_id: BanListId,
+  pub person_id: PersonId,
+  pub creator_id: PersonId,
+  pub reason: Option<String>,
+  pub expires: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_list_exemption))]
+#[cfg_attr(
+  feature = "full",
+  diesel(primary_key(ban_list_id, community_id, person_id))
+)]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A subscribed community which opted out of a single ban on the list.
+pub struct BanListExemption {
+  pub ban_list_id: BanListId,
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = ban_list_exemption))]
+pub struct BanListExemptionForm {
+  pub ban_list_id: BanListId,
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+}
@@ -0,0 +1,58 @@
+#[cfg(feature = "full")]
+use crate::schema::{client_restriction, client_usage};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = client_usage))]
+#[cfg_attr(feature = "full", diesel(primary_key(client, day)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Number of requests which a client, identified by the `X-Lemmy-Client` header, made on a
+/// single day.
+pub struct ClientUsage {
+  pub client: String,
+  /// Start of the day in UTC.
+  pub day: DateTime<Utc>,
+  pub request_count: i64,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = client_usage))]
+pub struct ClientUsageForm {
+  pub client: String,
+  pub day: DateTime<Utc>,
+  pub request_count: i64,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = client_restriction))]
+#[cfg_attr(feature = "full", diesel(primary_key(client)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A client which is throttled or blocked instance-wide by the admins.
+pub struct ClientRestriction {
+  pub client: String,
+  /// Maximum number of requests per minute. If none, the client is blocked entirely.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub requests_per_minute: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = client_restriction))]
+pub struct ClientRestrictionForm {
+  pub client: String,
+  pub requests_per_minute: Option<i32>,
+  pub reason: Option<String>,
+}
@@ -51,6 +51,11 @@ pub struct Comment {
   /// Whether the comment has been distinguished(speaking officially) by a mod.
   pub distinguished: bool,
   pub language_id: LanguageId,
+  /// Whether the content was moved to the archive. It is filled in again when the comment is
+  /// read.
+  pub archived: bool,
+  /// Whether the comment was pinned to the top of the post by the post creator or a mod.
+  pub distinguished_pinned: bool,
 }
 
 #[derive(Debug, Clone, derive_new::new)]
@@ -91,6 +96,7 @@ pub struct CommentUpdateForm {
   pub local: Option<bool>,
   pub distinguished: Option<bool>,
   pub language_id: Option<LanguageId>,
+  pub distinguished_pinned: Option<bool>,
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -138,6 +144,7 @@ pub struct CommentSaved {
   #[cfg_attr(feature = "full", diesel(select_expression = comment_actions::saved.assume_not_null()))]
   #[cfg_attr(feature = "full", diesel(select_expression_type = dsl::AssumeNotNull<comment_actions::saved>))]
   pub published: DateTime<Utc>,
+  pub saved_note: Option<String>,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -145,4 +152,5 @@ pub struct CommentSaved {
 pub struct CommentSavedForm {
   pub comment_id: CommentId,
   pub person_id: PersonId,
+  pub saved_note: Option<String>,
 }
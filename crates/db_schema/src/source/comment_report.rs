@@ -1,6 +1,10 @@
-use crate::newtypes::{CommentId, CommentReportId, PersonId};
 #[cfg(feature = "full")]
 use crate::schema::comment_report;
+use crate::{
+  newtypes::{CommentId, CommentReportId, PersonId, ReportReasonCategoryId},
+  ReportOutcome,
+  ReportSeverity,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -30,6 +34,16 @@ pub struct CommentReport {
   pub published: DateTime<Utc>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub updated: Option<DateTime<Utc>>,
+  pub severity: ReportSeverity,
+  /// The structured reason which the reporter picked.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub category_id: Option<ReportReasonCategoryId>,
+  /// How the report was handled, empty for pending reports.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub outcome: Option<ReportOutcome>,
+  /// The moderator who claimed the report.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub assigned_to: Option<PersonId>,
 }
 
 #[derive(Clone)]
@@ -40,4 +54,6 @@ pub struct CommentReportForm {
   pub comment_id: CommentId,
   pub original_comment_text: String,
   pub reason: String,
+  pub severity: Option<ReportSeverity>,
+  pub category_id: Option<ReportReasonCategoryId>,
 }
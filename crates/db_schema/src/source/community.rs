@@ -5,6 +5,7 @@ use crate::{
   sensitive::SensitiveString,
   source::placeholder_apub_url,
   CommunityVisibility,
+  NewAccountRestriction,
 };
 use chrono::{DateTime, Utc};
 #[cfg(feature = "full")]
@@ -76,6 +77,41 @@ pub struct Community {
   /// A shorter, one-line description of the site.
   #[cfg_attr(feature = "full", ts(optional))]
   pub description: Option<String>,
+  /// Whether anyone can export the comments of posts, not only moderators.
+  pub comment_export_public: bool,
+  /// A new community which is left out of Local/All listings and search until it has enough posts,
+  /// or an admin launches it.
+  pub soft_launched: bool,
+  /// Whether text recognized in uploaded images is used to find posts of the community in search.
+  pub image_text_search: bool,
+  /// Minimum reputation of users to post and comment in the community. Doesn't apply to
+  /// moderators and admins.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub posting_min_reputation: Option<i64>,
+  /// Sent as a private message by the top moderator to people who start following the
+  /// community. Only used for local communities.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub welcome_message: Option<String>,
+  /// Whether new posts of members wait for approval by a moderator before they are visible.
+  /// Trusted contributors, moderators and admins are exempt.
+  pub posts_require_approval: bool,
+  /// People whose active warning strikes in the community reach this number are banned from it
+  /// automatically. Unset if warnings never lead to a ban.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub warning_ban_strikes: Option<i32>,
+  /// The length of automatic bans for too many warning strikes.
+  pub warning_ban_days: i32,
+  /// New accounts must be at least this many days old for the restricted actions.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_min_age_days: Option<i32>,
+  /// New accounts must have written at least this many comments for the restricted actions.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_min_comments: Option<i32>,
+  /// New accounts must have a verified email for the restricted actions.
+  pub new_account_require_verified_email: bool,
+  /// The actions which are restricted until an account meets the thresholds above. Moderators and
+  /// admins are exempt.
+  pub new_account_restrictions: Vec<NewAccountRestriction>,
 }
 
 #[derive(Debug, Clone, derive_new::new)]
@@ -126,12 +162,37 @@ pub struct CommunityInsertForm {
   pub visibility: Option<CommunityVisibility>,
   #[new(default)]
   pub description: Option<String>,
+  #[new(default)]
+  pub comment_export_public: Option<bool>,
+  #[new(default)]
+  pub soft_launched: Option<bool>,
+  #[new(default)]
+  pub image_text_search: Option<bool>,
+  #[new(default)]
+  pub posting_min_reputation: Option<i64>,
+  #[new(default)]
+  pub welcome_message: Option<String>,
+  #[new(default)]
+  pub posts_require_approval: Option<bool>,
+  #[new(default)]
+  pub warning_ban_strikes: Option<i32>,
+  #[new(default)]
+  pub warning_ban_days: Option<i32>,
+  #[new(default)]
+  pub new_account_min_age_days: Option<i32>,
+  #[new(default)]
+  pub new_account_min_comments: Option<i32>,
+  #[new(default)]
+  pub new_account_require_verified_email: Option<bool>,
+  #[new(default)]
+  pub new_account_restrictions: Option<Vec<NewAccountRestriction>>,
 }
 
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "full", derive(AsChangeset))]
 #[cfg_attr(feature = "full", diesel(table_name = community))]
 pub struct CommunityUpdateForm {
+  pub name: Option<String>,
   pub title: Option<String>,
   pub sidebar: Option<Option<String>>,
   pub removed: Option<bool>,
@@ -154,6 +215,18 @@ pub struct CommunityUpdateForm {
   pub posting_restricted_to_mods: Option<bool>,
   pub visibility: Option<CommunityVisibility>,
   pub description: Option<Option<String>>,
+  pub comment_export_public: Option<bool>,
+  pub soft_launched: Option<bool>,
+  pub image_text_search: Option<bool>,
+  pub posting_min_reputation: Option<Option<i64>>,
+  pub welcome_message: Option<Option<String>>,
+  pub posts_require_approval: Option<bool>,
+  pub warning_ban_strikes: Option<Option<i32>>,
+  pub warning_ban_days: Option<i32>,
+  pub new_account_min_age_days: Option<Option<i32>>,
+  pub new_account_min_comments: Option<Option<i32>>,
+  pub new_account_require_verified_email: Option<bool>,
+  pub new_account_restrictions: Option<Vec<NewAccountRestriction>>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -204,6 +277,11 @@ pub struct CommunityPersonBan {
   pub published: DateTime<Utc>,
   #[cfg_attr(feature = "full", diesel(column_name = ban_expires))]
   pub expires: Option<DateTime<Utc>>,
+  #[cfg_attr(feature = "full", diesel(column_name = ban_reason))]
+  pub reason: Option<String>,
+  /// Where or how the banned person can appeal the ban, eg a url or a message.
+  #[cfg_attr(feature = "full", diesel(column_name = ban_appeal))]
+  pub appeal: Option<String>,
 }
 
 #[derive(Clone)]
@@ -214,6 +292,10 @@ pub struct CommunityPersonBanForm {
   pub person_id: PersonId,
   #[cfg_attr(feature = "full", diesel(column_name = ban_expires))]
   pub expires: Option<Option<DateTime<Utc>>>,
+  #[cfg_attr(feature = "full", diesel(column_name = ban_reason))]
+  pub reason: Option<Option<String>>,
+  #[cfg_attr(feature = "full", diesel(column_name = ban_appeal))]
+  pub appeal: Option<Option<String>>,
 }
 
 #[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
@@ -268,3 +350,32 @@ pub struct CommunityFollowerForm {
   #[cfg_attr(feature = "full", diesel(column_name = follow_approver_id))]
   pub approver_id: Option<PersonId>,
 }
+
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(
+  feature = "full",
+  derive(Identifiable, Queryable, Selectable, Associations)
+)]
+#[cfg_attr(
+  feature = "full",
+  diesel(belongs_to(crate::source::community::Community))
+)]
+#[cfg_attr(feature = "full", diesel(table_name = community_actions))]
+#[cfg_attr(feature = "full", diesel(primary_key(person_id, community_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+/// A community which the user doesn't want recommended in discovery listings, without blocking
+/// it entirely.
+pub struct CommunitySuppressedRecommendation {
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+  #[cfg_attr(feature = "full", diesel(select_expression = community_actions::suppressed_recommendation.assume_not_null()))]
+  #[cfg_attr(feature = "full", diesel(select_expression_type = dsl::AssumeNotNull<community_actions::suppressed_recommendation>))]
+  pub published: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_actions))]
+pub(crate) struct CommunitySuppressedRecommendationForm {
+  pub community_id: CommunityId,
+  pub person_id: PersonId,
+}
@@ -0,0 +1,96 @@
+#[cfg(feature = "full")]
+use crate::schema::{community_bot_action, community_bot_delegation};
+use crate::{
+  newtypes::{CommentId, CommunityBotDelegationId, CommunityId, PersonId, PostId},
+  sensitive::SensitiveString,
+  CommunityBotScope,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_bot_delegation))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Allows a bot account to perform some moderation actions in a community. The bot acts with the
+/// authority of the moderator who created the delegation, by authenticating with its token.
+pub struct CommunityBotDelegation {
+  pub id: CommunityBotDelegationId,
+  pub community_id: CommunityId,
+  pub bot_person_id: PersonId,
+  /// The moderator who created the delegation.
+  pub creator_id: PersonId,
+  pub revoked: bool,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+  /// The moderation actions which the token may be used for.
+  pub scopes: Vec<CommunityBotScope>,
+  #[serde(skip)]
+  pub token: SensitiveString,
+}
+
+impl CommunityBotDelegation {
+  pub fn allows(&self, scope: CommunityBotScope) -> bool {
+    !self.revoked && self.scopes.contains(&scope)
+  }
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = community_bot_delegation))]
+pub struct CommunityBotDelegationInsertForm {
+  pub community_id: CommunityId,
+  pub bot_person_id: PersonId,
+  pub creator_id: PersonId,
+  pub scopes: Vec<CommunityBotScope>,
+  pub token: SensitiveString,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_bot_delegation))]
+pub struct CommunityBotDelegationUpdateForm {
+  pub scopes: Option<Vec<CommunityBotScope>>,
+  pub revoked: Option<bool>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_bot_action))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An audit log entry for a moderation action which a bot performed through a delegation.
+pub struct CommunityBotAction {
+  pub id: i32,
+  pub delegation_id: CommunityBotDelegationId,
+  pub scope: CommunityBotScope,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub post_id: Option<PostId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub comment_id: Option<CommentId>,
+  /// False if the action was reverted, eg a post was restored.
+  pub enabled: bool,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = community_bot_action))]
+pub struct CommunityBotActionForm {
+  pub delegation_id: CommunityBotDelegationId,
+  pub scope: CommunityBotScope,
+  pub post_id: Option<PostId>,
+  pub comment_id: Option<CommentId>,
+  pub enabled: bool,
+  pub reason: Option<String>,
+}
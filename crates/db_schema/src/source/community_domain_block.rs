@@ -0,0 +1,38 @@
+use crate::newtypes::{CommunityId, PersonId};
+#[cfg(feature = "full")]
+use crate::schema::community_domain_block;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_domain_block))]
+#[cfg_attr(feature = "full", diesel(primary_key(community_id, domain)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A domain which posts in the community can't link to. Subdomains are blocked too.
+pub struct CommunityDomainBlock {
+  pub community_id: CommunityId,
+  /// Always lowercase.
+  pub domain: String,
+  /// The moderator who blocked the domain.
+  pub creator_id: PersonId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_domain_block))]
+pub struct CommunityDomainBlockForm {
+  pub community_id: CommunityId,
+  pub domain: String,
+  pub creator_id: PersonId,
+  #[new(default)]
+  pub reason: Option<String>,
+}
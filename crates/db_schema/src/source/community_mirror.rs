@@ -0,0 +1,87 @@
+use crate::newtypes::{CommunityId, CommunityMirrorId, PersonId, PostId};
+#[cfg(feature = "full")]
+use crate::schema::{community_mirror, community_mirror_action};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_mirror))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An agreement of a local community to apply the bans and removals of a remote partner
+/// community.
+pub struct CommunityMirror {
+  pub id: CommunityMirrorId,
+  pub community_id: CommunityId,
+  pub partner_community_id: CommunityId,
+  /// The moderator who created the agreement.
+  pub creator_id: PersonId,
+  pub mirror_bans: bool,
+  pub mirror_removals: bool,
+  pub enabled: bool,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = community_mirror))]
+pub struct CommunityMirrorInsertForm {
+  pub community_id: CommunityId,
+  pub partner_community_id: CommunityId,
+  pub creator_id: PersonId,
+  #[new(default)]
+  pub mirror_bans: Option<bool>,
+  #[new(default)]
+  pub mirror_removals: Option<bool>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_mirror))]
+pub struct CommunityMirrorUpdateForm {
+  pub mirror_bans: Option<bool>,
+  pub mirror_removals: Option<bool>,
+  pub enabled: Option<bool>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_mirror_action))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A ban or removal which was applied because of a mirror agreement.
+pub struct CommunityMirrorAction {
+  pub id: i32,
+  pub mirror_id: CommunityMirrorId,
+  /// The moderator of the partner community who performed the original action.
+  pub mod_person_id: PersonId,
+  /// Set for bans.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub other_person_id: Option<PersonId>,
+  /// Set for removals, this is the local post which was removed.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub post_id: Option<PostId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+  pub when_: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = community_mirror_action))]
+pub struct CommunityMirrorActionForm {
+  pub mirror_id: CommunityMirrorId,
+  pub mod_person_id: PersonId,
+  pub other_person_id: Option<PersonId>,
+  pub post_id: Option<PostId>,
+  pub reason: Option<String>,
+}
@@ -0,0 +1,41 @@
+use crate::newtypes::{CommunityId, PersonId};
+#[cfg(feature = "full")]
+use crate::schema::community_name_alias;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_name_alias))]
+#[cfg_attr(feature = "full", diesel(primary_key(name)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A name which can't be used for new communities. Either the old name of a renamed community,
+/// which still resolves to it, or a name reserved by an admin.
+pub struct CommunityNameAlias {
+  /// Always lowercase.
+  pub name: String,
+  /// The renamed community, or none if the name is reserved.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  /// The admin who renamed the community or reserved the name.
+  pub creator_id: PersonId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = community_name_alias))]
+pub struct CommunityNameAliasForm {
+  pub name: String,
+  pub community_id: Option<CommunityId>,
+  pub creator_id: PersonId,
+  #[new(default)]
+  pub reason: Option<String>,
+}
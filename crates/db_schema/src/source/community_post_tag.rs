@@ -0,0 +1,50 @@
+use crate::newtypes::{CommunityId, CommunityPostTagId, PostId};
+#[cfg(feature = "full")]
+use crate::schema::{community_post_tag, post_tag};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_post_tag))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A tag which the moderators of a community defined, and which can be attached to its posts.
+pub struct CommunityPostTag {
+  pub id: CommunityPostTagId,
+  pub community_id: CommunityId,
+  pub name: String,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = community_post_tag))]
+pub struct CommunityPostTagInsertForm {
+  pub community_id: CommunityId,
+  pub name: String,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_post_tag))]
+pub struct CommunityPostTagUpdateForm {
+  pub name: Option<String>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = post_tag))]
+#[cfg_attr(feature = "full", diesel(primary_key(post_id, tag_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PostTag {
+  pub post_id: PostId,
+  pub tag_id: CommunityPostTagId,
+}
@@ -0,0 +1,108 @@
+#[cfg(feature = "full")]
+use crate::schema::{community_request, community_request_comment, community_request_vote};
+use crate::{
+  newtypes::{CommunityId, CommunityRequestCommentId, CommunityRequestId, PersonId},
+  CommunityRequestStatus,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_request))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A request by a user for a new community, which admins can approve or deny.
+pub struct CommunityRequest {
+  pub id: CommunityRequestId,
+  pub creator_id: PersonId,
+  /// The name for the new community.
+  pub name: String,
+  pub title: String,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub description: Option<String>,
+  pub status: CommunityRequestStatus,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub deny_reason: Option<String>,
+  /// The admin who approved or denied the request.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub admin_id: Option<PersonId>,
+  /// The community which was created for an approved request.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = community_request))]
+pub struct CommunityRequestInsertForm {
+  pub creator_id: PersonId,
+  pub name: String,
+  pub title: String,
+  #[new(default)]
+  pub description: Option<String>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_request))]
+pub struct CommunityRequestUpdateForm {
+  pub status: Option<CommunityRequestStatus>,
+  pub deny_reason: Option<Option<String>>,
+  pub admin_id: Option<Option<PersonId>>,
+  pub community_id: Option<Option<CommunityId>>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_request_vote))]
+#[cfg_attr(feature = "full", diesel(primary_key(person_id, community_request_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A vote of a user for or against a community request.
+pub struct CommunityRequestVote {
+  pub person_id: PersonId,
+  pub community_request_id: CommunityRequestId,
+  pub score: i16,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_request_vote))]
+pub struct CommunityRequestVoteForm {
+  pub person_id: PersonId,
+  pub community_request_id: CommunityRequestId,
+  pub score: i16,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_request_comment))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A comment on a community request, eg to suggest a different name.
+pub struct CommunityRequestComment {
+  pub id: CommunityRequestCommentId,
+  pub community_request_id: CommunityRequestId,
+  pub creator_id: PersonId,
+  pub content: String,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = community_request_comment))]
+pub struct CommunityRequestCommentForm {
+  pub community_request_id: CommunityRequestId,
+  pub creator_id: PersonId,
+  pub content: String,
+}
@@ -0,0 +1,78 @@
+#[cfg(feature = "full")]
+use crate::schema::community_role_permission;
+use crate::{newtypes::CommunityId, CommunityRole};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_role_permission))]
+#[cfg_attr(feature = "full", diesel(primary_key(community_id, role)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// What people with a role are allowed to do in a community. The owner can always do everything.
+pub struct CommunityRolePermission {
+  pub community_id: CommunityId,
+  pub role: CommunityRole,
+  pub can_feature_posts: bool,
+  pub can_remove_comments: bool,
+  pub can_edit_sidebar: bool,
+  /// Unset if the role still has the default permissions.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+  /// Whether the role can see who voted on posts and comments.
+  pub can_view_votes: bool,
+}
+
+/// The actions which can be allowed per community role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommunityPermission {
+  FeaturePost,
+  RemoveComment,
+  EditSidebar,
+  ViewVotes,
+}
+
+impl CommunityRolePermission {
+  /// Permissions of a role which the owner hasn't changed. Moderators can do everything, other
+  /// roles nothing.
+  pub fn default_for(community_id: CommunityId, role: CommunityRole) -> Self {
+    let allowed = matches!(role, CommunityRole::Owner | CommunityRole::Moderator);
+    CommunityRolePermission {
+      community_id,
+      role,
+      can_feature_posts: allowed,
+      can_remove_comments: allowed,
+      can_edit_sidebar: allowed,
+      updated: None,
+      can_view_votes: allowed,
+    }
+  }
+
+  pub fn allows(&self, permission: CommunityPermission) -> bool {
+    self.role == CommunityRole::Owner
+      || match permission {
+        CommunityPermission::FeaturePost => self.can_feature_posts,
+        CommunityPermission::RemoveComment => self.can_remove_comments,
+        CommunityPermission::EditSidebar => self.can_edit_sidebar,
+        CommunityPermission::ViewVotes => self.can_view_votes,
+      }
+  }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_role_permission))]
+pub struct CommunityRolePermissionForm {
+  pub community_id: CommunityId,
+  pub role: CommunityRole,
+  pub can_feature_posts: bool,
+  pub can_remove_comments: bool,
+  pub can_edit_sidebar: bool,
+  pub updated: DateTime<Utc>,
+  pub can_view_votes: bool,
+}
@@ -0,0 +1,64 @@
+use crate::newtypes::{CommunityId, CustomFeedId, PersonId};
+#[cfg(feature = "full")]
+use crate::schema::{custom_feed, custom_feed_community};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = custom_feed))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A named feed which combines the posts of several communities.
+pub struct CustomFeed {
+  pub id: CustomFeedId,
+  pub creator_id: PersonId,
+  pub name: String,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub description: Option<String>,
+  /// Public feeds can be viewed by anyone, other feeds only by their creator.
+  pub public: bool,
+  /// Identifies the feed in urls which are shared with others.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub slug: Option<String>,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = custom_feed))]
+pub struct CustomFeedInsertForm {
+  pub creator_id: PersonId,
+  pub name: String,
+  #[new(default)]
+  pub description: Option<String>,
+  #[new(default)]
+  pub public: Option<bool>,
+  #[new(default)]
+  pub slug: Option<String>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = custom_feed))]
+pub struct CustomFeedUpdateForm {
+  pub name: Option<String>,
+  pub description: Option<Option<String>>,
+  pub public: Option<bool>,
+  pub slug: Option<Option<String>>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = custom_feed_community))]
+pub struct CustomFeedCommunityForm {
+  pub custom_feed_id: CustomFeedId,
+  pub community_id: CommunityId,
+}
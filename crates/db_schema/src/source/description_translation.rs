@@ -0,0 +1,74 @@
+use crate::newtypes::{CommunityId, LanguageId, SiteId};
+#[cfg(feature = "full")]
+use crate::schema::{community_description_translation, site_description_translation};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_description_translation))]
+#[cfg_attr(feature = "full", diesel(primary_key(community_id, language_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The sidebar and description of a community in another language. Fields which aren't translated
+/// fall back to the original text.
+pub struct CommunityDescriptionTranslation {
+  pub community_id: CommunityId,
+  pub language_id: LanguageId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub sidebar: Option<String>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub description: Option<String>,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = community_description_translation))]
+pub struct CommunityDescriptionTranslationForm {
+  pub community_id: CommunityId,
+  pub language_id: LanguageId,
+  #[new(default)]
+  pub sidebar: Option<String>,
+  #[new(default)]
+  pub description: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = site_description_translation))]
+#[cfg_attr(feature = "full", diesel(primary_key(site_id, language_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The sidebar and description of the site in another language. Fields which aren't translated
+/// fall back to the original text.
+pub struct SiteDescriptionTranslation {
+  pub site_id: SiteId,
+  pub language_id: LanguageId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub sidebar: Option<String>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub description: Option<String>,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = site_description_translation))]
+pub struct SiteDescriptionTranslationForm {
+  pub site_id: SiteId,
+  pub language_id: LanguageId,
+  #[new(default)]
+  pub sidebar: Option<String>,
+  #[new(default)]
+  pub description: Option<String>,
+}
@@ -0,0 +1,35 @@
+#[cfg(feature = "full")]
+use crate::schema::feature_flag;
+use crate::FeatureFlagName;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = feature_flag))]
+#[cfg_attr(feature = "full", diesel(primary_key(feature)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Admin configuration for an experimental feature. Features without a row are disabled.
+pub struct FeatureFlag {
+  pub feature: FeatureFlagName,
+  pub enabled: bool,
+  /// Share of local users, from 0 to 100, who get the feature while it is enabled.
+  pub rollout_percentage: i16,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = feature_flag))]
+pub struct FeatureFlagForm {
+  pub feature: FeatureFlagName,
+  pub enabled: bool,
+  pub rollout_percentage: i16,
+}
@@ -21,6 +21,8 @@ pub struct FederationBlockList {
   pub instance_id: InstanceId,
   pub published: DateTime<Utc>,
   pub updated: Option<DateTime<Utc>>,
+  /// Publicly visible reason for the block, if `instance_blocks_public` is enabled.
+  pub reason: Option<String>,
 }
 
 #[derive(Clone, Default)]
@@ -30,3 +32,11 @@ pub struct FederationBlockListForm {
   pub instance_id: InstanceId,
   pub updated: Option<DateTime<Utc>>,
 }
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = federation_blocklist))]
+pub struct FederationBlockListUpdateForm {
+  pub reason: Option<Option<String>>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
@@ -0,0 +1,79 @@
+#[cfg(feature = "full")]
+use crate::schema::filter_rule;
+use crate::{
+  newtypes::{CommunityId, FilterRuleId, PersonId},
+  FilterRuleAction,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = filter_rule))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A keyword or regular expression which is checked against new and edited local content,
+/// optionally together with conditions about links in the content and about its author.
+pub struct FilterRule {
+  pub id: FilterRuleId,
+  /// Empty for rules which apply to the whole site.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  pub pattern: String,
+  pub is_regex: bool,
+  pub action: FilterRuleAction,
+  pub creator_id: PersonId,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+  /// Only content linking to this domain or its subdomains matches.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub link_domain: Option<String>,
+  /// Only content by accounts younger than this matches.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub max_account_age_days: Option<i32>,
+  /// Only content by people with at most this reputation matches.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub max_reputation: Option<i64>,
+  /// Only content for which this expression is true matches. See
+  /// `crates/utils/src/utils/expression.rs` for the syntax.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub condition: Option<String>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = filter_rule))]
+pub struct FilterRuleInsertForm {
+  pub community_id: Option<CommunityId>,
+  pub pattern: String,
+  pub is_regex: bool,
+  pub action: FilterRuleAction,
+  pub creator_id: PersonId,
+  #[new(default)]
+  pub link_domain: Option<String>,
+  #[new(default)]
+  pub max_account_age_days: Option<i32>,
+  #[new(default)]
+  pub max_reputation: Option<i64>,
+  #[new(default)]
+  pub condition: Option<String>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = filter_rule))]
+pub struct FilterRuleUpdateForm {
+  pub pattern: Option<String>,
+  pub is_regex: Option<bool>,
+  pub action: Option<FilterRuleAction>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+  pub link_domain: Option<Option<String>>,
+  pub max_account_age_days: Option<Option<i32>>,
+  pub max_reputation: Option<Option<i64>>,
+  pub condition: Option<Option<String>>,
+}
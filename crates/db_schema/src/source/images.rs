@@ -1,6 +1,6 @@
 use crate::newtypes::{DbUrl, LocalUserId};
 #[cfg(feature = "full")]
-use crate::schema::{image_details, local_image, remote_image};
+use crate::schema::{image_details, image_text, local_image, remote_image};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -75,3 +75,26 @@ pub struct ImageDetailsForm {
   pub height: i32,
   pub content_type: String,
 }
+
+/// Text which the OCR service recognized in an uploaded image, used to find posts with the image
+/// in search.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = image_text))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", diesel(primary_key(pictrs_alias)))]
+pub struct ImageText {
+  pub pictrs_alias: String,
+  pub link: DbUrl,
+  pub text: String,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = image_text))]
+pub struct ImageTextForm {
+  pub pictrs_alias: String,
+  pub link: DbUrl,
+  pub text: String,
+}
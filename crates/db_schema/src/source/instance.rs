@@ -1,6 +1,6 @@
-use crate::newtypes::InstanceId;
 #[cfg(feature = "full")]
 use crate::schema::instance;
+use crate::{newtypes::InstanceId, InstanceTrustLevel};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -25,6 +25,7 @@ pub struct Instance {
   pub software: Option<String>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub version: Option<String>,
+  pub trust_level: InstanceTrustLevel,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -0,0 +1,45 @@
+use crate::newtypes::{CommunityId, InterestCategoryId};
+#[cfg(feature = "full")]
+use crate::schema::{interest_category, interest_category_community};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = interest_category))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A topic which new users can pick during onboarding, to get community suggestions.
+pub struct InterestCategory {
+  pub id: InterestCategoryId,
+  pub name: String,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = interest_category))]
+pub struct InterestCategoryInsertForm {
+  pub name: String,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = interest_category))]
+pub struct InterestCategoryUpdateForm {
+  pub name: String,
+  pub updated: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = interest_category_community))]
+pub(crate) struct InterestCategoryCommunityForm {
+  pub category_id: InterestCategoryId,
+  pub community_id: CommunityId,
+}
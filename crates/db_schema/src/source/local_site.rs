@@ -5,6 +5,7 @@ use crate::{
   CommentSortType,
   FederationMode,
   ListingType,
+  NewAccountRestriction,
   PostListingMode,
   PostSortType,
   RegistrationMode,
@@ -32,9 +33,6 @@ pub struct LocalSite {
   pub community_creation_admin_only: bool,
   /// Whether emails are required.
   pub require_email_verification: bool,
-  /// An optional registration application questionnaire in markdown.
-  #[cfg_attr(feature = "full", ts(optional))]
-  pub application_question: Option<String>,
   /// Whether the instance is private or public.
   pub private_instance: bool,
   /// The default front-end theme.
@@ -83,6 +81,65 @@ pub struct LocalSite {
   pub comment_upvotes: FederationMode,
   /// What kind of comment downvotes your site allows.
   pub comment_downvotes: FederationMode,
+  /// Whether the list of blocked and allowed instances, including block reasons, is visible to
+  /// everyone.
+  pub instance_blocks_public: bool,
+  /// Fold unicode lookalike characters and strip accents and invisible characters before
+  /// applying the slur filter, so that it can't be bypassed with homoglyphs.
+  pub slur_filter_normalize: bool,
+  /// Weight of the share of links in new content for the spam score.
+  pub spam_link_density_weight: i32,
+  /// Weight of the same content being posted repeatedly in different places for the spam score.
+  pub spam_repetition_weight: i32,
+  /// Weight of the creator's account being new for the spam score.
+  pub spam_account_age_weight: i32,
+  /// New content with at least this spam score is hidden until an admin reviews it.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_hold_threshold: Option<i32>,
+  /// New content with at least this spam score is removed.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub spam_remove_threshold: Option<i32>,
+  /// While enabled, all mutating API calls are rejected and incoming activities are queued.
+  pub maintenance_mode: bool,
+  /// If set, new local communities are left out of Local/All listings and search until they have
+  /// this many posts, or an admin launches them.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_soft_launch_posts: Option<i32>,
+  /// Show NSFW content for new accounts.
+  pub default_show_nsfw: bool,
+  /// Show posts and comments of bot accounts for new accounts.
+  pub default_show_bot_accounts: bool,
+  /// Send notifications to email for new accounts.
+  pub default_send_notifications_to_email: bool,
+  /// Interface language for new accounts whose browser doesn't send a
+  /// preferred language.
+  pub default_interface_language: String,
+  /// After this many days a vote only counts half for the reputation of its recipient. Without it,
+  /// reputation isn't computed.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reputation_half_life_days: Option<i32>,
+  /// Weight of the creator having a negative reputation for the spam score.
+  pub spam_low_reputation_weight: i32,
+  /// If set, users can also log in with the credentials of this LDAP directory.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub ldap_url: Option<String>,
+  /// The DN to bind as when logging in with LDAP, where `{username}` is replaced by the name
+  /// which was entered.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub ldap_user_dn_template: Option<String>,
+  /// Reject signups with email addresses of well-known disposable email providers.
+  pub block_disposable_email_domains: bool,
+  /// New accounts must be at least this many days old for the restricted actions.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_min_age_days: Option<i32>,
+  /// New accounts must have written at least this many comments for the restricted actions.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub new_account_min_comments: Option<i32>,
+  /// New accounts must have a verified email for the restricted actions.
+  pub new_account_require_verified_email: bool,
+  /// The actions which are restricted until an account meets the thresholds above. Admins are
+  /// exempt.
+  pub new_account_restrictions: Vec<NewAccountRestriction>,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -97,8 +154,6 @@ pub struct LocalSiteInsertForm {
   #[new(default)]
   pub require_email_verification: Option<bool>,
   #[new(default)]
-  pub application_question: Option<String>,
-  #[new(default)]
   pub private_instance: Option<bool>,
   #[new(default)]
   pub default_theme: Option<String>,
@@ -142,6 +197,50 @@ pub struct LocalSiteInsertForm {
   pub comment_upvotes: Option<FederationMode>,
   #[new(default)]
   pub comment_downvotes: Option<FederationMode>,
+  #[new(default)]
+  pub instance_blocks_public: Option<bool>,
+  #[new(default)]
+  pub slur_filter_normalize: Option<bool>,
+  #[new(default)]
+  pub spam_link_density_weight: Option<i32>,
+  #[new(default)]
+  pub spam_repetition_weight: Option<i32>,
+  #[new(default)]
+  pub spam_account_age_weight: Option<i32>,
+  #[new(default)]
+  pub spam_hold_threshold: Option<i32>,
+  #[new(default)]
+  pub spam_remove_threshold: Option<i32>,
+  #[new(default)]
+  pub maintenance_mode: Option<bool>,
+  #[new(default)]
+  pub community_soft_launch_posts: Option<i32>,
+  #[new(default)]
+  pub default_show_nsfw: Option<bool>,
+  #[new(default)]
+  pub default_show_bot_accounts: Option<bool>,
+  #[new(default)]
+  pub default_send_notifications_to_email: Option<bool>,
+  #[new(default)]
+  pub default_interface_language: Option<String>,
+  #[new(default)]
+  pub reputation_half_life_days: Option<i32>,
+  #[new(default)]
+  pub spam_low_reputation_weight: Option<i32>,
+  #[new(default)]
+  pub ldap_url: Option<String>,
+  #[new(default)]
+  pub ldap_user_dn_template: Option<String>,
+  #[new(default)]
+  pub block_disposable_email_domains: Option<bool>,
+  #[new(default)]
+  pub new_account_min_age_days: Option<i32>,
+  #[new(default)]
+  pub new_account_min_comments: Option<i32>,
+  #[new(default)]
+  pub new_account_require_verified_email: Option<bool>,
+  #[new(default)]
+  pub new_account_restrictions: Option<Vec<NewAccountRestriction>>,
 }
 
 #[derive(Clone, Default)]
@@ -151,7 +250,6 @@ pub struct LocalSiteUpdateForm {
   pub site_setup: Option<bool>,
   pub community_creation_admin_only: Option<bool>,
   pub require_email_verification: Option<bool>,
-  pub application_question: Option<Option<String>>,
   pub private_instance: Option<bool>,
   pub default_theme: Option<String>,
   pub default_post_listing_type: Option<ListingType>,
@@ -175,4 +273,26 @@ pub struct LocalSiteUpdateForm {
   pub post_downvotes: Option<FederationMode>,
   pub comment_upvotes: Option<FederationMode>,
   pub comment_downvotes: Option<FederationMode>,
+  pub instance_blocks_public: Option<bool>,
+  pub slur_filter_normalize: Option<bool>,
+  pub spam_link_density_weight: Option<i32>,
+  pub spam_repetition_weight: Option<i32>,
+  pub spam_account_age_weight: Option<i32>,
+  pub spam_hold_threshold: Option<Option<i32>>,
+  pub spam_remove_threshold: Option<Option<i32>>,
+  pub maintenance_mode: Option<bool>,
+  pub community_soft_launch_posts: Option<Option<i32>>,
+  pub default_show_nsfw: Option<bool>,
+  pub default_show_bot_accounts: Option<bool>,
+  pub default_send_notifications_to_email: Option<bool>,
+  pub default_interface_language: Option<String>,
+  pub reputation_half_life_days: Option<Option<i32>>,
+  pub spam_low_reputation_weight: Option<i32>,
+  pub ldap_url: Option<Option<String>>,
+  pub ldap_user_dn_template: Option<Option<String>>,
+  pub block_disposable_email_domains: Option<bool>,
+  pub new_account_min_age_days: Option<Option<i32>>,
+  pub new_account_min_comments: Option<Option<i32>>,
+  pub new_account_require_verified_email: Option<bool>,
+  pub new_account_restrictions: Option<Vec<NewAccountRestriction>>,
 }
@@ -0,0 +1,29 @@
+#[cfg(feature = "full")]
+use crate::schema::local_site_email_domain;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = local_site_email_domain))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An email domain which is allowed or blocked for signups.
+pub struct LocalSiteEmailDomain {
+  pub id: i32,
+  /// The domain, optionally starting with `*.` to include all subdomains.
+  pub domain: String,
+  /// True if the domain is part of the allowlist, false if it is blocked.
+  pub allowed: bool,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = local_site_email_domain))]
+pub struct LocalSiteEmailDomainForm {
+  pub domain: String,
+  pub allowed: bool,
+}
@@ -38,6 +38,13 @@ pub struct LocalSiteRateLimit {
   pub updated: Option<DateTime<Utc>>,
   pub import_user_settings: i32,
   pub import_user_settings_per_second: i32,
+  /// Registrations from the same IP subnet (/24 for IPv4, /48 for IPv6). 0 disables the limit.
+  pub register_subnet: i32,
+  pub register_subnet_per_second: i32,
+  /// Registrations from the same autonomous system, only if a MaxMind ASN database is configured.
+  /// 0 disables the limit.
+  pub register_asn: i32,
+  pub register_asn_per_second: i32,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -73,6 +80,14 @@ pub struct LocalSiteRateLimitInsertForm {
   pub import_user_settings: Option<i32>,
   #[new(default)]
   pub import_user_settings_per_second: Option<i32>,
+  #[new(default)]
+  pub register_subnet: Option<i32>,
+  #[new(default)]
+  pub register_subnet_per_second: Option<i32>,
+  #[new(default)]
+  pub register_asn: Option<i32>,
+  #[new(default)]
+  pub register_asn_per_second: Option<i32>,
 }
 
 #[derive(Clone, Default)]
@@ -93,5 +108,9 @@ pub struct LocalSiteRateLimitUpdateForm {
   pub search_per_second: Option<i32>,
   pub import_user_settings: Option<i32>,
   pub import_user_settings_per_second: Option<i32>,
+  pub register_subnet: Option<i32>,
+  pub register_subnet_per_second: Option<i32>,
+  pub register_asn: Option<i32>,
+  pub register_asn_per_second: Option<i32>,
   pub updated: Option<Option<DateTime<Utc>>>,
 }
@@ -3,11 +3,14 @@ use crate::schema::local_user;
 use crate::{
   newtypes::{LocalUserId, PersonId},
   sensitive::SensitiveString,
+  AllowPrivateMessages,
   CommentSortType,
+  DefaultUserSetting,
   ListingType,
   PostListingMode,
   PostSortType,
 };
+use chrono::NaiveTime;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
@@ -70,6 +73,37 @@ pub struct LocalUser {
   pub default_comment_sort_type: CommentSortType,
   /// Whether to automatically mark fetched posts as read.
   pub auto_mark_fetched_posts_as_read: bool,
+  /// Percentage of posts in the Home listing which come from communities you're not subscribed to.
+  pub home_discovery_percent: i16,
+  /// Frozen by an admin, which prevents login until the account is unfrozen.
+  pub frozen: bool,
+  /// Whether uploaded images of the user are sent to the OCR service of the instance, so that the
+  /// recognized text can be used for search.
+  pub image_text_recognition: bool,
+  /// Settings which the user changed. New defaults from the admins are not applied to these.
+  pub customized_settings: Vec<DefaultUserSetting>,
+  /// Whether to hold back notification emails during quiet hours.
+  pub quiet_hours_enabled: bool,
+  /// The time at which quiet hours start, in the quiet hours timezone.
+  pub quiet_hours_start: NaiveTime,
+  /// The time at which quiet hours end, in the quiet hours timezone.
+  pub quiet_hours_end: NaiveTime,
+  /// The IANA timezone of the quiet hours, for example `Europe/Berlin`.
+  pub quiet_hours_timezone: String,
+  /// Whether saving a post also marks it as read.
+  pub mark_read_on_save: bool,
+  /// Whether private messages from strangers are held back as message requests.
+  pub private_message_requests: bool,
+  /// Who can send private messages to the user.
+  pub allow_private_messages: AllowPrivateMessages,
+  /// Whether bot posts are left out of feeds with several communities, and bot comments are
+  /// listed last.
+  pub downrank_bot_accounts: bool,
+  /// Whether the account was created by logging in with the credentials of the LDAP directory.
+  /// Only these accounts can log in through LDAP.
+  pub ldap_login: bool,
+  /// Whether an admin account created through LDAP may still log in through LDAP.
+  pub ldap_admin_login: bool,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -128,6 +162,32 @@ pub struct LocalUserInsertForm {
   pub default_comment_sort_type: Option<CommentSortType>,
   #[new(default)]
   pub auto_mark_fetched_posts_as_read: Option<bool>,
+  #[new(default)]
+  pub home_discovery_percent: Option<i16>,
+  #[new(default)]
+  pub frozen: Option<bool>,
+  #[new(default)]
+  pub image_text_recognition: Option<bool>,
+  #[new(default)]
+  pub quiet_hours_enabled: Option<bool>,
+  #[new(default)]
+  pub quiet_hours_start: Option<NaiveTime>,
+  #[new(default)]
+  pub quiet_hours_end: Option<NaiveTime>,
+  #[new(default)]
+  pub quiet_hours_timezone: Option<String>,
+  #[new(default)]
+  pub mark_read_on_save: Option<bool>,
+  #[new(default)]
+  pub private_message_requests: Option<bool>,
+  #[new(default)]
+  pub allow_private_messages: Option<AllowPrivateMessages>,
+  #[new(default)]
+  pub downrank_bot_accounts: Option<bool>,
+  #[new(default)]
+  pub ldap_login: Option<bool>,
+  #[new(default)]
+  pub ldap_admin_login: Option<bool>,
 }
 
 #[derive(Clone, Default)]
@@ -160,4 +220,17 @@ pub struct LocalUserUpdateForm {
   pub collapse_bot_comments: Option<bool>,
   pub default_comment_sort_type: Option<CommentSortType>,
   pub auto_mark_fetched_posts_as_read: Option<bool>,
+  pub home_discovery_percent: Option<i16>,
+  pub frozen: Option<bool>,
+  pub image_text_recognition: Option<bool>,
+  pub customized_settings: Option<Vec<DefaultUserSetting>>,
+  pub quiet_hours_enabled: Option<bool>,
+  pub quiet_hours_start: Option<NaiveTime>,
+  pub quiet_hours_end: Option<NaiveTime>,
+  pub quiet_hours_timezone: Option<String>,
+  pub mark_read_on_save: Option<bool>,
+  pub private_message_requests: Option<bool>,
+  pub allow_private_messages: Option<AllowPrivateMessages>,
+  pub downrank_bot_accounts: Option<bool>,
+  pub ldap_admin_login: Option<bool>,
 }
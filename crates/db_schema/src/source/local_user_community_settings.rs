@@ -0,0 +1,53 @@
+#[cfg(feature = "full")]
+use crate::schema::local_user_community_settings;
+use crate::{
+  newtypes::{CommunityId, LocalUserId},
+  PostListingMode,
+  PostSortType,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = local_user_community_settings))]
+#[cfg_attr(feature = "full", diesel(primary_key(local_user_id, community_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The sort and listing mode which you last chose for a community. Empty values mean that your
+/// default settings are used.
+pub struct LocalUserCommunitySettings {
+  #[serde(skip)]
+  pub local_user_id: LocalUserId,
+  pub community_id: CommunityId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub post_sort_type: Option<PostSortType>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub post_listing_mode: Option<PostListingMode>,
+  pub updated: DateTime<Utc>,
+  /// Overrides whether bot content is shown in this community, for communities which rely on
+  /// helpful bots.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub show_bot_accounts: Option<bool>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = local_user_community_settings))]
+#[cfg_attr(feature = "full", diesel(treat_none_as_null = true))]
+pub struct LocalUserCommunitySettingsForm {
+  pub local_user_id: LocalUserId,
+  pub community_id: CommunityId,
+  #[new(default)]
+  pub post_sort_type: Option<PostSortType>,
+  #[new(default)]
+  pub post_listing_mode: Option<PostListingMode>,
+  #[new(value = "Utc::now()")]
+  pub updated: DateTime<Utc>,
+  #[new(default)]
+  pub show_bot_accounts: Option<bool>,
+}
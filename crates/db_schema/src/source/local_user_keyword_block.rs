@@ -0,0 +1,31 @@
+use crate::newtypes::{LocalUserId, LocalUserKeywordBlockId};
+#[cfg(feature = "full")]
+use crate::schema::local_user_keyword_block;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = local_user_keyword_block))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Posts and comments which contain this keyword are hidden from the user.
+pub struct LocalUserKeywordBlock {
+  pub id: LocalUserKeywordBlockId,
+  pub local_user_id: LocalUserId,
+  pub keyword: String,
+  /// Whether the keyword is a regular expression, instead of plain text.
+  pub is_regex: bool,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = local_user_keyword_block))]
+pub struct LocalUserKeywordBlockForm {
+  pub local_user_id: LocalUserId,
+  pub keyword: String,
+  pub is_regex: bool,
+}
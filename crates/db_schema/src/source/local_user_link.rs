@@ -0,0 +1,27 @@
+use crate::newtypes::LocalUserId;
+#[cfg(feature = "full")]
+use crate::schema::local_user_link;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Another account on this instance, which the user proved to control.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = local_user_link))]
+#[cfg_attr(
+  feature = "full",
+  diesel(primary_key(local_user_id, linked_local_user_id))
+)]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct LocalUserLink {
+  pub local_user_id: LocalUserId,
+  pub linked_local_user_id: LocalUserId,
+  pub published: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = local_user_link))]
+pub(crate) struct LocalUserLinkForm {
+  pub local_user_id: LocalUserId,
+  pub linked_local_user_id: LocalUserId,
+}
@@ -1,6 +1,9 @@
 #[cfg(feature = "full")]
 use crate::schema::login_token;
-use crate::{newtypes::LocalUserId, sensitive::SensitiveString};
+use crate::{
+  newtypes::{LocalUserId, LoginTokenId},
+  sensitive::SensitiveString,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -28,6 +31,11 @@ pub struct LoginToken {
   pub ip: Option<String>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub user_agent: Option<String>,
+  /// If this token was issued by switching from a linked account, the user id of that account.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub linked_from_user_id: Option<LocalUserId>,
+  /// Identifies the login when revoking it.
+  pub id: LoginTokenId,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -37,4 +45,5 @@ pub struct LoginTokenCreateForm {
   pub user_id: LocalUserId,
   pub ip: Option<String>,
   pub user_agent: Option<String>,
+  pub linked_from_user_id: Option<LocalUserId>,
 }
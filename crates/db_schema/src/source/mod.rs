@@ -4,26 +4,45 @@ use url::Url;
 #[cfg(feature = "full")]
 pub mod activity;
 pub mod actor_language;
+pub mod ban_appeal;
+pub mod ban_list;
 pub mod captcha_answer;
+pub mod client_usage;
 pub mod comment;
 pub mod comment_reply;
 pub mod comment_report;
 pub mod community;
 pub mod community_block;
+pub mod community_bot;
+pub mod community_domain_block;
+pub mod community_mirror;
+pub mod community_name_alias;
+pub mod community_post_tag;
+pub mod community_request;
+pub mod community_role;
 pub mod custom_emoji;
 pub mod custom_emoji_keyword;
+pub mod custom_feed;
+pub mod description_translation;
 pub mod email_verification;
+pub mod feature_flag;
 pub mod federation_allowlist;
 pub mod federation_blocklist;
 pub mod federation_queue_state;
+pub mod filter_rule;
 pub mod images;
 pub mod instance;
 pub mod instance_block;
+pub mod interest_category;
 pub mod language;
 pub mod local_site;
+pub mod local_site_email_domain;
 pub mod local_site_rate_limit;
 pub mod local_site_url_blocklist;
 pub mod local_user;
+pub mod local_user_community_settings;
+pub mod local_user_keyword_block;
+pub mod local_user_link;
 pub mod local_user_vote_display_mode;
 pub mod login_token;
 pub mod moderator;
@@ -33,14 +52,27 @@ pub mod password_reset_request;
 pub mod person;
 pub mod person_block;
 pub mod person_mention;
+pub mod person_mod_note;
+pub mod person_pin;
+pub mod pm_conversation;
+pub mod poll;
 pub mod post;
+pub mod post_media;
 pub mod post_report;
 pub mod private_message;
 pub mod private_message_report;
 pub mod registration_application;
+pub mod registration_question;
+pub mod registration_source;
+pub mod report_note;
+pub mod report_reason_category;
 pub mod secret;
 pub mod site;
+pub mod spam_score;
 pub mod tagline;
+pub mod transparency_report;
+pub mod vote_anomaly;
+pub mod webauthn_credential;
 
 /// Default value for columns like [community::Community.inbox_url] which are marked as serde(skip).
 ///
@@ -1,10 +1,12 @@
 use crate::newtypes::{CommentId, CommunityId, PersonId, PostId};
 #[cfg(feature = "full")]
 use crate::schema::{
+  admin_freeze_account,
   admin_purge_comment,
   admin_purge_community,
   admin_purge_person,
   admin_purge_post,
+  admin_shadow_ban_person,
   mod_add,
   mod_add_community,
   mod_ban,
@@ -15,7 +17,9 @@ use crate::schema::{
   mod_remove_comment,
   mod_remove_community,
   mod_remove_post,
+  mod_resolve_ban_appeal,
   mod_transfer_community,
+  mod_warn_person,
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -38,6 +42,8 @@ pub struct ModRemovePost {
   pub reason: Option<String>,
   pub removed: bool,
   pub when_: DateTime<Utc>,
+  /// True if a community bot performed the action, which is then attributed to the community.
+  pub automod: bool,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -47,6 +53,7 @@ pub struct ModRemovePostForm {
   pub post_id: PostId,
   pub reason: Option<String>,
   pub removed: Option<bool>,
+  pub automod: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
@@ -61,6 +68,8 @@ pub struct ModLockPost {
   pub post_id: PostId,
   pub locked: bool,
   pub when_: DateTime<Utc>,
+  /// True if a community bot performed the action, which is then attributed to the community.
+  pub automod: bool,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -69,6 +78,7 @@ pub struct ModLockPostForm {
   pub mod_person_id: PersonId,
   pub post_id: PostId,
   pub locked: Option<bool>,
+  pub automod: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
@@ -84,6 +94,8 @@ pub struct ModFeaturePost {
   pub featured: bool,
   pub when_: DateTime<Utc>,
   pub is_featured_community: bool,
+  /// True if a community bot performed the action, which is then attributed to the community.
+  pub automod: bool,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -93,6 +105,7 @@ pub struct ModFeaturePostForm {
   pub post_id: PostId,
   pub featured: bool,
   pub is_featured_community: bool,
+  pub automod: bool,
 }
 
 #[skip_serializing_none]
@@ -110,6 +123,8 @@ pub struct ModRemoveComment {
   pub reason: Option<String>,
   pub removed: bool,
   pub when_: DateTime<Utc>,
+  /// True if a community bot performed the action, which is then attributed to the community.
+  pub automod: bool,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -119,6 +134,7 @@ pub struct ModRemoveCommentForm {
   pub comment_id: CommentId,
   pub reason: Option<String>,
   pub removed: Option<bool>,
+  pub automod: bool,
 }
 
 #[skip_serializing_none]
@@ -301,6 +317,122 @@ pub struct ModAddForm {
   pub removed: Option<bool>,
 }
 
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_warn_person))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When someone is warned, in a community or for the whole site.
+pub struct ModWarnPerson {
+  pub id: i32,
+  pub mod_person_id: PersonId,
+  pub other_person_id: PersonId,
+  /// Unset for warnings by admins, which apply to the whole site.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+  /// How many strikes the warning counts as until it expires.
+  pub strikes: i32,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub expires: Option<DateTime<Utc>>,
+  /// Whether the warned person has seen the warning.
+  pub read: bool,
+  pub when_: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_warn_person))]
+pub struct ModWarnPersonForm {
+  pub mod_person_id: PersonId,
+  pub other_person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub reason: String,
+  pub strikes: Option<i32>,
+  pub expires: Option<DateTime<Utc>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_resolve_ban_appeal))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When an appeal against a community ban is accepted or denied.
+pub struct ModResolveBanAppeal {
+  pub id: i32,
+  pub mod_person_id: PersonId,
+  pub other_person_id: PersonId,
+  pub community_id: CommunityId,
+  /// Whether the ban was lifted.
+  pub accepted: bool,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+  pub when_: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_resolve_ban_appeal))]
+pub struct ModResolveBanAppealForm {
+  pub mod_person_id: PersonId,
+  pub other_person_id: PersonId,
+  pub community_id: CommunityId,
+  pub accepted: bool,
+  pub reason: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = admin_freeze_account))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When an admin freezes or unfreezes a local account. Only visible to admins.
+pub struct AdminFreezeAccount {
+  pub id: i32,
+  pub admin_person_id: PersonId,
+  pub other_person_id: PersonId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+  pub frozen: bool,
+  pub when_: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = admin_freeze_account))]
+pub struct AdminFreezeAccountForm {
+  pub admin_person_id: PersonId,
+  pub other_person_id: PersonId,
+  pub reason: Option<String>,
+  pub frozen: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = admin_shadow_ban_person))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When an admin shadow bans or unbans a person. Only visible to admins.
+pub struct AdminShadowBanPerson {
+  pub id: i32,
+  pub admin_person_id: PersonId,
+  pub other_person_id: PersonId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reason: Option<String>,
+  pub shadow_banned: bool,
+  pub when_: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = admin_shadow_ban_person))]
+pub struct AdminShadowBanPersonForm {
+  pub admin_person_id: PersonId,
+  pub other_person_id: PersonId,
+  pub reason: Option<String>,
+  pub shadow_banned: bool,
+}
+
 #[skip_serializing_none]
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
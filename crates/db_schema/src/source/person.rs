@@ -64,6 +64,10 @@ pub struct Person {
   #[cfg_attr(feature = "full", ts(optional))]
   pub ban_expires: Option<DateTime<Utc>>,
   pub instance_id: InstanceId,
+  /// Whether the person is shadow banned. Hidden from everyone so that the person doesn't notice.
+  #[cfg_attr(feature = "full", ts(skip))]
+  #[serde(skip)]
+  pub shadow_banned: bool,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -127,6 +131,7 @@ pub struct PersonUpdateForm {
   pub matrix_user_id: Option<Option<String>>,
   pub bot_account: Option<bool>,
   pub ban_expires: Option<Option<DateTime<Utc>>>,
+  pub shadow_banned: Option<bool>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
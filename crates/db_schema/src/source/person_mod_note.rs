@@ -0,0 +1,47 @@
+use crate::newtypes::{CommunityId, PersonId, PersonModNoteId};
+#[cfg(feature = "full")]
+use crate::schema::person_mod_note;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = person_mod_note))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A private note which a moderator or admin left on a person.
+pub struct PersonModNote {
+  pub id: PersonModNoteId,
+  /// The person the note is about.
+  pub person_id: PersonId,
+  /// Unset for instance-wide notes, which only admins can see.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  pub creator_id: PersonId,
+  pub content: String,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = person_mod_note))]
+pub struct PersonModNoteInsertForm {
+  pub person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub creator_id: PersonId,
+  pub content: String,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = person_mod_note))]
+pub struct PersonModNoteUpdateForm {
+  pub content: String,
+  pub updated: DateTime<Utc>,
+}
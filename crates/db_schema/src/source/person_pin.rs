@@ -0,0 +1,35 @@
+use crate::newtypes::{CommentId, PersonId, PersonPinId, PostId};
+#[cfg(feature = "full")]
+use crate::schema::person_pin;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = person_pin))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A post or comment which a person pinned to their own profile. Exactly one of `post_id` and
+/// `comment_id` is set.
+pub struct PersonPin {
+  pub id: PersonPinId,
+  pub person_id: PersonId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub post_id: Option<PostId>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub comment_id: Option<CommentId>,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = person_pin))]
+pub struct PersonPinForm {
+  pub person_id: PersonId,
+  pub post_id: Option<PostId>,
+  pub comment_id: Option<CommentId>,
+}
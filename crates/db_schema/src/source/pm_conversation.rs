@@ -0,0 +1,83 @@
+use crate::newtypes::{DbUrl, PersonId, PmConversationId};
+#[cfg(feature = "full")]
+use crate::schema::{pm_conversation, pm_conversation_participant};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = pm_conversation))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A conversation which groups private messages. Conversations between two persons are created
+/// automatically when the first message is sent, group conversations are created explicitly.
+pub struct PmConversation {
+  pub id: PmConversationId,
+  /// The participant with the lower id. Empty for group conversations.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub person_a_id: Option<PersonId>,
+  /// The participant with the higher id. Empty for group conversations.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub person_b_id: Option<PersonId>,
+  pub published: DateTime<Utc>,
+  pub last_message_published: DateTime<Utc>,
+  /// Group conversations have any number of participants, which are listed separately.
+  pub is_group: bool,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub name: Option<String>,
+  /// The person who created the group and manages its participants.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub creator_id: Option<PersonId>,
+  /// Identifies group conversations across instances.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub ap_id: Option<DbUrl>,
+  pub local: bool,
+  /// Set while the conversation is a message request, which this person still has to accept.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub request_recipient_id: Option<PersonId>,
+  /// The Matrix room where the conversation is bridged to, if the instance has a Matrix bridge.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub matrix_room_id: Option<String>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = pm_conversation))]
+pub struct PmGroupConversationInsertForm {
+  pub name: Option<String>,
+  #[new(value = "true")]
+  pub is_group: bool,
+  #[new(default)]
+  pub creator_id: Option<PersonId>,
+  #[new(default)]
+  pub ap_id: Option<DbUrl>,
+  #[new(default)]
+  pub local: Option<bool>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = pm_conversation_participant))]
+#[cfg_attr(feature = "full", diesel(primary_key(conversation_id, person_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A member of a group conversation.
+pub struct PmConversationParticipant {
+  pub conversation_id: PmConversationId,
+  pub person_id: PersonId,
+  pub published: DateTime<Utc>,
+  /// Messages which were published after this time are unread.
+  pub last_read: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = pm_conversation_participant))]
+pub struct PmConversationParticipantForm {
+  pub conversation_id: PmConversationId,
+  pub person_id: PersonId,
+}
@@ -0,0 +1,65 @@
+use crate::newtypes::{PersonId, PollId, PollOptionId, PostId};
+#[cfg(feature = "full")]
+use crate::schema::{poll, poll_option, poll_vote};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = poll))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A poll which is attached to a post.
+pub struct Poll {
+  pub id: PollId,
+  pub post_id: PostId,
+  /// Whether voters can pick more than one option.
+  pub multiple_choice: bool,
+  /// After this time no more votes are accepted.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub end_time: Option<DateTime<Utc>>,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = poll))]
+pub struct PollInsertForm {
+  pub post_id: PostId,
+  pub multiple_choice: bool,
+  #[new(default)]
+  pub end_time: Option<DateTime<Utc>>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = poll_option))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// One of the answers of a poll.
+pub struct PollOption {
+  pub id: PollOptionId,
+  pub poll_id: PollId,
+  pub name: String,
+  /// The order in which the options are shown, starting at 0.
+  pub position: i32,
+  pub vote_count: i32,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = poll_vote))]
+#[cfg_attr(feature = "full", diesel(primary_key(poll_option_id, person_id)))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PollVote {
+  pub poll_id: PollId,
+  pub poll_option_id: PollOptionId,
+  pub person_id: PersonId,
+  pub published: DateTime<Utc>,
+}
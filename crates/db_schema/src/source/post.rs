@@ -67,6 +67,14 @@ pub struct Post {
   /// Time at which the post will be published. None means publish immediately.
   #[cfg_attr(feature = "full", ts(optional))]
   pub scheduled_publish_time: Option<DateTime<Utc>>,
+  /// Whether the body was moved to the archive. It is filled in again when the post is read.
+  pub archived: bool,
+  /// Whether the post waits for approval by a moderator. Until then it is only visible to its
+  /// creator and the moderators, and isn't federated.
+  pub pending: bool,
+  /// Time at which a temporarily locked post is unlocked again.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub lock_expires: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, derive_new::new)]
@@ -116,6 +124,8 @@ pub struct PostInsertForm {
   pub alt_text: Option<String>,
   #[new(default)]
   pub scheduled_publish_time: Option<DateTime<Utc>>,
+  #[new(default)]
+  pub pending: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -143,6 +153,8 @@ pub struct PostUpdateForm {
   pub url_content_type: Option<Option<String>>,
   pub alt_text: Option<Option<String>>,
   pub scheduled_publish_time: Option<Option<DateTime<Utc>>>,
+  pub pending: Option<bool>,
+  pub lock_expires: Option<Option<DateTime<Utc>>>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -190,6 +202,7 @@ pub struct PostSaved {
   #[cfg_attr(feature = "full", diesel(select_expression = post_actions::saved.assume_not_null()))]
   #[cfg_attr(feature = "full", diesel(select_expression_type = dsl::AssumeNotNull<post_actions::saved>))]
   pub published: DateTime<Utc>,
+  pub saved_note: Option<String>,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -197,6 +210,7 @@ pub struct PostSaved {
 pub struct PostSavedForm {
   pub post_id: PostId,
   pub person_id: PersonId,
+  pub saved_note: Option<String>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
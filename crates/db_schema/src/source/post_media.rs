@@ -0,0 +1,33 @@
+use crate::newtypes::{DbUrl, PostId, PostMediaId};
+#[cfg(feature = "full")]
+use crate::schema::post_media;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = post_media))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An image of a gallery post. These are shown after the post url.
+pub struct PostMedia {
+  pub id: PostMediaId,
+  pub post_id: PostId,
+  /// The order in which the images are shown, starting at 0.
+  pub position: i32,
+  pub url: DbUrl,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub alt_text: Option<String>,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, derive_new::new)]
+pub struct PostMediaForm {
+  pub url: DbUrl,
+  #[new(default)]
+  pub alt_text: Option<String>,
+}
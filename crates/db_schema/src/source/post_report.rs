@@ -1,6 +1,9 @@
-use crate::newtypes::{DbUrl, PersonId, PostId, PostReportId};
 #[cfg(feature = "full")]
 use crate::schema::post_report;
+use crate::{
+  newtypes::{DbUrl, PersonId, PostId, PostReportI},
+  ReportOutcome,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -37,6 +40,12 @@ pub struct PostReport {
   pub published: DateTime<Utc>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub updated: Option<DateTime<Utc>>,
+  /// How the report was handled, empty for pending reports.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub outcome: Option<ReportOutcome>,
+  /// The moderator who claimed the report.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub assigned_to: Option<PersonId>,
 }
 
 #[derive(Clone, Default)]
@@ -1,4 +1,4 @@
-use crate::newtypes::{DbUrl, PersonId, PrivateMessageId};
+use crate::newtypes::{DbUrl, PersonId, PmConversationId, PrivateMessageId};
 #[cfg(feature = "full")]
 use crate::schema::private_message;
 use chrono::{DateTime, Utc};
@@ -24,7 +24,9 @@ use ts_rs::TS;
 pub struct PrivateMessage {
   pub id: PrivateMessageId,
   pub creator_id: PersonId,
-  pub recipient_id: PersonId,
+  /// Empty for messages in group conversations.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub recipient_id: Option<PersonId>,
   pub content: String,
   pub deleted: bool,
   pub read: bool,
@@ -33,6 +35,8 @@ pub struct PrivateMessage {
   pub updated: Option<DateTime<Utc>>,
   pub ap_id: DbUrl,
   pub local: bool,
+  /// The conversation between creator and recipient which this message belongs to.
+  pub conversation_id: PmConversationId,
 }
 
 #[derive(Clone, derive_new::new)]
@@ -40,7 +44,7 @@ pub struct PrivateMessage {
 #[cfg_attr(feature = "full", diesel(table_name = private_message))]
 pub struct PrivateMessageInsertForm {
   pub creator_id: PersonId,
-  pub recipient_id: PersonId,
+  pub recipient_id: Option<PersonId>,
   pub content: String,
   #[new(default)]
   pub deleted: Option<bool>,
@@ -54,6 +58,9 @@ pub struct PrivateMessageInsertForm {
   pub ap_id: Option<DbUrl>,
   #[new(default)]
   pub local: Option<bool>,
+  /// Only set for group messages, otherwise the conversation is found by creator and recipient.
+  #[new(default)]
+  pub conversation_id: Option<PmConversationId>,
 }
 
 #[derive(Clone, Default)]
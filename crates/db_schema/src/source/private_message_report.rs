@@ -1,6 +1,9 @@
-use crate::newtypes::{PersonId, PrivateMessageId, PrivateMessageReportId};
 #[cfg(feature = "full")]
 use crate::schema::private_message_report;
+use crate::{
+  newtypes::{PersonId, PrivateMessageId, PrivateMessageReportI},
+  ReportOutcome,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -34,6 +37,12 @@ pub struct PrivateMessageReport {
   pub published: DateTime<Utc>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub updated: Option<DateTime<Utc>>,
+  /// How the report was handled, empty for pending reports.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub outcome: Option<ReportOutcome>,
+  /// The moderator who claimed the report.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub assigned_to: Option<PersonId>,
 }
 
 #[derive(Clone)]
@@ -17,7 +17,6 @@ use ts_rs::TS;
 pub struct RegistrationApplication {
   pub id: RegistrationApplicationId,
   pub local_user_id: LocalUserId,
-  pub answer: String,
   #[cfg_attr(feature = "full", ts(optional))]
   pub admin_id: Option<PersonId>,
   #[cfg_attr(feature = "full", ts(optional))]
@@ -29,7 +28,6 @@ pub struct RegistrationApplication {
 #[cfg_attr(feature = "full", diesel(table_name = registration_application))]
 pub struct RegistrationApplicationInsertForm {
   pub local_user_id: LocalUserId,
-  pub answer: String,
 }
 
 #[cfg_attr(feature = "full", derive(AsChangeset))]
@@ -0,0 +1,76 @@
+use crate::newtypes::{RegistrationApplicationId, RegistrationQuestionId};
+#[cfg(feature = "full")]
+use crate::schema::{registration_answer, registration_question};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_question))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A question which new users have to answer in their registration application.
+pub struct RegistrationQuestion {
+  pub id: RegistrationQuestionId,
+  pub question: String,
+  /// Whether the application can be submitted without answering this question.
+  pub required: bool,
+  /// Questions are shown in ascending order of their position.
+  pub position: i32,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_question))]
+pub struct RegistrationQuestionInsertForm {
+  pub question: String,
+  #[new(default)]
+  pub required: Option<bool>,
+  #[new(default)]
+  pub position: Option<i32>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_question))]
+pub struct RegistrationQuestionUpdateForm {
+  pub question: Option<String>,
+  pub required: Option<bool>,
+  pub position: Option<i32>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_answer))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The answer to a registration question, given in a registration application.
+pub struct RegistrationAnswer {
+  pub id: i32,
+  pub registration_application_id: RegistrationApplicationId,
+  /// Unset if the question was deleted after the application was submitted.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub question_id: Option<RegistrationQuestionId>,
+  /// The question text at the time the application was submitted.
+  pub question: String,
+  pub answer: String,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_answer))]
+pub struct RegistrationAnswerInsertForm {
+  pub registration_application_id: RegistrationApplicationId,
+  pub question_id: Option<RegistrationQuestionId>,
+  pub question: String,
+  pub answer: String,
+}
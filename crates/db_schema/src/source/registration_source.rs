@@ -0,0 +1,55 @@
+use crate::newtypes::PersonId;
+#[cfg(feature = "full")]
+use crate::schema::registration_source;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_source))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+/// The network from which a local account was registered, used to throttle registration waves.
+pub struct RegistrationSource {
+  pub id: i32,
+  pub person_id: PersonId,
+  /// The /24 subnet for IPv4, or the /48 subnet for IPv6.
+  pub ip_subnet: String,
+  /// The autonomous system number, if a MaxMind ASN database is configured.
+  pub asn: Option<i32>,
+  pub asn_organization: Option<String>,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = registration_source))]
+pub struct RegistrationSourceForm {
+  pub person_id: PersonId,
+  pub ip_subnet: String,
+  #[new(default)]
+  pub asn: Option<i32>,
+  #[new(default)]
+  pub asn_organization: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Several registrations from the same IP subnet or autonomous system within a short time.
+/// Exactly one of `ip_subnet` and `asn` is set.
+pub struct RegistrationBurst {
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub ip_subnet: Option<String>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub asn: Option<i32>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub asn_organization: Option<String>,
+  pub registrations: i64,
+  pub first_registration: DateTime<Utc>,
+  pub last_registration: DateTime<Utc>,
+}
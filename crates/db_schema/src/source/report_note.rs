@@ -0,0 +1,42 @@
+use crate::newtypes::{CommentReportId, PersonId, ReportNoteId};
+#[cfg(feature = "full")]
+use crate::schema::report_note;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = report_note))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An internal note which a moderator left on a comment report.
+pub struct ReportNote {
+  pub id: ReportNoteId,
+  pub comment_report_id: CommentReportId,
+  pub creator_id: PersonId,
+  pub content: String,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = report_note))]
+pub struct ReportNoteInsertForm {
+  pub comment_report_id: CommentReportId,
+  pub creator_id: PersonId,
+  pub content: String,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = report_note))]
+pub struct ReportNoteUpdateForm {
+  pub content: String,
+  pub updated: DateTime<Utc>,
+}
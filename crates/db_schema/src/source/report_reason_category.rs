@@ -0,0 +1,38 @@
+use crate::newtypes::ReportReasonCategoryId;
+#[cfg(feature = "full")]
+use crate::schema::report_reason_category;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = report_reason_category))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A structured report reason, like spam or harassment, which reporters can pick.
+pub struct ReportReasonCategory {
+  pub id: ReportReasonCategoryId,
+  pub name: String,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = report_reason_category))]
+pub struct ReportReasonCategoryInsertForm {
+  pub name: String,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = report_reason_category))]
+pub struct ReportReasonCategoryUpdateForm {
+  pub name: String,
+  pub updated: DateTime<Utc>,
+}
@@ -0,0 +1,57 @@
+#[cfg(feature = "full")]
+use crate::schema::spam_score;
+use crate::{
+  newtypes::{CommentId, PersonId, PostId, SpamScoreId},
+  SpamAction,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = spam_score))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The spam score which was calculated for a new local post or comment.
+pub struct SpamScore {
+  pub id: SpamScoreId,
+  pub creator_id: PersonId,
+  /// For comments, the post which the comment belongs to.
+  pub post_id: PostId,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub comment_id: Option<CommentId>,
+  pub score: i32,
+  pub action: SpamAction,
+  /// Whether an admin approved or rejected the content after review.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub approved: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reviewer_id: Option<PersonId>,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = spam_score))]
+pub struct SpamScoreInsertForm {
+  pub creator_id: PersonId,
+  pub post_id: PostId,
+  pub comment_id: Option<CommentId>,
+  pub score: i32,
+  pub action: SpamAction,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = spam_score))]
+pub struct SpamScoreUpdateForm {
+  pub approved: Option<Option<bool>>,
+  pub reviewer_id: Option<Option<PersonId>>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
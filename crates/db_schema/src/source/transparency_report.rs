@@ -0,0 +1,54 @@
+use crate::newtypes::{CommunityId, TransparencyReportId};
+#[cfg(feature = "full")]
+use crate::schema::transparency_report;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = transparency_report))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Counts of moderation actions and reports during one quarter, for a local community or the
+/// whole instance. Generated by a scheduled task after the quarter ends.
+pub struct TransparencyReport {
+  pub id: TransparencyReportId,
+  /// None for the instance-wide report.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community_id: Option<CommunityId>,
+  pub period_start: DateTime<Utc>,
+  pub period_end: DateTime<Utc>,
+  pub posts_removed: i64,
+  pub comments_removed: i64,
+  /// Only counted for the instance-wide report.
+  pub communities_removed: i64,
+  /// Only counted for the instance-wide report.
+  pub site_bans: i64,
+  pub community_bans: i64,
+  pub post_reports: i64,
+  pub comment_reports: i64,
+  /// Only counted for the instance-wide report.
+  pub private_message_reports: i64,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = transparency_report))]
+pub struct TransparencyReportForm {
+  pub community_id: Option<CommunityId>,
+  pub period_start: DateTime<Utc>,
+  pub period_end: DateTime<Utc>,
+  pub posts_removed: i64,
+  pub comments_removed: i64,
+  pub communities_removed: i64,
+  pub site_bans: i64,
+  pub community_bans: i64,
+  pub post_reports: i64,
+  pub comment_reports: i64,
+  pub private_message_reports: i64,
+}
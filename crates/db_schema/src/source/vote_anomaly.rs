@@ -0,0 +1,64 @@
+#[cfg(feature = "full")]
+use crate::schema::vote_anomaly;
+use crate::{
+  newtypes::{InstanceId, PersonId, PostId, VoteAnomalyId},
+  VoteAnomalyKind,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = vote_anomaly))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A suspicious voting pattern which was found by the scheduled vote analysis.
+pub struct VoteAnomaly {
+  pub id: VoteAnomalyId,
+  pub kind: VoteAnomalyKind,
+  /// For `SingleTargetUpvotes`, the person whose content all votes went to.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub person_id: Option<PersonId>,
+  /// For `InstanceVoteBurst`, the instance of the voters.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub instance_id: Option<InstanceId>,
+  /// For `InstanceVoteBurst`, the post which was voted on.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub post_id: Option<PostId>,
+  pub voter_ids: Vec<PersonId>,
+  /// Whether an admin removed the votes or dismissed the finding after review.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub votes_removed: Option<bool>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reviewer_id: Option<PersonId>,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = vote_anomaly))]
+pub struct VoteAnomalyInsertForm {
+  pub kind: VoteAnomalyKind,
+  pub voter_ids: Vec<PersonId>,
+  #[new(default)]
+  pub person_id: Option<PersonId>,
+  #[new(default)]
+  pub instance_id: Option<InstanceId>,
+  #[new(default)]
+  pub post_id: Option<PostId>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = vote_anomaly))]
+pub struct VoteAnomalyUpdateForm {
+  pub votes_removed: Option<Option<bool>>,
+  pub reviewer_id: Option<Option<PersonId>>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
@@ -0,0 +1,59 @@
+use crate::newtypes::{LocalUserId, WebauthnCredentialId};
+#[cfg(feature = "full")]
+use crate::schema::{webauthn_challenge, webauthn_credential};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = webauthn_credential))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A passkey or hardware key with which a local user can log in.
+pub struct WebauthnCredential {
+  pub id: WebauthnCredentialId,
+  pub local_user_id: LocalUserId,
+  pub credential_id: String,
+  #[serde(skip)]
+  pub passkey: String,
+  /// Chosen by the user, to tell their keys apart.
+  pub name: String,
+  pub published: DateTime<Utc>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub last_used: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = webauthn_credential))]
+pub struct WebauthnCredentialInsertForm {
+  pub local_user_id: LocalUserId,
+  pub credential_id: String,
+  pub passkey: String,
+  pub name: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "full", derive(Queryable, Selectable))]
+#[cfg_attr(feature = "full", diesel(table_name = webauthn_challenge))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+/// The server side state of a passkey registration or login which is in progress.
+pub struct WebauthnChallenge {
+  pub uuid: Uuid,
+  pub local_user_id: LocalUserId,
+  pub state: String,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, derive_new::new)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = webauthn_challenge))]
+pub struct WebauthnChallengeForm {
+  pub local_user_id: LocalUserId,
+  pub state: String,
+}
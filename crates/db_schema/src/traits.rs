@@ -1,6 +1,7 @@
 use crate::{
   newtypes::{CommunityId, DbUrl, PersonId},
   utils::{get_conn, uplete, DbPool},
+  ReportOutcome,
 };
 use diesel::{
   associations::HasTable,
@@ -153,9 +154,11 @@ pub trait Reportable {
     pool: &mut DbPool<'_>,
     report_id: Self::IdType,
     resolver_id: PersonId,
+    outcome: ReportOutcome,
   ) -> Result<usize, Error>
   where
     Self: Sized;
+  /// Resolves all pending reports for an object which was removed.
   async fn resolve_all_for_object(
     pool: &mut DbPool<'_>,
     comment_id_: Self::ObjectIdType,
@@ -170,6 +173,14 @@ pub trait Reportable {
   ) -> Result<usize, Error>
   where
     Self: Sized;
+  /// Assigns the report to a moderator, or removes the assignment with `None`.
+  async fn assign(
+    pool: &mut DbPool<'_>,
+    report_id: Self::IdType,
+    assignee_id: Option<PersonId>,
+  ) -> Result<usize, Error>
+  where
+    Self: Sized;
 }
 
 #[async_trait]
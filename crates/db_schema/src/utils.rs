@@ -1,3 +1,5 @@
+pub mod cache_invalidation;
+pub mod partition;
 pub mod uplete;
 
 use crate::{newtypes::DbUrl, CommentSortType, PostSortType};
@@ -29,7 +31,7 @@ use diesel::{
 use diesel_async::{
   pg::AsyncPgConnection,
   pooled_connection::{
-    deadpool::{Hook, HookError, Object as PooledConnection, Pool},
+    deadpool::{Hook, HookError, Object as PooledConnection, Pool, PoolError},
     AsyncDieselConnectionManager,
     ManagerConfig,
   },
@@ -59,10 +61,15 @@ use rustls::{
 };
 use std::{
   ops::{Deref, DerefMut},
-  sync::{Arc, LazyLock, OnceLock},
-  time::Duration,
+  sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+    LazyLock,
+    OnceLock,
+  },
+  time::{Duration, Instant},
 };
-use tracing::error;
+use tracing::{error, warn};
 use url::Url;
 
 const FETCH_LIMIT_DEFAULT: i64 = 10;
@@ -85,23 +92,80 @@ pub enum DbPool<'a> {
 }
 
 pub enum DbConn<'a> {
-  Pool(PooledConnection<AsyncPgConnection>),
+  /// A connection from the pool, and a timer started when it was taken from the pool
+  Pool(PooledConnection<AsyncPgConnection>, ConnectionTimer),
   Conn(&'a mut AsyncPgConnection),
 }
 
+/// Counters for connection pool usage, which are exported by the metrics endpoint.
+pub struct DbPoolStats {
+  /// Number of connections taken from the pool
+  pub acquired: AtomicU64,
+  /// Total time spent waiting for connections from the pool (in microseconds)
+  pub acquire_micros: AtomicU64,
+  /// Number of times that no connection was available before the timeout
+  pub timeouts: AtomicU64,
+  /// Number of times that a connection was used for longer than the slow query threshold
+  pub slow_queries: AtomicU64,
+}
+
+pub static DB_POOL_STATS: DbPoolStats = DbPoolStats {
+  acquired: AtomicU64::new(0),
+  acquire_micros: AtomicU64::new(0),
+  timeouts: AtomicU64::new(0),
+  slow_queries: AtomicU64::new(0),
+};
+
 pub async fn get_conn<'a, 'b: 'a>(pool: &'a mut DbPool<'b>) -> Result<DbConn<'a>, DieselError> {
   Ok(match pool {
-    DbPool::Pool(pool) => DbConn::Pool(pool.get().await.map_err(|e| QueryBuilderError(e.into()))?),
+    DbPool::Pool(pool) => {
+      let start = Instant::now();
+      let conn = pool.get().await;
+      let waited = u64::try_from(start.elapsed().as_micros()).unwrap_or(u64::MAX);
+      DB_POOL_STATS
+        .acquire_micros
+        .fetch_add(waited, Ordering::Relaxed);
+      match conn {
+        Ok(conn) => {
+          DB_POOL_STATS.acquired.fetch_add(1, Ordering::Relaxed);
+          DbConn::Pool(conn, ConnectionTimer(Instant::now()))
+        }
+        Err(e) => {
+          if matches!(e, PoolError::Timeout(_)) {
+            DB_POOL_STATS.timeouts.fetch_add(1, Ordering::Relaxed);
+            warn!("Timed out waiting for a database connection, the pool is exhausted");
+          }
+          return Err(QueryBuilderError(e.into()));
+        }
+      }
+    }
     DbPool::Conn(conn) => DbConn::Conn(conn),
   })
 }
 
+/// Logs a warning when it is dropped after the slow query threshold. The timer is kept in a
+/// separate type, because a `Drop` impl for `DbConn` would make the borrow of the pool last until
+/// the end of the scope.
+pub struct ConnectionTimer(Instant);
+
+impl Drop for ConnectionTimer {
+  fn drop(&mut self) {
+    let threshold = SETTINGS.database.slow_query_threshold;
+    let elapsed = self.0.elapsed();
+    if threshold > 0 && elapsed > Duration::from_millis(threshold) {
+      DB_POOL_STATS.slow_queries.fetch_add(1, Ordering::Relaxed);
+      // The enclosing spans show which view or function made the query
+      warn!("Slow query: database connection was used for {elapsed:?}");
+    }
+  }
+}
+
 impl<'a> Deref for DbConn<'a> {
   type Target = AsyncPgConnection;
 
   fn deref(&self) -> &Self::Target {
     match self {
-      DbConn::Pool(conn) => conn.deref(),
+      DbConn::Pool(conn, _) => conn.deref(),
       DbConn::Conn(conn) => conn.deref(),
     }
   }
@@ -110,7 +174,7 @@ impl<'a> Deref for DbConn<'a> {
 impl<'a> DerefMut for DbConn<'a> {
   fn deref_mut(&mut self) -> &mut Self::Target {
     match self {
-      DbConn::Pool(conn) => conn.deref_mut(),
+      DbConn::Pool(conn, _) => conn.deref_mut(),
       DbConn::Conn(conn) => conn.deref_mut(),
     }
   }
@@ -276,19 +340,24 @@ pub fn limit_and_offset(
     }
     None => 1,
   };
-  let limit = match limit {
+  let limit = limit_fetch(limit)?;
+  let offset = limit * (page - 1);
+  Ok((limit, offset))
+}
+
+/// The number of items to fetch, for lists which use cursors instead of page numbers.
+pub fn limit_fetch(limit: Option<i64>) -> Result<i64, diesel::result::Error> {
+  match limit {
     Some(limit) => {
       if !(1..=FETCH_LIMIT_MAX).contains(&limit) {
         return Err(QueryBuilderError(
           format!("Fetch limit is > {FETCH_LIMIT_MAX}").into(),
         ));
       }
-      limit
+      Ok(limit)
     }
-    None => FETCH_LIMIT_DEFAULT,
-  };
-  let offset = limit * (page - 1);
-  Ok((limit, offset))
+    None => Ok(FETCH_LIMIT_DEFAULT),
+  }
 }
 
 pub fn limit_and_offset_unlimited(page: Option<i64>, limit: Option<i64>) -> (i64, i64) {
@@ -322,6 +391,16 @@ pub fn diesel_required_string_update(opt: Option<&str>) -> Option<String> {
   }
 }
 
+/// Takes an API optional number input, and converts it to an optional diesel DB update.
+pub fn diesel_opt_number_update<T: PartialOrd + Default>(opt: Option<T>) -> Option<Option<T>> {
+  match opt {
+    // A negative number is an erase
+    Some(n) if n < T::default() => Some(None),
+    Some(n) => Some(Some(n)),
+    None => None,
+  }
+}
+
 /// Takes an optional API URL-type input, and converts it to an optional diesel DB update.
 /// Also cleans the url params.
 pub fn diesel_url_update(opt: Option<&str>) -> LemmyResult<Option<Option<DbUrl>>> {
@@ -390,14 +469,7 @@ fn establish_connection(config: &str) -> BoxFuture<ConnectionResult<AsyncPgConne
 
     // We only support TLS with sslmode=require currently
     let conn = if config.contains("sslmode=require") {
-      let rustls_config = DangerousClientConfigBuilder {
-        cfg: ClientConfig::builder(),
-      }
-      .with_custom_certificate_verifier(Arc::new(NoCertVerifier {}))
-      .with_no_client_auth();
-
-      let tls = tokio_postgres_rustls::MakeRustlsConnect::new(rustls_config);
-      let (client, conn) = tokio_postgres::connect(config, tls)
+      let (client, conn) = tokio_postgres::connect(config, tls_connector())
         .await
         .map_err(|e| ConnectionError::BadConnection(e.to_string()))?;
       tokio::spawn(async move {
@@ -415,6 +487,17 @@ fn establish_connection(config: &str) -> BoxFuture<ConnectionResult<AsyncPgConne
   fut.boxed()
 }
 
+/// Creates the TLS connector for postgres connections with sslmode=require.
+fn tls_connector() -> tokio_postgres_rustls::MakeRustlsConnect {
+  let rustls_config = DangerousClientConfigBuilder {
+    cfg: ClientConfig::builder(),
+  }
+  .with_custom_certificate_verifier(Arc::new(NoCertVerifier {}))
+  .with_no_client_auth();
+
+  tokio_postgres_rustls::MakeRustlsConnect::new(rustls_config)
+}
+
 #[derive(Debug)]
 struct NoCertVerifier {}
 
@@ -475,6 +558,7 @@ pub fn build_db_pool() -> LemmyResult<ActualDbPool> {
   let manager = AsyncDieselConnectionManager::<AsyncPgConnection>::new_with_config(&db_url, config);
   let pool = Pool::builder(manager)
     .max_size(SETTINGS.database.pool_size)
+    .wait_timeout(Some(Duration::from_secs(SETTINGS.database.pool_timeout)))
     .runtime(Runtime::Tokio1)
     // Limit connection age to prevent use of prepared statements that have query plans based on
     // very old statistics
@@ -525,12 +609,17 @@ pub mod functions {
 
   sql_function! {
     #[sql_name = "r.hot_rank"]
-    fn hot_rank(score: BigInt, time: Timestamptz) -> Double;
+    fn hot_rank(score: BigInt, time: Timestamptz, at_time: Timestamptz) -> Double;
   }
 
   sql_function! {
     #[sql_name = "r.scaled_rank"]
-    fn scaled_rank(score: BigInt, time: Timestamptz, users_active_month: BigInt) -> Double;
+    fn scaled_rank(
+      score: BigInt,
+      time: Timestamptz,
+      users_active_month: BigInt,
+      at_time: Timestamptz,
+    ) -> Double;
   }
 
   sql_function! {
@@ -544,6 +633,9 @@ pub mod functions {
 
   // really this function is variadic, this just adds the two-argument version
   sql_function!(fn coalesce<T: diesel::sql_types::SqlType + diesel::sql_types::SingleValue>(x: diesel::sql_types::Nullable<T>, y: T) -> T);
+
+  // Case insensitive match of a Postgres regular expression
+  diesel::infix_operator!(RegexMatchInsensitive, " ~* ", backend: diesel::pg::Pg);
 }
 
 pub const DELETED_REPLACEMENT_TEXT: &str = "*Permanently Deleted*";
@@ -574,6 +666,10 @@ type OnTupleEq<T, L0, L1, R0, R1> = dsl::On<T, dsl::Eq<AsRecordOutput<(L0, L1)>,
 /// Creates an `ON` clause for a table where a person ID and another column are used as the
 /// primary key. Use with the `QueryDsl::left_join` method.
 ///
+/// The person ID is compared with a bind parameter instead of another column, so that Postgres
+/// only reads the matching partition of tables in [partition::PARTITIONED_ACTION_TABLES]. Keep it
+/// that way when changing this.
+///
 /// This example modifies a query to make columns in `community_actions` available:
 ///
 /// ```
@@ -701,6 +797,7 @@ impl Queries<(), ()> {
 }
 
 impl<RF, LF> Queries<RF, LF> {
+  #[tracing::instrument(skip_all, fields(view = std::any::type_name::<T>()))]
   pub async fn read<'a, T, Args>(
     self,
     pool: &'a mut DbPool<'_>,
@@ -713,6 +810,7 @@ impl<RF, LF> Queries<RF, LF> {
     (self.read_fn)(conn, args).await
   }
 
+  #[tracing::instrument(skip_all, fields(view = std::any::type_name::<T>()))]
   pub async fn list<'a, T, Args>(
     self,
     pool: &'a mut DbPool<'_>,
@@ -757,6 +855,13 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_diesel_option_overwrite_number() {
+    assert_eq!(diesel_opt_number_update(None), None);
+    assert_eq!(diesel_opt_number_update(Some(-1)), Some(None));
+    assert_eq!(diesel_opt_number_update(Some(5)), Some(Some(5)));
+  }
+
   #[test]
   fn test_diesel_option_overwrite_to_url() -> LemmyResult<()> {
     assert!(matches!(diesel_url_update(None), Ok(None)));
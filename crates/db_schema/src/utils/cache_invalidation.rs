@@ -0,0 +1,151 @@
+//! Cache invalidation across all Lemmy processes which share a database, using Postgres
+//! `LISTEN`/`NOTIFY`. A process which changes cached data sends a notification, and every process
+//! including the sender drops its cached copy.
+
+use super::{get_conn, tls_connector, DbPool};
+use diesel::{result::Error, sql_types::Text};
+use diesel_async::RunQueryDsl;
+use futures_util::{
+  stream::{self, BoxStream},
+  Future,
+  StreamExt,
+};
+use lemmy_utils::{error::LemmyResult, settings::SETTINGS};
+use strum::{Display, EnumString};
+use tokio::sync::mpsc;
+use tokio_postgres::{AsyncMessage, Client, NoTls};
+use tracing::warn;
+
+const CACHE_INVALIDATION_CHANNEL: &str = "lemmy_cache_invalidation";
+
+/// Cached data which can be invalidated across processes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumString)]
+pub enum CacheInvalidation {
+  /// The local site and its settings.
+  SiteView,
+  /// The blocked URLs of the local site.
+  UrlBlocklist,
+  /// The rate limits of the local site.
+  RateLimits,
+}
+
+impl CacheInvalidation {
+  /// Tells all Lemmy processes using this database, including this one, to drop their cached
+  /// data.
+  pub async fn notify(self, pool: &mut DbPool<'_>) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::sql_query("SELECT pg_notify($1, $2)")
+      .bind::<Text, _>(CACHE_INVALIDATION_CHANNEL)
+      .bind::<Text, _>(self.to_string())
+      .execute(conn)
+      .await?;
+    Ok(())
+  }
+
+  /// Listens for cache invalidations from all Lemmy processes using this database, and passes
+  /// them to the handler. Only returns if the connection to the database is lost.
+  pub async fn listen<F, Fut>(mut handler: F) -> LemmyResult<()>
+  where
+    F: FnMut(CacheInvalidation) -> Fut,
+    Fut: Future<Output = ()>,
+  {
+    // Notifications are only delivered to the connection which listens for them, so this uses a
+    // dedicated connection instead of one from the pool.
+    let (client, mut messages) = connect_for_notifications(&SETTINGS.get_database_url()).await?;
+
+    // The connection needs to be driven while the client is used, so messages are read in a
+    // separate task.
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+      while let Some(message) = messages.next().await {
+        match message {
+          Ok(AsyncMessage::Notification(n)) if n.channel() == CACHE_INVALIDATION_CHANNEL => {
+            if sender.send(n.payload().to_string()).is_err() {
+              break;
+            }
+          }
+          Ok(_) => {}
+          Err(e) => {
+            warn!("Cache invalidation connection failed: {e}");
+            break;
+          }
+        }
+      }
+    });
+
+    client
+      .batch_execute(&format!("LISTEN {CACHE_INVALIDATION_CHANNEL}"))
+      .await?;
+
+    while let Some(payload) = receiver.recv().await {
+      match payload.parse::<CacheInvalidation>() {
+        Ok(cache) => handler(cache).await,
+        Err(_) => warn!("Received unknown cache invalidation {payload}"),
+      }
+    }
+
+    Err(anyhow::anyhow!("Lost database connection for cache invalidations").into())
+  }
+}
+
+type Messages = BoxStream<'static, Result<AsyncMessage, tokio_postgres::Error>>;
+
+async fn connect_for_notifications(
+  config: &str,
+) -> Result<(Client, Messages), tokio_postgres::Error> {
+  // We only support TLS with sslmode=require currently
+  if config.contains("sslmode=require") {
+    let (client, mut conn) = tokio_postgres::connect(config, tls_connector()).await?;
+    Ok((
+      client,
+      stream::poll_fn(move |cx| conn.poll_message(cx)).boxed(),
+    ))
+  } else {
+    let (client, mut conn) = tokio_postgres::connect(config, NoTls).await?;
+    Ok((
+      client,
+      stream::poll_fn(move |cx| conn.poll_message(cx)).boxed(),
+    ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::CacheInvalidation;
+  use crate::utils::build_db_pool_for_tests;
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+  use std::time::Duration;
+  use tokio::{sync::mpsc, time::timeout};
+
+  #[tokio::test]
+  #[serial]
+  async fn test_cache_invalidation() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    tokio::spawn(CacheInvalidation::listen(move |cache| {
+      let sender = sender.clone();
+      async move {
+        sender.send(cache).ok();
+      }
+    }));
+
+    // Keep notifying until the listener is set up
+    let received = timeout(Duration::from_secs(10), async {
+      loop {
+        CacheInvalidation::UrlBlocklist.notify(pool).await?;
+        if let Ok(Some(cache)) = timeout(Duration::from_millis(100), receiver.recv()).await {
+          return LemmyResult::Ok(cache);
+        }
+      }
+    })
+    .await??;
+    assert_eq!(CacheInvalidation::UrlBlocklist, received);
+
+    Ok(())
+  }
+}
@@ -0,0 +1,165 @@
+use crate::utils::{get_conn, DbPool};
+use diesel::{
+  result::Error,
+  sql_query,
+  sql_types::{BigInt, Text},
+  QueryableByName,
+};
+use diesel_async::{RunQueryDsl, SimpleAsyncConnection};
+
+/// Tables which are hash partitioned by `person_id`. Queries of these tables should always filter
+/// by person id, so that Postgres only has to read a single partition.
+pub const PARTITIONED_ACTION_TABLES: [&str; 2] = ["post_actions", "comment_actions"];
+
+/// Highest number of partitions which can be created per table.
+pub const MAX_PARTITIONS: i32 = 256;
+
+#[derive(QueryableByName)]
+struct PartitionCount {
+  #[diesel(sql_type = BigInt)]
+  count: i64,
+}
+
+/// The number of partitions of a partitioned table.
+pub async fn partition_count(pool: &mut DbPool<'_>, table: &str) -> Result<i64, Error> {
+  let conn = &mut get_conn(pool).await?;
+  sql_query("SELECT count(*) AS count FROM pg_inherits WHERE inhparent = $1::regclass")
+    .bind::<Text, _>(table)
+    .get_result::<PartitionCount>(conn)
+    .await
+    .map(|c| c.count)
+}
+
+/// Splits one of the [PARTITIONED_ACTION_TABLES] into the given number of partitions, and moves
+/// all rows into the new partitions. The table is locked until this is finished, so it should be
+/// run while the site is in maintenance mode.
+pub async fn repartition(pool: &mut DbPool<'_>, table: &str, count: i32) -> Result<(), Error> {
+  let Some(table) = PARTITIONED_ACTION_TABLES.into_iter().find(|t| *t == table) else {
+    return Err(Error::QueryBuilderError(
+      format!("{table} is not a partitioned table").into(),
+    ));
+  };
+  if !(1..=MAX_PARTITIONS).contains(&count) {
+    return Err(Error::QueryBuilderError(
+      format!("Partition count must be between 1 and {MAX_PARTITIONS}").into(),
+    ));
+  }
+
+  let conn = &mut get_conn(pool).await?;
+  // The old partitions are detached and renamed, then new ones are created with the same naming
+  // scheme and the rows are moved over. Indexes and constraints of the parent table are added to
+  // the new partitions automatically. Triggers are disabled while moving the rows, so that the
+  // aggregates aren't counted twice. Row triggers are cloned to each partition, and older Postgres
+  // versions don't disable those together with the parent table, so it's done for each one.
+  let sql = format!(
+    r#"
+    LOCK TABLE {table} IN ACCESS EXCLUSIVE MODE;
+    DO $$
+    DECLARE
+        old_partitions text[] := ARRAY (
+            SELECT
+                inhrelid::regclass::text
+            FROM
+                pg_inherits
+            WHERE
+                inhparent = '{table}'::regclass);
+        p text;
+    BEGIN
+        FOREACH p IN ARRAY old_partitions LOOP
+            EXECUTE format('ALTER TABLE {table} DETACH PARTITION %I', p);
+            EXECUTE format('ALTER TABLE %I RENAME TO %I', p, p || '_old');
+        END LOOP;
+        FOR i IN 0..{count} - 1 LOOP
+            EXECUTE format('CREATE TABLE {table}_%s PARTITION OF {table} FOR VALUES WITH (MODULUS {count}, REMAINDER %s)', i, i);
+        END LOOP;
+        ALTER TABLE {table} DISABLE TRIGGER USER;
+        FOR i IN 0..{count} - 1 LOOP
+            EXECUTE format('ALTER TABLE {table}_%s DISABLE TRIGGER USER', i);
+        END LOOP;
+        FOREACH p IN ARRAY old_partitions LOOP
+            EXECUTE format('INSERT INTO {table} SELECT * FROM %I', p || '_old');
+            EXECUTE format('DROP TABLE %I', p || '_old');
+        END LOOP;
+        FOR i IN 0..{count} - 1 LOOP
+            EXECUTE format('ALTER TABLE {table}_%s ENABLE TRIGGER USER', i);
+        END LOOP;
+        ALTER TABLE {table} ENABLE TRIGGER USER;
+    END
+    $$;
+    "#
+  );
+  conn
+    .build_transaction()
+    .run(|conn| Box::pin(async move { conn.batch_execute(&sql).await }) as _)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::*;
+  use crate::{
+    aggregates::structs::PostAggregates,
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm, PostLike, PostLikeForm},
+    },
+    traits::{Crud, Likeable},
+    utils::{build_db_pool_for_tests, uplete},
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_repartition() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm::test_form(inserted_instance.id, "partitioner");
+    let inserted_person = Person::create(pool, &person_form).await?;
+    let community_form = CommunityInsertForm::new(
+      inserted_instance.id,
+      "partitions".into(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &community_form).await?;
+    let post_form = PostInsertForm::new(
+      "partitioned post".into(),
+      inserted_person.id,
+      inserted_community.id,
+    );
+    let inserted_post = Post::create(pool, &post_form).await?;
+    let like_form = PostLikeForm {
+      post_id: inserted_post.id,
+      person_id: inserted_person.id,
+      score: 1,
+    };
+    PostLike::like(pool, &like_form).await?;
+
+    let original_count = partition_count(pool, "post_actions").await?;
+    repartition(pool, "post_actions", 4).await?;
+    assert_eq!(4, partition_count(pool, "post_actions").await?);
+
+    // The like was moved over, without being counted again
+    let aggregates = PostAggregates::read(pool, inserted_post.id).await?;
+    assert_eq!(1, aggregates.score);
+    let removed = PostLike::remove(pool, inserted_person.id, inserted_post.id).await?;
+    assert_eq!(uplete::Count::only_deleted(1), removed);
+    let aggregates = PostAggregates::read(pool, inserted_post.id).await?;
+    assert_eq!(0, aggregates.score);
+
+    assert!(repartition(pool, "person", 4).await.is_err());
+    assert!(repartition(pool, "post_actions", 0).await.is_err());
+
+    repartition(pool, "post_actions", i32::try_from(original_count)?).await?;
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,204 @@
+//! Builders which create test data in the database, so that tests only need a few lines of
+//! setup instead of inserting every row by hand.
+//!
+//! ```
+//! let instance = SeededInstance::create(pool, "my_domain.tld").await?;
+//! let (timmy, _) = instance.local_user(pool, "timmy").await?;
+//! let community = instance
+//!   .community("test_community")
+//!   .moderator(timmy.id)
+//!   .posts(2)
+//!   .comments_per_post(3)
+//!   .create(pool)
+//!   .await?;
+//! // ...
+//! instance.delete(pool).await?;
+//! ```
+
+use lemmy_db_schema::{
+  newtypes::PersonId,
+  source::{
+    comment::{Comment, CommentInsertForm},
+    community::{Community, CommunityInsertForm, CommunityModerator, CommunityModeratorForm},
+    instance::Instance,
+    local_user::{LocalUser, LocalUserInsertForm},
+    person::{Person, PersonInsertForm},
+    post::{Post, PostInsertForm},
+  },
+  traits::{Crud, Joinable},
+  utils::DbPool,
+};
+use lemmy_utils::error::LemmyResult;
+
+/// An instance which test data is created in. Deleting it also deletes everything created
+/// through it.
+pub struct SeededInstance {
+  pub instance: Instance,
+}
+
+impl SeededInstance {
+  pub async fn create(pool: &mut DbPool<'_>, domain: &str) -> LemmyResult<Self> {
+    let instance = Instance::read_or_create(pool, domain.to_string()).await?;
+    Ok(SeededInstance { instance })
+  }
+
+  /// Creates a person without a local user.
+  pub async fn person(&self, pool: &mut DbPool<'_>, name: &str) -> LemmyResult<Person> {
+    let form = PersonInsertForm::test_form(self.instance.id, name);
+    Ok(Person::create(pool, &form).await?)
+  }
+
+  /// Creates a person with a local user.
+  pub async fn local_user(
+    &self,
+    pool: &mut DbPool<'_>,
+    name: &str,
+  ) -> LemmyResult<(Person, LocalUser)> {
+    let person = self.person(pool, name).await?;
+    let form = LocalUserInsertForm::test_form(person.id);
+    let local_user = LocalUser::create(pool, &form, vec![]).await?;
+    Ok((person, local_user))
+  }
+
+  /// Creates a person with an admin account.
+  pub async fn admin(&self, pool: &mut DbPool<'_>, name: &str) -> LemmyResult<(Person, LocalUser)> {
+    let person = self.person(pool, name).await?;
+    let form = LocalUserInsertForm::test_form_admin(person.id);
+    let local_user = LocalUser::create(pool, &form, vec![]).await?;
+    Ok((person, local_user))
+  }
+
+  /// Starts building a community in this instance.
+  pub fn community(&self, name: &str) -> SeededCommunityBuilder<'_> {
+    SeededCommunityBuilder {
+      instance: self,
+      name: name.to_string(),
+      users: 0,
+      posts: 0,
+      comments_per_post: 0,
+      creator_id: None,
+      moderator_ids: vec![],
+    }
+  }
+
+  /// Deletes the instance, together with all persons, communities, posts and comments in it.
+  pub async fn delete(self, pool: &mut DbPool<'_>) -> LemmyResult<()> {
+    Instance::delete(pool, self.instance.id).await?;
+    Ok(())
+  }
+}
+
+/// A community with its generated users, posts and comments.
+pub struct SeededCommunity {
+  pub community: Community,
+  /// Persons created for this community, named `<community>_user_<n>`
+  pub users: Vec<Person>,
+  /// Posts in creation order
+  pub posts: Vec<Post>,
+  /// Comments in creation order, grouped by post
+  pub comments: Vec<Comment>,
+}
+
+pub struct SeededCommunityBuilder<'a> {
+  instance: &'a SeededInstance,
+  name: String,
+  users: usize,
+  posts: usize,
+  comments_per_post: usize,
+  creator_id: Option<PersonId>,
+  moderator_ids: Vec<PersonId>,
+}
+
+impl SeededCommunityBuilder<'_> {
+  /// Number of users to create. Posts and comments are spread over them, unless a creator is
+  /// set.
+  pub fn users(mut self, users: usize) -> Self {
+    self.users = users;
+    self
+  }
+
+  pub fn posts(mut self, posts: usize) -> Self {
+    self.posts = posts;
+    self
+  }
+
+  pub fn comments_per_post(mut self, comments_per_post: usize) -> Self {
+    self.comments_per_post = comments_per_post;
+    self
+  }
+
+  /// Person who creates all posts and comments.
+  pub fn creator(mut self, creator_id: PersonId) -> Self {
+    self.creator_id = Some(creator_id);
+    self
+  }
+
+  pub fn moderator(mut self, moderator_id: PersonId) -> Self {
+    self.moderator_ids.push(moderator_id);
+    self
+  }
+
+  pub async fn create(self, pool: &mut DbPool<'_>) -> LemmyResult<SeededCommunity> {
+    let instance_id = self.instance.instance.id;
+    let community_form = CommunityInsertForm::new(
+      instance_id,
+      self.name.clone(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+
+    for person_id in self.moderator_ids {
+      let form = CommunityModeratorForm {
+        community_id: community.id,
+        person_id,
+      };
+      CommunityModerator::join(pool, &form).await?;
+    }
+
+    // Content needs a creator, so create at least one user if none is given
+    let user_count = if self.creator_id.is_none() {
+      self.users.max(1)
+    } else {
+      self.users
+    };
+    let mut users = Vec::with_capacity(user_count);
+    for i in 0..user_count {
+      let name = format!("{}_user_{i}", self.name);
+      users.push(self.instance.person(pool, &name).await?);
+    }
+    let mut creator_ids = users.iter().map(|u| u.id).cycle();
+    let mut next_creator_id = || self.creator_id.or_else(|| creator_ids.next());
+
+    let mut posts = Vec::with_capacity(self.posts);
+    let mut comments = Vec::with_capacity(self.posts * self.comments_per_post);
+    for i in 0..self.posts {
+      let Some(creator_id) = next_creator_id() else {
+        break;
+      };
+      let post_form = PostInsertForm::new(
+        format!("Post {i} in {}", self.name),
+        creator_id,
+        community.id,
+      );
+      let post = Post::create(pool, &post_form).await?;
+
+      for j in 0..self.comments_per_post {
+        let Some(creator_id) = next_creator_id() else {
+          break;
+        };
+        let comment_form =
+          CommentInsertForm::new(creator_id, post.id, format!("Comment {j} on post {i}"));
+        comments.push(Comment::create(pool, &comment_form, None).await?);
+      }
+      posts.push(post);
+    }
+
+    Ok(SeededCommunity {
+      community,
+      users,
+      posts,
+      comments,
+    })
+  }
+}
@@ -0,0 +1,209 @@
+use crate::structs::BanAppealView;
+use diesel::{
+  dsl::exists,
+  result::Error,
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  aliases,
+  newtypes::{BanAppealId, CommunityId, PersonId},
+  schema::{ban_appeal, community, community_actions, person},
+  utils::{find_action, get_conn, limit_and_offset, DbPool},
+  BanAppealStatus,
+};
+
+impl BanAppealView {
+  pub async fn read(pool: &mut DbPool<'_>, id: BanAppealId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    ban_appeal::table
+      .find(id)
+      .inner_join(person::table.on(ban_appeal::creator_id.eq(person::id)))
+      .inner_join(community::table)
+      .left_join(
+        aliases::person1
+          .on(ban_appeal::resolver_id.eq(aliases::person1.field(person::id).nullable())),
+      )
+      .select((
+        ban_appeal::all_columns,
+        person::all_columns,
+        community::all_columns,
+        aliases::person1.fields(person::all_columns).nullable(),
+      ))
+      .first::<Self>(conn)
+      .await
+  }
+}
+
+#[derive(Default)]
+pub struct BanAppealQuery {
+  pub status: Option<BanAppealStatus>,
+  pub community_id: Option<CommunityId>,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+impl BanAppealQuery {
+  /// Lists the appeals in communities which the person moderates, or in all communities for
+  /// admins. The oldest appeals are shown first, so that they are handled in order.
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+    my_person_id: PersonId,
+    admin: bool,
+  ) -> Result<Vec<BanAppealView>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = ban_appeal::table
+      .inner_join(person::table.on(ban_appeal::creator_id.eq(person::id)))
+      .inner_join(community::table)
+      .left_join(
+        aliases::person1
+          .on(ban_appeal::resolver_id.eq(aliases::person1.field(person::id).nullable())),
+      )
+      .select((
+        ban_appeal::all_columns,
+        person::all_columns,
+        community::all_columns,
+        aliases::person1.fields(person::all_columns).nullable(),
+      ))
+      .into_boxed();
+
+    if !admin {
+      query = query.filter(exists(find_action(
+        community_actions::became_moderator,
+        (my_person_id, ban_appeal::community_id),
+      )));
+    }
+
+    if let Some(status) = self.status {
+      query = query.filter(ban_appeal::status.eq(status));
+    }
+
+    if let Some(community_id) = self.community_id {
+      query = query.filter(ban_appeal::community_id.eq(community_id));
+    }
+
+    let (limit, offset) = limit_and_offset(self.page, self.limit)?;
+
+    query
+      .order_by(ban_appeal::published.asc())
+      .limit(limit)
+      .offset(offset)
+      .load::<BanAppealView>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+
+  use crate::{ban_appeal_view::BanAppealQuery, structs::BanAppealView};
+  use lemmy_db_schema::{
+    source::{
+      ban_appeal::{BanAppeal, BanAppealInsertForm, BanAppealUpdateForm},
+      community::{Community, CommunityInsertForm, CommunityModerator, CommunityModeratorForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    traits::{Crud, Joinable},
+    utils::build_db_pool_for_tests,
+    BanAppealStatus,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_ban_appeals() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let appellant = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "ba_appellant"),
+    )
+    .await?;
+    let moderator = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "ba_moderator"),
+    )
+    .await?;
+    let other = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "ba_other"),
+    )
+    .await?;
+
+    let community = Community::create(
+      pool,
+      &CommunityInsertForm::new(
+        inserted_instance.id,
+        "test_ban_appeals".to_string(),
+        "nada".to_owned(),
+        "pubkey".to_string(),
+      ),
+    )
+    .await?;
+    CommunityModerator::join(
+      pool,
+      &CommunityModeratorForm {
+        community_id: community.id,
+        person_id: moderator.id,
+      },
+    )
+    .await?;
+
+    let appeal = BanAppeal::create(
+      pool,
+      &BanAppealInsertForm::new(appellant.id, community.id, "I am sorry".into()),
+    )
+    .await?;
+    assert_eq!(BanAppealStatus::Pending, appeal.status);
+    assert!(BanAppeal::exists_for_ban(pool, appellant.id, community.id, appeal.published).await?);
+
+    // Only moderators of the community and admins see the appeal
+    let mod_list = BanAppealQuery::default()
+      .list(pool, moderator.id, false)
+      .await?;
+    assert_eq!(1, mod_list.len());
+    assert_eq!(appellant.id, mod_list[0].creator.id);
+    let other_list = BanAppealQuery::default()
+      .list(pool, other.id, false)
+      .await?;
+    assert!(other_list.is_empty());
+    let admin_list = BanAppealQuery::default().list(pool, other.id, true).await?;
+    assert_eq!(1, admin_list.len());
+
+    BanAppeal::update(
+      pool,
+      appeal.id,
+      &BanAppealUpdateForm {
+        status: Some(BanAppealStatus::Denied),
+        deny_reason: Some(Some("No".into())),
+        resolver_id: Some(Some(moderator.id)),
+        ..Default::default()
+      },
+    )
+    .await?;
+    let view = BanAppealView::read(pool, appeal.id).await?;
+    assert_eq!(Some(moderator.id), view.resolver.map(|r| r.id));
+
+    let pending = BanAppealQuery {
+      status: Some(BanAppealStatus::Pending),
+      ..Default::default()
+    }
+    .list(pool, moderator.id, false)
+    .await?;
+    assert!(pending.is_empty());
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -1,18 +1,22 @@
-use crate::structs::{CommentReportView, LocalUserView};
+use crate::structs::{CommentReportView, LocalUserView, PaginationCursor, ReportNoteView};
 use diesel::{
+  deserialize::{self, FromStaticSqlRow, Queryable},
   dsl::now,
   pg::Pg,
   result::Error,
+  sql_types::Bool,
   BoolExpressionMethods,
   ExpressionMethods,
+  IntoSql,
   JoinOnDsl,
   NullableExpressionMethods,
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
+  aggregates::structs::CommentAggregates,
   aliases::{self, creator_community_actions},
-  newtypes::{CommentId, CommentReportId, CommunityId, PersonId},
+  newtypes::{CommentId, CommentReportId, CommunityId, PersonId, ReportReasonCategoryId},
   schema::{
     comment,
     comment_actions,
@@ -25,21 +29,94 @@ use lemmy_db_schema::{
     person_actions,
     post,
   },
-  source::community::CommunityFollower,
+  source::{
+    comment::Comment,
+    comment_report::CommentReport,
+    community::{Community, CommunityFollower},
+    person::Person,
+    post::Post,
+  },
   utils::{
     actions,
     actions_alias,
     functions::coalesce,
     get_conn,
-    limit_and_offset,
+    limit_fetch,
     DbConn,
     DbPool,
     ListFn,
     Queries,
     ReadFn,
   },
+  ReportOutcome,
+  SubscribedType,
 };
 
+type CommentReportViewTuple = (
+  CommentReport,
+  Comment,
+  Post,
+  Community,
+  Person,
+  Person,
+  CommentAggregates,
+  bool,
+  bool,
+  bool,
+  bool,
+  SubscribedType,
+  bool,
+  Option<i16>,
+  Option<Person>,
+);
+
+/// The notes aren't part of the selected row, they are only loaded for moderators with
+/// [`CommentReportView::fill_notes`].
+impl<ST> Queryable<ST, Pg> for CommentReportView
+where
+  CommentReportViewTuple: FromStaticSqlRow<ST, Pg>,
+{
+  type Row = CommentReportViewTuple;
+
+  fn build(row: Self::Row) -> deserialize::Result<Self> {
+    let (
+      comment_report,
+      comment,
+      post,
+      community,
+      creator,
+      comment_creator,
+      counts,
+      creator_banned_from_community,
+      creator_is_moderator,
+      creator_is_admin,
+      creator_blocked,
+      subscribed,
+      saved,
+      my_vote,
+      resolver,
+    ) = row;
+    Ok(Self {
+      comment_report,
+      comment,
+      post,
+      community,
+      creator,
+      comment_creator,
+      counts,
+      creator_banned_from_community,
+      creator_is_moderator,
+      creator_is_admin,
+      creator_blocked,
+      subscribed,
+      saved,
+      my_vote,
+      resolver,
+      notes: None,
+    })
+  }
+}
+
 fn queries<'a>() -> Queries<
   impl ReadFn<'a, CommentReportView, (CommentReportId, PersonId)>,
   impl ListFn<'a, CommentReportView, (CommentReportQuery, &'a LocalUserView)>,
@@ -140,19 +217,76 @@ fn queries<'a>() -> Queries<
       query = query.filter(comment_report::comment_id.eq(comment_id));
     }
 
+    if let Some(ids) = options.ids {
+      query = query.filter(comment_report::id.eq_any(ids));
+    }
+
+    if let Some(creator_id) = options.creator_id {
+      query = query.filter(comment_report::creator_id.eq(creator_id));
+    }
+
+    if let Some(comment_creator_id) = options.comment_creator_id {
+      query = query.filter(comment::creator_id.eq(comment_creator_id));
+    }
+
+    if let Some(category) = options.category {
+      query = query.filter(comment_report::category_id.eq(category));
+    }
+
+    if let Some(outcome) = options.outcome {
+      query = query.filter(comment_report::outcome.eq(outcome));
+    }
+
+    if options.assigned_to_me {
+      query = query.filter(comment_report::assigned_to.eq(user.person.id));
+    }
+
+    if options.sort_by_severity {
+      query = query.order_by(comment_report::severity.desc());
+    }
+
     // If viewing all reports, order by newest, but if viewing unresolved only, show the oldest
-    // first (FIFO)
+    // first (FIFO). Reports published at the same time are ordered by id.
     if options.unresolved_only {
       query = query
         .filter(comment_report::resolved.eq(false))
-        .order_by(comment_report::published.asc());
+        .then_order_by(comment_report::published.asc())
+        .then_order_by(comment_report::id.asc());
     } else {
-      query = query.order_by(comment_report::published.desc());
+      query = query
+        .then_order_by(comment_report::published.desc())
+        .then_order_by(comment_report::id.desc());
     }
 
-    let (limit, offset) = limit_and_offset(options.page, options.limit)?;
+    // Only reports which come after the cursor in the order above
+    if let Some(CommentReportPaginationCursorData(after)) = options.page_after {
+      if options.sort_by_severity {
+        query = query.filter(comment_report::severity.le(after.severity));
+      }
+      let lower_severity = comment_report::severity
+        .lt(after.severity)
+        .and(options.sort_by_severity.into_sql::<Bool>());
+      let same_published = comment_report::published.eq(after.published);
+      if options.unresolved_only {
+        query = query.filter(
+          lower_severity.or(
+            comment_report::published
+              .gt(after.published)
+              .or(same_published.and(comment_report::id.gt(after.id))),
+          ),
+        );
+      } else {
+        query = query.filter(
+          lower_severity.or(
+            comment_report::published
+              .lt(after.published)
+              .or(same_published.and(comment_report::id.lt(after.id))),
+          ),
+        );
+      }
+    }
 
-    query = query.limit(limit).offset(offset);
+    query = query.limit(limit_fetch(options.limit)?);
 
     // If its not an admin, get only the ones you mod
     if !user.local_user.admin {
@@ -174,7 +308,32 @@ impl CommentReportView {
     report_id: CommentReportId,
     my_person_id: PersonId,
   ) -> Result<Self, Error> {
-    queries().read(pool, (report_id, my_person_id)).await
+    let mut res = queries().read(pool, (report_id, my_person_id)).await?;
+    Comment::restore_archived(pool, [&mut res.comment]).await?;
+    Post::restore_archived(pool, [&mut res.post]).await?;
+    Ok(res)
+  }
+
+  /// Get cursor for the page that starts immediately after this report.
+  pub fn to_cursor(&self) -> PaginationCursor {
+    // hex encoding to prevent ossification
+    PaginationCursor(format!("C{:x}", self.comment_report.id.0))
+  }
+
+  /// Adds the internal moderator notes to the given reports. Only call this for moderators.
+  pub async fn fill_notes(pool: &mut DbPool<'_>, reports: &mut [Self]) -> Result<(), Error> {
+    let report_ids: Vec<_> = reports.iter().map(|r| r.comment_report.id).collect();
+    let notes = ReportNoteView::list_for_comment_reports(pool, &report_ids).await?;
+    for report in reports {
+      report.notes = Some(
+        notes
+          .iter()
+          .filter(|n| n.report_note.comment_report_id == report.comment_report.id)
+          .cloned()
+          .collect(),
+      );
+    }
+    Ok(())
   }
 
   /// Returns the current unresolved comment report count for the communities you mod
@@ -221,13 +380,49 @@ impl CommentReportView {
   }
 }
 
+/// The position of a comment report in a report list, used for keyset pagination.
+#[derive(Debug, Clone)]
+pub struct CommentReportPaginationCursorData(CommentReport);
+
+impl CommentReportPaginationCursorData {
+  /// Reads the report referenced by the given cursor.
+  pub async fn read(cursor: &PaginationCursor, pool: &mut DbPool<'_>) -> Result<Self, Error> {
+    let err_msg = || Error::QueryBuilderError("Could not parse pagination token".into());
+    let id = cursor
+      .0
+      .strip_prefix('C')
+      .and_then(|e| i32::from_str_radix(e, 16).ok())
+      .ok_or_else(err_msg)?;
+    let conn = &mut get_conn(pool).await?;
+    let report = comment_report::table
+      .find(CommentReportId(id))
+      .first::<CommentReport>(conn)
+      .await?;
+    Ok(CommentReportPaginationCursorData(report))
+  }
+}
+
 #[derive(Default)]
 pub struct CommentReportQuery {
   pub community_id: Option<CommunityId>,
   pub comment_id: Option<CommentId>,
-  pub page: Option<i64>,
+  /// Only the reports with these ids
+  pub ids: Option<Vec<CommentReportId>>,
+  /// Only reports filed by this person
+  pub creator_id: Option<PersonId>,
+  /// Only reports against content created by this person
+  pub comment_creator_id: Option<PersonId>,
+  pub page_after: Option<CommentReportPaginationCursorData>,
   pub limit: Option<i64>,
   pub unresolved_only: bool,
+  /// Show the most urgent reports first, and order reports of the same severity as usual
+  pub sort_by_severity: bool,
+  /// Only reports with this report reason category
+  pub category: Option<ReportReasonCategoryId>,
+  /// Only reports which were handled this way
+  pub outcome: Option<ReportOutcome>,
+  /// Only reports which were claimed by the user
+  pub assigned_to_me: bool,
 }
 
 impl CommentReportQuery {
@@ -236,7 +431,10 @@ impl CommentReportQuery {
     pool: &mut DbPool<'_>,
     user: &LocalUserView,
   ) -> Result<Vec<CommentReportView>, Error> {
-    queries().list(pool, (self, user)).await
+    let mut reports = queries().list(pool, (self, user)).await?;
+    Comment::restore_archived(pool, reports.iter_mut().map(|r| &mut r.comment)).await?;
+    Post::restore_archived(pool, reports.iter_mut().map(|r| &mut r.post)).await?;
+    Ok(reports)
   }
 }
 
@@ -245,26 +443,27 @@ impl CommentReportQuery {
 mod tests {
 
   use crate::{
-    comment_report_view::{CommentReportQuery, CommentReportView},
-    structs::LocalUserView,
+    comment_report_view::{
+      CommentReportPaginationCursorData,
+      CommentReportQuery,
+      CommentReportView,
+    },
+    structs::{LocalUserView, ReportNoteView},
   };
   use lemmy_db_schema::{
     aggregates::structs::CommentAggregates,
     source::{
-      comment::{Comment, CommentInsertForm},
       comment_report::{CommentReport, CommentReportForm},
-      community::{Community, CommunityInsertForm, CommunityModerator, CommunityModeratorForm},
-      instance::Instance,
-      local_user::{LocalUser, LocalUserInsertForm},
       local_user_vote_display_mode::LocalUserVoteDisplayMode,
-      person::{Person, PersonInsertForm},
-      post::{Post, PostInsertForm},
+      report_note::{ReportNote, ReportNoteInsertForm},
     },
-    traits::{Crud, Joinable, Reportable},
-    utils::{build_db_pool_for_tests, RANK_DEFAULT},
-    CommunityVisibility,
+    traits::{Crud, Reportable},
+    utils::build_db_pool_for_tests,
+    ReportOutcome,
+    ReportSeverity,
     SubscribedType,
   };
+  use lemmy_db_testutils::SeededInstance;
   use lemmy_utils::error::LemmyResult;
   use pretty_assertions::assert_eq;
   use serial_test::serial;
@@ -275,206 +474,77 @@ mod tests {
     let pool = &build_db_pool_for_tests();
     let pool = &mut pool.into();
 
-    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
-
-    let new_person = PersonInsertForm::test_form(inserted_instance.id, "timmy_crv");
-
-    let inserted_timmy = Person::create(pool, &new_person).await?;
-
-    let new_local_user = LocalUserInsertForm::test_form(inserted_timmy.id);
-    let timmy_local_user = LocalUser::create(pool, &new_local_user, vec![]).await?;
+    let instance = SeededInstance::create(pool, "my_domain.tld").await?;
+    let (timmy, timmy_local_user) = instance.local_user(pool, "timmy_crv").await?;
     let timmy_view = LocalUserView {
       local_user: timmy_local_user,
       local_user_vote_display_mode: LocalUserVoteDisplayMode::default(),
-      person: inserted_timmy.clone(),
+      person: timmy.clone(),
       counts: Default::default(),
     };
-
-    let new_person_2 = PersonInsertForm::test_form(inserted_instance.id, "sara_crv");
-
-    let inserted_sara = Person::create(pool, &new_person_2).await?;
-
+    let sara = instance.person(pool, "sara_crv").await?;
     // Add a third person, since new ppl can only report something once.
-    let new_person_3 = PersonInsertForm::test_form(inserted_instance.id, "jessica_crv");
-
-    let inserted_jessica = Person::create(pool, &new_person_3).await?;
-
-    let new_community = CommunityInsertForm::new(
-      inserted_instance.id,
-      "test community crv".to_string(),
-      "nada".to_owned(),
-      "pubkey".to_string(),
-    );
-    let inserted_community = Community::create(pool, &new_community).await?;
-
-    // Make timmy a mod
-    let timmy_moderator_form = CommunityModeratorForm {
-      community_id: inserted_community.id,
-      person_id: inserted_timmy.id,
-    };
-
-    let _inserted_moderator = CommunityModerator::join(pool, &timmy_moderator_form).await?;
-
-    let new_post = PostInsertForm::new(
-      "A test post crv".into(),
-      inserted_timmy.id,
-      inserted_community.id,
-    );
-
-    let inserted_post = Post::create(pool, &new_post).await?;
-
-    let comment_form = CommentInsertForm::new(
-      inserted_timmy.id,
-      inserted_post.id,
-      "A test comment 32".into(),
-    );
-    let inserted_comment = Comment::create(pool, &comment_form, None).await?;
+    let jessica = instance.person(pool, "jessica_crv").await?;
+
+    // Timmy is a mod and writes the comment
+    let community = instance
+      .community("test community crv")
+      .creator(timmy.id)
+      .moderator(timmy.id)
+      .posts(1)
+      .comments_per_post(1)
+      .create(pool)
+      .await?;
+    let comment = &community.comments[0];
 
     // sara reports
     let sara_report_form = CommentReportForm {
-      creator_id: inserted_sara.id,
-      comment_id: inserted_comment.id,
+      creator_id: sara.id,
+      comment_id: comment.id,
       original_comment_text: "this was it at time of creation".into(),
       reason: "from sara".into(),
+      severity: Some(ReportSeverity::Urgent),
+      category_id: None,
     };
-
     let inserted_sara_report = CommentReport::report(pool, &sara_report_form).await?;
 
     // jessica reports
     let jessica_report_form = CommentReportForm {
-      creator_id: inserted_jessica.id,
-      comment_id: inserted_comment.id,
+      creator_id: jessica.id,
+      comment_id: comment.id,
       original_comment_text: "this was it at time of creation".into(),
       reason: "from jessica".into(),
+      severity: None,
+      category_id: None,
     };
-
     let inserted_jessica_report = CommentReport::report(pool, &jessica_report_form).await?;
 
-    let agg = CommentAggregates::read(pool, inserted_comment.id).await?;
-
     let read_jessica_report_view =
-      CommentReportView::read(pool, inserted_jessica_report.id, inserted_timmy.id).await?;
+      CommentReportView::read(pool, inserted_jessica_report.id, timmy.id).await?;
     let expected_jessica_report_view = CommentReportView {
       comment_report: inserted_jessica_report.clone(),
-      comment: inserted_comment.clone(),
-      post: inserted_post,
+      comment: comment.clone(),
+      post: community.posts[0].clone(),
       creator_is_moderator: true,
       creator_is_admin: false,
       creator_blocked: false,
       subscribed: SubscribedType::NotSubscribed,
       saved: false,
-      community: Community {
-        id: inserted_community.id,
-        name: inserted_community.name,
-        icon: None,
-        removed: false,
-        deleted: false,
-        nsfw: false,
-        actor_id: inserted_community.actor_id.clone(),
-        local: true,
-        title: inserted_community.title,
-        sidebar: None,
-        description: None,
-        updated: None,
-        banner: None,
-        hidden: false,
-        posting_restricted_to_mods: false,
-        published: inserted_community.published,
-        private_key: inserted_community.private_key,
-        public_key: inserted_community.public_key,
-        last_refreshed_at: inserted_community.last_refreshed_at,
-        followers_url: inserted_community.followers_url,
-        inbox_url: inserted_community.inbox_url,
-        moderators_url: inserted_community.moderators_url,
-        featured_url: inserted_community.featured_url,
-        instance_id: inserted_instance.id,
-        visibility: CommunityVisibility::Public,
-      },
-      creator: Person {
-        id: inserted_jessica.id,
-        name: inserted_jessica.name,
-        display_name: None,
-        published: inserted_jessica.published,
-        avatar: None,
-        actor_id: inserted_jessica.actor_id.clone(),
-        local: true,
-        banned: false,
-        deleted: false,
-        bot_account: false,
-        bio: None,
-        banner: None,
-        updated: None,
-        inbox_url: inserted_jessica.inbox_url.clone(),
-        matrix_user_id: None,
-        ban_expires: None,
-        instance_id: inserted_instance.id,
-        private_key: inserted_jessica.private_key,
-        public_key: inserted_jessica.public_key,
-        last_refreshed_at: inserted_jessica.last_refreshed_at,
-      },
-      comment_creator: Person {
-        id: inserted_timmy.id,
-        name: inserted_timmy.name.clone(),
-        display_name: None,
-        published: inserted_timmy.published,
-        avatar: None,
-        actor_id: inserted_timmy.actor_id.clone(),
-        local: true,
-        banned: false,
-        deleted: false,
-        bot_account: false,
-        bio: None,
-        banner: None,
-        updated: None,
-        inbox_url: inserted_timmy.inbox_url.clone(),
-        matrix_user_id: None,
-        ban_expires: None,
-        instance_id: inserted_instance.id,
-        private_key: inserted_timmy.private_key.clone(),
-        public_key: inserted_timmy.public_key.clone(),
-        last_refreshed_at: inserted_timmy.last_refreshed_at,
-      },
+      community: community.community.clone(),
+      creator: jessica.clone(),
+      comment_creator: timmy.clone(),
       creator_banned_from_community: false,
-      counts: CommentAggregates {
-        comment_id: inserted_comment.id,
-        score: 0,
-        upvotes: 0,
-        downvotes: 0,
-        published: agg.published,
-        child_count: 0,
-        hot_rank: RANK_DEFAULT,
-        controversy_rank: 0.0,
-      },
+      counts: CommentAggregates::read(pool, comment.id).await?,
       my_vote: None,
       resolver: None,
+      notes: None,
     };
 
     assert_eq!(read_jessica_report_view, expected_jessica_report_view);
 
     let mut expected_sara_report_view = expected_jessica_report_view.clone();
     expected_sara_report_view.comment_report = inserted_sara_report;
-    expected_sara_report_view.creator = Person {
-      id: inserted_sara.id,
-      name: inserted_sara.name,
-      display_name: None,
-      published: inserted_sara.published,
-      avatar: None,
-      actor_id: inserted_sara.actor_id.clone(),
-      local: true,
-      banned: false,
-      deleted: false,
-      bot_account: false,
-      bio: None,
-      banner: None,
-      updated: None,
-      inbox_url: inserted_sara.inbox_url.clone(),
-      matrix_user_id: None,
-      ban_expires: None,
-      instance_id: inserted_instance.id,
-      private_key: inserted_sara.private_key,
-      public_key: inserted_sara.public_key,
-      last_refreshed_at: inserted_sara.last_refreshed_at,
-    };
+    expected_sara_report_view.creator = sara;
 
     // Do a batch read of timmys reports
     let reports = CommentReportQuery::default()
@@ -489,15 +559,147 @@ mod tests {
       ]
     );
 
+    // Unresolved reports are paged oldest first, one report per page
+    let first_page = CommentReportQuery {
+      unresolved_only: true,
+      limit: Some(1),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_eq!(first_page, [expected_sara_report_view.clone()]);
+    let cursor = CommentReportPaginationCursorData::read(&first_page[0].to_cursor(), pool).await?;
+    let second_page = CommentReportQuery {
+      unresolved_only: true,
+      limit: Some(1),
+      page_after: Some(cursor),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_eq!(second_page, [expected_jessica_report_view.clone()]);
+    let cursor = CommentReportPaginationCursorData::read(&second_page[0].to_cursor(), pool).await?;
+    let last_page = CommentReportQuery {
+      unresolved_only: true,
+      limit: Some(1),
+      page_after: Some(cursor),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert!(last_page.is_empty());
+
+    // Filter by the person who filed the report
+    let sara_filed = CommentReportQuery {
+      creator_id: Some(expected_sara_report_view.creator.id),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_eq!(sara_filed, [expected_sara_report_view.clone()]);
+
+    // Filter by the person whose comment was reported
+    let timmy_received = CommentReportQuery {
+      comment_creator_id: Some(timmy.id),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_eq!(2, timmy_received.len());
+    let jessica_received = CommentReportQuery {
+      comment_creator_id: Some(jessica.id),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert!(jessica_received.is_empty());
+
+    // Saras urgent report comes first when sorting by severity
+    let reports_by_severity = CommentReportQuery {
+      sort_by_severity: true,
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_eq!(
+      reports_by_severity,
+      [
+        expected_sara_report_view.clone(),
+        expected_jessica_report_view.clone(),
+      ]
+    );
+
+    // The next page after saras report only contains jessicas
+    let cursor =
+      CommentReportPaginationCursorData::read(&expected_sara_report_view.to_cursor(), pool).await?;
+    let next_page_by_severity = CommentReportQuery {
+      sort_by_severity: true,
+      page_after: Some(cursor),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_eq!(
+      next_page_by_severity,
+      [expected_jessica_report_view.clone()]
+    );
+
+    // Timmy leaves a note on saras report, which is only included on request
+    let note_form = ReportNoteInsertForm {
+      comment_report_id: expected_sara_report_view.comment_report.id,
+      creator_id: timmy.id,
+      content: "waiting for a second opinion".into(),
+    };
+    let note = ReportNote::create(pool, &note_form).await?;
+    let mut reports_with_notes = reports.clone();
+    CommentReportView::fill_notes(pool, &mut reports_with_notes).await?;
+    assert_eq!(Some(vec![]), reports_with_notes[0].notes);
+    assert_eq!(
+      Some(vec![ReportNoteView {
+        report_note: note,
+        creator: timmy.clone(),
+      }]),
+      reports_with_notes[1].notes
+    );
+
+    // Timmy claims saras report
+    CommentReport::assign(
+      pool,
+      expected_sara_report_view.comment_report.id,
+      Some(timmy.id),
+    )
+    .await?;
+    let assigned_reports = CommentReportQuery {
+      assigned_to_me: true,
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_eq!(1, assigned_reports.len());
+    assert_eq!(
+      expected_sara_report_view.comment_report.id,
+      assigned_reports[0].comment_report.id
+    );
+    assert_eq!(
+      Some(timmy.id),
+      assigned_reports[0].comment_report.assigned_to
+    );
+    CommentReport::assign(pool, expected_sara_report_view.comment_report.id, None).await?;
+
     // Make sure the counts are correct
-    let report_count =
-      CommentReportView::get_report_count(pool, inserted_timmy.id, false, None).await?;
+    let report_count = CommentReportView::get_report_count(pool, timmy.id, false, None).await?;
     assert_eq!(2, report_count);
 
     // Try to resolve the report
-    CommentReport::resolve(pool, inserted_jessica_report.id, inserted_timmy.id).await?;
+    CommentReport::resolve(
+      pool,
+      inserted_jessica_report.id,
+      timmy.id,
+      ReportOutcome::Dismissed,
+    )
+    .await?;
     let read_jessica_report_view_after_resolve =
-      CommentReportView::read(pool, inserted_jessica_report.id, inserted_timmy.id).await?;
+      CommentReportView::read(pool, inserted_jessica_report.id, timmy.id).await?;
 
     let mut expected_jessica_report_view_after_resolve = expected_jessica_report_view;
     expected_jessica_report_view_after_resolve
@@ -505,34 +707,16 @@ mod tests {
       .resolved = true;
     expected_jessica_report_view_after_resolve
       .comment_report
-      .resolver_id = Some(inserted_timmy.id);
+      .resolver_id = Some(timmy.id);
+    expected_jessica_report_view_after_resolve
+      .comment_report
+      .outcome = Some(ReportOutcome::Dismissed);
     expected_jessica_report_view_after_resolve
       .comment_report
       .updated = read_jessica_report_view_after_resolve
       .comment_report
       .updated;
-    expected_jessica_report_view_after_resolve.resolver = Some(Person {
-      id: inserted_timmy.id,
-      name: inserted_timmy.name.clone(),
-      display_name: None,
-      published: inserted_timmy.published,
-      avatar: None,
-      actor_id: inserted_timmy.actor_id.clone(),
-      local: true,
-      banned: false,
-      deleted: false,
-      bot_account: false,
-      bio: None,
-      banner: None,
-      updated: None,
-      inbox_url: inserted_timmy.inbox_url.clone(),
-      private_key: inserted_timmy.private_key.clone(),
-      public_key: inserted_timmy.public_key.clone(),
-      last_refreshed_at: inserted_timmy.last_refreshed_at,
-      matrix_user_id: None,
-      ban_expires: None,
-      instance_id: inserted_instance.id,
-    });
+    expected_jessica_report_view_after_resolve.resolver = Some(timmy.clone());
 
     assert_eq!(
       read_jessica_report_view_after_resolve,
@@ -550,16 +734,42 @@ mod tests {
     assert_eq!(reports_after_resolve[0], expected_sara_report_view);
     assert_eq!(reports_after_resolve.len(), 1);
 
+    // Only jessicas report was dismissed
+    let dismissed_reports = CommentReportQuery {
+      outcome: Some(ReportOutcome::Dismissed),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_eq!(
+      dismissed_reports,
+      [expected_jessica_report_view_after_resolve.clone()]
+    );
+
     // Make sure the counts are correct
     let report_count_after_resolved =
-      CommentReportView::get_report_count(pool, inserted_timmy.id, false, None).await?;
+      CommentReportView::get_report_count(pool, timmy.id, false, None).await?;
     assert_eq!(1, report_count_after_resolved);
 
-    Person::delete(pool, inserted_timmy.id).await?;
-    Person::delete(pool, inserted_sara.id).await?;
-    Person::delete(pool, inserted_jessica.id).await?;
-    Community::delete(pool, inserted_community.id).await?;
-    Instance::delete(pool, inserted_instance.id).await?;
+    // Resolving all reports of the post only touches saras open report
+    let resolved = CommentReport::resolve_all_for_post(
+      pool,
+      comment.post_id,
+      timmy.id,
+      ReportOutcome::ResolvedNoAction,
+    )
+    .await?;
+    assert_eq!(1, resolved);
+    let resolved = CommentReport::resolve_all_for_comment(
+      pool,
+      comment.id,
+      timmy.id,
+      ReportOutcome::ResolvedNoAction,
+    )
+    .await?;
+    assert_eq!(0, resolved);
+
+    instance.delete(pool).await?;
 
     Ok(())
   }
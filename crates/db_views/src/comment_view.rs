@@ -3,8 +3,10 @@ use diesel::{
   dsl::{exists, not},
   pg::Pg,
   result::Error,
+  sql_types::Text,
   BoolExpressionMethods,
   ExpressionMethods,
+  IntoSql,
   JoinOnDsl,
   NullableExpressionMethods,
   PgTextExpressionMethods,
@@ -13,30 +15,36 @@ use diesel::{
 use diesel_async::RunQueryDsl;
 use diesel_ltree::{nlevel, subpath, Ltree, LtreeExtensions};
 use lemmy_db_schema::{
-  aliases::creator_community_actions,
+  aliases::{comment1, creator_community_actions},
   impls::local_user::LocalUserOptionHelper,
   newtypes::{CommentId, CommunityId, LocalUserId, PersonId, PostId},
   schema::{
     comment,
     comment_actions,
     comment_aggregates,
+    comment_archive,
     community,
     community_actions,
     instance_actions,
     local_user,
+    local_user_community_settings,
     local_user_language,
     person,
     person_actions,
     post,
   },
   source::{
+    comment::Comment,
     community::{CommunityFollower, CommunityFollowerState},
     local_user::LocalUser,
+    local_user_keyword_block::LocalUserKeywordBlock,
+    post::Post,
     site::Site,
   },
   utils::{
     actions,
     actions_alias,
+    functions::RegexMatchInsensitive,
     fuzzy_search,
     limit_and_offset,
     DbConn,
@@ -113,6 +121,7 @@ fn queries<'a>() -> Queries<
         comment_actions::saved.nullable().is_not_null(),
         person_actions::blocked.nullable().is_not_null(),
         comment_actions::like_score.nullable(),
+        comment_actions::saved_note.nullable(),
       ))
   };
 
@@ -134,6 +143,12 @@ fn queries<'a>() -> Queries<
           .ne(CommunityVisibility::Private)
           .or(community_actions::follow_state.eq(CommunityFollowerState::Accepted)),
       );
+      // Comments of shadow banned persons are only visible to themselves and admins
+      query = query.filter(
+        person::shadow_banned
+          .eq(false)
+          .or(comment::creator_id.nullable().eq(my_local_user.person_id())),
+      );
     }
     query.first(&mut conn).await
   };
@@ -151,6 +166,20 @@ fn queries<'a>() -> Queries<
       query = query.filter(comment::creator_id.eq(creator_id));
     };
 
+    // hide comments which match the keyword blocks of the user, except their own
+    if let (Some(local_user_id), Some(person_id)) = (
+      options.local_user.local_user_id(),
+      options.local_user.person_id(),
+    ) {
+      if let Some(pattern) =
+        LocalUserKeywordBlock::read_pattern(&mut (&mut conn).into(), local_user_id).await?
+      {
+        query = query.filter(comment::creator_id.eq(person_id).or(not(
+          RegexMatchInsensitive::new(comment::content, pattern.into_sql::<Text>()),
+        )));
+      }
+    }
+
     if let Some(post_id) = options.post_id {
       query = query.filter(comment::post_id.eq(post_id));
     };
@@ -160,9 +189,17 @@ fn queries<'a>() -> Queries<
     };
     //filtering out removed and deleted comments from search
     if let Some(search_term) = options.search_term {
+      let searcher = fuzzy_search(&search_term);
+      // The content of archived comments was moved to the archive table
+      let archive_filter = comment::archived.and(exists(
+        comment_archive::table
+          .filter(comment_archive::comment_id.eq(comment::id))
+          .filter(comment_archive::content.ilike(searcher.clone())),
+      ));
       query = query.filter(
         comment::content
-          .ilike(fuzzy_search(&search_term))
+          .ilike(searcher)
+          .or(archive_filter)
           .and(not(comment::removed.or(comment::deleted))),
       );
     };
@@ -174,23 +211,36 @@ fn queries<'a>() -> Queries<
     let is_subscribed = community_actions::followed.is_not_null();
 
     match options.listing_type.unwrap_or_default() {
-      ListingType::Subscribed => query = query.filter(is_subscribed), /* TODO could be this: and(community_follower::person_id.eq(person_id_join)), */
+      ListingType::Subscribed | ListingType::Home => query = query.filter(is_subscribed), /* TODO could be this: and(community_follower::person_id.eq(person_id_join)), */
       ListingType::Local => {
-        query = query
-          .filter(community::local.eq(true))
-          .filter(community::hidden.eq(false).or(is_subscribed))
+        query = query.filter(community::local.eq(true)).filter(
+          community::hidden
+            .eq(false)
+            .and(community::soft_launched.eq(false))
+            .or(is_subscribed),
+        )
+      }
+      ListingType::All => {
+        query = query.filter(
+          community::hidden
+            .eq(false)
+            .and(community::soft_launched.eq(false))
+            .or(is_subscribed),
+        )
       }
-      ListingType::All => query = query.filter(community::hidden.eq(false).or(is_subscribed)),
       ListingType::ModeratorView => {
         query = query.filter(community_actions::became_moderator.is_not_null());
       }
     }
 
     // If its saved only, then filter, and order by the saved time, not the comment creation time.
-    if options.saved_only.unwrap_or_default() {
+    if options.saved_only.unwrap_or_default() || options.saved_notes.is_some() {
       query = query
         .filter(comment_actions::saved.is_not_null())
         .then_order_by(comment_actions::saved.desc());
+      if let Some(saved_notes) = &options.saved_notes {
+        query = query.filter(comment_actions::saved_note.ilike(fuzzy_search(saved_notes)));
+      }
     }
 
     if let Some(my_id) = options.local_user.person_id() {
@@ -206,10 +256,37 @@ fn queries<'a>() -> Queries<
       }
     }
 
+    if options.followed_persons_only.unwrap_or_default() {
+      query = query.filter(person_actions::followed.is_not_null());
+    }
+
+    // The bot settings of the user can be overridden for single communities
+    let community_shows_bots = |show: bool| {
+      exists(
+        local_user_community_settings::table.filter(
+          local_user_community_settings::local_user_id
+            .eq(local_user_id_join)
+            .and(local_user_community_settings::community_id.eq(post::community_id))
+            .and(local_user_community_settings::show_bot_accounts.eq(show)),
+        ),
+      )
+    };
     if !options.local_user.show_bot_accounts() {
-      query = query.filter(person::bot_account.eq(false));
+      query = query.filter(person::bot_account.eq(false).or(community_shows_bots(true)));
+    } else if options.local_user.is_some() {
+      query = query.filter(not(person::bot_account.and(community_shows_bots(false))));
     };
 
+    if !options.local_user.is_admin() {
+      query = query.filter(
+        person::shadow_banned.eq(false).or(
+          comment::creator_id
+            .nullable()
+            .eq(options.local_user.person_id()),
+        ),
+      );
+    }
+
     if options.local_user.is_some()
       && options.listing_type.unwrap_or_default() != ListingType::ModeratorView
     {
@@ -279,9 +356,20 @@ fn queries<'a>() -> Queries<
       limit_and_offset(options.page, options.limit)?
     };
 
-    // distinguished comments should go first when viewing post
+    // pinned and distinguished comments should go first when viewing post
     if options.post_id.is_some() || options.parent_path.is_some() {
-      query = query.then_order_by(comment::distinguished.desc());
+      query = query
+        .then_order_by(comment::distinguished_pinned.desc())
+        .then_order_by(comment::distinguished.desc());
+    }
+
+    // down-ranked bot comments go last
+    if options.local_user.downrank_bot_accounts() {
+      query = query.then_order_by(
+        person::bot_account
+          .and(not(community_shows_bots(true)))
+          .asc(),
+      );
     }
 
     query = match options.sort.unwrap_or(CommentSortType::Hot) {
@@ -294,6 +382,21 @@ fn queries<'a>() -> Queries<
       CommentSortType::New => query.then_order_by(comment::published.desc()),
       CommentSortType::Old => query.then_order_by(comment::published.asc()),
       CommentSortType::Top => query.then_order_by(comment_aggregates::score.desc()),
+      CommentSortType::QandA => {
+        // Comments which the post creator replied to, anywhere below the comment
+        let post_creator_replied = exists(
+          comment1.filter(
+            comment1
+              .field(comment::path)
+              .contained_by(comment::path)
+              .and(comment1.field(comment::id).ne(comment::id))
+              .and(comment1.field(comment::creator_id).eq(post::creator_id)),
+          ),
+        );
+        query
+          .then_order_by(post_creator_replied.desc())
+          .then_order_by(comment_aggregates::score.desc())
+      }
     };
 
     // Note: deleted and removed comments are done on the front side
@@ -315,7 +418,9 @@ impl CommentView {
   ) -> Result<Self, Error> {
     // If a person is given, then my_vote (res.9), if None, should be 0, not null
     // Necessary to differentiate between other person's votes
-    let res = queries().read(pool, (comment_id, my_local_user)).await?;
+    let mut res = queries().read(pool, (comment_id, my_local_user)).await?;
+    Comment::restore_archived(pool, [&mut res.comment]).await?;
+    Post::restore_archived(pool, [&mut res.post]).await?;
     let mut new_view = res.clone();
     if my_local_user.is_some() && res.my_vote.is_none() {
       new_view.my_vote = Some(0);
@@ -338,8 +443,12 @@ pub struct CommentQuery<'a> {
   pub local_user: Option<&'a LocalUser>,
   pub search_term: Option<String>,
   pub saved_only: Option<bool>,
+  /// Only saved items whose personal note contains this text.
+  pub saved_notes: Option<String>,
   pub liked_only: Option<bool>,
   pub disliked_only: Option<bool>,
+  /// Only comments by persons which the user follows.
+  pub followed_persons_only: Option<bool>,
   pub page: Option<i64>,
   pub limit: Option<i64>,
   pub max_depth: Option<i32>,
@@ -347,10 +456,11 @@ pub struct CommentQuery<'a> {
 
 impl<'a> CommentQuery<'a> {
   pub async fn list(self, site: &Site, pool: &mut DbPool<'_>) -> Result<Vec<CommentView>, Error> {
+    let mut comments = queries().list(pool, (self, site)).await?;
+    Comment::restore_archived(pool, comments.iter_mut().map(|c| &mut c.comment)).await?;
+    Post::restore_archived(pool, comments.iter_mut().map(|c| &mut c.post)).await?;
     Ok(
-      queries()
-        .list(pool, (self, site))
-        .await?
+      comments
         .into_iter()
         .map(|mut c| {
           if c.comment.deleted || c.comment.removed {
@@ -402,6 +512,7 @@ mod tests {
       instance::Instance,
       language::Language,
       local_user::{LocalUser, LocalUserInsertForm},
+      local_user_community_settings::{LocalUserCommunitySettings, LocalUserCommunitySettingsForm},
       local_user_vote_display_mode::LocalUserVoteDisplayMode,
       person::{Person, PersonInsertForm},
       person_block::{PersonBlock, PersonBlockForm},
@@ -838,6 +949,62 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn test_pinned_first() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let form = CommentUpdateForm {
+      distinguished: Some(true),
+      ..Default::default()
+    };
+    Comment::update(pool, data.inserted_comment_2.id, &form).await?;
+    let form = CommentUpdateForm {
+      distinguished_pinned: Some(true),
+      ..Default::default()
+    };
+    Comment::update(pool, data.inserted_comment_0.id, &form).await?;
+
+    // The pinned comment goes before the distinguished one
+    let comments = CommentQuery {
+      post_id: Some(data.inserted_comment_0.post_id),
+      ..Default::default()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(comments[0].comment.id, data.inserted_comment_0.id);
+    assert!(comments[0].comment.distinguished_pinned);
+    assert_eq!(comments[1].comment.id, data.inserted_comment_2.id);
+
+    cleanup(data, pool).await
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_sort_q_and_a() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    // Timmy created the post, and replied below comments 0, 1 and 4
+    let comments = CommentQuery {
+      post_id: Some(data.inserted_post.id),
+      sort: Some(CommentSortType::QandA),
+      ..Default::default()
+    }
+    .list(&data.site, pool)
+    .await?;
+    let ids = comments.iter().map(|c| c.comment.id).collect::<Vec<_>>();
+    assert_eq!(6, ids.len());
+    assert!(ids[..3].contains(&data.inserted_comment_0.id));
+    assert!(ids[..3].contains(&data.inserted_comment_1.id));
+    assert!(ids[3..].contains(&data.inserted_comment_2.id));
+
+    cleanup(data, pool).await
+  }
+
   #[tokio::test]
   #[serial]
   async fn test_creator_is_moderator() -> LemmyResult<()> {
@@ -894,6 +1061,73 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn test_downrank_bots() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let bot_person_form = PersonInsertForm {
+      bot_account: Some(true),
+      ..PersonInsertForm::test_form(data.inserted_instance.id, "bot_commenter")
+    };
+    let inserted_bot_person = Person::create(pool, &bot_person_form).await?;
+    let english_id = Language::read_id_from_code(pool, "en").await?;
+    let bot_comment_form = CommentInsertForm {
+      language_id: Some(english_id),
+      ..CommentInsertForm::new(
+        inserted_bot_person.id,
+        data.inserted_post.id,
+        "Bot comment".into(),
+      )
+    };
+    let inserted_bot_comment = Comment::create(pool, &bot_comment_form, None).await?;
+
+    let local_user = LocalUser {
+      show_bot_accounts: true,
+      downrank_bot_accounts: true,
+      ..data.timmy_local_user_view.local_user.clone()
+    };
+    let list_newest = |local_user| CommentQuery {
+      sort: Some(CommentSortType::New),
+      post_id: Some(data.inserted_post.id),
+      local_user: Some(local_user),
+      ..Default::default()
+    };
+
+    // The newest comment is from a bot, so it only goes last when down-ranked
+    let comments = list_newest(&data.timmy_local_user_view.local_user)
+      .list(&data.site, pool)
+      .await?;
+    assert_eq!(comments[0].comment.id, inserted_bot_comment.id);
+    let comments = list_newest(&local_user).list(&data.site, pool).await?;
+    assert_eq!(
+      comments.last().map(|c| c.comment.id),
+      Some(inserted_bot_comment.id)
+    );
+
+    // Rank bots normally in a community which relies on them
+    let mut form = LocalUserCommunitySettingsForm {
+      show_bot_accounts: Some(true),
+      ..LocalUserCommunitySettingsForm::new(local_user.id, data.inserted_community.id)
+    };
+    LocalUserCommunitySettings::save(pool, &form).await?;
+    let comments = list_newest(&local_user).list(&data.site, pool).await?;
+    assert_eq!(comments[0].comment.id, inserted_bot_comment.id);
+
+    // Or hide them only in this community
+    form.show_bot_accounts = Some(false);
+    LocalUserCommunitySettings::save(pool, &form).await?;
+    let comments = list_newest(&local_user).list(&data.site, pool).await?;
+    assert!(comments
+      .iter()
+      .all(|c| c.comment.id != inserted_bot_comment.id));
+
+    Person::delete(pool, inserted_bot_person.id).await?;
+    cleanup(data, pool).await
+  }
+
   #[tokio::test]
   #[serial]
   async fn test_saved_order() -> LemmyResult<()> {
@@ -905,12 +1139,14 @@ mod tests {
     let save_comment_0_form = CommentSavedForm {
       person_id: data.timmy_local_user_view.person.id,
       comment_id: data.inserted_comment_0.id,
+      saved_note: None,
     };
     CommentSaved::save(pool, &save_comment_0_form).await?;
 
     let save_comment_2_form = CommentSavedForm {
       person_id: data.timmy_local_user_view.person.id,
       comment_id: data.inserted_comment_2.id,
+      saved_note: None,
     };
     CommentSaved::save(pool, &save_comment_2_form).await?;
 
@@ -965,6 +1201,7 @@ mod tests {
       my_vote: None,
       subscribed: SubscribedType::NotSubscribed,
       saved: false,
+      saved_note: None,
       creator_blocked: false,
       comment: Comment {
         id: data.inserted_comment_0.id,
@@ -980,6 +1217,8 @@ mod tests {
         distinguished: false,
         path: data.inserted_comment_0.clone().path,
         language_id: LanguageId(37),
+        archived: false,
+        distinguished_pinned: false,
       },
       creator: Person {
         id: data.timmy_local_user_view.person.id,
@@ -998,6 +1237,7 @@ mod tests {
         inbox_url: data.timmy_local_user_view.person.inbox_url.clone(),
         matrix_user_id: None,
         ban_expires: None,
+        shadow_banned: false,
         instance_id: data.inserted_instance.id,
         private_key: data.timmy_local_user_view.person.private_key.clone(),
         public_key: data.timmy_local_user_view.person.public_key.clone(),
@@ -1028,6 +1268,9 @@ mod tests {
         featured_local: false,
         url_content_type: None,
         scheduled_publish_time: None,
+        archived: false,
+        pending: false,
+        lock_expires: None,
       },
       community: Community {
         id: data.inserted_community.id,
@@ -1045,6 +1288,18 @@ mod tests {
         banner: None,
         hidden: false,
         posting_restricted_to_mods: false,
+        comment_export_public: false,
+        soft_launched: false,
+        image_text_search: true,
+        posting_min_reputation: None,
+        welcome_message: None,
+        posts_require_approval: false,
+        warning_ban_strikes: None,
+        warning_ban_days: 7,
+        new_account_min_age_days: None,
+        new_account_min_comments: None,
+        new_account_require_verified_email: false,
+        new_account_restrictions: vec![],
         published: data.inserted_community.published,
         instance_id: data.inserted_instance.id,
         private_key: data.inserted_community.private_key.clone(),
@@ -1140,6 +1395,8 @@ mod tests {
         community_id: data.inserted_community.id,
         person_id: inserted_banned_from_comm_person.id,
         expires: None,
+        reason: None,
+        appeal: None,
       },
     )
     .await?;
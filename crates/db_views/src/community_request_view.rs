@@ -0,0 +1,238 @@
+use crate::structs::{CommunityRequestCommentView, CommunityRequestView};
+use diesel::{
+  dsl::sum,
+  pg::Pg,
+  result::Error,
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{CommunityRequestId, PersonId},
+  schema::{community_request, community_request_comment, community_request_vote, person},
+  utils::{
+    actions,
+    functions::coalesce,
+    get_conn,
+    limit_and_offset,
+    DbConn,
+    DbPool,
+    ListFn,
+    Queries,
+    ReadFn,
+  },
+  CommunityRequestStatus,
+};
+
+fn queries<'a>() -> Queries<
+  impl ReadFn<'a, CommunityRequestView, (CommunityRequestId, Option<PersonId>)>,
+  impl ListFn<'a, CommunityRequestView, CommunityRequestQuery>,
+> {
+  let score = || {
+    coalesce(
+      community_request_vote::table
+        .filter(community_request_vote::community_request_id.eq(community_request::id))
+        .select(sum(community_request_vote::score))
+        .single_value(),
+      0,
+    )
+  };
+
+  let all_joins = move |query: community_request::BoxedQuery<'a, Pg>,
+                        my_person_id: Option<PersonId>| {
+    query
+      .inner_join(person::table.on(community_request::creator_id.eq(person::id)))
+      .left_join(actions(
+        community_request_vote::table,
+        my_person_id,
+        community_request::id,
+      ))
+      .select((
+        community_request::all_columns,
+        person::all_columns,
+        score(),
+        community_request_vote::score.nullable(),
+      ))
+  };
+
+  let read = move |mut conn: DbConn<'a>,
+                   (id, my_person_id): (CommunityRequestId, Option<PersonId>)| async move {
+    all_joins(community_request::table.find(id).into_boxed(), my_person_id)
+      .first(&mut conn)
+      .await
+  };
+
+  let list = move |mut conn: DbConn<'a>, options: CommunityRequestQuery| async move {
+    let mut query = all_joins(community_request::table.into_boxed(), options.my_person_id);
+
+    if let Some(status) = options.status {
+      query = query.filter(community_request::status.eq(status));
+    }
+
+    // Requests with the most support are shown first, so that admins can see what users want
+    if options.sort_by_score {
+      query = query.order_by(score().desc());
+    }
+
+    let (limit, offset) = limit_and_offset(options.page, options.limit)?;
+
+    query
+      .then_order_by(community_request::published.desc())
+      .limit(limit)
+      .offset(offset)
+      .load::<CommunityRequestView>(&mut conn)
+      .await
+  };
+
+  Queries::new(read, list)
+}
+
+impl CommunityRequestView {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    id: CommunityRequestId,
+    my_person_id: Option<PersonId>,
+  ) -> Result<Self, Error> {
+    queries().read(pool, (id, my_person_id)).await
+  }
+}
+
+#[derive(Default)]
+pub struct CommunityRequestQuery {
+  pub status: Option<CommunityRequestStatus>,
+  pub sort_by_score: bool,
+  pub my_person_id: Option<PersonId>,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+impl CommunityRequestQuery {
+  pub async fn list(self, pool: &mut DbPool<'_>) -> Result<Vec<CommunityRequestView>, Error> {
+    queries().list(pool, self).await
+  }
+}
+
+impl CommunityRequestCommentView {
+  /// All comments on a request, oldest first.
+  pub async fn list_for_request(
+    pool: &mut DbPool<'_>,
+    community_request_id: CommunityRequestId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_request_comment::table
+      .inner_join(person::table)
+      .filter(community_request_comment::community_request_id.eq(community_request_id))
+      .select((community_request_comment::all_columns, person::all_columns))
+      .order_by(community_request_comment::published.asc())
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+
+  use crate::{
+    community_request_view::CommunityRequestQuery,
+    structs::{CommunityRequestCommentView, CommunityRequestView},
+  };
+  use lemmy_db_schema::{
+    source::{
+      community_request::{
+        CommunityRequest,
+        CommunityRequestComment,
+        CommunityRequestCommentForm,
+        CommunityRequestInsertForm,
+        CommunityRequestVote,
+        CommunityRequestVoteForm,
+      },
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+    CommunityRequestStatus,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_community_request_votes() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let requester = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "cr_requester"),
+    )
+    .await?;
+    let voter = Person::create(
+      pool,
+      &PersonInsertForm::test_form(inserted_instance.id, "cr_voter"),
+    )
+    .await?;
+
+    let request = CommunityRequest::create(
+      pool,
+      &CommunityRequestInsertForm::new(requester.id, "gardening".into(), "Gardening".into()),
+    )
+    .await?;
+    assert!(CommunityRequest::pending_name_exists(pool, "Gardening").await?);
+
+    for (person_id, score) in [(requester.id, 1), (voter.id, -1), (voter.id, 1)] {
+      CommunityRequestVote::vote(
+        pool,
+        &CommunityRequestVoteForm {
+          person_id,
+          community_request_id: request.id,
+          score,
+        },
+      )
+      .await?;
+    }
+
+    let view = CommunityRequestView::read(pool, request.id, Some(voter.id)).await?;
+    assert_eq!(2, view.score);
+    assert_eq!(Some(1), view.my_vote);
+
+    CommunityRequestVote::remove(pool, voter.id, request.id).await?;
+    let view = CommunityRequestView::read(pool, request.id, None).await?;
+    assert_eq!(1, view.score);
+    assert_eq!(None, view.my_vote);
+
+    CommunityRequestComment::create(
+      pool,
+      &CommunityRequestCommentForm::new(request.id, voter.id, "Maybe plants instead?".into()),
+    )
+    .await?;
+    let comments = CommunityRequestCommentView::list_for_request(pool, request.id).await?;
+    assert_eq!(1, comments.len());
+    assert_eq!(voter.id, comments[0].creator.id);
+
+    let pending = CommunityRequestQuery {
+      status: Some(CommunityRequestStatus::Pending),
+      sort_by_score: true,
+      ..Default::default()
+    }
+    .list(pool)
+    .await?;
+    assert_eq!(1, pending.len());
+    let denied = CommunityRequestQuery {
+      status: Some(CommunityRequestStatus::Denied),
+      ..Default::default()
+    }
+    .list(pool)
+    .await?;
+    assert!(denied.is_empty());
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+}
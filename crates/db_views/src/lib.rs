@@ -1,17 +1,27 @@
 #[cfg(test)]
 extern crate serial_test;
 
+#[cfg(feature = "full")]
+pub mod ban_appeal_view;
 #[cfg(feature = "full")]
 pub mod comment_report_view;
 #[cfg(feature = "full")]
 pub mod comment_view;
 #[cfg(feature = "full")]
+pub mod community_request_view;
+#[cfg(feature = "full")]
 pub mod custom_emoji_view;
 #[cfg(feature = "full")]
 pub mod local_image_view;
 #[cfg(feature = "full")]
 pub mod local_user_view;
 #[cfg(feature = "full")]
+pub mod person_mod_note_view;
+#[cfg(feature = "full")]
+pub mod pm_conversation_view;
+#[cfg(feature = "full")]
+pub mod poll_view;
+#[cfg(feature = "full")]
 pub mod post_report_view;
 #[cfg(feature = "full")]
 pub mod post_view;
@@ -22,7 +32,17 @@ pub mod private_message_view;
 #[cfg(feature = "full")]
 pub mod registration_application_view;
 #[cfg(feature = "full")]
+pub mod report_combined_view;
+#[cfg(feature = "full")]
+pub mod report_note_view;
+#[cfg(feature = "full")]
 pub mod site_view;
+#[cfg(feature = "full")]
+pub mod spam_score_view;
 pub mod structs;
+#[cfg(all(test, feature = "full"))]
+mod visibility_tests;
+#[cfg(feature = "full")]
+pub mod vote_anomaly_view;
 #[cfg(feature = "full")]
 pub mod vote_view;
@@ -0,0 +1,144 @@
+use crate::structs::{LocalUserView, PersonModNoteView};
+use diesel::{result::Error, ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{PersonId, PersonModNoteId},
+  schema::{community, community_actions, person, person_mod_note},
+  utils::{get_conn, DbPool},
+};
+
+impl PersonModNoteView {
+  pub async fn read(pool: &mut DbPool<'_>, note_id: PersonModNoteId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    person_mod_note::table
+      .find(note_id)
+      .inner_join(person::table.on(person_mod_note::creator_id.eq(person::id)))
+      .left_join(community::table)
+      .select((
+        person_mod_note::all_columns,
+        person::all_columns,
+        community::all_columns.nullable(),
+      ))
+      .first(conn)
+      .await
+  }
+
+  /// The notes on the given persons which the user can see, newest first. Admins see all notes,
+  /// moderators only those of the communities they moderate.
+  pub async fn list_for_persons(
+    pool: &mut DbPool<'_>,
+    person_ids: &[PersonId],
+    local_user_view: &LocalUserView,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = person_mod_note::table
+      .inner_join(person::table.on(person_mod_note::creator_id.eq(person::id)))
+      .left_join(community::table)
+      .filter(person_mod_note::person_id.eq_any(person_ids))
+      .select((
+        person_mod_note::all_columns,
+        person::all_columns,
+        community::all_columns.nullable(),
+      ))
+      .into_boxed();
+
+    if !local_user_view.local_user.admin {
+      let moderated = community_actions::table
+        .filter(community_actions::person_id.eq(local_user_view.person.id))
+        .filter(community_actions::became_moderator.is_not_null())
+        .select(community_actions::community_id.nullable());
+      query = query.filter(person_mod_note::community_id.eq_any(moderated));
+    }
+
+    query
+      .order_by(person_mod_note::published.desc())
+      .then_order_by(person_mod_note::id.desc())
+      .load(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing)]
+mod tests {
+  use crate::structs::{LocalUserView, PersonModNoteView};
+  use lemmy_db_schema::{
+    source::{
+      local_user_vote_display_mode::LocalUserVoteDisplayMode,
+      person_mod_note::{PersonModNote, PersonModNoteInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_db_testutils::SeededInstance;
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_visibility() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = SeededInstance::create(pool, "my_domain.tld").await?;
+    let (admin, admin_local_user) = instance.admin(pool, "admin_pmnv").await?;
+    let (moderator, moderator_local_user) = instance.local_user(pool, "mod_pmnv").await?;
+    let spammer = instance.person(pool, "spammer_pmnv").await?;
+    let moderated = instance
+      .community("moderated_pmnv")
+      .moderator(moderator.id)
+      .create(pool)
+      .await?;
+    let other = instance.community("other_pmnv").create(pool).await?;
+    let admin_view = LocalUserView {
+      local_user: admin_local_user,
+      local_user_vote_display_mode: LocalUserVoteDisplayMode::default(),
+      person: admin.clone(),
+      counts: Default::default(),
+    };
+    let moderator_view = LocalUserView {
+      local_user: moderator_local_user,
+      local_user_vote_display_mode: LocalUserVoteDisplayMode::default(),
+      person: moderator.clone(),
+      counts: Default::default(),
+    };
+
+    for (community_id, creator_id, content) in [
+      (None, admin.id, "warned twice for spam"),
+      (
+        Some(moderated.community.id),
+        moderator.id,
+        "removed a spam post",
+      ),
+      (Some(other.community.id), admin.id, "banned from other"),
+    ] {
+      let form = PersonModNoteInsertForm {
+        person_id: spammer.id,
+        community_id,
+        creator_id,
+        content: content.into(),
+      };
+      PersonModNote::create(pool, &form).await?;
+    }
+
+    // Admins see everything, moderators only the notes of their own community
+    let notes = PersonModNoteView::list_for_persons(pool, &[spammer.id], &admin_view).await?;
+    assert_eq!(3, notes.len());
+    let notes = PersonModNoteView::list_for_persons(pool, &[spammer.id], &moderator_view).await?;
+    assert_eq!(1, notes.len());
+    assert_eq!("removed a spam post", notes[0].person_mod_note.content);
+    assert_eq!(moderator, notes[0].creator);
+    assert_eq!(
+      Some(moderated.community.id),
+      notes[0].community.as_ref().map(|c| c.id)
+    );
+
+    // Notes on other persons aren't included
+    let notes = PersonModNoteView::list_for_persons(pool, &[moderator.id], &admin_view).await?;
+    assert!(notes.is_empty());
+
+    instance.delete(pool).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,316 @@
+use crate::structs::PmConversationView;
+use diesel::{
+  dsl::{count, exists},
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::PersonId,
+  schema::{
+    instance_actions,
+    person,
+    person_actions,
+    pm_conversation,
+    pm_conversation_participant,
+    private_message,
+  },
+  source::{person::Person, pm_conversation::PmConversation, private_message::PrivateMessage},
+  utils::{actions, functions::coalesce, get_conn, limit_and_offset, DbPool},
+};
+
+#[derive(Default)]
+pub struct PmConversationQuery {
+  /// Only conversations which contain unread messages.
+  pub unread_only: bool,
+  /// Only message requests which I haven't accepted yet. Otherwise these are left out.
+  pub requests_only: bool,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+impl PmConversationQuery {
+  /// Lists the conversations of the given person, with the most recently active ones first.
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+    my_person_id: PersonId,
+  ) -> Result<Vec<PmConversationView>, Error> {
+    let conn = &mut get_conn(pool).await?;
+
+    // Messages sent directly to me are unread until they are marked as read, while group messages
+    // are unread if they were published after I last read the group.
+    let unread = || {
+      private_message::table
+        .filter(private_message::conversation_id.eq(pm_conversation::id))
+        .filter(private_message::deleted.eq(false))
+        .filter(
+          private_message::recipient_id
+            .eq(my_person_id)
+            .and(private_message::read.eq(false))
+            .or(
+              private_message::recipient_id
+                .is_null()
+                .and(private_message::creator_id.ne(my_person_id))
+                .and(private_message::published.gt(pm_conversation_participant::last_read)),
+            ),
+        )
+    };
+
+    let mut query = pm_conversation::table
+      .left_join(
+        person::table.on(
+          pm_conversation::person_a_id
+            .eq(my_person_id)
+            .and(person::id.nullable().eq(pm_conversation::person_b_id))
+            .or(
+              pm_conversation::person_b_id
+                .eq(my_person_id)
+                .and(person::id.nullable().eq(pm_conversation::person_a_id)),
+            ),
+        ),
+      )
+      .left_join(
+        pm_conversation_participant::table.on(
+          pm_conversation_participant::conversation_id
+            .eq(pm_conversation::id)
+            .and(pm_conversation_participant::person_id.eq(my_person_id)),
+        ),
+      )
+      .left_join(actions(
+        person_actions::table,
+        Some(my_person_id),
+        person::id,
+      ))
+      .left_join(actions(
+        instance_actions::table,
+        Some(my_person_id),
+        person::instance_id,
+      ))
+      // Only conversations which I take part in
+      .filter(
+        person::id.nullable().is_not_null().or(
+          pm_conversation_participant::person_id
+            .nullable()
+            .is_not_null(),
+        ),
+      )
+      // Dont show conversations with blocked users
+      .filter(person_actions::blocked.is_null())
+      // Dont show conversations with blocked instances
+      .filter(instance_actions::blocked.is_null())
+      .select((
+        pm_conversation::all_columns,
+        person::all_columns.nullable(),
+        coalesce(
+          unread().select(count(private_message::id)).single_value(),
+          0,
+        ),
+      ))
+      .into_boxed();
+
+    if self.unread_only {
+      query = query.filter(exists(unread()));
+    }
+
+    query = if self.requests_only {
+      query.filter(pm_conversation::request_recipient_id.eq(my_person_id))
+    } else {
+      query.filter(
+        pm_conversation::request_recipient_id
+          .is_null()
+          .or(pm_conversation::request_recipient_id.ne(my_person_id)),
+      )
+    };
+
+    let (limit, offset) = limit_and_offset(self.page, self.limit)?;
+    let conversations = query
+      .order_by(pm_conversation::last_message_published.desc())
+      .then_order_by(pm_conversation::id.desc())
+      .limit(limit)
+      .offset(offset)
+      .load::<(PmConversation, Option<Person>, i64)>(conn)
+      .await?;
+
+    // Load the newest message of each conversation
+    let conversation_ids = conversations.iter().map(|c| c.0.id).collect::<Vec<_>>();
+    let mut last_messages = private_message::table
+      .filter(private_message::conversation_id.eq_any(conversation_ids))
+      .filter(private_message::deleted.eq(false))
+      .distinct_on(private_message::conversation_id)
+      .order_by((
+        private_message::conversation_id,
+        private_message::published.desc(),
+      ))
+      .load::<PrivateMessage>(conn)
+      .await?;
+
+    Ok(
+      conversations
+        .into_iter()
+        .map(|(conversation, other_person, unread_count)| {
+          let last_message = last_messages
+            .iter()
+            .position(|m| m.conversation_id == conversation.id)
+            .map(|i| last_messages.swap_remove(i));
+          PmConversationView {
+            conversation,
+            other_person,
+            last_message,
+            unread_count,
+          }
+        })
+        .collect(),
+    )
+  }
+}
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing)]
+mod tests {
+
+  use crate::pm_conversation_view::PmConversationQuery;
+  use lemmy_db_schema::{
+    assert_length,
+    source::{
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      person_block::{PersonBlock, PersonBlockForm},
+      pm_conversation::{PmConversation, PmConversationParticipant, PmGroupConversationInsertForm},
+      private_message::{PrivateMessage, PrivateMessageInsertForm},
+    },
+    traits::{Blockable, Crud},
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn list_conversations() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let timmy =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "timmy_pmc")).await?;
+    let sara = Person::create(pool, &PersonInsertForm::test_form(instance.id, "sara_pmc")).await?;
+    let jess = Person::create(pool, &PersonInsertForm::test_form(instance.id, "jess_pmc")).await?;
+
+    for (creator, recipient) in [
+      (&sara, &timmy),
+      (&sara, &timmy),
+      (&timmy, &sara),
+      (&jess, &timmy),
+    ] {
+      let form = PrivateMessageInsertForm::new(creator.id, Some(recipient.id), String::new());
+      PrivateMessage::create(pool, &form).await?;
+    }
+    let last = PrivateMessageInsertForm::new(timmy.id, Some(jess.id), "last".into());
+    let last = PrivateMessage::create(pool, &last).await?;
+
+    // The conversation with jess has the newest message, so it comes first
+    let conversations = PmConversationQuery::default().list(pool, timmy.id).await?;
+    assert_length!(2, &conversations);
+    assert_eq!(
+      Some(jess.id),
+      conversations[0].other_person.as_ref().map(|p| p.id)
+    );
+    assert_eq!(
+      Some(last.id),
+      conversations[0].last_message.as_ref().map(|m| m.id)
+    );
+    assert_eq!(1, conversations[0].unread_count);
+    assert_eq!(
+      Some(sara.id),
+      conversations[1].other_person.as_ref().map(|p| p.id)
+    );
+    assert_eq!(2, conversations[1].unread_count);
+
+    // Sara has no unread messages, only timmy's reply
+    let sara_conversations = PmConversationQuery::default().list(pool, sara.id).await?;
+    assert_length!(1, &sara_conversations);
+    assert_eq!(
+      Some(timmy.id),
+      sara_conversations[0].other_person.as_ref().map(|p| p.id)
+    );
+    assert_eq!(1, sara_conversations[0].unread_count);
+
+    PrivateMessage::mark_conversation_as_read(pool, conversations[1].conversation.id, timmy.id)
+      .await?;
+    let unread = PmConversationQuery {
+      unread_only: true,
+      ..Default::default()
+    }
+    .list(pool, timmy.id)
+    .await?;
+    assert_length!(1, &unread);
+    assert_eq!(Some(jess.id), unread[0].other_person.as_ref().map(|p| p.id));
+
+    // Conversations with blocked persons are hidden
+    let form = PersonBlockForm {
+      person_id: timmy.id,
+      target_id: jess.id,
+    };
+    PersonBlock::block(pool, &form).await?;
+    let conversations = PmConversationQuery::default().list(pool, timmy.id).await?;
+    assert_length!(1, &conversations);
+    assert_eq!(
+      Some(sara.id),
+      conversations[0].other_person.as_ref().map(|p| p.id)
+    );
+
+    // Group messages are unread until the participant reads the group
+    let group = PmGroupConversationInsertForm::new(Some("group".into()));
+    let group = PmConversation::create_group(pool, &group, &[timmy.id, sara.id]).await?;
+    let mut message = PrivateMessageInsertForm::new(sara.id, None, "hello".into());
+    message.conversation_id = Some(group.id);
+    PrivateMessage::create(pool, &message).await?;
+
+    let conversations = PmConversationQuery::default().list(pool, timmy.id).await?;
+    assert_length!(2, &conversations);
+    assert_eq!(group.id, conversations[0].conversation.id);
+    assert_eq!(None, conversations[0].other_person);
+    assert_eq!(1, conversations[0].unread_count);
+    let sara_conversations = PmConversationQuery::default().list(pool, sara.id).await?;
+    assert_eq!(0, sara_conversations[0].unread_count);
+
+    PmConversationParticipant::mark_as_read(pool, group.id, timmy.id).await?;
+    let conversations = PmConversationQuery::default().list(pool, timmy.id).await?;
+    assert_eq!(0, conversations[0].unread_count);
+
+    // Message requests are listed separately until they are accepted
+    let dave = Person::create(pool, &PersonInsertForm::test_form(instance.id, "dave_pmc")).await?;
+    let form = PrivateMessageInsertForm::new(dave.id, Some(timmy.id), "hi".into());
+    let request = PrivateMessage::create(pool, &form).await?;
+    PmConversation::mark_as_request(pool, request.conversation_id, timmy.id).await?;
+    let requests_only = PmConversationQuery {
+      requests_only: true,
+      ..Default::default()
+    };
+
+    let conversations = PmConversationQuery::default().list(pool, timmy.id).await?;
+    assert_length!(2, &conversations);
+    let requests = requests_only.list(pool, timmy.id).await?;
+    assert_length!(1, &requests);
+    assert_eq!(
+      Some(dave.id),
+      requests[0].other_person.as_ref().map(|p| p.id)
+    );
+    let dave_conversations = PmConversationQuery::default().list(pool, dave.id).await?;
+    assert_length!(1, &dave_conversations);
+
+    PmConversation::accept_request(pool, request.conversation_id, timmy.id).await?;
+    let conversations = PmConversationQuery::default().list(pool, timmy.id).await?;
+    assert_length!(3, &conversations);
+
+    Instance::delete(pool, instance.id).await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,48 @@
+use crate::structs::PollView;
+use diesel::result::Error;
+use lemmy_db_schema::{
+  newtypes::{PersonId, PollId, PostId},
+  source::poll::{Poll, PollOption, PollVote},
+  utils::DbPool,
+};
+
+impl PollView {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    poll_id: PollId,
+    my_person_id: Option<PersonId>,
+  ) -> Result<Self, Error> {
+    let poll = Poll::read(pool, poll_id).await?;
+    Self::from_poll(pool, poll, my_person_id).await
+  }
+
+  /// The poll of a post, if it has one. The votes of the person are included if one is given.
+  pub async fn read_for_post(
+    pool: &mut DbPool<'_>,
+    post_id: PostId,
+    my_person_id: Option<PersonId>,
+  ) -> Result<Option<Self>, Error> {
+    let Some(poll) = Poll::read_for_post(pool, post_id).await? else {
+      return Ok(None);
+    };
+    Ok(Some(Self::from_poll(pool, poll, my_person_id).await?))
+  }
+
+  async fn from_poll(
+    pool: &mut DbPool<'_>,
+    poll: Poll,
+    my_person_id: Option<PersonId>,
+  ) -> Result<Self, Error> {
+    let options = PollOption::list_for_poll(pool, poll.id).await?;
+    let my_votes = if let Some(person_id) = my_person_id {
+      Some(PollVote::list_for_person(pool, poll.id, person_id).await?)
+    } else {
+      None
+    };
+    Ok(PollView {
+      poll,
+      options,
+      my_votes,
+    })
+  }
+}
@@ -1,4 +1,4 @@
-use crate::structs::{LocalUserView, PostReportView};
+use crate::structs::{LocalUserView, PaginationCursor, PostReportView};
 use diesel::{
   pg::Pg,
   result::Error,
@@ -23,13 +23,13 @@ use lemmy_db_schema::{
     post_aggregates,
     post_report,
   },
-  source::community::CommunityFollower,
+  source::{community::CommunityFollower, post::Post, post_report::PostReport},
   utils::{
     actions,
     actions_alias,
     functions::coalesce,
     get_conn,
-    limit_and_offset,
+    limit_fetch,
     DbConn,
     DbPool,
     ListFn,
@@ -126,19 +126,50 @@ fn queries<'a>() -> Queries<
       query = query.filter(post::id.eq(post_id));
     }
 
+    if let Some(ids) = options.ids {
+      query = query.filter(post_report::id.eq_any(ids));
+    }
+
+    if let Some(creator_id) = options.creator_id {
+      query = query.filter(post_report::creator_id.eq(creator_id));
+    }
+
+    if let Some(post_creator_id) = options.post_creator_id {
+      query = query.filter(post::creator_id.eq(post_creator_id));
+    }
+
     // If viewing all reports, order by newest, but if viewing unresolved only, show the oldest
-    // first (FIFO)
+    // first (FIFO). Reports published at the same time are ordered by id.
     if options.unresolved_only {
       query = query
         .filter(post_report::resolved.eq(false))
-        .order_by(post_report::published.asc());
+        .order_by(post_report::published.asc())
+        .then_order_by(post_report::id.asc());
     } else {
-      query = query.order_by(post_report::published.desc());
+      query = query
+        .order_by(post_report::published.desc())
+        .then_order_by(post_report::id.desc());
     }
 
-    let (limit, offset) = limit_and_offset(options.page, options.limit)?;
+    // Only reports which come after the cursor in the order above
+    if let Some(PostReportPaginationCursorData(after)) = options.page_after {
+      let same_published = post_report::published.eq(after.published);
+      if options.unresolved_only {
+        query = query.filter(
+          post_report::published
+            .gt(after.published)
+            .or(same_published.and(post_report::id.gt(after.id))),
+        );
+      } else {
+        query = query.filter(
+          post_report::published
+            .lt(after.published)
+            .or(same_published.and(post_report::id.lt(after.id))),
+        );
+      }
+    }
 
-    query = query.limit(limit).offset(offset);
+    query = query.limit(limit_fetch(options.limit)?);
 
     // If its not an admin, get only the ones you mod
     if !user.local_user.admin {
@@ -160,7 +191,15 @@ impl PostReportView {
     report_id: PostReportId,
     my_person_id: PersonId,
   ) -> Result<Self, Error> {
-    queries().read(pool, (report_id, my_person_id)).await
+    let mut res = queries().read(pool, (report_id, my_person_id)).await?;
+    Post::restore_archived(pool, [&mut res.post]).await?;
+    Ok(res)
+  }
+
+  /// Get cursor for the page that starts immediately after this report.
+  pub fn to_cursor(&self) -> PaginationCursor {
+    // hex encoding to prevent ossification
+    PaginationCursor(format!("P{:x}", self.post_report.id.0))
   }
 
   /// returns the current unresolved post report count for the communities you mod
@@ -204,11 +243,39 @@ impl PostReportView {
   }
 }
 
+/// The position of a post report in a report list, used for keyset pagination.
+#[derive(Debug, Clone)]
+pub struct PostReportPaginationCursorData(PostReport);
+
+impl PostReportPaginationCursorData {
+  /// Reads the report referenced by the given cursor.
+  pub async fn read(cursor: &PaginationCursor, pool: &mut DbPool<'_>) -> Result<Self, Error> {
+    let err_msg = || Error::QueryBuilderError("Could not parse pagination token".into());
+    let id = cursor
+      .0
+      .strip_prefix('P')
+      .and_then(|e| i32::from_str_radix(e, 16).ok())
+      .ok_or_else(err_msg)?;
+    let conn = &mut get_conn(pool).await?;
+    let report = post_report::table
+      .find(PostReportId(id))
+      .first::<PostReport>(conn)
+      .await?;
+    Ok(PostReportPaginationCursorData(report))
+  }
+}
+
 #[derive(Default)]
 pub struct PostReportQuery {
   pub community_id: Option<CommunityId>,
   pub post_id: Option<PostId>,
-  pub page: Option<i64>,
+  /// Only the reports with these ids
+  pub ids: Option<Vec<PostReportId>>,
+  /// Only reports filed by this person
+  pub creator_id: Option<PersonId>,
+  /// Only reports against content created by this person
+  pub post_creator_id: Option<PersonId>,
+  pub page_after: Option<PostReportPaginationCursorData>,
   pub limit: Option<i64>,
   pub unresolved_only: bool,
 }
@@ -219,7 +286,9 @@ impl PostReportQuery {
     pool: &mut DbPool<'_>,
     user: &LocalUserView,
   ) -> Result<Vec<PostReportView>, Error> {
-    queries().list(pool, (self, user)).await
+    let mut reports = queries().list(pool, (self, user)).await?;
+    Post::restore_archived(pool, reports.iter_mut().map(|r| &mut r.post)).await?;
+    Ok(reports)
   }
 }
 
@@ -228,7 +297,7 @@ impl PostReportQuery {
 mod tests {
 
   use crate::{
-    post_report_view::{PostReportQuery, PostReportView},
+    post_report_view::{PostReportPaginationCursorData, PostReportQuery, PostReportView},
     structs::LocalUserView,
   };
   use lemmy_db_schema::{
@@ -353,11 +422,81 @@ mod tests {
     assert_eq!(reports[1].creator.id, inserted_sara.id);
     assert_eq!(reports[0].creator.id, inserted_jessica.id);
 
+    // The page after jessicas report only contains saras
+    let next_page = PostReportQuery {
+      page_after: Some(PostReportPaginationCursorData::read(&reports[0].to_cursor(), pool).await?),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_length!(1, next_page);
+    assert_eq!(next_page[0].creator.id, inserted_sara.id);
+
+    // Unresolved reports are paged oldest first, one report per page
+    let first_page = PostReportQuery {
+      unresolved_only: true,
+      limit: Some(1),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_length!(1, first_page);
+    assert_eq!(first_page[0].creator.id, inserted_sara.id);
+    let second_page = PostReportQuery {
+      unresolved_only: true,
+      limit: Some(1),
+      page_after: Some(
+        PostReportPaginationCursorData::read(&first_page[0].to_cursor(), pool).await?,
+      ),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_length!(1, second_page);
+    assert_eq!(second_page[0].creator.id, inserted_jessica.id);
+    let last_page = PostReportQuery {
+      unresolved_only: true,
+      limit: Some(1),
+      page_after: Some(
+        PostReportPaginationCursorData::read(&second_page[0].to_cursor(), pool).await?,
+      ),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_length!(0, last_page);
+
     // Make sure the counts are correct
     let report_count =
       PostReportView::get_report_count(pool, inserted_timmy.id, false, None).await?;
     assert_eq!(2, report_count);
 
+    // Filter by the person who filed the report
+    let sara_filed = PostReportQuery {
+      creator_id: Some(inserted_sara.id),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_length!(1, sara_filed);
+    assert_eq!(sara_filed[0].post.id, inserted_post.id);
+
+    // Filter by the person whose content was reported
+    let timmy_received = PostReportQuery {
+      post_creator_id: Some(inserted_timmy.id),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_length!(2, timmy_received);
+    let sara_received = PostReportQuery {
+      post_creator_id: Some(inserted_sara.id),
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_length!(0, sara_received);
+
     // Pretend the post was removed, and resolve all reports for that object.
     // This is called manually in the API for post removals
     PostReport::resolve_all_for_object(pool, inserted_jessica_report.post_id, inserted_timmy.id)
@@ -1,12 +1,15 @@
 use crate::structs::{PaginationCursor, PostView};
 use diesel::{
   debug_query,
+  deserialize::{self, FromStaticSqlRow, Queryable},
   dsl::{exists, not, IntervalDsl},
   pg::Pg,
   query_builder::AsQuery,
   result::Error,
+  sql_types::Text,
   BoolExpressionMethods,
   ExpressionMethods,
+  IntoSql,
   JoinOnDsl,
   NullableExpressionMethods,
   OptionalExtension,
@@ -19,30 +22,41 @@ use lemmy_db_schema::{
   aggregates::structs::{post_aggregates_keys as key, PostAggregates},
   aliases::creator_community_actions,
   impls::local_user::LocalUserOptionHelper,
-  newtypes::{CommunityId, LocalUserId, PersonId, PostId},
+  newtypes::{CommunityId, CommunityPostTagId, CustomFeedId, LocalUserId, PersonId, PostId},
   schema::{
     community,
     community_actions,
+    custom_feed_community,
     image_details,
+    image_text,
     instance_actions,
     local_user,
+    local_user_community_settings,
     local_user_language,
     person,
     person_actions,
     post,
     post_actions,
     post_aggregates,
+    post_archive,
+    post_tag,
   },
   source::{
-    community::{CommunityFollower, CommunityFollowerState},
+    community::{Community, CommunityFollower, CommunityFollowerState},
+    community_post_tag::{CommunityPostTag, PostTag},
+    images::ImageDetails,
     local_user::LocalUser,
+    local_user_keyword_block::LocalUserKeywordBlock,
+    person::Person,
+    post::Post,
+    post_media::PostMedia,
     site::Site,
   },
   utils::{
     action_query,
     actions,
     actions_alias,
-    functions::coalesce,
+    functions::{coalesce, RegexMatchInsensitive},
     fuzzy_search,
     get_conn,
     limit_and_offset,
@@ -58,10 +72,83 @@ use lemmy_db_schema::{
   CommunityVisibility,
   ListingType,
   PostSortType,
+  SubscribedType,
 };
 use tracing::debug;
 use PostSortType::*;
 
+type PostViewTuple = (
+  Post,
+  Person,
+  Community,
+  Option<ImageDetails>,
+  bool,
+  bool,
+  bool,
+  bool,
+  PostAggregates,
+  SubscribedType,
+  bool,
+  bool,
+  bool,
+  bool,
+  Option<i16>,
+  i64,
+  Option<String>,
+);
+
+/// The gallery and tags aren't part of the selected row, they are loaded afterwards with
+/// [`PostView::fill_gallery`] and [`PostView::fill_tags`].
+impl<ST> Queryable<ST, Pg> for PostView
+where
+  PostViewTuple: FromStaticSqlRow<ST, Pg>,
+{
+  type Row = PostViewTuple;
+
+  fn build(row: Self::Row) -> deserialize::Result<Self> {
+    let (
+      post,
+      creator,
+      community,
+      image_details,
+      creator_banned_from_community,
+      banned_from_community,
+      creator_is_moderator,
+      creator_is_admin,
+      counts,
+      subscribed,
+      saved,
+      read,
+      hidden,
+      creator_blocked,
+      my_vote,
+      unread_comments,
+      saved_note,
+    ) = row;
+    Ok(Self {
+      post,
+      creator,
+      community,
+      image_details,
+      creator_banned_from_community,
+      banned_from_community,
+      creator_is_moderator,
+      creator_is_admin,
+      counts,
+      subscribed,
+      saved,
+      read,
+      hidden,
+      creator_blocked,
+      my_vote,
+      unread_comments,
+      saved_note,
+      gallery: vec![],
+      tags: vec![],
+    })
+  }
+}
+
 fn queries<'a>() -> Queries<
   impl ReadFn<'a, PostView, (PostId, Option<&'a LocalUser>, bool)>,
   impl ListFn<'a, PostView, (PostQuery<'a>, &'a Site)>,
@@ -133,6 +220,7 @@ fn queries<'a>() -> Queries<
           post_aggregates::comments.nullable() - post_actions::read_comments_amount.nullable(),
           post_aggregates::comments,
         ),
+        post_actions::saved_note.nullable(),
       ))
   };
 
@@ -177,6 +265,12 @@ fn queries<'a>() -> Queries<
             .eq(false)
             .or(post::creator_id.eq(person_id_join)),
         )
+        // posts waiting for approval are only visible to their creator
+        .filter(
+          post::pending
+            .eq(false)
+            .or(post::creator_id.eq(person_id_join)),
+        )
         // private communities can only by browsed by accepted followers
         .filter(
           community::visibility
@@ -185,6 +279,15 @@ fn queries<'a>() -> Queries<
         );
     }
 
+    // posts of shadow banned persons are only visible to themselves and admins
+    if !my_local_user.is_admin() {
+      query = query.filter(
+        person::shadow_banned
+          .eq(false)
+          .or(post::creator_id.eq(person_id_join)),
+      );
+    }
+
     query = my_local_user.visible_communities_only(query);
 
     Commented::new(query)
@@ -208,6 +311,28 @@ fn queries<'a>() -> Queries<
     // hide posts from deleted communities
     query = query.filter(community::deleted.eq(false));
 
+    // hide posts which match the keyword blocks of the user, except their own
+    if let (Some(local_user_id), Some(person_id)) = (
+      options.local_user.local_user_id(),
+      options.local_user.person_id(),
+    ) {
+      if let Some(pattern) =
+        LocalUserKeywordBlock::read_pattern(&mut (&mut conn).into(), local_user_id).await?
+      {
+        let matches_name =
+          RegexMatchInsensitive::new(post::name, pattern.clone().into_sql::<Text>());
+        let matches_body = RegexMatchInsensitive::new(
+          coalesce(post::body, String::new()),
+          pattern.into_sql::<Text>(),
+        );
+        query = query.filter(
+          post::creator_id
+            .eq(person_id)
+            .or(not(matches_name.or(matches_body))),
+        );
+      }
+    }
+
     // only creator can see deleted posts and unpublished scheduled posts
     if let Some(person_id) = options.local_user.person_id() {
       query = query.filter(post::deleted.eq(false).or(post::creator_id.eq(person_id)));
@@ -222,6 +347,16 @@ fn queries<'a>() -> Queries<
         .filter(post::scheduled_publish_time.is_null());
     }
 
+    // posts waiting for approval are only visible to their creator, except in the approval queue
+    // which shows nothing else
+    if options.pending_only.unwrap_or_default() {
+      query = query.filter(post::pending.eq(true));
+    } else if let Some(person_id) = options.local_user.person_id() {
+      query = query.filter(post::pending.eq(false).or(post::creator_id.eq(person_id)));
+    } else {
+      query = query.filter(post::pending.eq(false));
+    }
+
     // only show removed posts to admin when viewing user profile
     if !(options.creator_id.is_some() && options.local_user.is_admin()) {
       query = query
@@ -232,6 +367,26 @@ fn queries<'a>() -> Queries<
       query = query.filter(post_aggregates::community_id.eq(community_id));
     }
 
+    if let Some(tag_id) = options.tag_id {
+      query = query.filter(exists(
+        post_tag::table.filter(
+          post_tag::post_id
+            .eq(post_aggregates::post_id)
+            .and(post_tag::tag_id.eq(tag_id)),
+        ),
+      ));
+    }
+
+    if let Some(custom_feed_id) = options.custom_feed_id {
+      query = query.filter(exists(
+        custom_feed_community::table.filter(
+          custom_feed_community::community_id
+            .eq(post_aggregates::community_id)
+            .and(custom_feed_community::custom_feed_id.eq(custom_feed_id)),
+        ),
+      ));
+    }
+
     if let Some(creator_id) = options.creator_id {
       query = query.filter(post_aggregates::creator_id.eq(creator_id));
     }
@@ -240,14 +395,33 @@ fn queries<'a>() -> Queries<
     match options.listing_type.unwrap_or_default() {
       ListingType::Subscribed => query = query.filter(is_subscribed),
       ListingType::Local => {
-        query = query
-          .filter(community::local.eq(true))
-          .filter(community::hidden.eq(false).or(is_subscribed));
+        query = query.filter(community::local.eq(true)).filter(
+          community::hidden
+            .eq(false)
+            .and(community::soft_launched.eq(false))
+            .or(is_subscribed),
+        );
+      }
+      ListingType::All => {
+        query = query.filter(
+          community::hidden
+            .eq(false)
+            .and(community::soft_launched.eq(false))
+            .or(is_subscribed),
+        )
       }
-      ListingType::All => query = query.filter(community::hidden.eq(false).or(is_subscribed)),
       ListingType::ModeratorView => {
         query = query.filter(community_actions::became_moderator.is_not_null());
       }
+      // Only the discovery part of the home listing, subscribed posts are fetched separately
+      // in [PostQuery::list_home]
+      ListingType::Home => {
+        query = query
+          .filter(community::hidden.eq(false))
+          .filter(community::soft_launched.eq(false))
+          .filter(community_actions::followed.is_null())
+          .filter(community_actions::suppressed_recommendation.is_null());
+      }
     }
 
     if let Some(search_term) = &options.search_term {
@@ -257,10 +431,27 @@ fn queries<'a>() -> Queries<
         let searcher = fuzzy_search(search_term);
         let name_filter = post::name.ilike(searcher.clone());
         let body_filter = post::body.ilike(searcher.clone());
+        // The body of archived posts was moved to the archive table
+        let archive_filter = post::archived.and(exists(
+          post_archive::table
+            .filter(post_archive::post_id.eq(post::id))
+            .filter(post_archive::body.ilike(searcher.clone())),
+        ));
+        // Text recognized in uploaded images, unless the community opted out
+        let image_text_filter = community::image_text_search.and(exists(
+          image_text::table
+            .filter(image_text::link.nullable().eq(post::url))
+            .filter(image_text::text.ilike(searcher.clone())),
+        ));
         query = if options.title_only.unwrap_or_default() {
           query.filter(name_filter)
         } else {
-          query.filter(name_filter.or(body_filter))
+          query.filter(
+            name_filter
+              .or(body_filter)
+              .or(archive_filter)
+              .or(image_text_filter),
+          )
         }
         .filter(not(post::removed.or(post::deleted)));
       }
@@ -275,26 +466,62 @@ fn queries<'a>() -> Queries<
         .filter(community::nsfw.eq(false));
     };
 
+    // The bot settings of the user can be overridden for single communities
+    let community_shows_bots = |show: bool| {
+      exists(
+        local_user_community_settings::table.filter(
+          local_user_community_settings::local_user_id
+            .eq(local_user_id_join)
+            .and(local_user_community_settings::community_id.eq(post::community_id))
+            .and(local_user_community_settings::show_bot_accounts.eq(show)),
+        ),
+      )
+    };
     if !options.local_user.show_bot_accounts() {
-      query = query.filter(person::bot_account.eq(false));
+      query = query.filter(person::bot_account.eq(false).or(community_shows_bots(true)));
+    } else if options.local_user.is_some() {
+      query = query.filter(not(person::bot_account.and(community_shows_bots(false))));
     };
 
+    // down-ranked bot posts go last, unless the community overrides it
+    if options.local_user.downrank_bot_accounts() {
+      query = query.then_order_by(
+        person::bot_account
+          .and(not(community_shows_bots(true)))
+          .asc(),
+      );
+    }
+
+    if !options.local_user.is_admin() {
+      query = query.filter(
+        person::shadow_banned.eq(false).or(
+          post::creator_id
+            .nullable()
+            .eq(options.local_user.person_id()),
+        ),
+      );
+    }
+
     // Filter to show only posts with no comments
     if options.no_comments_only.unwrap_or_default() {
       query = query.filter(post_aggregates::comments.eq(0));
     };
 
     // If its saved only, then filter, and order by the saved time, not the comment creation time.
-    if options.saved_only.unwrap_or_default() {
+    if options.saved_only.unwrap_or_default() || options.saved_notes.is_some() {
       query = query
         .filter(post_actions::saved.is_not_null())
         .then_order_by(post_actions::saved.desc());
+      if let Some(saved_notes) = &options.saved_notes {
+        query = query.filter(post_actions::saved_note.ilike(fuzzy_search(saved_notes)));
+      }
     }
     // Only hide the read posts, if the saved_only is false. Otherwise ppl with the hide_read
-    // setting wont be able to see saved posts.
-    else if !options
-      .show_read
-      .unwrap_or(options.local_user.show_read_posts())
+    // setting wont be able to see saved posts. The same goes for the read history.
+    else if !options.read_only.unwrap_or_default()
+      && !options
+        .show_read
+        .unwrap_or(options.local_user.show_read_posts())
     {
       // Do not hide read posts when it is a user profile view
       // Or, only hide read posts on non-profile views
@@ -303,6 +530,13 @@ fn queries<'a>() -> Queries<
       }
     }
 
+    // The read history, most recently read posts first
+    if options.read_only.unwrap_or_default() {
+      query = query
+        .filter(post_actions::read.is_not_null())
+        .then_order_by(post_actions::read.desc());
+    }
+
     // If a creator id isn't given (IE its on home or community pages), hide the hidden posts
     if !options.show_hidden.unwrap_or_default() && options.creator_id.is_none() {
       query = query.filter(post_actions::hidden.is_null());
@@ -321,6 +555,10 @@ fn queries<'a>() -> Queries<
       }
     };
 
+    if options.followed_persons_only.unwrap_or_default() {
+      query = query.filter(person_actions::followed.is_not_null());
+    }
+
     query = options.local_user.visible_communities_only(query);
 
     if !options.local_user.is_admin() {
@@ -437,9 +675,41 @@ impl PostView {
     my_local_user: Option<&'a LocalUser>,
     is_mod_or_admin: bool,
   ) -> Result<Self, Error> {
-    queries()
+    let mut post_view = queries()
       .read(pool, (post_id, my_local_user, is_mod_or_admin))
-      .await
+      .await?;
+    Post::restore_archived(pool, [&mut post_view.post]).await?;
+    Self::fill_gallery(pool, std::slice::from_mut(&mut post_view)).await?;
+    Self::fill_tags(pool, std::slice::from_mut(&mut post_view)).await?;
+    Ok(post_view)
+  }
+
+  /// Loads the gallery images of the given posts.
+  pub async fn fill_gallery(pool: &mut DbPool<'_>, posts: &mut [Self]) -> Result<(), Error> {
+    let post_ids = posts.iter().map(|p| p.post.id).collect::<Vec<_>>();
+    let mut media = PostMedia::list_for_posts(pool, &post_ids).await?;
+    for post_view in posts.iter_mut() {
+      let (gallery, rest): (Vec<_>, Vec<_>) = media
+        .into_iter()
+        .partition(|m| m.post_id == post_view.post.id);
+      post_view.gallery = gallery;
+      media = rest;
+    }
+    Ok(())
+  }
+
+  /// Loads the tags of the given posts.
+  pub async fn fill_tags(pool: &mut DbPool<'_>, posts: &mut [Self]) -> Result<(), Error> {
+    let post_ids = posts.iter().map(|p| p.post.id).collect::<Vec<_>>();
+    let tags = PostTag::list_for_posts(pool, &post_ids).await?;
+    for post_view in posts.iter_mut() {
+      post_view.tags = tags
+        .iter()
+        .filter(|(post_id, _)| *post_id == post_view.post.id)
+        .map(|(_, tag)| tag.clone())
+        .collect::<Vec<CommunityPostTag>>();
+    }
+    Ok(())
   }
 }
 
@@ -482,12 +752,20 @@ pub struct PostQuery<'a> {
   // if true, the query should be handled as if community_id was not given except adding the
   // literal filter
   pub community_id_just_for_prefetch: bool,
+  /// Only posts which have this tag.
+  pub tag_id: Option<CommunityPostTagId>,
+  /// Only posts in the communities of this custom feed.
+  pub custom_feed_id: Option<CustomFeedId>,
   pub local_user: Option<&'a LocalUser>,
   pub search_term: Option<String>,
   pub url_only: Option<bool>,
   pub saved_only: Option<bool>,
+  /// Only saved items whose personal note contains this text.
+  pub saved_notes: Option<String>,
   pub liked_only: Option<bool>,
   pub disliked_only: Option<bool>,
+  /// Only posts by persons which the user follows.
+  pub followed_persons_only: Option<bool>,
   pub title_only: Option<bool>,
   pub page: Option<i64>,
   pub limit: Option<i64>,
@@ -496,8 +774,12 @@ pub struct PostQuery<'a> {
   pub page_back: Option<bool>,
   pub show_hidden: Option<bool>,
   pub show_read: Option<bool>,
+  /// Only posts which the user read. These are shown even if `show_read` is false.
+  pub read_only: Option<bool>,
   pub show_nsfw: Option<bool>,
   pub no_comments_only: Option<bool>,
+  /// Only posts which wait for approval by a moderator.
+  pub pending_only: Option<bool>,
 }
 
 impl<'a> PostQuery<'a> {
@@ -580,6 +862,18 @@ impl<'a> PostQuery<'a> {
   }
 
   pub async fn list(self, site: &Site, pool: &mut DbPool<'_>) -> Result<Vec<PostView>, Error> {
+    let mut posts = if self.listing_type == Some(ListingType::Home) {
+      self.list_home(site, pool).await?
+    } else {
+      self.list_single(site, pool).await?
+    };
+    Post::restore_archived(pool, posts.iter_mut().map(|p| &mut p.post)).await?;
+    PostView::fill_gallery(pool, &mut posts).await?;
+    PostView::fill_tags(pool, &mut posts).await?;
+    Ok(posts)
+  }
+
+  async fn list_single(self, site: &Site, pool: &mut DbPool<'_>) -> Result<Vec<PostView>, Error> {
     if self.listing_type == Some(ListingType::Subscribed)
       && self.community_id.is_none()
       && self.local_user.is_some()
@@ -597,6 +891,70 @@ impl<'a> PostQuery<'a> {
       queries().list(pool, (self, site)).await
     }
   }
+
+  /// The home listing consists of a page of subscribed posts, with posts from other communities
+  /// mixed in according to the user's `home_discovery_percent`.
+  ///
+  /// Discovery posts are only taken from the range between the first and last subscribed post of
+  /// the page, so that pagination (which continues after the last post) neither skips nor
+  /// repeats any subscribed posts.
+  async fn list_home(self, site: &Site, pool: &mut DbPool<'_>) -> Result<Vec<PostView>, Error> {
+    let (limit, _) = limit_and_offset(self.page, self.limit)?;
+    let discovery_percent = self
+      .local_user
+      .map(|l| i64::from(l.home_discovery_percent.clamp(0, 100)))
+      .unwrap_or_default();
+    // Always leave room for at least one subscribed post
+    let discovery_limit = (limit * discovery_percent / 100).min(limit - 1);
+
+    let subscribed = PostQuery {
+      listing_type: Some(ListingType::Subscribed),
+      limit: Some(limit - discovery_limit),
+      ..self.clone()
+    }
+    .list_single(site, pool)
+    .await?;
+
+    let (Some(first), Some(last)) = (subscribed.first(), subscribed.last()) else {
+      // No subscriptions (or no more subscribed posts), so only show discovery posts
+      return self.list_single(site, pool).await;
+    };
+    if discovery_limit == 0 || subscribed.len() < 2 {
+      return Ok(subscribed);
+    }
+
+    let discovery = PostQuery {
+      limit: Some(discovery_limit),
+      page: None,
+      page_after: Some(PaginationCursorData(first.counts.clone())),
+      page_before_or_equal: Some(PaginationCursorData(last.counts.clone())),
+      page_back: None,
+      ..self
+    }
+    .list_single(site, pool)
+    .await?;
+
+    Ok(interleave_home_posts(subscribed, discovery))
+  }
+}
+
+/// Spreads the discovery posts evenly between the subscribed posts. The last post is always a
+/// subscribed one, as the cursor for the next page is taken from it.
+fn interleave_home_posts<T>(subscribed: Vec<T>, discovery: Vec<T>) -> Vec<T> {
+  let discovery_count = discovery.len().min(subscribed.len().saturating_sub(1));
+  if discovery_count == 0 {
+    return subscribed;
+  }
+  let step = subscribed.len() / (discovery_count + 1);
+  let mut discovery = discovery.into_iter().take(discovery_count);
+  let mut posts = Vec::with_capacity(subscribed.len() + discovery_count);
+  for (i, post) in subscribed.into_iter().enumerate() {
+    posts.push(post);
+    if (i + 1) % step == 0 {
+      posts.extend(discovery.next());
+    }
+  }
+  posts
 }
 
 #[cfg(test)]
@@ -627,12 +985,17 @@ mod tests {
         CommunityUpdateForm,
       },
       community_block::{CommunityBlock, CommunityBlockForm},
+      community_post_tag::{CommunityPostTag, CommunityPostTagInsertForm, PostTag},
+      custom_feed::{CustomFeed, CustomFeedInsertForm},
+      images::{ImageDetailsForm, ImageText, ImageTextForm, LocalImage, LocalImageForm},
       instance::Instance,
       instance_block::{InstanceBlock, InstanceBlockForm},
       language::Language,
       local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
+      local_user_community_settings::{LocalUserCommunitySettings, LocalUserCommunitySettingsForm},
+      local_user_keyword_block::{LocalUserKeywordBlock, LocalUserKeywordBlockForm},
       local_user_vote_display_mode::LocalUserVoteDisplayMode,
-      person::{Person, PersonInsertForm},
+      person::{Person, PersonFollower, PersonFollowerForm, PersonInsertForm},
       person_block::{PersonBlock, PersonBlockForm},
       post::{
         Post,
@@ -645,6 +1008,7 @@ mod tests {
         PostSavedForm,
         PostUpdateForm,
       },
+      post_media::{PostMedia, PostMediaForm},
       site::Site,
     },
     traits::{Bannable, Blockable, Crud, Followable, Joinable, Likeable, Saveable},
@@ -804,6 +1168,28 @@ mod tests {
     })
   }
 
+  #[test]
+  fn interleave_home_posts() {
+    let subscribed = vec!["s1", "s2", "s3", "s4", "s5", "s6"];
+    let discovery = vec!["d1", "d2"];
+    assert_eq!(
+      vec!["s1", "s2", "d1", "s3", "s4", "d2", "s5", "s6"],
+      super::interleave_home_posts(subscribed.clone(), discovery)
+    );
+
+    // The last post must always be a subscribed one
+    let discovery = vec!["d1", "d2", "d3", "d4", "d5", "d6", "d7"];
+    assert_eq!(
+      vec!["s1", "d1", "s2", "d2", "s3", "d3", "s4", "d4", "s5", "d5", "s6"],
+      super::interleave_home_posts(subscribed.clone(), discovery)
+    );
+
+    assert_eq!(
+      subscribed.clone(),
+      super::interleave_home_posts(subscribed, vec![])
+    );
+  }
+
   #[tokio::test]
   #[serial]
   async fn post_listing_with_person() -> LemmyResult<()> {
@@ -864,6 +1250,59 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_downrank_bots() -> LemmyResult<()> {
+    let pool = &build_db_pool()?;
+    let pool = &mut pool.into();
+    let mut data = init_data(pool).await?;
+
+    let local_user_form = LocalUserUpdateForm {
+      show_bot_accounts: Some(true),
+      downrank_bot_accounts: Some(true),
+      ..Default::default()
+    };
+    LocalUser::update(pool, data.local_user_view.local_user.id, &local_user_form).await?;
+    data.local_user_view.local_user.show_bot_accounts = true;
+    data.local_user_view.local_user.downrank_bot_accounts = true;
+
+    // Down-ranked bot posts are still shown, but after the newer post of a human
+    let feed = data.default_post_query().list(&data.site, pool).await?;
+    assert_eq!(vec![POST, POST_BY_BOT], names(&feed));
+    let community_listing = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(vec![POST, POST_BY_BOT], names(&community_listing));
+
+    // Rank bots normally in a community which relies on them
+    let mut form = LocalUserCommunitySettingsForm {
+      show_bot_accounts: Some(true),
+      ..LocalUserCommunitySettingsForm::new(
+        data.local_user_view.local_user.id,
+        data.inserted_community.id,
+      )
+    };
+    LocalUserCommunitySettings::save(pool, &form).await?;
+    let feed = data.default_post_query().list(&data.site, pool).await?;
+    assert_eq!(vec![POST_BY_BOT, POST], names(&feed));
+
+    // Or hide them only in this community
+    form.show_bot_accounts = Some(false);
+    LocalUserCommunitySettings::save(pool, &form).await?;
+    let community_listing = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(vec![POST], names(&community_listing));
+
+    cleanup(data, pool).await
+  }
+
   #[tokio::test]
   #[serial]
   async fn post_listing_no_person() -> LemmyResult<()> {
@@ -961,6 +1400,60 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_image_text() -> LemmyResult<()> {
+    let pool = &build_db_pool()?;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let link = Url::parse("https://my_domain.tld/pictrs/image/meme.png")?;
+    let image_form = LocalImageForm {
+      local_user_id: Some(data.local_user_view.local_user.id),
+      pictrs_alias: "meme.png".to_string(),
+      pictrs_delete_token: "token".to_string(),
+    };
+    let image_details_form = ImageDetailsForm {
+      link: link.clone().into(),
+      width: 100,
+      height: 100,
+      content_type: "image/png".to_string(),
+    };
+    LocalImage::create(pool, &image_form, &image_details_form).await?;
+    let text_form = ImageTextForm {
+      pictrs_alias: "meme.png".to_string(),
+      link: link.clone().into(),
+      text: "one does not simply".to_string(),
+    };
+    ImageText::upsert(pool, &text_form).await?;
+
+    let post_form = PostUpdateForm {
+      url: Some(Some(link.into())),
+      ..Default::default()
+    };
+    Post::update(pool, data.inserted_post.id, &post_form).await?;
+
+    // The post is found by the text contained in its image
+    let search = || PostQuery {
+      search_term: Some("simply".to_string()),
+      ..data.default_post_query()
+    };
+    let read_post_listing = search().list(&data.site, pool).await?;
+    assert_eq!(vec![POST], names(&read_post_listing));
+
+    // But not if the community opted out of image text search
+    let community_form = CommunityUpdateForm {
+      image_text_search: Some(false),
+      ..Default::default()
+    };
+    Community::update(pool, data.inserted_community.id, &community_form).await?;
+    let read_post_listing = search().list(&data.site, pool).await?;
+    assert!(read_post_listing.is_empty());
+
+    LocalImage::delete_by_alias(pool, "meme.png").await?;
+    cleanup(data, pool).await
+  }
+
   #[tokio::test]
   #[serial]
   async fn post_listing_block_community() -> LemmyResult<()> {
@@ -1106,6 +1599,7 @@ mod tests {
     let post_save_form = PostSavedForm {
       post_id: data.inserted_bot_post.id,
       person_id: data.local_user_view.person.id,
+      saved_note: None,
     };
     PostSaved::save(pool, &post_save_form).await?;
 
@@ -1124,6 +1618,247 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_custom_feed() -> LemmyResult<()> {
+    let pool = &build_db_pool()?;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let form = CustomFeedInsertForm::new(data.local_user_view.person.id, "My feed".to_string());
+    let custom_feed = CustomFeed::create(pool, &form).await?;
+
+    // A feed without communities has no posts
+    let empty_feed_listing = PostQuery {
+      custom_feed_id: Some(custom_feed.id),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(empty_feed_listing, vec![]);
+
+    CustomFeed::set_communities(pool, custom_feed.id, &[data.inserted_community.id]).await?;
+    let feed_listing = PostQuery {
+      custom_feed_id: Some(custom_feed.id),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    let community_listing = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(community_listing, feed_listing);
+
+    cleanup(data, pool).await
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_followed_persons_only() -> LemmyResult<()> {
+    let pool = &build_db_pool()?;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let follow_form = PersonFollowerForm {
+      person_id: data.inserted_bot.id,
+      follower_id: data.local_user_view.person.id,
+      pending: false,
+    };
+    PersonFollower::follow(pool, &follow_form).await?;
+
+    let read_followed_post_listing = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      followed_persons_only: Some(true),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+
+    // Only the posts of the followed bot are shown
+    assert_eq!(vec![POST_BY_BOT], names(&read_followed_post_listing));
+
+    PersonFollower::unfollow(pool, &follow_form).await?;
+    cleanup(data, pool).await
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_saved_notes() -> LemmyResult<()> {
+    let pool = &build_db_pool()?;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let post_save_form = PostSavedForm {
+      post_id: data.inserted_bot_post.id,
+      person_id: data.local_user_view.person.id,
+      saved_note: Some("Recipe for Sunday".into()),
+    };
+    PostSaved::save(pool, &post_save_form).await?;
+    let post_save_form = PostSavedForm {
+      post_id: data.inserted_post.id,
+      person_id: data.local_user_view.person.id,
+      saved_note: None,
+    };
+    PostSaved::save(pool, &post_save_form).await?;
+
+    // Only the post with a matching note is returned
+    let saved_notes_listing = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      saved_notes: Some("recipe".into()),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(vec![POST_BY_BOT], names(&saved_notes_listing));
+    assert_eq!(
+      Some("Recipe for Sunday".to_string()),
+      saved_notes_listing
+        .first()
+        .and_then(|p| p.saved_note.clone())
+    );
+
+    // The note isn't shown to others
+    let other_listing = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      local_user: None,
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert!(other_listing.iter().all(|p| p.saved_note.is_none()));
+
+    cleanup(data, pool).await
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_gallery() -> LemmyResult<()> {
+    let pool = &build_db_pool()?;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let gallery = vec![
+      PostMediaForm::new(Url::parse("https://example.com/1.png")?.into()),
+      PostMediaForm {
+        alt_text: Some("second".into()),
+        ..PostMediaForm::new(Url::parse("https://example.com/2.png")?.into())
+      },
+    ];
+    PostMedia::replace_for_post(pool, data.inserted_post.id, gallery).await?;
+
+    let post_view = PostView::read(pool, data.inserted_post.id, None, false).await?;
+    let urls = post_view
+      .gallery
+      .iter()
+      .map(|m| m.url.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      vec!["https://example.com/1.png", "https://example.com/2.png"],
+      urls
+    );
+
+    // Only the post with images has a gallery in the listing
+    let listing = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    for post_view in &listing {
+      let expected = if post_view.post.id == data.inserted_post.id {
+        2
+      } else {
+        0
+      };
+      assert_eq!(expected, post_view.gallery.len());
+    }
+
+    // Replacing with an empty list removes the gallery
+    PostMedia::replace_for_post(pool, data.inserted_post.id, vec![]).await?;
+    let post_view = PostView::read(pool, data.inserted_post.id, None, false).await?;
+    assert!(post_view.gallery.is_empty());
+
+    cleanup(data, pool).await
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_keyword_block() -> LemmyResult<()> {
+    let pool = &build_db_pool()?;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+    let local_user_id = data.local_user_view.local_user.id;
+
+    let form = LocalUserKeywordBlockForm::new(local_user_id, "BY BOT".into(), false);
+    LocalUserKeywordBlock::block(pool, &form).await?;
+    let listing = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(vec![POST], names(&listing));
+
+    // Own posts are never hidden
+    let form = LocalUserKeywordBlockForm::new(local_user_id, "^post$".into(), true);
+    LocalUserKeywordBlock::block(pool, &form).await?;
+    let listing = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(vec![POST], names(&listing));
+
+    // Others still see everything
+    let listing = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      local_user: None,
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert!(names(&listing).contains(&POST_BY_BOT));
+
+    cleanup(data, pool).await
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_tag() -> LemmyResult<()> {
+    let pool = &build_db_pool()?;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    let tag_form =
+      CommunityPostTagInsertForm::new(data.inserted_community.id, "Announcement".into());
+    let tag = CommunityPostTag::create(pool, &tag_form).await?;
+    PostTag::set(pool, data.inserted_bot_post.id, vec![tag.id]).await?;
+
+    let tagged_listing = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      tag_id: Some(tag.id),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(vec![POST_BY_BOT], names(&tagged_listing));
+    assert_eq!(
+      Some(vec![tag.clone()]),
+      tagged_listing.first().map(|p| p.tags.clone())
+    );
+
+    // Deleting the tag removes it from the post
+    CommunityPostTag::delete(pool, tag.id).await?;
+    let post_view = PostView::read(pool, data.inserted_bot_post.id, None, false).await?;
+    assert!(post_view.tags.is_empty());
+
+    cleanup(data, pool).await
+  }
+
   #[tokio::test]
   #[serial]
   async fn creator_info() -> LemmyResult<()> {
@@ -1297,6 +2032,53 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn post_listings_pending() -> LemmyResult<()> {
+    let pool = &build_db_pool()?;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    Post::update(
+      pool,
+      data.inserted_post.id,
+      &PostUpdateForm {
+        pending: Some(true),
+        ..Default::default()
+      },
+    )
+    .await?;
+
+    // Pending post is only shown to creator
+    for (local_user, expect_contains_pending) in [
+      (None, false),
+      (Some(&data.blocked_local_user_view.local_user), false),
+      (Some(&data.local_user_view.local_user), true),
+    ] {
+      let contains_pending = PostQuery {
+        local_user,
+        ..data.default_post_query()
+      }
+      .list(&data.site, pool)
+      .await?
+      .iter()
+      .any(|p| p.post.id == data.inserted_post.id);
+
+      assert_eq!(expect_contains_pending, contains_pending);
+    }
+
+    // The approval queue contains nothing else
+    let pending_posts = PostQuery {
+      pending_only: Some(true),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(vec![POST], names(&pending_posts));
+
+    cleanup(data, pool).await
+  }
+
   #[tokio::test]
   #[serial]
   async fn post_listings_hidden_community() -> LemmyResult<()> {
@@ -1333,6 +2115,38 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn post_listings_soft_launched_community() -> LemmyResult<()> {
+    let pool = &build_db_pool()?;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+
+    Community::update(
+      pool,
+      data.inserted_community.id,
+      &CommunityUpdateForm {
+        soft_launched: Some(true),
+        ..Default::default()
+      },
+    )
+    .await?;
+
+    let posts = PostQuery::default().list(&data.site, pool).await?;
+    assert!(posts.is_empty());
+
+    // Not enough posts yet
+    Community::end_soft_launch_if_enough_posts(pool, data.inserted_community.id, 1000).await?;
+    let posts = PostQuery::default().list(&data.site, pool).await?;
+    assert!(posts.is_empty());
+
+    Community::end_soft_launch_if_enough_posts(pool, data.inserted_community.id, 1).await?;
+    let posts = PostQuery::default().list(&data.site, pool).await?;
+    assert!(!posts.is_empty());
+
+    cleanup(data, pool).await
+  }
+
   #[tokio::test]
   #[serial]
   async fn post_listing_instance_block() -> LemmyResult<()> {
@@ -1552,6 +2366,59 @@ mod tests {
     .list(&data.site, pool)
     .await?;
     assert_eq!(vec![POST], names(&post_listings_show_read_false));
+
+    // The read history only contains the read post
+    let post_listings_read_only = PostQuery {
+      show_read: Some(true),
+      read_only: Some(true),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(vec![POST_BY_BOT], names(&post_listings_read_only));
+
+    // Also when read posts are hidden otherwise
+    let post_listings_read_only_hide_read = PostQuery {
+      show_read: Some(false),
+      read_only: Some(true),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(vec![POST_BY_BOT], names(&post_listings_read_only_hide_read));
+    cleanup(data, pool).await
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listings_read_history() -> LemmyResult<()> {
+    let pool = &build_db_pool()?;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+    let person_id = data.local_user_view.person.id;
+
+    let read_history_query = PostQuery {
+      read_only: Some(true),
+      ..data.default_post_query()
+    };
+
+    // Nothing was read yet
+    let post_listings_empty = read_history_query.clone().list(&data.site, pool).await?;
+    assert!(post_listings_empty.is_empty());
+
+    // Read the newer post first, so the history order differs from the publish order
+    PostRead::mark_as_read(pool, data.inserted_bot_post.id, person_id).await?;
+    PostRead::mark_as_read(pool, data.inserted_post.id, person_id).await?;
+
+    let post_listings_read = read_history_query.clone().list(&data.site, pool).await?;
+    assert_eq!(vec![POST, POST_BY_BOT], names(&post_listings_read));
+    assert!(post_listings_read.iter().all(|p| p.read));
+
+    // Marking a post as unread removes it from the history
+    PostRead::mark_as_unread(pool, data.inserted_post.id, person_id).await?;
+    let post_listings_unread = read_history_query.list(&data.site, pool).await?;
+    assert_eq!(vec![POST_BY_BOT], names(&post_listings_unread));
+
     cleanup(data, pool).await
   }
 
@@ -1591,6 +2458,39 @@ mod tests {
     cleanup(data, pool).await
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn post_listings_hide_many() -> LemmyResult<()> {
+    let pool = &build_db_pool()?;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await?;
+    let person_id = data.local_user_view.person.id;
+
+    // Hide both posts at once
+    let post_ids = [data.inserted_post.id, data.inserted_bot_post.id];
+    PostHide::hide_many(pool, &post_ids, person_id).await?;
+
+    let post_listings_hide_hidden = data.default_post_query().list(&data.site, pool).await?;
+    assert!(post_listings_hide_hidden.is_empty());
+
+    // Both come back with the show_hidden option
+    let post_listings_show_hidden = PostQuery {
+      show_hidden: Some(true),
+      ..data.default_post_query()
+    }
+    .list(&data.site, pool)
+    .await?;
+    assert_eq!(vec![POST_BY_BOT, POST], names(&post_listings_show_hidden));
+    assert!(post_listings_show_hidden.iter().all(|p| p.hidden));
+
+    // Unhiding only affects the given posts
+    PostHide::unhide_many(pool, &[data.inserted_post.id], person_id).await?;
+    let post_listings_unhide = data.default_post_query().list(&data.site, pool).await?;
+    assert_eq!(vec![POST], names(&post_listings_unhide));
+
+    cleanup(data, pool).await
+  }
+
   #[tokio::test]
   #[serial]
   async fn post_listings_hide_nsfw() -> LemmyResult<()> {
@@ -1673,9 +2573,15 @@ mod tests {
         featured_local: false,
         url_content_type: None,
         scheduled_publish_time: None,
+        archived: false,
+        pending: false,
+        lock_expires: None,
       },
       my_vote: None,
       unread_comments: 0,
+      saved_note: None,
+      gallery: vec![],
+      tags: vec![],
       creator: Person {
         id: inserted_person.id,
         name: inserted_person.name.clone(),
@@ -1693,6 +2599,7 @@ mod tests {
         inbox_url: inserted_person.inbox_url.clone(),
         matrix_user_id: None,
         ban_expires: None,
+        shadow_banned: false,
         instance_id: data.inserted_instance.id,
         private_key: inserted_person.private_key.clone(),
         public_key: inserted_person.public_key.clone(),
@@ -1719,6 +2626,18 @@ mod tests {
         banner: None,
         hidden: false,
         posting_restricted_to_mods: false,
+        comment_export_public: false,
+        soft_launched: false,
+        image_text_search: true,
+        posting_min_reputation: None,
+        welcome_message: None,
+        posts_require_approval: false,
+        warning_ban_strikes: None,
+        warning_ban_days: 7,
+        new_account_min_age_days: None,
+        new_account_min_comments: None,
+        new_account_require_verified_email: false,
+        new_account_restrictions: vec![],
         published: inserted_community.published,
         instance_id: data.inserted_instance.id,
         private_key: inserted_community.private_key.clone(),
@@ -1830,6 +2749,8 @@ mod tests {
         community_id: data.inserted_community.id,
         person_id: inserted_banned_from_comm_person.id,
         expires: None,
+        reason: None,
+        appeal: None,
       },
     )
     .await?;
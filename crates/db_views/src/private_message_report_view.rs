@@ -1,7 +1,8 @@
-use crate::structs::PrivateMessageReportView;
+use crate::structs::{PaginationCursor, PrivateMessageReportView};
 use diesel::{
   pg::Pg,
   result::Error,
+  BoolExpressionMethods,
   ExpressionMethods,
   JoinOnDsl,
   NullableExpressionMethods,
@@ -10,9 +11,10 @@ use diesel::{
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   aliases,
-  newtypes::PrivateMessageReportId,
+  newtypes::{PersonId, PrivateMessageReportId},
   schema::{person, private_message, private_message_report},
-  utils::{get_conn, limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
+  source::private_message_report::PrivateMessageReport,
+  utils::{get_conn, limit_fetch, DbConn, DbPool, ListFn, Queries, ReadFn},
 };
 
 fn queries<'a>() -> Queries<
@@ -49,21 +51,51 @@ fn queries<'a>() -> Queries<
   let list = move |mut conn: DbConn<'a>, options: PrivateMessageReportQuery| async move {
     let mut query = all_joins(private_message_report::table.into_boxed());
 
+    if let Some(ids) = options.ids {
+      query = query.filter(private_message_report::id.eq_any(ids));
+    }
+
+    if let Some(creator_id) = options.creator_id {
+      query = query.filter(private_message_report::creator_id.eq(creator_id));
+    }
+
+    if let Some(private_message_creator_id) = options.private_message_creator_id {
+      query = query.filter(private_message::creator_id.eq(private_message_creator_id));
+    }
+
     // If viewing all reports, order by newest, but if viewing unresolved only, show the oldest
-    // first (FIFO)
+    // first (FIFO). Reports published at the same time are ordered by id.
     if options.unresolved_only {
       query = query
         .filter(private_message_report::resolved.eq(false))
-        .order_by(private_message_report::published.asc());
+        .order_by(private_message_report::published.asc())
+        .then_order_by(private_message_report::id.asc());
     } else {
-      query = query.order_by(private_message_report::published.desc());
+      query = query
+        .order_by(private_message_report::published.desc())
+        .then_order_by(private_message_report::id.desc());
     }
 
-    let (limit, offset) = limit_and_offset(options.page, options.limit)?;
+    // Only reports which come after the cursor in the order above
+    if let Some(PrivateMessageReportPaginationCursorData(after)) = options.page_after {
+      let same_published = private_message_report::published.eq(after.published);
+      if options.unresolved_only {
+        query = query.filter(
+          private_message_report::published
+            .gt(after.published)
+            .or(same_published.and(private_message_report::id.gt(after.id))),
+        );
+      } else {
+        query = query.filter(
+          private_message_report::published
+            .lt(after.published)
+            .or(same_published.and(private_message_report::id.lt(after.id))),
+        );
+      }
+    }
 
     query
-      .limit(limit)
-      .offset(offset)
+      .limit(limit_fetch(options.limit)?)
       .load::<PrivateMessageReportView>(&mut conn)
       .await
   };
@@ -82,6 +114,12 @@ impl PrivateMessageReportView {
     queries().read(pool, report_id).await
   }
 
+  /// Get cursor for the page that starts immediately after this report.
+  pub fn to_cursor(&self) -> PaginationCursor {
+    // hex encoding to prevent ossification
+    PaginationCursor(format!("M{:x}", self.private_message_report.id.0))
+  }
+
   /// Returns the current unresolved post report count for the communities you mod
   pub async fn get_report_count(pool: &mut DbPool<'_>) -> Result<i64, Error> {
     use diesel::dsl::count;
@@ -97,9 +135,37 @@ impl PrivateMessageReportView {
   }
 }
 
+/// The position of a private message report in a report list, used for keyset pagination.
+#[derive(Debug, Clone)]
+pub struct PrivateMessageReportPaginationCursorData(PrivateMessageReport);
+
+impl PrivateMessageReportPaginationCursorData {
+  /// Reads the report referenced by the given cursor.
+  pub async fn read(cursor: &PaginationCursor, pool: &mut DbPool<'_>) -> Result<Self, Error> {
+    let err_msg = || Error::QueryBuilderError("Could not parse pagination token".into());
+    let id = cursor
+      .0
+      .strip_prefix('M')
+      .and_then(|e| i32::from_str_radix(e, 16).ok())
+      .ok_or_else(err_msg)?;
+    let conn = &mut get_conn(pool).await?;
+    let report = private_message_report::table
+      .find(PrivateMessageReportId(id))
+      .first::<PrivateMessageReport>(conn)
+      .await?;
+    Ok(PrivateMessageReportPaginationCursorData(report))
+  }
+}
+
 #[derive(Default)]
 pub struct PrivateMessageReportQuery {
-  pub page: Option<i64>,
+  /// Only the reports with these ids
+  pub ids: Option<Vec<PrivateMessageReportId>>,
+  /// Only reports filed by this person
+  pub creator_id: Option<PersonId>,
+  /// Only reports against messages sent by this person
+  pub private_message_creator_id: Option<PersonId>,
+  pub page_after: Option<PrivateMessageReportPaginationCursorData>,
   pub limit: Option<i64>,
   pub unresolved_only: bool,
 }
@@ -114,7 +180,10 @@ impl PrivateMessageReportQuery {
 #[expect(clippy::indexing_slicing)]
 mod tests {
 
-  use crate::private_message_report_view::PrivateMessageReportQuery;
+  use crate::private_message_report_view::{
+    PrivateMessageReportPaginationCursorData,
+    PrivateMessageReportQuery,
+  };
   use lemmy_db_schema::{
     assert_length,
     source::{
@@ -125,6 +194,7 @@ mod tests {
     },
     traits::{Crud, Reportable},
     utils::build_db_pool_for_tests,
+    ReportOutcome,
   };
   use lemmy_utils::error::LemmyResult;
   use pretty_assertions::assert_eq;
@@ -147,7 +217,7 @@ mod tests {
     // timmy sends private message to jessica
     let pm_form = PrivateMessageInsertForm::new(
       inserted_timmy.id,
-      inserted_jessica.id,
+      Some(inserted_jessica.id),
       "something offensive".to_string(),
     );
     let pm = PrivateMessage::create(pool, &pm_form).await?;
@@ -169,11 +239,91 @@ mod tests {
     assert_eq!(pm_report.reason, reports[0].private_message_report.reason);
     assert_eq!(pm.content, reports[0].private_message.content);
 
+    // Filter by the person who filed the report, and by the sender of the message
+    let jessica_filed = PrivateMessageReportQuery {
+      creator_id: Some(inserted_jessica.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await?;
+    assert_length!(1, jessica_filed);
+    let timmy_filed = PrivateMessageReportQuery {
+      creator_id: Some(inserted_timmy.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await?;
+    assert_length!(0, timmy_filed);
+    let timmy_received = PrivateMessageReportQuery {
+      private_message_creator_id: Some(inserted_timmy.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await?;
+    assert_length!(1, timmy_received);
+    let jessica_received = PrivateMessageReportQuery {
+      private_message_creator_id: Some(inserted_jessica.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await?;
+    assert_length!(0, jessica_received);
+
+    // jessica reports a second message, which comes first as it is newer
+    let pm_form_2 = PrivateMessageInsertForm::new(
+      inserted_timmy.id,
+      Some(inserted_jessica.id),
+      "something else offensive".to_string(),
+    );
+    let pm_2 = PrivateMessage::create(pool, &pm_form_2).await?;
+    let pm_report_form_2 = PrivateMessageReportForm {
+      private_message_id: pm_2.id,
+      ..pm_report_form
+    };
+    let pm_report_2 = PrivateMessageReport::report(pool, &pm_report_form_2).await?;
+
+    let first_page = PrivateMessageReportQuery {
+      limit: Some(1),
+      ..Default::default()
+    }
+    .list(pool)
+    .await?;
+    assert_length!(1, first_page);
+    assert_eq!(pm_report_2.id, first_page[0].private_message_report.id);
+    let cursor =
+      PrivateMessageReportPaginationCursorData::read(&first_page[0].to_cursor(), pool).await?;
+    let second_page = PrivateMessageReportQuery {
+      limit: Some(1),
+      page_after: Some(cursor),
+      ..Default::default()
+    }
+    .list(pool)
+    .await?;
+    assert_length!(1, second_page);
+    assert_eq!(pm_report.id, second_page[0].private_message_report.id);
+    let cursor =
+      PrivateMessageReportPaginationCursorData::read(&second_page[0].to_cursor(), pool).await?;
+    let last_page = PrivateMessageReportQuery {
+      limit: Some(1),
+      page_after: Some(cursor),
+      ..Default::default()
+    }
+    .list(pool)
+    .await?;
+    assert_length!(0, last_page);
+    PrivateMessage::delete(pool, pm_2.id).await?;
+
     let new_person_3 = PersonInsertForm::test_form(inserted_instance.id, "admin_mrv");
     let inserted_admin = Person::create(pool, &new_person_3).await?;
 
     // admin resolves the report (after taking appropriate action)
-    PrivateMessageReport::resolve(pool, pm_report.id, inserted_admin.id).await?;
+    PrivateMessageReport::resolve(
+      pool,
+      pm_report.id,
+      inserted_admin.id,
+      ReportOutcome::ResolvedNoAction,
+    )
+    .await?;
 
     let reports = PrivateMessageReportQuery {
       unresolved_only: (false),
@@ -1,18 +1,29 @@
 use crate::structs::PrivateMessageView;
+use chrono::{DateTime, Utc};
 use diesel::{
   debug_query,
+  dsl::{exists, not},
   pg::Pg,
   result::Error,
   BoolExpressionMethods,
   ExpressionMethods,
   JoinOnDsl,
+  NullableExpressionMethods,
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   aliases,
-  newtypes::{PersonId, PrivateMessageId},
-  schema::{instance_actions, person, person_actions, private_message},
+  newtypes::{PersonId, PmConversationId, PrivateMessageId},
+  schema::{
+    instance_actions,
+    local_user,
+    person,
+    person_actions,
+    pm_conversation,
+    pm_conversation_participant,
+    private_message,
+  },
   utils::{actions, get_conn, limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
 };
 use tracing::debug;
@@ -21,20 +32,21 @@ fn queries<'a>() -> Queries<
   impl ReadFn<'a, PrivateMessageView, PrivateMessageId>,
   impl ListFn<'a, PrivateMessageView, (PrivateMessageQuery, PersonId)>,
 > {
-  let all_joins = |query: private_message::BoxedQuery<'a, Pg>| {
+  let all_joins = |query: private_message::BoxedQuery<'a, Pg>, my_person_id: Option<PersonId>| {
     query
       .inner_join(person::table.on(private_message::creator_id.eq(person::id)))
-      .inner_join(
-        aliases::person1.on(private_message::recipient_id.eq(aliases::person1.field(person::id))),
+      .left_join(
+        aliases::person1
+          .on(private_message::recipient_id.eq(aliases::person1.field(person::id).nullable())),
       )
       .left_join(actions(
         person_actions::table,
-        Some(aliases::person1.field(person::id)),
+        my_person_id,
         private_message::creator_id,
       ))
       .left_join(actions(
         instance_actions::table,
-        Some(aliases::person1.field(person::id)),
+        my_person_id,
         person::instance_id,
       ))
   };
@@ -42,25 +54,41 @@ fn queries<'a>() -> Queries<
   let selection = (
     private_message::all_columns,
     person::all_columns,
-    aliases::person1.fields(person::all_columns),
+    aliases::person1.fields(person::all_columns).nullable(),
   );
 
   let read = move |mut conn: DbConn<'a>, private_message_id: PrivateMessageId| async move {
-    all_joins(private_message::table.find(private_message_id).into_boxed())
-      .order_by(private_message::published.desc())
-      .select(selection)
-      .first(&mut conn)
-      .await
+    all_joins(
+      private_message::table.find(private_message_id).into_boxed(),
+      None,
+    )
+    .order_by(private_message::published.desc())
+    .select(selection)
+    .first(&mut conn)
+    .await
   };
 
   let list = move |mut conn: DbConn<'a>,
                    (options, recipient_id): (PrivateMessageQuery, PersonId)| async move {
-    let mut query = all_joins(private_message::table.into_boxed())
+    let mut query = all_joins(private_message::table.into_boxed(), Some(recipient_id))
       .select(selection)
       // Dont show replies from blocked users
       .filter(person_actions::blocked.is_null())
       // Dont show replies from blocked instances
-      .filter(instance_actions::blocked.is_null());
+      .filter(instance_actions::blocked.is_null())
+      // Messages of shadow banned persons are only shown to themselves and admins
+      .filter(
+        person::shadow_banned
+          .eq(false)
+          .or(private_message::creator_id.eq(recipient_id))
+          .or(exists(
+            local_user::table.filter(
+              local_user::person_id
+                .eq(recipient_id)
+                .and(local_user::admin.eq(true)),
+            ),
+          )),
+      );
 
     // If its unread, I only want the ones to me
     if options.unread_only {
@@ -70,12 +98,17 @@ fn queries<'a>() -> Queries<
       }
       query = query.filter(private_message::recipient_id.eq(recipient_id));
     }
-    // Otherwise, I want the ALL view to show both sent and received
+    // Otherwise, I want the ALL view to show both sent and received, including the messages of
+    // group conversations which I take part in
     else {
+      let group_participant = pm_conversation_participant::table
+        .filter(pm_conversation_participant::conversation_id.eq(private_message::conversation_id))
+        .filter(pm_conversation_participant::person_id.eq(recipient_id));
       query = query.filter(
         private_message::recipient_id
           .eq(recipient_id)
-          .or(private_message::creator_id.eq(recipient_id)),
+          .or(private_message::creator_id.eq(recipient_id))
+          .or(exists(group_participant)),
       );
       if let Some(i) = options.creator_id {
         query = query.filter(
@@ -86,13 +119,34 @@ fn queries<'a>() -> Queries<
       }
     }
 
+    // Message requests are only shown when their conversation is opened
+    if let Some(conversation_id) = options.conversation_id {
+      query = query.filter(private_message::conversation_id.eq(conversation_id));
+    } else {
+      let request = pm_conversation::table
+        .filter(pm_conversation::id.eq(private_message::conversation_id))
+        .filter(pm_conversation::request_recipient_id.eq(recipient_id));
+      query = query.filter(not(exists(request)));
+    }
+
+    if let Some((published, id)) = options.before {
+      query = query.filter(
+        private_message::published.lt(published).or(
+          private_message::published
+            .eq(published)
+            .and(private_message::id.lt(id)),
+        ),
+      );
+    }
+
     let (limit, offset) = limit_and_offset(options.page, options.limit)?;
 
     query = query
       .filter(private_message::deleted.eq(false))
       .limit(limit)
       .offset(offset)
-      .order_by(private_message::published.desc());
+      .order_by(private_message::published.desc())
+      .then_order_by(private_message::id.desc());
 
     debug!(
       "Private Message View Query: {:?}",
@@ -137,9 +191,25 @@ impl PrivateMessageView {
       .filter(person_actions::blocked.is_null())
       // Dont count replies from blocked instances
       .filter(instance_actions::blocked.is_null())
+      // Dont count messages of shadow banned persons, unless an admin receives them
+      .filter(
+        person::shadow_banned.eq(false).or(exists(
+          local_user::table.filter(
+            local_user::person_id
+              .eq(my_person_id)
+              .and(local_user::admin.eq(true)),
+          ),
+        )),
+      )
       .filter(private_message::read.eq(false))
       .filter(private_message::recipient_id.eq(my_person_id))
       .filter(private_message::deleted.eq(false))
+      // Dont count message requests
+      .filter(not(exists(
+        pm_conversation::table
+          .filter(pm_conversation::id.eq(private_message::conversation_id))
+          .filter(pm_conversation::request_recipient_id.eq(my_person_id)),
+      )))
       .select(count(private_message::id))
       .first::<i64>(conn)
       .await
@@ -152,6 +222,11 @@ pub struct PrivateMessageQuery {
   pub page: Option<i64>,
   pub limit: Option<i64>,
   pub creator_id: Option<PersonId>,
+  /// Only messages of this conversation.
+  pub conversation_id: Option<PmConversationId>,
+  /// Only messages which were published before this time, or at the same time with a lower id.
+  /// Used for cursor pagination.
+  pub before: Option<(DateTime<Utc>, PrivateMessageId)>,
 }
 
 impl PrivateMessageQuery {
@@ -175,7 +250,8 @@ mod tests {
     source::{
       instance::Instance,
       instance_block::{InstanceBlock, InstanceBlockForm},
-      person::{Person, PersonInsertForm},
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm, PersonUpdateForm},
       person_block::{PersonBlock, PersonBlockForm},
       private_message::{PrivateMessage, PrivateMessageInsertForm},
     },
@@ -211,19 +287,19 @@ mod tests {
     let jess = Person::create(pool, &jess_form).await?;
 
     let sara_timmy_message_form =
-      PrivateMessageInsertForm::new(sara.id, timmy.id, message_content.clone());
+      PrivateMessageInsertForm::new(sara.id, Some(timmy.id), message_content.clone());
     PrivateMessage::create(pool, &sara_timmy_message_form).await?;
 
     let sara_jess_message_form =
-      PrivateMessageInsertForm::new(sara.id, jess.id, message_content.clone());
+      PrivateMessageInsertForm::new(sara.id, Some(jess.id), message_content.clone());
     PrivateMessage::create(pool, &sara_jess_message_form).await?;
 
     let timmy_sara_message_form =
-      PrivateMessageInsertForm::new(timmy.id, sara.id, message_content.clone());
+      PrivateMessageInsertForm::new(timmy.id, Some(sara.id), message_content.clone());
     PrivateMessage::create(pool, &timmy_sara_message_form).await?;
 
     let jess_timmy_message_form =
-      PrivateMessageInsertForm::new(jess.id, timmy.id, message_content.clone());
+      PrivateMessageInsertForm::new(jess.id, Some(timmy.id), message_content.clone());
     PrivateMessage::create(pool, &jess_timmy_message_form).await?;
 
     Ok(Data {
@@ -262,11 +338,20 @@ mod tests {
 
     assert_length!(3, &timmy_messages);
     assert_eq!(timmy_messages[0].creator.id, jess.id);
-    assert_eq!(timmy_messages[0].recipient.id, timmy.id);
+    assert_eq!(
+      timmy_messages[0].recipient.as_ref().map(|r| r.id),
+      Some(timmy.id)
+    );
     assert_eq!(timmy_messages[1].creator.id, timmy.id);
-    assert_eq!(timmy_messages[1].recipient.id, sara.id);
+    assert_eq!(
+      timmy_messages[1].recipient.as_ref().map(|r| r.id),
+      Some(sara.id)
+    );
     assert_eq!(timmy_messages[2].creator.id, sara.id);
-    assert_eq!(timmy_messages[2].recipient.id, timmy.id);
+    assert_eq!(
+      timmy_messages[2].recipient.as_ref().map(|r| r.id),
+      Some(timmy.id)
+    );
 
     let timmy_unread_messages = PrivateMessageQuery {
       unread_only: true,
@@ -278,9 +363,15 @@ mod tests {
 
     assert_length!(2, &timmy_unread_messages);
     assert_eq!(timmy_unread_messages[0].creator.id, jess.id);
-    assert_eq!(timmy_unread_messages[0].recipient.id, timmy.id);
+    assert_eq!(
+      timmy_unread_messages[0].recipient.as_ref().map(|r| r.id),
+      Some(timmy.id)
+    );
     assert_eq!(timmy_unread_messages[1].creator.id, sara.id);
-    assert_eq!(timmy_unread_messages[1].recipient.id, timmy.id);
+    assert_eq!(
+      timmy_unread_messages[1].recipient.as_ref().map(|r| r.id),
+      Some(timmy.id)
+    );
 
     let timmy_sara_messages = PrivateMessageQuery {
       unread_only: false,
@@ -292,9 +383,15 @@ mod tests {
 
     assert_length!(2, &timmy_sara_messages);
     assert_eq!(timmy_sara_messages[0].creator.id, timmy.id);
-    assert_eq!(timmy_sara_messages[0].recipient.id, sara.id);
+    assert_eq!(
+      timmy_sara_messages[0].recipient.as_ref().map(|r| r.id),
+      Some(sara.id)
+    );
     assert_eq!(timmy_sara_messages[1].creator.id, sara.id);
-    assert_eq!(timmy_sara_messages[1].recipient.id, timmy.id);
+    assert_eq!(
+      timmy_sara_messages[1].recipient.as_ref().map(|r| r.id),
+      Some(timmy.id)
+    );
 
     let timmy_sara_unread_messages = PrivateMessageQuery {
       unread_only: true,
@@ -306,7 +403,13 @@ mod tests {
 
     assert_length!(1, &timmy_sara_unread_messages);
     assert_eq!(timmy_sara_unread_messages[0].creator.id, sara.id);
-    assert_eq!(timmy_sara_unread_messages[0].recipient.id, timmy.id);
+    assert_eq!(
+      timmy_sara_unread_messages[0]
+        .recipient
+        .as_ref()
+        .map(|r| r.id),
+      Some(timmy.id)
+    );
 
     cleanup(instance.id, pool).await
   }
@@ -394,4 +497,48 @@ mod tests {
     assert_eq!(timmy_unread_messages, 0);
     cleanup(instance.id, pool).await
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn shadow_banned_messages() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+    let Data {
+      timmy,
+      jess,
+      sara,
+      instance,
+    } = init_data(pool).await?;
+    LocalUser::create(pool, &LocalUserInsertForm::test_form_admin(jess.id), vec![]).await?;
+
+    let form = PersonUpdateForm {
+      shadow_banned: Some(true),
+      ..Default::default()
+    };
+    Person::update(pool, sara.id, &form).await?;
+
+    // Others don't get the messages of sara
+    let timmy_messages = PrivateMessageQuery::default().list(pool, timmy.id).await?;
+    assert_length!(2, &timmy_messages);
+    assert!(timmy_messages.iter().all(|m| m.creator.id != sara.id));
+    assert_eq!(
+      1,
+      PrivateMessageView::get_unread_messages(pool, timmy.id).await?
+    );
+
+    // Admins do
+    let jess_messages = PrivateMessageQuery::default().list(pool, jess.id).await?;
+    assert_length!(2, &jess_messages);
+    assert_eq!(jess_messages[1].creator.id, sara.id);
+    assert_eq!(
+      1,
+      PrivateMessageView::get_unread_messages(pool, jess.id).await?
+    );
+
+    // Sara still sees her sent messages
+    let sara_messages = PrivateMessageQuery::default().list(pool, sara.id).await?;
+    assert_length!(3, &sara_messages);
+
+    cleanup(instance.id, pool).await
+  }
 }
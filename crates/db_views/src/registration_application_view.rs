@@ -1,5 +1,6 @@
 use crate::structs::RegistrationApplicationView;
 use diesel::{
+  deserialize::{self, FromStaticSqlRow, Queryable},
   dsl::count,
   pg::Pg,
   result::Error,
@@ -13,9 +14,38 @@ use lemmy_db_schema::{
   aliases,
   newtypes::{PersonId, RegistrationApplicationId},
   schema::{local_user, person, registration_application},
+  source::{
+    local_user::LocalUser,
+    person::Person,
+    registration_application::RegistrationApplication,
+    registration_question::RegistrationAnswer,
+  },
   utils::{get_conn, limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
 };
 
+type RegistrationApplicationViewTuple =
+  (RegistrationApplication, LocalUser, Person, Option<Person>);
+
+/// The answers aren't part of the selected row, they are loaded afterwards with
+/// [`RegistrationApplicationView::fill_answers`].
+impl<ST> Queryable<ST, Pg> for RegistrationApplicationView
+where
+  RegistrationApplicationViewTuple: FromStaticSqlRow<ST, Pg>,
+{
+  type Row = RegistrationApplicationViewTuple;
+
+  fn build(row: Self::Row) -> deserialize::Result<Self> {
+    let (registration_application, creator_local_user, creator, admin) = row;
+    Ok(Self {
+      registration_application,
+      creator_local_user,
+      creator,
+      admin,
+      answers: vec![],
+    })
+  }
+}
+
 enum ReadBy {
   Id(RegistrationApplicationId),
   Person(PersonId),
@@ -82,11 +112,32 @@ fn queries<'a>() -> Queries<
 
 impl RegistrationApplicationView {
   pub async fn read(pool: &mut DbPool<'_>, id: RegistrationApplicationId) -> Result<Self, Error> {
-    queries().read(pool, ReadBy::Id(id)).await
+    let mut view = queries().read(pool, ReadBy::Id(id)).await?;
+    Self::fill_answers(pool, std::slice::from_mut(&mut view)).await?;
+    Ok(view)
   }
 
   pub async fn read_by_person(pool: &mut DbPool<'_>, person_id: PersonId) -> Result<Self, Error> {
-    queries().read(pool, ReadBy::Person(person_id)).await
+    let mut view = queries().read(pool, ReadBy::Person(person_id)).await?;
+    Self::fill_answers(pool, std::slice::from_mut(&mut view)).await?;
+    Ok(view)
+  }
+
+  /// Loads the answers of the given applications.
+  pub async fn fill_answers(pool: &mut DbPool<'_>, applications: &mut [Self]) -> Result<(), Error> {
+    let application_ids = applications
+      .iter()
+      .map(|a| a.registration_application.id)
+      .collect::<Vec<_>>();
+    let mut answers = RegistrationAnswer::list_for_applications(pool, &application_ids).await?;
+    for view in applications.iter_mut() {
+      let (own, rest): (Vec<_>, Vec<_>) = answers
+        .into_iter()
+        .partition(|a| a.registration_application_id == view.registration_application.id);
+      view.answers = own;
+      answers = rest;
+    }
+    Ok(())
   }
   /// Returns the current unread registration_application count
   pub async fn get_unread_count(
@@ -130,7 +181,9 @@ impl RegistrationApplicationQuery {
     self,
     pool: &mut DbPool<'_>,
   ) -> Result<Vec<RegistrationApplicationView>, Error> {
-    queries().list(pool, self).await
+    let mut applications = queries().list(pool, self).await?;
+    RegistrationApplicationView::fill_answers(pool, &mut applications).await?;
+    Ok(applications)
   }
 }
 
@@ -151,6 +204,7 @@ mod tests {
         RegistrationApplicationInsertForm,
         RegistrationApplicationUpdateForm,
       },
+      registration_question::{RegistrationAnswer, RegistrationAnswerInsertForm},
     },
     traits::Crud,
     utils::build_db_pool_for_tests,
@@ -187,10 +241,19 @@ mod tests {
     // Sara creates an application
     let sara_app_form = RegistrationApplicationInsertForm {
       local_user_id: inserted_sara_local_user.id,
-      answer: "LET ME IIIIINN".to_string(),
     };
 
     let sara_app = RegistrationApplication::create(pool, &sara_app_form).await?;
+    let sara_answers = RegistrationAnswer::create_many(
+      pool,
+      &[RegistrationAnswerInsertForm::new(
+        sara_app.id,
+        None,
+        "Why do you want to join?".to_string(),
+        "LET ME IIIIINN".to_string(),
+      )],
+    )
+    .await?;
 
     let read_sara_app_view = RegistrationApplicationView::read(pool, sara_app.id).await?;
 
@@ -205,7 +268,6 @@ mod tests {
     // Sara creates an application
     let jess_app_form = RegistrationApplicationInsertForm {
       local_user_id: inserted_jess_local_user.id,
-      answer: "LET ME IIIIINN".to_string(),
     };
 
     let jess_app = RegistrationApplication::create(pool, &jess_app_form).await?;
@@ -243,6 +305,20 @@ mod tests {
         enable_private_messages: inserted_sara_local_user.enable_private_messages,
         collapse_bot_comments: inserted_sara_local_user.collapse_bot_comments,
         auto_mark_fetched_posts_as_read: false,
+        home_discovery_percent: inserted_sara_local_user.home_discovery_percent,
+        frozen: false,
+        image_text_recognition: true,
+        customized_settings: vec![],
+        quiet_hours_enabled: false,
+        quiet_hours_start: inserted_sara_local_user.quiet_hours_start,
+        quiet_hours_end: inserted_sara_local_user.quiet_hours_end,
+        quiet_hours_timezone: inserted_sara_local_user.quiet_hours_timezone,
+        mark_read_on_save: true,
+        private_message_requests: false,
+        allow_private_messages: inserted_sara_local_user.allow_private_messages,
+        downrank_bot_accounts: false,
+        ldap_login: false,
+        ldap_admin_login: false,
       },
       creator: Person {
         id: inserted_sara_person.id,
@@ -254,6 +330,7 @@ mod tests {
         local: true,
         banned: false,
         ban_expires: None,
+        shadow_banned: false,
         deleted: false,
         bot_account: false,
         bio: None,
@@ -267,9 +344,11 @@ mod tests {
         last_refreshed_at: inserted_sara_person.last_refreshed_at,
       },
       admin: None,
+      answers: sara_answers,
     };
 
     assert_eq!(read_sara_app_view, expected_sara_app_view);
+    assert!(read_jess_app_view.answers.is_empty());
 
     // Do a batch read of the applications
     let apps = RegistrationApplicationQuery {
@@ -323,6 +402,7 @@ mod tests {
       local: true,
       banned: false,
       ban_expires: None,
+      shadow_banned: false,
       deleted: false,
       bot_account: false,
       bio: None,
@@ -0,0 +1,474 @@
+use crate::{
+  comment_report_view::CommentReportQuery,
+  post_report_view::PostReportQuery,
+  private_message_report_view::PrivateMessageReportQuery,
+  structs::{
+    CommentReportView,
+    LocalUserView,
+    PaginationCursor,
+    PostReportView,
+    PrivateMessageReportView,
+    ReportCombinedView,
+  },
+};
+use chrono::{DateTime, Utc};
+use diesel::{
+  result::Error,
+  sql_query,
+  sql_types::{BigInt, Bool, Integer, Nullable, Text, Timestamptz},
+  QueryDsl,
+  QueryableByName,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{CommentReportId, CommunityId, PersonId, PostReportId, PrivateMessageReportId},
+  schema::{comment_report, post_report, private_message_report},
+  utils::{get_conn, limit_fetch, DbPool},
+};
+
+/// Discriminants of the report types in the combined list. Reports published at the same time
+/// are ordered by these, then by id.
+const POST: &str = "post";
+const COMMENT: &str = "comment";
+const PRIVATE_MESSAGE: &str = "private_message";
+
+#[derive(QueryableByName)]
+struct ReportId {
+  #[diesel(sql_type = Text)]
+  kind: String,
+  #[diesel(sql_type = Integer)]
+  id: i32,
+}
+
+impl ReportCombinedView {
+  /// Returns the number of unresolved reports of all types. Private message reports are only
+  /// counted for admins.
+  pub async fn get_report_count(
+    pool: &mut DbPool<'_>,
+    my_person_id: PersonId,
+    admin: bool,
+    community_id: Option<CommunityId>,
+  ) -> Result<i64, Error> {
+    let comment_reports =
+      CommentReportView::get_report_count(pool, my_person_id, admin, community_id).await?;
+    let post_reports =
+      PostReportView::get_report_count(pool, my_person_id, admin, community_id).await?;
+    let private_message_reports = if admin && community_id.is_none() {
+      PrivateMessageReportView::get_report_count(pool).await?
+    } else {
+      0
+    };
+    Ok(comment_reports + post_reports + private_message_reports)
+  }
+
+  /// Get cursor for the page that starts immediately after this report.
+  pub fn to_cursor(&self) -> PaginationCursor {
+    match self {
+      ReportCombinedView::Post(v) => v.to_cursor(),
+      ReportCombinedView::Comment(v) => v.to_cursor(),
+      ReportCombinedView::PrivateMessage(v) => v.to_cursor(),
+    }
+  }
+}
+
+/// The position of a report in the combined report list, used for keyset pagination.
+#[derive(Debug, Clone)]
+pub struct ReportCombinedPaginationCursorData {
+  published: DateTime<Utc>,
+  kind: &'static str,
+  id: i32,
+}
+
+impl ReportCombinedPaginationCursorData {
+  /// Reads the report referenced by the given cursor, which may be of any report type.
+  pub async fn read(cursor: &PaginationCursor, pool: &mut DbPool<'_>) -> Result<Self, Error> {
+    let err_msg = || Error::QueryBuilderError("Could not parse pagination token".into());
+    let mut chars = cursor.0.chars();
+    let prefix = chars.next();
+    let id = i32::from_str_radix(chars.as_str(), 16).map_err(|_| err_msg())?;
+    let conn = &mut get_conn(pool).await?;
+    let (kind, published) = match prefix {
+      Some('P') => (
+        POST,
+        post_report::table
+          .find(PostReportId(id))
+          .select(post_report::published)
+          .first(conn)
+          .await?,
+      ),
+      Some('C') => (
+        COMMENT,
+        comment_report::table
+          .find(CommentReportId(id))
+          .select(comment_report::published)
+          .first(conn)
+          .await?,
+      ),
+      Some('M') => (
+        PRIVATE_MESSAGE,
+        private_message_report::table
+          .find(PrivateMessageReportId(id))
+          .select(private_message_report::published)
+          .first(conn)
+          .await?,
+      ),
+      _ => return Err(err_msg()),
+    };
+    Ok(ReportCombinedPaginationCursorData {
+      published,
+      kind,
+      id,
+    })
+  }
+}
+
+#[derive(Default)]
+pub struct ReportCombinedQuery {
+  pub community_id: Option<CommunityId>,
+  pub page_after: Option<ReportCombinedPaginationCursorData>,
+  pub limit: Option<i64>,
+  pub unresolved_only: bool,
+}
+
+impl ReportCombinedQuery {
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+    user: &LocalUserView,
+  ) -> Result<Vec<ReportCombinedView>, Error> {
+    let limit = limit_fetch(self.limit)?;
+    let ids = self.list_ids(pool, user, limit).await?;
+    let ids_of = |kind: &str| {
+      ids
+        .iter()
+        .filter(|r| r.kind == kind)
+        .map(|r| r.id)
+        .collect::<Vec<_>>()
+    };
+
+    // The full views are read with one query per report type
+    let post_ids = ids_of(POST);
+    let mut post_reports = if post_ids.is_empty() {
+      vec![]
+    } else {
+      PostReportQuery {
+        ids: Some(post_ids.into_iter().map(PostReportId).collect()),
+        limit: Some(limit),
+        ..Default::default()
+      }
+      .list(pool, user)
+      .await?
+    };
+    let comment_ids = ids_of(COMMENT);
+    let mut comment_reports = if comment_ids.is_empty() {
+      vec![]
+    } else {
+      CommentReportQuery {
+        ids: Some(comment_ids.into_iter().map(CommentReportId).collect()),
+        limit: Some(limit),
+        ..Default::default()
+      }
+      .list(pool, user)
+      .await?
+    };
+    let private_message_ids = ids_of(PRIVATE_MESSAGE);
+    let mut private_message_reports = if private_message_ids.is_empty() {
+      vec![]
+    } else {
+      PrivateMessageReportQuery {
+        ids: Some(
+          private_message_ids
+            .into_iter()
+            .map(PrivateMessageReportId)
+            .collect(),
+        ),
+        limit: Some(limit),
+        ..Default::default()
+      }
+      .list(pool)
+      .await?
+    };
+
+    // Put the views back into the order of the combined list
+    let mut reports = Vec::with_capacity(ids.len());
+    for ReportId { kind, id } in ids {
+      let report = match kind.as_str() {
+        POST => post_reports
+          .iter()
+          .position(|r| r.post_report.id.0 == id)
+          .map(|i| ReportCombinedView::Post(post_reports.swap_remove(i))),
+        COMMENT => comment_reports
+          .iter()
+          .position(|r| r.comment_report.id.0 == id)
+          .map(|i| ReportCombinedView::Comment(comment_reports.swap_remove(i))),
+        _ => private_message_reports
+          .iter()
+          .position(|r| r.private_message_report.id.0 == id)
+          .map(|i| ReportCombinedView::PrivateMessage(private_message_reports.swap_remove(i))),
+      };
+      reports.extend(report);
+    }
+    Ok(reports)
+  }
+
+  /// The ids of the next `limit` reports visible to the user, ordered like the separate report
+  /// lists: oldest first for unresolved reports, otherwise newest first. All report types are
+  /// merged in a single query, so that pages are cut in the database.
+  async fn list_ids(
+    &self,
+    pool: &mut DbPool<'_>,
+    user: &LocalUserView,
+    limit: i64,
+  ) -> Result<Vec<ReportId>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (order, after) = if self.unresolved_only {
+      ("ASC", ">")
+    } else {
+      ("DESC", "<")
+    };
+    let unresolved = if self.unresolved_only {
+      "AND NOT r.resolved"
+    } else {
+      ""
+    };
+    // Non-admins only see the reports in communities they moderate. Private message reports are
+    // only visible to admins, and don't belong to any community.
+    let visible_in_community = r#"
+      ($1 OR EXISTS (
+        SELECT 1 FROM community_actions ca
+        WHERE ca.community_id = p.community_id
+          AND ca.person_id = $2
+          AND ca.became_moderator IS NOT NULL))
+      AND ($3::int IS NULL OR p.community_id = $3)"#;
+    let sql = format!(
+      r#"SELECT kind, id FROM (
+           SELECT r.published, '{POST}' AS kind, r.id
+             FROM post_report r
+             JOIN post p ON p.id = r.post_id
+             WHERE {visible_in_community} {unresolved}
+           UNION ALL
+           SELECT r.published, '{COMMENT}', r.id
+             FROM comment_report r
+             JOIN comment c ON c.id = r.comment_id
+             JOIN post p ON p.id = c.post_id
+             WHERE {visible_in_community} {unresolved}
+           UNION ALL
+           SELECT r.published, '{PRIVATE_MESSAGE}', r.id
+             FROM private_message_report r
+             WHERE $1 AND $3::int IS NULL {unresolved}
+         ) AS reports
+         WHERE $4::timestamptz IS NULL OR (published, kind, id) {after} ($4, $5, $6)
+         ORDER BY published {order}, kind {order}, id {order}
+         LIMIT $7"#
+    );
+    let after = self.page_after.as_ref();
+    sql_query(sql)
+      .bind::<Bool, _>(user.local_user.admin)
+      .bind::<Integer, _>(user.person.id)
+      .bind::<Nullable<Integer>, _>(self.community_id)
+      .bind::<Nullable<Timestamptz>, _>(after.map(|a| a.published))
+      .bind::<Nullable<Text>, _>(after.map(|a| a.kind))
+      .bind::<Nullable<Integer>, _>(after.map(|a| a.id))
+      .bind::<BigInt, _>(limit)
+      .load::<ReportId>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing)]
+mod tests {
+
+  use crate::{
+    report_combined_view::{ReportCombinedPaginationCursorData, ReportCombinedQuery},
+    structs::{LocalUserView, ReportCombinedView},
+  };
+  use lemmy_db_schema::{
+    assert_length,
+    source::{
+      comment_report::{CommentReport, CommentReportForm},
+      local_user_vote_display_mode::LocalUserVoteDisplayMode,
+      post_report::{PostReport, PostReportForm},
+      private_message::{PrivateMessage, PrivateMessageInsertForm},
+      private_message_report::{PrivateMessageReport, PrivateMessageReportForm},
+    },
+    traits::{Crud, Reportable},
+    utils::build_db_pool_for_tests,
+    ReportOutcome,
+  };
+  use lemmy_db_testutils::SeededInstance;
+  use lemmy_utils::error::LemmyResult;
+  use pretty_assertions::assert_eq;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_list_and_paginate() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let instance = SeededInstance::create(pool, "my_domain.tld").await?;
+    let (timmy, timmy_local_user) = instance.local_user(pool, "timmy_rcv").await?;
+    let timmy_view = LocalUserView {
+      local_user: timmy_local_user,
+      local_user_vote_display_mode: LocalUserVoteDisplayMode::default(),
+      person: timmy.clone(),
+      counts: Default::default(),
+    };
+    let (admin, admin_local_user) = instance.admin(pool, "admin_rcv").await?;
+    let admin_view = LocalUserView {
+      local_user: admin_local_user,
+      local_user_vote_display_mode: LocalUserVoteDisplayMode::default(),
+      person: admin,
+      counts: Default::default(),
+    };
+    let sara = instance.person(pool, "sara_rcv").await?;
+
+    // Timmy only moderates the first community
+    let community = instance
+      .community("test community rcv")
+      .creator(timmy.id)
+      .moderator(timmy.id)
+      .posts(1)
+      .comments_per_post(1)
+      .create(pool)
+      .await?;
+    let other_community = instance
+      .community("other community rcv")
+      .posts(1)
+      .create(pool)
+      .await?;
+
+    // Sara reports one of each, in this order
+    let post_report_form = PostReportForm {
+      creator_id: sara.id,
+      post_id: community.posts[0].id,
+      original_post_name: "Orig post".into(),
+      original_post_url: None,
+      original_post_body: None,
+      reason: "from sara".into(),
+    };
+    let post_report = PostReport::report(pool, &post_report_form).await?;
+
+    let comment_report_form = CommentReportForm {
+      creator_id: sara.id,
+      comment_id: community.comments[0].id,
+      original_comment_text: "Orig comment".into(),
+      reason: "from sara".into(),
+      severity: None,
+      category_id: None,
+    };
+    let comment_report = CommentReport::report(pool, &comment_report_form).await?;
+
+    let other_post_report_form = PostReportForm {
+      post_id: other_community.posts[0].id,
+      ..post_report_form
+    };
+    let other_post_report = PostReport::report(pool, &other_post_report_form).await?;
+
+    let pm_form = PrivateMessageInsertForm::new(timmy.id, Some(sara.id), "offensive".into());
+    let pm = PrivateMessage::create(pool, &pm_form).await?;
+    let pm_report_form = PrivateMessageReportForm {
+      creator_id: sara.id,
+      private_message_id: pm.id,
+      original_pm_text: pm.content.clone(),
+      reason: "from sara".into(),
+    };
+    let pm_report = PrivateMessageReport::report(pool, &pm_report_form).await?;
+
+    let report_ids = |reports: &[ReportCombinedView]| {
+      reports
+        .iter()
+        .map(|r| match r {
+          ReportCombinedView::Post(v) => ("post", v.post_report.id.0),
+          ReportCombinedView::Comment(v) => ("comment", v.comment_report.id.0),
+          ReportCombinedView::PrivateMessage(v) => ("pm", v.private_message_report.id.0),
+        })
+        .collect::<Vec<_>>()
+    };
+
+    // The mod only sees the reports in the community they moderate, newest first
+    let timmy_reports = ReportCombinedQuery::default()
+      .list(pool, &timmy_view)
+      .await?;
+    assert_eq!(
+      vec![("comment", comment_report.id.0), ("post", post_report.id.0)],
+      report_ids(&timmy_reports)
+    );
+
+    // The admin sees all of them
+    let admin_reports = ReportCombinedQuery::default()
+      .list(pool, &admin_view)
+      .await?;
+    let all_ids = vec![
+      ("pm", pm_report.id.0),
+      ("post", other_post_report.id.0),
+      ("comment", comment_report.id.0),
+      ("post", post_report.id.0),
+    ];
+    assert_eq!(all_ids, report_ids(&admin_reports));
+
+    // Walking the pages one report at a time gives the same list
+    let mut paged_ids = vec![];
+    let mut page_after = None;
+    loop {
+      let page = ReportCombinedQuery {
+        page_after,
+        limit: Some(1),
+        ..Default::default()
+      }
+      .list(pool, &admin_view)
+      .await?;
+      let Some(last) = page.last() else {
+        break;
+      };
+      page_after = Some(ReportCombinedPaginationCursorData::read(&last.to_cursor(), pool).await?);
+      paged_ids.extend(report_ids(&page));
+    }
+    assert_eq!(all_ids, paged_ids);
+
+    // Unresolved reports are listed oldest first, and the community filter excludes private
+    // message reports
+    let first_page = ReportCombinedQuery {
+      community_id: Some(community.community.id),
+      unresolved_only: true,
+      limit: Some(1),
+      ..Default::default()
+    }
+    .list(pool, &admin_view)
+    .await?;
+    assert_eq!(vec![("post", post_report.id.0)], report_ids(&first_page));
+    let second_page = ReportCombinedQuery {
+      community_id: Some(community.community.id),
+      unresolved_only: true,
+      page_after: Some(
+        ReportCombinedPaginationCursorData::read(&first_page[0].to_cursor(), pool).await?,
+      ),
+      ..Default::default()
+    }
+    .list(pool, &admin_view)
+    .await?;
+    assert_eq!(
+      vec![("comment", comment_report.id.0)],
+      report_ids(&second_page)
+    );
+
+    // Resolved reports are left out of the unresolved list
+    PostReport::resolve(pool, post_report.id, timmy.id, ReportOutcome::Dismissed).await?;
+    let unresolved = ReportCombinedQuery {
+      unresolved_only: true,
+      ..Default::default()
+    }
+    .list(pool, &timmy_view)
+    .await?;
+    assert_length!(1, unresolved);
+    assert_eq!(
+      vec![("comment", comment_report.id.0)],
+      report_ids(&unresolved)
+    );
+
+    instance.delete(pool).await?;
+
+    Ok(())
+  }
+}
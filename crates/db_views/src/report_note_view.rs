@@ -0,0 +1,36 @@
+use crate::structs::ReportNoteView;
+use diesel::{result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{CommentReportId, ReportNoteId},
+  schema::{person, report_note},
+  utils::{get_conn, DbPool},
+};
+
+impl ReportNoteView {
+  pub async fn read(pool: &mut DbPool<'_>, note_id: ReportNoteId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    report_note::table
+      .find(note_id)
+      .inner_join(person::table)
+      .select((report_note::all_columns, person::all_columns))
+      .first(conn)
+      .await
+  }
+
+  /// The notes of all the given comment reports, oldest first.
+  pub async fn list_for_comment_reports(
+    pool: &mut DbPool<'_>,
+    report_ids: &[CommentReportId],
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    report_note::table
+      .inner_join(person::table)
+      .filter(report_note::comment_report_id.eq_any(report_ids))
+      .select((report_note::all_columns, person::all_columns))
+      .order_by(report_note::published.asc())
+      .then_order_by(report_note::id.asc())
+      .load(conn)
+      .await
+  }
+}
@@ -0,0 +1,94 @@
+use crate::structs::SpamScoreView;
+use diesel::{
+  pg::Pg,
+  result::Error,
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  aliases,
+  newtypes::SpamScoreId,
+  schema::{comment, community, person, post, spam_score},
+  utils::{limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
+  SpamAction,
+};
+
+fn queries<'a>() -> Queries<
+  impl ReadFn<'a, SpamScoreView, SpamScoreId>,
+  impl ListFn<'a, SpamScoreView, SpamScoreQuery>,
+> {
+  let all_joins = |query: spam_score::BoxedQuery<'a, Pg>| {
+    query
+      .inner_join(person::table.on(spam_score::creator_id.eq(person::id)))
+      .inner_join(post::table.on(spam_score::post_id.eq(post::id)))
+      .left_join(comment::table.on(spam_score::comment_id.eq(comment::id.nullable())))
+      .inner_join(community::table.on(post::community_id.eq(community::id)))
+      .left_join(
+        aliases::person1
+          .on(spam_score::reviewer_id.eq(aliases::person1.field(person::id).nullable())),
+      )
+      .select((
+        spam_score::all_columns,
+        person::all_columns,
+        post::all_columns,
+        comment::all_columns.nullable(),
+        community::all_columns,
+        aliases::person1.fields(person::all_columns).nullable(),
+      ))
+  };
+
+  let read = move |mut conn: DbConn<'a>, id: SpamScoreId| async move {
+    all_joins(spam_score::table.find(id).into_boxed())
+      .first(&mut conn)
+      .await
+  };
+
+  let list = move |mut conn: DbConn<'a>, options: SpamScoreQuery| async move {
+    let mut query = all_joins(spam_score::table.into_boxed());
+
+    // Held content which wasn't reviewed yet is shown oldest first, like a queue. Otherwise
+    // all held and removed content is shown, as removals may need to be reverted too.
+    if options.unreviewed_only {
+      query = query
+        .filter(spam_score::action.eq(SpamAction::Hold))
+        .filter(spam_score::approved.is_null())
+        .order_by(spam_score::published.asc());
+    } else {
+      query = query
+        .filter(spam_score::action.ne(SpamAction::None))
+        .order_by(spam_score::published.desc());
+    }
+
+    let (limit, offset) = limit_and_offset(options.page, options.limit)?;
+
+    query
+      .limit(limit)
+      .offset(offset)
+      .load::<SpamScoreView>(&mut conn)
+      .await
+  };
+
+  Queries::new(read, list)
+}
+
+impl SpamScoreView {
+  pub async fn read(pool: &mut DbPool<'_>, id: SpamScoreId) -> Result<Self, Error> {
+    queries().read(pool, id).await
+  }
+}
+
+#[derive(Default)]
+pub struct SpamScoreQuery {
+  pub unreviewed_only: bool,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+impl SpamScoreQuery {
+  pub async fn list(self, pool: &mut DbPool<'_>) -> Result<Vec<SpamScoreView>, Error> {
+    queries().list(pool, self).await
+  }
+}
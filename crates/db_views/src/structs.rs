@@ -2,24 +2,37 @@
 use diesel::Queryable;
 use lemmy_db_schema::{
   aggregates::structs::{CommentAggregates, PersonAggregates, PostAggregates, SiteAggregates},
+  newtypes::PollOptionId,
   source::{
+    ban_appeal::BanAppeal,
     comment::Comment,
     comment_report::CommentReport,
     community::Community,
+    community_post_tag::CommunityPostTag,
+    community_request::{CommunityRequest, CommunityRequestComment},
     custom_emoji::CustomEmoji,
     custom_emoji_keyword::CustomEmojiKeyword,
     images::{ImageDetails, LocalImage},
+    instance::Instance,
     local_site::LocalSite,
     local_site_rate_limit::LocalSiteRateLimit,
     local_user::LocalUser,
     local_user_vote_display_mode::LocalUserVoteDisplayMode,
     person::Person,
+    person_mod_note::PersonModNote,
+    pm_conversation::PmConversation,
+    poll::{Poll, PollOption},
     post::Post,
+    post_media::PostMedia,
     post_report::PostReport,
     private_message::PrivateMessage,
     private_message_report::PrivateMessageReport,
     registration_application::RegistrationApplication,
+    registration_question::RegistrationAnswer,
+    report_note::ReportNote,
     site::Site,
+    spam_score::SpamScore,
+    vote_anomaly::VoteAnomaly,
   },
   SubscribedType,
 };
@@ -30,8 +43,7 @@ use ts_rs::TS;
 
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
-#[cfg_attr(feature = "full", derive(TS, Queryable))]
-#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// A comment report view.
 pub struct CommentReportView {
@@ -52,6 +64,46 @@ pub struct CommentReportView {
   pub my_vote: Option<i16>,
   #[cfg_attr(feature = "full", ts(optional))]
   pub resolver: Option<Person>,
+  /// Internal notes of the moderators, which are only included for moderators.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub notes: Option<Vec<ReportNoteView>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A moderator note on a report, with its creator.
+pub struct ReportNoteView {
+  pub report_note: ReportNote,
+  pub creator: Person,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An appeal against a community ban, with the banned person and the community.
+pub struct BanAppealView {
+  pub ban_appeal: BanAppeal,
+  pub creator: Person,
+  pub community: Community,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub resolver: Option<Person>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A private moderator note on a person, with its creator and community.
+pub struct PersonModNoteView {
+  pub person_mod_note: PersonModNote,
+  pub creator: Person,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community: Option<Community>,
 }
 
 #[skip_serializing_none]
@@ -75,6 +127,9 @@ pub struct CommentView {
   pub creator_blocked: bool,
   #[cfg_attr(feature = "full", ts(optional))]
   pub my_vote: Option<i16>,
+  /// The personal note of the user who saved the comment. Only visible to them.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub saved_note: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -128,8 +183,7 @@ pub struct PaginationCursor(pub String);
 
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
-#[cfg_attr(feature = "full", derive(TS, Queryable))]
-#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// A post view.
 pub struct PostView {
@@ -151,8 +205,16 @@ pub struct PostView {
   #[cfg_attr(feature = "full", ts(optional))]
   pub my_vote: Option<i16>,
   pub unread_comments: i64,
+  /// The personal note of the user who saved the post. Only visible to them.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub saved_note: Option<String>,
+  /// Additional images of a gallery post, in the order in which they are shown.
+  pub gallery: Vec<PostMedia>,
+  /// The tags of the post, which are defined by the community moderators.
+  pub tags: Vec<CommunityPostTag>,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS, Queryable))]
 #[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
@@ -161,7 +223,26 @@ pub struct PostView {
 pub struct PrivateMessageView {
   pub private_message: PrivateMessage,
   pub creator: Person,
-  pub recipient: Person,
+  /// Empty for messages in group conversations.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub recipient: Option<Person>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A private message conversation view.
+pub struct PmConversationView {
+  pub conversation: PmConversation,
+  /// The other participant of the conversation. Empty for group conversations.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub other_person: Option<Person>,
+  /// The newest message of the conversation which wasn't deleted.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub last_message: Option<PrivateMessage>,
+  /// The number of messages in the conversation which you haven't read yet.
+  pub unread_count: i64,
 }
 
 #[skip_serializing_none]
@@ -179,10 +260,20 @@ pub struct PrivateMessageReportView {
   pub resolver: Option<Person>,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+#[serde(tag = "type_")]
+/// A post, comment or private message report, for a single moderation queue.
+pub enum ReportCombinedView {
+  Post(PostReportView),
+  Comment(CommentReportView),
+  PrivateMessage(PrivateMessageReportView),
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
-#[cfg_attr(feature = "full", derive(TS, Queryable))]
-#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// A registration application view.
 pub struct RegistrationApplicationView {
@@ -191,6 +282,68 @@ pub struct RegistrationApplicationView {
   pub creator: Person,
   #[cfg_attr(feature = "full", ts(optional))]
   pub admin: Option<Person>,
+  /// The answers to the registration questions, in the order in which they were given.
+  pub answers: Vec<RegistrationAnswer>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A spam score, with the content it was calculated for.
+pub struct SpamScoreView {
+  pub spam_score: SpamScore,
+  pub creator: Person,
+  pub post: Post,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub comment: Option<Comment>,
+  pub community: Community,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reviewer: Option<Person>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A suspicious voting pattern, with the person, instance or post it concerns.
+pub struct VoteAnomalyView {
+  pub vote_anomaly: VoteAnomaly,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub person: Option<Person>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub instance: Option<Instance>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub post: Option<Post>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub reviewer: Option<Person>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A community request view.
+pub struct CommunityRequestView {
+  pub community_request: CommunityRequest,
+  pub creator: Person,
+  /// The sum of all votes for and against the request.
+  pub score: i64,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub my_vote: Option<i16>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A comment on a community request.
+pub struct CommunityRequestCommentView {
+  pub community_request_comment: CommunityRequestComment,
+  pub creator: Person,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -215,6 +368,19 @@ pub struct CustomEmojiView {
   pub keywords: Vec<CustomEmojiKeyword>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A poll with its options.
+pub struct PollView {
+  pub poll: Poll,
+  pub options: Vec<PollOption>,
+  /// The options which you voted for, if you are logged in.
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub my_votes: Option<Vec<PollOptionId>>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS, Queryable))]
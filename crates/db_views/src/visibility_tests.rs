@@ -0,0 +1,452 @@
+//! Property tests for the visibility rules of post, comment and report views.
+//!
+//! Random combinations of bans, blocks, deletions, removals, community visibility and origin are
+//! generated, and the content returned by the views is compared against [Scenario::expected],
+//! which is the single place where the rules for who may see what are written down.
+
+use crate::{
+  comment_report_view::CommentReportQuery,
+  comment_view::CommentQuery,
+  post_report_view::PostReportQuery,
+  post_view::PostQuery,
+  structs::LocalUserView,
+};
+use lemmy_db_schema::{
+  source::{
+    comment::{Comment, CommentInsertForm},
+    comment_report::{CommentReport, CommentReportForm},
+    community::{
+      Community,
+      CommunityFollower,
+      CommunityFollowerForm,
+      CommunityFollowerState,
+      CommunityInsertForm,
+      CommunityModerator,
+      CommunityModeratorForm,
+      CommunityPersonBan,
+      CommunityPersonBanForm,
+    },
+    community_block::{CommunityBlock, CommunityBlockForm},
+    instance_block::{InstanceBlock, InstanceBlockForm},
+    local_user_vote_display_mode::LocalUserVoteDisplayMode,
+    person::{Person, PersonUpdateForm},
+    person_block::{PersonBlock, PersonBlockForm},
+    post::{Post, PostInsertForm},
+    post_report::{PostReport, PostReportForm},
+    site::Site,
+  },
+  traits::{Bannable, Blockable, Crud, Followable, Joinable, Reportable},
+  utils::{build_db_pool_for_tests, DbPool},
+  CommunityVisibility,
+  ListingType,
+};
+use lemmy_db_testutils::SeededInstance;
+use lemmy_utils::error::LemmyResult;
+use proptest::{prelude::*, test_runner::TestRunner};
+use serial_test::serial;
+use url::Url;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Viewer {
+  Anonymous,
+  User,
+  /// Moderator of the generated community
+  Moderator,
+  Admin,
+}
+
+/// What the viewer has blocked.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Block {
+  None,
+  Creator,
+  Community,
+  Instance,
+}
+
+#[derive(Clone, Debug)]
+struct Scenario {
+  viewer: Viewer,
+  block: Block,
+  visibility: CommunityVisibility,
+  /// The post and comment are written by the viewer
+  viewer_is_creator: bool,
+  /// The viewer is an accepted follower of the community
+  viewer_follows: bool,
+  creator_banned_from_community: bool,
+  creator_shadow_banned: bool,
+  remote_community: bool,
+  local_listing: bool,
+  community_deleted: bool,
+  community_removed: bool,
+  post_deleted: bool,
+  post_removed: bool,
+  comment_deleted: bool,
+  comment_removed: bool,
+}
+
+/// What the viewer gets to see of the generated content.
+#[derive(Debug, Default, PartialEq)]
+struct Visible {
+  post: bool,
+  comment: bool,
+  comment_content: bool,
+  creator_banned_from_community: bool,
+  post_report: bool,
+  comment_report: bool,
+}
+
+fn scenario() -> impl Strategy<Value = Scenario> {
+  (
+    prop_oneof![
+      Just(Viewer::Anonymous),
+      Just(Viewer::User),
+      Just(Viewer::Moderator),
+      Just(Viewer::Admin),
+    ],
+    prop_oneof![
+      Just(Block::None),
+      Just(Block::Creator),
+      Just(Block::Community),
+      Just(Block::Instance),
+    ],
+    prop_oneof![
+      Just(CommunityVisibility::Public),
+      Just(CommunityVisibility::LocalOnly),
+      Just(CommunityVisibility::Private),
+    ],
+    any::<(bool, bool, bool, bool)>(),
+    any::<(bool, bool)>(),
+    any::<(bool, bool, bool, bool, bool, bool)>(),
+  )
+    .prop_map(
+      |(
+        viewer,
+        block,
+        visibility,
+        (viewer_is_creator, viewer_follows, creator_banned_from_community, creator_shadow_banned),
+        (remote_community, local_listing),
+        (
+          community_deleted,
+          community_removed,
+          post_deleted,
+          post_removed,
+          comment_deleted,
+          comment_removed,
+        ),
+      )| Scenario {
+        viewer,
+        block,
+        visibility,
+        viewer_is_creator,
+        viewer_follows,
+        creator_banned_from_community,
+        creator_shadow_banned,
+        remote_community,
+        local_listing,
+        community_deleted,
+        community_removed,
+        post_deleted,
+        post_removed,
+        comment_deleted,
+        comment_removed,
+      },
+    )
+}
+
+impl Scenario {
+  fn logged_in(&self) -> bool {
+    self.viewer != Viewer::Anonymous
+  }
+
+  fn is_creator(&self) -> bool {
+    self.logged_in() && self.viewer_is_creator
+  }
+
+  fn follows(&self) -> bool {
+    self.logged_in() && self.viewer_follows
+  }
+
+  /// Anonymous users can't block anything, and nobody can block themselves.
+  fn blocks(&self) -> Block {
+    if !self.logged_in() || (self.block == Block::Creator && self.is_creator()) {
+      Block::None
+    } else {
+      self.block
+    }
+  }
+
+  /// The visibility matrix which all views have to follow.
+  fn expected(&self) -> Visible {
+    let visibility_allowed = match self.visibility {
+      CommunityVisibility::Public => true,
+      CommunityVisibility::LocalOnly => self.logged_in(),
+      CommunityVisibility::Private => {
+        self.logged_in() && (self.viewer == Viewer::Admin || self.follows())
+      }
+    };
+    let listed = !(self.local_listing && self.remote_community);
+    let community_visible = visibility_allowed && listed && self.blocks() == Block::None;
+    // Content of shadow banned persons is only shown to themselves and admins.
+    let shadow_hidden =
+      self.creator_shadow_banned && !self.is_creator() && self.viewer != Viewer::Admin;
+
+    // Deleted posts are only shown to their creator, removed posts are only shown to admins when
+    // viewing a user profile.
+    let post = community_visible
+      && !self.community_deleted
+      && !self.community_removed
+      && !self.post_removed
+      && (!self.post_deleted || self.is_creator())
+      && !shadow_hidden;
+    // Deleted and removed comments are still listed to keep the comment tree intact, but without
+    // their content.
+    let comment = community_visible && !shadow_hidden;
+    let comment_content = comment && !self.comment_deleted && !self.comment_removed;
+    let can_see_reports = matches!(self.viewer, Viewer::Moderator | Viewer::Admin);
+
+    Visible {
+      post,
+      comment,
+      comment_content,
+      creator_banned_from_community: self.creator_banned_from_community && (post || comment),
+      post_report: can_see_reports,
+      comment_report: can_see_reports,
+    }
+  }
+}
+
+/// Creates the content for a scenario, and returns what the views show of it.
+async fn observe(pool: &mut DbPool<'_>, site: &Site, s: &Scenario) -> LemmyResult<Visible> {
+  let local = SeededInstance::create(pool, "my_domain.tld").await?;
+  let remote = SeededInstance::create(pool, "remote_domain.tld").await?;
+  let visible = observe_in(pool, site, s, &local, &remote).await;
+  // Clean up before checking the result, so that the next case starts from an empty instance
+  local.delete(pool).await?;
+  remote.delete(pool).await?;
+  visible
+}
+
+async fn observe_in(
+  pool: &mut DbPool<'_>,
+  site: &Site,
+  s: &Scenario,
+  local: &SeededInstance,
+  remote: &SeededInstance,
+) -> LemmyResult<Visible> {
+  let (viewer, viewer_local_user) = if s.viewer == Viewer::Admin {
+    local.admin(pool, "viewer").await?
+  } else {
+    local.local_user(pool, "viewer").await?
+  };
+  let creator = if s.is_creator() {
+    viewer.clone()
+  } else {
+    local.person(pool, "creator").await?
+  };
+  let reporter = local.person(pool, "reporter").await?;
+
+  let community_instance = if s.remote_community { remote } else { local };
+  let community_form = CommunityInsertForm {
+    local: Some(!s.remote_community),
+    visibility: Some(s.visibility),
+    deleted: Some(s.community_deleted),
+    removed: Some(s.community_removed),
+    ..CommunityInsertForm::new(
+      community_instance.instance.id,
+      "visibility".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    )
+  };
+  let community = Community::create(pool, &community_form).await?;
+
+  let post_form = PostInsertForm {
+    deleted: Some(s.post_deleted),
+    removed: Some(s.post_removed),
+    ..PostInsertForm::new("visibility post".into(), creator.id, community.id)
+  };
+  let post = Post::create(pool, &post_form).await?;
+  let comment_form = CommentInsertForm {
+    deleted: Some(s.comment_deleted),
+    removed: Some(s.comment_removed),
+    ..CommentInsertForm::new(creator.id, post.id, "visibility comment".into())
+  };
+  let comment = Comment::create(pool, &comment_form, None).await?;
+
+  if s.viewer == Viewer::Moderator {
+    let form = CommunityModeratorForm {
+      community_id: community.id,
+      person_id: viewer.id,
+    };
+    CommunityModerator::join(pool, &form).await?;
+  }
+  if s.follows() {
+    let form = CommunityFollowerForm {
+      state: Some(CommunityFollowerState::Accepted),
+      ..CommunityFollowerForm::new(community.id, viewer.id)
+    };
+    CommunityFollower::follow(pool, &form).await?;
+  }
+  match s.blocks() {
+    Block::None => {}
+    Block::Creator => {
+      let form = PersonBlockForm {
+        person_id: viewer.id,
+        target_id: creator.id,
+      };
+      PersonBlock::block(pool, &form).await?;
+    }
+    Block::Community => {
+      let form = CommunityBlockForm {
+        person_id: viewer.id,
+        community_id: community.id,
+      };
+      CommunityBlock::block(pool, &form).await?;
+    }
+    Block::Instance => {
+      let form = InstanceBlockForm {
+        person_id: viewer.id,
+        instance_id: community.instance_id,
+      };
+      InstanceBlock::block(pool, &form).await?;
+    }
+  }
+  if s.creator_banned_from_community {
+    let form = CommunityPersonBanForm {
+      community_id: community.id,
+      person_id: creator.id,
+      expires: None,
+      reason: None,
+      appeal: None,
+    };
+    CommunityPersonBan::ban(pool, &form).await?;
+  }
+  if s.creator_shadow_banned {
+    let form = PersonUpdateForm {
+      shadow_banned: Some(true),
+      ..Default::default()
+    };
+    Person::update(pool, creator.id, &form).await?;
+  }
+
+  let post_report_form = PostReportForm {
+    creator_id: reporter.id,
+    post_id: post.id,
+    original_post_name: post.name.clone(),
+    original_post_url: None,
+    original_post_body: None,
+    reason: "visibility".into(),
+  };
+  PostReport::report(pool, &post_report_form).await?;
+  let comment_report_form = CommentReportForm {
+    creator_id: reporter.id,
+    comment_id: comment.id,
+    original_comment_text: comment.content.clone(),
+    reason: "visibility".into(),
+    severity: None,
+    category_id: None,
+  };
+  CommentReport::report(pool, &comment_report_form).await?;
+
+  let local_user = s.logged_in().then_some(&viewer_local_user);
+  let listing_type = Some(if s.local_listing {
+    ListingType::Local
+  } else {
+    ListingType::All
+  });
+  let posts = PostQuery {
+    listing_type,
+    community_id: Some(community.id),
+    local_user,
+    ..Default::default()
+  }
+  .list(site, pool)
+  .await?;
+  let comments = CommentQuery {
+    listing_type,
+    post_id: Some(post.id),
+    local_user,
+    ..Default::default()
+  }
+  .list(site, pool)
+  .await?;
+
+  let mut visible = Visible {
+    post: !posts.is_empty(),
+    comment: !comments.is_empty(),
+    comment_content: comments.iter().any(|c| !c.comment.content.is_empty()),
+    creator_banned_from_community: posts
+      .iter()
+      .map(|p| p.creator_banned_from_community)
+      .chain(comments.iter().map(|c| c.creator_banned_from_community))
+      .any(|banned| banned),
+    ..Default::default()
+  };
+
+  // Reports can only be listed when logged in
+  if s.logged_in() {
+    let local_user_view = LocalUserView {
+      local_user: viewer_local_user,
+      local_user_vote_display_mode: LocalUserVoteDisplayMode::default(),
+      person: viewer,
+      counts: Default::default(),
+    };
+    visible.post_report = !PostReportQuery {
+      community_id: Some(community.id),
+      ..Default::default()
+    }
+    .list(pool, &local_user_view)
+    .await?
+    .is_empty();
+    visible.comment_report = !CommentReportQuery {
+      community_id: Some(community.id),
+      ..Default::default()
+    }
+    .list(pool, &local_user_view)
+    .await?
+    .is_empty();
+  }
+
+  Ok(visible)
+}
+
+fn site() -> LemmyResult<Site> {
+  Ok(Site {
+    id: Default::default(),
+    name: String::new(),
+    sidebar: None,
+    published: Default::default(),
+    updated: None,
+    icon: None,
+    banner: None,
+    description: None,
+    actor_id: Url::parse("http://example.com")?.into(),
+    last_refreshed_at: Default::default(),
+    inbox_url: Url::parse("http://example.com")?.into(),
+    private_key: None,
+    public_key: String::new(),
+    instance_id: Default::default(),
+    content_warning: None,
+  })
+}
+
+#[test]
+#[serial]
+fn views_follow_visibility_matrix() -> LemmyResult<()> {
+  let runtime = tokio::runtime::Runtime::new()?;
+  let _guard = runtime.enter();
+  let pool = build_db_pool_for_tests();
+  let site = site()?;
+
+  let mut runner = TestRunner::new(ProptestConfig::with_cases(64));
+  runner.run(&scenario(), |scenario| {
+    let visible = runtime
+      .block_on(observe(&mut (&pool).into(), &site, &scenario))
+      .map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(scenario.expected(), visible, "{:?}", scenario);
+    Ok(())
+  })?;
+
+  Ok(())
+}
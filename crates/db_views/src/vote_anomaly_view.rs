@@ -0,0 +1,87 @@
+use crate::structs::VoteAnomalyView;
+use diesel::{
+  pg::Pg,
+  result::Error,
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  aliases,
+  newtypes::VoteAnomalyId,
+  schema::{instance, person, post, vote_anomaly},
+  utils::{limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
+};
+
+fn queries<'a>() -> Queries<
+  impl ReadFn<'a, VoteAnomalyView, VoteAnomalyId>,
+  impl ListFn<'a, VoteAnomalyView, VoteAnomalyQuery>,
+> {
+  let all_joins = |query: vote_anomaly::BoxedQuery<'a, Pg>| {
+    query
+      .left_join(person::table.on(vote_anomaly::person_id.eq(person::id.nullable())))
+      .left_join(instance::table.on(vote_anomaly::instance_id.eq(instance::id.nullable())))
+      .left_join(post::table.on(vote_anomaly::post_id.eq(post::id.nullable())))
+      .left_join(
+        aliases::person1
+          .on(vote_anomaly::reviewer_id.eq(aliases::person1.field(person::id).nullable())),
+      )
+      .select((
+        vote_anomaly::all_columns,
+        person::all_columns.nullable(),
+        instance::all_columns.nullable(),
+        post::all_columns.nullable(),
+        aliases::person1.fields(person::all_columns).nullable(),
+      ))
+  };
+
+  let read = move |mut conn: DbConn<'a>, id: VoteAnomalyId| async move {
+    all_joins(vote_anomaly::table.find(id).into_boxed())
+      .first(&mut conn)
+      .await
+  };
+
+  let list = move |mut conn: DbConn<'a>, options: VoteAnomalyQuery| async move {
+    let mut query = all_joins(vote_anomaly::table.into_boxed());
+
+    // Findings which weren't reviewed yet are shown oldest first, like a queue
+    if options.unreviewed_only {
+      query = query
+        .filter(vote_anomaly::votes_removed.is_null())
+        .order_by(vote_anomaly::published.asc());
+    } else {
+      query = query.order_by(vote_anomaly::published.desc());
+    }
+
+    let (limit, offset) = limit_and_offset(options.page, options.limit)?;
+
+    query
+      .limit(limit)
+      .offset(offset)
+      .load::<VoteAnomalyView>(&mut conn)
+      .await
+  };
+
+  Queries::new(read, list)
+}
+
+impl VoteAnomalyView {
+  pub async fn read(pool: &mut DbPool<'_>, id: VoteAnomalyId) -> Result<Self, Error> {
+    queries().read(pool, id).await
+  }
+}
+
+#[derive(Default)]
+pub struct VoteAnomalyQuery {
+  pub unreviewed_only: bool,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+impl VoteAnomalyQuery {
+  pub async fn list(self, pool: &mut DbPool<'_>) -> Result<Vec<VoteAnomalyView>, Error> {
+    queries().list(pool, self).await
+  }
+}
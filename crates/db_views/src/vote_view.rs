@@ -36,6 +36,9 @@ impl VoteView {
         post_actions::like_score.assume_not_null(),
       ))
       .order_by(post_actions::like_score)
+      // Within the same score, newest votes first, so that brigades are easy to spot
+      .then_order_by(post_actions::liked.desc())
+      .then_order_by(post_actions::person_id)
       .limit(limit)
       .offset(offset)
       .load::<Self>(conn)
@@ -69,6 +72,8 @@ impl VoteView {
         comment_actions::like_score.assume_not_null(),
       ))
       .order_by(comment_actions::like_score)
+      .then_order_by(comment_actions::liked.desc())
+      .then_order_by(comment_actions::person_id)
       .limit(limit)
       .offset(offset)
       .load::<Self>(conn)
@@ -203,6 +208,8 @@ mod tests {
       community_id: inserted_community.id,
       person_id: inserted_timmy.id,
       expires: None,
+      reason: None,
+      appeal: None,
     };
     CommunityPersonBan::ban(pool, &ban_timmy_form).await?;
 
@@ -226,4 +233,54 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn newest_votes_first() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let mut voters = vec![];
+    for name in ["anna_vv", "bert_vv", "carl_vv"] {
+      let form = PersonInsertForm::test_form(inserted_instance.id, name);
+      voters.push(Person::create(pool, &form).await?);
+    }
+
+    let new_community = CommunityInsertForm::new(
+      inserted_instance.id,
+      "test community vv order".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &new_community).await?;
+    let new_post = PostInsertForm::new(
+      "A test post vv order".into(),
+      voters[0].id,
+      inserted_community.id,
+    );
+    let inserted_post = Post::create(pool, &new_post).await?;
+
+    // All of them downvote, one after the other
+    for voter in &voters {
+      let form = PostLikeForm {
+        post_id: inserted_post.id,
+        person_id: voter.id,
+        score: -1,
+      };
+      PostLike::like(pool, &form).await?;
+    }
+
+    // Votes with the same score are listed with the newest first
+    let read_post_vote_views = VoteView::list_for_post(pool, inserted_post.id, None, None).await?;
+    let voter_names = read_post_vote_views
+      .iter()
+      .map(|v| v.creator.name.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(vec!["carl_vv", "bert_vv", "anna_vv"], voter_names);
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
 }
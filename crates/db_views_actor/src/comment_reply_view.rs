@@ -1,4 +1,5 @@
-use crate::structs::CommentReplyView;
+use crate::structs::{CommentReplyGroupView, CommentReplyView};
+use chrono::{DateTime, Utc};
 use diesel::{
   dsl::{exists, not},
   pg::Pg,
@@ -10,9 +11,10 @@ use diesel::{
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
+use diesel_ltree::Ltree;
 use lemmy_db_schema::{
   aliases::{self, creator_community_actions},
-  newtypes::{CommentReplyId, PersonId},
+  newtypes::{CommentReplyId, PersonId, PostId},
   schema::{
     comment,
     comment_actions,
@@ -25,7 +27,7 @@ use lemmy_db_schema::{
     person_actions,
     post,
   },
-  source::{community::CommunityFollower, local_user::LocalUser},
+  source::{comment::Comment, community::CommunityFollower, local_user::LocalUser, post::Post},
   utils::{
     actions,
     actions_alias,
@@ -39,6 +41,7 @@ use lemmy_db_schema::{
   },
   CommentSortType,
 };
+use std::collections::HashMap;
 
 fn queries<'a>() -> Queries<
   impl ReadFn<'a, CommentReplyView, (CommentReplyId, Option<PersonId>)>,
@@ -126,18 +129,48 @@ fn queries<'a>() -> Queries<
       query = query.filter(comment_reply::read.eq(false));
     }
 
+    if let Some((published, id)) = options.before {
+      query = query.filter(
+        comment_reply::published.lt(published).or(
+          comment_reply::published
+            .eq(published)
+            .and(comment_reply::id.lt(id)),
+        ),
+      );
+    }
+
     if !options.show_bot_accounts {
       query = query.filter(not(person::bot_account));
     };
 
+    // Replies of shadow banned persons are only shown to themselves and admins
+    query = query.filter(
+      person::shadow_banned
+        .eq(false)
+        .or(person::id.nullable().eq(options.my_person_id))
+        .or(exists(
+          local_user::table.filter(
+            local_user::person_id
+              .nullable()
+              .eq(options.my_person_id)
+              .and(local_user::admin.eq(true)),
+          ),
+        )),
+    );
+
     query = match options.sort.unwrap_or(CommentSortType::New) {
       CommentSortType::Hot => query.then_order_by(comment_aggregates::hot_rank.desc()),
       CommentSortType::Controversial => {
         query.then_order_by(comment_aggregates::controversy_rank.desc())
       }
-      CommentSortType::New => query.then_order_by(comment_reply::published.desc()),
+      CommentSortType::New => query
+        .then_order_by(comment_reply::published.desc())
+        .then_order_by(comment_reply::id.desc()),
       CommentSortType::Old => query.then_order_by(comment_reply::published.asc()),
-      CommentSortType::Top => query.order_by(comment_aggregates::score.desc()),
+      // Mentions and replies are listed without their threads, so sort them like Top
+      CommentSortType::Top | CommentSortType::QandA => {
+        query.order_by(comment_aggregates::score.desc())
+      }
     };
 
     // Don't show replies from blocked persons
@@ -161,7 +194,12 @@ impl CommentReplyView {
     comment_reply_id: CommentReplyId,
     my_person_id: Option<PersonId>,
   ) -> Result<Self, Error> {
-    queries().read(pool, (comment_reply_id, my_person_id)).await
+    let mut res = queries()
+      .read(pool, (comment_reply_id, my_person_id))
+      .await?;
+    Comment::restore_archived(pool, [&mut res.comment]).await?;
+    Post::restore_archived(pool, [&mut res.post]).await?;
+    Ok(res)
   }
 
   /// Gets the number of unread replies
@@ -188,6 +226,10 @@ impl CommentReplyView {
       query = query.filter(not(person::bot_account));
     }
 
+    if !local_user.admin {
+      query = query.filter(person::shadow_banned.eq(false));
+    }
+
     query
       // Don't count replies from blocked users
       .filter(person_actions::blocked.is_null())
@@ -199,6 +241,141 @@ impl CommentReplyView {
       .first::<i64>(conn)
       .await
   }
+
+  /// Gets the number of posts and comments with unread replies
+  pub async fn get_unread_reply_groups(
+    pool: &mut DbPool<'_>,
+    local_user: &LocalUser,
+  ) -> Result<i64, Error> {
+    let keys = Self::list_group_keys(
+      pool,
+      local_user.person_id,
+      true,
+      local_user.show_bot_accounts,
+    )
+    .await?;
+
+    Ok(i64::try_from(group_replies(keys).len()).unwrap_or(i64::MAX))
+  }
+
+  /// Lists the id, read status and group key of the replies to a person, newest first. Replies
+  /// in deleted or removed comments are skipped.
+  async fn list_group_keys(
+    pool: &mut DbPool<'_>,
+    recipient_id: PersonId,
+    unread_only: bool,
+    show_bot_accounts: bool,
+  ) -> Result<Vec<(CommentReplyId, bool, ReplyGroupKey)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+
+    let mut query = comment_reply::table
+      .inner_join(comment::table)
+      .left_join(actions(
+        person_actions::table,
+        Some(recipient_id),
+        comment::creator_id,
+      ))
+      .inner_join(person::table.on(comment::creator_id.eq(person::id)))
+      .into_boxed();
+
+    if unread_only {
+      query = query.filter(comment_reply::read.eq(false));
+    }
+
+    if !show_bot_accounts {
+      query = query.filter(not(person::bot_account));
+    }
+
+    // Replies of shadow banned persons are only shown to admins
+    query = query.filter(
+      person::shadow_banned.eq(false).or(exists(
+        local_user::table.filter(
+          local_user::person_id
+            .eq(recipient_id)
+            .and(local_user::admin.eq(true)),
+        ),
+      )),
+    );
+
+    let rows = query
+      // Don't show replies from blocked persons
+      .filter(person_actions::blocked.is_null())
+      .filter(comment_reply::recipient_id.eq(recipient_id))
+      .filter(comment::deleted.eq(false))
+      .filter(comment::removed.eq(false))
+      .order_by(comment_reply::published.desc())
+      .then_order_by(comment_reply::id.desc())
+      .select((
+        comment_reply::id,
+        comment_reply::read,
+        comment::post_id,
+        comment::path,
+      ))
+      .load::<(CommentReplyId, bool, PostId, Ltree)>(conn)
+      .await?;
+
+    Ok(
+      rows
+        .into_iter()
+        .map(|(id, read, post_id, path)| (id, read, ReplyGroupKey::new(post_id, &path)))
+        .collect(),
+    )
+  }
+}
+
+/// The post or comment which a reply responds to. Top-level comments are grouped by their post,
+/// other comments by their parent comment.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ReplyGroupKey {
+  post_id: PostId,
+  parent_path: String,
+}
+
+impl ReplyGroupKey {
+  fn new(post_id: PostId, path: &Ltree) -> Self {
+    let parent_path = path
+      .0
+      .rsplit_once('.')
+      .map(|(parent, _)| parent)
+      .unwrap_or_default()
+      .to_string();
+    ReplyGroupKey {
+      post_id,
+      parent_path,
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct ReplyGroup {
+  latest_id: CommentReplyId,
+  count: i64,
+  unread_count: i64,
+}
+
+/// Groups replies by their key, keeping the order of the first (latest) reply of each group.
+fn group_replies(keys: Vec<(CommentReplyId, bool, ReplyGroupKey)>) -> Vec<ReplyGroup> {
+  let mut groups: Vec<ReplyGroup> = vec![];
+  let mut group_index = HashMap::new();
+
+  for (id, read, key) in keys {
+    let index = *group_index.entry(key).or_insert_with(|| {
+      groups.push(ReplyGroup {
+        latest_id: id,
+        count: 0,
+        unread_count: 0,
+      });
+      groups.len() - 1
+    });
+    if let Some(group) = groups.get_mut(index) {
+      group.count += 1;
+      if !read {
+        group.unread_count += 1;
+      }
+    }
+  }
+
+  groups
 }
 
 #[derive(Default, Clone)]
@@ -208,21 +385,67 @@ pub struct CommentReplyQuery {
   pub sort: Option<CommentSortType>,
   pub unread_only: bool,
   pub show_bot_accounts: bool,
+  /// Only replies which were published before this time, or at the same time with a lower id.
+  /// Used for cursor pagination with the `New` sort.
+  pub before: Option<(DateTime<Utc>, CommentReplyId)>,
   pub page: Option<i64>,
   pub limit: Option<i64>,
 }
 
 impl CommentReplyQuery {
   pub async fn list(self, pool: &mut DbPool<'_>) -> Result<Vec<CommentReplyView>, Error> {
-    queries().list(pool, self).await
+    let mut replies = queries().list(pool, self).await?;
+    Comment::restore_archived(pool, replies.iter_mut().map(|r| &mut r.comment)).await?;
+    Post::restore_archived(pool, replies.iter_mut().map(|r| &mut r.post)).await?;
+    Ok(replies)
+  }
+
+  /// Lists the replies grouped by the post or comment they respond to, ordered by the latest
+  /// reply of each group. The sort and cursor are ignored.
+  pub async fn list_grouped(
+    self,
+    pool: &mut DbPool<'_>,
+  ) -> Result<Vec<CommentReplyGroupView>, Error> {
+    let Some(recipient_id) = self.recipient_id else {
+      return Ok(vec![]);
+    };
+    let (limit, offset) = limit_and_offset(self.page, self.limit)?;
+
+    let keys = CommentReplyView::list_group_keys(
+      pool,
+      recipient_id,
+      self.unread_only,
+      self.show_bot_accounts,
+    )
+    .await?;
+    let groups = group_replies(keys)
+      .into_iter()
+      .skip(usize::try_from(offset).unwrap_or_default())
+      .take(usize::try_from(limit).unwrap_or_default());
+
+    let mut views = vec![];
+    for group in groups {
+      let latest = CommentReplyView::read(pool, group.latest_id, self.my_person_id).await?;
+      views.push(CommentReplyGroupView {
+        latest,
+        count: group.count,
+        unread_count: group.unread_count,
+      });
+    }
+    Ok(views)
   }
 }
 
 #[cfg(test)]
 mod tests {
 
-  use crate::{comment_reply_view::CommentReplyQuery, structs::CommentReplyView};
+  use crate::{
+    comment_reply_view::{group_replies, CommentReplyQuery, ReplyGroup, ReplyGroupKey},
+    structs::CommentReplyView,
+  };
+  use diesel_ltree::Ltree;
   use lemmy_db_schema::{
+    newtypes::{CommentReplyId, PostId},
     source::{
       comment::{Comment, CommentInsertForm},
       comment_reply::{CommentReply, CommentReplyInsertForm, CommentReplyUpdateForm},
@@ -313,6 +536,7 @@ mod tests {
       sort: None,
       unread_only: false,
       show_bot_accounts: true,
+      before: None,
       page: None,
       limit: None,
     };
@@ -376,4 +600,124 @@ mod tests {
     assert_eq!(expected_reply, updated_reply);
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_shadow_banned_replies() -> LemmyResult<()> {
+    let pool = &build_db_pool_for_tests();
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+
+    let spammer_form = PersonInsertForm::test_form(inserted_instance.id, "shadow_spammer");
+    let spammer = Person::create(pool, &spammer_form).await?;
+    let recipient_form = PersonInsertForm::test_form(inserted_instance.id, "shadow_recipient");
+    let recipient = Person::create(pool, &recipient_form).await?;
+    let recipient_local_user =
+      LocalUser::create(pool, &LocalUserInsertForm::test_form(recipient.id), vec![]).await?;
+    let admin_form = PersonInsertForm::test_form(inserted_instance.id, "shadow_admin");
+    let admin = Person::create(pool, &admin_form).await?;
+    let admin_local_user = LocalUser::create(
+      pool,
+      &LocalUserInsertForm::test_form_admin(admin.id),
+      vec![],
+    )
+    .await?;
+
+    let new_community = CommunityInsertForm::new(
+      inserted_instance.id,
+      "test community shadow".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let inserted_community = Community::create(pool, &new_community).await?;
+    let new_post = PostInsertForm::new("A test post".into(), spammer.id, inserted_community.id);
+    let inserted_post = Post::create(pool, &new_post).await?;
+    let comment_form = CommentInsertForm::new(spammer.id, inserted_post.id, "Spam".into());
+    let inserted_comment = Comment::create(pool, &comment_form, None).await?;
+    for recipient_id in [recipient.id, admin.id] {
+      let comment_reply_form = CommentReplyInsertForm {
+        recipient_id,
+        comment_id: inserted_comment.id,
+        read: None,
+      };
+      CommentReply::create(pool, &comment_reply_form).await?;
+    }
+
+    let person_update_form = PersonUpdateForm {
+      shadow_banned: Some(true),
+      ..Default::default()
+    };
+    Person::update(pool, spammer.id, &person_update_form).await?;
+
+    let query = |recipient_id, my_person_id| CommentReplyQuery {
+      recipient_id: Some(recipient_id),
+      my_person_id: Some(my_person_id),
+      show_bot_accounts: true,
+      ..Default::default()
+    };
+
+    // Others don't get the reply
+    assert_eq!(0, query(recipient.id, recipient.id).list(pool).await?.len());
+    assert_eq!(
+      0,
+      CommentReplyView::get_unread_replies(pool, &recipient_local_user).await?
+    );
+    assert_eq!(
+      0,
+      CommentReplyView::get_unread_reply_groups(pool, &recipient_local_user).await?
+    );
+
+    // Admins still see it
+    assert_eq!(1, query(admin.id, admin.id).list(pool).await?.len());
+    assert_eq!(
+      1,
+      CommentReplyView::get_unread_replies(pool, &admin_local_user).await?
+    );
+    assert_eq!(
+      1,
+      CommentReplyView::get_unread_reply_groups(pool, &admin_local_user).await?
+    );
+
+    // And so does the shadow banned author
+    assert_eq!(1, query(recipient.id, spammer.id).list(pool).await?.len());
+
+    Instance::delete(pool, inserted_instance.id).await?;
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_group_replies() {
+    let key = |post_id, path: &str| ReplyGroupKey::new(PostId(post_id), &Ltree(path.to_string()));
+    let keys = vec![
+      (CommentReplyId(5), false, key(1, "0.10.15")),
+      (CommentReplyId(4), false, key(1, "0.14")),
+      (CommentReplyId(3), true, key(1, "0.10.13")),
+      (CommentReplyId(2), true, key(2, "0.12")),
+      (CommentReplyId(1), true, key(1, "0.11")),
+    ];
+
+    let expected = vec![
+      // Replies to comment 10
+      ReplyGroup {
+        latest_id: CommentReplyId(5),
+        count: 2,
+        unread_count: 1,
+      },
+      // Top-level comments on post 1
+      ReplyGroup {
+        latest_id: CommentReplyId(4),
+        count: 2,
+        unread_count: 1,
+      },
+      // Top-level comments on post 2
+      ReplyGroup {
+        latest_id: CommentReplyId(2),
+        count: 1,
+        unread_count: 0,
+      },
+    ];
+    assert_eq!(expected, group_replies(keys));
+  }
 }
@@ -1,8 +1,6 @@
 use crate::structs::CommunityPersonBanView;
-use diesel::{
-  dsl::{exists, not},
-  select,
-};
+use chrono::{DateTime, Utc};
+use diesel::{OptionalExtension, QueryDsl};
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   newtypes::{CommunityId, PersonId},
@@ -12,19 +10,37 @@ use lemmy_db_schema::{
 use lemmy_utils::error::{LemmyErrorType, LemmyResult};
 
 impl CommunityPersonBanView {
+  /// Fails if the person is banned from the community. The error includes the reason of the ban,
+  /// how it can be appealed and when it expires.
   pub async fn check(
     pool: &mut DbPool<'_>,
     from_person_id: PersonId,
     from_community_id: CommunityId,
   ) -> LemmyResult<()> {
     let conn = &mut get_conn(pool).await?;
-    select(not(exists(find_action(
+    let ban = find_action(
       community_actions::received_ban,
       (from_person_id, from_community_id),
-    ))))
-    .get_result::<bool>(conn)
-    .await?
-    .then_some(())
-    .ok_or(LemmyErrorType::PersonIsBannedFromCommunity.into())
+    )
+    .select((
+      community_actions::ban_reason,
+      community_actions::ban_appeal,
+      community_actions::ban_expires,
+    ))
+    .first::<(Option<String>, Option<String>, Option<DateTime<Utc>>)>(conn)
+    .await
+    .optional()?;
+
+    match ban {
+      Some((reason, appeal, expires)) => Err(
+        LemmyErrorType::PersonIsBannedFromCommunity {
+          reason,
+          appeal,
+          expires: expires.map(|e| e.timestamp()),
+        }
+        .into(),
+      ),
+      None => Ok(()),
+    }
   }
 }
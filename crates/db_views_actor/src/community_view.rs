@@ -59,6 +59,9 @@ fn queries<'a>() -> Queries<
     community_actions::blocked.nullable().is_not_null(),
     community_aggregates::all_columns,
     community_actions::received_ban.nullable().is_not_null(),
+    community_actions::suppressed_recommendation
+      .nullable()
+      .is_not_null(),
   );
 
   let not_removed_or_deleted = community::removed
@@ -92,6 +95,7 @@ fn queries<'a>() -> Queries<
 
     let mut query = all_joins(community::table.into_boxed(), options.local_user).select(selection);
 
+    let is_search = options.search_term.is_some();
     if let Some(search_term) = options.search_term {
       let searcher = fuzzy_search(&search_term);
       let name_filter = community::name.ilike(searcher.clone());
@@ -109,6 +113,7 @@ fn queries<'a>() -> Queries<
       query = query.filter(not_removed_or_deleted).filter(
         community::hidden
           .eq(false)
+          .and(community::soft_launched.eq(false))
           .or(community_actions::follow_state.is_not_null()),
       );
     }
@@ -144,6 +149,16 @@ fn queries<'a>() -> Queries<
       };
     }
 
+    // Communities which the user asked not to recommend are left out of discovery listings, but can
+    // still be found via search
+    if !is_search && options.listing_type != Some(ListingType::Subscribed) {
+      query = query.filter(
+        community_actions::suppressed_recommendation
+          .is_null()
+          .or(community_actions::followed.is_not_null()),
+      );
+    }
+
     // Don't show blocked communities and communities on blocked instances. nsfw communities are
     // also hidden (based on profile setting)
     query = query.filter(instance_actions::blocked.is_null());
@@ -0,0 +1,264 @@
+use crate::{
+  comment_reply_view::CommentReplyQuery,
+  person_mention_view::PersonMentionQuery,
+  structs::{CommentReplyView, InboxCombinedView},
+};
+use chrono::{DateTime, Utc};
+use diesel::result::Error;
+use lemmy_db_schema::{
+  newtypes::{CommentReplyId, PersonId, PersonMentionId, PrivateMessageId},
+  source::{
+    comment::Comment,
+    comment_reply::CommentReply,
+    person_mention::PersonMention,
+    private_message::PrivateMessage,
+  },
+  traits::Crud,
+  utils::{limit_and_offset, DbPool},
+  CommentSortType,
+};
+use lemmy_db_views::{private_message_view::PrivateMessageQuery, structs::PaginationCursor};
+use std::cmp::Ordering;
+
+/// The kind of an inbox item. For items published at the same time, replies are listed before
+/// mentions, which are listed before private messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum InboxItemKind {
+  Reply,
+  Mention,
+  PrivateMessage,
+}
+
+impl InboxItemKind {
+  fn prefix(self) -> char {
+    match self {
+      InboxItemKind::Reply => 'R',
+      InboxItemKind::Mention => 'M',
+      InboxItemKind::PrivateMessage => 'D',
+    }
+  }
+
+  fn from_prefix(prefix: char) -> Option<Self> {
+    match prefix {
+      'R' => Some(InboxItemKind::Reply),
+      'M' => Some(InboxItemKind::Mention),
+      'D' => Some(InboxItemKind::PrivateMessage),
+      _ => None,
+    }
+  }
+}
+
+/// The position of an item in the combined inbox, which is ordered by descending publish time,
+/// then by kind, then by descending id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InboxCombinedPaginationCursorData {
+  published: DateTime<Utc>,
+  kind: InboxItemKind,
+  id: i32,
+}
+
+impl InboxCombinedPaginationCursorData {
+  /// Reads the position of the item referenced by the given cursor.
+  pub async fn read(cursor: &PaginationCursor, pool: &mut DbPool<'_>) -> Result<Self, Error> {
+    let err_msg = || Error::QueryBuilderError("Could not parse pagination token".into());
+    let kind = cursor
+      .0
+      .chars()
+      .next()
+      .and_then(InboxItemKind::from_prefix)
+      .ok_or_else(err_msg)?;
+    let id = cursor
+      .0
+      .get(1..)
+      .and_then(|e| i32::from_str_radix(e, 16).ok())
+      .ok_or_else(err_msg)?;
+
+    let published = match kind {
+      InboxItemKind::Reply => {
+        CommentReply::read(pool, CommentReplyId(id))
+          .await?
+          .published
+      }
+      InboxItemKind::Mention => {
+        // Mentions are sorted by the publish time of their comment
+        let mention = PersonMention::read(pool, PersonMentionId(id)).await?;
+        Comment::read(pool, mention.comment_id).await?.published
+      }
+      InboxItemKind::PrivateMessage => {
+        PrivateMessage::read(pool, PrivateMessageId(id))
+          .await?
+          .published
+      }
+    };
+
+    Ok(InboxCombinedPaginationCursorData {
+      published,
+      kind,
+      id,
+    })
+  }
+
+  /// The id bound to pass to the query of the given item kind, so that it only returns items
+  /// which come after this position.
+  fn before_id(&self, kind: InboxItemKind) -> i32 {
+    match kind.cmp(&self.kind) {
+      Ordering::Less => i32::MIN,
+      Ordering::Equal => self.id,
+      Ordering::Greater => i32::MAX,
+    }
+  }
+
+  fn cmp_position(&self, other: &Self) -> Ordering {
+    other
+      .published
+      .cmp(&self.published)
+      .then(self.kind.cmp(&other.kind))
+      .then(other.id.cmp(&self.id))
+  }
+}
+
+impl InboxCombinedView {
+  /// Get cursor for the page that starts immediately after this item.
+  pub fn to_cursor(&self) -> PaginationCursor {
+    let position = self.position();
+    // hex encoding to prevent ossification
+    PaginationCursor(format!("{}{:x}", position.kind.prefix(), position.id))
+  }
+
+  fn position(&self) -> InboxCombinedPaginationCursorData {
+    match self {
+      InboxCombinedView::CommentReply(v) | InboxCombinedView::PostReply(v) => {
+        InboxCombinedPaginationCursorData {
+          published: v.comment_reply.published,
+          kind: InboxItemKind::Reply,
+          id: v.comment_reply.id.0,
+        }
+      }
+      InboxCombinedView::PersonMention(v) => InboxCombinedPaginationCursorData {
+        published: v.comment.published,
+        kind: InboxItemKind::Mention,
+        id: v.person_mention.id.0,
+      },
+      InboxCombinedView::PrivateMessage(v) => InboxCombinedPaginationCursorData {
+        published: v.private_message.published,
+        kind: InboxItemKind::PrivateMessage,
+        id: v.private_message.id.0,
+      },
+    }
+  }
+
+  fn from_reply(view: CommentReplyView) -> Self {
+    // Top-level comments have a path of the form `0.<comment_id>`
+    if view.comment.path.0.split('.').count() == 2 {
+      InboxCombinedView::PostReply(view)
+    } else {
+      InboxCombinedView::CommentReply(view)
+    }
+  }
+}
+
+#[derive(Clone)]
+pub struct InboxCombinedQuery {
+  pub my_person_id: PersonId,
+  pub unread_only: bool,
+  pub show_bot_accounts: bool,
+  pub page_after: Option<InboxCombinedPaginationCursorData>,
+  pub limit: Option<i64>,
+}
+
+impl InboxCombinedQuery {
+  pub async fn list(self, pool: &mut DbPool<'_>) -> Result<Vec<InboxCombinedView>, Error> {
+    let (limit, _) = limit_and_offset(None, self.limit)?;
+    let before = |kind: InboxItemKind| self.page_after.map(|p| (p.published, p.before_id(kind)));
+
+    // Each source is ordered the same way as the combined inbox, so the first page of the
+    // merged list is always contained in the first pages of the sources.
+    let replies = CommentReplyQuery {
+      my_person_id: Some(self.my_person_id),
+      recipient_id: Some(self.my_person_id),
+      sort: Some(CommentSortType::New),
+      unread_only: self.unread_only,
+      show_bot_accounts: self.show_bot_accounts,
+      before: before(InboxItemKind::Reply).map(|(p, id)| (p, CommentReplyId(id))),
+      page: None,
+      limit: Some(limit),
+    }
+    .list(pool)
+    .await?;
+
+    let mentions = PersonMentionQuery {
+      my_person_id: Some(self.my_person_id),
+      recipient_id: Some(self.my_person_id),
+      sort: Some(CommentSortType::New),
+      unread_only: self.unread_only,
+      show_bot_accounts: self.show_bot_accounts,
+      before: before(InboxItemKind::Mention).map(|(p, id)| (p, PersonMentionId(id))),
+      page: None,
+      limit: Some(limit),
+    }
+    .list(pool)
+    .await?;
+
+    let messages = PrivateMessageQuery {
+      unread_only: self.unread_only,
+      page: None,
+      limit: Some(limit),
+      creator_id: None,
+      conversation_id: None,
+      before: before(InboxItemKind::PrivateMessage).map(|(p, id)| (p, PrivateMessageId(id))),
+    }
+    .list(pool, self.my_person_id)
+    .await?;
+
+    let mut inbox: Vec<InboxCombinedView> = replies
+      .into_iter()
+      .map(InboxCombinedView::from_reply)
+      .chain(mentions.into_iter().map(InboxCombinedView::PersonMention))
+      .chain(messages.into_iter().map(InboxCombinedView::PrivateMessage))
+      .collect();
+    inbox.sort_by(|a, b| a.position().cmp_position(&b.position()));
+    inbox.truncate(usize::try_from(limit).unwrap_or_default());
+
+    Ok(inbox)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::{InboxCombinedPaginationCursorData, InboxItemKind};
+  use chrono::{DateTime, Utc};
+  use pretty_assertions::assert_eq;
+  use std::cmp::Ordering;
+
+  fn position(published: i64, kind: InboxItemKind, id: i32) -> InboxCombinedPaginationCursorData {
+    InboxCombinedPaginationCursorData {
+      published: DateTime::<Utc>::from_timestamp(published, 0).unwrap_or_default(),
+      kind,
+      id,
+    }
+  }
+
+  #[test]
+  fn test_position_ordering() {
+    let newer = position(20, InboxItemKind::PrivateMessage, 1);
+    let reply = position(10, InboxItemKind::Reply, 5);
+    let mention = position(10, InboxItemKind::Mention, 7);
+    let older_mention = position(10, InboxItemKind::Mention, 6);
+
+    let mut positions = vec![older_mention, reply, newer, mention];
+    positions.sort_by(InboxCombinedPaginationCursorData::cmp_position);
+    assert_eq!(vec![newer, reply, mention, older_mention], positions);
+    assert_eq!(Ordering::Less, reply.cmp_position(&mention));
+  }
+
+  #[test]
+  fn test_before_id() {
+    let cursor = position(10, InboxItemKind::Mention, 7);
+    // All replies published at the same time come before the cursor
+    assert_eq!(i32::MIN, cursor.before_id(InboxItemKind::Reply));
+    assert_eq!(7, cursor.before_id(InboxItemKind::Mention));
+    // All private messages published at the same time come after the cursor
+    assert_eq!(i32::MAX, cursor.before_id(InboxItemKind::PrivateMessage));
+  }
+}
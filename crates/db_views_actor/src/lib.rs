@@ -9,6 +9,8 @@ pub mod community_person_ban_view;
 #[cfg(feature = "full")]
 pub mod community_view;
 #[cfg(feature = "full")]
+pub mod inbox_combined_view;
+#[cfg(feature = "full")]
 pub mod person_mention_view;
 #[cfg(feature = "full")]
 pub mod person_view;
@@ -1,4 +1,5 @@
 use crate::structs::PersonMentionView;
+use chrono::{DateTime, Utc};
 use diesel::{
   dsl::{exists, not},
   pg::Pg,
@@ -25,7 +26,7 @@ use lemmy_db_schema::{
     person_mention,
     post,
   },
-  source::{community::CommunityFollower, local_user::LocalUser},
+  source::{comment::Comment, community::CommunityFollower, local_user::LocalUser, post::Post},
   utils::{
     actions,
     actions_alias,
@@ -126,18 +127,48 @@ fn queries<'a>() -> Queries<
       query = query.filter(person_mention::read.eq(false));
     }
 
+    if let Some((published, id)) = options.before {
+      query = query.filter(
+        comment::published.lt(published).or(
+          comment::published
+            .eq(published)
+            .and(person_mention::id.lt(id)),
+        ),
+      );
+    }
+
     if !options.show_bot_accounts {
       query = query.filter(not(person::bot_account));
     };
 
+    // Mentions of shadow banned persons are only shown to themselves and admins
+    query = query.filter(
+      person::shadow_banned
+        .eq(false)
+        .or(person::id.nullable().eq(options.my_person_id))
+        .or(exists(
+          local_user::table.filter(
+            local_user::person_id
+              .nullable()
+              .eq(options.my_person_id)
+              .and(local_user::admin.eq(true)),
+          ),
+        )),
+    );
+
     query = match options.sort.unwrap_or(CommentSortType::Hot) {
       CommentSortType::Hot => query.then_order_by(comment_aggregates::hot_rank.desc()),
       CommentSortType::Controversial => {
         query.then_order_by(comment_aggregates::controversy_rank.desc())
       }
-      CommentSortType::New => query.then_order_by(comment::published.desc()),
+      CommentSortType::New => query
+        .then_order_by(comment::published.desc())
+        .then_order_by(person_mention::id.desc()),
       CommentSortType::Old => query.then_order_by(comment::published.asc()),
-      CommentSortType::Top => query.order_by(comment_aggregates::score.desc()),
+      // Mentions and replies are listed without their threads, so sort them like Top
+      CommentSortType::Top | CommentSortType::QandA => {
+        query.order_by(comment_aggregates::score.desc())
+      }
     };
 
     // Don't show mentions from blocked persons
@@ -161,9 +192,12 @@ impl PersonMentionView {
     person_mention_id: PersonMentionId,
     my_person_id: Option<PersonId>,
   ) -> Result<Self, Error> {
-    queries()
+    let mut res = queries()
       .read(pool, (person_mention_id, my_person_id))
-      .await
+      .await?;
+    Comment::restore_archived(pool, [&mut res.comment]).await?;
+    Post::restore_archived(pool, [&mut res.post]).await?;
+    Ok(res)
   }
 
   /// Gets the number of unread mentions
@@ -189,6 +223,10 @@ impl PersonMentionView {
       query = query.filter(not(person::bot_account));
     }
 
+    if !local_user.admin {
+      query = query.filter(person::shadow_banned.eq(false));
+    }
+
     query
       // Don't count replies from blocked users
       .filter(person_actions::blocked.is_null())
@@ -209,13 +247,19 @@ pub struct PersonMentionQuery {
   pub sort: Option<CommentSortType>,
   pub unread_only: bool,
   pub show_bot_accounts: bool,
+  /// Only mentions in comments which were published before this time, or at the same time with a
+  /// lower mention id. Used for cursor pagination with the `New` sort.
+  pub before: Option<(DateTime<Utc>, PersonMentionId)>,
   pub page: Option<i64>,
   pub limit: Option<i64>,
 }
 
 impl PersonMentionQuery {
   pub async fn list(self, pool: &mut DbPool<'_>) -> Result<Vec<PersonMentionView>, Error> {
-    queries().list(pool, self).await
+    let mut mentions = queries().list(pool, self).await?;
+    Comment::restore_archived(pool, mentions.iter_mut().map(|m| &mut m.comment)).await?;
+    Post::restore_archived(pool, mentions.iter_mut().map(|m| &mut m.post)).await?;
+    Ok(mentions)
   }
 }
 
@@ -316,6 +360,7 @@ mod tests {
       sort: None,
       unread_only: false,
       show_bot_accounts: true,
+      before: None,
       page: None,
       limit: None,
     };
@@ -12,6 +12,7 @@ use lemmy_db_schema::{
   },
   SubscribedType,
 };
+use lemmy_db_views::structs::PrivateMessageView;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
@@ -57,6 +58,8 @@ pub struct CommunityView {
   pub blocked: bool,
   pub counts: CommunityAggregates,
   pub banned_from_community: bool,
+  /// Whether you asked not to have this community recommended.
+  pub suppressed_recommendation: bool,
 }
 
 /// The community sort types. See here for descriptions: https://join-lemmy.org/docs/en/users/03-votes-and-ranking.html
@@ -138,6 +141,31 @@ pub struct CommentReplyView {
   pub my_vote: Option<i16>,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Replies to the same post or comment, grouped into a single notification.
+pub struct CommentReplyGroupView {
+  /// The latest reply of the group.
+  pub latest: CommentReplyView,
+  pub count: i64,
+  pub unread_count: i64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+#[serde(tag = "type_")]
+/// An item in the combined inbox of a user.
+pub enum InboxCombinedView {
+  /// A reply to one of the user's comments.
+  CommentReply(CommentReplyView),
+  /// A top-level comment on one of the user's posts.
+  PostReply(CommentReplyView),
+  PersonMention(PersonMentionView),
+  PrivateMessage(PrivateMessageView),
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS, Queryable))]
 #[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
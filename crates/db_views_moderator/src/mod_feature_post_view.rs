@@ -23,9 +23,11 @@ impl ModFeaturePostView {
     let show_mod_names = !params.hide_modlog_names;
     let show_mod_names_expr = show_mod_names.as_sql::<diesel::sql_types::Bool>();
 
+    // Actions of community bots are attributed to the community, without showing a moderator
     let admin_names_join = mod_feature_post::mod_person_id
       .eq(person::id)
-      .and(show_mod_names_expr.or(person::id.eq(admin_person_id_join)));
+      .and(show_mod_names_expr.or(person::id.eq(admin_person_id_join)))
+      .and(mod_feature_post::automod.eq(false));
     let mut query = mod_feature_post::table
       .left_join(person::table.on(admin_names_join))
       .inner_join(post::table)
@@ -44,7 +46,9 @@ impl ModFeaturePostView {
     };
 
     if let Some(mod_person_id) = params.mod_person_id {
-      query = query.filter(mod_feature_post::mod_person_id.eq(mod_person_id));
+      query = query
+        .filter(mod_feature_post::mod_person_id.eq(mod_person_id))
+        .filter(mod_feature_post::automod.eq(false));
     };
 
     if let Some(other_person_id) = params.other_person_id {
@@ -23,9 +23,11 @@ impl ModRemoveCommentView {
     let show_mod_names = !params.hide_modlog_names;
     let show_mod_names_expr = show_mod_names.as_sql::<diesel::sql_types::Bool>();
 
+    // Actions of community bots are attributed to the community, without showing a moderator
     let admin_names_join = mod_remove_comment::mod_person_id
       .eq(person::id)
-      .and(show_mod_names_expr.or(person::id.eq(admin_person_id_join)));
+      .and(show_mod_names_expr.or(person::id.eq(admin_person_id_join)))
+      .and(mod_remove_comment::automod.eq(false));
     let mut query = mod_remove_comment::table
       .left_join(person::table.on(admin_names_join))
       .inner_join(comment::table)
@@ -47,7 +49,9 @@ impl ModRemoveCommentView {
     };
 
     if let Some(mod_person_id) = params.mod_person_id {
-      query = query.filter(mod_remove_comment::mod_person_id.eq(mod_person_id));
+      query = query
+        .filter(mod_remove_comment::mod_person_id.eq(mod_person_id))
+        .filter(mod_remove_comment::automod.eq(false));
     };
 
     if let Some(other_person_id) = params.other_person_id {
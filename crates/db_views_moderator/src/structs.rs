@@ -20,7 +20,9 @@ use lemmy_db_schema::{
       ModRemoveComment,
       ModRemoveCommunity,
       ModRemovePost,
+      ModResolveBanAppeal,
       ModTransferCommunity,
+      ModWarnPerson,
     },
     person::Person,
     post::Post,
@@ -183,6 +185,35 @@ pub struct ModTransferCommunityView {
   pub modded_person: Person,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When an appeal against a community ban is accepted or denied.
+pub struct ModResolveBanAppealView {
+  pub mod_resolve_ban_appeal: ModResolveBanAppeal,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub moderator: Option<Person>,
+  pub community: Community,
+  pub appellant: Person,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When someone is warned.
+pub struct ModWarnPersonView {
+  pub mod_warn_person: ModWarnPerson,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub moderator: Option<Person>,
+  #[cfg_attr(feature = "full", ts(optional))]
+  pub community: Option<Community>,
+  pub warned_person: Person,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS, Queryable))]
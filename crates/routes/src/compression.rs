@@ -0,0 +1,137 @@
+use actix_web::{
+  body::{BodySize, MessageBody},
+  dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+  http::header::{HeaderMap, HeaderValue, CONTENT_ENCODING, CONTENT_TYPE},
+  middleware::{Compress, Condition},
+  Error,
+};
+use futures::future::LocalBoxFuture;
+use lemmy_utils::settings::structs::CompressionConfig;
+use std::{
+  future::{ready, Ready},
+  rc::Rc,
+};
+
+/// Content types which are already compressed, so compressing them again only wastes cpu time.
+const PRECOMPRESSED_CONTENT_TYPES: [&str; 5] = [
+  "image/",
+  "video/",
+  "audio/",
+  "application/zip",
+  "application/gzip",
+];
+
+/// Returns the compression middleware. Actix negotiates the best encoding supported by the client
+/// via `Accept-Encoding`, preferring zstd and brotli over gzip.
+///
+/// Needs to be wrapped outside of [CompressionFilter].
+pub fn compress(config: &CompressionConfig) -> Condition<Compress> {
+  Condition::new(config.enabled, Compress::default())
+}
+
+/// Marks responses which are not worth compressing with `Content-Encoding: identity`, so that the
+/// [Compress] middleware leaves them alone.
+#[derive(Clone)]
+pub struct CompressionFilter {
+  min_size: u64,
+}
+
+impl CompressionFilter {
+  pub fn new(config: &CompressionConfig) -> Self {
+    CompressionFilter {
+      min_size: config.min_size,
+    }
+  }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for CompressionFilter
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+  S::Future: 'static,
+  B: MessageBody + 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = Error;
+  type Transform = CompressionFilterService<S>;
+  type InitError = ();
+  type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+  fn new_transform(&self, service: S) -> Self::Future {
+    ready(Ok(CompressionFilterService {
+      service: Rc::new(service),
+      min_size: self.min_size,
+    }))
+  }
+}
+
+pub struct CompressionFilterService<S> {
+  service: Rc<S>,
+  min_size: u64,
+}
+
+impl<S, B> Service<ServiceRequest> for CompressionFilterService<S>
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+  S::Future: 'static,
+  B: MessageBody + 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = Error;
+  type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+  forward_ready!(service);
+
+  fn call(&self, req: ServiceRequest) -> Self::Future {
+    let svc = self.service.clone();
+    let min_size = self.min_size;
+
+    Box::pin(async move {
+      let mut res = svc.call(req).await?;
+
+      let body_size = res.response().body().size();
+      if !should_compress(res.headers(), body_size, min_size) {
+        res
+          .headers_mut()
+          .insert(CONTENT_ENCODING, HeaderValue::from_static("identity"));
+      }
+      Ok(res)
+    })
+  }
+}
+
+fn should_compress(headers: &HeaderMap, body_size: BodySize, min_size: u64) -> bool {
+  // Already encoded, the compress middleware will skip it anyway
+  if headers.contains_key(CONTENT_ENCODING) {
+    return true;
+  }
+  if let BodySize::Sized(size) = body_size {
+    if size < min_size {
+      return false;
+    }
+  }
+  let content_type = headers
+    .get(CONTENT_TYPE)
+    .and_then(|c| c.to_str().ok())
+    .unwrap_or_default();
+  !PRECOMPRESSED_CONTENT_TYPES
+    .iter()
+    .any(|t| content_type.starts_with(t))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_should_compress() {
+    let mut json = HeaderMap::new();
+    json.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    assert!(should_compress(&json, BodySize::Sized(4096), 1024));
+    assert!(should_compress(&json, BodySize::Stream, 1024));
+    assert!(!should_compress(&json, BodySize::Sized(100), 1024));
+
+    let mut image = HeaderMap::new();
+    image.insert(CONTENT_TYPE, HeaderValue::from_static("image/webp"));
+    assert!(!should_compress(&image, BodySize::Sized(4096), 1024));
+  }
+}
@@ -0,0 +1,308 @@
+use actix_web::{web, HttpResponse};
+use lemmy_api_common::{context::LemmyContext, utils::check_private_instance};
+use lemmy_db_schema::{
+  newtypes::{CommentId, PostId},
+  CommunityVisibility,
+};
+use lemmy_db_views::structs::{CommentView, PostView, SiteView};
+use lemmy_utils::{
+  cache_header::cache_1hour,
+  error::{LemmyErrorType, LemmyResult},
+  utils::markdown::{markdown_to_html, sanitize_html},
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Default size of the embedded iframe, if the consumer doesn't specify a maximum.
+const DEFAULT_EMBED_WIDTH: i32 = 600;
+const DEFAULT_EMBED_HEIGHT: i32 = 400;
+
+/// Implements an oEmbed provider, so that other sites can embed posts and comments.
+/// https://oembed.com/
+pub fn config(cfg: &mut web::ServiceConfig) {
+  cfg
+    .route("/oembed", web::get().to(oembed).wrap(cache_1hour()))
+    .service(
+      web::scope("/embed")
+        .route("/post/{id}", web::get().to(embed_post).wrap(cache_1hour()))
+        .route(
+          "/comment/{id}",
+          web::get().to(embed_comment).wrap(cache_1hour()),
+        ),
+    );
+}
+
+#[derive(Deserialize)]
+struct OEmbedParams {
+  url: Url,
+  maxwidth: Option<i32>,
+  maxheight: Option<i32>,
+  format: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+struct OEmbedResponse {
+  #[serde(rename = "type")]
+  kind: &'static str,
+  version: &'static str,
+  title: String,
+  author_name: String,
+  author_url: String,
+  provider_name: String,
+  provider_url: String,
+  html: String,
+  width: i32,
+  height: i32,
+}
+
+/// The content which a url on this instance points to.
+#[derive(Debug, PartialEq)]
+enum EmbedTarget {
+  Post(PostId),
+  Comment(CommentId),
+}
+
+/// Parses urls like `/post/123`, `/comment/456` or `/post/123/456` (a comment in the context of its
+/// post). Urls belonging to other instances are rejected.
+fn parse_embed_url(url: &Url, hostname: &str) -> Option<EmbedTarget> {
+  let host = match url.port() {
+    Some(port) => format!("{}:{port}", url.host_str()?),
+    None => url.host_str()?.to_string(),
+  };
+  if host != hostname {
+    return None;
+  }
+  let segments: Vec<&str> = url.path_segments()?.filter(|s| !s.is_empty()).collect();
+  match segments.as_slice() {
+    ["post", post_id] => Some(EmbedTarget::Post(PostId(post_id.parse().ok()?))),
+    ["post", _, comment_id] | ["comment", comment_id] => {
+      Some(EmbedTarget::Comment(CommentId(comment_id.parse().ok()?)))
+    }
+    _ => None,
+  }
+}
+
+async fn oembed(
+  info: web::Query<OEmbedParams>,
+  context: web::Data<LemmyContext>,
+) -> LemmyResult<HttpResponse> {
+  // Only json is supported, the spec requires 501 for other formats
+  if info.format.as_deref().is_some_and(|f| f != "json") {
+    return Ok(HttpResponse::NotImplemented().finish());
+  }
+  let settings = context.settings();
+  let protocol_and_hostname = settings.get_protocol_and_hostname();
+  let target = parse_embed_url(&info.url, &settings.hostname).ok_or(LemmyErrorType::NotFound)?;
+
+  let (title, creator, embed_path) = match target {
+    EmbedTarget::Post(post_id) => {
+      let post_view = read_embeddable_post(&context, post_id).await?;
+      (
+        post_view.post.name,
+        post_view.creator,
+        format!("post/{post_id}"),
+      )
+    }
+    EmbedTarget::Comment(comment_id) => {
+      let comment_view = read_embeddable_comment(&context, comment_id).await?;
+      (
+        format!("Comment on {}", comment_view.post.name),
+        comment_view.creator,
+        format!("comment/{comment_id}"),
+      )
+    }
+  };
+
+  let width = info
+    .maxwidth
+    .unwrap_or(DEFAULT_EMBED_WIDTH)
+    .min(DEFAULT_EMBED_WIDTH);
+  let height = info
+    .maxheight
+    .unwrap_or(DEFAULT_EMBED_HEIGHT)
+    .min(DEFAULT_EMBED_HEIGHT);
+  let html = format!(
+    "<iframe src=\"{protocol_and_hostname}/embed/{embed_path}\" width=\"{width}\" height=\"{height}\" frameborder=\"0\" sandbox=\"allow-popups allow-popups-to-escape-sandbox\"></iframe>"
+  );
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+
+  Ok(HttpResponse::Ok().json(OEmbedResponse {
+    kind: "rich",
+    version: "1.0",
+    title,
+    author_name: creator.name,
+    author_url: creator.actor_id.to_string(),
+    provider_name: site_view.site.name,
+    provider_url: protocol_and_hostname,
+    html,
+    width,
+    height,
+  }))
+}
+
+async fn embed_post(
+  post_id: web::Path<PostId>,
+  context: web::Data<LemmyContext>,
+) -> LemmyResult<HttpResponse> {
+  let post_view = read_embeddable_post(&context, post_id.into_inner()).await?;
+  let protocol_and_hostname = context.settings().get_protocol_and_hostname();
+  let link = format!("{protocol_and_hostname}/post/{}", post_view.post.id);
+
+  let mut content = format!(
+    "<h1><a href=\"{link}\" target=\"_blank\">{}</a></h1>",
+    sanitize_html(&post_view.post.name)
+  );
+  if let Some(url) = &post_view.post.url {
+    let url = sanitize_html(url.as_str());
+    content.push_str(&format!(
+      "<p><a href=\"{url}\" target=\"_blank\">{url}</a></p>"
+    ));
+  }
+  if let Some(body) = &post_view.post.body {
+    content.push_str(&markdown_to_html(body));
+  }
+  let footer = format!(
+    "{} points · {} comments",
+    post_view.counts.score, post_view.counts.comments
+  );
+
+  Ok(embed_html_response(
+    &post_view.post.name,
+    &post_view.creator.name,
+    &post_view.community.name,
+    &content,
+    &footer,
+    &link,
+  ))
+}
+
+async fn embed_comment(
+  comment_id: web::Path<CommentId>,
+  context: web::Data<LemmyContext>,
+) -> LemmyResult<HttpResponse> {
+  let comment_view = read_embeddable_comment(&context, comment_id.into_inner()).await?;
+  let protocol_and_hostname = context.settings().get_protocol_and_hostname();
+  let link = format!(
+    "{protocol_and_hostname}/comment/{}",
+    comment_view.comment.id
+  );
+
+  let content = markdown_to_html(&comment_view.comment.content);
+  let footer = format!(
+    "{} points · in <a href=\"{protocol_and_hostname}/post/{}\" target=\"_blank\">{}</a>",
+    comment_view.counts.score,
+    comment_view.post.id,
+    sanitize_html(&comment_view.post.name)
+  );
+
+  Ok(embed_html_response(
+    &comment_view.post.name,
+    &comment_view.creator.name,
+    &comment_view.community.name,
+    &content,
+    &footer,
+    &link,
+  ))
+}
+
+/// Only public, non-deleted content may be embedded, and never on private instances.
+async fn read_embeddable_post(context: &LemmyContext, post_id: PostId) -> LemmyResult<PostView> {
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+  check_private_instance(&None, &site_view.local_site)?;
+
+  let post_view = PostView::read(&mut context.pool(), post_id, None, false).await?;
+  if post_view.post.deleted
+    || post_view.post.removed
+    || post_view.community.visibility != CommunityVisibility::Public
+  {
+    Err(LemmyErrorType::NotFound)?
+  }
+  Ok(post_view)
+}
+
+async fn read_embeddable_comment(
+  context: &LemmyContext,
+  comment_id: CommentId,
+) -> LemmyResult<CommentView> {
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+  check_private_instance(&None, &site_view.local_site)?;
+
+  let comment_view = CommentView::read(&mut context.pool(), comment_id, None).await?;
+  if comment_view.comment.deleted
+    || comment_view.comment.removed
+    || comment_view.post.deleted
+    || comment_view.post.removed
+    || comment_view.community.visibility != CommunityVisibility::Public
+  {
+    Err(LemmyErrorType::NotFound)?
+  }
+  Ok(comment_view)
+}
+
+/// A minimal, self-contained html page which is shown inside the iframe.
+fn embed_html_response(
+  title: &str,
+  creator_name: &str,
+  community_name: &str,
+  content: &str,
+  footer: &str,
+  link: &str,
+) -> HttpResponse {
+  let html = format!(
+    r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title}</title>
+<style>
+body {{ font-family: sans-serif; margin: 0.5em; overflow-wrap: anywhere; }}
+header, footer {{ color: #777; font-size: 0.85em; }}
+img {{ max-width: 100%; }}
+</style>
+</head>
+<body>
+<header>{creator_name} in {community_name}</header>
+<main>{content}</main>
+<footer>{footer} · <a href="{link}" target="_blank">View on {link}</a></footer>
+</body>
+</html>"#,
+    title = sanitize_html(title),
+    creator_name = sanitize_html(creator_name),
+    community_name = sanitize_html(community_name),
+  );
+  HttpResponse::Ok()
+    .content_type("text/html; charset=utf-8")
+    .body(html)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_parse_embed_url() -> LemmyResult<()> {
+    let hostname = "lemmy.example";
+    let parse = |url: &str| -> LemmyResult<Option<EmbedTarget>> {
+      Ok(parse_embed_url(&Url::parse(url)?, hostname))
+    };
+
+    assert_eq!(
+      Some(EmbedTarget::Post(PostId(12))),
+      parse("https://lemmy.example/post/12")?
+    );
+    assert_eq!(
+      Some(EmbedTarget::Comment(CommentId(34))),
+      parse("https://lemmy.example/comment/34")?
+    );
+    assert_eq!(
+      Some(EmbedTarget::Comment(CommentId(34))),
+      parse("https://lemmy.example/post/12/34")?
+    );
+    assert_eq!(None, parse("https://other.example/post/12")?);
+    assert_eq!(None, parse("https://lemmy.example/c/test")?);
+    assert_eq!(None, parse("https://lemmy.example/post/abc")?);
+    Ok(())
+  }
+}
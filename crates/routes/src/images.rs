@@ -11,13 +11,16 @@ use actix_web::{
 };
 use futures::stream::{Stream, StreamExt};
 use http::HeaderValue;
-use lemmy_api_common::{context::LemmyContext, request::PictrsResponse};
+use lemmy_api_common::{
+  context::LemmyContext,
+  request::{recognize_image_text, PictrsResponse},
+};
 use lemmy_db_schema::source::{
   images::{LocalImage, LocalImageForm, RemoteImage},
   local_site::LocalSite,
 };
 use lemmy_db_views::structs::LocalUserView;
-use lemmy_utils::{error::LemmyResult, rate_limit::RateLimitCell, REQWEST_TIMEOUT};
+use lemmy_utils::{error::LemmyResult, rate_limit::RateLimitCell, spawn_try_task, REQWEST_TIMEOUT};
 use reqwest::Body;
 use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
 use serde::Deserialize;
@@ -165,6 +168,15 @@ async fn upload(
       // Also store the details for the image
       let details_form = image.details.build_image_details_form(&thumbnail_url);
       LocalImage::create(&mut context.pool(), &form, &details_form).await?;
+
+      // Recognize text in the background, so that the upload isn't delayed
+      if pictrs_config.ocr_url.is_some() && local_user_view.local_user.image_text_recognition {
+        let context = context.get_ref().clone();
+        let pictrs_alias = image.file.clone();
+        spawn_try_task(
+          async move { recognize_image_text(pictrs_alias, thumbnail_url, &context).await },
+        );
+      }
     }
   }
 
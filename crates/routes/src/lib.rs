@@ -1,7 +1,9 @@
-use lemmy_api_common::{claims::Claims, context::LemmyContext, utils::check_user_valid};
+use lemmy_api_common::{claims::Claims, context::LemmyContext, utils::check_local_user_valid};
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::error::LemmyResult;
 
+pub mod compression;
+pub mod embed;
 pub mod feeds;
 pub mod images;
 pub mod nodeinfo;
@@ -11,7 +13,7 @@ pub mod webfinger;
 async fn local_user_view_from_jwt(jwt: &str, context: &LemmyContext) -> LemmyResult<LocalUserView> {
   let local_user_id = Claims::validate(jwt, context).await?;
   let local_user_view = LocalUserView::read(&mut context.pool(), local_user_id).await?;
-  check_user_valid(&local_user_view.person)?;
+  check_local_user_valid(&local_user_view)?;
 
   Ok(local_user_view)
 }
@@ -0,0 +1,70 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use std::{
+  fmt::Debug,
+  sync::{Arc, Mutex},
+};
+
+/// Source of the current time for time dependent logic like hot ranks, ban expiry, scheduled
+/// posts and rate limits. Production code uses [SystemClock], tests can use [FakeClock] to move
+/// forward in time without waiting.
+pub trait Clock: Debug + Send + Sync {
+  fn now(&self) -> DateTime<Utc>;
+}
+
+pub type SharedClock = Arc<dyn Clock>;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+  fn now(&self) -> DateTime<Utc> {
+    Utc::now()
+  }
+}
+
+/// Clock which only moves when it is told to.
+#[derive(Debug)]
+pub struct FakeClock {
+  now: Mutex<DateTime<Utc>>,
+}
+
+impl FakeClock {
+  pub fn new(now: DateTime<Utc>) -> Self {
+    FakeClock {
+      now: Mutex::new(now),
+    }
+  }
+
+  pub fn set(&self, now: DateTime<Utc>) {
+    *self.now.lock().expect("lock fake clock") = now;
+  }
+
+  pub fn advance(&self, delta: TimeDelta) {
+    *self.now.lock().expect("lock fake clock") += delta;
+  }
+}
+
+impl Clock for FakeClock {
+  fn now(&self) -> DateTime<Utc> {
+    *self.now.lock().expect("lock fake clock")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_fake_clock() {
+    let start = Utc::now();
+    let clock = FakeClock::new(start);
+    assert_eq!(start, clock.now());
+
+    clock.advance(TimeDelta::hours(2));
+    assert_eq!(start + TimeDelta::hours(2), clock.now());
+
+    clock.set(start);
+    assert_eq!(start, clock.now());
+  }
+}
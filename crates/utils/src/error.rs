@@ -66,7 +66,16 @@ pub enum LemmyErrorType {
   RegistrationUsernameRequired,
   EmailAlreadyExists,
   UsernameAlreadyExists,
-  PersonIsBannedFromCommunity,
+  PersonIsBannedFromCommunity {
+    #[cfg_attr(feature = "full", ts(optional))]
+    reason: Option<String>,
+    /// Where or how the ban can be appealed.
+    #[cfg_attr(feature = "full", ts(optional))]
+    appeal: Option<String>,
+    /// When the ban expires, in unix epoch seconds.
+    #[cfg_attr(feature = "full", ts(optional))]
+    expires: Option<i64>,
+  },
   NoIdGiven,
   IncorrectLogin,
   ObjectNotLocal,
@@ -103,6 +112,7 @@ pub enum LemmyErrorType {
   CouldntSavePost,
   CouldntMarkPostAsRead,
   CouldntHidePost,
+  CouldntSuppressCommunityRecommendation,
   CouldntUpdateCommunity,
   CouldntUpdateReplies,
   CouldntUpdatePersonMentions,
@@ -146,10 +156,97 @@ pub enum LemmyErrorType {
   OauthLoginFailed,
   OauthRegistrationClosed,
   CouldntDeleteOauthProvider,
+  OauthAccountAlreadyLinked,
+  CantUnlinkLastLoginMethod,
+  InvalidLdapSettings,
+  MissingCommunityPermission,
+  CantAssignCommunityRole,
+  PostIsPending,
+  InvalidDomain,
+  DomainBlockedByCommunity,
+  NoModNoteEditAllowed,
   NotFound,
   CommunityHasNoFollowers,
   PostScheduleTimeMustBeInFuture,
   TooManyScheduledPosts,
+  InvalidHomeDiscoveryPercent,
+  InvalidTimezone,
+  InvalidInterestCategoryName,
+  AccountsNotLinked,
+  CantLinkAccountToItself,
+  PersonIsNotABot,
+  CouldntCreateCommunityBotDelegation,
+  SpamScoreAlreadyApproved,
+  AccountFrozen,
+  CouldntCreateBanList,
+  CouldntUpdateBanList,
+  InvalidBanListName,
+  CouldntCreateCommunityMirror,
+  MirrorPartnerMustBeRemote,
+  MaintenanceMode,
+  CommunityNameReserved,
+  ClientBlocked,
+  InvalidClientRestriction,
+  FeatureDisabled,
+  InvalidRolloutPercentage,
+  ReputationTooLow,
+  TooManyProfilePins,
+  CommunityRequestAlreadyExists,
+  CommunityRequestAlreadyHandled,
+  BanAppealAlreadyExists,
+  BanAppealAlreadyHandled,
+  NotBannedFromCommunity,
+  InvalidReportReasonCategoryName,
+  NoReportNoteEditAllowed,
+  ReportAlreadyAssigned,
+  InvalidPollOptions,
+  InvalidPollVote,
+  PollEnded,
+  TooManyGalleryImages,
+  InvalidPostTag,
+  InvalidKeywordBlock,
+  TooManyKeywordBlocks,
+  PostTagAlreadyExists,
+  ContentRejectedByFilter,
+  InvalidFilterRule,
+  InvalidFilterRuleCondition(String),
+  FilterRuleAlreadyExists,
+  InvalidPasskey,
+  PasskeyAlreadyExists,
+  InvalidPasskeyName,
+  RegistrationThrottled,
+  UnknownRegistrationQuestion,
+  EmailDomainNotAllowed,
+  /// The account needs to be older for this action.
+  AccountTooNew {
+    min_age_days: i32,
+  },
+  /// The account needs to have written more comments for this action.
+  TooFewComments {
+    min_comments: i32,
+  },
+  /// The account needs a verified email address for this action.
+  VerifiedEmailRequired,
+  /// A private message needs either a recipient or a group conversation.
+  InvalidPrivateMessageTarget,
+  NotPmConversationParticipant,
+  PmConversationNotAGroup,
+  PmConversationNotARequest,
+  TooManyPmConversationParticipants,
+  /// The recipient doesn't accept private messages from you.
+  PrivateMessagesNotAllowed,
+  CantFollowYourself,
+  /// You already have a custom feed with this name, or another feed has this slug.
+  CustomFeedAlreadyExists,
+  NotCustomFeedCreator,
+  TooManyCustomFeedCommunities,
+  /// Only top level comments can be pinned.
+  CantPinReply,
+  VoteAnomalyAlreadyReviewed,
+  DeniedByHook {
+    #[cfg_attr(feature = "full", ts(optional))]
+    reason: Option<String>,
+  },
   FederationError {
     #[cfg_attr(feature = "full", ts(optional))]
     error: Option<FederationError>,
@@ -185,12 +282,13 @@ pub enum FederationError {
   CantDeleteSite,
   ObjectIsNotPublic,
   ObjectIsNotPrivate,
+  ActivityActorMismatch,
 }
 
 cfg_if! {
   if #[cfg(feature = "full")] {
 
-    use std::fmt;
+    use std::{collections::HashMap, fmt, sync::LazyLock};
     pub type LemmyResult<T> = Result<T, LemmyError>;
 
     pub struct LemmyError {
@@ -243,6 +341,12 @@ cfg_if! {
         if self.error_type == LemmyErrorType::IncorrectLogin {
           return actix_web::http::StatusCode::UNAUTHORIZED;
         }
+        if self.error_type == LemmyErrorType::MaintenanceMode {
+          return actix_web::http::StatusCode::SERVICE_UNAVAILABLE;
+        }
+        if self.error_type == LemmyErrorType::ClientBlocked {
+          return actix_web::http::StatusCode::FORBIDDEN;
+        }
         match self.inner.downcast_ref::<diesel::result::Error>() {
           Some(diesel::result::Error::NotFound) => actix_web::http::StatusCode::NOT_FOUND,
           _ => actix_web::http::StatusCode::BAD_REQUEST,
@@ -250,7 +354,14 @@ cfg_if! {
       }
 
       fn error_response(&self) -> actix_web::HttpResponse {
-        actix_web::HttpResponse::build(self.status_code()).json(&self.error_type)
+        let mut res = actix_web::HttpResponse::build(self.status_code());
+        if self.error_type == LemmyErrorType::MaintenanceMode {
+          res.insert_header((
+            actix_web::http::header::RETRY_AFTER,
+            crate::MAINTENANCE_RETRY_AFTER.as_secs(),
+          ));
+        }
+        res.json(&self.error_type)
       }
     }
 
@@ -307,6 +418,57 @@ cfg_if! {
       }
     }
 
+    /// Translated error messages, keyed by language and then by error name.
+    static ERROR_TRANSLATIONS: LazyLock<HashMap<&str, HashMap<String, String>>> =
+      LazyLock::new(|| {
+        [
+          ("de", include_str!("../error_translations/de.json")),
+          ("en", include_str!("../error_translations/en.json")),
+          ("es", include_str!("../error_translations/es.json")),
+          ("fr", include_str!("../error_translations/fr.json")),
+        ]
+        .into_iter()
+        .filter_map(|(lang, json)| Some((lang, serde_json::from_str(json).ok()?)))
+        .collect()
+      });
+
+    impl LemmyErrorType {
+      /// Name of the error as used in the API, eg `email_already_exists`.
+      pub fn name(&self) -> String {
+        serde_json::to_value(self)
+          .ok()
+          .and_then(|v| Some(v.get("error")?.as_str()?.to_string()))
+          .unwrap_or_default()
+      }
+
+      /// Human readable message in the given language. Falls back to English, and to the error
+      /// name for errors which aren't translated at all.
+      pub fn localized_message(&self, lang: &str) -> String {
+        let name = self.name();
+        [lang, "en"]
+          .into_iter()
+          .find_map(|l| ERROR_TRANSLATIONS.get(l)?.get(&name).cloned())
+          .unwrap_or_else(|| {
+            let mut message = name.replace('_', " ");
+            if let Some(first) = message.get_mut(0..1) {
+              first.make_ascii_uppercase();
+            }
+            message
+          })
+      }
+    }
+
+    /// Picks the first of the preferred languages for which error messages are translated.
+    pub fn negotiate_error_language<'a>(
+      preferred: impl IntoIterator<Item = &'a str>,
+    ) -> Option<&'static str> {
+      preferred.into_iter().find_map(|lang| {
+        // Ignore the region code, eg `pt_BR` or `de-AT`
+        let lang = lang.split(['-', '_']).next()?;
+        ERROR_TRANSLATIONS.get_key_value(lang).map(|(l, _)| *l)
+      })
+    }
+
     #[cfg(test)]
     mod tests {
       #![allow(clippy::indexing_slicing)]
@@ -349,6 +511,55 @@ cfg_if! {
         assert_eq!(400, other_error.status_code());
       }
 
+      #[test]
+      fn test_maintenance_mode_response() {
+        let err = LemmyError::from(LemmyErrorType::MaintenanceMode);
+        let res = err.error_response();
+        assert_eq!(503, res.status());
+        assert_eq!(
+          Some("300"),
+          res
+            .headers()
+            .get(actix_web::http::header::RETRY_AFTER)
+            .and_then(|h| h.to_str().ok())
+        );
+      }
+
+      #[test]
+      fn test_localized_message() {
+        assert_eq!(
+          "Dieser Benutzername ist bereits vergeben.",
+          LemmyErrorType::UsernameAlreadyExists.localized_message("de")
+        );
+        // Untranslated languages and errors fall back to English
+        assert_eq!(
+          "This username is already taken.",
+          LemmyErrorType::UsernameAlreadyExists.localized_message("xx")
+        );
+        assert_eq!(
+          "Couldnt update post",
+          LemmyErrorType::CouldntUpdatePost.localized_message("fr")
+        );
+      }
+
+      #[test]
+      fn test_error_translations_are_valid() {
+        let names: Vec<_> = LemmyErrorType::iter().map(|e| e.name()).collect();
+        for (lang, messages) in ERROR_TRANSLATIONS.iter() {
+          for name in messages.keys() {
+            assert!(names.contains(name), "{lang}: {name}");
+          }
+        }
+        assert_eq!(4, ERROR_TRANSLATIONS.len());
+      }
+
+      #[test]
+      fn test_negotiate_error_language() {
+        assert_eq!(Some("de"), negotiate_error_language(["xx", "de-AT", "en"]));
+        assert_eq!(Some("es"), negotiate_error_language(["es_MX"]));
+        assert_eq!(None, negotiate_error_language(["browser"]));
+      }
+
       /// Check if errors match translations. Disabled because many are not translated at all.
       #[test]
       #[ignore]
@@ -3,6 +3,7 @@ use cfg_if::cfg_if;
 cfg_if! {
   if #[cfg(feature = "full")] {
     pub mod cache_header;
+    pub mod clock;
     pub mod email;
     pub mod rate_limit;
     pub mod request;
@@ -30,6 +31,12 @@ pub const CACHE_DURATION_API: Duration = Duration::from_secs(1);
 
 pub const MAX_COMMENT_DEPTH_LIMIT: usize = 50;
 
+/// Maximum number of participants in a group conversation of private messages.
+pub const MAX_PM_GROUP_PARTICIPANTS: usize = 50;
+
+/// Value of the `Retry-After` header which is sent while the site is in maintenance mode.
+pub const MAINTENANCE_RETRY_AFTER: Duration = Duration::from_secs(300);
+
 #[macro_export]
 macro_rules! location_info {
   () => {
@@ -1,5 +1,9 @@
-use crate::error::{LemmyError, LemmyErrorType};
+use crate::{
+  clock::{Clock, SharedClock, SystemClock},
+  error::{LemmyError, LemmyErrorType},
+};
 use actix_web::dev::{ConnectionInfo, Service, ServiceRequest, ServiceResponse, Transform};
+use chrono::{DateTime, Utc};
 use enum_map::{enum_map, EnumMap};
 use futures::future::{ok, Ready};
 pub use rate_limiter::{ActionType, BucketConfig};
@@ -20,20 +24,47 @@ pub mod rate_limiter;
 #[derive(Debug, Clone)]
 pub struct RateLimitChecker {
   state: Arc<Mutex<RateLimitState>>,
+  clock: RateLimitClock,
   action_type: ActionType,
 }
 
+/// Converts the time of a [Clock] into the seconds since startup used by the rate limiter.
+#[derive(Debug, Clone)]
+struct RateLimitClock {
+  clock: SharedClock,
+  start: DateTime<Utc>,
+}
+
+impl RateLimitClock {
+  fn now(&self) -> InstantSecs {
+    InstantSecs::elapsed(self.start, self.clock.now())
+  }
+}
+
 /// Single instance of rate limit config and buckets, which is shared across all threads.
 #[derive(Clone)]
 pub struct RateLimitCell {
   state: Arc<Mutex<RateLimitState>>,
+  clock: RateLimitClock,
 }
 
 impl RateLimitCell {
   pub fn new(rate_limit_config: EnumMap<ActionType, BucketConfig>) -> Self {
+    Self::with_clock(rate_limit_config, Arc::new(SystemClock))
+  }
+
+  pub fn with_clock(
+    rate_limit_config: EnumMap<ActionType, BucketConfig>,
+    clock: SharedClock,
+  ) -> Self {
     let state = Arc::new(Mutex::new(RateLimitState::new(rate_limit_config)));
+    let clock = RateLimitClock {
+      start: clock.now(),
+      clock,
+    };
 
     let state_weak_ref = Arc::downgrade(&state);
+    let task_clock = clock.clone();
 
     tokio::spawn(async move {
       let interval = Duration::from_secs(120);
@@ -44,11 +75,16 @@ impl RateLimitCell {
         state
           .lock()
           .expect("Failed to lock rate limit mutex for reading")
-          .remove_full_buckets(InstantSecs::now());
+          .remove_full_buckets(task_clock.now());
       }
     });
 
-    RateLimitCell { state }
+    RateLimitCell { state, clock }
+  }
+
+  /// The clock which is used to refill the buckets.
+  pub fn clock(&self) -> &SharedClock {
+    &self.clock.clock
   }
 
   pub fn set_config(&self, config: EnumMap<ActionType, BucketConfig>) {
@@ -90,12 +126,17 @@ impl RateLimitCell {
   fn new_checker(&self, action_type: ActionType) -> RateLimitChecker {
     RateLimitChecker {
       state: self.state.clone(),
+      clock: self.clock.clone(),
       action_type,
     }
   }
 
   pub fn with_test_config() -> Self {
-    Self::new(enum_map! {
+    Self::with_test_config_and_clock(Arc::new(SystemClock))
+  }
+
+  pub fn with_test_config_and_clock(clock: SharedClock) -> Self {
+    let config = enum_map! {
       ActionType::Message => BucketConfig {
         capacity: 180,
         secs_to_refill: 60,
@@ -124,7 +165,8 @@ impl RateLimitCell {
         capacity: 1,
         secs_to_refill: 24 * 60 * 60,
       },
-    })
+    };
+    Self::with_clock(config, clock)
   }
 }
 
@@ -143,7 +185,7 @@ impl RateLimitChecker {
       .lock()
       .expect("Failed to lock rate limit mutex for reading");
 
-    state.check(self.action_type, ip_addr, InstantSecs::now())
+    state.check(self.action_type, ip_addr, self.clock.now())
   }
 }
 
@@ -201,7 +243,8 @@ where
   }
 }
 
-fn get_ip(conn_info: &ConnectionInfo) -> IpAddr {
+/// The IP address of the client, taking reverse proxy headers into account.
+pub fn get_ip(conn_info: &ConnectionInfo) -> IpAddr {
   conn_info
     .realip_remote_addr()
     .and_then(parse_ip)
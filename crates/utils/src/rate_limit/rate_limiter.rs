@@ -1,16 +1,13 @@
+use chrono::{DateTime, Utc};
 use enum_map::EnumMap;
 use std::{
   collections::HashMap,
   hash::Hash,
   net::{IpAddr, Ipv4Addr, Ipv6Addr},
-  sync::LazyLock,
-  time::Instant,
 };
 use strum::{AsRefStr, Display};
 use tracing::debug;
 
-static START_TIME: LazyLock<Instant> = LazyLock::new(Instant::now);
-
 /// Smaller than `std::time::Instant` because it uses a smaller integer for seconds and doesn't
 /// store nanoseconds
 #[derive(PartialEq, Debug, Clone, Copy)]
@@ -19,9 +16,10 @@ pub struct InstantSecs {
 }
 
 impl InstantSecs {
-  pub fn now() -> Self {
+  /// Seconds which passed from `start` until `now`.
+  pub fn elapsed(start: DateTime<Utc>, now: DateTime<Utc>) -> Self {
     InstantSecs {
-      secs: u32::try_from(START_TIME.elapsed().as_secs())
+      secs: u32::try_from((now - start).num_seconds().max(0))
         .expect("server has been running for over 136 years"),
     }
   }
@@ -338,7 +336,7 @@ mod tests {
       },
     };
     let mut rate_limiter = RateLimitState::new(bucket_configs);
-    let mut now = InstantSecs::now();
+    let mut now = InstantSecs { secs: 0 };
 
     // Do 1 `Message` and 1 `Post` action for each IP address, and expect the limit to not be
     // reached
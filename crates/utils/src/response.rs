@@ -1,5 +1,19 @@
-use crate::error::{LemmyError, LemmyErrorType};
-use actix_web::{dev::ServiceResponse, middleware::ErrorHandlerResponse, HttpResponse};
+use crate::{
+  error::{negotiate_error_language, LemmyError, LemmyErrorType},
+  utils::language::accept_language_tags,
+};
+use actix_web::{
+  dev::ServiceResponse,
+  middleware::ErrorHandlerResponse,
+  HttpMessage,
+  HttpRequest,
+  HttpResponse,
+};
+
+/// Interface language of the logged in user, which is stored in the request extensions so that
+/// error messages can be localized.
+#[derive(Clone, Debug)]
+pub struct InterfaceLanguage(pub String);
 
 pub fn jsonify_plain_text_errors<BODY>(
   res: ServiceResponse<BODY>,
@@ -10,13 +24,13 @@ pub fn jsonify_plain_text_errors<BODY>(
   if maybe_error.is_none() {
     return Ok(ErrorHandlerResponse::Response(res.map_into_left_body()));
   }
-  // We're assuming that any LemmyError is already in JSON format, so we don't need to do anything
-  if maybe_error
+  // We're assuming that any LemmyError is already in JSON format, so we only need to localize it
+  if let Some(error) = maybe_error
     .expect("http responses with 400-599 statuses should have an error object")
     .as_error::<LemmyError>()
-    .is_some()
   {
-    return Ok(ErrorHandlerResponse::Response(res.map_into_left_body()));
+    let error_type = error.error_type.clone();
+    return localize_error(res, &error_type);
   }
 
   let (req, res) = res.into_parts();
@@ -31,13 +45,51 @@ pub fn jsonify_plain_text_errors<BODY>(
   ))
 }
 
+/// Adds a `localized_message` to the JSON error, in the interface language of the user or, if
+/// they didn't set one, the language preferred by the browser. The error is left unchanged if
+/// there are no translations for the language.
+fn localize_error<BODY>(
+  res: ServiceResponse<BODY>,
+  error_type: &LemmyErrorType,
+) -> actix_web::Result<ErrorHandlerResponse<BODY>> {
+  let Some(lang) = error_language(res.request()) else {
+    return Ok(ErrorHandlerResponse::Response(res.map_into_left_body()));
+  };
+
+  let mut json = serde_json::to_value(error_type)?;
+  if let Some(object) = json.as_object_mut() {
+    object.insert(
+      "localized_message".to_string(),
+      error_type.localized_message(lang).into(),
+    );
+  }
+  let (req, res) = res.into_parts();
+  let res = res.set_body(serde_json::to_string(&json)?);
+  Ok(ErrorHandlerResponse::Response(
+    ServiceResponse::new(req, res.map_into_boxed_body()).map_into_right_body(),
+  ))
+}
+
+fn error_language(req: &HttpRequest) -> Option<&'static str> {
+  let user_language = req
+    .extensions()
+    .get::<InterfaceLanguage>()
+    .map(|l| l.0.clone())
+    .filter(|l| l != "browser");
+  let preferred = match user_language {
+    Some(l) => vec![l],
+    None => accept_language_tags(req),
+  };
+  negotiate_error_language(preferred.iter().map(String::as_str))
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
   use crate::error::{LemmyError, LemmyErrorType};
   use actix_web::{
     error::ErrorInternalServerError,
-    http::StatusCode,
+    http::{header::ACCEPT_LANGUAGE, StatusCode},
     middleware::ErrorHandlers,
     test,
     web,
@@ -99,6 +151,31 @@ mod tests {
     .await;
   }
 
+  #[actix_web::test]
+  async fn test_lemmy_errors_are_localized() {
+    async fn lemmy_error_service() -> actix_web::Result<String, LemmyError> {
+      Err(LemmyError::from(LemmyErrorType::EmailAlreadyExists))
+    }
+
+    let app = test::init_service(
+      App::new()
+        .wrap(ErrorHandlers::new().default_handler(jsonify_plain_text_errors))
+        .route("/", web::get().to(lemmy_error_service)),
+    )
+    .await;
+    let req = test::TestRequest::default()
+      .insert_header((ACCEPT_LANGUAGE, "de-DE,en;q=0.5"))
+      .to_request();
+    let res = test::call_service(&app, req).await;
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(res).await;
+    assert_eq!(
+      body,
+      "{\"error\":\"email_already_exists\",\"localized_message\":\"Diese E-Mail-Adresse wird bereits verwendet.\"}"
+    );
+  }
+
   async fn check_for_jsonification(
     service: impl Handler<(), Output = impl Responder + 'static>,
     expected_status_code: StatusCode,
@@ -35,6 +35,15 @@ pub struct Settings {
   /// Port where lemmy should listen for incoming requests
   #[default(8536)]
   pub port: u16,
+  /// Listen on a unix domain socket instead of `bind` and `port`. Useful when running behind a
+  /// reverse proxy on the same machine.
+  #[default(None)]
+  #[doku(example = "Some(Default::default())")]
+  pub unix_socket: Option<UnixSocketConfig>,
+  /// Use the socket passed by systemd socket activation (`LISTEN_FDS`) instead of opening one.
+  /// Takes precedence over `unix_socket`, `bind` and `port`.
+  #[default(false)]
+  pub systemd_socket_activation: bool,
   /// Whether the site is available over TLS. Needs to be true for federation to work.
   #[default(true)]
   pub tls_enabled: bool,
@@ -51,9 +60,42 @@ pub struct Settings {
   pub prometheus: Option<PrometheusConfig>,
   /// Sets a response Access-Control-Allow-Origin CORS header
   /// https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Access-Control-Allow-Origin
+  ///
+  /// Shorthand for setting `cors.default.allowed_origins` to a single origin.
   #[default(None)]
   #[doku(example = "lemmy.tld")]
   cors_origin: Option<String>,
+  /// Detailed CORS configuration, for example to allow embedding of certain routes on other sites.
+  #[default(Default::default())]
+  pub cors: CorsConfig,
+  /// Compression of HTTP responses
+  #[default(Default::default())]
+  pub compression: CompressionConfig,
+  /// Move the text of old posts and comments with little engagement into compressed archive
+  /// tables. It is still shown, but loading it is slower.
+  #[default(None)]
+  #[doku(example = "Some(Default::default())")]
+  pub archive: Option<ArchiveConfig>,
+  /// External hooks which are called at certain extension points, so that instances can customize
+  /// behaviour without forking. Hooks for the same extension point are called in the listed order.
+  #[default(Vec::new())]
+  pub hooks: Vec<HookConfig>,
+  /// Path to a MaxMind ASN database (eg GeoLite2-ASN.mmdb). If set, registrations are also rate
+  /// limited per autonomous system, with the `register_asn` site rate limit.
+  #[default(None)]
+  #[doku(example = "/var/lib/lemmy/GeoLite2-ASN.mmdb")]
+  pub maxmind_asn_database: Option<String>,
+  /// Bridge private messages to Matrix direct rooms, when both persons have a Matrix user id set.
+  /// Lemmy stores private messages unencrypted, while the room in Matrix can be used to continue
+  /// the conversation with end-to-end encryption.
+  #[default(None)]
+  #[doku(example = "Some(Default::default())")]
+  pub matrix: Option<MatrixConfig>,
+  /// Also serve the API over HTTP/3 (QUIC). Only has an effect if Lemmy was compiled with the
+  /// `http3` feature.
+  #[default(None)]
+  #[doku(example = "Some(Default::default())")]
+  pub http3: Option<Http3Config>,
 }
 
 impl Settings {
@@ -94,6 +136,13 @@ pub struct PictrsConfig {
   /// Resize post thumbnails to this maximum width/height.
   #[default(512)]
   pub max_thumbnail_size: u32,
+
+  /// Optional OCR service which recognizes text in uploaded images, so that posts with these
+  /// images can be found with search. Each image is sent as request body, and the service needs
+  /// to respond with json like `{"text": "..."}`. Users and communities can opt out.
+  #[default(None)]
+  #[doku(example = "http://localhost:8090/ocr")]
+  pub ocr_url: Option<Url>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document, PartialEq)]
@@ -128,6 +177,13 @@ pub struct DatabaseConfig {
   /// Maximum number of active sql connections
   #[default(30)]
   pub pool_size: usize,
+  /// Maximum time to wait for a free connection from the pool (in seconds)
+  #[default(30)]
+  pub pool_timeout: u64,
+  /// Log a warning when a database connection is used for longer than this, eg because of a slow
+  /// query (in milliseconds). 0 disables the warning.
+  #[default(1000)]
+  pub slow_query_threshold: u64,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
@@ -248,3 +304,160 @@ pub struct FederationWorkerConfig {
   #[default(1)]
   pub concurrent_sends_per_instance: i8,
 }
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default, deny_unknown_fields)]
+pub struct CompressionConfig {
+  /// Compress responses with zstd, brotli or gzip, depending on what the client accepts.
+  #[default(true)]
+  pub enabled: bool,
+  /// Responses smaller than this many bytes are sent uncompressed, as the overhead of compression
+  /// outweighs the savings.
+  #[default(1024)]
+  pub min_size: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default, deny_unknown_fields)]
+pub struct ArchiveConfig {
+  /// Minimum age of posts and comments before they are archived (in days)
+  #[default(365)]
+  pub min_age_days: u32,
+  /// Only posts and comments with at most this score are archived
+  #[default(1)]
+  pub max_score: i64,
+  /// Only posts with at most this many comments, and comments with at most this many replies are
+  /// archived
+  #[default(0)]
+  pub max_comments: i32,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default, deny_unknown_fields)]
+pub struct UnixSocketConfig {
+  /// Filesystem path of the socket. An existing file at this location is removed on startup.
+  #[default("/run/lemmy/lemmy.sock")]
+  pub path: String,
+  /// File permissions for the socket in octal notation. The reverse proxy needs write access.
+  #[default("660")]
+  pub permissions: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default, deny_unknown_fields)]
+pub struct CorsConfig {
+  /// Policy for all routes which are not covered by one of the `route_groups`
+  #[default(Default::default())]
+  pub default: CorsPolicy,
+  /// Separate policies for routes under a given path. If multiple groups match, the one which is
+  /// listed first is used.
+  #[default(Vec::new())]
+  pub route_groups: Vec<CorsRouteGroup>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default, deny_unknown_fields)]
+pub struct CorsRouteGroup {
+  /// Path prefix of the routes, eg `/api/v3` or `/pictrs`
+  #[default("/api/v3")]
+  pub path: String,
+  #[default(Default::default())]
+  pub policy: CorsPolicy,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default, deny_unknown_fields)]
+pub struct CorsPolicy {
+  /// Origins which are allowed to make cross-origin requests, or `*` to allow any origin. The
+  /// origin of the instance itself (where lemmy-ui is served) is always allowed, and by default
+  /// it is the only one.
+  #[default(Vec::new())]
+  #[doku(example = "https://lemmy-ui.example.com")]
+  pub allowed_origins: Vec<String>,
+  /// Request headers which are allowed in cross-origin requests. If empty, any header is allowed.
+  #[default(Vec::new())]
+  #[doku(example = "Authorization")]
+  pub allowed_headers: Vec<String>,
+  /// How long browsers may cache the result of a preflight request (in seconds)
+  #[default(3600)]
+  pub max_age: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default, deny_unknown_fields)]
+pub struct HookConfig {
+  /// The extension point where the hook is called
+  pub point: HookPoint,
+  /// Url which receives the hook data as JSON POST request. Either this or `wasm_module` must be
+  /// set.
+  #[default(None)]
+  #[doku(example = "http://localhost:8080/hook")]
+  pub url: Option<Url>,
+  /// Path to a WebAssembly module which exports a `hook` function. Only has an effect if Lemmy was
+  /// compiled with the `wasm-hooks` feature.
+  #[default(None)]
+  #[doku(example = "/var/lib/lemmy/hooks/filter.wasm")]
+  pub wasm_module: Option<String>,
+  /// Expression which decides if the hook applies, with the hook data available as `data`. If
+  /// only a condition is given, matching actions are denied. See
+  /// `crates/utils/src/utils/expression.rs` for the syntax.
+  #[default(None)]
+  #[doku(example = "data.name.lowerAscii().contains(\"crypto\") && size(data.body) < 20")]
+  pub condition: Option<String>,
+  /// Reason shown to the user when an action is denied by `condition`
+  #[default(None)]
+  #[doku(example = "Looks like spam")]
+  pub deny_reason: Option<String>,
+  /// Maximum time the hook may take (in milliseconds)
+  #[default(1000)]
+  pub timeout_ms: u64,
+  /// Reject the action if the hook fails or times out. By default the action is allowed.
+  #[default(false)]
+  pub fail_closed: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, SmartDefault, Document, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HookPoint {
+  /// Before a local post is created. The hook receives `CreatePost`.
+  #[default]
+  PrePostCreate,
+  /// Before a local comment is created. The hook receives `CreateComment`.
+  PreCommentCreate,
+  /// Before a report for a post is created. The hook receives `CreatePostReport`.
+  PostReportCreate,
+  /// Before an activity is queued for sending to other instances. The hook receives the activity
+  /// JSON.
+  PreFederationSend,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(deny_unknown_fields)]
+pub struct MatrixConfig {
+  /// Address of the Matrix homeserver where the bot account is registered
+  #[default(Url::parse("http://localhost:8008").expect("parse matrix url"))]
+  #[doku(example = "https://matrix.example.com")]
+  pub homeserver_url: Url,
+  /// Access token of the bot account, which creates the rooms and relays the messages
+  #[doku(example = "string")]
+  pub access_token: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, SmartDefault, Document)]
+#[serde(default, deny_unknown_fields)]
+pub struct Http3Config {
+  /// UDP port where HTTP/3 requests are accepted, on the same address as the HTTP listener. The
+  /// HTTP listener stays active, clients which support HTTP/3 switch over after receiving the
+  /// `Alt-Svc` header.
+  #[default(443)]
+  pub port: u16,
+  /// QUIC always uses TLS, so the certificate chain and private key of the site are needed (PEM
+  /// files).
+  #[default("/etc/lemmy/tls/fullchain.pem".to_string())]
+  pub certificate: String,
+  #[default("/etc/lemmy/tls/privkey.pem".to_string())]
+  pub private_key: String,
+  /// How long clients may remember the HTTP/3 endpoint (in seconds)
+  #[default(86400)]
+  pub max_age: u32,
+}
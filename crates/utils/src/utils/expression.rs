@@ -0,0 +1,782 @@
+//! A small, sandboxed expression language for hook conditions, modeled after a subset of CEL.
+//!
+//! Expressions work on JSON values and support:
+//! - literals: `1`, `2.5`, `"text"`, `'text'`, `true`, `false`, `null`, `[1, 2]`
+//! - variables and member access: `data.name`, `data["name"]`, `data.tags[0]`
+//! - operators: `!`, `-`, `*`, `/`, `%`, `+`, `==`, `!=`, `<`, `<=`, `>`, `>=`, `in`, `&&`, `||`
+//! - functions: `size(x)`, and the string methods `contains`, `startsWith`, `endsWith`, `matches`
+//!   (regex) and `lowerAscii`
+//!
+//! Missing fields evaluate to `null`. There are no loops or assignments, so the evaluation time is
+//! bounded by the length of the expression. It is additionally aborted when the timeout expires.
+
+use regex::{Regex, RegexBuilder};
+use serde_json::{Map, Number, Value};
+use std::{
+  cmp::Ordering,
+  fmt,
+  iter::Peekable,
+  time::{Duration, Instant},
+  vec::IntoIter,
+};
+
+const MAX_SOURCE_LENGTH: usize = 2000;
+const MAX_DEPTH: usize = 32;
+const MAX_REGEX_SIZE: usize = 100_000;
+/// Two character operators need to be listed before their one character prefixes.
+const PUNCTUATION: [&str; 20] = [
+  "==", "!=", "<=", ">=", "&&", "||", "(", ")", "[", "]", ".", ",", "!", "-", "+", "*", "/", "%",
+  "<", ">",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpressionError(String);
+
+impl fmt::Display for ExpressionError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+impl std::error::Error for ExpressionError {}
+
+fn error<T>(message: impl Into<String>) -> Result<T, ExpressionError> {
+  Err(ExpressionError(message.into()))
+}
+
+/// A parsed expression, which can be evaluated many times.
+#[derive(Debug, Clone)]
+pub struct Expression {
+  root: Node,
+}
+
+impl Expression {
+  pub fn parse(source: &str) -> Result<Self, ExpressionError> {
+    if source.len() > MAX_SOURCE_LENGTH {
+      return error(format!(
+        "Expression is longer than {MAX_SOURCE_LENGTH} characters"
+      ));
+    }
+    let mut parser = Parser {
+      tokens: tokenize(source)?.into_iter().peekable(),
+      depth: 0,
+    };
+    let root = parser.parse_expression()?;
+    if let Some(token) = parser.tokens.next() {
+      return error(format!("Unexpected {token} after end of expression"));
+    }
+    Ok(Expression { root })
+  }
+
+  pub fn evaluate(
+    &self,
+    variables: &Map<String, Value>,
+    timeout: Duration,
+  ) -> Result<Value, ExpressionError> {
+    let mut evaluator = Evaluator {
+      variables,
+      deadline: Instant::now() + timeout,
+    };
+    evaluator.eval(&self.root)
+  }
+
+  /// Same as [Expression::evaluate], but fails if the result is not a boolean.
+  pub fn evaluate_bool(
+    &self,
+    variables: &Map<String, Value>,
+    timeout: Duration,
+  ) -> Result<bool, ExpressionError> {
+    match self.evaluate(variables, timeout)? {
+      Value::Bool(result) => Ok(result),
+      other => error(format!(
+        "Expression must return a boolean, not {}",
+        type_name(&other)
+      )),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+  Number(Number),
+  String(String),
+  Ident(String),
+  Punct(&'static str),
+}
+
+impl fmt::Display for Token {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Token::Number(n) => write!(f, "number `{n}`"),
+      Token::String(s) => write!(f, "string \"{s}\""),
+      Token::Ident(i) => write!(f, "`{i}`"),
+      Token::Punct(p) => write!(f, "`{p}`"),
+    }
+  }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, ExpressionError> {
+  let mut tokens = vec![];
+  let mut chars = source.chars().peekable();
+  while let Some(&c) = chars.peek() {
+    if c.is_whitespace() {
+      chars.next();
+    } else if c.is_ascii_digit() {
+      let mut text = String::new();
+      while let Some(&c) = chars.peek() {
+        if !c.is_ascii_digit() && c != '.' {
+          break;
+        }
+        text.push(c);
+        chars.next();
+      }
+      let number = if text.contains('.') {
+        text.parse::<f64>().ok().and_then(Number::from_f64)
+      } else {
+        text.parse::<i64>().ok().map(Number::from)
+      };
+      match number {
+        Some(number) => tokens.push(Token::Number(number)),
+        None => return error(format!("Invalid number `{text}`")),
+      }
+    } else if c.is_alphabetic() || c == '_' {
+      let mut ident = String::new();
+      while let Some(&c) = chars.peek() {
+        if !c.is_alphanumeric() && c != '_' {
+          break;
+        }
+        ident.push(c);
+        chars.next();
+      }
+      tokens.push(Token::Ident(ident));
+    } else if c == '"' || c == '\'' {
+      chars.next();
+      let mut text = String::new();
+      loop {
+        match chars.next() {
+          Some(end) if end == c => break,
+          Some('\\') => match chars.next() {
+            Some('n') => text.push('\n'),
+            Some('t') => text.push('\t'),
+            Some(escaped) => text.push(escaped),
+            None => return error("Unterminated string"),
+          },
+          Some(other) => text.push(other),
+          None => return error("Unterminated string"),
+        }
+      }
+      tokens.push(Token::String(text));
+    } else {
+      chars.next();
+      let two = chars.peek().map(|next| format!("{c}{next}"));
+      let one = c.to_string();
+      if let Some(punct) = PUNCTUATION.into_iter().find(|p| two.as_deref() == Some(*p)) {
+        chars.next();
+        tokens.push(Token::Punct(punct));
+      } else if let Some(punct) = PUNCTUATION.into_iter().find(|p| *p == one) {
+        tokens.push(Token::Punct(punct));
+      } else {
+        return error(format!("Unexpected character `{c}`"));
+      }
+    }
+  }
+  Ok(tokens)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinaryOp {
+  Eq,
+  Ne,
+  Lt,
+  Le,
+  Gt,
+  Ge,
+  In,
+  Add,
+  Sub,
+  Mul,
+  Div,
+  Rem,
+}
+
+impl fmt::Display for BinaryOp {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let symbol = match self {
+      BinaryOp::Eq => "==",
+      BinaryOp::Ne => "!=",
+      BinaryOp::Lt => "<",
+      BinaryOp::Le => "<=",
+      BinaryOp::Gt => ">",
+      BinaryOp::Ge => ">=",
+      BinaryOp::In => "in",
+      BinaryOp::Add => "+",
+      BinaryOp::Sub => "-",
+      BinaryOp::Mul => "*",
+      BinaryOp::Div => "/",
+      BinaryOp::Rem => "%",
+    };
+    f.write_str(symbol)
+  }
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+  Literal(Value),
+  Variable(String),
+  Member(Box<Node>, String),
+  Index(Box<Node>, Box<Node>),
+  List(Vec<Node>),
+  Call {
+    target: Option<Box<Node>>,
+    name: String,
+    args: Vec<Node>,
+  },
+  /// `matches` with a constant pattern, whose regex is compiled while parsing.
+  Matches(Box<Node>, Regex),
+  Not(Box<Node>),
+  Negate(Box<Node>),
+  And(Box<Node>, Box<Node>),
+  Or(Box<Node>, Box<Node>),
+  Binary(BinaryOp, Box<Node>, Box<Node>),
+}
+
+struct Parser {
+  tokens: Peekable<IntoIter<Token>>,
+  depth: usize,
+}
+
+impl Parser {
+  fn eat(&mut self, punct: &str) -> bool {
+    let found = matches!(self.tokens.peek(), Some(Token::Punct(p)) if *p == punct);
+    if found {
+      self.tokens.next();
+    }
+    found
+  }
+
+  fn eat_keyword(&mut self, keyword: &str) -> bool {
+    let found = matches!(self.tokens.peek(), Some(Token::Ident(i)) if i == keyword);
+    if found {
+      self.tokens.next();
+    }
+    found
+  }
+
+  fn expect(&mut self, punct: &str) -> Result<(), ExpressionError> {
+    if self.eat(punct) {
+      Ok(())
+    } else {
+      match self.tokens.peek() {
+        Some(token) => error(format!("Expected `{punct}`, found {token}")),
+        None => error(format!("Expected `{punct}`, found end of expression")),
+      }
+    }
+  }
+
+  /// Guards against stack overflows from deeply nested expressions.
+  fn descend(&mut self) -> Result<(), ExpressionError> {
+    self.depth += 1;
+    if self.depth > MAX_DEPTH {
+      error("Expression is nested too deeply")
+    } else {
+      Ok(())
+    }
+  }
+
+  fn parse_expression(&mut self) -> Result<Node, ExpressionError> {
+    self.descend()?;
+    let node = self.parse_or()?;
+    self.depth -= 1;
+    Ok(node)
+  }
+
+  fn parse_or(&mut self) -> Result<Node, ExpressionError> {
+    let mut node = self.parse_and()?;
+    while self.eat("||") {
+      node = Node::Or(Box::new(node), Box::new(self.parse_and()?));
+    }
+    Ok(node)
+  }
+
+  fn parse_and(&mut self) -> Result<Node, ExpressionError> {
+    let mut node = self.parse_comparison()?;
+    while self.eat("&&") {
+      node = Node::And(Box::new(node), Box::new(self.parse_comparison()?));
+    }
+    Ok(node)
+  }
+
+  fn parse_comparison(&mut self) -> Result<Node, ExpressionError> {
+    let mut node = self.parse_additive()?;
+    loop {
+      let op = if self.eat("==") {
+        BinaryOp::Eq
+      } else if self.eat("!=") {
+        BinaryOp::Ne
+      } else if self.eat("<=") {
+        BinaryOp::Le
+      } else if self.eat(">=") {
+        BinaryOp::Ge
+      } else if self.eat("<") {
+        BinaryOp::Lt
+      } else if self.eat(">") {
+        BinaryOp::Gt
+      } else if self.eat_keyword("in") {
+        BinaryOp::In
+      } else {
+        return Ok(node);
+      };
+      node = Node::Binary(op, Box::new(node), Box::new(self.parse_additive()?));
+    }
+  }
+
+  fn parse_additive(&mut self) -> Result<Node, ExpressionError> {
+    let mut node = self.parse_multiplicative()?;
+    loop {
+      let op = if self.eat("+") {
+        BinaryOp::Add
+      } else if self.eat("-") {
+        BinaryOp::Sub
+      } else {
+        return Ok(node);
+      };
+      node = Node::Binary(op, Box::new(node), Box::new(self.parse_multiplicative()?));
+    }
+  }
+
+  fn parse_multiplicative(&mut self) -> Result<Node, ExpressionError> {
+    let mut node = self.parse_unary()?;
+    loop {
+      let op = if self.eat("*") {
+        BinaryOp::Mul
+      } else if self.eat("/") {
+        BinaryOp::Div
+      } else if self.eat("%") {
+        BinaryOp::Rem
+      } else {
+        return Ok(node);
+      };
+      node = Node::Binary(op, Box::new(node), Box::new(self.parse_unary()?));
+    }
+  }
+
+  fn parse_unary(&mut self) -> Result<Node, ExpressionError> {
+    if self.eat("!") {
+      self.descend()?;
+      let node = Node::Not(Box::new(self.parse_unary()?));
+      self.depth -= 1;
+      Ok(node)
+    } else if self.eat("-") {
+      self.descend()?;
+      let node = Node::Negate(Box::new(self.parse_unary()?));
+      self.depth -= 1;
+      Ok(node)
+    } else {
+      self.parse_postfix()
+    }
+  }
+
+  fn parse_postfix(&mut self) -> Result<Node, ExpressionError> {
+    let mut node = self.parse_primary()?;
+    loop {
+      if self.eat(".") {
+        let name = match self.tokens.next() {
+          Some(Token::Ident(name)) => name,
+          Some(token) => return error(format!("Expected field name, found {token}")),
+          None => return error("Expected field name, found end of expression"),
+        };
+        node = if self.eat("(") {
+          let args = self.parse_list(")")?;
+          match (name.as_str(), args.as_slice()) {
+            ("matches", [Node::Literal(Value::String(pattern))]) => {
+              Node::Matches(Box::new(node), compile_regex(pattern)?)
+            }
+            _ => Node::Call {
+              target: Some(Box::new(node)),
+              name,
+              args,
+            },
+          }
+        } else {
+          Node::Member(Box::new(node), name)
+        };
+      } else if self.eat("[") {
+        let index = self.parse_expression()?;
+        self.expect("]")?;
+        node = Node::Index(Box::new(node), Box::new(index));
+      } else {
+        return Ok(node);
+      }
+    }
+  }
+
+  fn parse_primary(&mut self) -> Result<Node, ExpressionError> {
+    match self.tokens.next() {
+      Some(Token::Number(n)) => Ok(Node::Literal(Value::Number(n))),
+      Some(Token::String(s)) => Ok(Node::Literal(Value::String(s))),
+      Some(Token::Ident(name)) => match name.as_str() {
+        "true" => Ok(Node::Literal(Value::Bool(true))),
+        "false" => Ok(Node::Literal(Value::Bool(false))),
+        "null" => Ok(Node::Literal(Value::Null)),
+        _ if self.eat("(") => Ok(Node::Call {
+          target: None,
+          name,
+          args: self.parse_list(")")?,
+        }),
+        _ => Ok(Node::Variable(name)),
+      },
+      Some(Token::Punct("(")) => {
+        let node = self.parse_expression()?;
+        self.expect(")")?;
+        Ok(node)
+      }
+      Some(Token::Punct("[")) => Ok(Node::List(self.parse_list("]")?)),
+      Some(token) => error(format!("Unexpected {token}")),
+      None => error("Unexpected end of expression"),
+    }
+  }
+
+  /// Comma separated expressions up to the closing punctuation, which was already opened.
+  fn parse_list(&mut self, close: &str) -> Result<Vec<Node>, ExpressionError> {
+    let mut items = vec![];
+    if self.eat(close) {
+      return Ok(items);
+    }
+    loop {
+      items.push(self.parse_expression()?);
+      if self.eat(close) {
+        return Ok(items);
+      }
+      self.expect(",")?;
+    }
+  }
+}
+
+struct Evaluator<'a> {
+  variables: &'a Map<String, Value>,
+  deadline: Instant,
+}
+
+impl Evaluator<'_> {
+  fn eval(&mut self, node: &Node) -> Result<Value, ExpressionError> {
+    if Instant::now() >= self.deadline {
+      return error("Evaluation exceeded the time limit");
+    }
+    match node {
+      Node::Literal(value) => Ok(value.clone()),
+      Node::Variable(name) => match self.variables.get(name) {
+        Some(value) => Ok(value.clone()),
+        None => error(format!("Unknown variable `{name}`")),
+      },
+      Node::Member(target, name) => match self.eval(target)? {
+        Value::Object(mut map) => Ok(map.remove(name).unwrap_or(Value::Null)),
+        Value::Null => Ok(Value::Null),
+        other => error(format!(
+          "Can't access field `{name}` of {}",
+          type_name(&other)
+        )),
+      },
+      Node::Index(target, index) => match (self.eval(target)?, self.eval(index)?) {
+        (Value::Array(values), Value::Number(n)) => Ok(
+          n.as_u64()
+            .and_then(|i| usize::try_from(i).ok())
+            .and_then(|i| values.into_iter().nth(i))
+            .unwrap_or(Value::Null),
+        ),
+        (Value::Object(mut map), Value::String(key)) => Ok(map.remove(&key).unwrap_or(Value::Null)),
+        (Value::Null, _) => Ok(Value::Null),
+        (target, index) => error(format!(
+          "Can't index {} with {}",
+          type_name(&target),
+          type_name(&index)
+        )),
+      },
+      Node::List(items) => Ok(Value::Array(
+        items
+          .iter()
+          .map(|item| self.eval(item))
+          .collect::<Result<_, _>>()?,
+      )),
+      Node::Call { target, name, args } => {
+        let target = target.as_ref().map(|t| self.eval(t)).transpose()?;
+        let args = args
+          .iter()
+          .map(|arg| self.eval(arg))
+          .collect::<Result<Vec<_>, _>>()?;
+        call(target, name, &args)
+      }
+      Node::Matches(target, regex) => match self.eval(target)? {
+        Value::String(s) => Ok(Value::Bool(regex.is_match(&s))),
+        other => error(format!(
+          "No method `matches` on {} with 1 arguments",
+          type_name(&other)
+        )),
+      },
+      Node::Not(operand) => Ok(Value::Bool(!self.eval_bool(operand)?)),
+      Node::Negate(operand) => arithmetic(BinaryOp::Sub, &Value::from(0), &self.eval(operand)?),
+      Node::And(left, right) => Ok(Value::Bool(self.eval_bool(left)? && self.eval_bool(right)?)),
+      Node::Or(left, right) => Ok(Value::Bool(self.eval_bool(left)? || self.eval_bool(right)?)),
+      Node::Binary(op, left, right) => {
+        let left = self.eval(left)?;
+        let right = self.eval(right)?;
+        binary(*op, &left, &right)
+      }
+    }
+  }
+
+  fn eval_bool(&mut self, node: &Node) -> Result<bool, ExpressionError> {
+    match self.eval(node)? {
+      Value::Bool(value) => Ok(value),
+      other => error(format!("Expected a boolean, found {}", type_name(&other))),
+    }
+  }
+}
+
+fn binary(op: BinaryOp, left: &Value, right: &Value) -> Result<Value, ExpressionError> {
+  match op {
+    BinaryOp::Eq => Ok(Value::Bool(equals(left, right))),
+    BinaryOp::Ne => Ok(Value::Bool(!equals(left, right))),
+    BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => {
+      let ordering = compare(left, right)?;
+      Ok(Value::Bool(match op {
+        BinaryOp::Lt => ordering.is_lt(),
+        BinaryOp::Le => ordering.is_le(),
+        BinaryOp::Gt => ordering.is_gt(),
+        _ => ordering.is_ge(),
+      }))
+    }
+    BinaryOp::In => contains(right, left).map(Value::Bool),
+    BinaryOp::Add => match (left, right) {
+      (Value::String(a), Value::String(b)) => Ok(Value::String(format!("{a}{b}"))),
+      (Value::Array(a), Value::Array(b)) => Ok(Value::Array(a.iter().chain(b).cloned().collect())),
+      _ => arithmetic(op, left, right),
+    },
+    BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem => arithmetic(op, left, right),
+  }
+}
+
+/// Like `==` on JSON values, except that integers and floats with the same value are equal.
+fn equals(left: &Value, right: &Value) -> bool {
+  match (left.as_f64(), right.as_f64()) {
+    (Some(a), Some(b)) => a == b,
+    _ => left == right,
+  }
+}
+
+fn compare(left: &Value, right: &Value) -> Result<Ordering, ExpressionError> {
+  let ordering = match (left, right) {
+    (Value::Number(a), Value::Number(b)) => a.as_f64().partial_cmp(&b.as_f64()),
+    (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+    _ => None,
+  };
+  ordering.map_or_else(
+    || {
+      error(format!(
+        "Can't compare {} with {}",
+        type_name(left),
+        type_name(right)
+      ))
+    },
+    Ok,
+  )
+}
+
+fn contains(container: &Value, item: &Value) -> Result<bool, ExpressionError> {
+  match (container, item) {
+    (Value::Array(values), _) => Ok(values.iter().any(|v| equals(v, item))),
+    (Value::String(haystack), Value::String(needle)) => Ok(haystack.contains(needle.as_str())),
+    (Value::Object(map), Value::String(key)) => Ok(map.contains_key(key)),
+    _ => error(format!(
+      "Can't check if {} is in {}",
+      type_name(item),
+      type_name(container)
+    )),
+  }
+}
+
+fn arithmetic(op: BinaryOp, left: &Value, right: &Value) -> Result<Value, ExpressionError> {
+  let (Value::Number(a), Value::Number(b)) = (left, right) else {
+    return error(format!(
+      "Can't apply `{op}` to {} and {}",
+      type_name(left),
+      type_name(right)
+    ));
+  };
+  if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+    let result = match op {
+      BinaryOp::Add => a.checked_add(b),
+      BinaryOp::Sub => a.checked_sub(b),
+      BinaryOp::Mul => a.checked_mul(b),
+      BinaryOp::Div => a.checked_div(b),
+      BinaryOp::Rem => a.checked_rem(b),
+      _ => None,
+    };
+    return result.map_or_else(
+      || error(format!("Integer overflow or division by zero in `{op}`")),
+      |result| Ok(Value::from(result)),
+    );
+  }
+  let a = a.as_f64().unwrap_or_default();
+  let b = b.as_f64().unwrap_or_default();
+  let result = match op {
+    BinaryOp::Add => a + b,
+    BinaryOp::Sub => a - b,
+    BinaryOp::Mul => a * b,
+    BinaryOp::Div => a / b,
+    BinaryOp::Rem => a % b,
+    _ => f64::NAN,
+  };
+  Number::from_f64(result).map_or_else(
+    || error(format!("Result of `{op}` is not a finite number")),
+    |result| Ok(Value::Number(result)),
+  )
+}
+
+fn call(target: Option<Value>, name: &str, args: &[Value]) -> Result<Value, ExpressionError> {
+  match (target, name, args) {
+    (None, "size", [value]) => size(value),
+    (Some(value), "size", []) => size(&value),
+    (Some(Value::String(s)), "contains", [Value::String(arg)]) => {
+      Ok(Value::Bool(s.contains(arg.as_str())))
+    }
+    (Some(Value::String(s)), "startsWith", [Value::String(arg)]) => {
+      Ok(Value::Bool(s.starts_with(arg.as_str())))
+    }
+    (Some(Value::String(s)), "endsWith", [Value::String(arg)]) => {
+      Ok(Value::Bool(s.ends_with(arg.as_str())))
+    }
+    (Some(Value::String(s)), "matches", [Value::String(pattern)]) => {
+      Ok(Value::Bool(compile_regex(pattern)?.is_match(&s)))
+    }
+    (Some(Value::String(s)), "lowerAscii", []) => Ok(Value::String(s.to_ascii_lowercase())),
+    (Some(target), _, _) => error(format!(
+      "No method `{name}` on {} with {} arguments",
+      type_name(&target),
+      args.len()
+    )),
+    (None, _, _) => error(format!(
+      "No function `{name}` with {} arguments",
+      args.len()
+    )),
+  }
+}
+
+fn compile_regex(pattern: &str) -> Result<Regex, ExpressionError> {
+  RegexBuilder::new(pattern)
+    .size_limit(MAX_REGEX_SIZE)
+    .build()
+    .map_err(|e| ExpressionError(format!("Invalid regex: {e}")))
+}
+
+fn size(value: &Value) -> Result<Value, ExpressionError> {
+  match value {
+    Value::String(s) => Ok(Value::from(s.chars().count())),
+    Value::Array(values) => Ok(Value::from(values.len())),
+    Value::Object(map) => Ok(Value::from(map.len())),
+    other => error(format!("Can't get size of {}", type_name(other))),
+  }
+}
+
+fn type_name(value: &Value) -> &'static str {
+  match value {
+    Value::Null => "null",
+    Value::Bool(_) => "bool",
+    Value::Number(_) => "number",
+    Value::String(_) => "string",
+    Value::Array(_) => "list",
+    Value::Object(_) => "map",
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+
+  const TIMEOUT: Duration = Duration::from_secs(1);
+
+  fn eval(source: &str) -> Result<Value, ExpressionError> {
+    let variables = json!({
+      "data": {
+        "name": "Cheap Crypto",
+        "body": null,
+        "tags": ["spam", "ads"],
+        "score": 3,
+      }
+    });
+    let variables = variables.as_object().cloned().unwrap_or_default();
+    Expression::parse(source)?.evaluate(&variables, TIMEOUT)
+  }
+
+  #[test]
+  fn test_evaluate() -> Result<(), ExpressionError> {
+    assert_eq!(json!(7), eval("1 + 2 * 3")?);
+    assert_eq!(json!(-1), eval("-(4 - 3)")?);
+    assert_eq!(json!(2.5), eval("5.0 / 2")?);
+    assert_eq!(json!(true), eval("data.score >= 3 && data.score != 4.0")?);
+    assert_eq!(
+      json!(true),
+      eval("data.name.lowerAscii().contains('crypto')")?
+    );
+    assert_eq!(json!(true), eval(r#"data.name.matches("^[A-Z]")"#)?);
+    assert_eq!(json!(false), eval(r#"data.name.matches("^" + "c")"#)?);
+    assert_eq!(
+      json!(true),
+      eval(r#""ads" in data.tags && "name" in data"#)?
+    );
+    assert_eq!(json!("spam"), eval("data.tags[0]")?);
+    assert_eq!(json!(null), eval("data.tags[5]")?);
+    assert_eq!(json!(12), eval("size(data.name)")?);
+    assert_eq!(
+      json!(true),
+      eval("data.body == null || data.body.size() > 5")?
+    );
+    assert_eq!(json!(true), eval("data.missing.field == null")?);
+    assert_eq!(json!(false), eval("!(data.name.startsWith(\"Cheap\"))")?);
+    Ok(())
+  }
+
+  #[test]
+  fn test_errors() {
+    assert!(Expression::parse("data.name ==").is_err());
+    assert!(Expression::parse("(1 + 2").is_err());
+    assert!(Expression::parse("1 2").is_err());
+    assert!(Expression::parse("data.name = 'x'").is_err());
+    assert!(Expression::parse("'unterminated").is_err());
+    assert!(Expression::parse(&"(".repeat(100)).is_err());
+    assert!(Expression::parse(&"1+".repeat(MAX_SOURCE_LENGTH)).is_err());
+    // Constant regexes are checked while parsing
+    assert!(Expression::parse("data.name.matches('(')").is_err());
+
+    assert!(eval("unknown").is_err());
+    assert!(eval("data.name && true").is_err());
+    assert!(eval("data.name < 1").is_err());
+    assert!(eval("1 / 0").is_err());
+    assert!(eval("data.body.contains('x')").is_err());
+    assert!(eval("data.name.matches('(' + '')").is_err());
+    assert!(eval("data.body.matches('x')").is_err());
+  }
+
+  #[test]
+  fn test_timeout() -> Result<(), ExpressionError> {
+    let expression = Expression::parse("size([1, 2, 3]) > 0")?;
+    let result = expression.evaluate_bool(&Map::new(), Duration::ZERO);
+    assert_eq!(
+      Err(ExpressionError(
+        "Evaluation exceeded the time limit".to_string()
+      )),
+      result
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_evaluate_bool() -> Result<(), ExpressionError> {
+    let expression = Expression::parse("1 + 1")?;
+    assert!(expression.evaluate_bool(&Map::new(), TIMEOUT).is_err());
+    let expression = Expression::parse("1 + 1 == 2")?;
+    assert!(expression.evaluate_bool(&Map::new(), TIMEOUT)?);
+    Ok(())
+  }
+}
@@ -0,0 +1,33 @@
+use actix_web::{http::header::ACCEPT_LANGUAGE, HttpRequest};
+
+/// Languages from the `Accept-Language` header, ordered by preference and without region codes.
+pub fn accept_language_tags(req: &HttpRequest) -> Vec<String> {
+  req
+    .headers()
+    .get(ACCEPT_LANGUAGE)
+    .map(|hdr| accept_language::parse(hdr.to_str().unwrap_or_default()))
+    .iter()
+    .flatten()
+    // Remove the optional region code
+    .map(|lang_str| lang_str.split('-').next().unwrap_or_default().to_string())
+    .filter(|lang| !lang.is_empty() && lang != "*")
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use actix_web::test::TestRequest;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn test_accept_language_tags() {
+    let req = TestRequest::default()
+      .insert_header((ACCEPT_LANGUAGE, "de-AT,fr;q=0.5,*;q=0.1,en-US;q=0.8"))
+      .to_http_request();
+    assert_eq!(vec!["de", "en", "fr"], accept_language_tags(&req));
+
+    let req = TestRequest::default().to_http_request();
+    assert!(accept_language_tags(&req).is_empty());
+  }
+}
@@ -1,3 +1,5 @@
+pub mod expression;
+pub mod language;
 pub mod markdown;
 pub mod mention;
 pub mod slurs;
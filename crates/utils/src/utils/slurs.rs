@@ -1,20 +1,151 @@
 use crate::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 use regex::{Regex, RegexBuilder};
+use std::ops::Range;
+use unicode_normalization::char::{decompose_compatible, is_combining_mark};
 
-pub fn remove_slurs(test: &str, slur_regex: &Option<Regex>) -> String {
-  if let Some(slur_regex) = slur_regex {
-    slur_regex.replace_all(test, "*removed*").to_string()
-  } else {
-    test.to_string()
+/// The compiled slur filter of an instance.
+#[derive(Clone, Debug)]
+pub struct SlurRegex {
+  regex: Regex,
+  /// Match against the [NormalizedText] instead of the raw text.
+  normalize: bool,
+}
+
+impl SlurRegex {
+  pub fn new(regex: Regex, normalize: bool) -> Self {
+    SlurRegex { regex, normalize }
+  }
+
+  /// Byte ranges of all matches, always relative to the original text.
+  fn find_ranges(&self, text: &str) -> Vec<Range<usize>> {
+    if self.normalize {
+      let normalized = NormalizedText::new(text);
+      self
+        .regex
+        .find_iter(&normalized.text)
+        .map(|m| normalized.original_range(m.range()))
+        .collect()
+    } else {
+      self.regex.find_iter(text).map(|m| m.range()).collect()
+    }
   }
 }
 
-pub(crate) fn slur_check<'a>(
+/// Text with compatibility characters decomposed (eg fullwidth or mathematical letters),
+/// diacritics and invisible characters removed, and common homoglyphs folded to their ascii
+/// lookalikes. Keeps track of where each char came from, so that matches can be mapped back.
+struct NormalizedText {
+  text: String,
+  /// Byte offset of each char in `text`, and the byte range of the original char it came from.
+  origins: Vec<(usize, Range<usize>)>,
+  original_len: usize,
+}
+
+impl NormalizedText {
+  fn new(original: &str) -> Self {
+    let mut text = String::with_capacity(original.len());
+    let mut origins = Vec::with_capacity(original.len());
+    for (start, c) in original.char_indices() {
+      let origin = start..start + c.len_utf8();
+      decompose_compatible(c, |d| {
+        if is_combining_mark(d) || is_invisible(d) {
+          return;
+        }
+        origins.push((text.len(), origin.clone()));
+        text.push(fold_homoglyph(d));
+      });
+    }
+    NormalizedText {
+      text,
+      origins,
+      original_len: original.len(),
+    }
+  }
+
+  fn original_range(&self, range: Range<usize>) -> Range<usize> {
+    let first = self.origins.partition_point(|(pos, _)| *pos < range.start);
+    let last = self.origins.partition_point(|(pos, _)| *pos < range.end);
+    let start = self
+      .origins
+      .get(first)
+      .map_or(self.original_len, |(_, o)| o.start);
+    let end = last
+      .checked_sub(1)
+      .and_then(|i| self.origins.get(i))
+      .map_or(start, |(_, o)| o.end)
+      .max(start);
+    start..end
+  }
+}
+
+/// Characters which don't render, and can be used to split up words.
+fn is_invisible(c: char) -> bool {
+  matches!(
+    c,
+    '\u{00AD}' | '\u{180E}' | '\u{200B}'..='\u{200F}' | '\u{2060}'..='\u{2064}' | '\u{FEFF}'
+  )
+}
+
+/// Maps Cyrillic and Greek letters to the latin letter which they look like.
+fn fold_homoglyph(c: char) -> char {
+  match c {
+    'а' | 'А' | 'α' | 'Α' => 'a',
+    'в' | 'В' | 'β' | 'Β' => 'b',
+    'с' | 'С' | 'ϲ' | 'Ϲ' => 'c',
+    'ԁ' => 'd',
+    'е' | 'Е' | 'ё' | 'Ё' | 'ε' | 'Ε' => 'e',
+    'ғ' => 'f',
+    'ԍ' => 'g',
+    'һ' | 'н' | 'Н' | 'Η' => 'h',
+    'і' | 'І' | 'ї' | 'Ї' | 'ι' | 'Ι' | 'ӏ' | 'Ӏ' => 'i',
+    'ј' | 'Ј' => 'j',
+    'к' | 'К' | 'κ' | 'Κ' => 'k',
+    'м' | 'М' | 'Μ' => 'm',
+    'п' | 'η' | 'Ν' => 'n',
+    'о' | 'О' | 'ο' | 'Ο' | 'σ' => 'o',
+    'р' | 'Р' | 'ρ' | 'Ρ' => 'p',
+    'ԛ' => 'q',
+    'г' => 'r',
+    'ѕ' | 'Ѕ' => 's',
+    'т' | 'Т' | 'τ' | 'Τ' => 't',
+    'υ' | 'ս' => 'u',
+    'ν' | 'ѵ' | 'Ѵ' => 'v',
+    'ԝ' | 'ѡ' | 'ω' => 'w',
+    'х' | 'Х' | 'χ' | 'Χ' => 'x',
+    'у' | 'У' | 'γ' | 'Υ' | 'Ү' => 'y',
+    'Ζ' => 'z',
+    _ => c,
+  }
+}
+
+pub fn remove_slurs(test: &str, slur_regex: &Option<SlurRegex>) -> String {
+  let Some(slur_regex) = slur_regex else {
+    return test.to_string();
+  };
+  let mut result = String::with_capacity(test.len());
+  let mut last_end = 0;
+  for range in slur_regex.find_ranges(test) {
+    // Two matches can map to the same original char, eg if a ligature was decomposed
+    if range.start >= last_end {
+      result.push_str(&test[last_end..range.start]);
+      result.push_str("*removed*");
+    }
+    last_end = last_end.max(range.end);
+  }
+  result.push_str(&test[last_end..]);
+  result
+}
+
+pub fn slur_check<'a>(
   test: &'a str,
-  slur_regex: &'a Option<Regex>,
+  slur_regex: &'a Option<SlurRegex>,
 ) -> Result<(), Vec<&'a str>> {
   if let Some(slur_regex) = slur_regex {
-    let mut matches: Vec<&str> = slur_regex.find_iter(test).map(|mat| mat.as_str()).collect();
+    let mut matches: Vec<&str> = slur_regex
+      .find_ranges(test)
+      .into_iter()
+      .map(|range| &test[range])
+      .collect();
 
     // Unique
     matches.sort_unstable();
@@ -30,16 +161,17 @@ pub(crate) fn slur_check<'a>(
   }
 }
 
-pub fn build_slur_regex(regex_str: Option<&str>) -> Option<Regex> {
+pub fn build_slur_regex(regex_str: Option<&str>, normalize: bool) -> Option<SlurRegex> {
   regex_str.map(|slurs| {
-    RegexBuilder::new(slurs)
+    let regex = RegexBuilder::new(slurs)
       .case_insensitive(true)
       .build()
-      .expect("compile regex")
+      .expect("compile regex");
+    SlurRegex::new(regex, normalize)
   })
 }
 
-pub fn check_slurs(text: &str, slur_regex: &Option<Regex>) -> LemmyResult<()> {
+pub fn check_slurs(text: &str, slur_regex: &Option<SlurRegex>) -> LemmyResult<()> {
   if let Err(slurs) = slur_check(text, slur_regex) {
     Err(anyhow::anyhow!("{}", slurs_vec_to_str(&slurs))).with_lemmy_type(LemmyErrorType::Slurs)
   } else {
@@ -47,7 +179,7 @@ pub fn check_slurs(text: &str, slur_regex: &Option<Regex>) -> LemmyResult<()> {
   }
 }
 
-pub fn check_slurs_opt(text: &Option<String>, slur_regex: &Option<Regex>) -> LemmyResult<()> {
+pub fn check_slurs_opt(text: &Option<String>, slur_regex: &Option<SlurRegex>) -> LemmyResult<()> {
   match text {
     Some(t) => check_slurs(t, slur_regex),
     None => Ok(()),
@@ -65,14 +197,18 @@ mod test {
 
   use crate::{
     error::LemmyResult,
-    utils::slurs::{remove_slurs, slur_check, slurs_vec_to_str},
+    utils::slurs::{remove_slurs, slur_check, slurs_vec_to_str, SlurRegex},
   };
   use pretty_assertions::assert_eq;
-  use regex::RegexBuilder;
+  use regex::{Regex, RegexBuilder};
+
+  fn test_regex() -> LemmyResult<Regex> {
+    Ok(RegexBuilder::new(r"(fag(g|got|tard)?\b|cock\s?sucker(s|ing)?|ni((g{2,}|q)+|[gq]{2,})[e3r]+(s|z)?|mudslime?s?|kikes?|\bspi(c|k)s?\b|\bchinks?|gooks?|bitch(es|ing|y)?|whor(es?|ing)|\btr(a|@)nn?(y|ies?)|\b(b|re|r)tard(ed)?s?)").case_insensitive(true).build()?)
+  }
 
   #[test]
   fn test_slur_filter() -> LemmyResult<()> {
-    let slur_regex = Some(RegexBuilder::new(r"(fag(g|got|tard)?\b|cock\s?sucker(s|ing)?|ni((g{2,}|q)+|[gq]{2,})[e3r]+(s|z)?|mudslime?s?|kikes?|\bspi(c|k)s?\b|\bchinks?|gooks?|bitch(es|ing|y)?|whor(es?|ing)|\btr(a|@)nn?(y|ies?)|\b(b|re|r)tard(ed)?s?)").case_insensitive(true).build()?);
+    let slur_regex = Some(SlurRegex::new(test_regex()?, false));
     let test =
       "faggot test kike tranny cocksucker retardeds. Capitalized Niggerz. This is a bunch of other safe text.";
     let slur_free = "No slurs here";
@@ -101,6 +237,49 @@ mod test {
     Ok(())
   }
 
+  #[test]
+  fn test_slur_filter_homoglyphs() -> LemmyResult<()> {
+    let plain = Some(SlurRegex::new(test_regex()?, false));
+    let normalized = Some(SlurRegex::new(test_regex()?, true));
+
+    // Each of these evades the plain filter
+    let corpus = [
+      // Cyrillic lookalikes
+      "kіkе",
+      "сocksucker",
+      "whоre",
+      // Greek lookalikes
+      "bιtch",
+      // Fullwidth and mathematical letters
+      "ｋｉｋｅ",
+      "𝐛𝐢𝐭𝐜𝐡",
+      // Diacritics
+      "kïkë",
+      "ḅíṭčh",
+      // Invisible characters
+      "ki\u{200B}ke",
+      "bit\u{00AD}ch",
+    ];
+    for text in corpus {
+      assert_eq!(Ok(()), slur_check(text, &plain), "{text}");
+      assert_eq!(Err(vec![text]), slur_check(text, &normalized), "{text}");
+    }
+
+    // The original text is kept outside of matches
+    assert_eq!(
+      "ｈｅｌｌｏ *removed* ☃ *removed*.",
+      remove_slurs("ｈｅｌｌｏ kіkе ☃ ｗｈｏｒｅ.", &normalized)
+    );
+
+    // Legitimate text in other scripts isn't affected
+    let safe = ["привет мир", "Καλημέρα", "naïve café", "日本語のテキスト"];
+    for text in safe {
+      assert_eq!(Ok(()), slur_check(text, &normalized), "{text}");
+      assert_eq!(text, remove_slurs(text, &normalized));
+    }
+    Ok(())
+  }
+
   // These helped with testing
   // #[test]
   // fn test_send_email() {
@@ -13,6 +13,13 @@ static VALID_MATRIX_ID_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 // taken from https://en.wikipedia.org/wiki/UTM_parameters
 static URL_CLEANER: LazyLock<UrlCleaner> =
   LazyLock::new(|| UrlCleaner::from_embedded_rules().expect("compile clearurls"));
+static DISPOSABLE_EMAIL_DOMAINS: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
+  include_str!("disposable_email_domains.txt")
+    .lines()
+    .map(str::trim)
+    .filter(|l| !l.is_empty() && !l.starts_with('#'))
+    .collect()
+});
 const ALLOWED_POST_URL_SCHEMES: [&str; 3] = ["http", "https", "magnet"];
 
 const BODY_MAX_LENGTH: usize = 10000;
@@ -23,6 +30,8 @@ const ALT_TEXT_MAX_LENGTH: usize = 1500;
 const SITE_NAME_MAX_LENGTH: usize = 20;
 const SITE_NAME_MIN_LENGTH: usize = 1;
 const SITE_DESCRIPTION_MAX_LENGTH: usize = 150;
+const CLIENT_NAME_MAX_LENGTH: usize = 100;
+const POST_TAG_MAX_LENGTH: usize = 50;
 //Invisible unicode characters, taken from https://invisible-characters.com/
 const FORBIDDEN_DISPLAY_CHARS: [char; 53] = [
   '\u{0009}',
@@ -174,6 +183,54 @@ pub fn is_valid_alt_text_field(alt_text: &str) -> LemmyResult<()> {
   Ok(())
 }
 
+/// Checks a client identifier as sent in the `X-Lemmy-Client` header. It needs to consist of
+/// visible ascii characters, like `my-app/1.2`.
+pub fn is_valid_client_name(name: &str) -> LemmyResult<()> {
+  let valid = !name.is_empty()
+    && name.len() <= CLIENT_NAME_MAX_LENGTH
+    && name.chars().all(|c| c.is_ascii_graphic());
+  if valid {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidClientRestriction.into())
+  }
+}
+
+/// Checks the name of a community post tag. It can't be empty or contain line breaks.
+pub fn is_valid_post_tag_name(name: &str) -> LemmyResult<()> {
+  let valid = !name.trim().is_empty()
+    && name.trim() == name
+    && name.chars().count() <= POST_TAG_MAX_LENGTH
+    && !name.chars().any(char::is_control);
+  if valid {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidPostTag.into())
+  }
+}
+
+/// Checks that LDAP login is either fully configured or not at all. Empty values count as unset.
+pub fn is_valid_ldap_settings(
+  url: Option<&str>,
+  user_dn_template: Option<&str>,
+) -> LemmyResult<()> {
+  let url = url.filter(|u| !u.is_empty());
+  let user_dn_template = user_dn_template.filter(|t| !t.is_empty());
+  let valid = match (url, user_dn_template) {
+    (None, None) => true,
+    (Some(url), Some(user_dn_template)) => {
+      Url::parse(url).is_ok_and(|u| ["ldap", "ldaps"].contains(&u.scheme()))
+        && user_dn_template.contains("{username}")
+    }
+    _ => false,
+  };
+  if valid {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidLdapSettings.into())
+  }
+}
+
 /// Checks the site name length, the limit as defined in the DB.
 pub fn site_name_length_check(name: &str) -> LemmyResult<()> {
   min_length_check(name, SITE_NAME_MIN_LENGTH, LemmyErrorType::SiteNameRequired)?;
@@ -343,6 +400,77 @@ pub fn build_url_str_without_scheme(url_str: &str) -> LemmyResult<String> {
   Ok(out)
 }
 
+/// Normalizes a domain to lowercase, and checks that it is a plain domain without scheme, port or
+/// path.
+pub fn clean_domain(domain: &str) -> LemmyResult<String> {
+  let domain = domain.trim().to_lowercase();
+  let url =
+    Url::parse(&format!("https://{domain}/")).with_lemmy_type(LemmyErrorType::InvalidDomain)?;
+  if url.host_str() != Some(&domain) || url.port().is_some() {
+    Err(LemmyErrorType::InvalidDomain)?
+  }
+  Ok(domain)
+}
+
+/// Normalizes an entry of the email domain allowlist or blocklist. A leading `*.` makes it match
+/// all subdomains.
+pub fn clean_email_domain(domain: &str) -> LemmyResult<String> {
+  let domain = domain.trim();
+  let (wildcard, parent) = match domain.strip_prefix("*.") {
+    Some(parent) => (true, parent),
+    None => (false, domain),
+  };
+  if parent.contains('*') {
+    Err(LemmyErrorType::InvalidDomain)?
+  }
+  let parent = clean_domain(parent)?;
+  Ok(if wildcard {
+    format!("*.{parent}")
+  } else {
+    parent
+  })
+}
+
+fn is_subdomain(domain: &str, parent: &str) -> bool {
+  domain
+    .strip_suffix(parent)
+    .is_some_and(|sub| sub.ends_with('.'))
+}
+
+fn email_domain_matches(domain: &str, pattern: &str) -> bool {
+  match pattern.strip_prefix("*.") {
+    Some(parent) => is_subdomain(domain, parent),
+    None => domain == pattern,
+  }
+}
+
+/// Checks that the domain of an email address may be used for signups. Blocked domains, including
+/// the disposable email providers if enabled, are rejected. If the allowlist is not empty, only
+/// domains in it are accepted.
+pub fn check_email_domain(
+  email: &str,
+  allowed: &[String],
+  blocked: &[String],
+  block_disposable: bool,
+) -> LemmyResult<()> {
+  let domain = email
+    .rsplit_once('@')
+    .map(|(_, domain)| domain.trim().to_lowercase())
+    .unwrap_or_default();
+
+  let is_blocked = blocked.iter().any(|b| email_domain_matches(&domain, b));
+  let is_disposable = block_disposable
+    && DISPOSABLE_EMAIL_DOMAINS
+      .iter()
+      .any(|d| domain == *d || is_subdomain(&domain, d));
+  let is_allowed = allowed.is_empty() || allowed.iter().any(|a| email_domain_matches(&domain, a));
+
+  if is_blocked || is_disposable || !is_allowed {
+    Err(LemmyErrorType::EmailDomainNotAllowed)?
+  }
+  Ok(())
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -350,15 +478,21 @@ mod tests {
     error::{LemmyErrorType, LemmyResult},
     utils::validation::{
       build_and_check_regex,
+      check_email_domain,
       check_site_visibility_valid,
       check_urls_are_valid,
+      clean_domain,
+      clean_email_domain,
       clean_url,
       clean_urls_in_text,
       is_url_blocked,
       is_valid_actor_name,
       is_valid_bio_field,
+      is_valid_client_name,
       is_valid_display_name,
+      is_valid_ldap_settings,
       is_valid_matrix_id,
+      is_valid_post_tag_name,
       is_valid_post_title,
       is_valid_url,
       site_name_length_check,
@@ -540,6 +674,34 @@ Line3",
     );
   }
 
+  #[test]
+  fn test_valid_client_name() {
+    assert!(is_valid_client_name("my-app/1.2").is_ok());
+    assert!(is_valid_client_name("").is_err());
+    assert!(is_valid_client_name("my app").is_err());
+    assert!(is_valid_client_name(&"a".repeat(101)).is_err());
+  }
+
+  #[test]
+  fn test_valid_post_tag_name() {
+    assert!(is_valid_post_tag_name("Announcement").is_ok());
+    assert!(is_valid_post_tag_name("").is_err());
+    assert!(is_valid_post_tag_name(" padded ").is_err());
+    assert!(is_valid_post_tag_name("two\nlines").is_err());
+    assert!(is_valid_post_tag_name(&"a".repeat(51)).is_err());
+  }
+
+  #[test]
+  fn test_valid_ldap_settings() {
+    let template = Some("uid={username},ou=people,dc=example,dc=com");
+    assert!(is_valid_ldap_settings(None, None).is_ok());
+    assert!(is_valid_ldap_settings(Some(""), Some("")).is_ok());
+    assert!(is_valid_ldap_settings(Some("ldaps://ldap.example.com"), template).is_ok());
+    assert!(is_valid_ldap_settings(Some("https://ldap.example.com"), template).is_err());
+    assert!(is_valid_ldap_settings(Some("ldap://ldap.example.com"), None).is_err());
+    assert!(is_valid_ldap_settings(Some("ldap://ldap.example.com"), Some("ou=people")).is_err());
+  }
+
   #[test]
   fn test_valid_site_description() {
     assert!(site_or_community_description_length_check(
@@ -671,4 +833,45 @@ Line3",
     assert!(check_urls_are_valid(&vec!["https://example .com".to_string()]).is_err());
     Ok(())
   }
+
+  #[test]
+  fn test_clean_domain() {
+    assert_eq!(
+      Some("example.com".to_string()),
+      clean_domain(" Example.com ").ok()
+    );
+    assert!(clean_domain("https://example.com").is_err());
+    assert!(clean_domain("example.com/path").is_err());
+    assert!(clean_domain("example.com:8080").is_err());
+    assert!(clean_domain("").is_err());
+  }
+
+  #[test]
+  fn test_clean_email_domain() {
+    assert_eq!(
+      Some("*.example.com".to_string()),
+      clean_email_domain(" *.Example.com").ok()
+    );
+    assert!(clean_email_domain("*example.com").is_err());
+    assert!(clean_email_domain("user@example.com").is_err());
+  }
+
+  #[test]
+  fn test_check_email_domain() {
+    let allowed = vec!["example.com".to_string(), "*.uni.example".to_string()];
+    let blocked = vec!["*.spam.example".to_string()];
+
+    assert!(check_email_domain("a@Example.com", &allowed, &[], false).is_ok());
+    assert!(check_email_domain("a@cs.uni.example", &allowed, &[], false).is_ok());
+    // Wildcards only match subdomains
+    assert!(check_email_domain("a@uni.example", &allowed, &[], false).is_err());
+    assert!(check_email_domain("a@notexample.com", &allowed, &[], false).is_err());
+
+    assert!(check_email_domain("a@other.example", &[], &blocked, false).is_ok());
+    assert!(check_email_domain("a@mx.spam.example", &[], &blocked, false).is_err());
+
+    assert!(check_email_domain("a@mailinator.com", &[], &[], false).is_ok());
+    assert!(check_email_domain("a@mailinator.com", &[], &[], true).is_err());
+    assert!(check_email_domain("a@eu.mailinator.com", &[], &[], true).is_err());
+  }
 }
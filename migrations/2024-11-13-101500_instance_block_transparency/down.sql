@@ -0,0 +1,6 @@
+ALTER TABLE federation_blocklist
+    DROP COLUMN reason;
+
+ALTER TABLE local_site
+    DROP COLUMN instance_blocks_public;
+
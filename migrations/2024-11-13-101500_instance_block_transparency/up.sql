@@ -0,0 +1,6 @@
+ALTER TABLE federation_blocklist
+    ADD COLUMN reason text;
+
+ALTER TABLE local_site
+    ADD COLUMN instance_blocks_public boolean DEFAULT FALSE NOT NULL;
+
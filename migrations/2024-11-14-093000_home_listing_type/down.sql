@@ -0,0 +1,53 @@
+ALTER TABLE local_user
+    DROP COLUMN home_discovery_percent;
+
+ALTER TABLE local_user
+    ALTER default_listing_type DROP DEFAULT;
+
+ALTER TABLE local_site
+    ALTER default_post_listing_type DROP DEFAULT;
+
+UPDATE
+    local_user
+SET
+    default_listing_type = 'Subscribed'
+WHERE
+    default_listing_type = 'Home';
+
+UPDATE
+    local_site
+SET
+    default_post_listing_type = 'Subscribed'
+WHERE
+    default_post_listing_type = 'Home';
+
+-- rename the old enum
+ALTER TYPE listing_type_enum RENAME TO listing_type_enum__;
+
+-- create the new enum
+CREATE TYPE listing_type_enum AS ENUM (
+    'All',
+    'Local',
+    'Subscribed',
+    'ModeratorView'
+);
+
+-- alter all your enum columns
+ALTER TABLE local_user
+    ALTER COLUMN default_listing_type TYPE listing_type_enum
+    USING default_listing_type::text::listing_type_enum;
+
+ALTER TABLE local_site
+    ALTER COLUMN default_post_listing_type TYPE listing_type_enum
+    USING default_post_listing_type::text::listing_type_enum;
+
+-- Add back in the default
+ALTER TABLE local_user
+    ALTER default_listing_type SET DEFAULT 'Local';
+
+ALTER TABLE local_site
+    ALTER default_post_listing_type SET DEFAULT 'Local';
+
+-- drop the old enum
+DROP TYPE listing_type_enum__;
+
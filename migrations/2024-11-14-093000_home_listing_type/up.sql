@@ -0,0 +1,6 @@
+ALTER TYPE listing_type_enum
+    ADD VALUE 'Home';
+
+ALTER TABLE local_user
+    ADD COLUMN home_discovery_percent smallint DEFAULT 20 NOT NULL CHECK (home_discovery_percent BETWEEN 0 AND 100);
+
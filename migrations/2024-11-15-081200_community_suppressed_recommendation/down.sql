@@ -0,0 +1,3 @@
+ALTER TABLE community_actions
+    DROP COLUMN suppressed_recommendation;
+
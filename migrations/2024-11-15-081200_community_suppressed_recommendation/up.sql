@@ -0,0 +1,3 @@
+ALTER TABLE community_actions
+    ADD COLUMN suppressed_recommendation timestamptz;
+
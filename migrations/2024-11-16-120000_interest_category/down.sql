@@ -0,0 +1,4 @@
+DROP TABLE interest_category_community;
+
+DROP TABLE interest_category;
+
@@ -0,0 +1,15 @@
+CREATE TABLE interest_category (
+    id serial PRIMARY KEY,
+    name varchar(100) NOT NULL UNIQUE,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+-- Communities which admins picked for a category. These are shown first during onboarding.
+CREATE TABLE interest_category_community (
+    category_id int REFERENCES interest_category ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (category_id, community_id)
+);
+
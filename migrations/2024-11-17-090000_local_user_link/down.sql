@@ -0,0 +1,5 @@
+ALTER TABLE login_token
+    DROP COLUMN linked_from_user_id;
+
+DROP TABLE local_user_link;
+
@@ -0,0 +1,14 @@
+-- Accounts on this instance which a user proved to control, so that clients can switch between
+-- them. Links are stored in both directions.
+CREATE TABLE local_user_link (
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    linked_local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (local_user_id, linked_local_user_id),
+    CHECK (local_user_id <> linked_local_user_id)
+);
+
+-- Keeps track of which account a login token was issued from, when switching accounts
+ALTER TABLE login_token
+    ADD COLUMN linked_from_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE SET NULL;
+
@@ -0,0 +1,6 @@
+DROP TABLE community_bot_action;
+
+DROP TABLE community_bot_delegation;
+
+DROP TYPE community_bot_scope_enum;
+
@@ -0,0 +1,43 @@
+CREATE TYPE community_bot_scope_enum AS enum (
+    'RemovePost',
+    'RemoveComment',
+    'LockPost',
+    'FeaturePost'
+);
+
+-- Allows a bot account to perform some moderation actions in a community, with the authority of
+-- the moderator who created the delegation.
+CREATE TABLE community_bot_delegation (
+    id serial PRIMARY KEY,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    bot_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    can_remove_posts boolean DEFAULT FALSE NOT NULL,
+    can_remove_comments boolean DEFAULT FALSE NOT NULL,
+    can_lock_posts boolean DEFAULT FALSE NOT NULL,
+    can_feature_posts boolean DEFAULT FALSE NOT NULL,
+    revoked boolean DEFAULT FALSE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+-- Only one active delegation per bot and community
+CREATE UNIQUE INDEX idx_community_bot_delegation_active ON community_bot_delegation (community_id, bot_person_id)
+WHERE
+    NOT revoked;
+
+-- Audit log of all actions which bots performed through a delegation
+CREATE TABLE community_bot_action (
+    id serial PRIMARY KEY,
+    delegation_id int REFERENCES community_bot_delegation ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    scope community_bot_scope_enum NOT NULL,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    -- false if the action was reverted, eg restoring a post
+    enabled boolean NOT NULL,
+    reason text,
+    published timestamptz DEFAULT now() NOT NULL
+);
+
+CREATE INDEX idx_community_bot_action_delegation ON community_bot_action (delegation_id);
+
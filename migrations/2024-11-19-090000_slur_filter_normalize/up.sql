@@ -0,0 +1,3 @@
+ALTER TABLE local_site
+    ADD COLUMN slur_filter_normalize boolean DEFAULT FALSE NOT NULL;
+
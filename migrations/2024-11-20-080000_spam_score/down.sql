@@ -0,0 +1,11 @@
+DROP TABLE spam_score;
+
+ALTER TABLE local_site
+    DROP COLUMN spam_link_density_weight,
+    DROP COLUMN spam_repetition_weight,
+    DROP COLUMN spam_account_age_weight,
+    DROP COLUMN spam_hold_threshold,
+    DROP COLUMN spam_remove_threshold;
+
+DROP TYPE spam_action_enum;
+
@@ -0,0 +1,39 @@
+CREATE TYPE spam_action_enum AS enum (
+    'None',
+    'Hold',
+    'Remove'
+);
+
+-- Weights of the individual heuristics, and the scores at which content is held for review or
+-- removed. Without thresholds the score is only recorded.
+ALTER TABLE local_site
+    ADD COLUMN spam_link_density_weight int DEFAULT 40 NOT NULL CHECK (spam_link_density_weight >= 0),
+    ADD COLUMN spam_repetition_weight int DEFAULT 40 NOT NULL CHECK (spam_repetition_weight >= 0),
+    ADD COLUMN spam_account_age_weight int DEFAULT 20 NOT NULL CHECK (spam_account_age_weight >= 0),
+    ADD COLUMN spam_hold_threshold int,
+    ADD COLUMN spam_remove_threshold int;
+
+-- The spam score of each local post and comment. For comments, post_id is the post which they
+-- belong to.
+CREATE TABLE spam_score (
+    id serial PRIMARY KEY,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    score int NOT NULL,
+    action spam_action_enum NOT NULL,
+    -- Set once an admin approved or rejected held or removed content
+    approved boolean,
+    reviewer_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+CREATE INDEX idx_spam_score_review_queue ON spam_score (published)
+WHERE
+    action != 'None' AND approved IS NULL;
+
+CREATE INDEX idx_spam_score_post ON spam_score (post_id);
+
+CREATE INDEX idx_spam_score_comment ON spam_score (comment_id);
+
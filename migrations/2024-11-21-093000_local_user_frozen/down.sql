@@ -0,0 +1,5 @@
+DROP TABLE admin_freeze_account;
+
+ALTER TABLE local_user
+    DROP COLUMN frozen;
+
@@ -0,0 +1,17 @@
+-- A frozen account can't login or post while a compromise is investigated. Unlike a ban this isn't
+-- visible to other users.
+ALTER TABLE local_user
+    ADD COLUMN frozen boolean DEFAULT FALSE NOT NULL;
+
+-- Not part of the public modlog, as freezing is meant to be discreet
+CREATE TABLE admin_freeze_account (
+    id serial PRIMARY KEY,
+    admin_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    other_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    reason text,
+    frozen boolean DEFAULT TRUE NOT NULL,
+    when_ timestamptz DEFAULT now() NOT NULL
+);
+
+CREATE INDEX idx_admin_freeze_account_other_person ON admin_freeze_account (other_person_id);
+
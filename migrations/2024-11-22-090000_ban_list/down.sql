@@ -0,0 +1,5 @@
+ALTER TABLE community_actions
+    DROP COLUMN ban_list_id;
+
+DROP TABLE ban_list_exemption, ban_list_entry, ban_list_community, ban_list;
+
@@ -0,0 +1,43 @@
+-- A ban list is shared by a group of communities. Bans added to the list are applied to every
+-- subscribed community, unless that community has exempted the person.
+CREATE TABLE ban_list (
+    id serial PRIMARY KEY,
+    name varchar(255) NOT NULL,
+    description text,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+CREATE TABLE ban_list_community (
+    ban_list_id int REFERENCES ban_list ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (ban_list_id, community_id)
+);
+
+CREATE INDEX idx_ban_list_community_community ON ban_list_community (community_id);
+
+CREATE TABLE ban_list_entry (
+    ban_list_id int REFERENCES ban_list ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    reason text,
+    expires timestamptz,
+    published timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (ban_list_id, person_id)
+);
+
+CREATE TABLE ban_list_exemption (
+    ban_list_id int REFERENCES ban_list ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (ban_list_id, community_id, person_id)
+);
+
+-- Set when a community ban was applied by a ban list, so that it can be lifted again when the
+-- entry is removed or the community unsubscribes. Direct bans leave this null.
+ALTER TABLE community_actions
+    ADD COLUMN ban_list_id int REFERENCES ban_list ON UPDATE CASCADE ON DELETE SET NULL;
+
@@ -0,0 +1,2 @@
+DROP TABLE community_mirror_action, community_mirror;
+
@@ -0,0 +1,30 @@
+-- An opt-in agreement of a local community to apply the bans and removals of a remote partner
+-- community which covers the same topic.
+CREATE TABLE community_mirror (
+    id serial PRIMARY KEY,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    partner_community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    mirror_bans boolean DEFAULT TRUE NOT NULL,
+    mirror_removals boolean DEFAULT TRUE NOT NULL,
+    enabled boolean DEFAULT TRUE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz,
+    UNIQUE (community_id, partner_community_id)
+);
+
+CREATE INDEX idx_community_mirror_partner ON community_mirror (partner_community_id);
+
+-- Audit trail of the actions which were applied because of a mirror agreement
+CREATE TABLE community_mirror_action (
+    id serial PRIMARY KEY,
+    mirror_id int REFERENCES community_mirror ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    mod_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    other_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    reason text,
+    when_ timestamptz DEFAULT now() NOT NULL
+);
+
+CREATE INDEX idx_community_mirror_action_mirror ON community_mirror_action (mirror_id);
+
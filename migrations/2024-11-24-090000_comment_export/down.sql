@@ -0,0 +1,3 @@
+ALTER TABLE community
+    DROP COLUMN comment_export_public;
+
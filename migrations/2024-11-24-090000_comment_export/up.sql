@@ -0,0 +1,5 @@
+-- Whether anyone may export the full comment tree of posts in the community. Moderators and
+-- admins can always export.
+ALTER TABLE community
+    ADD COLUMN comment_export_public boolean DEFAULT FALSE NOT NULL;
+
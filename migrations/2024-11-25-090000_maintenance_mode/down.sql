@@ -0,0 +1,5 @@
+ALTER TABLE local_site
+    DROP COLUMN maintenance_mode;
+
+DROP TABLE queued_activity;
+
@@ -0,0 +1,11 @@
+ALTER TABLE local_site
+    ADD COLUMN maintenance_mode boolean DEFAULT FALSE NOT NULL;
+
+-- Activities received while the site is in maintenance mode, processed once it is turned off again
+CREATE TABLE queued_activity (
+    id serial PRIMARY KEY,
+    actor_id text NOT NULL,
+    data jsonb NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL
+);
+
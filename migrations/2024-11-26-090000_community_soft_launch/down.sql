@@ -0,0 +1,6 @@
+ALTER TABLE local_site
+    DROP COLUMN community_soft_launch_posts;
+
+ALTER TABLE community
+    DROP COLUMN soft_launched;
+
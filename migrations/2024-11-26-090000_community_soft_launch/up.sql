@@ -0,0 +1,8 @@
+-- New local communities can be kept out of Local/All listings and search until they have enough
+-- posts, or an admin launches them
+ALTER TABLE local_site
+    ADD COLUMN community_soft_launch_posts int;
+
+ALTER TABLE community
+    ADD COLUMN soft_launched boolean DEFAULT FALSE NOT NULL;
+
@@ -0,0 +1,12 @@
+-- Names which can't be used for new communities. Either the old name of a renamed community,
+-- which is still resolved to it, or a name reserved by an admin (community_id is null).
+CREATE TABLE community_name_alias (
+    name text PRIMARY KEY,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    reason text,
+    published timestamptz DEFAULT now() NOT NULL
+);
+
+CREATE INDEX idx_community_name_alias_community ON community_name_alias (community_id);
+
@@ -0,0 +1,8 @@
+DROP TABLE image_text;
+
+ALTER TABLE local_user
+    DROP COLUMN image_text_recognition;
+
+ALTER TABLE community
+    DROP COLUMN image_text_search;
+
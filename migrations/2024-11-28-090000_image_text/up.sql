@@ -0,0 +1,17 @@
+-- Text which an external OCR service recognized in uploaded images, so that posts with these
+-- images can be found with search
+CREATE TABLE image_text (
+    pictrs_alias text PRIMARY KEY REFERENCES local_image ON UPDATE CASCADE ON DELETE CASCADE,
+    link text NOT NULL,
+    text text NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL
+);
+
+CREATE INDEX idx_image_text_link ON image_text (link);
+
+ALTER TABLE local_user
+    ADD COLUMN image_text_recognition boolean DEFAULT TRUE NOT NULL;
+
+ALTER TABLE community
+    ADD COLUMN image_text_search boolean DEFAULT TRUE NOT NULL;
+
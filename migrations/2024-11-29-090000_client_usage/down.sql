@@ -0,0 +1,2 @@
+DROP TABLE client_usage, client_restriction;
+
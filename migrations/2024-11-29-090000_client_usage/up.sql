@@ -0,0 +1,20 @@
+-- Number of requests per day made by each client which identifies itself with the
+-- X-Lemmy-Client header.
+CREATE TABLE client_usage (
+    client text NOT NULL,
+    day timestamptz NOT NULL,
+    request_count bigint DEFAULT 0 NOT NULL,
+    PRIMARY KEY (client, day)
+);
+
+CREATE INDEX idx_client_usage_day ON client_usage (day);
+
+-- Clients which are throttled or blocked instance-wide. If requests_per_minute is null, the
+-- client is blocked entirely.
+CREATE TABLE client_restriction (
+    client text PRIMARY KEY,
+    requests_per_minute int,
+    reason text,
+    published timestamptz DEFAULT now() NOT NULL
+);
+
@@ -0,0 +1,2 @@
+DROP TABLE transparency_report;
+
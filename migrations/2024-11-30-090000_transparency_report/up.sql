@@ -0,0 +1,24 @@
+-- Quarterly counts of moderation actions and reports, for each local community and for the
+-- whole instance (community_id is null).
+CREATE TABLE transparency_report (
+    id serial PRIMARY KEY,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    period_start timestamptz NOT NULL,
+    period_end timestamptz NOT NULL,
+    posts_removed bigint DEFAULT 0 NOT NULL,
+    comments_removed bigint DEFAULT 0 NOT NULL,
+    communities_removed bigint DEFAULT 0 NOT NULL,
+    site_bans bigint DEFAULT 0 NOT NULL,
+    community_bans bigint DEFAULT 0 NOT NULL,
+    post_reports bigint DEFAULT 0 NOT NULL,
+    comment_reports bigint DEFAULT 0 NOT NULL,
+    private_message_reports bigint DEFAULT 0 NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL
+);
+
+CREATE UNIQUE INDEX idx_transparency_report_community_period ON transparency_report (community_id, period_start);
+
+CREATE UNIQUE INDEX idx_transparency_report_instance_period ON transparency_report (period_start)
+WHERE
+    community_id IS NULL;
+
@@ -0,0 +1,26 @@
+UPDATE
+    post
+SET
+    body = post_archive.body
+FROM
+    post_archive
+WHERE
+    post.id = post_archive.post_id;
+
+UPDATE
+    comment
+SET
+    content = comment_archive.content
+FROM
+    comment_archive
+WHERE
+    comment.id = comment_archive.comment_id;
+
+DROP TABLE post_archive, comment_archive;
+
+ALTER TABLE post
+    DROP COLUMN archived;
+
+ALTER TABLE comment
+    DROP COLUMN archived;
+
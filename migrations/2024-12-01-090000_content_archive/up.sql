@@ -0,0 +1,25 @@
+-- The text of old posts and comments with little engagement is moved out of the post and comment
+-- tables into these archive tables. The low toast_tuple_target makes postgres compress even short
+-- texts.
+ALTER TABLE post
+    ADD COLUMN archived boolean DEFAULT FALSE NOT NULL;
+
+ALTER TABLE comment
+    ADD COLUMN archived boolean DEFAULT FALSE NOT NULL;
+
+CREATE TABLE post_archive (
+    post_id int PRIMARY KEY REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    body text NOT NULL
+)
+WITH (
+    toast_tuple_target = 128
+);
+
+CREATE TABLE comment_archive (
+    comment_id int PRIMARY KEY REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    content text NOT NULL
+)
+WITH (
+    toast_tuple_target = 128
+);
+
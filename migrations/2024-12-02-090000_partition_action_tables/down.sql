@@ -0,0 +1,79 @@
+ALTER TABLE post_actions RENAME TO post_actions_partitioned;
+
+ALTER TABLE comment_actions RENAME TO comment_actions_partitioned;
+
+CREATE TABLE post_actions (
+    LIKE post_actions_partitioned INCLUDING DEFAULTS INCLUDING CONSTRAINTS
+);
+
+CREATE TABLE comment_actions (
+    LIKE comment_actions_partitioned INCLUDING DEFAULTS INCLUDING CONSTRAINTS
+);
+
+INSERT INTO post_actions
+SELECT
+    *
+FROM
+    post_actions_partitioned;
+
+INSERT INTO comment_actions
+SELECT
+    *
+FROM
+    comment_actions_partitioned;
+
+DROP TABLE post_actions_partitioned, comment_actions_partitioned;
+
+ALTER TABLE post_actions
+    ADD PRIMARY KEY (person_id, post_id),
+    ADD CONSTRAINT post_actions_person_id_fkey FOREIGN KEY (person_id) REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    ADD CONSTRAINT post_actions_post_id_fkey FOREIGN KEY (post_id) REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE;
+
+ALTER TABLE comment_actions
+    ADD PRIMARY KEY (person_id, comment_id),
+    ADD CONSTRAINT comment_actions_person_id_fkey FOREIGN KEY (person_id) REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    ADD CONSTRAINT comment_actions_comment_id_fkey FOREIGN KEY (comment_id) REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE;
+
+CREATE INDEX idx_post_actions_person ON post_actions (person_id);
+
+CREATE INDEX idx_post_actions_post ON post_actions (post_id);
+
+CREATE INDEX idx_comment_actions_comment ON comment_actions (comment_id);
+
+CREATE INDEX idx_post_actions_read_not_null ON post_actions (person_id, post_id)
+WHERE
+    read IS NOT NULL;
+
+CREATE INDEX idx_post_actions_read_comments_not_null ON post_actions (person_id, post_id)
+WHERE
+    read_comments IS NOT NULL OR read_comments_amount IS NOT NULL;
+
+CREATE INDEX idx_post_actions_saved_not_null ON post_actions (person_id, post_id)
+WHERE
+    saved IS NOT NULL;
+
+CREATE INDEX idx_post_actions_liked_not_null ON post_actions (person_id, post_id)
+WHERE
+    liked IS NOT NULL OR like_score IS NOT NULL;
+
+CREATE INDEX idx_post_actions_hidden_not_null ON post_actions (person_id, post_id)
+WHERE
+    hidden IS NOT NULL;
+
+CREATE INDEX idx_comment_actions_liked_not_null ON comment_actions (person_id, comment_id)
+WHERE
+    liked IS NOT NULL OR like_score IS NOT NULL;
+
+CREATE INDEX idx_comment_actions_saved_not_null ON comment_actions (person_id, comment_id)
+WHERE
+    saved IS NOT NULL;
+
+CREATE statistics comment_actions_liked_stat ON (liked IS NULL), (like_score IS NULL)
+FROM comment_actions;
+
+CREATE statistics post_actions_read_comments_stat ON (read_comments IS NULL), (read_comments_amount IS NULL)
+FROM post_actions;
+
+CREATE statistics post_actions_liked_stat ON (liked IS NULL), (like_score IS NULL), (post_id IS NULL)
+FROM post_actions;
+
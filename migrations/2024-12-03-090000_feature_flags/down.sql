@@ -0,0 +1,4 @@
+DROP TABLE feature_flag;
+
+DROP TYPE feature_flag_name_enum;
+
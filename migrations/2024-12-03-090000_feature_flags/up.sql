@@ -0,0 +1,17 @@
+-- Experimental subsystems which admins can roll out gradually.
+CREATE TYPE feature_flag_name_enum AS enum (
+    'Reactions',
+    'Polls',
+    'ClientToServer'
+);
+
+-- A missing row means that the feature is disabled. rollout_percentage is the share of local
+-- users who get the feature while it is enabled.
+CREATE TABLE feature_flag (
+    feature feature_flag_name_enum PRIMARY KEY,
+    enabled boolean DEFAULT FALSE NOT NULL,
+    rollout_percentage smallint DEFAULT 100 NOT NULL CHECK (rollout_percentage BETWEEN 0 AND 100),
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
@@ -0,0 +1,11 @@
+ALTER TABLE local_site
+    DROP COLUMN default_show_nsfw,
+    DROP COLUMN default_show_bot_accounts,
+    DROP COLUMN default_send_notifications_to_email,
+    DROP COLUMN default_interface_language;
+
+ALTER TABLE local_user
+    DROP COLUMN customized_settings;
+
+DROP TYPE default_user_setting_enum;
+
@@ -0,0 +1,22 @@
+-- Defaults for new accounts, in addition to the existing default sort and listing types.
+ALTER TABLE local_site
+    ADD COLUMN default_show_nsfw boolean DEFAULT FALSE NOT NULL,
+    ADD COLUMN default_show_bot_accounts boolean DEFAULT TRUE NOT NULL,
+    ADD COLUMN default_send_notifications_to_email boolean DEFAULT FALSE NOT NULL,
+    ADD COLUMN default_interface_language varchar(20) DEFAULT 'browser' NOT NULL;
+
+CREATE TYPE default_user_setting_enum AS enum (
+    'PostSortType',
+    'CommentSortType',
+    'ListingType',
+    'ShowNsfw',
+    'ShowBotAccounts',
+    'SendNotificationsToEmail',
+    'InterfaceLanguage'
+);
+
+-- Settings which the user changed. These are left alone when admins apply new defaults to
+-- existing users.
+ALTER TABLE local_user
+    ADD COLUMN customized_settings default_user_setting_enum[] DEFAULT '{}' NOT NULL;
+
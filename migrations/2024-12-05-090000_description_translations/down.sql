@@ -0,0 +1,2 @@
+DROP TABLE community_description_translation, site_description_translation;
+
@@ -0,0 +1,22 @@
+-- Translations of the sidebar and description of communities and the site, for multilingual
+-- instances. Fields which are null fall back to the original text.
+CREATE TABLE community_description_translation (
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    language_id int REFERENCES LANGUAGE ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    sidebar text,
+    description varchar(150),
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz,
+    PRIMARY KEY (community_id, language_id)
+);
+
+CREATE TABLE site_description_translation (
+    site_id int REFERENCES site ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    language_id int REFERENCES LANGUAGE ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    sidebar text,
+    description varchar(150),
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz,
+    PRIMARY KEY (site_id, language_id)
+);
+
@@ -0,0 +1,6 @@
+ALTER TABLE local_user
+    DROP COLUMN quiet_hours_enabled,
+    DROP COLUMN quiet_hours_start,
+    DROP COLUMN quiet_hours_end,
+    DROP COLUMN quiet_hours_timezone;
+
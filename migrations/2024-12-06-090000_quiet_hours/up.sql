@@ -0,0 +1,8 @@
+-- Quiet hours, during which no notification emails are sent to the user. The times are in the
+-- given timezone, and the window may wrap around midnight.
+ALTER TABLE local_user
+    ADD COLUMN quiet_hours_enabled boolean DEFAULT FALSE NOT NULL,
+    ADD COLUMN quiet_hours_start time DEFAULT '22:00' NOT NULL,
+    ADD COLUMN quiet_hours_end time DEFAULT '07:00' NOT NULL,
+    ADD COLUMN quiet_hours_timezone text DEFAULT 'UTC' NOT NULL;
+
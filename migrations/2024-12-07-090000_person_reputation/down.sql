@@ -0,0 +1,10 @@
+ALTER TABLE person_aggregates
+    DROP COLUMN reputation;
+
+ALTER TABLE local_site
+    DROP COLUMN reputation_half_life_days,
+    DROP COLUMN spam_low_reputation_weight;
+
+ALTER TABLE community
+    DROP COLUMN posting_min_reputation;
+
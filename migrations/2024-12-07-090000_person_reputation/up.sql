@@ -0,0 +1,13 @@
+-- Karma where each vote counts less the older it is, recomputed periodically.
+ALTER TABLE person_aggregates
+    ADD COLUMN reputation bigint DEFAULT 0 NOT NULL;
+
+-- After this many days a vote only counts half for the reputation. Without it, reputation isn't
+-- computed.
+ALTER TABLE local_site
+    ADD COLUMN reputation_half_life_days int CHECK (reputation_half_life_days > 0),
+    ADD COLUMN spam_low_reputation_weight int DEFAULT 0 NOT NULL CHECK (spam_low_reputation_weight >= 0);
+
+ALTER TABLE community
+    ADD COLUMN posting_min_reputation bigint;
+
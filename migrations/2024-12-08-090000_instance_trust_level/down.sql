@@ -0,0 +1,5 @@
+ALTER TABLE instance
+    DROP COLUMN trust_level;
+
+DROP TYPE instance_trust_level_enum;
+
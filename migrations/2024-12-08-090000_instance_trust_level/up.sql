@@ -0,0 +1,10 @@
+-- Activities from trusted instances skip refetching actors for verification, and only rely on
+-- the signature checks.
+CREATE TYPE instance_trust_level_enum AS enum (
+    'Normal',
+    'Trusted'
+);
+
+ALTER TABLE instance
+    ADD COLUMN trust_level instance_trust_level_enum DEFAULT 'Normal' NOT NULL;
+
@@ -0,0 +1,6 @@
+ALTER TABLE post_actions
+    DROP COLUMN saved_note;
+
+ALTER TABLE comment_actions
+    DROP COLUMN saved_note;
+
@@ -0,0 +1,9 @@
+-- Personal notes for saved posts and comments, only visible to the person who saved them.
+ALTER TABLE post_actions
+    ADD COLUMN saved_note text,
+    ADD CONSTRAINT post_actions_check_saved_note CHECK (saved_note IS NULL OR saved IS NOT NULL);
+
+ALTER TABLE comment_actions
+    ADD COLUMN saved_note text,
+    ADD CONSTRAINT comment_actions_check_saved_note CHECK (saved_note IS NULL OR saved IS NOT NULL);
+
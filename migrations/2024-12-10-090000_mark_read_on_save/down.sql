@@ -0,0 +1,3 @@
+ALTER TABLE local_user
+    DROP COLUMN mark_read_on_save;
+
@@ -0,0 +1,5 @@
+-- Whether saving a post also marks it as read. Enabled by default as that was the previous
+-- behaviour.
+ALTER TABLE local_user
+    ADD COLUMN mark_read_on_save boolean DEFAULT TRUE NOT NULL;
+
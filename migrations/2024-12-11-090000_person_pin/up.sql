@@ -0,0 +1,12 @@
+-- Posts and comments which people pinned to their own profile.
+CREATE TABLE person_pin (
+    id serial PRIMARY KEY,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    published timestamptz DEFAULT now() NOT NULL,
+    CHECK (num_nonnulls (post_id, comment_id) = 1),
+    UNIQUE (person_id, post_id),
+    UNIQUE (person_id, comment_id)
+);
+
@@ -0,0 +1,4 @@
+DROP TABLE community_request_comment, community_request_vote, community_request;
+
+DROP TYPE community_request_status_enum;
+
@@ -0,0 +1,44 @@
+-- Users can request new communities, which admins then approve or deny. This replaces meta posts
+-- asking for communities on instances where only admins can create them.
+CREATE TYPE community_request_status_enum AS enum (
+    'Pending',
+    'Approved',
+    'Denied'
+);
+
+CREATE TABLE community_request (
+    id serial PRIMARY KEY,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    name varchar(255) NOT NULL,
+    title varchar(255) NOT NULL,
+    description text,
+    status community_request_status_enum DEFAULT 'Pending' NOT NULL,
+    deny_reason text,
+    admin_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE SET NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+CREATE INDEX idx_community_request_status_published ON community_request (status, published DESC);
+
+CREATE TABLE community_request_vote (
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_request_id int REFERENCES community_request ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    score smallint NOT NULL CHECK (score IN (-1, 1)),
+    published timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (person_id, community_request_id)
+);
+
+CREATE INDEX idx_community_request_vote_request ON community_request_vote (community_request_id);
+
+CREATE TABLE community_request_comment (
+    id serial PRIMARY KEY,
+    community_request_id int REFERENCES community_request ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    content text NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL
+);
+
+CREATE INDEX idx_community_request_comment_request ON community_request_comment (community_request_id);
+
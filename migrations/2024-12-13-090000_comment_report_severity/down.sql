@@ -0,0 +1,5 @@
+ALTER TABLE comment_report
+    DROP COLUMN severity;
+
+DROP TYPE report_severity_enum;
+
@@ -0,0 +1,10 @@
+-- Lets moderators handle the most urgent comment reports first.
+CREATE TYPE report_severity_enum AS enum (
+    'Low',
+    'Normal',
+    'Urgent'
+);
+
+ALTER TABLE comment_report
+    ADD COLUMN severity report_severity_enum DEFAULT 'Normal' NOT NULL;
+
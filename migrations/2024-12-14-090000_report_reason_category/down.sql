@@ -0,0 +1,5 @@
+ALTER TABLE comment_report
+    DROP COLUMN category_id;
+
+DROP TABLE report_reason_category;
+
@@ -0,0 +1,14 @@
+-- Structured report reasons which admins define, and which reporters can pick from.
+CREATE TABLE report_reason_category (
+    id serial PRIMARY KEY,
+    name varchar(100) NOT NULL UNIQUE,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+INSERT INTO report_reason_category (name)
+    VALUES ('Spam'), ('Harassment'), ('CSAM'), ('Other');
+
+ALTER TABLE comment_report
+    ADD COLUMN category_id int REFERENCES report_reason_category ON UPDATE CASCADE ON DELETE SET NULL;
+
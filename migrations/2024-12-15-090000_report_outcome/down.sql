@@ -0,0 +1,11 @@
+ALTER TABLE comment_report
+    DROP COLUMN outcome;
+
+ALTER TABLE post_report
+    DROP COLUMN outcome;
+
+ALTER TABLE private_message_report
+    DROP COLUMN outcome;
+
+DROP TYPE report_outcome_enum;
+
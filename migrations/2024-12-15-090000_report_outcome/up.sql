@@ -0,0 +1,17 @@
+-- Distinguishes reports which led to a removal from reports which were handled without action
+-- or dismissed. It is null for pending reports, and for reports resolved before this existed.
+CREATE TYPE report_outcome_enum AS enum (
+    'ResolvedRemoved',
+    'ResolvedNoAction',
+    'Dismissed'
+);
+
+ALTER TABLE comment_report
+    ADD COLUMN outcome report_outcome_enum;
+
+ALTER TABLE post_report
+    ADD COLUMN outcome report_outcome_enum;
+
+ALTER TABLE private_message_report
+    ADD COLUMN outcome report_outcome_enum;
+
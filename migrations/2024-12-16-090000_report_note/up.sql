@@ -0,0 +1,12 @@
+-- Internal notes which moderators leave on a comment report, only visible to other moderators.
+CREATE TABLE report_note (
+    id serial PRIMARY KEY,
+    comment_report_id int REFERENCES comment_report ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    content text NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+CREATE INDEX idx_report_note_comment_report ON report_note (comment_report_id);
+
@@ -0,0 +1,9 @@
+ALTER TABLE comment_report
+    DROP COLUMN assigned_to;
+
+ALTER TABLE post_report
+    DROP COLUMN assigned_to;
+
+ALTER TABLE private_message_report
+    DROP COLUMN assigned_to;
+
@@ -0,0 +1,10 @@
+-- The moderator who claimed a report, so that other moderators can see it is being handled.
+ALTER TABLE comment_report
+    ADD COLUMN assigned_to int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL;
+
+ALTER TABLE post_report
+    ADD COLUMN assigned_to int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL;
+
+ALTER TABLE private_message_report
+    ADD COLUMN assigned_to int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL;
+
@@ -0,0 +1,30 @@
+-- Native polls, which are attached to a post. They federate as ActivityPub Question.
+CREATE TABLE poll (
+    id serial PRIMARY KEY,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL UNIQUE,
+    multiple_choice boolean DEFAULT FALSE NOT NULL,
+    end_time timestamptz,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+-- The vote count is kept here, because votes on remote polls only arrive as totals.
+CREATE TABLE poll_option (
+    id serial PRIMARY KEY,
+    poll_id int REFERENCES poll ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    name text NOT NULL,
+    position int NOT NULL,
+    vote_count int DEFAULT 0 NOT NULL,
+    UNIQUE (poll_id, position)
+);
+
+CREATE TABLE poll_vote (
+    poll_id int REFERENCES poll ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    poll_option_id int REFERENCES poll_option ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (poll_option_id, person_id)
+);
+
+CREATE INDEX idx_poll_vote_poll_person ON poll_vote (poll_id, person_id);
+
@@ -0,0 +1,12 @@
+-- Additional images of a gallery post, shown after the post url. They federate as extra
+-- attachments of the Page.
+CREATE TABLE post_media (
+    id serial PRIMARY KEY,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    position int NOT NULL,
+    url text NOT NULL,
+    alt_text text,
+    published timestamptz DEFAULT now() NOT NULL,
+    UNIQUE (post_id, position)
+);
+
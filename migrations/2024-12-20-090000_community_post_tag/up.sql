@@ -0,0 +1,18 @@
+-- Tags which the moderators of a community define, so that posts can be organized by them.
+CREATE TABLE community_post_tag (
+    id serial PRIMARY KEY,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    name text NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz,
+    UNIQUE (community_id, name)
+);
+
+CREATE TABLE post_tag (
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    tag_id int REFERENCES community_post_tag ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    PRIMARY KEY (post_id, tag_id)
+);
+
+CREATE INDEX idx_post_tag_tag ON post_tag (tag_id);
+
@@ -0,0 +1,10 @@
+-- Keywords or regular expressions with which users hide posts and comments.
+CREATE TABLE local_user_keyword_block (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    keyword text NOT NULL,
+    is_regex boolean DEFAULT FALSE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    UNIQUE (local_user_id, keyword)
+);
+
@@ -0,0 +1,4 @@
+DROP TABLE filter_rule;
+
+DROP TYPE filter_rule_action_enum;
+
@@ -0,0 +1,21 @@
+CREATE TYPE filter_rule_action_enum AS enum (
+    'Reject',
+    'Flag',
+    'Report'
+);
+
+-- Keywords or regular expressions which are filtered out of new and edited local content. Rules
+-- without a community apply to the whole site, community rules override them.
+CREATE TABLE filter_rule (
+    id serial PRIMARY KEY,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    pattern text NOT NULL,
+    is_regex boolean DEFAULT FALSE NOT NULL,
+    action filter_rule_action_enum NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+CREATE UNIQUE INDEX idx_filter_rule_community_pattern ON filter_rule (coalesce(community_id, 0), pattern);
+
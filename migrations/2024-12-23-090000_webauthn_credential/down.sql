@@ -0,0 +1,4 @@
+DROP TABLE webauthn_challenge;
+
+DROP TABLE webauthn_credential;
+
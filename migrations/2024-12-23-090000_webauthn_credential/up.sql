@@ -0,0 +1,21 @@
+-- Passkeys and hardware keys with which users can log in.
+CREATE TABLE webauthn_credential (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    -- Base64url encoded, as sent by the authenticator
+    credential_id text NOT NULL UNIQUE,
+    -- Serialized public key and signature counter
+    passkey text NOT NULL,
+    name text NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    last_used timestamptz
+);
+
+-- Passkey registrations and logins which were started, but not finished yet.
+CREATE TABLE webauthn_challenge (
+    uuid uuid PRIMARY KEY DEFAULT gen_random_uuid (),
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    state text NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL
+);
+
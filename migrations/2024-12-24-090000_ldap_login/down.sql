@@ -0,0 +1,4 @@
+ALTER TABLE local_site
+    DROP COLUMN ldap_url,
+    DROP COLUMN ldap_user_dn_template;
+
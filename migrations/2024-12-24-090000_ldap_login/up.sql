@@ -0,0 +1,5 @@
+-- Lets users log in with the credentials of an LDAP directory
+ALTER TABLE local_site
+    ADD COLUMN ldap_url text,
+    ADD COLUMN ldap_user_dn_template text;
+
@@ -0,0 +1,4 @@
+-- Lets users refer to a single login, without exposing its token.
+ALTER TABLE login_token
+    ADD COLUMN id serial UNIQUE NOT NULL;
+
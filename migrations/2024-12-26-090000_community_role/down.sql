@@ -0,0 +1,7 @@
+DROP TABLE community_role_permission;
+
+ALTER TABLE community_actions
+    DROP COLUMN role;
+
+DROP TYPE community_role_enum;
+
@@ -0,0 +1,23 @@
+CREATE TYPE community_role_enum AS enum (
+    'Owner',
+    'Moderator',
+    'TrustedContributor',
+    'Member'
+);
+
+-- Owners and moderators come from the moderator list, only other roles are stored here
+ALTER TABLE community_actions
+    ADD COLUMN role community_role_enum;
+
+-- What each role may do in a community, as configured by its owner. Roles without a row use the
+-- defaults.
+CREATE TABLE community_role_permission (
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    role community_role_enum NOT NULL,
+    can_feature_posts boolean NOT NULL,
+    can_remove_comments boolean NOT NULL,
+    can_edit_sidebar boolean NOT NULL,
+    updated timestamptz,
+    PRIMARY KEY (community_id, role)
+);
+
@@ -0,0 +1,4 @@
+-- Private message sent to people when they start following a local community
+ALTER TABLE community
+    ADD COLUMN welcome_message text;
+
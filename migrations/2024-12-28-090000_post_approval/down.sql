@@ -0,0 +1,8 @@
+DROP INDEX idx_post_pending;
+
+ALTER TABLE post
+    DROP COLUMN pending;
+
+ALTER TABLE community
+    DROP COLUMN posts_require_approval;
+
@@ -0,0 +1,11 @@
+-- New posts of people who aren't trusted by the community wait for approval by a moderator
+ALTER TABLE community
+    ADD COLUMN posts_require_approval boolean DEFAULT FALSE NOT NULL;
+
+ALTER TABLE post
+    ADD COLUMN pending boolean DEFAULT FALSE NOT NULL;
+
+CREATE INDEX idx_post_pending ON post (community_id)
+WHERE
+    pending;
+
@@ -0,0 +1,30 @@
+DELETE FROM filter_rule
+WHERE action IN ('Remove', 'RequireApproval', 'Lock')
+    OR pattern = ''
+    OR link_domain IS NOT NULL
+    OR max_account_age_days IS NOT NULL
+    OR max_reputation IS NOT NULL;
+
+DROP INDEX idx_filter_rule_community_pattern;
+
+CREATE UNIQUE INDEX idx_filter_rule_community_pattern ON filter_rule (coalesce(community_id, 0), pattern);
+
+ALTER TABLE filter_rule
+    DROP COLUMN link_domain,
+    DROP COLUMN max_account_age_days,
+    DROP COLUMN max_reputation;
+
+ALTER TYPE filter_rule_action_enum RENAME TO filter_rule_action_enum__;
+
+CREATE TYPE filter_rule_action_enum AS enum (
+    'Reject',
+    'Flag',
+    'Report'
+);
+
+ALTER TABLE filter_rule
+    ALTER COLUMN action TYPE filter_rule_action_enum
+    USING action::text::filter_rule_action_enum;
+
+DROP TYPE filter_rule_action_enum__;
+
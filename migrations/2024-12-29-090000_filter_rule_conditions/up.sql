@@ -0,0 +1,20 @@
+ALTER TYPE filter_rule_action_enum
+    ADD VALUE 'Remove';
+
+ALTER TYPE filter_rule_action_enum
+    ADD VALUE 'RequireApproval';
+
+ALTER TYPE filter_rule_action_enum
+    ADD VALUE 'Lock';
+
+-- Conditions about the content and its author, which all have to hold for a rule to match. A rule
+-- with conditions can have an empty pattern, which matches any text.
+ALTER TABLE filter_rule
+    ADD COLUMN link_domain text,
+    ADD COLUMN max_account_age_days int CHECK (max_account_age_days > 0),
+    ADD COLUMN max_reputation bigint;
+
+DROP INDEX idx_filter_rule_community_pattern;
+
+CREATE UNIQUE INDEX idx_filter_rule_community_pattern ON filter_rule (coalesce(community_id, 0), pattern, coalesce(link_domain, ''), coalesce(max_account_age_days, 0), coalesce(max_reputation::text, ''));
+
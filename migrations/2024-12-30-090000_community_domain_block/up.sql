@@ -0,0 +1,11 @@
+-- Domains which moderators don't allow as post links in their community. Subdomains are blocked
+-- too.
+CREATE TABLE community_domain_block (
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    domain text NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    reason text,
+    published timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (community_id, domain)
+);
+
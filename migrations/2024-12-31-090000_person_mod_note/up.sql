@@ -0,0 +1,14 @@
+-- Private notes which moderators and admins leave on a person. Notes without a community are
+-- instance-wide and only visible to admins, the others to the moderators of the community.
+CREATE TABLE person_mod_note (
+    id serial PRIMARY KEY,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    content text NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+CREATE INDEX idx_person_mod_note_person ON person_mod_note (person_id);
+
@@ -0,0 +1,6 @@
+ALTER TABLE community
+    DROP COLUMN warning_ban_strikes,
+    DROP COLUMN warning_ban_days;
+
+DROP TABLE mod_warn_person;
+
@@ -0,0 +1,21 @@
+-- Formal warnings which moderators give to a person. Each warning counts as strikes until it
+-- expires. Warnings without a community are given by admins for the whole instance.
+CREATE TABLE mod_warn_person (
+    id serial PRIMARY KEY,
+    mod_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    other_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    reason text NOT NULL,
+    strikes int NOT NULL DEFAULT 1,
+    expires timestamptz,
+    read boolean NOT NULL DEFAULT FALSE,
+    when_ timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_mod_warn_person_other_person ON mod_warn_person (other_person_id);
+
+-- Optionally ban people from a community for some days once their active strikes reach a limit
+ALTER TABLE community
+    ADD COLUMN warning_ban_strikes int,
+    ADD COLUMN warning_ban_days int NOT NULL DEFAULT 7;
+
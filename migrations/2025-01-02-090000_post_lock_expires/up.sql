@@ -0,0 +1,8 @@
+-- Posts can be locked for a limited time, after which a scheduled task unlocks them again
+ALTER TABLE post
+    ADD COLUMN lock_expires timestamptz;
+
+CREATE INDEX idx_post_lock_expires ON post (lock_expires)
+WHERE
+    lock_expires IS NOT NULL;
+
@@ -0,0 +1,4 @@
+ALTER TABLE community_actions
+    DROP COLUMN ban_reason,
+    DROP COLUMN ban_appeal;
+
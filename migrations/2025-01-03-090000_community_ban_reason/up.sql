@@ -0,0 +1,6 @@
+-- Store why someone is banned from a community and how they can appeal, so that it can be shown
+-- to them when their actions are rejected
+ALTER TABLE community_actions
+    ADD COLUMN ban_reason text,
+    ADD COLUMN ban_appeal text;
+
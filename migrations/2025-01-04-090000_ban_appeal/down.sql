@@ -0,0 +1,4 @@
+DROP TABLE mod_resolve_ban_appeal, ban_appeal;
+
+DROP TYPE ban_appeal_status_enum;
+
@@ -0,0 +1,34 @@
+-- People who are banned from a community can appeal the ban once. Moderators accept the appeal,
+-- which lifts the ban, or deny it.
+CREATE TYPE ban_appeal_status_enum AS enum (
+    'Pending',
+    'Accepted',
+    'Denied'
+);
+
+CREATE TABLE ban_appeal (
+    id serial PRIMARY KEY,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    reason text NOT NULL,
+    status ban_appeal_status_enum DEFAULT 'Pending' NOT NULL,
+    deny_reason text,
+    resolver_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+CREATE INDEX idx_ban_appeal_creator_community ON ban_appeal (creator_id, community_id);
+
+CREATE INDEX idx_ban_appeal_status_published ON ban_appeal (status, published DESC);
+
+CREATE TABLE mod_resolve_ban_appeal (
+    id serial PRIMARY KEY,
+    mod_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    other_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    accepted boolean NOT NULL,
+    reason text,
+    when_ timestamptz DEFAULT now() NOT NULL
+);
+
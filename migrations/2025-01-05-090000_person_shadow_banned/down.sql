@@ -0,0 +1,5 @@
+DROP TABLE admin_shadow_ban_person;
+
+ALTER TABLE person
+    DROP COLUMN shadow_banned;
+
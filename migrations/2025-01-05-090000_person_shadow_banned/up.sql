@@ -0,0 +1,17 @@
+-- The content of a shadow banned person is only visible to themselves and admins, and isn't
+-- federated. Meant for spammers, who would otherwise just create a new account after a ban.
+ALTER TABLE person
+    ADD COLUMN shadow_banned boolean DEFAULT FALSE NOT NULL;
+
+-- Not part of the public modlog, as the person shouldn't find out about it
+CREATE TABLE admin_shadow_ban_person (
+    id serial PRIMARY KEY,
+    admin_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    other_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    reason text,
+    shadow_banned boolean DEFAULT TRUE NOT NULL,
+    when_ timestamptz DEFAULT now() NOT NULL
+);
+
+CREATE INDEX idx_admin_shadow_ban_person_other_person ON admin_shadow_ban_person (other_person_id);
+
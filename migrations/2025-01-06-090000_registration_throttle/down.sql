@@ -0,0 +1,8 @@
+DROP TABLE registration_source;
+
+ALTER TABLE local_site_rate_limit
+    DROP COLUMN register_subnet,
+    DROP COLUMN register_subnet_per_second,
+    DROP COLUMN register_asn,
+    DROP COLUMN register_asn_per_second;
+
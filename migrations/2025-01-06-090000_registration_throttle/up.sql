@@ -0,0 +1,25 @@
+-- Registrations are additionally limited per IP subnet, and per autonomous system if a MaxMind
+-- database is configured. Spam waves often come from a single hosting provider, so limits per IP
+-- address don't stop them.
+ALTER TABLE local_site_rate_limit
+    ADD COLUMN register_subnet int DEFAULT 10 NOT NULL,
+    ADD COLUMN register_subnet_per_second int DEFAULT 86400 NOT NULL,
+    ADD COLUMN register_asn int DEFAULT 50 NOT NULL,
+    ADD COLUMN register_asn_per_second int DEFAULT 86400 NOT NULL;
+
+-- Where registrations came from. Only the subnet is stored instead of the full IP address.
+CREATE TABLE registration_source (
+    id serial PRIMARY KEY,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    ip_subnet text NOT NULL,
+    asn int,
+    asn_organization text,
+    published timestamptz DEFAULT now() NOT NULL
+);
+
+CREATE INDEX idx_registration_source_subnet ON registration_source (ip_subnet, published);
+
+CREATE INDEX idx_registration_source_asn ON registration_source (asn, published);
+
+CREATE INDEX idx_registration_source_published ON registration_source (published);
+
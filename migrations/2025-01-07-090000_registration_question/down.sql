@@ -0,0 +1,46 @@
+ALTER TABLE local_site
+    ADD COLUMN application_question text;
+
+ALTER TABLE registration_application
+    ADD COLUMN answer text NOT NULL DEFAULT '';
+
+ALTER TABLE registration_application
+    ALTER COLUMN answer DROP DEFAULT;
+
+-- Only the first question and its answers can be kept
+UPDATE
+    local_site
+SET
+    application_question = (
+        SELECT
+            question
+        FROM
+            registration_question
+        ORDER BY
+            position,
+            id
+        LIMIT 1);
+
+UPDATE
+    registration_application ra
+SET
+    answer = (
+        SELECT
+            string_agg(answer, E'\n\n' ORDER BY id)
+        FROM
+            registration_answer
+        WHERE
+            registration_application_id = ra.id)
+WHERE
+    EXISTS (
+        SELECT
+            1
+        FROM
+            registration_answer
+        WHERE
+            registration_application_id = ra.id);
+
+DROP TABLE registration_answer;
+
+DROP TABLE registration_question;
+
@@ -0,0 +1,48 @@
+-- Admins can ask several questions in registration applications, instead of a single one
+CREATE TABLE registration_question (
+    id serial PRIMARY KEY,
+    question text NOT NULL,
+    required boolean DEFAULT TRUE NOT NULL,
+    position int DEFAULT 0 NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+-- The question is copied, so that the answer still makes sense after the question is edited or
+-- deleted.
+CREATE TABLE registration_answer (
+    id serial PRIMARY KEY,
+    registration_application_id int REFERENCES registration_application ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    question_id int REFERENCES registration_question ON UPDATE CASCADE ON DELETE SET NULL,
+    question text NOT NULL,
+    answer text NOT NULL
+);
+
+CREATE INDEX idx_registration_answer_application ON registration_answer (registration_application_id);
+
+-- The existing question becomes the first one, and existing answers are moved over
+INSERT INTO registration_question (question)
+SELECT
+    application_question
+FROM
+    local_site
+WHERE
+    application_question IS NOT NULL
+    AND application_question != '';
+
+INSERT INTO registration_answer (registration_application_id, question_id, question, answer)
+SELECT
+    ra.id,
+    rq.id,
+    coalesce(rq.question, ''),
+    ra.answer
+FROM
+    registration_application ra
+    LEFT JOIN registration_question rq ON TRUE;
+
+ALTER TABLE registration_application
+    DROP COLUMN answer;
+
+ALTER TABLE local_site
+    DROP COLUMN application_question;
+
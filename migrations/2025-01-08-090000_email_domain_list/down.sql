@@ -0,0 +1,5 @@
+DROP TABLE local_site_email_domain;
+
+ALTER TABLE local_site
+    DROP COLUMN block_disposable_email_domains;
+
@@ -0,0 +1,13 @@
+-- Email domains which are allowed or blocked for new signups. A leading `*.` also matches all
+-- subdomains.
+CREATE TABLE local_site_email_domain (
+    id serial PRIMARY KEY,
+    domain text NOT NULL,
+    allowed boolean NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    UNIQUE (domain, allowed)
+);
+
+ALTER TABLE local_site
+    ADD COLUMN block_disposable_email_domains boolean DEFAULT FALSE NOT NULL;
+
@@ -0,0 +1,14 @@
+ALTER TABLE local_site
+    DROP COLUMN new_account_min_age_days,
+    DROP COLUMN new_account_min_comments,
+    DROP COLUMN new_account_require_verified_email,
+    DROP COLUMN new_account_restrictions;
+
+ALTER TABLE community
+    DROP COLUMN new_account_min_age_days,
+    DROP COLUMN new_account_min_comments,
+    DROP COLUMN new_account_require_verified_email,
+    DROP COLUMN new_account_restrictions;
+
+DROP TYPE new_account_restriction_enum;
+
@@ -0,0 +1,19 @@
+-- Actions which new accounts can't take until they meet the thresholds of the site or community
+CREATE TYPE new_account_restriction_enum AS enum (
+    'Posts',
+    'Links',
+    'PrivateMessages'
+);
+
+ALTER TABLE local_site
+    ADD COLUMN new_account_min_age_days int,
+    ADD COLUMN new_account_min_comments int,
+    ADD COLUMN new_account_require_verified_email boolean DEFAULT FALSE NOT NULL,
+    ADD COLUMN new_account_restrictions new_account_restriction_enum[] DEFAULT '{}' NOT NULL;
+
+ALTER TABLE community
+    ADD COLUMN new_account_min_age_days int,
+    ADD COLUMN new_account_min_comments int,
+    ADD COLUMN new_account_require_verified_email boolean DEFAULT FALSE NOT NULL,
+    ADD COLUMN new_account_restrictions new_account_restriction_enum[] DEFAULT '{}' NOT NULL;
+
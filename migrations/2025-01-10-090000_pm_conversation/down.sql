@@ -0,0 +1,4 @@
+ALTER TABLE private_message
+    DROP COLUMN conversation_id;
+
+DROP TABLE pm_conversation;
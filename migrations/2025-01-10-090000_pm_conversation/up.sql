@@ -0,0 +1,43 @@
+-- Group private messages into conversations between two persons. The person with the lower id
+-- is always stored as person_a, so that each pair of persons has exactly one conversation.
+CREATE TABLE pm_conversation (
+    id serial PRIMARY KEY,
+    person_a_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    person_b_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    last_message_published timestamptz DEFAULT now() NOT NULL,
+    CHECK (person_a_id <= person_b_id),
+    UNIQUE (person_a_id, person_b_id)
+);
+
+CREATE INDEX idx_pm_conversation_person_b ON pm_conversation (person_b_id);
+
+INSERT INTO pm_conversation (person_a_id, person_b_id, published, last_message_published)
+SELECT
+    least (creator_id, recipient_id),
+    greatest (creator_id, recipient_id),
+    min(published),
+    max(published)
+FROM
+    private_message
+GROUP BY
+    1,
+    2;
+
+ALTER TABLE private_message
+    ADD COLUMN conversation_id int REFERENCES pm_conversation ON UPDATE CASCADE ON DELETE CASCADE;
+
+UPDATE
+    private_message AS pm
+SET
+    conversation_id = c.id
+FROM
+    pm_conversation AS c
+WHERE
+    c.person_a_id = least (pm.creator_id, pm.recipient_id)
+    AND c.person_b_id = greatest (pm.creator_id, pm.recipient_id);
+
+ALTER TABLE private_message
+    ALTER COLUMN conversation_id SET NOT NULL;
+
+CREATE INDEX idx_private_message_conversation ON private_message (conversation_id, published DESC);
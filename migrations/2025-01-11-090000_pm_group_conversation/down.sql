@@ -0,0 +1,20 @@
+DELETE FROM private_message
+WHERE recipient_id IS NULL;
+
+ALTER TABLE private_message
+    ALTER COLUMN recipient_id SET NOT NULL;
+
+DROP TABLE pm_conversation_participant;
+
+DELETE FROM pm_conversation
+WHERE is_group;
+
+ALTER TABLE pm_conversation
+    DROP CONSTRAINT pm_conversation_group_check,
+    DROP COLUMN is_group,
+    DROP COLUMN name,
+    DROP COLUMN creator_id,
+    DROP COLUMN ap_id,
+    DROP COLUMN local,
+    ALTER COLUMN person_a_id SET NOT NULL,
+    ALTER COLUMN person_b_id SET NOT NULL;
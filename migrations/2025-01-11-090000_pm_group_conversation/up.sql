@@ -0,0 +1,28 @@
+-- Group conversations between more than two persons. Instead of person_a and person_b, their
+-- members are stored in pm_conversation_participant. The ap_id identifies the group across
+-- instances, and is sent as context of the federated messages.
+ALTER TABLE pm_conversation
+    ALTER COLUMN person_a_id DROP NOT NULL,
+    ALTER COLUMN person_b_id DROP NOT NULL,
+    ADD COLUMN is_group boolean DEFAULT FALSE NOT NULL,
+    ADD COLUMN name text,
+    ADD COLUMN creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    ADD COLUMN ap_id varchar(255) UNIQUE,
+    ADD COLUMN local boolean DEFAULT TRUE NOT NULL,
+    ADD CONSTRAINT pm_conversation_group_check CHECK (is_group = (person_a_id IS NULL
+        AND person_b_id IS NULL));
+
+CREATE TABLE pm_conversation_participant (
+    conversation_id int REFERENCES pm_conversation ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    -- Messages published after this time are unread for the participant
+    last_read timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (conversation_id, person_id)
+);
+
+CREATE INDEX idx_pm_conversation_participant_person ON pm_conversation_participant (person_id);
+
+-- Messages in group conversations have no single recipient
+ALTER TABLE private_message
+    ALTER COLUMN recipient_id DROP NOT NULL;
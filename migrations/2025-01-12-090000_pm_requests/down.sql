@@ -0,0 +1,5 @@
+ALTER TABLE local_user
+    DROP COLUMN private_message_requests;
+
+ALTER TABLE pm_conversation
+    DROP COLUMN request_recipient_id;
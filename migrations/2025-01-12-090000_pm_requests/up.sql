@@ -0,0 +1,11 @@
+-- Hold back private messages from strangers until the recipient accepts them.
+ALTER TABLE local_user
+    ADD COLUMN private_message_requests boolean DEFAULT FALSE NOT NULL;
+
+-- The person who has to accept the conversation before its messages are shown in the inbox.
+ALTER TABLE pm_conversation
+    ADD COLUMN request_recipient_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE;
+
+CREATE INDEX idx_pm_conversation_request_recipient ON pm_conversation (request_recipient_id)
+WHERE
+    request_recipient_id IS NOT NULL;
@@ -0,0 +1,4 @@
+ALTER TABLE local_user
+    DROP COLUMN allow_private_messages;
+
+DROP TYPE allow_private_messages_enum;
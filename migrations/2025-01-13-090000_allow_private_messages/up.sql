@@ -0,0 +1,9 @@
+-- Who can send private messages to a user
+CREATE TYPE allow_private_messages_enum AS enum (
+    'Everyone',
+    'Followed',
+    'Nobody'
+);
+
+ALTER TABLE local_user
+    ADD COLUMN allow_private_messages allow_private_messages_enum DEFAULT 'Everyone' NOT NULL;
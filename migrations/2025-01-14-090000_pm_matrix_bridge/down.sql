@@ -0,0 +1,2 @@
+ALTER TABLE pm_conversation
+    DROP COLUMN matrix_room_id;
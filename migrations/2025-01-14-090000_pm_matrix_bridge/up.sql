@@ -0,0 +1,3 @@
+-- The Matrix room where a conversation is bridged to
+ALTER TABLE pm_conversation
+    ADD COLUMN matrix_room_id text;
@@ -0,0 +1,3 @@
+DROP TABLE custom_feed_community;
+
+DROP TABLE custom_feed;
@@ -0,0 +1,20 @@
+-- Named feeds which combine the posts of any communities. Public feeds can be viewed by anyone
+-- through their slug.
+CREATE TABLE custom_feed (
+    id serial PRIMARY KEY,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    name text NOT NULL,
+    description text,
+    public boolean DEFAULT FALSE NOT NULL,
+    slug text UNIQUE,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz,
+    UNIQUE (creator_id, name)
+);
+
+CREATE TABLE custom_feed_community (
+    custom_feed_id int REFERENCES custom_feed ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (custom_feed_id, community_id)
+);
@@ -0,0 +1 @@
+DROP TABLE local_user_community_settings;
@@ -0,0 +1,10 @@
+-- The sort and listing mode which a user last chose for a community, so that clients can restore
+-- them when the community is opened again.
+CREATE TABLE local_user_community_settings (
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    post_sort_type post_sort_type_enum,
+    post_listing_mode post_listing_mode_enum,
+    updated timestamptz DEFAULT now() NOT NULL,
+    PRIMARY KEY (local_user_id, community_id)
+);
@@ -0,0 +1,3 @@
+-- Comments which the post creator or a mod pinned to the top of the post
+ALTER TABLE comment
+    ADD COLUMN distinguished_pinned boolean DEFAULT FALSE NOT NULL;
@@ -0,0 +1,37 @@
+UPDATE
+    local_user
+SET
+    default_comment_sort_type = 'Hot'
+WHERE
+    default_comment_sort_type = 'QandA';
+
+UPDATE
+    local_site
+SET
+    default_comment_sort_type = 'Hot'
+WHERE
+    default_comment_sort_type = 'QandA';
+
+ALTER TYPE comment_sort_type_enum RENAME TO comment_sort_type_enum__;
+
+CREATE TYPE comment_sort_type_enum AS enum (
+    'Hot',
+    'Top',
+    'New',
+    'Old',
+    'Controversial'
+);
+
+ALTER TABLE local_user
+    ALTER COLUMN default_comment_sort_type DROP DEFAULT,
+    ALTER COLUMN default_comment_sort_type TYPE comment_sort_type_enum
+    USING default_comment_sort_type::text::comment_sort_type_enum,
+    ALTER COLUMN default_comment_sort_type SET DEFAULT 'Hot';
+
+ALTER TABLE local_site
+    ALTER COLUMN default_comment_sort_type DROP DEFAULT,
+    ALTER COLUMN default_comment_sort_type TYPE comment_sort_type_enum
+    USING default_comment_sort_type::text::comment_sort_type_enum,
+    ALTER COLUMN default_comment_sort_type SET DEFAULT 'Hot';
+
+DROP TYPE comment_sort_type_enum__;
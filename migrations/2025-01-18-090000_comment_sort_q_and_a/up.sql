@@ -0,0 +1,2 @@
+ALTER TYPE comment_sort_type_enum
+    ADD VALUE 'QandA';
@@ -0,0 +1,5 @@
+ALTER TABLE local_user
+    DROP COLUMN downrank_bot_accounts;
+
+ALTER TABLE local_user_community_settings
+    DROP COLUMN show_bot_accounts;
@@ -0,0 +1,7 @@
+-- Leave bot posts out of feeds which combine several communities, and list bot comments last
+ALTER TABLE local_user
+    ADD COLUMN downrank_bot_accounts boolean DEFAULT FALSE NOT NULL;
+
+-- Overrides the bot settings of the user in communities which rely on helpful bots
+ALTER TABLE local_user_community_settings
+    ADD COLUMN show_bot_accounts boolean;
@@ -0,0 +1,2 @@
+ALTER TABLE community_role_permission
+    DROP COLUMN can_view_votes;
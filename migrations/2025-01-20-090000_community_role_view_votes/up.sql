@@ -0,0 +1,11 @@
+-- Whether a role can see who voted on posts and comments in the community. Moderators could
+-- always do this, so stored moderator permissions keep it.
+ALTER TABLE community_role_permission
+    ADD COLUMN can_view_votes boolean DEFAULT FALSE NOT NULL;
+
+UPDATE
+    community_role_permission
+SET
+    can_view_votes = TRUE
+WHERE
+    role = 'Moderator';
@@ -0,0 +1,4 @@
+DROP TABLE vote_anomaly;
+
+DROP TYPE vote_anomaly_kind_enum;
+
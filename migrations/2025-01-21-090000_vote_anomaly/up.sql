@@ -0,0 +1,30 @@
+CREATE TYPE vote_anomaly_kind_enum AS enum (
+    'SingleTargetUpvotes',
+    'InstanceVoteBurst'
+);
+
+-- Suspicious voting patterns which were found by the scheduled analysis, for review by admins.
+CREATE TABLE vote_anomaly (
+    id serial PRIMARY KEY,
+    kind vote_anomaly_kind_enum NOT NULL,
+    -- For SingleTargetUpvotes, the person whose content all votes went to
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    -- For InstanceVoteBurst, the instance of the voters and the post which they voted on
+    instance_id int REFERENCES instance ON UPDATE CASCADE ON DELETE CASCADE,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    voter_ids int[] NOT NULL,
+    -- Set once an admin reviewed the finding, to whether the votes were removed or it was dismissed
+    votes_removed boolean,
+    reviewer_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    published timestamptz DEFAULT now() NOT NULL,
+    updated timestamptz
+);
+
+CREATE INDEX idx_vote_anomaly_review_queue ON vote_anomaly (published)
+WHERE
+    votes_removed IS NULL;
+
+CREATE INDEX idx_vote_anomaly_person ON vote_anomaly (person_id);
+
+CREATE INDEX idx_vote_anomaly_post ON vote_anomaly (post_id);
+
@@ -0,0 +1,12 @@
+ALTER TABLE mod_remove_post
+    DROP COLUMN automod;
+
+ALTER TABLE mod_remove_comment
+    DROP COLUMN automod;
+
+ALTER TABLE mod_lock_post
+    DROP COLUMN automod;
+
+ALTER TABLE mod_feature_post
+    DROP COLUMN automod;
+
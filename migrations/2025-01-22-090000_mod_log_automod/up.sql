@@ -0,0 +1,14 @@
+-- Mod actions which a community bot performs through a delegation are attributed to the community,
+-- and don't show the moderator who created the delegation.
+ALTER TABLE mod_remove_post
+    ADD COLUMN automod boolean DEFAULT FALSE NOT NULL;
+
+ALTER TABLE mod_remove_comment
+    ADD COLUMN automod boolean DEFAULT FALSE NOT NULL;
+
+ALTER TABLE mod_lock_post
+    ADD COLUMN automod boolean DEFAULT FALSE NOT NULL;
+
+ALTER TABLE mod_feature_post
+    ADD COLUMN automod boolean DEFAULT FALSE NOT NULL;
+
@@ -0,0 +1,3 @@
+ALTER TABLE local_user
+    DROP COLUMN ldap_login,
+    DROP COLUMN ldap_admin_login;
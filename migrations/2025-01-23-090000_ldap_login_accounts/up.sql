@@ -0,0 +1,5 @@
+-- Only accounts created through LDAP login may log in with the directory credentials, and admins
+-- only after opting in
+ALTER TABLE local_user
+    ADD COLUMN ldap_login boolean DEFAULT FALSE NOT NULL,
+    ADD COLUMN ldap_admin_login boolean DEFAULT FALSE NOT NULL;
@@ -0,0 +1,3 @@
+ALTER TABLE filter_rule
+    DROP COLUMN condition;
+
@@ -0,0 +1,4 @@
+-- Expression which decides if a filter rule matches, in the same language as hook conditions
+ALTER TABLE filter_rule
+    ADD COLUMN condition text;
+
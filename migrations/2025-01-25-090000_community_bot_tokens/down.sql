@@ -0,0 +1,18 @@
+ALTER TABLE community_bot_delegation
+    ADD COLUMN can_remove_posts boolean DEFAULT FALSE NOT NULL,
+    ADD COLUMN can_remove_comments boolean DEFAULT FALSE NOT NULL,
+    ADD COLUMN can_lock_posts boolean DEFAULT FALSE NOT NULL,
+    ADD COLUMN can_feature_posts boolean DEFAULT FALSE NOT NULL;
+
+UPDATE
+    community_bot_delegation
+SET
+    can_remove_posts = 'RemovePost' = ANY (scopes),
+    can_remove_comments = 'RemoveComment' = ANY (scopes),
+    can_lock_posts = 'LockPost' = ANY (scopes),
+    can_feature_posts = 'FeaturePost' = ANY (scopes);
+
+ALTER TABLE community_bot_delegation
+    DROP COLUMN scopes,
+    DROP COLUMN token;
+
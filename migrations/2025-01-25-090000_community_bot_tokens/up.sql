@@ -0,0 +1,40 @@
+-- Bots act through a token which is only valid for the scopes listed in the delegation, instead
+-- of with their normal login.
+ALTER TABLE community_bot_delegation
+    ADD COLUMN scopes community_bot_scope_enum[] DEFAULT '{}' NOT NULL,
+    ADD COLUMN token text;
+
+UPDATE
+    community_bot_delegation
+SET
+    scopes = array_remove(ARRAY[
+        CASE WHEN can_remove_posts THEN
+            'RemovePost'::community_bot_scope_enum
+        END,
+        CASE WHEN can_remove_comments THEN
+            'RemoveComment'::community_bot_scope_enum
+        END,
+        CASE WHEN can_lock_posts THEN
+            'LockPost'::community_bot_scope_enum
+        END,
+        CASE WHEN can_feature_posts THEN
+            'FeaturePost'::community_bot_scope_enum
+        END], NULL);
+
+-- The token of a delegation is only shown to the moderator when creating it, so existing
+-- delegations can't be given one. They are revoked and need to be created again.
+UPDATE
+    community_bot_delegation
+SET
+    token = 'lemmy_bot_' || gen_random_uuid (),
+    revoked = TRUE;
+
+ALTER TABLE community_bot_delegation
+    ALTER COLUMN scopes DROP DEFAULT,
+    ALTER COLUMN token SET NOT NULL,
+    ADD CONSTRAINT community_bot_delegation_token_key UNIQUE (token),
+    DROP COLUMN can_remove_posts,
+    DROP COLUMN can_remove_comments,
+    DROP COLUMN can_lock_posts,
+    DROP COLUMN can_feature_posts;
+
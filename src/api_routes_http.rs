@@ -4,71 +4,163 @@ use lemmy_api::{
     distinguish::distinguish_comment,
     like::like_comment,
     list_comment_likes::list_comment_likes,
+    pin::pin_comment,
     save::save_comment,
   },
   comment_report::{
     create::create_comment_report,
+    create_note::create_comment_report_note,
+    delete_note::delete_comment_report_note,
+    edit_note::edit_comment_report_note,
     list::list_comment_reports,
     resolve::resolve_comment_report,
+    resolve_many::resolve_comment_reports,
   },
   community::{
     add_mod::add_mod_to_community,
     ban::ban_from_community,
+    ban_list::{
+      ban::ban_from_ban_list,
+      create::create_ban_list,
+      edit::edit_ban_list,
+      exempt::exempt_from_ban_list,
+      get::get_ban_list,
+      list::list_ban_lists,
+      subscribe::subscribe_ban_list,
+    },
     block::block_community,
+    bot::{
+      create::create_community_bot_delegation,
+      edit::edit_community_bot_delegation,
+      list::list_community_bot_delegations,
+      list_actions::list_community_bot_actions,
+      revoke::revoke_community_bot_delegation,
+    },
+    description_translation::edit_community_description_translation,
+    domain_block::{block_community_domain, list_community_domain_blocks},
     follow::follow_community,
     hide::hide_community,
+    launch::launch_community,
+    mirror::{
+      create::create_community_mirror,
+      edit::edit_community_mirror,
+      list::list_community_mirrors,
+      list_actions::list_community_mirror_actions,
+    },
+    onboarding::get_onboarding_communities,
     pending_follows::{
       approve::post_pending_follows_approve,
       count::get_pending_follows_count,
       list::get_pending_follows_list,
     },
+    post_tag::{
+      create::create_community_post_tag,
+      delete::delete_community_post_tag,
+      edit::edit_community_post_tag,
+      list::list_community_post_tags,
+    },
     random::get_random_community,
+    rename::rename_community,
+    reserve_name::{list_reserved_community_names, reserve_community_name},
+    role::{
+      edit_permission::edit_community_role_permission,
+      list_permissions::list_community_role_permissions,
+      set::set_community_role,
+    },
+    settings::save_community_settings,
+    suppress_recommendation::suppress_community_recommendation,
     transfer::transfer_community,
   },
   local_user::{
     add_admin::add_admin,
+    assign_report::assign_report,
     ban_person::ban_from_site,
     block::block_person,
+    block_keyword::block_keyword,
     change_password::change_password,
     change_password_after_reset::change_password_after_reset,
+    follow::follow_person,
+    freeze_account::freeze_account,
     generate_totp_secret::generate_totp_secret,
     get_captcha::get_captcha,
+    linked_accounts::{
+      link::link_account,
+      list::list_linked_accounts,
+      switch::switch_account,
+      unlink::unlink_account,
+    },
+    list_account_freezes::list_account_freezes,
     list_banned::list_banned_users,
     list_logins::list_logins,
     list_media::list_media,
+    list_read_posts::list_read_posts,
+    list_reports::list_reports,
+    list_shadow_bans::list_shadow_bans,
     login::login,
     logout::logout,
+    mod_note::{
+      create::create_person_mod_note,
+      delete::delete_person_mod_note,
+      edit::edit_person_mod_note,
+      list::list_person_mod_notes,
+    },
     notifications::{
+      list_inbox::list_inbox,
       list_mentions::list_mentions,
       list_replies::list_replies,
-      mark_all_read::mark_all_notifications_read,
+      list_warnings::list_warnings,
+      mark_all_read::{mark_all_notifications_read, mark_inbox_as_read},
       mark_mention_read::mark_person_mention_as_read,
       mark_reply_read::mark_reply_as_read,
       unread_count::unread_count,
     },
+    passkey::{
+      delete::delete_passkey,
+      list::list_passkeys,
+      login::{finish_passkey_login, start_passkey_login},
+      register::{finish_passkey_registration, start_passkey_registration},
+    },
+    pin_to_profile::pin_to_profile,
     report_count::report_count,
+    report_history::get_person_report_history,
     reset_password::reset_password,
+    revoke_login::revoke_login,
     save_settings::save_user_settings,
+    shadow_ban::shadow_ban_person,
     update_totp::update_totp,
     validate_auth::validate_auth,
     verify_email::verify_email,
+    warn_person::warn_person,
   },
   post::{
+    approve_pending::approve_pending_post,
+    export_comments::export_post_comments,
     feature::feature_post,
     get_link_metadata::get_link_metadata,
-    hide::hide_post,
+    hide::{hide_post, hide_posts},
     like::like_post,
+    list_pending::list_pending_posts,
     list_post_likes::list_post_likes,
     lock::lock_post,
     mark_read::mark_post_as_read,
     save::save_post,
+    vote_poll::vote_poll,
   },
   post_report::{
     create::create_post_report,
     list::list_post_reports,
     resolve::resolve_post_report,
   },
-  private_message::mark_read::mark_pm_as_read,
+  private_message::{
+    add_participant::add_pm_group_participant,
+    approve_request::approve_pm_request,
+    create_group::create_pm_group,
+    get_conversation::get_pm_conversation,
+    list_conversations::list_pm_conversations,
+    mark_conversation_read::mark_pm_conversation_as_read,
+    mark_read::mark_pm_as_read,
+    remove_participant::remove_pm_group_participant,
+  },
   private_message_report::{
     create::create_pm_report,
     list::list_pm_reports,
@@ -76,9 +168,16 @@ use lemmy_api::{
   },
   site::{
     block::block_instance,
+    client_usage::{list_client_usage, restrict_client},
+    description_translation::edit_site_description_translation,
+    feature_flag::edit_feature_flag,
     federated_instances::get_federated_instances,
+    hook_condition::validate_hook_condition,
+    instance_blocks::{edit_instance_block, get_instance_blocks},
+    instance_trust_level::edit_instance_trust_level,
     leave_admin::leave_admin,
     list_all_media::list_all_media,
+    maintenance_mode::set_maintenance_mode,
     mod_log::get_mod_log,
     purge::{
       comment::purge_comment,
@@ -90,12 +189,18 @@ use lemmy_api::{
       approve::approve_registration_application,
       get::get_registration_application,
       list::list_registration_applications,
+      list_bursts::list_registration_bursts,
       unread_count::get_unread_registration_application_count,
     },
+    spam::{list::list_spam_scores, preview::preview_content_filters, review::review_spam_score},
+    transparency_report::list_transparency_reports,
+    user_defaults::apply_user_defaults,
+    vote_anomaly::{list::list_vote_anomalies, review::review_vote_anomaly},
   },
   sitemap::get_sitemap,
 };
 use lemmy_api_crud::{
+  ban_appeal::{create::create_ban_appeal, list::list_ban_appeals, resolve::resolve_ban_appeal},
   comment::{
     create::create_comment,
     delete::delete_comment,
@@ -110,12 +215,39 @@ use lemmy_api_crud::{
     remove::remove_community,
     update::update_community,
   },
+  community_request::{
+    approve::approve_community_request,
+    comment::create_community_request_comment,
+    create::create_community_request,
+    list::list_community_requests,
+    read::get_community_request,
+    vote::vote_community_request,
+  },
   custom_emoji::{
     create::create_custom_emoji,
     delete::delete_custom_emoji,
     list::list_custom_emojis,
     update::update_custom_emoji,
   },
+  custom_feed::{
+    create::create_custom_feed,
+    delete::delete_custom_feed,
+    list::list_custom_feeds,
+    read::get_custom_feed,
+    update::update_custom_feed,
+  },
+  filter_rule::{
+    create::create_filter_rule,
+    delete::delete_filter_rule,
+    list::list_filter_rules,
+    update::update_filter_rule,
+  },
+  interest_category::{
+    create::create_interest_category,
+    delete::delete_interest_category,
+    list::list_interest_categories,
+    update::update_interest_category,
+  },
   oauth_provider::{
     create::create_oauth_provider,
     delete::delete_oauth_provider,
@@ -134,6 +266,17 @@ use lemmy_api_crud::{
     read::get_private_message,
     update::update_private_message,
   },
+  registration_question::{
+    create::create_registration_question,
+    delete::delete_registration_question,
+    update::update_registration_question,
+  },
+  report_reason_category::{
+    create::create_report_reason_category,
+    delete::delete_report_reason_category,
+    list::list_report_reason_categories,
+    update::update_report_reason_category,
+  },
   site::{create::create_site, read::get_site, update::update_site},
   tagline::{
     create::create_tagline,
@@ -144,6 +287,7 @@ use lemmy_api_crud::{
   user::{
     create::{authenticate_with_oauth, register},
     delete::delete_account,
+    oauth_account::{link_oauth_account, list_oauth_accounts, unlink_oauth_account},
   },
 };
 use lemmy_apub::api::{
@@ -153,6 +297,7 @@ use lemmy_apub::api::{
   read_person::read_person,
   resolve_object::resolve_object,
   search::search,
+  user_data_export::export_data,
   user_settings_backup::{export_settings, import_settings},
 };
 use lemmy_routes::images::image_proxy;
@@ -170,6 +315,10 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           // Admin Actions
           .route("", web::post().to(create_site))
           .route("", web::put().to(update_site))
+          .route(
+            "/description_translation",
+            web::put().to(edit_site_description_translation),
+          )
           .route("/block", web::post().to(block_instance)),
       )
       .service(
@@ -177,6 +326,11 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .wrap(rate_limit.message())
           .route(web::get().to(get_mod_log)),
       )
+      .service(
+        web::resource("/transparency_report/list")
+          .wrap(rate_limit.message())
+          .route(web::get().to(list_transparency_reports)),
+      )
       .service(
         web::resource("/search")
           .wrap(rate_limit.search())
@@ -187,6 +341,24 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .wrap(rate_limit.message())
           .route(web::get().to(resolve_object)),
       )
+      // Community requests
+      .service(
+        web::scope("/community_request")
+          .wrap(rate_limit.message())
+          .route("", web::get().to(get_community_request))
+          .route("", web::post().to(create_community_request))
+          .route("/list", web::get().to(list_community_requests))
+          .route("/vote", web::post().to(vote_community_request))
+          .route("/comment", web::post().to(create_community_request_comment)),
+      )
+      // Ban appeals
+      .service(
+        web::scope("/ban_appeal")
+          .wrap(rate_limit.message())
+          .route("", web::post().to(create_ban_appeal))
+          .route("/list", web::get().to(list_ban_appeals))
+          .route("/resolve", web::put().to(resolve_ban_appeal)),
+      )
       // Community
       .service(
         web::resource("/community")
@@ -200,10 +372,32 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("", web::get().to(get_community))
           .route("", web::put().to(update_community))
           .route("/random", web::get().to(get_random_community))
+          .route(
+            "/description_translation",
+            web::put().to(edit_community_description_translation),
+          )
           .route("/hide", web::put().to(hide_community))
+          .route("/launch", web::post().to(launch_community))
+          .route("/rename", web::post().to(rename_community))
+          .route("/domain_block", web::post().to(block_community_domain))
+          .route(
+            "/domain_block/list",
+            web::get().to(list_community_domain_blocks),
+          )
+          .route("/reserve_name", web::post().to(reserve_community_name))
+          .route(
+            "/reserve_name/list",
+            web::get().to(list_reserved_community_names),
+          )
           .route("/list", web::get().to(list_communities))
+          .route("/onboarding", web::post().to(get_onboarding_communities))
           .route("/follow", web::post().to(follow_community))
           .route("/block", web::post().to(block_community))
+          .route(
+            "/suppress_recommendation",
+            web::post().to(suppress_community_recommendation),
+          )
+          .route("/settings", web::put().to(save_community_settings))
           .route("/delete", web::post().to(delete_community))
           // Mod Actions
           .route("/remove", web::post().to(remove_community))
@@ -216,12 +410,67 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
               .route("/count", web::get().to(get_pending_follows_count))
               .route("/list", web::get().to(get_pending_follows_list))
               .route("/approve", web::post().to(post_pending_follows_approve)),
+          )
+          .service(
+            web::scope("/bot")
+              .route("", web::post().to(create_community_bot_delegation))
+              .route("", web::put().to(edit_community_bot_delegation))
+              .route("/revoke", web::post().to(revoke_community_bot_delegation))
+              .route("/list", web::get().to(list_community_bot_delegations))
+              .route("/actions", web::get().to(list_community_bot_actions)),
+          )
+          .service(
+            web::scope("/role")
+              .route("", web::post().to(set_community_role))
+              .route(
+                "/permissions",
+                web::get().to(list_community_role_permissions),
+              )
+              .route(
+                "/permissions",
+                web::put().to(edit_community_role_permission),
+              ),
+          )
+          .service(
+            web::scope("/ban_list")
+              .route("", web::get().to(get_ban_list))
+              .route("", web::post().to(create_ban_list))
+              .route("", web::put().to(edit_ban_list))
+              .route("/list", web::get().to(list_ban_lists))
+              .route("/subscribe", web::post().to(subscribe_ban_list))
+              .route("/ban", web::post().to(ban_from_ban_list))
+              .route("/exempt", web::post().to(exempt_from_ban_list)),
+          )
+          .service(
+            web::scope("/mirror")
+              .route("", web::post().to(create_community_mirror))
+              .route("", web::put().to(edit_community_mirror))
+              .route("/list", web::get().to(list_community_mirrors))
+              .route("/actions", web::get().to(list_community_mirror_actions)),
+          )
+          .service(
+            web::scope("/tag")
+              .route("", web::post().to(create_community_post_tag))
+              .route("", web::put().to(edit_community_post_tag))
+              .route("/delete", web::post().to(delete_community_post_tag))
+              .route("/list", web::get().to(list_community_post_tags)),
           ),
       )
+      .service(
+        web::scope("/interest_category")
+          .wrap(rate_limit.message())
+          .route("/list", web::get().to(list_interest_categories)),
+      )
+      .service(
+        web::scope("/report_reason_category")
+          .wrap(rate_limit.message())
+          .route("/list", web::get().to(list_report_reason_categories)),
+      )
       .service(
         web::scope("/federated_instances")
           .wrap(rate_limit.message())
-          .route("", web::get().to(get_federated_instances)),
+          .route("", web::get().to(get_federated_instances))
+          .route("/blocks", web::get().to(get_instance_blocks)),
       )
       // Post
       .service(
@@ -240,16 +489,21 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("/remove", web::post().to(remove_post))
           .route("/mark_as_read", web::post().to(mark_post_as_read))
           .route("/hide", web::post().to(hide_post))
+          .route("/hide/bulk", web::post().to(hide_posts))
           .route("/lock", web::post().to(lock_post))
           .route("/feature", web::post().to(feature_post))
           .route("/list", web::get().to(list_posts))
           .route("/like", web::post().to(like_post))
           .route("/like/list", web::get().to(list_post_likes))
+          .route("/pending/list", web::get().to(list_pending_posts))
+          .route("/pending/approve", web::post().to(approve_pending_post))
+          .route("/poll/vote", web::post().to(vote_poll))
           .route("/save", web::put().to(save_post))
           .route("/report", web::post().to(create_post_report))
           .route("/report/resolve", web::put().to(resolve_post_report))
           .route("/report/list", web::get().to(list_post_reports))
-          .route("/site_metadata", web::get().to(get_link_metadata)),
+          .route("/site_metadata", web::get().to(get_link_metadata))
+          .route("/comments/export", web::get().to(export_post_comments)),
       )
       // Comment
       .service(
@@ -268,13 +522,24 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("/remove", web::post().to(remove_comment))
           .route("/mark_as_read", web::post().to(mark_reply_as_read))
           .route("/distinguish", web::post().to(distinguish_comment))
+          .route("/pin", web::post().to(pin_comment))
           .route("/like", web::post().to(like_comment))
           .route("/like/list", web::get().to(list_comment_likes))
           .route("/save", web::put().to(save_comment))
           .route("/list", web::get().to(list_comments))
           .route("/report", web::post().to(create_comment_report))
           .route("/report/resolve", web::put().to(resolve_comment_report))
-          .route("/report/list", web::get().to(list_comment_reports)),
+          .route(
+            "/report/resolve_many",
+            web::put().to(resolve_comment_reports),
+          )
+          .route("/report/list", web::get().to(list_comment_reports))
+          .route("/report/note", web::post().to(create_comment_report_note))
+          .route("/report/note", web::put().to(edit_comment_report_note))
+          .route(
+            "/report/note/delete",
+            web::post().to(delete_comment_report_note),
+          ),
       )
       // Private Message
       .service(
@@ -285,10 +550,35 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("", web::put().to(update_private_message))
           .route("/delete", web::post().to(delete_private_message))
           .route("/mark_as_read", web::post().to(mark_pm_as_read))
+          .route("/conversation", web::get().to(get_pm_conversation))
+          .route("/conversation/list", web::get().to(list_pm_conversations))
+          .route("/conversation/group", web::post().to(create_pm_group))
+          .route(
+            "/conversation/participant",
+            web::post().to(add_pm_group_participant),
+          )
+          .route(
+            "/conversation/participant/remove",
+            web::post().to(remove_pm_group_participant),
+          )
+          .route(
+            "/conversation/mark_as_read",
+            web::post().to(mark_pm_conversation_as_read),
+          )
+          .route(
+            "/conversation/request/approve",
+            web::post().to(approve_pm_request),
+          )
           .route("/report", web::post().to(create_pm_report))
           .route("/report/resolve", web::put().to(resolve_pm_report))
           .route("/report/list", web::get().to(list_pm_reports)),
       )
+      // Reports of all types
+      .service(
+        web::scope("/report")
+          .wrap(rate_limit.message())
+          .route("/assign", web::put().to(assign_report)),
+      )
       // User
       .service(
         // Account action, I don't like that it's in /user maybe /accounts
@@ -309,6 +599,12 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .wrap(rate_limit.register())
           .route(web::post().to(login)),
       )
+      .service(
+        web::scope("/user/passkey/login")
+          .wrap(rate_limit.register())
+          .route("/start", web::post().to(start_passkey_login))
+          .route("/finish", web::post().to(finish_passkey_login)),
+      )
       .service(
         web::resource("/user/password_reset")
           .wrap(rate_limit.register())
@@ -325,16 +621,40 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .wrap(rate_limit.import_user_settings())
           .route(web::get().to(export_settings)),
       )
+      .service(
+        web::resource("/user/export_data")
+          .wrap(rate_limit.import_user_settings())
+          .route(web::get().to(export_data)),
+      )
       .service(
         web::resource("/user/import_settings")
           .wrap(rate_limit.import_user_settings())
           .route(web::post().to(import_settings)),
       )
       // TODO, all the current account related actions under /user need to get moved here eventually
+      .service(
+        // Checks a password, so it uses the same rate limit as login
+        web::resource("/account/link")
+          .guard(guard::Post())
+          .wrap(rate_limit.register())
+          .route(web::post().to(link_account)),
+      )
+      .service(
+        // Exchanges an authorization code, so it uses the same rate limit as login
+        web::resource("/account/oauth/link")
+          .guard(guard::Post())
+          .wrap(rate_limit.register())
+          .route(web::post().to(link_oauth_account)),
+      )
       .service(
         web::scope("/account")
           .wrap(rate_limit.message())
-          .route("/list_media", web::get().to(list_media)),
+          .route("/list_media", web::get().to(list_media))
+          .route("/linked", web::get().to(list_linked_accounts))
+          .route("/switch", web::post().to(switch_account))
+          .route("/unlink", web::post().to(unlink_account))
+          .route("/oauth/list", web::get().to(list_oauth_accounts))
+          .route("/oauth/unlink", web::post().to(unlink_oauth_account)),
       )
       // User actions
       .service(
@@ -347,10 +667,21 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
             web::post().to(mark_person_mention_as_read),
           )
           .route("/replies", web::get().to(list_replies))
+          .route("/inbox", web::get().to(list_inbox))
+          .route(
+            "/inbox/mark_all_as_read",
+            web::post().to(mark_inbox_as_read),
+          )
           // Admin action. I don't like that it's in /user
           .route("/ban", web::post().to(ban_from_site))
+          .route("/warn", web::post().to(warn_person))
+          .route("/warning/list", web::get().to(list_warnings))
           .route("/banned", web::get().to(list_banned_users))
           .route("/block", web::post().to(block_person))
+          .route("/block_keyword", web::post().to(block_keyword))
+          .route("/read_posts", web::get().to(list_read_posts))
+          .route("/follow", web::post().to(follow_person))
+          .route("/pin_to_profile", web::post().to(pin_to_profile))
           // TODO Account actions. I don't like that they're in /user maybe /accounts
           .route("/logout", web::post().to(logout))
           .route("/delete_account", web::post().to(delete_account))
@@ -366,12 +697,29 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("/save_user_settings", web::put().to(save_user_settings))
           .route("/change_password", web::put().to(change_password))
           .route("/report_count", web::get().to(report_count))
+          .route("/report_history", web::get().to(get_person_report_history))
+          .route("/mod_note", web::post().to(create_person_mod_note))
+          .route("/mod_note", web::put().to(edit_person_mod_note))
+          .route("/mod_note/delete", web::post().to(delete_person_mod_note))
+          .route("/mod_note/list", web::get().to(list_person_mod_notes))
+          .route("/report/list", web::get().to(list_reports))
           .route("/unread_count", web::get().to(unread_count))
           .route("/verify_email", web::post().to(verify_email))
           .route("/leave_admin", web::post().to(leave_admin))
           .route("/totp/generate", web::post().to(generate_totp_secret))
           .route("/totp/update", web::post().to(update_totp))
+          .route(
+            "/passkey/register/start",
+            web::post().to(start_passkey_registration),
+          )
+          .route(
+            "/passkey/register/finish",
+            web::post().to(finish_passkey_registration),
+          )
+          .route("/passkey/list", web::get().to(list_passkeys))
+          .route("/passkey/delete", web::post().to(delete_passkey))
           .route("/list_logins", web::get().to(list_logins))
+          .route("/revoke_login", web::post().to(revoke_login))
           .route("/validate_auth", web::get().to(validate_auth)),
       )
       // Admin Actions
@@ -391,11 +739,45 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
             "/registration_application/approve",
             web::put().to(approve_registration_application),
           )
+          .route(
+            "/registration_application/bursts",
+            web::get().to(list_registration_bursts),
+          )
           .route(
             "/registration_application",
             web::get().to(get_registration_application),
           )
+          .route("/spam/list", web::get().to(list_spam_scores))
+          .route("/spam/review", web::put().to(review_spam_score))
+          .route("/vote_anomaly/list", web::get().to(list_vote_anomalies))
+          .route("/vote_anomaly/review", web::put().to(review_vote_anomaly))
+          .route(
+            "/community_request/approve",
+            web::put().to(approve_community_request),
+          )
+          .route(
+            "/content_filter/preview",
+            web::post().to(preview_content_filters),
+          )
+          .route("/freeze", web::post().to(freeze_account))
+          .route("/freeze/list", web::get().to(list_account_freezes))
+          .route("/shadow_ban", web::post().to(shadow_ban_person))
+          .route("/shadow_ban/list", web::get().to(list_shadow_bans))
           .route("/list_all_media", web::get().to(list_all_media))
+          .route("/instance_block", web::put().to(edit_instance_block))
+          .route(
+            "/instance_trust_level",
+            web::put().to(edit_instance_trust_level),
+          )
+          .route("/maintenance_mode", web::put().to(set_maintenance_mode))
+          .route("/feature_flag", web::put().to(edit_feature_flag))
+          .route(
+            "/hook/validate_condition",
+            web::post().to(validate_hook_condition),
+          )
+          .route("/user_defaults/apply", web::post().to(apply_user_defaults))
+          .route("/client_usage", web::get().to(list_client_usage))
+          .route("/client_restriction", web::put().to(restrict_client))
           .service(
             web::scope("/purge")
               .route("/person", web::post().to(purge_person))
@@ -410,6 +792,27 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
               .route("", web::put().to(update_tagline))
               .route("/delete", web::post().to(delete_tagline))
               .route("/list", web::get().to(list_taglines)),
+          )
+          .service(
+            web::scope("/registration_question")
+              .wrap(rate_limit.message())
+              .route("", web::post().to(create_registration_question))
+              .route("", web::put().to(update_registration_question))
+              .route("/delete", web::post().to(delete_registration_question)),
+          )
+          .service(
+            web::scope("/interest_category")
+              .wrap(rate_limit.message())
+              .route("", web::post().to(create_interest_category))
+              .route("", web::put().to(update_interest_category))
+              .route("/delete", web::post().to(delete_interest_category)),
+          )
+          .service(
+            web::scope("/report_reason_category")
+              .wrap(rate_limit.message())
+              .route("", web::post().to(create_report_reason_category))
+              .route("", web::put().to(update_report_reason_category))
+              .route("/delete", web::post().to(delete_report_reason_category)),
           ),
       )
       .service(
@@ -420,6 +823,23 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("/delete", web::post().to(delete_custom_emoji))
           .route("/list", web::get().to(list_custom_emojis)),
       )
+      .service(
+        web::scope("/custom_feed")
+          .wrap(rate_limit.message())
+          .route("", web::get().to(get_custom_feed))
+          .route("", web::post().to(create_custom_feed))
+          .route("", web::put().to(update_custom_feed))
+          .route("/delete", web::post().to(delete_custom_feed))
+          .route("/list", web::get().to(list_custom_feeds)),
+      )
+      .service(
+        web::scope("/filter_rule")
+          .wrap(rate_limit.message())
+          .route("", web::post().to(create_filter_rule))
+          .route("", web::put().to(update_filter_rule))
+          .route("/delete", web::post().to(delete_filter_rule))
+          .route("/list", web::get().to(list_filter_rules)),
+      )
       .service(
         web::scope("/oauth_provider")
           .wrap(rate_limit.message())
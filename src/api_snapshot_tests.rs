@@ -0,0 +1,185 @@
+//! Snapshot tests for the JSON returned by the most used endpoints. If one of these fails, the
+//! API contract has changed and clients may break. After checking that the change is intended,
+//! update the snapshots with `cargo insta review`.
+
+use activitypub_federation::config::Data;
+use actix_web::{test::TestRequest, web::Query};
+use lemmy_api::{comment_report::list::list_comment_reports, post_report::list::list_post_reports};
+use lemmy_api_common::{
+  comment::ListCommentReports,
+  context::LemmyContext,
+  lemmy_db_views::structs::LocalUserView,
+  post::{GetPosts, ListPostReports},
+};
+use lemmy_api_crud::site::read::get_site;
+use lemmy_apub::api::list_posts::list_posts;
+use lemmy_db_schema::{
+  source::{
+    comment_report::{CommentReport, CommentReportForm},
+    local_site::{LocalSite, LocalSiteInsertForm},
+    local_site_rate_limit::{LocalSiteRateLimit, LocalSiteRateLimitInsertForm},
+    post_report::{PostReport, PostReportForm},
+    site::{Site, SiteInsertForm},
+  },
+  traits::{Crud, Reportable},
+  PostSortType,
+};
+use lemmy_db_testutils::{SeededCommunity, SeededInstance};
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+use serde::Serialize;
+use serde_json::Value;
+use serial_test::serial;
+
+/// Keys whose values are different on every run.
+const VOLATILE_KEYS: [&str; 9] = [
+  "published",
+  "updated",
+  "last_refreshed_at",
+  "newest_comment_time",
+  "newest_comment_time_necro",
+  "hot_rank",
+  "hot_rank_active",
+  "scaled_rank",
+  "next_page",
+];
+
+/// Serializes the response and replaces ids, urls, timestamps and ranks with placeholders, so that
+/// only the shape of the response and the seeded values remain.
+fn normalize<T: Serialize>(response: &T) -> LemmyResult<Value> {
+  fn walk(key: Option<&str>, value: &mut Value) {
+    match value {
+      Value::Object(map) => map.iter_mut().for_each(|(k, v)| walk(Some(k), v)),
+      Value::Array(values) => values.iter_mut().for_each(|v| walk(key, v)),
+      Value::Null => {}
+      _ => {
+        let Some(key) = key else { return };
+        if VOLATILE_KEYS.contains(&key) {
+          *value = Value::String(format!("[{key}]"));
+        } else if (key == "id" || key.ends_with("_id")) && value.is_number() {
+          *value = Value::String("[id]".to_string());
+        } else if value.as_str().is_some_and(|s| s.starts_with("http")) {
+          *value = Value::String("[url]".to_string());
+        }
+      }
+    }
+  }
+  let mut value = serde_json::to_value(response)?;
+  walk(None, &mut value);
+  Ok(value)
+}
+
+struct TestData {
+  instance: SeededInstance,
+  admin: LocalUserView,
+  community: SeededCommunity,
+}
+
+async fn init_data(context: &Data<LemmyContext>) -> LemmyResult<TestData> {
+  let pool = &mut context.pool();
+  let instance = SeededInstance::create(pool, "my_domain.tld").await?;
+
+  let site_form = SiteInsertForm::new("snapshot site".to_string(), instance.instance.id);
+  let site = Site::create(pool, &site_form).await?;
+  let local_site_form = LocalSiteInsertForm {
+    site_setup: Some(true),
+    ..LocalSiteInsertForm::new(site.id)
+  };
+  let local_site = LocalSite::create(pool, &local_site_form).await?;
+  let rate_limit_form = LocalSiteRateLimitInsertForm::new(local_site.id);
+  LocalSiteRateLimit::create(pool, &rate_limit_form).await?;
+
+  let (admin, _) = instance.admin(pool, "snapshot_admin").await?;
+  let admin = LocalUserView::read_person(pool, admin.id).await?;
+  let reporter = instance.person(pool, "snapshot_reporter").await?;
+  let community = instance
+    .community("snapshots")
+    .creator(admin.person.id)
+    .moderator(admin.person.id)
+    .posts(2)
+    .comments_per_post(1)
+    .create(pool)
+    .await?;
+
+  let post = community.posts.first().ok_or(LemmyErrorType::NotFound)?;
+  let post_report_form = PostReportForm {
+    creator_id: reporter.id,
+    post_id: post.id,
+    original_post_name: post.name.clone(),
+    original_post_url: None,
+    original_post_body: None,
+    reason: "spam".into(),
+  };
+  PostReport::report(pool, &post_report_form).await?;
+
+  let comment = community.comments.first().ok_or(LemmyErrorType::NotFound)?;
+  let comment_report_form = CommentReportForm {
+    creator_id: reporter.id,
+    comment_id: comment.id,
+    original_comment_text: comment.content.clone(),
+    reason: "rude".into(),
+    severity: None,
+    category_id: None,
+  };
+  CommentReport::report(pool, &comment_report_form).await?;
+
+  Ok(TestData {
+    instance,
+    admin,
+    community,
+  })
+}
+
+#[tokio::test]
+#[serial]
+async fn api_response_snapshots() -> LemmyResult<()> {
+  let context = LemmyContext::init_test_context().await;
+  let data = init_data(&context).await?;
+
+  let get_posts = GetPosts {
+    sort: Some(PostSortType::New),
+    community_id: Some(data.community.community.id),
+    ..Default::default()
+  };
+  let posts = list_posts(
+    Query(get_posts.clone()),
+    context.reset_request_count(),
+    None,
+  )
+  .await?;
+  insta::assert_json_snapshot!("get_posts", normalize(&posts.0)?);
+  let posts = list_posts(
+    Query(get_posts),
+    context.reset_request_count(),
+    Some(data.admin.clone()),
+  )
+  .await?;
+  insta::assert_json_snapshot!("get_posts_logged_in", normalize(&posts.0)?);
+
+  let site = get_site(
+    TestRequest::default().to_http_request(),
+    None,
+    context.reset_request_count(),
+  )
+  .await?;
+  insta::assert_json_snapshot!("get_site", normalize(&site.0)?);
+
+  let post_reports = list_post_reports(
+    Query(ListPostReports::default()),
+    context.reset_request_count(),
+    data.admin.clone(),
+  )
+  .await?;
+  insta::assert_json_snapshot!("list_post_reports", normalize(&post_reports.0)?);
+
+  let comment_reports = list_comment_reports(
+    Query(ListCommentReports::default()),
+    context.reset_request_count(),
+    data.admin,
+  )
+  .await?;
+  insta::assert_json_snapshot!("list_comment_reports", normalize(&comment_reports.0)?);
+
+  data.instance.delete(&mut context.pool()).await?;
+
+  Ok(())
+}
@@ -0,0 +1,49 @@
+use lemmy_api_common::{
+  context::LemmyContext,
+  utils::{invalidate_url_blocklist_cache, local_site_rate_limit_to_rate_limit_config},
+};
+use lemmy_api_crud::site::read::invalidate_site_cache;
+use lemmy_apub::invalidate_local_site_data_cache;
+use lemmy_db_schema::{
+  source::local_site::LocalSite,
+  utils::cache_invalidation::CacheInvalidation,
+};
+use lemmy_db_views::structs::SiteView;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Keeps listening for cache invalidations from all Lemmy processes using the same database, so
+/// that changes made through one process take effect everywhere.
+pub async fn setup(context: LemmyContext) {
+  loop {
+    let result = CacheInvalidation::listen(|cache| invalidate(cache, &context)).await;
+    if let Err(e) = result {
+      warn!("Listening for cache invalidations failed: {e}");
+    }
+    // The database might be restarting, so wait a bit before reconnecting
+    tokio::time::sleep(Duration::from_secs(10)).await;
+  }
+}
+
+async fn invalidate(cache: CacheInvalidation, context: &LemmyContext) {
+  info!("Invalidating cache {cache}");
+  match cache {
+    CacheInvalidation::SiteView => {
+      LocalSite::invalidate_cache();
+      invalidate_site_cache();
+      invalidate_local_site_data_cache();
+    }
+    CacheInvalidation::UrlBlocklist => {
+      invalidate_url_blocklist_cache();
+      invalidate_site_cache();
+    }
+    CacheInvalidation::RateLimits => match SiteView::read_local(&mut context.pool()).await {
+      Ok(site_view) => {
+        let rate_limit_config =
+          local_site_rate_limit_to_rate_limit_config(&site_view.local_site_rate_limit);
+        context.rate_limit_cell().set_config(rate_limit_config);
+      }
+      Err(e) => warn!("Failed to reload rate limits: {e}"),
+    },
+  }
+}
@@ -0,0 +1,259 @@
+use actix_web::{
+  body::MessageBody,
+  dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+  Error,
+};
+use chrono::{NaiveTime, Utc};
+use core::future::Ready;
+use futures_util::future::LocalBoxFuture;
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  source::client_usage::{ClientRestriction, ClientUsage, ClientUsageForm},
+  utils::DbPool,
+};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType, LemmyResult},
+  utils::validation::is_valid_client_name,
+  CACHE_DURATION_API,
+};
+use moka::future::Cache;
+use std::{
+  collections::HashMap,
+  future::ready,
+  rc::Rc,
+  sync::{Arc, LazyLock, Mutex},
+  time::Duration,
+};
+use tracing::warn;
+
+/// Optional header with which third-party apps identify themselves, eg `my-app/1.2`.
+pub const CLIENT_HEADER: &str = "X-Lemmy-Client";
+
+/// Upper bound for the number of distinct clients which are counted between two writes to the
+/// database, so that random header values can't use up memory.
+const MAX_PENDING_CLIENTS: usize = 1000;
+
+const FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Request counts which are not written to the database yet, and the request counts of
+/// throttled clients in the current minute.
+#[derive(Default)]
+struct ClientUsageState {
+  pending: HashMap<String, i64>,
+  throttle: HashMap<String, (i64, i32)>,
+}
+
+impl ClientUsageState {
+  fn record(&mut self, client: &str) {
+    if let Some(count) = self.pending.get_mut(client) {
+      *count += 1;
+    } else if self.pending.len() < MAX_PENDING_CLIENTS {
+      self.pending.insert(client.to_string(), 1);
+    }
+  }
+
+  /// Returns false if the client made more than the allowed number of requests in this minute.
+  fn check_throttle(&mut self, client: &str, requests_per_minute: i32, minute: i64) -> bool {
+    let entry = self
+      .throttle
+      .entry(client.to_string())
+      .or_insert((minute, 0));
+    if entry.0 != minute {
+      *entry = (minute, 0);
+    }
+    entry.1 += 1;
+    entry.1 <= requests_per_minute
+  }
+}
+
+/// Counts requests per client, as identified by the `X-Lemmy-Client` header, and enforces the
+/// client restrictions set by admins. Counts are written to the database once a minute.
+#[derive(Clone)]
+pub struct ClientUsageMiddleware {
+  context: LemmyContext,
+  state: Arc<Mutex<ClientUsageState>>,
+}
+
+impl ClientUsageMiddleware {
+  pub fn new(context: LemmyContext) -> Self {
+    let state = Arc::new(Mutex::new(ClientUsageState::default()));
+
+    let state_weak_ref = Arc::downgrade(&state);
+    let flush_context = context.clone();
+    tokio::spawn(async move {
+      // This loop stops when all other references to `state` are dropped
+      while let Some(state) = state_weak_ref.upgrade() {
+        tokio::time::sleep(FLUSH_INTERVAL).await;
+        flush_client_usage(&state, &mut flush_context.pool())
+          .await
+          .inspect_err(|e| warn!("Failed to write client usage: {e}"))
+          .ok();
+      }
+    });
+
+    ClientUsageMiddleware { context, state }
+  }
+}
+
+/// Writes the request counts collected since the last call to the database.
+async fn flush_client_usage(
+  state: &Mutex<ClientUsageState>,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  let pending = {
+    let mut state = state.lock().expect("Failed to lock client usage mutex");
+    let minute = current_minute();
+    state.throttle.retain(|_, (m, _)| *m == minute);
+    std::mem::take(&mut state.pending)
+  };
+  if pending.is_empty() {
+    return Ok(());
+  }
+
+  let day = Utc::now().date_naive().and_time(NaiveTime::MIN).and_utc();
+  let forms = pending
+    .into_iter()
+    .map(|(client, request_count)| ClientUsageForm {
+      client,
+      day,
+      request_count,
+    })
+    .collect::<Vec<_>>();
+  ClientUsage::increment(pool, &forms).await?;
+  Ok(())
+}
+
+/// All client restrictions, mapping the client to its allowed requests per minute, or none if
+/// it is blocked.
+async fn client_restrictions(context: &LemmyContext) -> Arc<HashMap<String, Option<i32>>> {
+  static CACHE: LazyLock<Cache<(), Arc<HashMap<String, Option<i32>>>>> = LazyLock::new(|| {
+    Cache::builder()
+      .max_capacity(1)
+      .time_to_live(CACHE_DURATION_API)
+      .build()
+  });
+  CACHE
+    .try_get_with((), async {
+      let restrictions = ClientRestriction::list(&mut context.pool()).await?;
+      let restrictions = restrictions
+        .into_iter()
+        .map(|r| (r.client, r.requests_per_minute))
+        .collect();
+      LemmyResult::Ok(Arc::new(restrictions))
+    })
+    .await
+    .unwrap_or_default()
+}
+
+fn current_minute() -> i64 {
+  Utc::now().timestamp() / 60
+}
+
+impl<S, B> Transform<S, ServiceRequest> for ClientUsageMiddleware
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+  S::Future: 'static,
+  B: MessageBody + 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = Error;
+  type Transform = ClientUsageService<S>;
+  type InitError = ();
+  type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+  fn new_transform(&self, service: S) -> Self::Future {
+    ready(Ok(ClientUsageService {
+      service: Rc::new(service),
+      context: self.context.clone(),
+      state: self.state.clone(),
+    }))
+  }
+}
+
+pub struct ClientUsageService<S> {
+  service: Rc<S>,
+  context: LemmyContext,
+  state: Arc<Mutex<ClientUsageState>>,
+}
+
+impl<S, B> Service<ServiceRequest> for ClientUsageService<S>
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+  S::Future: 'static,
+  B: 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = Error;
+  type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+  forward_ready!(service);
+
+  fn call(&self, req: ServiceRequest) -> Self::Future {
+    let svc = self.service.clone();
+    let context = self.context.clone();
+    let state = self.state.clone();
+    let client = req
+      .headers()
+      .get(CLIENT_HEADER)
+      .and_then(|h| h.to_str().ok())
+      .filter(|c| is_valid_client_name(c).is_ok())
+      .map(ToString::to_string);
+
+    Box::pin(async move {
+      if let Some(client) = client {
+        state
+          .lock()
+          .expect("Failed to lock client usage mutex")
+          .record(&client);
+
+        match client_restrictions(&context).await.get(&client) {
+          Some(None) => return Err(LemmyError::from(LemmyErrorType::ClientBlocked).into()),
+          Some(Some(requests_per_minute)) => {
+            let allowed = state
+              .lock()
+              .expect("Failed to lock client usage mutex")
+              .check_throttle(&client, *requests_per_minute, current_minute());
+            if !allowed {
+              return Err(LemmyError::from(LemmyErrorType::RateLimitError).into());
+            }
+          }
+          None => {}
+        }
+      }
+      svc.call(req).await
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::*;
+
+  #[test]
+  fn test_check_throttle() {
+    let mut state = ClientUsageState::default();
+    assert!(state.check_throttle("my-app", 2, 1));
+    assert!(state.check_throttle("my-app", 2, 1));
+    assert!(!state.check_throttle("my-app", 2, 1));
+    assert!(state.check_throttle("other-app", 2, 1));
+    // A new minute resets the count
+    assert!(state.check_throttle("my-app", 2, 2));
+  }
+
+  #[test]
+  fn test_record() {
+    let mut state = ClientUsageState::default();
+    state.record("my-app");
+    state.record("my-app");
+    assert_eq!(Some(&2), state.pending.get("my-app"));
+
+    for i in 0..MAX_PENDING_CLIENTS {
+      state.record(&format!("app-{i}"));
+    }
+    assert_eq!(MAX_PENDING_CLIENTS, state.pending.len());
+    // Already known clients are still counted once the limit is reached
+    state.record("my-app");
+    assert_eq!(Some(&3), state.pending.get("my-app"));
+  }
+}
@@ -0,0 +1,147 @@
+use actix_cors::{Cors, CorsMiddleware};
+use actix_web::{
+  body::{EitherBody, MessageBody},
+  dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+  Error,
+};
+use futures_util::future::LocalBoxFuture;
+use lemmy_utils::settings::structs::{CorsPolicy, Settings};
+use std::rc::Rc;
+
+/// Applies a different CORS policy depending on the request path, as configured in
+/// `settings.cors.route_groups`.
+pub struct RouteGroupCors {
+  groups: Vec<(String, Cors)>,
+  default: Cors,
+}
+
+impl RouteGroupCors {
+  pub fn new(settings: &Settings) -> Self {
+    let mut default_policy = settings.cors.default.clone();
+    if let Some(origin) = settings.cors_origin() {
+      default_policy.allowed_origins = vec![origin];
+    }
+    RouteGroupCors {
+      groups: settings
+        .cors
+        .route_groups
+        .iter()
+        .map(|g| {
+          let path = g.path.trim_end_matches('/').to_string();
+          (path, cors_for_policy(&g.policy, settings))
+        })
+        .collect(),
+      default: cors_for_policy(&default_policy, settings),
+    }
+  }
+}
+
+fn cors_for_policy(policy: &CorsPolicy, settings: &Settings) -> Cors {
+  let allow_any_origin = cfg!(debug_assertions) || policy.allowed_origins.iter().any(|o| o == "*");
+
+  let mut cors = Cors::default()
+    .allow_any_method()
+    .expose_any_header()
+    .max_age(policy.max_age);
+
+  // Need to call allow_any_origin() explicitly, passing "*" into allowed_origin() results in
+  // error
+  if allow_any_origin {
+    cors = cors.allow_any_origin();
+  } else {
+    cors = cors.allowed_origin(&settings.get_protocol_and_hostname());
+    for origin in &policy.allowed_origins {
+      cors = cors.allowed_origin(origin);
+    }
+  }
+
+  if policy.allowed_headers.is_empty() {
+    cors.allow_any_header()
+  } else {
+    cors.allowed_headers(policy.allowed_headers.iter().map(String::as_str))
+  }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RouteGroupCors
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+  S::Future: 'static,
+  B: MessageBody + 'static,
+{
+  type Response = ServiceResponse<EitherBody<B>>;
+  type Error = Error;
+  type Transform = RouteGroupCorsService<S>;
+  type InitError = ();
+  type Future = LocalBoxFuture<'static, Result<Self::Transform, Self::InitError>>;
+
+  fn new_transform(&self, service: S) -> Self::Future {
+    let service = Rc::new(service);
+    let groups: Vec<_> = self
+      .groups
+      .iter()
+      .map(|(path, cors)| (path.clone(), cors.new_transform(service.clone())))
+      .collect();
+    let default = self.default.new_transform(service.clone());
+
+    Box::pin(async move {
+      let mut group_services = Vec::with_capacity(groups.len());
+      for (path, cors) in groups {
+        group_services.push((path, cors.await?));
+      }
+      Ok(RouteGroupCorsService {
+        service,
+        groups: group_services,
+        default: default.await?,
+      })
+    })
+  }
+}
+
+pub struct RouteGroupCorsService<S> {
+  service: Rc<S>,
+  groups: Vec<(String, CorsMiddleware<Rc<S>>)>,
+  default: CorsMiddleware<Rc<S>>,
+}
+
+impl<S, B> Service<ServiceRequest> for RouteGroupCorsService<S>
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+  S::Future: 'static,
+  B: MessageBody + 'static,
+{
+  type Response = ServiceResponse<EitherBody<B>>;
+  type Error = Error;
+  type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+  forward_ready!(service);
+
+  fn call(&self, req: ServiceRequest) -> Self::Future {
+    let path = req.path();
+    let cors = self
+      .groups
+      .iter()
+      .find(|(prefix, _)| is_under_path(path, prefix))
+      .map(|(_, cors)| cors)
+      .unwrap_or(&self.default);
+    cors.call(req)
+  }
+}
+
+/// Only matches whole path segments, so that `/api/v3` doesn't cover `/api/v30`.
+fn is_under_path(path: &str, prefix: &str) -> bool {
+  path == prefix || path.starts_with(&format!("{prefix}/"))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_is_under_path() {
+    assert!(is_under_path("/api/v3", "/api/v3"));
+    assert!(is_under_path("/api/v3/post/list", "/api/v3"));
+    assert!(!is_under_path("/api/v30/post/list", "/api/v3"));
+    assert!(!is_under_path("/api/v3post", "/api/v3"));
+    assert!(!is_under_path("/pictrs/image", "/api/v3"));
+  }
+}
@@ -0,0 +1,183 @@
+//! Serves the API over HTTP/3. Actix can't accept QUIC connections, so requests received here are
+//! forwarded to the HTTP listener, which handles them like any other request.
+
+use bytes::{Buf, Bytes, BytesMut};
+use h3::{error::ErrorLevel, server::RequestStream};
+use h3_quinn::BidiStream;
+use http::{
+  header::{CONNECTION, FORWARDED, HOST, TRANSFER_ENCODING, UPGRADE},
+  uri::PathAndQuery,
+  HeaderName,
+  HeaderValue,
+  Request,
+  Response,
+  StatusCode,
+};
+use lemmy_utils::{error::LemmyResult, settings::structs::Http3Config};
+use quinn::{crypto::rustls::QuicServerConfig, Endpoint, Incoming, ServerConfig};
+use reqwest::{redirect::Policy, Client};
+use std::{
+  fs::File,
+  io::{self, BufReader},
+  net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+  sync::Arc,
+};
+use tracing::debug;
+
+/// Request bodies are buffered before forwarding, so bigger ones are rejected.
+const MAX_BODY_SIZE: usize = 100 * 1024 * 1024;
+
+const X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+const X_FORWARDED_PROTO: HeaderName = HeaderName::from_static("x-forwarded-proto");
+
+/// Starts accepting QUIC connections on `bind`, and forwards the requests to the HTTP listener at
+/// `upstream`.
+pub fn serve_http3(config: &Http3Config, bind: IpAddr, upstream: SocketAddr) -> LemmyResult<()> {
+  let endpoint = Endpoint::server(server_config(config)?, (bind, config.port).into())?;
+  let client = Client::builder()
+    .redirect(Policy::none())
+    .no_gzip()
+    .build()?;
+  let upstream = local_address(upstream);
+
+  tokio::task::spawn(async move {
+    while let Some(incoming) = endpoint.accept().await {
+      let client = client.clone();
+      tokio::task::spawn(async move {
+        if let Err(e) = handle_connection(incoming, client, upstream).await {
+          debug!("HTTP/3 connection failed: {e}");
+        }
+      });
+    }
+  });
+  Ok(())
+}
+
+fn server_config(config: &Http3Config) -> LemmyResult<ServerConfig> {
+  let certificates = rustls_pemfile::certs(&mut BufReader::new(File::open(&config.certificate)?))
+    .collect::<Result<Vec<_>, _>>()?;
+  let private_key =
+    rustls_pemfile::private_key(&mut BufReader::new(File::open(&config.private_key)?))?
+      .ok_or_else(|| io::Error::other("No private key found for HTTP/3"))?;
+
+  // QUIC only works with TLS 1.3
+  let mut tls =
+    rustls::ServerConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+      .with_protocol_versions(&[&rustls::version::TLS13])?
+      .with_no_client_auth()
+      .with_single_cert(certificates, private_key)?;
+  tls.alpn_protocols = vec![b"h3".to_vec()];
+
+  Ok(ServerConfig::with_crypto(Arc::new(
+    QuicServerConfig::try_from(tls)?,
+  )))
+}
+
+/// The HTTP listener may be bound to all interfaces, but requests are forwarded over loopback.
+fn local_address(mut address: SocketAddr) -> SocketAddr {
+  if address.ip().is_unspecified() {
+    address.set_ip(match address.ip() {
+      IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+      IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+    });
+  }
+  address
+}
+
+async fn handle_connection(
+  incoming: Incoming,
+  client: Client,
+  upstream: SocketAddr,
+) -> LemmyResult<()> {
+  let connection = incoming.await?;
+  let remote = connection.remote_address();
+  let mut connection =
+    h3::server::Connection::<_, Bytes>::new(h3_quinn::Connection::new(connection)).await?;
+
+  loop {
+    match connection.accept().await {
+      Ok(Some((request, stream))) => {
+        let client = client.clone();
+        tokio::task::spawn(async move {
+          if let Err(e) = forward(request, stream, &client, upstream, remote).await {
+            debug!("HTTP/3 request failed: {e}");
+          }
+        });
+      }
+      Ok(None) => return Ok(()),
+      // Only this request is broken, the connection can still be used
+      Err(e) if matches!(e.get_error_level(), ErrorLevel::StreamError) => continue,
+      Err(e) => Err(e)?,
+    }
+  }
+}
+
+async fn forward(
+  request: Request<()>,
+  mut stream: RequestStream<BidiStream<Bytes>, Bytes>,
+  client: &Client,
+  upstream: SocketAddr,
+  remote: SocketAddr,
+) -> LemmyResult<()> {
+  let mut body = BytesMut::new();
+  while let Some(mut chunk) = stream.recv_data().await? {
+    if body.len() + chunk.remaining() > MAX_BODY_SIZE {
+      return respond_with_status(stream, StatusCode::PAYLOAD_TOO_LARGE).await;
+    }
+    body.extend_from_slice(&chunk.copy_to_bytes(chunk.remaining()));
+  }
+
+  let (parts, ()) = request.into_parts();
+  let path = parts.uri.path_and_query().map_or("/", PathAndQuery::as_str);
+  let mut headers = parts.headers;
+  if let Some(authority) = parts.uri.authority() {
+    headers.insert(HOST, HeaderValue::from_str(authority.as_str())?);
+  }
+  // This is the edge of the server, so forwarding headers sent by the client can't be trusted
+  headers.remove(FORWARDED);
+  headers.insert(
+    X_FORWARDED_FOR,
+    HeaderValue::from_str(&remote.ip().to_string())?,
+  );
+  headers.insert(X_FORWARDED_PROTO, HeaderValue::from_static("https"));
+
+  let upstream_response = client
+    .request(parts.method, format!("http://{upstream}{path}"))
+    .headers(headers)
+    .body(body.freeze())
+    .send()
+    .await;
+  let upstream_response = match upstream_response {
+    Ok(r) => r,
+    Err(e) => {
+      respond_with_status(stream, StatusCode::BAD_GATEWAY).await?;
+      Err(e)?
+    }
+  };
+
+  let mut response = Response::new(());
+  *response.status_mut() = upstream_response.status();
+  *response.headers_mut() = upstream_response.headers().clone();
+  // Connection specific headers are not allowed in HTTP/3
+  for name in [CONNECTION, TRANSFER_ENCODING, UPGRADE] {
+    response.headers_mut().remove(name);
+  }
+  response.headers_mut().remove("keep-alive");
+  let body = upstream_response.bytes().await?;
+
+  stream.send_response(response).await?;
+  if !body.is_empty() {
+    stream.send_data(body).await?;
+  }
+  Ok(stream.finish().await?)
+}
+
+async fn respond_with_status(
+  mut stream: RequestStream<BidiStream<Bytes>, Bytes>,
+  status: StatusCode,
+) -> LemmyResult<()> {
+  let mut response = Response::new(());
+  *response.status_mut() = status;
+  stream.send_response(response).await?;
+  Ok(stream.finish().await?)
+}
@@ -1,15 +1,30 @@
 pub mod api_routes_http;
+#[cfg(test)]
+mod api_snapshot_tests;
+pub mod cache_invalidation;
+pub mod client_usage_middleware;
 pub mod code_migrations;
+pub mod cors;
+#[cfg(feature = "http3")]
+mod http3;
+pub mod listener;
+pub mod maintenance_middleware;
 pub mod prometheus_metrics;
 pub mod scheduled_tasks;
 pub mod session_middleware;
 
-use crate::{code_migrations::run_advanced_migrations, session_middleware::SessionMiddleware};
+use crate::{
+  client_usage_middleware::ClientUsageMiddleware,
+  code_migrations::run_advanced_migrations,
+  cors::RouteGroupCors,
+  listener::Listener,
+  maintenance_middleware::MaintenanceMiddleware,
+  session_middleware::SessionMiddleware,
+};
 use activitypub_federation::config::{FederationConfig, FederationMiddleware};
-use actix_cors::Cors;
 use actix_web::{
   dev::{ServerHandle, ServiceResponse},
-  middleware::{self, Condition, ErrorHandlerResponse, ErrorHandlers},
+  middleware::{self, Condition, DefaultHeaders, ErrorHandlerResponse, ErrorHandlers},
   web::Data,
   App,
   HttpResponse,
@@ -33,9 +48,25 @@ use lemmy_apub::{
   VerifyUrlData,
   FEDERATION_HTTP_FETCH_LIMIT,
 };
-use lemmy_db_schema::{source::secret::Secret, utils::build_db_pool};
+use lemmy_db_schema::{
+  source::{
+    local_site::{LocalSite, LocalSiteUpdateForm},
+    secret::Secret,
+  },
+  utils::{
+    build_db_pool,
+    partition::{repartition, PARTITIONED_ACTION_TABLES},
+  },
+};
 use lemmy_federate::{Opts, SendManager};
-use lemmy_routes::{feeds, images, nodeinfo, webfinger};
+use lemmy_routes::{
+  compression::{compress, CompressionFilter},
+  embed,
+  feeds,
+  images,
+  nodeinfo,
+  webfinger,
+};
 use lemmy_utils::{
   error::LemmyResult,
   rate_limit::RateLimitCell,
@@ -102,6 +133,19 @@ pub struct CmdArgs {
   /// If set, make sure to set --federate-process-index differently for each.
   #[arg(long, default_value_t = 1, env = "LEMMY_FEDERATE_PROCESS_COUNT")]
   federate_process_count: i32,
+  /// Turn maintenance mode on or off, then exit without starting the server.
+  ///
+  /// While maintenance mode is on, all mutating API calls return status 503 and incoming
+  /// activities are queued until it is turned off again. Reads keep working.
+  #[arg(long)]
+  set_maintenance_mode: Option<bool>,
+  /// Split the post and comment action tables into the given number of partitions, then exit
+  /// without starting the server.
+  ///
+  /// This locks the tables until all rows are moved, so turn on maintenance mode first. More
+  /// partitions keep the indexes of each partition smaller on instances with many users.
+  #[arg(long)]
+  repartition_action_tables: Option<i32>,
 }
 
 /// Placing the main function in lib.rs allows other crates to import it and embed Lemmy
@@ -109,10 +153,34 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
   // Print version number to log
   println!("Starting Lemmy v{VERSION}");
 
+  if let Some(maintenance_mode) = args.set_maintenance_mode {
+    let pool = build_db_pool()?;
+    let form = LocalSiteUpdateForm {
+      maintenance_mode: Some(maintenance_mode),
+      ..Default::default()
+    };
+    LocalSite::update(&mut (&pool).into(), &form).await?;
+    println!("Maintenance mode set to {maintenance_mode}");
+    return Ok(());
+  }
+
+  if let Some(count) = args.repartition_action_tables {
+    let pool = build_db_pool()?;
+    for table in PARTITIONED_ACTION_TABLES {
+      println!("Repartitioning {table}...");
+      repartition(&mut (&pool).into(), table, count).await?;
+    }
+    println!("Action tables split into {count} partitions");
+    return Ok(());
+  }
+
   // return error 503 while running db migrations and startup tasks
   let mut startup_server_handle = None;
+  let mut listener = None;
   if !args.disable_http_server {
-    startup_server_handle = Some(create_startup_server()?);
+    let l = Listener::new(&SETTINGS)?;
+    startup_server_handle = Some(create_startup_server(l.try_clone()?)?);
+    listener = Some(l);
   }
 
   // Set up the connection pool
@@ -140,11 +208,6 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
     local_site_rate_limit_to_rate_limit_config(&site_view.local_site_rate_limit);
   let rate_limit_cell = RateLimitCell::new(rate_limit_config);
 
-  println!(
-    "Starting HTTP server at {}:{}",
-    SETTINGS.bind, SETTINGS.port
-  );
-
   let client = ClientBuilder::new(client_builder(&SETTINGS).build()?)
     .with(TracingMiddleware::default())
     .build();
@@ -155,6 +218,9 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
     rate_limit_cell.clone(),
   );
 
+  // Keep caches consistent with other Lemmy processes using the same database
+  tokio::task::spawn(cache_invalidation::setup(context.clone()));
+
   if let Some(prometheus) = SETTINGS.prometheus.clone() {
     serve_prometheus(prometheus, context.clone())?;
   }
@@ -189,15 +255,17 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
     tokio::task::spawn(scheduled_tasks::setup(request_data.reset_request_count()))
   });
 
-  let server = if !args.disable_http_server {
+  let server = if let Some(listener) = listener {
     if let Some(startup_server_handle) = startup_server_handle {
       startup_server_handle.stop(true).await;
     }
 
+    println!("Starting HTTP server at {listener}");
     Some(create_http_server(
       federation_config.clone(),
       SETTINGS.clone(),
       federation_enabled,
+      listener,
     )?)
   } else {
     None
@@ -252,7 +320,7 @@ pub async fn start_lemmy_server(args: CmdArgs) -> LemmyResult<()> {
 }
 
 /// Creates temporary HTTP server which returns status 503 for all requests.
-fn create_startup_server() -> LemmyResult<ServerHandle> {
+fn create_startup_server(listener: Listener) -> LemmyResult<ServerHandle> {
   let startup_server = HttpServer::new(move || {
     App::new().wrap(ErrorHandlers::new().default_handler(move |req| {
       let (req, _) = req.into_parts();
@@ -263,8 +331,11 @@ fn create_startup_server() -> LemmyResult<ServerHandle> {
         service_response.map_into_right_body(),
       ))
     }))
-  })
-  .bind((SETTINGS.bind, SETTINGS.port))?
+  });
+  let startup_server = match listener {
+    Listener::Tcp(l) => startup_server.listen(l)?,
+    Listener::Unix(l) => startup_server.listen_uds(l)?,
+  }
   .run();
   let startup_server_handle = startup_server.handle();
   tokio::task::spawn(startup_server);
@@ -275,6 +346,7 @@ fn create_http_server(
   federation_config: FederationConfig<LemmyContext>,
   settings: Settings,
   federation_enabled: bool,
+  listener: Listener,
 ) -> LemmyResult<ServerHandle> {
   // this must come before the HttpServer creation
   // creates a middleware that populates http metrics for each path, method, and status code
@@ -291,10 +363,13 @@ fn create_http_server(
     .with(TracingMiddleware::default())
     .build();
 
+  let client_usage = ClientUsageMiddleware::new(context.clone());
+
+  let alt_svc = serve_http3(&settings, &listener)?;
+
   // Create Http server
-  let bind = (settings.bind, settings.port);
   let server = HttpServer::new(move || {
-    let cors_config = cors_config(&settings);
+    let cors_config = RouteGroupCors::new(&settings);
     let app = App::new()
       .wrap(middleware::Logger::new(
         // This is the default log format save for the usage of %{r}a over %a to guarantee to
@@ -302,7 +377,8 @@ fn create_http_server(
         // frequently just a reverse proxy
         "%{r}a '%r' %s %b '%{Referer}i' '%{User-Agent}i' %T",
       ))
-      .wrap(middleware::Compress::default())
+      .wrap(CompressionFilter::new(&settings.compression))
+      .wrap(compress(&settings.compression))
       .wrap(cors_config)
       .wrap(TracingLogger::<DefaultRootSpanBuilder>::new())
       .wrap(ErrorHandlers::new().default_handler(jsonify_plain_text_errors))
@@ -310,9 +386,15 @@ fn create_http_server(
       .app_data(Data::new(rate_limit_cell.clone()))
       .wrap(FederationMiddleware::new(federation_config.clone()))
       .wrap(SessionMiddleware::new(context.clone()))
+      .wrap(MaintenanceMiddleware::new(context.clone()))
+      .wrap(client_usage.clone())
       .wrap(Condition::new(
         SETTINGS.prometheus.is_some(),
         prom_api_metrics.clone(),
+      ))
+      .wrap(Condition::new(
+        alt_svc.is_some(),
+        DefaultHeaders::new().add(("Alt-Svc", alt_svc.clone().unwrap_or_default())),
       ));
 
     // The routes
@@ -325,45 +407,43 @@ fn create_http_server(
         }
       })
       .configure(feeds::config)
+      .configure(embed::config)
       .configure(|cfg| images::config(cfg, pictrs_client.clone(), &rate_limit_cell))
       .configure(nodeinfo::config)
   })
-  .disable_signals()
-  .bind(bind)?
+  .disable_signals();
+  let server = match listener {
+    Listener::Tcp(l) => server.listen(l)?,
+    Listener::Unix(l) => server.listen_uds(l)?,
+  }
   .run();
   let handle = server.handle();
   tokio::task::spawn(server);
   Ok(handle)
 }
 
-fn cors_config(settings: &Settings) -> Cors {
-  let self_origin = settings.get_protocol_and_hostname();
-  let cors_origin_setting = settings.cors_origin();
-
-  // A default setting for either wildcard, or None
-  let cors_default = Cors::default()
-    .allow_any_origin()
-    .allow_any_method()
-    .allow_any_header()
-    .expose_any_header()
-    .max_age(3600);
-
-  match (cors_origin_setting.clone(), cfg!(debug_assertions)) {
-    (Some(origin), false) => {
-      // Need to call send_wildcard() explicitly, passing this into allowed_origin() results in
-      // error
-      if origin == "*" {
-        cors_default
-      } else {
-        Cors::default()
-          .allowed_origin(&origin)
-          .allowed_origin(&self_origin)
-          .allow_any_method()
-          .allow_any_header()
-          .expose_any_header()
-          .max_age(3600)
-      }
+/// Starts the HTTP/3 listener if it is configured, and returns the `Alt-Svc` header which tells
+/// clients about it.
+#[cfg(feature = "http3")]
+fn serve_http3(settings: &Settings, listener: &Listener) -> LemmyResult<Option<String>> {
+  let (Some(config), Listener::Tcp(tcp)) = (&settings.http3, listener) else {
+    if settings.http3.is_some() {
+      tracing::warn!("HTTP/3 needs a TCP listener to forward requests to, not starting it");
     }
-    _ => cors_default,
-  }
+    return Ok(None);
+  };
+  http3::serve_http3(config, settings.bind, tcp.local_addr()?)?;
+  println!(
+    "Starting HTTP/3 server at {}:{}",
+    settings.bind, config.port
+  );
+  Ok(Some(format!(
+    "h3=\":{}\"; ma={}",
+    config.port, config.max_age
+  )))
+}
+
+#[cfg(not(feature = "http3"))]
+fn serve_http3(_settings: &Settings, _listener: &Listener) -> LemmyResult<Option<String>> {
+  Ok(None)
 }
@@ -0,0 +1,95 @@
+use lemmy_utils::{error::LemmyResult, settings::structs::Settings};
+use std::{
+  env,
+  fmt,
+  fs,
+  io,
+  net::TcpListener,
+  os::unix::{
+    fs::PermissionsExt,
+    io::{FromRawFd, IntoRawFd},
+    net::UnixListener,
+  },
+  path::Path,
+};
+
+/// First file descriptor passed by systemd, see `sd_listen_fds(3)`
+const SD_LISTEN_FDS_START: i32 = 3;
+
+/// The socket which the HTTP server accepts connections on.
+pub enum Listener {
+  Tcp(TcpListener),
+  Unix(UnixListener),
+}
+
+impl Listener {
+  /// Opens the socket according to the settings. This needs to happen only once, so that the
+  /// startup server and the actual HTTP server can share it.
+  pub fn new(settings: &Settings) -> LemmyResult<Self> {
+    if settings.systemd_socket_activation {
+      return Self::from_systemd();
+    }
+    if let Some(unix_socket) = &settings.unix_socket {
+      let path = Path::new(&unix_socket.path);
+      // Left over from a previous run which wasn't shut down cleanly
+      if path.exists() {
+        fs::remove_file(path)?;
+      }
+      let listener = UnixListener::bind(path)?;
+      let mode = u32::from_str_radix(&unix_socket.permissions, 8)?;
+      fs::set_permissions(path, fs::Permissions::from_mode(mode))?;
+      return Ok(Listener::Unix(listener));
+    }
+    Ok(Listener::Tcp(TcpListener::bind((
+      settings.bind,
+      settings.port,
+    ))?))
+  }
+
+  fn from_systemd() -> LemmyResult<Self> {
+    let listen_pid = env::var("LISTEN_PID")?.parse::<u32>()?;
+    let listen_fds = env::var("LISTEN_FDS")?.parse::<i32>()?;
+    if listen_pid != std::process::id() || listen_fds < 1 {
+      Err(io::Error::other("No socket passed by systemd"))?
+    }
+
+    // SAFETY: Systemd guarantees that the file descriptor is an open socket which belongs to this
+    // process (checked via LISTEN_PID above). It is only taken once.
+    let tcp = unsafe { TcpListener::from_raw_fd(SD_LISTEN_FDS_START) };
+    if tcp.local_addr().is_ok() {
+      return Ok(Listener::Tcp(tcp));
+    }
+    // Not an inet socket, so it must be a unix socket
+    // SAFETY: Ownership of the file descriptor is moved from the tcp listener.
+    let unix = unsafe { UnixListener::from_raw_fd(tcp.into_raw_fd()) };
+    Ok(Listener::Unix(unix))
+  }
+
+  pub fn try_clone(&self) -> LemmyResult<Self> {
+    Ok(match self {
+      Listener::Tcp(l) => Listener::Tcp(l.try_clone()?),
+      Listener::Unix(l) => Listener::Unix(l.try_clone()?),
+    })
+  }
+}
+
+impl fmt::Display for Listener {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Listener::Tcp(l) => match l.local_addr() {
+        Ok(addr) => write!(f, "{addr}"),
+        Err(_) => write!(f, "unknown address"),
+      },
+      Listener::Unix(l) => {
+        let path = l
+          .local_addr()
+          .ok()
+          .and_then(|a| a.as_pathname().map(Path::to_owned));
+        match path {
+          Some(path) => write!(f, "unix:{}", path.display()),
+          None => write!(f, "unnamed unix socket"),
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,119 @@
+use actix_web::{
+  body::MessageBody,
+  dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+  http::Method,
+  Error,
+};
+use core::future::Ready;
+use futures_util::future::LocalBoxFuture;
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::source::local_site::LocalSite;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+use std::{future::ready, rc::Rc};
+
+/// Endpoints which keep working in maintenance mode, so that admins can login and turn it off
+/// again.
+const MAINTENANCE_EXEMPT_PATHS: [&str; 2] = ["/user/login", "/admin/maintenance_mode"];
+
+/// Rejects all mutating requests with status 503 while the site is in maintenance mode. Reads
+/// keep working, and incoming activities are queued by the inbox itself.
+#[derive(Clone)]
+pub struct MaintenanceMiddleware {
+  context: LemmyContext,
+}
+
+impl MaintenanceMiddleware {
+  pub fn new(context: LemmyContext) -> Self {
+    MaintenanceMiddleware { context }
+  }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for MaintenanceMiddleware
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+  S::Future: 'static,
+  B: MessageBody + 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = Error;
+  type Transform = MaintenanceService<S>;
+  type InitError = ();
+  type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+  fn new_transform(&self, service: S) -> Self::Future {
+    ready(Ok(MaintenanceService {
+      service: Rc::new(service),
+      context: self.context.clone(),
+    }))
+  }
+}
+
+pub struct MaintenanceService<S> {
+  service: Rc<S>,
+  context: LemmyContext,
+}
+
+impl<S, B> Service<ServiceRequest> for MaintenanceService<S>
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+  S::Future: 'static,
+  B: 'static,
+{
+  type Response = ServiceResponse<B>;
+  type Error = Error;
+  type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+  forward_ready!(service);
+
+  fn call(&self, req: ServiceRequest) -> Self::Future {
+    let svc = self.service.clone();
+    let context = self.context.clone();
+
+    Box::pin(async move {
+      if is_mutating_request(&req) {
+        let maintenance_mode = LocalSite::read(&mut context.pool())
+          .await
+          .map(|l| l.maintenance_mode)
+          .unwrap_or_default();
+        if maintenance_mode {
+          return Err(LemmyError::from(LemmyErrorType::MaintenanceMode).into());
+        }
+      }
+      svc.call(req).await
+    })
+  }
+}
+
+fn is_mutating_request(req: &ServiceRequest) -> bool {
+  let safe_method = [Method::GET, Method::HEAD, Method::OPTIONS].contains(req.method());
+  let path = req.path();
+  let exempt =
+    path.ends_with("/inbox") || MAINTENANCE_EXEMPT_PATHS.iter().any(|p| path.ends_with(p));
+  !safe_method && !exempt
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::*;
+  use actix_web::test::TestRequest;
+
+  #[test]
+  fn test_is_mutating_request() {
+    let mutating = |method: Method, path: &str| {
+      is_mutating_request(
+        &TestRequest::default()
+          .method(method)
+          .uri(path)
+          .to_srv_request(),
+      )
+    };
+    assert!(!mutating(Method::GET, "/api/v3/post/list"));
+    assert!(mutating(Method::POST, "/api/v3/post"));
+    assert!(mutating(Method::PUT, "/api/v3/site"));
+    assert!(mutating(Method::POST, "/pictrs/image"));
+    assert!(!mutating(Method::POST, "/inbox"));
+    assert!(!mutating(Method::POST, "/api/v3/user/login"));
+    assert!(!mutating(Method::PUT, "/api/v3/admin/maintenance_mode"));
+  }
+}
@@ -1,8 +1,15 @@
 use actix_web::{rt::System, web, App, HttpServer};
 use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::utils::DB_POOL_STATS;
 use lemmy_utils::{error::LemmyResult, settings::structs::PrometheusConfig};
-use prometheus::{default_registry, Encoder, Gauge, Opts, TextEncoder};
-use std::{sync::Arc, thread};
+use prometheus::{default_registry, Counter, Encoder, Gauge, IntCounter, Opts, TextEncoder};
+use std::{
+  sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+  },
+  thread,
+};
 use tracing::error;
 
 struct PromContext {
@@ -14,6 +21,10 @@ struct DbPoolMetrics {
   max_size: Gauge,
   size: Gauge,
   available: Gauge,
+  acquired: IntCounter,
+  acquire_seconds: Counter,
+  timeouts: IntCounter,
+  slow_queries: IntCounter,
 }
 
 pub fn serve_prometheus(config: PrometheusConfig, lemmy_context: LemmyContext) -> LemmyResult<()> {
@@ -75,11 +86,31 @@ fn create_db_pool_metrics() -> LemmyResult<DbPoolMetrics> {
       "lemmy_db_pool_available_connections",
       "Number of available connections in the pool",
     ))?,
+    acquired: IntCounter::with_opts(Opts::new(
+      "lemmy_db_pool_acquired_total",
+      "Number of connections taken from the pool",
+    ))?,
+    acquire_seconds: Counter::with_opts(Opts::new(
+      "lemmy_db_pool_acquire_seconds_total",
+      "Total time spent waiting for connections from the pool",
+    ))?,
+    timeouts: IntCounter::with_opts(Opts::new(
+      "lemmy_db_pool_timeouts_total",
+      "Number of times that no connection became available before the timeout",
+    ))?,
+    slow_queries: IntCounter::with_opts(Opts::new(
+      "lemmy_db_slow_queries_total",
+      "Number of times that a connection was used for longer than the slow query threshold",
+    ))?,
   };
 
   default_registry().register(Box::new(metrics.max_size.clone()))?;
   default_registry().register(Box::new(metrics.size.clone()))?;
   default_registry().register(Box::new(metrics.available.clone()))?;
+  default_registry().register(Box::new(metrics.acquired.clone()))?;
+  default_registry().register(Box::new(metrics.acquire_seconds.clone()))?;
+  default_registry().register(Box::new(metrics.timeouts.clone()))?;
+  default_registry().register(Box::new(metrics.slow_queries.clone()))?;
 
   Ok(metrics)
 }
@@ -95,4 +126,18 @@ fn collect_db_pool_metrics(context: &PromContext) {
     .db_pool_metrics
     .available
     .set(pool_status.available as f64);
+
+  // The counters are kept in lemmy_db_schema, so only the increase since the last collection is
+  // added here
+  let metrics = &context.db_pool_metrics;
+  let increase = |counter: &IntCounter, total: &AtomicU64| {
+    counter.inc_by(total.load(Ordering::Relaxed).saturating_sub(counter.get()));
+  };
+  increase(&metrics.acquired, &DB_POOL_STATS.acquired);
+  increase(&metrics.timeouts, &DB_POOL_STATS.timeouts);
+  increase(&metrics.slow_queries, &DB_POOL_STATS.slow_queries);
+  let acquire_seconds = DB_POOL_STATS.acquire_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0;
+  metrics
+    .acquire_seconds
+    .inc_by((acquire_seconds - metrics.acquire_seconds.get()).max(0.0));
 }
@@ -1,5 +1,5 @@
 use activitypub_federation::config::Data;
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Datelike, Months, TimeDelta, TimeZone, Utc};
 use clokwerk::{AsyncScheduler, TimeUnits as CTimeUnits};
 use diesel::{
   dsl::{exists, not, IntervalDsl},
@@ -17,46 +17,65 @@ use lemmy_api_common::{
   send_activity::{ActivityChannel, SendActivityData},
 };
 use lemmy_api_crud::post::create::send_webmention;
+use lemmy_apub::http::process_queued_activities;
 use lemmy_db_schema::{
   schema::{
+    ban_list_entry,
     captcha_answer,
     comment,
     community,
     community_actions,
     instance,
+    mod_lock_post,
     person,
     post,
     received_activity,
     sent_activity,
   },
   source::{
+    client_usage::ClientUsage,
+    comment::Comment,
     community::Community,
     instance::{Instance, InstanceForm},
+    local_site::LocalSite,
     local_user::LocalUser,
+    moderator::{ModLockPost, ModLockPostForm},
+    person::Person,
     post::{Post, PostUpdateForm},
+    registration_source::RegistrationSource,
+    transparency_report::TransparencyReport,
+    webauthn_credential::WebauthnChallenge,
   },
   traits::Crud,
-  utils::{
-    find_action,
-    functions::coalesce,
-    get_conn,
-    naive_now,
-    now,
-    DbPool,
-    DELETED_REPLACEMENT_TEXT,
-  },
+  utils::{find_action, get_conn, naive_now, now, DbPool, DELETED_REPLACEMENT_TEXT},
 };
 use lemmy_routes::nodeinfo::{NodeInfo, NodeInfoWellKnown};
-use lemmy_utils::error::LemmyResult;
+use lemmy_utils::{error::LemmyResult, settings::structs::ArchiveConfig};
 use reqwest_middleware::ClientWithMiddleware;
 use std::time::Duration;
 use tracing::{error, info, warn};
 
+/// Number of posts or comments which are archived in one transaction
+const ARCHIVE_BATCH_SIZE: i64 = 1000;
+
+/// Accounts with at least this many votes, which are all upvotes on content of the same person,
+/// count towards a `SingleTargetUpvotes` anomaly
+const SINGLE_TARGET_MIN_VOTES: i32 = 5;
+
+/// Number of such accounts at which a person is flagged
+const SINGLE_TARGET_MIN_VOTERS: i32 = 3;
+
+/// Votes from one instance on the same post which fall within this many seconds form a burst
+const VOTE_BURST_SECONDS: i32 = 60;
+
+/// Number of votes at which a burst is flagged
+const VOTE_BURST_MIN_VOTES: i32 = 10;
+
 /// Schedules various cleanup tasks for lemmy in a background thread
 pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
   // Setup the connections
   let mut scheduler = AsyncScheduler::new();
-  startup_jobs(&mut context.pool()).await;
+  startup_jobs(&mut context.pool(), context.clock().now()).await;
 
   let context_1 = context.clone();
   // Update active counts expired bans and unpublished posts every hour
@@ -65,19 +84,38 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
 
     async move {
       active_counts(&mut context.pool()).await;
-      update_banned_when_expired(&mut context.pool()).await;
+      update_banned_when_expired(&mut context.pool(), context.clock().now()).await;
+    }
+  });
+
+  let context_1 = context.reset_request_count();
+  // Every minute process activities which were queued during maintenance mode
+  scheduler.every(CTimeUnits::minutes(1)).run(move || {
+    let context = context_1.reset_request_count();
+
+    async move {
+      process_queued_activities(&context)
+        .await
+        .inspect_err(|e| warn!("Failed to process queued activities: {e}"))
+        .ok();
     }
   });
 
   let context_1 = context.reset_request_count();
-  // Every 10 minutes update hot ranks, delete expired captchas and publish scheduled posts
+  // Every 10 minutes update hot ranks, delete expired captchas and passkey challenges, publish
+  // scheduled posts and unlock posts whose lock expired
   scheduler.every(CTimeUnits::minutes(10)).run(move || {
     let context = context_1.reset_request_count();
 
     async move {
-      update_hot_ranks(&mut context.pool()).await;
+      update_hot_ranks(&mut context.pool(), context.clock().now()).await;
       delete_expired_captcha_answers(&mut context.pool()).await;
+      WebauthnChallenge::delete_expired(&mut context.pool())
+        .await
+        .inspect_err(|e| error!("Failed to clear expired passkey challenges: {e}"))
+        .ok();
       publish_scheduled_posts(&context).await;
+      unlock_expired_posts(&context).await;
     }
   });
 
@@ -95,6 +133,11 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
   // Daily tasks:
   // - Overwrite deleted & removed posts and comments every day
   // - Delete old denied users
+  // - Delete old client usage
+  // - Delete old registration sources
+  // - Generate transparency reports once a quarter has ended
+  // - Archive old posts and comments, if enabled
+  // - Detect suspicious voting patterns
   // - Update instance software
   scheduler.every(CTimeUnits::days(1)).run(move || {
     let context = context_1.clone();
@@ -102,6 +145,13 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
     async move {
       overwrite_deleted_posts_and_comments(&mut context.pool()).await;
       delete_old_denied_users(&mut context.pool()).await;
+      delete_old_client_usage(&mut context.pool()).await;
+      delete_old_registration_sources(&mut context.pool()).await;
+      generate_transparency_reports(&mut context.pool()).await;
+      if let Some(config) = &context.settings().archive {
+        archive_old_content(&mut context.pool(), config, context.clock().now()).await;
+      }
+      detect_vote_anomalies(&mut context.pool(), context.clock().now()).await;
       update_instance_software(&mut context.pool(), context.client())
         .await
         .inspect_err(|e| warn!("Failed to update instance software: {e}"))
@@ -117,10 +167,10 @@ pub async fn setup(context: Data<LemmyContext>) -> LemmyResult<()> {
 }
 
 /// Run these on server startup
-async fn startup_jobs(pool: &mut DbPool<'_>) {
+async fn startup_jobs(pool: &mut DbPool<'_>, now: DateTime<Utc>) {
   active_counts(pool).await;
-  update_hot_ranks(pool).await;
-  update_banned_when_expired(pool).await;
+  update_hot_ranks(pool, now).await;
+  update_banned_when_expired(pool, now).await;
   clear_old_activities(pool).await;
   overwrite_deleted_posts_and_comments(pool).await;
   delete_old_denied_users(pool).await;
@@ -128,20 +178,25 @@ async fn startup_jobs(pool: &mut DbPool<'_>) {
 
 /// Update the hot_rank columns for the aggregates tables
 /// Runs in batches until all necessary rows are updated once
-async fn update_hot_ranks(pool: &mut DbPool<'_>) {
+async fn update_hot_ranks(pool: &mut DbPool<'_>, now: DateTime<Utc>) {
   info!("Updating hot ranks for all history...");
 
+  let reputation_half_life_days = LocalSite::read(pool)
+    .await
+    .ok()
+    .and_then(|s| s.reputation_half_life_days);
   let conn = get_conn(pool).await;
 
   match conn {
     Ok(mut conn) => {
-      process_post_aggregates_ranks_in_batches(&mut conn).await;
+      process_post_aggregates_ranks_in_batches(&mut conn, now).await;
 
       process_ranks_in_batches(
         &mut conn,
         "comment",
         "a.hot_rank != 0",
-        "SET hot_rank = r.hot_rank(a.score, a.published)",
+        "SET hot_rank = r.hot_rank(a.score, a.published, $3)",
+        now,
       )
       .await;
 
@@ -149,10 +204,15 @@ async fn update_hot_ranks(pool: &mut DbPool<'_>) {
         &mut conn,
         "community",
         "a.hot_rank != 0",
-        "SET hot_rank = r.hot_rank(a.subscribers, a.published)",
+        "SET hot_rank = r.hot_rank(a.subscribers, a.published, $3)",
+        now,
       )
       .await;
 
+      if let Some(half_life_days) = reputation_half_life_days {
+        update_reputation(&mut conn, now, half_life_days).await;
+      }
+
       info!("Finished hot ranks update!");
     }
     Err(e) => {
@@ -168,7 +228,8 @@ struct HotRanksUpdateResult {
 }
 
 /// Runs the hot rank update query in batches until all rows have been processed.
-/// In `where_clause` and `set_clause`, "a" will refer to the current aggregates table.
+/// In `where_clause` and `set_clause`, "a" will refer to the current aggregates table, and `$3`
+/// to the current time.
 /// Locked rows are skipped in order to prevent deadlocks (they will likely get updated on the next
 /// run)
 async fn process_ranks_in_batches(
@@ -176,6 +237,7 @@ async fn process_ranks_in_batches(
   table_name: &str,
   where_clause: &str,
   set_clause: &str,
+  now: DateTime<Utc>,
 ) {
   let process_start_time: DateTime<Utc> = Utc
     .timestamp_opt(0, 0)
@@ -205,6 +267,7 @@ async fn process_ranks_in_batches(
     ))
     .bind::<Timestamptz, _>(previous_batch_last_published)
     .bind::<Integer, _>(update_batch_size)
+    .bind::<Timestamptz, _>(now)
     .get_results::<HotRanksUpdateResult>(conn)
     .await;
 
@@ -227,7 +290,10 @@ async fn process_ranks_in_batches(
 
 /// Post aggregates is a special case, since it needs to join to the community_aggregates
 /// table, to get the active monthly user counts.
-async fn process_post_aggregates_ranks_in_batches(conn: &mut AsyncPgConnection) {
+async fn process_post_aggregates_ranks_in_batches(
+  conn: &mut AsyncPgConnection,
+  now: DateTime<Utc>,
+) {
   let process_start_time: DateTime<Utc> = Utc
     .timestamp_opt(0, 0)
     .single()
@@ -246,15 +312,16 @@ async fn process_post_aggregates_ranks_in_batches(conn: &mut AsyncPgConnection)
                LIMIT $2
                FOR UPDATE SKIP LOCKED)
          UPDATE post_aggregates pa
-           SET hot_rank = r.hot_rank(pa.score, pa.published),
-           hot_rank_active = r.hot_rank(pa.score, pa.newest_comment_time_necro),
-           scaled_rank = r.scaled_rank(pa.score, pa.published, ca.users_active_month)
+           SET hot_rank = r.hot_rank(pa.score, pa.published, $3),
+           hot_rank_active = r.hot_rank(pa.score, pa.newest_comment_time_necro, $3),
+           scaled_rank = r.scaled_rank(pa.score, pa.published, ca.users_active_month, $3)
          FROM batch, community_aggregates ca
          WHERE pa.post_id = batch.post_id and pa.community_id = ca.community_id RETURNING pa.published;
     "#,
     )
     .bind::<Timestamptz, _>(previous_batch_last_published)
     .bind::<Integer, _>(update_batch_size)
+    .bind::<Timestamptz, _>(now)
     .get_results::<HotRanksUpdateResult>(conn)
     .await;
 
@@ -275,6 +342,44 @@ async fn process_post_aggregates_ranks_in_batches(conn: &mut AsyncPgConnection)
   );
 }
 
+/// Recomputes the reputation of all people, which is the sum of the votes on their posts and
+/// comments, with each vote losing half of its weight after `half_life_days`. Votes older than ten
+/// half-lives are ignored, as they count for less than 0.1%.
+async fn update_reputation(conn: &mut AsyncPgConnection, now: DateTime<Utc>, half_life_days: i32) {
+  let result = sql_query(
+    r#"WITH votes AS (
+           SELECT p.creator_id AS person_id, pa.like_score, pa.liked
+             FROM post_actions pa
+             JOIN post p ON p.id = pa.post_id
+             WHERE pa.liked > $1 - make_interval(days => $2 * 10)
+           UNION ALL
+           SELECT c.creator_id, ca.like_score, ca.liked
+             FROM comment_actions ca
+             JOIN comment c ON c.id = ca.comment_id
+             WHERE ca.liked > $1 - make_interval(days => $2 * 10)),
+         reputation AS (
+           SELECT person_id,
+             round(sum(like_score * power(0.5, extract(epoch FROM $1 - liked) / ($2 * 86400.0))))::bigint AS reputation
+             FROM votes
+             GROUP BY person_id)
+       UPDATE person_aggregates a
+         SET reputation = coalesce(r.reputation, 0)
+       FROM person_aggregates a2
+       LEFT JOIN reputation r ON r.person_id = a2.person_id
+       WHERE a.person_id = a2.person_id AND a.reputation != coalesce(r.reputation, 0)
+    "#,
+  )
+  .bind::<Timestamptz, _>(now)
+  .bind::<Integer, _>(half_life_days)
+  .execute(conn)
+  .await;
+
+  match result {
+    Ok(count) => info!("Updated reputation of {count} people"),
+    Err(e) => error!("Failed to update reputation: {e}"),
+  }
+}
+
 async fn delete_expired_captcha_answers(pool: &mut DbPool<'_>) {
   let conn = get_conn(pool).await;
 
@@ -339,6 +444,213 @@ async fn delete_old_denied_users(pool: &mut DbPool<'_>) {
     .ok();
 }
 
+/// Client usage is only listed for the last 90 days, so older counts can be removed
+async fn delete_old_client_usage(pool: &mut DbPool<'_>) {
+  ClientUsage::delete_older_than(pool, Utc::now() - TimeDelta::days(90))
+    .await
+    .inspect_err(|e| error!("Failed to delete old client usage: {e}"))
+    .ok();
+}
+
+/// Registration bursts are only listed for the last 30 days, so older sources can be removed
+async fn delete_old_registration_sources(pool: &mut DbPool<'_>) {
+  RegistrationSource::delete_older_than(pool, Utc::now() - TimeDelta::days(30))
+    .await
+    .inspect_err(|e| error!("Failed to delete old registration sources: {e}"))
+    .ok();
+}
+
+/// Flags suspicious voting patterns for review by admins: accounts which only ever upvoted
+/// content of one person, and bursts of votes on a post from accounts of one instance. Voters
+/// which are already part of a previous finding for the same person or post aren't flagged again.
+async fn detect_vote_anomalies(pool: &mut DbPool<'_>, now: DateTime<Utc>) {
+  info!("Detecting vote anomalies...");
+  let conn = get_conn(pool).await;
+  let since = now - TimeDelta::days(1);
+
+  match conn {
+    Ok(mut conn) => {
+      // Own votes are left out, as new content is upvoted by its creator
+      let single_target = sql_query(
+        r#"WITH recent_voters AS (
+               SELECT person_id FROM post_actions WHERE liked > $1
+               UNION
+               SELECT person_id FROM comment_actions WHERE liked > $1),
+             votes AS (
+               SELECT pa.person_id AS voter_id, p.creator_id AS target_id, pa.like_score
+                 FROM post_actions pa
+                 JOIN post p ON p.id = pa.post_id
+                 JOIN recent_voters r ON r.person_id = pa.person_id
+                 WHERE pa.like_score IS NOT NULL AND pa.person_id != p.creator_id
+               UNION ALL
+               SELECT ca.person_id, c.creator_id, ca.like_score
+                 FROM comment_actions ca
+                 JOIN comment c ON c.id = ca.comment_id
+                 JOIN recent_voters r ON r.person_id = ca.person_id
+                 WHERE ca.like_score IS NOT NULL AND ca.person_id != c.creator_id),
+             voters AS (
+               SELECT voter_id, min(target_id) AS target_id
+                 FROM votes
+                 GROUP BY voter_id
+                 HAVING count(DISTINCT target_id) = 1 AND bool_and(like_score = 1)
+                   AND count(*) >= $2),
+             targets AS (
+               SELECT target_id, array_agg(voter_id ORDER BY voter_id) AS voter_ids
+                 FROM voters
+                 GROUP BY target_id
+                 HAVING count(*) >= $3)
+           INSERT INTO vote_anomaly (kind, person_id, voter_ids)
+           SELECT 'SingleTargetUpvotes', t.target_id, t.voter_ids
+             FROM targets t
+             WHERE NOT EXISTS (
+               SELECT FROM vote_anomaly a
+                 WHERE a.kind = 'SingleTargetUpvotes' AND a.person_id = t.target_id
+                   AND a.voter_ids @> t.voter_ids)
+        "#,
+      )
+      .bind::<Timestamptz, _>(since)
+      .bind::<Integer, _>(SINGLE_TARGET_MIN_VOTES)
+      .bind::<Integer, _>(SINGLE_TARGET_MIN_VOTERS)
+      .execute(&mut conn)
+      .await;
+
+      // A burst starts with any vote which is followed by enough votes from the same instance
+      // within the time span, and contains all of these votes
+      let bursts = sql_query(
+        r#"WITH recent AS (
+               SELECT voter.instance_id, pa.post_id, pa.person_id, pa.liked
+                 FROM post_actions pa
+                 JOIN person voter ON voter.id = pa.person_id
+                 WHERE pa.liked > $1),
+             counted AS (
+               SELECT instance_id, post_id, liked,
+                 count(*) OVER (PARTITION BY instance_id, post_id ORDER BY liked
+                   RANGE BETWEEN CURRENT ROW AND make_interval(secs => $2) FOLLOWING) AS votes
+                 FROM recent),
+             burst_votes AS (
+               SELECT DISTINCT r.instance_id, r.post_id, r.person_id
+                 FROM counted b
+                 JOIN recent r ON r.instance_id = b.instance_id AND r.post_id = b.post_id
+                   AND r.liked >= b.liked AND r.liked <= b.liked + make_interval(secs => $2)
+                 WHERE b.votes >= $3),
+             bursts AS (
+               SELECT instance_id, post_id, array_agg(person_id ORDER BY person_id) AS voter_ids
+                 FROM burst_votes
+                 GROUP BY instance_id, post_id)
+           INSERT INTO vote_anomaly (kind, instance_id, post_id, voter_ids)
+           SELECT 'InstanceVoteBurst', b.instance_id, b.post_id, b.voter_ids
+             FROM bursts b
+             WHERE NOT EXISTS (
+               SELECT FROM vote_anomaly a
+                 WHERE a.kind = 'InstanceVoteBurst' AND a.instance_id = b.instance_id
+                   AND a.post_id = b.post_id AND a.voter_ids @> b.voter_ids)
+        "#,
+      )
+      .bind::<Timestamptz, _>(since)
+      .bind::<Integer, _>(VOTE_BURST_SECONDS)
+      .bind::<Integer, _>(VOTE_BURST_MIN_VOTES)
+      .execute(&mut conn)
+      .await;
+
+      match (single_target, bursts) {
+        (Ok(single_target), Ok(bursts)) => {
+          info!("Found {single_target} single target voting rings and {bursts} vote bursts")
+        }
+        (Err(e), _) | (_, Err(e)) => error!("Failed to detect vote anomalies: {e}"),
+      }
+    }
+    Err(e) => {
+      error!("Failed to get connection from pool: {e}");
+    }
+  }
+}
+
+/// Moves the text of old posts and comments with little engagement into the archive tables
+async fn archive_old_content(pool: &mut DbPool<'_>, config: &ArchiveConfig, now: DateTime<Utc>) {
+  info!("Archiving old posts and comments...");
+  let published_before = now - TimeDelta::days(config.min_age_days.into());
+
+  let mut archived_posts = 0;
+  loop {
+    match Post::archive_old(
+      pool,
+      published_before,
+      config.max_score,
+      config.max_comments.into(),
+      ARCHIVE_BATCH_SIZE,
+    )
+    .await
+    {
+      Ok(count) => {
+        archived_posts += count;
+        if (count as i64) < ARCHIVE_BATCH_SIZE {
+          break;
+        }
+      }
+      Err(e) => {
+        error!("Failed to archive posts: {e}");
+        break;
+      }
+    }
+  }
+
+  let mut archived_comments = 0;
+  loop {
+    match Comment::archive_old(
+      pool,
+      published_before,
+      config.max_score,
+      config.max_comments,
+      ARCHIVE_BATCH_SIZE,
+    )
+    .await
+    {
+      Ok(count) => {
+        archived_comments += count;
+        if (count as i64) < ARCHIVE_BATCH_SIZE {
+          break;
+        }
+      }
+      Err(e) => {
+        error!("Failed to archive comments: {e}");
+        break;
+      }
+    }
+  }
+  info!("Archived {archived_posts} posts and {archived_comments} comments.");
+}
+
+/// Generates the transparency reports for the previous quarter, unless that happened already
+async fn generate_transparency_reports(pool: &mut DbPool<'_>) {
+  let Some((period_start, period_end)) = previous_quarter(Utc::now()) else {
+    return;
+  };
+  match TransparencyReport::exists_for_period(pool, period_start).await {
+    Ok(false) => {
+      info!("Generating transparency reports...");
+      TransparencyReport::generate(pool, period_start, period_end)
+        .await
+        .map(|_| {
+          info!("Done.");
+        })
+        .inspect_err(|e| error!("Failed to generate transparency reports: {e}"))
+        .ok();
+    }
+    Ok(true) => {}
+    Err(e) => error!("Failed to check for transparency reports: {e}"),
+  }
+}
+
+/// Start and end of the calendar quarter before the one which contains the given time.
+fn previous_quarter(time: DateTime<Utc>) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+  let quarter_start_month = time.month0() / 3 * 3 + 1;
+  let end = Utc
+    .with_ymd_and_hms(time.year(), quarter_start_month, 1, 0, 0, 0)
+    .single()?;
+  let start = end.checked_sub_months(Months::new(3))?;
+  Some((start, end))
+}
+
 /// overwrite posts and comments 30d after deletion
 async fn overwrite_deleted_posts_and_comments(pool: &mut DbPool<'_>) {
   info!("Overwriting deleted posts...");
@@ -429,7 +741,7 @@ async fn active_counts(pool: &mut DbPool<'_>) {
 }
 
 /// Set banned to false after ban expires
-async fn update_banned_when_expired(pool: &mut DbPool<'_>) {
+async fn update_banned_when_expired(pool: &mut DbPool<'_>, now: DateTime<Utc>) {
   info!("Updating banned column if it expires ...");
   let conn = get_conn(pool).await;
 
@@ -438,7 +750,7 @@ async fn update_banned_when_expired(pool: &mut DbPool<'_>) {
       diesel::update(
         person::table
           .filter(person::banned.eq(true))
-          .filter(person::ban_expires.lt(now().nullable())),
+          .filter(person::ban_expires.lt(now)),
       )
       .set(person::banned.eq(false))
       .execute(&mut conn)
@@ -446,13 +758,17 @@ async fn update_banned_when_expired(pool: &mut DbPool<'_>) {
       .inspect_err(|e| error!("Failed to update person.banned when expires: {e}"))
       .ok();
 
-      diesel::delete(
-        community_actions::table.filter(community_actions::ban_expires.lt(now().nullable())),
-      )
-      .execute(&mut conn)
-      .await
-      .inspect_err(|e| error!("Failed to remove community_ban expired rows: {e}"))
-      .ok();
+      diesel::delete(community_actions::table.filter(community_actions::ban_expires.lt(now)))
+        .execute(&mut conn)
+        .await
+        .inspect_err(|e| error!("Failed to remove community_ban expired rows: {e}"))
+        .ok();
+
+      diesel::delete(ban_list_entry::table.filter(ban_list_entry::expires.lt(now)))
+        .execute(&mut conn)
+        .await
+        .inspect_err(|e| error!("Failed to remove expired ban list entries: {e}"))
+        .ok();
     }
     Err(e) => {
       error!("Failed to get connection from pool: {e}");
@@ -464,6 +780,7 @@ async fn update_banned_when_expired(pool: &mut DbPool<'_>) {
 async fn publish_scheduled_posts(context: &Data<LemmyContext>) {
   let pool = &mut context.pool();
   let conn = get_conn(pool).await;
+  let now = context.clock().now();
 
   match conn {
     Ok(mut conn) => {
@@ -472,9 +789,11 @@ async fn publish_scheduled_posts(context: &Data<LemmyContext>) {
         .inner_join(person::table)
         // find all posts which have scheduled_publish_time that is in the  past
         .filter(post::scheduled_publish_time.is_not_null())
-        .filter(coalesce(post::scheduled_publish_time, now()).lt(now()))
+        .filter(post::scheduled_publish_time.lt(now))
         // make sure the post, person and community are still around
         .filter(not(post::deleted.or(post::removed)))
+        // pending posts are published once they are approved
+        .filter(not(post::pending))
         .filter(not(person::banned.or(person::deleted)))
         .filter(not(community::removed.or(community::deleted)))
         // ensure that user isnt banned from community
@@ -514,6 +833,73 @@ async fn publish_scheduled_posts(context: &Data<LemmyContext>) {
   }
 }
 
+/// Unlock all posts whose temporary lock has expired. The unlock is logged and federated in the
+/// name of the moderator who locked the post.
+async fn unlock_expired_posts(context: &Data<LemmyContext>) {
+  let pool = &mut context.pool();
+  let conn = get_conn(pool).await;
+  let now = context.clock().now();
+
+  match conn {
+    Ok(mut conn) => {
+      let expired_posts: Vec<Post> = post::table
+        .filter(post::locked.eq(true))
+        .filter(post::lock_expires.lt(now))
+        .select(post::all_columns)
+        .get_results(&mut conn)
+        .await
+        .inspect_err(|e| error!("Failed to read posts with expired locks: {e}"))
+        .ok()
+        .unwrap_or_default();
+
+      for post in expired_posts {
+        let form = PostUpdateForm {
+          locked: Some(false),
+          lock_expires: Some(None),
+          ..Default::default()
+        };
+        let Ok(post) = Post::update(&mut context.pool(), post.id, &form)
+          .await
+          .inspect_err(|e| error!("Failed to unlock post: {e}"))
+        else {
+          continue;
+        };
+
+        let moderator = mod_lock_post::table
+          .inner_join(person::table)
+          .filter(mod_lock_post::post_id.eq(post.id))
+          .filter(mod_lock_post::locked.eq(true))
+          .order_by(mod_lock_post::when_.desc())
+          .select((person::all_columns, mod_lock_post::automod))
+          .first::<(Person, bool)>(&mut conn)
+          .await
+          .ok();
+        // The unlock is attributed to whoever locked the post, which may be a community bot
+        if let Some((moderator, automod)) = moderator {
+          let form = ModLockPostForm {
+            mod_person_id: moderator.id,
+            post_id: post.id,
+            locked: Some(false),
+            automod,
+          };
+          ModLockPost::create(&mut context.pool(), &form)
+            .await
+            .inspect_err(|e| error!("Failed to log post unlock: {e}"))
+            .ok();
+
+          let send_activity = SendActivityData::LockPost(post, moderator, false, automod);
+          ActivityChannel::submit_activity(send_activity, context)
+            .inspect_err(|e| error!("Failed to federate post unlock: {e}"))
+            .ok();
+        }
+      }
+    }
+    Err(e) => {
+      error!("Failed to get connection from pool: {e}");
+    }
+  }
+}
+
 /// Updates the instance software and version.
 ///
 /// Does so using the /.well-known/nodeinfo protocol described here:
@@ -613,15 +999,44 @@ async fn build_update_instance_form(
 #[cfg(test)]
 mod tests {
 
-  use crate::scheduled_tasks::build_update_instance_form;
-  use lemmy_api_common::request::client_builder;
+  use crate::scheduled_tasks::{
+    archive_old_content,
+    build_update_instance_form,
+    detect_vote_anomalies,
+    previous_quarter,
+    unlock_expired_posts,
+    update_banned_when_expired,
+    update_reputation,
+  };
+  use chrono::{TimeDelta, TimeZone, Utc};
+  use diesel::QueryDsl;
+  use diesel_async::RunQueryDsl;
+  use lemmy_api_common::{context::LemmyContext, request::client_builder};
+  use lemmy_db_schema::{
+    aggregates::structs::PersonAggregates,
+    schema::vote_anomaly,
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      local_user::LocalUser,
+      moderator::{ModLockPost, ModLockPostForm},
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm, PostLike, PostLikeForm, PostUpdateForm},
+      vote_anomaly::VoteAnomaly,
+    },
+    traits::{Crud, Likeable},
+    utils::get_conn,
+    VoteAnomalyKind,
+  };
   use lemmy_utils::{
+    clock::FakeClock,
     error::{LemmyErrorType, LemmyResult},
-    settings::structs::Settings,
+    settings::structs::{ArchiveConfig, Settings},
   };
   use pretty_assertions::assert_eq;
   use reqwest_middleware::ClientBuilder;
   use serial_test::serial;
+  use std::sync::Arc;
 
   #[tokio::test]
   #[serial]
@@ -644,4 +1059,305 @@ mod tests {
     assert_eq!(form.software.ok_or(LemmyErrorType::NotFound)?, "mastodon");
     Ok(())
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_ban_expires() -> LemmyResult<()> {
+    let clock = Arc::new(FakeClock::new(Utc::now()));
+    let context = LemmyContext::init_test_context_with_clock(clock.clone()).await;
+    let pool = &mut context.pool();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person_form = PersonInsertForm {
+      banned: Some(true),
+      ban_expires: Some(context.clock().now() + TimeDelta::days(1)),
+      ..PersonInsertForm::test_form(instance.id, "temp_banned")
+    };
+    let person = Person::create(pool, &person_form).await?;
+
+    update_banned_when_expired(pool, context.clock().now()).await;
+    assert!(Person::read(pool, person.id).await?.banned);
+
+    // The ban is lifted once the clock has moved past the expiry time
+    clock.advance(TimeDelta::days(2));
+    update_banned_when_expired(pool, context.clock().now()).await;
+    assert!(!Person::read(pool, person.id).await?.banned);
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_lock_expires() -> LemmyResult<()> {
+    let clock = Arc::new(FakeClock::new(Utc::now()));
+    let context = LemmyContext::init_test_context_with_clock(clock.clone()).await;
+    let pool = &mut context.pool();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "lock_mod")).await?;
+    let community_form = CommunityInsertForm::new(
+      instance.id,
+      "lock_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let post_form = PostInsertForm::new("locked post".into(), person.id, community.id);
+    let post = Post::create(pool, &post_form).await?;
+
+    let form = PostUpdateForm {
+      locked: Some(true),
+      lock_expires: Some(Some(context.clock().now() + TimeDelta::days(1))),
+      ..Default::default()
+    };
+    Post::update(pool, post.id, &form).await?;
+    let form = ModLockPostForm {
+      mod_person_id: person.id,
+      post_id: post.id,
+      locked: Some(true),
+      automod: false,
+    };
+    ModLockPost::create(pool, &form).await?;
+
+    unlock_expired_posts(&context).await;
+    assert!(Post::read(pool, post.id).await?.locked);
+
+    // The post is unlocked once the clock has moved past the expiry time
+    clock.advance(TimeDelta::days(2));
+    unlock_expired_posts(&context).await;
+    let post = Post::read(pool, post.id).await?;
+    assert!(!post.locked);
+    assert_eq!(None, post.lock_expires);
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_archive_old_content() -> LemmyResult<()> {
+    let clock = Arc::new(FakeClock::new(Utc::now()));
+    let context = LemmyContext::init_test_context_with_clock(clock.clone()).await;
+    let pool = &mut context.pool();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let person = Person::create(
+      pool,
+      &PersonInsertForm::test_form(instance.id, "archive_author"),
+    )
+    .await?;
+    let community_form = CommunityInsertForm::new(
+      instance.id,
+      "archive_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let post_form = PostInsertForm {
+      body: Some("old body".to_string()),
+      ..PostInsertForm::new("old post".into(), person.id, community.id)
+    };
+    let post = Post::create(pool, &post_form).await?;
+    let config = ArchiveConfig::default();
+
+    archive_old_content(pool, &config, context.clock().now()).await;
+    assert!(!Post::read(pool, post.id).await?.archived);
+
+    // The post is archived once the clock has moved past the minimum age
+    clock.advance(TimeDelta::days((config.min_age_days + 1).into()));
+    archive_old_content(pool, &config, context.clock().now()).await;
+    let mut archived = Post::read(pool, post.id).await?;
+    assert!(archived.archived);
+    assert_eq!(None, archived.body);
+
+    Post::restore_archived(pool, [&mut archived]).await?;
+    assert_eq!(Some("old body".to_string()), archived.body);
+
+    // The user data export contains the original text
+    let export = LocalUser::export_content(pool, person.id).await?;
+    assert_eq!(
+      Some("old body".to_string()),
+      export.posts.first().and_then(|p| p.body.clone())
+    );
+
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_update_reputation() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let author =
+      Person::create(pool, &PersonInsertForm::test_form(instance.id, "reputable")).await?;
+    let community_form = CommunityInsertForm::new(
+      instance.id,
+      "reputation_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let post_form = PostInsertForm::new("liked post".into(), author.id, community.id);
+    let post = Post::create(pool, &post_form).await?;
+    for name in ["reputation_voter_1", "reputation_voter_2"] {
+      let voter = Person::create(pool, &PersonInsertForm::test_form(instance.id, name)).await?;
+      let form = PostLikeForm {
+        post_id: post.id,
+        person_id: voter.id,
+        score: 1,
+      };
+      PostLike::like(pool, &form).await?;
+    }
+
+    let conn = &mut get_conn(pool).await?;
+    let now = Utc::now();
+    update_reputation(conn, now, 10).await;
+    assert_eq!(
+      2,
+      PersonAggregates::read(&mut conn.into(), author.id)
+        .await?
+        .reputation
+    );
+
+    // After one half-life the votes count half
+    update_reputation(conn, now + TimeDelta::days(10), 10).await;
+    assert_eq!(
+      1,
+      PersonAggregates::read(&mut conn.into(), author.id)
+        .await?
+        .reputation
+    );
+
+    // Votes older than ten half-lives are ignored
+    update_reputation(conn, now + TimeDelta::days(101), 10).await;
+    assert_eq!(
+      0,
+      PersonAggregates::read(&mut conn.into(), author.id)
+        .await?
+        .reputation
+    );
+
+    Instance::delete(&mut conn.into(), instance.id).await?;
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_detect_vote_anomalies() -> LemmyResult<()> {
+    let context = LemmyContext::init_test_context().await;
+    let pool = &mut context.pool();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string()).await?;
+    let other_instance = Instance::read_or_create(pool, "vote_burst.tld".to_string()).await?;
+    let creator = Person::create(
+      pool,
+      &PersonInsertForm::test_form(instance.id, "vote_target"),
+    )
+    .await?;
+    let community_form = CommunityInsertForm::new(
+      instance.id,
+      "vote_community".to_string(),
+      "nada".to_owned(),
+      "pubkey".to_string(),
+    );
+    let community = Community::create(pool, &community_form).await?;
+    let mut posts = vec![];
+    for i in 0..5 {
+      let post_form = PostInsertForm::new(format!("vote post {i}"), creator.id, community.id);
+      posts.push(Post::create(pool, &post_form).await?);
+    }
+
+    // Three accounts which only upvote posts of the same person
+    let mut ring_ids = vec![];
+    for i in 0..3 {
+      let form = PersonInsertForm::test_form(instance.id, &format!("vote_ring_{i}"));
+      let voter = Person::create(pool, &form).await?;
+      for post in &posts {
+        let form = PostLikeForm {
+          post_id: post.id,
+          person_id: voter.id,
+          score: 1,
+        };
+        PostLike::like(pool, &form).await?;
+      }
+      ring_ids.push(voter.id);
+    }
+
+    // Many accounts from one instance voting on the same post at once
+    let mut burst_ids = vec![];
+    for i in 0..10 {
+      let form = PersonInsertForm::test_form(other_instance.id, &format!("vote_burst_{i}"));
+      let voter = Person::create(pool, &form).await?;
+      let form = PostLikeForm {
+        post_id: posts[0].id,
+        person_id: voter.id,
+        score: 1,
+      };
+      PostLike::like(pool, &form).await?;
+      burst_ids.push(voter.id);
+    }
+
+    detect_vote_anomalies(pool, context.clock().now()).await;
+    // Findings aren't repeated on the next run
+    detect_vote_anomalies(pool, context.clock().now()).await;
+
+    let anomalies = vote_anomaly::table
+      .order_by(vote_anomaly::id)
+      .load::<VoteAnomaly>(&mut get_conn(pool).await?)
+      .await?;
+    assert_eq!(2, anomalies.len());
+    let ring = &anomalies[0];
+    assert_eq!(VoteAnomalyKind::SingleTargetUpvotes, ring.kind);
+    assert_eq!(Some(creator.id), ring.person_id);
+    assert_eq!(ring_ids, ring.voter_ids);
+    let burst = &anomalies[1];
+    assert_eq!(VoteAnomalyKind::InstanceVoteBurst, burst.kind);
+    assert_eq!(Some(other_instance.id), burst.instance_id);
+    assert_eq!(Some(posts[0].id), burst.post_id);
+    assert_eq!(burst_ids, burst.voter_ids);
+
+    assert_eq!(15, ring.remove_votes(pool).await?);
+    assert_eq!(10, burst.remove_votes(pool).await?);
+
+    Instance::delete(pool, other_instance.id).await?;
+    Instance::delete(pool, instance.id).await?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_previous_quarter() -> LemmyResult<()> {
+    let time = Utc
+      .with_ymd_and_hms(2024, 5, 17, 12, 0, 0)
+      .single()
+      .ok_or(LemmyErrorType::NotFound)?;
+    let (start, end) = previous_quarter(time).ok_or(LemmyErrorType::NotFound)?;
+    assert_eq!(
+      Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).single(),
+      Some(start)
+    );
+    assert_eq!(
+      Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).single(),
+      Some(end)
+    );
+
+    let time = Utc
+      .with_ymd_and_hms(2024, 2, 1, 0, 0, 0)
+      .single()
+      .ok_or(LemmyErrorType::NotFound)?;
+    let (start, end) = previous_quarter(time).ok_or(LemmyErrorType::NotFound)?;
+    assert_eq!(
+      Utc.with_ymd_and_hms(2023, 10, 1, 0, 0, 0).single(),
+      Some(start)
+    );
+    assert_eq!(
+      Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).single(),
+      Some(end)
+    );
+    Ok(())
+  }
 }
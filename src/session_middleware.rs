@@ -8,7 +8,11 @@ use actix_web::{
 use core::future::Ready;
 use futures_util::future::LocalBoxFuture;
 use lemmy_api::{local_user_view_from_jwt, read_auth_token};
-use lemmy_api_common::context::LemmyContext;
+use lemmy_api_common::{
+  community_bot::{CommunityBotToken, COMMUNITY_BOT_TOKEN_PREFIX},
+  context::LemmyContext,
+};
+use lemmy_utils::response::InterfaceLanguage;
 use std::{future::ready, rc::Rc};
 
 #[derive(Clone)]
@@ -65,13 +69,24 @@ where
     Box::pin(async move {
       let jwt = read_auth_token(req.request())?;
 
-      if let Some(jwt) = &jwt {
+      if let Some(token) = jwt
+        .as_ref()
+        .filter(|t| t.starts_with(COMMUNITY_BOT_TOKEN_PREFIX))
+      {
+        // Bot tokens only work for the moderation actions of their delegation
+        if let Ok(Some(bot_token)) = CommunityBotToken::read(token, &context).await {
+          req.extensions_mut().insert(bot_token);
+        }
+      } else if let Some(jwt) = &jwt {
         // Ignore any invalid auth so the site can still be used
         // TODO: this means it will be impossible to get any error message for invalid jwt. Need
         //       to add a separate endpoint for that.
         //       https://github.com/LemmyNet/lemmy/issues/3702
         let local_user_view = local_user_view_from_jwt(jwt, &context).await.ok();
         if let Some(local_user_view) = local_user_view {
+          req.extensions_mut().insert(InterfaceLanguage(
+            local_user_view.local_user.interface_language.clone(),
+          ));
           req.extensions_mut().insert(local_user_view);
         }
       }